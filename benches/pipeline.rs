@@ -0,0 +1,148 @@
+//! Criterion benchmarks over a generated 100k-row CSV: parse, bind, optimize
+//! and execute are measured separately for each query. run with
+//! `cargo bench --bench pipeline`; `-- --save-baseline main` on one commit and
+//! `-- --baseline main` on another reports the regressions between them
+
+use celect::{Binder, Optimizer, Parser, PhysicalPlanner, PipelineExecutor, Planner};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+
+const ROWS: usize = 100_000;
+
+const QUERIES: [(&str, &str); 4] = [
+    ("full scan", "SELECT * FROM '{file}'"),
+    (
+        "filter",
+        "SELECT name, score FROM '{file}' WHERE age > 30 AND active = true AND score > 50.0",
+    ),
+    (
+        "group by",
+        "SELECT city, COUNT(*), AVG(score) FROM '{file}' GROUP BY city",
+    ),
+    (
+        "top-n",
+        "SELECT name, score FROM '{file}' ORDER BY score DESC LIMIT 10",
+    ),
+];
+
+/// each query with the generated file's path filled in
+fn queries(file: &BenchFile) -> impl Iterator<Item = (&'static str, String)> + '_ {
+    QUERIES.into_iter().map(|(name, template)| {
+        (
+            name,
+            template.replace("{file}", &file.path.display().to_string()),
+        )
+    })
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let file = BenchFile::generate(ROWS);
+    let mut group = c.benchmark_group("parse");
+    for (name, sql) in queries(&file) {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &sql, |b, sql| {
+            b.iter(|| Parser::new().parse(sql).expect("parse failed"));
+        });
+    }
+    group.finish();
+}
+
+fn bench_bind(c: &mut Criterion) {
+    let file = BenchFile::generate(ROWS);
+    let mut group = c.benchmark_group("bind");
+    for (name, sql) in queries(&file) {
+        let query = Parser::new().parse(&sql).expect("parse failed");
+        group.bench_with_input(BenchmarkId::from_parameter(name), &query, |b, query| {
+            b.iter(|| Binder::new().bind(query.clone()).expect("binding failed"));
+        });
+    }
+    group.finish();
+}
+
+fn bench_optimize(c: &mut Criterion) {
+    let file = BenchFile::generate(ROWS);
+    let mut group = c.benchmark_group("optimize");
+    for (name, sql) in queries(&file) {
+        let query = Parser::new().parse(&sql).expect("parse failed");
+        let bound_query = Binder::new().bind(query).expect("binding failed");
+        // planning is part of the optimize stage; both are pure plan rewrites
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &bound_query,
+            |b, bound| {
+                b.iter(|| Optimizer::new().optimize(Planner::new().plan(bound.clone())));
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_execute(c: &mut Criterion) {
+    let file = BenchFile::generate(ROWS);
+    let mut group = c.benchmark_group("execute");
+    // a run scans the whole file: fewer samples keep the benchmark short
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(ROWS as u64));
+    for (name, sql) in queries(&file) {
+        let query = Parser::new().parse(&sql).expect("parse failed");
+        let bound_query = Binder::new().bind(query).expect("binding failed");
+        let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+        let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+        // one executor, reset between runs
+        let mut executor = PipelineExecutor::new(operators, schemas);
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| {
+                executor.reset();
+                black_box(executor.execute())
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_parse,
+    bench_bind,
+    bench_optimize,
+    bench_execute
+);
+criterion_main!(benches);
+
+/// generated CSV in the temp directory, removed when dropped
+struct BenchFile {
+    path: PathBuf,
+}
+
+impl BenchFile {
+    fn generate(rows: usize) -> Self {
+        const CITIES: [&str; 5] = ["Paris", "Tokyo", "Lima", "Oslo", "Cairo"];
+        let mut contents = String::from("id,name,age,score,active,city\n");
+        for i in 0..rows {
+            // a small linear congruential sequence keeps the data reproducible
+            let mix = (i as u64)
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            contents.push_str(&format!(
+                "{},user{},{},{:.1},{},{}\n",
+                i,
+                i,
+                18 + (mix >> 33) % 60,
+                ((mix >> 17) % 1000) as f64 / 10.0,
+                mix % 3 != 0,
+                CITIES[(mix >> 40) as usize % CITIES.len()]
+            ));
+        }
+
+        let path = std::env::temp_dir().join(format!("celect_bench_{}.csv", std::process::id()));
+        fs::write(&path, contents).expect("cannot write the benchmark CSV");
+        Self { path }
+    }
+}
+
+impl Drop for BenchFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
@@ -1,6 +1,6 @@
-use celect::binder::BoundAggregateExpression;
-use celect::parser::LiteralValue;
-use celect::{Binder, BoundExpression, LogicalOperator, Optimizer, Parser, Planner};
+use celect::{Binder, LogicalOperator, Optimizer, Parser, Planner};
+use celect::date::{format_date, format_timestamp};
+use celect::format::format_float;
 use celect::{DataChunk, PhysicalPlanner, PipelineExecutor, Value};
 use colored::*;
 use std::time::Instant;
@@ -72,7 +72,7 @@ fn main() {
             }
 
             if let Some(where_clause) = &bound.where_clause {
-                println!("WHERE clause bound: {}", format_expression(where_clause));
+                println!("WHERE clause bound: {}", where_clause);
             }
 
             bound
@@ -98,7 +98,7 @@ fn main() {
         format!("({})", format_duration(plan_duration)).bright_black()
     );
     println!("Before optimization:");
-    print_plan(&plan, 0);
+    print_plan(&plan);
 
     println!();
     println!("{}", "=== OPTIMIZATION ===".bright_cyan().bold());
@@ -115,7 +115,7 @@ fn main() {
         format!("({})", format_duration(opt_duration)).bright_black()
     );
     println!("After optimization:");
-    print_plan(&optimized_plan, 0);
+    print_plan(&optimized_plan);
 
     println!();
     println!("{}", "=== EXECUTION ===".bright_cyan().bold());
@@ -146,7 +146,7 @@ fn main() {
     println!();
 
     // print results
-    print_results(&results);
+    print_results(&executor.output_schema().names(), &results);
 
     println!();
     let total_duration = total_start.elapsed();
@@ -157,7 +157,7 @@ fn main() {
     );
 }
 
-fn print_results(chunks: &[DataChunk]) {
+fn print_results(column_names: &[String], chunks: &[DataChunk]) {
     if chunks.is_empty() {
         println!("{}", "No results".yellow());
         return;
@@ -173,11 +173,8 @@ fn print_results(chunks: &[DataChunk]) {
     let col_count = chunks[0].column_count();
 
     // print table header
-    for col_idx in 0..col_count {
-        print!(
-            "{:<20} ",
-            format!("Column {}", col_idx).bright_cyan().bold()
-        );
+    for name in column_names {
+        print!("{:<20} ", name.bright_cyan().bold());
     }
     println!();
     println!("{}", "─".repeat(col_count * 21).bright_black());
@@ -200,9 +197,11 @@ fn print_results(chunks: &[DataChunk]) {
 fn format_value(value: &Value) -> String {
     match value {
         Value::Integer(i) => i.to_string(),
-        Value::Float(f) => f.to_string(),
+        Value::Float(f) => format_float(*f, None),
         Value::Boolean(b) => b.to_string(),
         Value::Varchar(s) => format!("'{}'", s),
+        Value::Date(d) => format_date(*d),
+        Value::Timestamp(t) => format_timestamp(*t),
         Value::Null => "NULL".to_string(),
     }
 }
@@ -218,113 +217,9 @@ fn format_duration(duration: std::time::Duration) -> String {
     }
 }
 
-fn format_expression(expr: &BoundExpression) -> String {
-    match expr {
-        BoundExpression::Or(left, right) => format!(
-            "{} OR {}",
-            format_expression(left),
-            format_expression(right)
-        ),
-        BoundExpression::And(left, right) => format!(
-            "{} AND {}",
-            format_expression(left),
-            format_expression(right)
-        ),
-        BoundExpression::Not(inner) => format!("NOT ({})", format_expression(inner)),
-        BoundExpression::ColumnRef { name, .. } => name.clone(),
-        BoundExpression::Literal { value, .. } => match value {
-            LiteralValue::Integer(v) => v.to_string(),
-            LiteralValue::Float(v) => v.to_string(),
-            LiteralValue::String(v) => format!("'{}'", v),
-            LiteralValue::Boolean(v) => v.to_string(),
-            LiteralValue::Null => "NULL".to_string(),
-        },
-        BoundExpression::Equal(l, r) => {
-            format!("{} = {}", format_expression(l), format_expression(r))
-        }
-        BoundExpression::NotEqual(l, r) => {
-            format!("{} != {}", format_expression(l), format_expression(r))
-        }
-        BoundExpression::GreaterThan(l, r) => {
-            format!("{} > {}", format_expression(l), format_expression(r))
-        }
-        BoundExpression::GreaterThanOrEqual(l, r) => {
-            format!("{} >= {}", format_expression(l), format_expression(r))
-        }
-        BoundExpression::LessThan(l, r) => {
-            format!("{} < {}", format_expression(l), format_expression(r))
-        }
-        BoundExpression::LessThanOrEqual(l, r) => {
-            format!("{} <= {}", format_expression(l), format_expression(r))
-        }
-    }
-}
-
-fn print_plan(operator: &LogicalOperator, indent: usize) {
-    let indent_str = if indent > 0 {
-        " ".repeat(indent) + "└── "
-    } else {
-        "".to_string()
-    };
-
-    match operator {
-        LogicalOperator::Projection(proj) => {
-            let columns: Vec<String> = proj.expressions.iter().map(format_expression).collect();
-            println!(
-                "{}LogicalProjection (Output: {})",
-                indent_str,
-                columns.join(", ")
-            );
-            print_plan(&proj.child, indent + 2);
-        }
-        LogicalOperator::Filter(filter) => {
-            println!(
-                "{}LogicalFilter (Condition: {})",
-                indent_str,
-                format_expression(&filter.expression)
-            );
-            print_plan(&filter.child, indent + 5); // increase indent to align under "LogicalFilter"
-        }
-        LogicalOperator::Get(get) => {
-            let columns: Vec<String> = get.columns.iter().map(|c| c.name.clone()).collect();
-            println!(
-                "{}LogicalGet (File: {}, Schema: [{}])",
-                indent_str,
-                get.file_path
-                    .file_name()
-                    .unwrap_or_default()
-                    .to_string_lossy(),
-                columns.join(", ")
-            );
-        }
-        LogicalOperator::Limit(limit) => {
-            let limit_str = limit
-                .limit
-                .map(|l| format!("LIMIT {}", l))
-                .unwrap_or_default();
-            let offset_str = limit
-                .offset
-                .map(|o| format!("OFFSET {}", o))
-                .unwrap_or_default();
-            let clause = format!("{} {}", limit_str, offset_str).trim().to_string();
-            println!("{}LogicalLimit ({})", indent_str, clause);
-            print_plan(&limit.child, indent + 2);
-        }
-        LogicalOperator::Aggregate(agg) => {
-            let agg_names: Vec<String> = agg
-                .aggregates
-                .iter()
-                .map(|a| match a {
-                    BoundAggregateExpression::CountStar => "COUNT(*)".to_string(),
-                    BoundAggregateExpression::Count { column } => format!("COUNT({})", column.name),
-                })
-                .collect();
-            println!(
-                "{}LogicalAggregate (Aggregates: [{}])",
-                indent_str,
-                agg_names.join(", ")
-            );
-            print_plan(&agg.child, indent + 2);
-        }
+/// print the plan tree indented under its heading
+fn print_plan(operator: &LogicalOperator) {
+    for line in operator.explain().lines() {
+        println!("  {}", line);
     }
 }
@@ -8,48 +8,100 @@ module.exports = grammar({
   extras: $ => [/\s/, /\n/],
 
   rules: {
-    source_file: $ => seq($._statement, optional(';')),
+    source_file: $ => seq(optional($.explain), $._statement, optional(';')),
+
+    // EXPLAIN prefix: return the plan instead of running the query
+    // (EXPLAIN ANALYZE runs it and reports what each operator did)
+    explain: $ => seq(kw('EXPLAIN'), optional($.analyze)),
+
+    analyze: $ => kw('ANALYZE'),
 
     _statement: $ => choice(
+      $.select_statement,
+      $.union_statement
+    ),
+
+    // SELECT ... UNION [ALL] SELECT ...: ORDER BY, LIMIT and OFFSET after the
+    // last SELECT apply to the combined rows
+    union_statement: $ => seq(
+      $.select_statement,
+      repeat1($.union_clause)
+    ),
+
+    union_clause: $ => seq(
+      kw('UNION'),
+      optional($.union_all),
       $.select_statement
     ),
 
+    // UNION ALL keeps duplicate rows
+    union_all: $ => kw('ALL'),
+
     select_statement: $ => seq(
       kw('SELECT'),
       $.select_list,
       kw('FROM'),
       $.file_name,
+      repeat(seq(',', $.file_name)),  // files with the same columns, read one after another
+      optional($.sample_clause),
       optional($.where_clause),
+      optional($.group_by_clause),
+      optional($.having_clause),
+      optional($.order_by_clause),
       optional($.limit_clause),
-      optional($.offset_clause)
+      optional($.offset_clause),
+      optional($.fetch_clause)
     ),
 
-    select_list: $ => choice(
-      $.column_list,
-      '*'
-    ),
+    // '*' is an entry of the list, so it can be combined with columns: SELECT *, name
+    select_list: $ => $.column_list,
 
     column_list: $ => seq(
       $.select_expression,
       repeat(seq(',', $.select_expression))
     ),
 
+    // a column, an aggregate or a computed expression (name || ' ' || city),
+    // optionally renamed: COUNT(*) AS n
     select_expression: $ => choice(
-      $.aggregate_function,
-      $.column_name,
-      seq('(', $.column_name, ')')  // Allow parenthesized column names
+      '*',
+      seq($.expression, optional(seq(kw('AS'), $.alias)))
     ),
-    
-    aggregate_function: $ => choice(
-      seq(kw('COUNT'), '(', '*', ')'),
-      seq(kw('COUNT'), '(', $.column_name, ')')
+
+    alias: $ => $._identifier,
+
+    // aggregates (COUNT, SUM, ...) and scalar functions (UPPER, ...) share one rule
+    function_call: $ => seq(
+      $.function_name,
+      '(',
+      optional(choice(
+        '*',
+        seq($.expression, repeat(seq(',', $.expression)))
+      )),
+      ')'
+    ),
+
+    // any identifier followed by '(' - the parser and binder check the name
+    function_name: $ => $._identifier,
+
+    // bare, or quoted to allow spaces and other characters: "first name", [first name], `first name`
+    // optionally qualified by the source name: data.name
+    column_name: $ => seq(
+      optional(seq($.table_qualifier, '.')),
+      choice(
+        $._identifier,
+        seq('"', /[^"]*/, '"'),
+        seq('[', /[^\]]*/, ']'),
+        seq('`', /[^`]*/, '`')
+      )
     ),
 
-    column_name: $ => $._identifier,
+    table_qualifier: $ => $._identifier,
 
     file_name: $ => choice(
       $._identifier,
-      $.string_literal
+      $.string_literal,
+      seq('"', /[^"]*/, '"')
     ),
 
     where_clause: $ => seq(
@@ -57,14 +109,55 @@ module.exports = grammar({
       $.expression
     ),
 
+    group_by_clause: $ => seq(
+      kw('GROUP'),
+      kw('BY'),
+      $.column_name,
+      repeat(seq(',', $.column_name))
+    ),
+
+    having_clause: $ => seq(
+      kw('HAVING'),
+      $.expression
+    ),
+
+    // keys are expressions, output aliases or 1-based SELECT list positions
+    order_by_clause: $ => seq(
+      kw('ORDER'),
+      kw('BY'),
+      $.order_by_item,
+      repeat(seq(',', $.order_by_item))
+    ),
+
+    order_by_item: $ => seq($.expression, optional($.sort_direction)),
+
+    sort_direction: $ => choice(kw('ASC'), kw('DESC')),
+
+    // SAMPLE n: at most n rows of the file, taken before WHERE
+    sample_clause: $ => seq(
+      kw('SAMPLE'),
+      $.number_literal
+    ),
+
+    // LIMIT ALL is the same as no LIMIT
     limit_clause: $ => seq(
       kw('LIMIT'),
-      $.number_literal
+      choice($.number_literal, kw('ALL'))
     ),
 
     offset_clause: $ => seq(
       kw('OFFSET'),
-      $.number_literal
+      $.number_literal,
+      optional(choice(kw('ROWS'), kw('ROW')))
+    ),
+
+    // standard SQL spelling of LIMIT: FETCH FIRST n ROWS ONLY
+    fetch_clause: $ => seq(
+      kw('FETCH'),
+      choice(kw('FIRST'), kw('NEXT')),
+      $.number_literal,
+      choice(kw('ROWS'), kw('ROW')),
+      kw('ONLY')
     ),
 
     expression: $ => $.or_expression,
@@ -86,6 +179,11 @@ module.exports = grammar({
 
     primary_expression: $ => choice(
       $.comparison_expression,
+      $.arithmetic_expression,
+      $.concat_expression,
+      $.cast_expression,
+      $.case_expression,
+      $.function_call,
       $.column_name,
       $.literal,
       seq('(', $.expression, ')')
@@ -98,22 +196,83 @@ module.exports = grammar({
       prec.left(3, seq($.primary_expression, '>', $.primary_expression)),
       prec.left(3, seq($.primary_expression, '>=', $.primary_expression)),
       prec.left(3, seq($.primary_expression, '<', $.primary_expression)),
-      prec.left(3, seq($.primary_expression, '<=', $.primary_expression))
+      prec.left(3, seq($.primary_expression, '<=', $.primary_expression)),
+      prec.left(3, seq($.primary_expression, kw('LIKE'), $.primary_expression, optional($.like_escape))),
+      prec.left(3, seq($.primary_expression, kw('NOT'), kw('LIKE'), $.primary_expression, optional($.like_escape))),
+      prec.left(3, seq($.primary_expression, kw('ILIKE'), $.primary_expression, optional($.like_escape))),
+      prec.left(3, seq($.primary_expression, kw('NOT'), kw('ILIKE'), $.primary_expression, optional($.like_escape))),
+      prec.left(3, seq($.primary_expression, kw('IN'), $.in_list)),
+      prec.left(3, seq($.primary_expression, kw('NOT'), kw('IN'), $.in_list)),
+      prec.left(3, seq($.primary_expression, kw('IS'), optional(kw('NOT')), $.boolean_literal))
+    ),
+
+    // LIKE '50!%' ESCAPE '!': the escape character makes the next % or _ literal
+    like_escape: $ => seq(kw('ESCAPE'), $.string_literal),
+
+    arithmetic_expression: $ => choice(
+      prec.left(4, seq($.primary_expression, '+', $.primary_expression)),
+      prec.left(4, seq($.primary_expression, '-', $.primary_expression)),
+      prec.left(5, seq($.primary_expression, '*', $.primary_expression)),
+      prec.left(5, seq($.primary_expression, '/', $.primary_expression)),
+      prec.left(5, seq($.primary_expression, '%', $.primary_expression)),
+      prec(6, seq('-', $.primary_expression))  // unary negation
+    ),
+
+    // explicit conversion: CAST(zip AS INTEGER)
+    cast_expression: $ => seq(
+      kw('CAST'),
+      '(',
+      $.expression,
+      kw('AS'),
+      $.type_name,
+      ')'
+    ),
+
+    // INTEGER, FLOAT, VARCHAR, BOOLEAN and their aliases - the parser checks the name
+    type_name: $ => $._identifier,
+
+    // conditional value: CASE WHEN age >= 18 THEN 'adult' ELSE 'minor' END
+    case_expression: $ => seq(
+      kw('CASE'),
+      repeat1($.when_clause),
+      optional($.else_clause),
+      kw('END')
+    ),
+
+    when_clause: $ => seq(kw('WHEN'), $.expression, kw('THEN'), $.expression),
+
+    else_clause: $ => seq(kw('ELSE'), $.expression),
+
+    // string concatenation, at the same level as + and -
+    concat_expression: $ => prec.left(4, seq($.primary_expression, '||', $.primary_expression)),
+
+    in_list: $ => seq(
+      '(',
+      $.literal,
+      repeat(seq(',', $.literal)),
+      ')'
     ),
 
     literal: $ => choice(
+      $.date_literal,
+      $.timestamp_literal,
       $.string_literal,
       $.number_literal,
       $.boolean_literal,
       kw('NULL')
     ),
 
-    string_literal: $ => choice(
-      seq("'", /[^']*/, "'"),
-      seq('"', /[^"]*/, '"')
-    ),
+    // double quotes delimit identifiers, not strings
+    string_literal: $ => seq("'", /[^']*/, "'"),
+
+    // DATE '2024-01-15'. the keyword and the opening quote are one token, so
+    // a column named date is still a column
+    date_literal: $ => seq(kw("DATE\\s*'"), /[^']*/, "'"),
+
+    // TIMESTAMP '2024-01-15 13:45:00', tokenized the same way
+    timestamp_literal: $ => seq(kw("TIMESTAMP\\s*'"), /[^']*/, "'"),
 
-    number_literal: $ => /-?\d+(\.\d+)?/,
+    number_literal: $ => /-?\d+(\.\d+)?([eE][+-]?\d+)?/,
 
     boolean_literal: $ => choice(kw('true'), kw('false')),
 
@@ -1,31 +1,204 @@
-use crate::parser::{AggregateFunction, Expression, LiteralValue, Query, SelectColumn};
-use std::fs;
+use crate::date::{parse_date, parse_timestamp};
+use crate::parser::{
+    AggregateFunction, Expression, LiteralValue, OrderBy, Query, SelectClause, SelectColumn,
+    UnionClause, sql_type_name,
+};
+use crate::source::{CsvFileSource, DataSource, StringSource};
+use std::fmt;
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BinderError {
+    pub kind: BinderErrorKind,
     pub message: String,
 }
 
+/// what a BinderError is about, for callers that handle some failures
+/// (a missing file, say) differently from others
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinderErrorKind {
+    FileNotFound,     // no file at the path, or no file matches the pattern
+    PermissionDenied, // the file exists but can't be read
+    Io,               // any other failure reading the file
+    EmptyFile,        // the file has no header or data to take a schema from
+    MalformedFile,    // the file's contents can't be read as one table
+    Query,            // the query doesn't fit the files: unknown columns, type errors, ...
+}
+
+impl BinderErrorKind {
+    /// the kind of an error raised while reading a file
+    fn of_io(error: &std::io::Error) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::NotFound => BinderErrorKind::FileNotFound,
+            std::io::ErrorKind::PermissionDenied => BinderErrorKind::PermissionDenied,
+            _ => BinderErrorKind::Io,
+        }
+    }
+}
+
+impl fmt::Display for BinderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for BinderError {}
+
 pub type BindResult<T> = Result<T, BinderError>;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BoundQuery {
     pub select_columns: Vec<Column>, // validated and bound columns
     pub file_path: PathBuf,
+    pub union_files: Vec<PathBuf>, // further FROM files, read after file_path (same schema)
     pub schema: Schema,
     pub where_clause: Option<BoundExpression>, // bound expression instead of raw
+    pub group_by: Vec<Column>,                 // GROUP BY columns (empty if none)
+    // HAVING and output are bound against the aggregate output:
+    // the group columns first, then one column per aggregate
+    pub having: Option<BoundExpression>,
+    pub output: Vec<BoundExpression>, // SELECT list as expressions, empty for ungrouped aggregates
+    pub aliases: Vec<Option<String>>, // AS names for output (None keeps the default name)
+    pub order_by: Vec<BoundOrderBy>,  // sort keys over the input of the output projection
+    pub sample: Option<usize>,        // SAMPLE n: rows taken from the file before WHERE
     pub limit: Option<usize>,
     pub offset: Option<usize>,
     pub aggregates: Vec<BoundAggregateExpression>, // aggregate functions in SELECT
+    pub explain: bool,                             // describe the plan instead of running it
+    pub analyze: bool,                             // EXPLAIN ANALYZE: run it and report metrics
+    pub has_header: bool,                          // the file's first line is a header
+    pub format: SourceFormat,                      // how the file is read
+    pub empty_as_null: bool,                       // empty Varchar cells read as NULL
+    pub null_tokens: Vec<String>,                  // cell texts read as NULL (NA, \N, ...)
+    pub source: Option<Arc<dyn DataSource>>,       // read instead of file_path (see bind_source)
+    // SELECTs combined with this one; order_by, limit and offset then apply
+    // to the combined rows, and the sort keys index their columns
+    pub unions: Vec<BoundUnion>,
+}
+
+/// a bound `UNION [ALL] SELECT ...`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundUnion {
+    pub all: bool, // UNION ALL keeps duplicate rows
+    pub query: BoundQuery,
+}
+
+impl BoundQuery {
+    /// names and types of the columns this SELECT outputs (before any UNION)
+    pub fn output_schema(&self) -> Schema {
+        // only aggregates, unrenamed: the aggregate's own columns
+        if self.output.is_empty() {
+            return Schema::from_columns(
+                self.aggregates
+                    .iter()
+                    .map(|aggregate| (aggregate.to_string(), aggregate.result_type())),
+            );
+        }
+        // named like the output projection names its columns
+        let aliases = self.aliases.iter().chain(std::iter::repeat(&None));
+        Schema::from_columns(self.output.iter().zip(aliases).map(|(expr, alias)| {
+            match (expr, alias) {
+                (_, Some(alias)) => (alias.clone(), expr.result_type()),
+                (BoundExpression::ColumnRef { name, type_, .. }, None) => {
+                    (name.clone(), type_.clone())
+                }
+                (_, None) => (expr.to_string(), expr.result_type()),
+            }
+        }))
+    }
+
+    /// every file the query reads, the UNIONed SELECTs' included
+    pub fn files(&self) -> Vec<&PathBuf> {
+        let mut files: Vec<&PathBuf> = std::iter::once(&self.file_path)
+            .chain(&self.union_files)
+            .collect();
+        for union in &self.unions {
+            files.extend(union.query.files());
+        }
+        files
+    }
+}
+
+/// ORDER BY key bound against the rows the output is projected from
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundOrderBy {
+    pub expression: BoundExpression,
+    pub descending: bool,
+}
+
+impl fmt::Display for BoundOrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.descending {
+            write!(f, "{} DESC", self.expression)
+        } else {
+            write!(f, "{}", self.expression)
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum BoundAggregateExpression {
     CountStar,
     Count {
-        column: Column, // column to count non-NULL values
+        expression: BoundExpression, // counts non-NULL values
+    },
+    Sum {
+        expression: BoundExpression,
+    },
+    Min {
+        expression: BoundExpression,
+    },
+    Max {
+        expression: BoundExpression,
     },
+    Avg {
+        expression: BoundExpression,
+    },
+}
+
+impl BoundAggregateExpression {
+    /// the argument expression (None for COUNT(*))
+    pub fn expression(&self) -> Option<&BoundExpression> {
+        match self {
+            BoundAggregateExpression::CountStar => None,
+            BoundAggregateExpression::Count { expression }
+            | BoundAggregateExpression::Sum { expression }
+            | BoundAggregateExpression::Min { expression }
+            | BoundAggregateExpression::Max { expression }
+            | BoundAggregateExpression::Avg { expression } => Some(expression),
+        }
+    }
+
+    /// type of the aggregate's result column
+    pub fn result_type(&self) -> ColumnType {
+        match self {
+            BoundAggregateExpression::CountStar | BoundAggregateExpression::Count { .. } => {
+                ColumnType::Integer
+            }
+            BoundAggregateExpression::Sum { expression } => match expression.result_type() {
+                ColumnType::Float => ColumnType::Float,
+                _ => ColumnType::Integer,
+            },
+            BoundAggregateExpression::Min { expression }
+            | BoundAggregateExpression::Max { expression } => expression.result_type(),
+            BoundAggregateExpression::Avg { .. } => ColumnType::Float,
+        }
+    }
+}
+
+impl fmt::Display for BoundAggregateExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoundAggregateExpression::CountStar => write!(f, "COUNT(*)"),
+            BoundAggregateExpression::Count { expression } => write!(f, "COUNT({})", expression),
+            BoundAggregateExpression::Sum { expression } => write!(f, "SUM({})", expression),
+            BoundAggregateExpression::Min { expression } => write!(f, "MIN({})", expression),
+            BoundAggregateExpression::Max { expression } => write!(f, "MAX({})", expression),
+            BoundAggregateExpression::Avg { expression } => write!(f, "AVG({})", expression),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -33,6 +206,26 @@ pub struct Schema {
     pub columns: Vec<Column>,
 }
 
+impl Schema {
+    /// build a schema from (name, type) pairs, numbering the columns in order
+    pub fn from_columns(columns: impl IntoIterator<Item = (String, ColumnType)>) -> Self {
+        let columns = columns
+            .into_iter()
+            .enumerate()
+            .map(|(index, (name, type_))| Column { name, type_, index })
+            .collect();
+        Self { columns }
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.columns.iter().map(|col| col.name.clone()).collect()
+    }
+
+    pub fn types(&self) -> Vec<ColumnType> {
+        self.columns.iter().map(|col| col.type_.clone()).collect()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Column {
     pub name: String,
@@ -40,13 +233,118 @@ pub struct Column {
     pub index: usize,
 }
 
+/// whether the first line of a CSV file holds column names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeaderMode {
+    /// the first line is always a header (default)
+    #[default]
+    Always,
+    /// there is no header; columns are named column1, column2, ...
+    Never,
+    /// treat the first line as a header when its values don't fit the
+    /// types inferred from the rows below it
+    Auto,
+}
+
+/// file format of a query's source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SourceFormat {
+    /// comma-separated values (default)
+    #[default]
+    Csv,
+    /// newline-delimited JSON, one object per line
+    JsonLines,
+}
+
+impl SourceFormat {
+    /// format implied by a file's extension (.jsonl / .ndjson, optionally
+    /// gzipped); anything else is read as CSV
+    pub fn from_path(path: &Path) -> Self {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        let name = name.strip_suffix(".gz").unwrap_or(&name);
+        if name.ends_with(".jsonl") || name.ends_with(".ndjson") {
+            SourceFormat::JsonLines
+        } else {
+            SourceFormat::Csv
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ColumnType {
     Integer,
     Float,
     Boolean,
     Varchar,
-    Null, // if column is all NULL
+    Date,      // YYYY-MM-DD, stored as days since 1970-01-01
+    Timestamp, // YYYY-MM-DD HH:MM:SS, stored as microseconds since 1970-01-01
+    Null,      // if column is all NULL
+}
+
+/// scalar functions that can be called in expressions (one value per row)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarFunction {
+    Upper,    // UPPER(varchar) -> varchar
+    Lower,    // LOWER(varchar) -> varchar
+    Length,   // LENGTH(varchar) -> integer, counted in characters
+    Substr,   // SUBSTR(varchar, start [, length]) -> varchar, 1-based
+    Trim,     // TRIM(varchar) -> varchar without leading/trailing whitespace
+    Coalesce, // COALESCE(a, b, ...) -> first non-NULL argument, in their common type
+}
+
+impl ScalarFunction {
+    /// every supported function, in the order they are listed in errors
+    pub const ALL: [ScalarFunction; 6] = [
+        ScalarFunction::Upper,
+        ScalarFunction::Lower,
+        ScalarFunction::Length,
+        ScalarFunction::Substr,
+        ScalarFunction::Trim,
+        ScalarFunction::Coalesce,
+    ];
+
+    /// look a function up by name, ignoring case
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|function| function.name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScalarFunction::Upper => "UPPER",
+            ScalarFunction::Lower => "LOWER",
+            ScalarFunction::Length => "LENGTH",
+            ScalarFunction::Substr => "SUBSTR",
+            ScalarFunction::Trim => "TRIM",
+            ScalarFunction::Coalesce => "COALESCE",
+        }
+    }
+
+    /// type of the value the function returns for (binder-checked) argument types
+    pub fn result_type(&self, arg_types: &[ColumnType]) -> ColumnType {
+        match self {
+            ScalarFunction::Upper
+            | ScalarFunction::Lower
+            | ScalarFunction::Substr
+            | ScalarFunction::Trim => ColumnType::Varchar,
+            ScalarFunction::Length => ColumnType::Integer,
+            ScalarFunction::Coalesce => common_type(arg_types.iter()),
+        }
+    }
+}
+
+/// shared type of binder-checked values (COALESCE arguments, CASE results):
+/// NULL doesn't count and Integer mixed with Float is Float
+fn common_type<'a>(types: impl Iterator<Item = &'a ColumnType>) -> ColumnType {
+    types.fold(ColumnType::Null, |common, other| match (common, other) {
+        (ColumnType::Null, other) => other.clone(),
+        (ColumnType::Integer, ColumnType::Float) => ColumnType::Float,
+        (common, _) => common,
+    })
 }
 
 /// bound expression with metadata attached (column indices, types, etc.)
@@ -77,54 +375,1199 @@ pub enum BoundExpression {
     GreaterThanOrEqual(Box<BoundExpression>, Box<BoundExpression>),
     LessThan(Box<BoundExpression>, Box<BoundExpression>),
     LessThanOrEqual(Box<BoundExpression>, Box<BoundExpression>),
+
+    // pattern matching (returns Boolean), with an optional ESCAPE character
+    Like(Box<BoundExpression>, Box<BoundExpression>, Option<char>),
+
+    // membership in a literal list (returns Boolean)
+    In(Box<BoundExpression>, Vec<BoundExpression>),
+
+    // truth tests on a Boolean operand (never NULL)
+    IsTrue(Box<BoundExpression>),
+    IsFalse(Box<BoundExpression>),
+
+    // arithmetic operators (Integer if both sides are Integer, else Float)
+    Add(Box<BoundExpression>, Box<BoundExpression>),
+    Subtract(Box<BoundExpression>, Box<BoundExpression>),
+    Multiply(Box<BoundExpression>, Box<BoundExpression>),
+    Divide(Box<BoundExpression>, Box<BoundExpression>),
+
+    // integer remainder (both sides Integer, returns Integer; x % 0 is NULL)
+    Modulo(Box<BoundExpression>, Box<BoundExpression>),
+
+    // unary minus (returns the operand's type)
+    Negate(Box<BoundExpression>),
+
+    // string concatenation (returns Varchar; other types use their text form)
+    Concat(Box<BoundExpression>, Box<BoundExpression>),
+
+    // explicit conversion to target; values that don't convert become NULL
+    Cast {
+        expr: Box<BoundExpression>,
+        target: ColumnType,
+    },
+
+    // scalar function call with checked argument count and types
+    FunctionCall {
+        function: ScalarFunction,
+        args: Vec<BoundExpression>,
+    },
+
+    // first branch whose Boolean condition is true gives the value, else
+    // else_ (NULL without one); results share a common type
+    Case {
+        branches: Vec<(BoundExpression, BoundExpression)>,
+        else_: Option<Box<BoundExpression>>,
+    },
 }
 
-pub struct Binder;
+/// SQL-like text form, same layout as the parser's Expression (used by EXPLAIN)
+impl fmt::Display for BoundExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // wrap compound operands so the text keeps the tree's grouping
+        let operand = |e: &BoundExpression| match e {
+            BoundExpression::ColumnRef { .. }
+            | BoundExpression::Literal { .. }
+            | BoundExpression::FunctionCall { .. }
+            | BoundExpression::Cast { .. }
+            | BoundExpression::Case { .. } => e.to_string(),
+            _ => format!("({})", e),
+        };
+        let binary =
+            |f: &mut fmt::Formatter<'_>, l: &BoundExpression, op: &str, r: &BoundExpression| {
+                write!(f, "{} {} {}", operand(l), op, operand(r))
+            };
+
+        match self {
+            BoundExpression::Or(l, r) => binary(f, l, "OR", r),
+            BoundExpression::And(l, r) => binary(f, l, "AND", r),
+            BoundExpression::Not(inner) => write!(f, "NOT {}", operand(inner)),
+            BoundExpression::Equal(l, r) => binary(f, l, "=", r),
+            BoundExpression::NotEqual(l, r) => binary(f, l, "!=", r),
+            BoundExpression::GreaterThan(l, r) => binary(f, l, ">", r),
+            BoundExpression::GreaterThanOrEqual(l, r) => binary(f, l, ">=", r),
+            BoundExpression::LessThan(l, r) => binary(f, l, "<", r),
+            BoundExpression::LessThanOrEqual(l, r) => binary(f, l, "<=", r),
+            BoundExpression::Like(l, r, escape) => {
+                binary(f, l, "LIKE", r)?;
+                match escape {
+                    Some(escape) => write!(f, " ESCAPE '{}'", escape),
+                    None => Ok(()),
+                }
+            }
+            BoundExpression::IsTrue(inner) => write!(f, "{} IS TRUE", operand(inner)),
+            BoundExpression::IsFalse(inner) => write!(f, "{} IS FALSE", operand(inner)),
+            BoundExpression::In(l, list) => {
+                let items: Vec<String> = list.iter().map(|item| item.to_string()).collect();
+                write!(f, "{} IN ({})", operand(l), items.join(", "))
+            }
+            BoundExpression::Add(l, r) => binary(f, l, "+", r),
+            BoundExpression::Subtract(l, r) => binary(f, l, "-", r),
+            BoundExpression::Multiply(l, r) => binary(f, l, "*", r),
+            BoundExpression::Divide(l, r) => binary(f, l, "/", r),
+            BoundExpression::Modulo(l, r) => binary(f, l, "%", r),
+            BoundExpression::Negate(inner) => write!(f, "-{}", operand(inner)),
+            BoundExpression::Concat(l, r) => match l.as_ref() {
+                // chains read left to right without nested parentheses: a || b || c
+                BoundExpression::Concat(..) => write!(f, "{} || {}", l, operand(r)),
+                _ => binary(f, l, "||", r),
+            },
+            BoundExpression::FunctionCall { function, args } => {
+                let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+                write!(f, "{}({})", function.name(), args.join(", "))
+            }
+            BoundExpression::Cast { expr, target } => {
+                write!(f, "CAST({} AS {})", expr, sql_type_name(target))
+            }
+            BoundExpression::Case { branches, else_ } => {
+                write!(f, "CASE")?;
+                for (condition, result) in branches {
+                    write!(f, " WHEN {} THEN {}", condition, result)?;
+                }
+                if let Some(else_) = else_ {
+                    write!(f, " ELSE {}", else_)?;
+                }
+                write!(f, " END")
+            }
+            BoundExpression::ColumnRef { name, .. } => {
+                write!(f, "{}", Expression::Column(name.clone()))
+            }
+            BoundExpression::Literal { value, .. } => {
+                write!(f, "{}", Expression::Literal(value.clone()))
+            }
+        }
+    }
+}
+
+impl BoundExpression {
+    /// type of the value this expression evaluates to
+    pub fn result_type(&self) -> ColumnType {
+        match self {
+            BoundExpression::ColumnRef { type_, .. } | BoundExpression::Literal { type_, .. } => {
+                type_.clone()
+            }
+            BoundExpression::Add(left, right)
+            | BoundExpression::Subtract(left, right)
+            | BoundExpression::Multiply(left, right)
+            | BoundExpression::Divide(left, right) => {
+                match (left.result_type(), right.result_type()) {
+                    (ColumnType::Integer, ColumnType::Integer) => ColumnType::Integer,
+                    (ColumnType::Null, ColumnType::Null) => ColumnType::Null,
+                    _ => ColumnType::Float,
+                }
+            }
+            BoundExpression::Modulo(left, right) => {
+                match (left.result_type(), right.result_type()) {
+                    (ColumnType::Null, ColumnType::Null) => ColumnType::Null,
+                    _ => ColumnType::Integer,
+                }
+            }
+            BoundExpression::Negate(inner) => inner.result_type(),
+            BoundExpression::Concat(..) => ColumnType::Varchar,
+            BoundExpression::FunctionCall { function, args } => {
+                let arg_types: Vec<ColumnType> = args.iter().map(|arg| arg.result_type()).collect();
+                function.result_type(&arg_types)
+            }
+            BoundExpression::Cast { target, .. } => target.clone(),
+            BoundExpression::Case { branches, else_ } => {
+                let results: Vec<ColumnType> = branches
+                    .iter()
+                    .map(|(_, result)| result)
+                    .chain(else_.as_deref())
+                    .map(|result| result.result_type())
+                    .collect();
+                common_type(results.iter())
+            }
+            _ => ColumnType::Boolean,
+        }
+    }
+}
+
+pub struct Binder {
+    boolean_header_hint: bool, // only infer Boolean when the header name suggests it
+    case_insensitive_columns: bool, // resolve unquoted column references ignoring case
+    strict: bool,              // reject sample rows whose field count differs from the header
+    rename_duplicate_headers: bool, // suffix repeated header names instead of failing
+    header: HeaderMode,        // whether the file starts with a header line
+    format: Option<SourceFormat>, // None: pick by file extension
+    strict_types: bool,        // only identical types can be compared
+    dedupe_columns: bool,      // list each column once in the SELECT output
+    empty_as_null: bool,       // empty Varchar cells are NULL rather than ""
+    null_tokens: Vec<String>,  // cell texts that are NULL, matched ignoring case
+    sample_size: usize,        // rows read to infer types, 0 for all of them
+}
+
+/// rows sampled for type inference unless Binder::with_sample_size says otherwise
+pub const DEFAULT_SAMPLE_SIZE: usize = 20;
 
 impl Binder {
     pub fn new() -> Self {
-        Self
+        Self {
+            boolean_header_hint: false,
+            case_insensitive_columns: true,
+            strict: false,
+            rename_duplicate_headers: false,
+            header: HeaderMode::Always,
+            format: None,
+            strict_types: false,
+            dedupe_columns: false,
+            empty_as_null: true,
+            null_tokens: vec!["null".to_string()],
+            sample_size: DEFAULT_SAMPLE_SIZE,
+        }
+    }
+
+    /// only infer Boolean for true/false columns whose header looks boolean
+    /// (is_*, has_*, active, enabled, ...). other true/false columns become Varchar.
+    pub fn with_boolean_header_hint(mut self, enabled: bool) -> Self {
+        self.boolean_header_hint = enabled;
+        self
+    }
+
+    /// resolve column references case-insensitively (`SELECT ID` finds header `id`).
+    /// on by default; pass false to require exact-case column names
+    pub fn with_case_insensitive_columns(mut self, enabled: bool) -> Self {
+        self.case_insensitive_columns = enabled;
+        self
+    }
+
+    /// fail binding when a sampled row has more or fewer fields than the header
+    /// (by default short rows are NULL-padded and extra fields are ignored)
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// rename repeated header names (`id,name,id` reads as `id,name,id_2`)
+    /// instead of rejecting the file
+    pub fn with_rename_duplicate_headers(mut self, enabled: bool) -> Self {
+        self.rename_duplicate_headers = enabled;
+        self
+    }
+
+    /// whether files have a header line (HeaderMode::Auto detects it per file)
+    pub fn with_header(mut self, header: HeaderMode) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// read every file in this format instead of going by its extension
+    pub fn with_format(mut self, format: SourceFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// only compare values of identical types: `age = 18.5` (Integer vs Float)
+    /// and comparisons with NULL become binding errors
+    pub fn with_strict_types(mut self, enabled: bool) -> Self {
+        self.strict_types = enabled;
+        self
+    }
+
+    /// drop repeated columns from the SELECT list, keeping the first occurrence
+    /// (`SELECT *, name` lists name once, in its header position)
+    pub fn with_dedupe_columns(mut self, enabled: bool) -> Self {
+        self.dedupe_columns = enabled;
+        self
+    }
+
+    /// read empty cells in Varchar columns as NULL (the default) or, when
+    /// disabled, as empty strings. a column with only empty cells is then
+    /// Varchar instead of Null
+    pub fn with_empty_as_null(mut self, enabled: bool) -> Self {
+        self.empty_as_null = enabled;
+        self
+    }
+
+    /// cell texts that mean NULL, matched case-insensitively (`null` by default).
+    /// `NA` or `\N` cells then don't make a numeric column Varchar and are read
+    /// as NULL. empty cells are NULL regardless (see with_empty_as_null)
+    pub fn with_null_tokens(mut self, tokens: Vec<String>) -> Self {
+        self.null_tokens = tokens;
+        self
+    }
+
+    /// infer column types from the first `rows` data rows (20 by default), or
+    /// from every row with 0. a larger sample catches a late Float or text value
+    /// in an otherwise Integer column at the cost of reading more of the file
+    pub fn with_sample_size(mut self, rows: usize) -> Self {
+        self.sample_size = rows;
+        self
+    }
+
+    /// an empty cell or a NULL token
+    fn is_null_text(&self, value: &str) -> bool {
+        value.is_empty()
+            || self
+                .null_tokens
+                .iter()
+                .any(|token| token.eq_ignore_ascii_case(value))
+    }
+
+    /// number of rows to sample: usize::MAX when every row is read
+    fn sample_limit(&self) -> usize {
+        match self.sample_size {
+            0 => usize::MAX,
+            rows => rows,
+        }
     }
 
     /// binds a parsed Query to create a BoundQuery with all metadata attached.
     /// this performs validation and binding in one step.
     pub fn bind(&self, query: Query) -> BindResult<BoundQuery> {
-        // step 1: Resolve file name
-        let file_path = self.resolve_file_name(&query.from.file)?;
+        self.bind_union(query, &|query| self.bind_files(query))
+    }
+
+    /// binds one SELECT against the files it names
+    fn bind_files(&self, query: Query) -> BindResult<BoundQuery> {
+        // step 1: Resolve file names (expanding glob patterns)
+        let mut files = query
+            .from
+            .files
+            .iter()
+            .map(|file| self.resolve_file_names(file))
+            .collect::<BindResult<Vec<_>>>()?
+            .concat();
+        let format = self
+            .format
+            .unwrap_or_else(|| SourceFormat::from_path(&files[0]));
+
+        // steps 2 and 3: read the column names and infer their types
+        let (schema, has_header) = self.read_files_schema(&files, format)?;
+        let file_path = files.remove(0);
+
+        let mut bound = self.bind_query(query, file_path, schema, has_header, format)?;
+        bound.union_files = files;
+        Ok(bound)
+    }
+
+    /// column names and inferred types of a file (or of the files a glob
+    /// pattern names), without binding a query
+    pub fn describe(&self, file_name: &str) -> BindResult<Schema> {
+        let files = self.resolve_file_names(file_name)?;
+        let format = self
+            .format
+            .unwrap_or_else(|| SourceFormat::from_path(&files[0]));
+        let (schema, _) = self.read_files_schema(&files, format)?;
+        Ok(schema)
+    }
+
+    /// schema of files read as one table: the first file's, checked against
+    /// (and widened by) every further file
+    fn read_files_schema(
+        &self,
+        files: &[PathBuf],
+        format: SourceFormat,
+    ) -> BindResult<(Schema, bool)> {
+        let (mut schema, has_header) = self.read_schema(&CsvFileSource::new(&files[0]), format)?;
+        for other in &files[1..] {
+            let (other_schema, _) = self.read_schema(&CsvFileSource::new(other), format)?;
+            self.merge_union_schema(&mut schema, &other_schema, &files[0], other)?;
+        }
+        Ok((schema, has_header))
+    }
 
-        // step 2: Read headers (assume has_header=true for now)
-        let mut schema = self.read_csv_headers(&file_path)?;
+    /// column names and types of one source file, and whether it has a header line
+    fn read_schema(
+        &self,
+        source: &dyn DataSource,
+        format: SourceFormat,
+    ) -> BindResult<(Schema, bool)> {
+        let content = self.read_sample(source)?;
+        self.read_schema_from_str(&content, format)
+    }
 
-        // step 3: Infer types
-        self.infer_column_types(&file_path, &mut schema, true)?;
+    /// column names and types of a source's text, and whether it has a header line
+    fn read_schema_from_str(
+        &self,
+        content: &str,
+        format: SourceFormat,
+    ) -> BindResult<(Schema, bool)> {
+        if format == SourceFormat::JsonLines {
+            // JSON lines carry their own keys and value types
+            return Ok((self.read_json_lines_schema_from_str(content)?, false));
+        }
+
+        let has_header = self.detect_header_from_str(content)?;
+        let mut schema = if has_header {
+            self.read_csv_headers_from_str(content)?
+        } else {
+            self.read_csv_without_headers_from_str(content)?
+        };
+        self.infer_column_types_from_str(content, &mut schema, has_header)?;
+        Ok((schema, has_header))
+    }
+
+    /// whole text of a (possibly gzip-compressed) file
+    fn read_file(file_path: &PathBuf) -> BindResult<String> {
+        Self::read_source(&CsvFileSource::new(file_path))
+    }
+
+    fn read_source(source: &dyn DataSource) -> BindResult<String> {
+        source.read_to_string().map_err(Self::read_error)
+    }
+
+    /// the start of a source's text: the header line and the type-inference
+    /// sample after it (blank lines aside), so binding a query doesn't read
+    /// the rest of a large file
+    fn read_sample(&self, source: &dyn DataSource) -> BindResult<String> {
+        let mut reader = BufReader::new(source.open().map_err(Self::read_error)?);
+        let mut content = String::new();
+        let mut lines = 0;
+        while lines <= self.sample_limit() {
+            let start = content.len();
+            if reader.read_line(&mut content).map_err(Self::read_error)? == 0 {
+                break;
+            }
+            if !content[start..].trim().is_empty() {
+                lines += 1;
+            }
+        }
+        Ok(content)
+    }
+
+    fn read_error(e: std::io::Error) -> BinderError {
+        BinderError {
+            kind: BinderErrorKind::of_io(&e),
+            message: format!("Failed to read file: {}", e),
+        }
+    }
+
+    /// check that a further FROM file has the same columns, in the same order, as the
+    /// first one. types are widened where the files disagree (Integer and Float read
+    /// as Float; an all-empty column takes the other file's type)
+    fn merge_union_schema(
+        &self,
+        schema: &mut Schema,
+        other: &Schema,
+        first_path: &Path,
+        other_path: &Path,
+    ) -> BindResult<()> {
+        if schema.names() != other.names() {
+            return Err(BinderError {
+                kind: BinderErrorKind::MalformedFile,
+                message: format!(
+                    "File '{}' does not have the same columns as '{}': expected [{}], found [{}]",
+                    other_path.display(),
+                    first_path.display(),
+                    schema.names().join(", "),
+                    other.names().join(", ")
+                ),
+            });
+        }
+
+        for (column, other_column) in schema.columns.iter_mut().zip(&other.columns) {
+            column.type_ = match (&column.type_, &other_column.type_) {
+                (left, right) if left == right => continue,
+                (ColumnType::Null, right) => right.clone(),
+                (_, ColumnType::Null) => continue,
+                (ColumnType::Integer, ColumnType::Float)
+                | (ColumnType::Float, ColumnType::Integer) => ColumnType::Float,
+                (left, right) => {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::MalformedFile,
+                        message: format!(
+                            "Column '{}' is {} in '{}' but {} in '{}'",
+                            column.name,
+                            self.type_to_string(left),
+                            first_path.display(),
+                            self.type_to_string(right),
+                            other_path.display()
+                        ),
+                    });
+                }
+            };
+        }
+        Ok(())
+    }
+
+    /// binds a parsed Query against a known schema without touching the filesystem.
+    /// useful for validating queries before the data exists.
+    /// file_path is left as the unresolved name from the FROM clause.
+    pub fn bind_with_schema(&self, query: Query, schema: Schema) -> BindResult<BoundQuery> {
+        self.bind_union(query, &|query| {
+            let mut files: Vec<PathBuf> = query.from.files.iter().map(PathBuf::from).collect();
+            let file_path = files.remove(0);
+            let format = SourceFormat::from_path(&file_path);
+            let mut bound = self.bind_query(query, file_path, schema.clone(), true, format)?;
+            bound.union_files = files;
+            Ok(bound)
+        })
+    }
+
+    /// binds a parsed Query against CSV text instead of a file, for tests and
+    /// embedding. the FROM clause must be the source's name (`FROM people`);
+    /// the text is kept in the BoundQuery and scanned from memory
+    pub fn bind_from_str(
+        &self,
+        query: Query,
+        csv_contents: &str,
+        name: &str,
+    ) -> BindResult<BoundQuery> {
+        self.bind_source(query, Arc::new(StringSource::new(name, csv_contents)))
+    }
+
+    /// binds a parsed Query against any data source; the FROM clause must be
+    /// the source's name. the scan reads its rows from the same source
+    pub fn bind_source(&self, query: Query, source: Arc<dyn DataSource>) -> BindResult<BoundQuery> {
+        self.bind_union(query, &|query| self.bind_one_source(query, source.clone()))
+    }
+
+    fn bind_one_source(&self, query: Query, source: Arc<dyn DataSource>) -> BindResult<BoundQuery> {
+        let name = source.name();
+        if query.from.files != [name.as_str()] {
+            return Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!(
+                    "FROM must name the source '{}', found '{}'",
+                    name,
+                    query.from.files.join(", ")
+                ),
+            });
+        }
+
+        let format = self.format.unwrap_or(SourceFormat::Csv);
+        let (schema, has_header) = self.read_schema(source.as_ref(), format)?;
+        let mut bound = self.bind_query(query, PathBuf::from(name), schema, has_header, format)?;
+        bound.source = Some(source);
+        Ok(bound)
+    }
+
+    /// binds a query and the SELECTs UNIONed with it, each with `bind_one`.
+    /// every SELECT must output as many columns as the first, of the same
+    /// types; ORDER BY names or numbers the combined columns
+    fn bind_union(
+        &self,
+        mut query: Query,
+        bind_one: &dyn Fn(Query) -> BindResult<BoundQuery>,
+    ) -> BindResult<BoundQuery> {
+        if query.unions.is_empty() {
+            return bind_one(query);
+        }
+        let unions = std::mem::take(&mut query.unions);
+        let order_by = std::mem::take(&mut query.order_by);
+
+        let mut bound = bind_one(query)?;
+        let mut schema = bound.output_schema();
+        for (position, UnionClause { all, query }) in unions.into_iter().enumerate() {
+            let branch = bind_one(query)?;
+            self.merge_branch_schema(&mut schema, &branch.output_schema(), position + 2)?;
+            bound.unions.push(BoundUnion { all, query: branch });
+        }
+
+        // the combined columns are named like the first SELECT's
+        let names: Vec<Option<String>> = schema.names().into_iter().map(Some).collect();
+        bound.order_by = order_by
+            .iter()
+            .map(|key| {
+                let Some(position) = self.find_output_column(&key.expression, &names)? else {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::Query,
+                        message: format!(
+                            "ORDER BY after a UNION must name or number an output column, found {}",
+                            key.expression
+                        ),
+                    });
+                };
+                let column = &schema.columns[position];
+                Ok(BoundOrderBy {
+                    expression: BoundExpression::ColumnRef {
+                        name: column.name.clone(),
+                        index: position,
+                        type_: column.type_.clone(),
+                    },
+                    descending: key.descending,
+                })
+            })
+            .collect::<BindResult<_>>()?;
+        Ok(bound)
+    }
+
+    /// check the output of the `position`th SELECT of a UNION against the
+    /// columns so far. an all-NULL column takes the other SELECT's type
+    fn merge_branch_schema(
+        &self,
+        schema: &mut Schema,
+        branch: &Schema,
+        position: usize,
+    ) -> BindResult<()> {
+        if branch.columns.len() != schema.columns.len() {
+            return Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!(
+                    "SELECT {} of the UNION has {} columns, expected {}",
+                    position,
+                    branch.columns.len(),
+                    schema.columns.len()
+                ),
+            });
+        }
+
+        for (column, other) in schema.columns.iter_mut().zip(&branch.columns) {
+            column.type_ = match (&column.type_, &other.type_) {
+                (left, right) if left == right => continue,
+                (ColumnType::Null, right) => right.clone(),
+                (_, ColumnType::Null) => continue,
+                (left, right) => {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::Query,
+                        message: format!(
+                            "Column {} of the UNION is {} in SELECT {} but {} before it",
+                            column.index + 1,
+                            self.type_to_string(right),
+                            position,
+                            self.type_to_string(left)
+                        ),
+                    });
+                }
+            };
+        }
+        Ok(())
+    }
+
+    /// binds SELECT and WHERE against a resolved schema
+    fn bind_query(
+        &self,
+        query: Query,
+        file_path: PathBuf,
+        schema: Schema,
+        has_header: bool,
+        format: SourceFormat,
+    ) -> BindResult<BoundQuery> {
+        // column qualifiers (data.name) must name the source
+        self.check_qualifiers(&query)?;
 
         // step 4: Validate and bind SELECT columns and aggregates
         let (select_columns, aggregates) =
             self.validate_select_columns(&query.select.columns, &schema)?;
 
         // step 5: Validate and bind WHERE clause (if present)
-        let where_clause = if let Some(where_clause) = query.where_clause {
+        let where_clause = if let Some(where_clause) = &query.where_clause {
             // validate first
+            Self::reject_where_aggregates(&where_clause.condition)?;
             self.validate_where_expression(&where_clause.condition, &schema)?;
+            self.validate_predicate(&where_clause.condition, &schema)?;
             // then bind
-            Some(self.bind_expression(&where_clause.condition, &schema)?)
+            Some(self.bind_predicate(&where_clause.condition, &schema)?)
         } else {
             None
         };
 
+        // step 6: Bind GROUP BY and HAVING (if present)
+        if query.group_by.is_empty() {
+            if query.having.is_some() {
+                return Err(BinderError {
+                    kind: BinderErrorKind::Query,
+                    message: "HAVING requires a GROUP BY clause".to_string(),
+                });
+            }
+
+            // without aggregates the SELECT list is projected row by row
+            let mut aggregates = aggregates;
+            let only_aggregates = query
+                .select
+                .columns
+                .iter()
+                .all(|col| matches!(col, SelectColumn::Aggregate(..)));
+            let (output, aliases, order_by) = if aggregates.is_empty() {
+                let (output, aliases) = self.bind_select_list(&query.select, &schema)?;
+                let order_by = self.bind_order_by(&query.order_by, &output, &aliases, &schema)?;
+                (output, aliases, order_by)
+            } else if only_aggregates {
+                // a single result row is already sorted; renamed aggregates get a
+                // projection over it
+                let (output, aliases) = self.rename_aggregates(&query.select, &aggregates);
+                (output, aliases, Vec::new())
+            } else {
+                // other columns beside the aggregates (`SELECT 'total', COUNT(*)`):
+                // bound like a grouped query with no groups, so only constants
+                // and aggregates are allowed
+                let aliases = self.expand_aliases(&query.select, &schema);
+                let (_, output, order_by) =
+                    self.bind_grouping(&query, &aliases, &[], &mut aggregates, &schema)?;
+                (output, aliases, order_by)
+            };
+
+            return Ok(BoundQuery {
+                select_columns,
+                file_path,
+                union_files: Vec::new(),
+                schema,
+                where_clause,
+                group_by: Vec::new(),
+                having: None,
+                output,
+                aliases,
+                order_by,
+                sample: query.sample,
+                limit: query.limit,
+                offset: query.offset,
+                aggregates,
+                explain: query.explain,
+                analyze: query.analyze,
+                has_header,
+                format,
+                empty_as_null: self.empty_as_null,
+                null_tokens: self.null_tokens.clone(),
+                source: None,
+                unions: Vec::new(),
+            });
+        }
+
+        let group_by = query
+            .group_by
+            .iter()
+            .map(|name| self.find_column(name, &schema).cloned())
+            .collect::<BindResult<Vec<_>>>()?;
+
+        let mut aggregates = aggregates;
+        let aliases = self.expand_aliases(&query.select, &schema);
+        let (having, output, order_by) =
+            self.bind_grouping(&query, &aliases, &group_by, &mut aggregates, &schema)?;
+
         Ok(BoundQuery {
             select_columns,
             file_path,
+            union_files: Vec::new(),
             schema,
             where_clause,
+            group_by,
+            having,
+            output,
+            aliases,
+            order_by,
+            sample: query.sample,
             limit: query.limit,
             offset: query.offset,
             aggregates,
+            explain: query.explain,
+            analyze: query.analyze,
+            has_header,
+            format,
+            empty_as_null: self.empty_as_null,
+            null_tokens: self.null_tokens.clone(),
+            source: None,
+            unions: Vec::new(),
         })
     }
 
+    /// binds the SELECT list of an ungrouped query without aggregates: columns
+    /// become column references and computed expressions are bound in place.
+    /// returns the output expressions with their AS names
+    fn bind_select_list(
+        &self,
+        select: &SelectClause,
+        schema: &Schema,
+    ) -> BindResult<(Vec<BoundExpression>, Vec<Option<String>>)> {
+        let column_ref = |col: &Column| BoundExpression::ColumnRef {
+            name: col.name.clone(),
+            index: col.index,
+            type_: col.type_.clone(),
+        };
+
+        let mut output = Vec::new();
+        for col in &select.columns {
+            match col {
+                SelectColumn::All => output.extend(schema.columns.iter().map(column_ref)),
+                SelectColumn::Expression(expression, _) => {
+                    self.validate_where_expression(expression, schema)?;
+                    output.push(self.bind_expression(expression, schema)?);
+                }
+                SelectColumn::Aggregate(..) => {}
+            }
+        }
+        let mut aliases = self.expand_aliases(select, schema);
+
+        // same rule as validate_select_columns: only plain columns are deduplicated
+        if self.dedupe_columns {
+            let mut seen = std::collections::HashSet::new();
+            let (kept, kept_aliases) = output
+                .into_iter()
+                .zip(aliases)
+                .filter(|(expression, _)| match expression {
+                    BoundExpression::ColumnRef { index, .. } => seen.insert(*index),
+                    _ => true,
+                })
+                .unzip();
+            output = kept;
+            aliases = kept_aliases;
+        }
+
+        Ok((output, aliases))
+    }
+
+    /// AS names lined up with the output columns (`*` expands to unnamed columns)
+    fn expand_aliases(&self, select: &SelectClause, schema: &Schema) -> Vec<Option<String>> {
+        select
+            .columns
+            .iter()
+            .flat_map(|col| match col {
+                SelectColumn::All => vec![None; schema.columns.len()],
+                _ => vec![col.alias().map(str::to_string)],
+            })
+            .collect()
+    }
+
+    /// output for an ungrouped aggregate query: empty unless an aggregate is
+    /// renamed, then one column reference per aggregate result
+    fn rename_aggregates(
+        &self,
+        select: &SelectClause,
+        aggregates: &[BoundAggregateExpression],
+    ) -> (Vec<BoundExpression>, Vec<Option<String>>) {
+        let aliases: Vec<Option<String>> = select
+            .columns
+            .iter()
+            .filter_map(|col| match col {
+                SelectColumn::Aggregate(_, alias) => Some(alias.clone()),
+                _ => None,
+            })
+            .collect();
+        if aliases.iter().all(Option::is_none) {
+            return (Vec::new(), Vec::new());
+        }
+
+        let output = aggregates
+            .iter()
+            .enumerate()
+            .map(|(index, aggregate)| BoundExpression::ColumnRef {
+                name: aggregate.to_string(),
+                index,
+                type_: aggregate.result_type(),
+            })
+            .collect();
+        (output, aliases)
+    }
+
+    /// binds ORDER BY keys against the rows the output is projected from.
+    /// positions and aliases pick an output expression; anything else is bound
+    /// against `schema`
+    fn bind_order_by(
+        &self,
+        order_by: &[OrderBy],
+        output: &[BoundExpression],
+        aliases: &[Option<String>],
+        schema: &Schema,
+    ) -> BindResult<Vec<BoundOrderBy>> {
+        order_by
+            .iter()
+            .map(|key| {
+                let expression = match self.find_output_column(&key.expression, aliases)? {
+                    Some(position) => output[position].clone(),
+                    None => {
+                        self.validate_where_expression(&key.expression, schema)?;
+                        self.bind_expression(&key.expression, schema)?
+                    }
+                };
+                Ok(BoundOrderBy {
+                    expression,
+                    descending: key.descending,
+                })
+            })
+            .collect()
+    }
+
+    /// output position an ORDER BY key refers to: `ORDER BY 2` is the second
+    /// column and a bare name matching an AS alias is that column
+    fn find_output_column(
+        &self,
+        key: &Expression,
+        aliases: &[Option<String>],
+    ) -> BindResult<Option<usize>> {
+        match key {
+            Expression::Literal(LiteralValue::Integer(position)) => {
+                if *position < 1 || *position as usize > aliases.len() {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::Query,
+                        message: format!(
+                            "ORDER BY position {} is not in the SELECT list (expected 1 to {})",
+                            position,
+                            aliases.len()
+                        ),
+                    });
+                }
+                Ok(Some(*position as usize - 1))
+            }
+            Expression::Column(name) => Ok(aliases.iter().position(|alias| {
+                alias
+                    .as_ref()
+                    .is_some_and(|alias| self.column_matches(alias, name))
+            })),
+            _ => Ok(None),
+        }
+    }
+
+    /// binds HAVING, the SELECT list and ORDER BY of a grouped query against the
+    /// aggregate output schema. aggregates only used by HAVING, ORDER BY or inside
+    /// SELECT expressions are appended to `aggregates` so the aggregate operator
+    /// computes them even though they are not selected on their own.
+    fn bind_grouping(
+        &self,
+        query: &Query,
+        aliases: &[Option<String>],
+        group_by: &[Column],
+        aggregates: &mut Vec<BoundAggregateExpression>,
+        schema: &Schema,
+    ) -> BindResult<(
+        Option<BoundExpression>,
+        Vec<BoundExpression>,
+        Vec<BoundOrderBy>,
+    )> {
+        let select_columns = &query.select.columns;
+        let having = query.having.as_ref();
+
+        // aggregate labels in output order; SELECT aggregates were bound in order
+        let mut labels: Vec<String> = select_columns
+            .iter()
+            .filter_map(|col| match col {
+                SelectColumn::Aggregate(function, _) => Some(function.to_string()),
+                _ => None,
+            })
+            .collect();
+
+        let mut functions = Vec::new();
+        for col in select_columns {
+            if let SelectColumn::Expression(expression, _) = col {
+                let mut columns = Vec::new();
+                Self::collect_references(expression, &mut functions, &mut columns);
+
+                if let Some(name) = columns.iter().find(|name| !self.is_grouped(name, group_by)) {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::Query,
+                        message: format!(
+                            "Column '{}' must appear in the GROUP BY clause or be used in an aggregate function",
+                            name
+                        ),
+                    });
+                }
+            }
+        }
+
+        if let Some(having) = having {
+            let mut columns = Vec::new();
+            Self::collect_references(having, &mut functions, &mut columns);
+
+            if let Some(name) = columns.iter().find(|name| !self.is_grouped(name, group_by)) {
+                return Err(BinderError {
+                    kind: BinderErrorKind::Query,
+                    message: format!(
+                        "Column '{}' in HAVING must appear in the GROUP BY clause or be used in an aggregate function",
+                        name
+                    ),
+                });
+            }
+        }
+
+        // ORDER BY keys that aren't output positions or aliases are sorted on
+        // like HAVING conditions: group columns and aggregates only
+        for key in &query.order_by {
+            if self.find_output_column(&key.expression, aliases)?.is_some() {
+                continue;
+            }
+            let mut columns = Vec::new();
+            Self::collect_references(&key.expression, &mut functions, &mut columns);
+
+            if let Some(name) = columns.iter().find(|name| !self.is_grouped(name, group_by)) {
+                return Err(BinderError {
+                    kind: BinderErrorKind::Query,
+                    message: format!(
+                        "Column '{}' in ORDER BY must appear in the GROUP BY clause or be used in an aggregate function",
+                        name
+                    ),
+                });
+            }
+        }
+
+        for function in functions {
+            let label = function.to_string();
+            if !labels.contains(&label) {
+                aggregates.push(self.bind_aggregate_function(function, schema)?);
+                labels.push(label);
+            }
+        }
+
+        // aggregate output: group columns, then aggregates named by their text
+        let group_columns = group_by.iter().enumerate().map(|(index, col)| Column {
+            name: col.name.clone(),
+            type_: col.type_.clone(),
+            index,
+        });
+        let aggregate_columns =
+            labels
+                .iter()
+                .zip(aggregates.iter())
+                .enumerate()
+                .map(|(i, (label, aggregate))| Column {
+                    name: label.clone(),
+                    type_: aggregate.result_type(),
+                    index: group_by.len() + i,
+                });
+        let output_schema = Schema {
+            columns: group_columns.chain(aggregate_columns).collect(),
+        };
+
+        let having = match having {
+            Some(expression) => {
+                self.validate_where_expression(expression, &output_schema)?;
+                self.validate_predicate(expression, &output_schema)?;
+                Some(self.bind_predicate(expression, &output_schema)?)
+            }
+            None => None,
+        };
+
+        let mut output = Vec::new();
+        for col in select_columns {
+            let names = match col {
+                SelectColumn::All => schema.columns.iter().map(|c| c.name.clone()).collect(),
+                SelectColumn::Aggregate(function, _) => vec![function.to_string()],
+                SelectColumn::Expression(expression, _) => {
+                    // columns were checked above; aggregates resolve to their output column
+                    self.validate_where_expression(expression, &output_schema)?;
+                    output.push(self.bind_expression(expression, &output_schema)?);
+                    continue;
+                }
+            };
+            for name in names {
+                if matches!(col, SelectColumn::All) && !self.is_grouped(&name, group_by) {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::Query,
+                        message: format!(
+                            "Column '{}' must appear in the GROUP BY clause or be used in an aggregate function",
+                            name
+                        ),
+                    });
+                }
+                output.push(self.bind_expression(&Expression::Column(name), &output_schema)?);
+            }
+        }
+
+        let order_by = self.bind_order_by(&query.order_by, &output, aliases, &output_schema)?;
+        Ok((having, output, order_by))
+    }
+
+    /// WHERE filters rows before grouping, so it can't use aggregates (HAVING can)
+    fn reject_where_aggregates(condition: &Expression) -> BindResult<()> {
+        let mut functions = Vec::new();
+        Self::collect_references(condition, &mut functions, &mut Vec::new());
+        if functions.is_empty() {
+            Ok(())
+        } else {
+            Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: "aggregate functions are not allowed in WHERE".to_string(),
+            })
+        }
+    }
+
+    /// collects aggregate calls and plain column references outside aggregates
+    fn collect_references<'a>(
+        expr: &'a Expression,
+        functions: &mut Vec<&'a AggregateFunction>,
+        columns: &mut Vec<&'a str>,
+    ) {
+        match expr {
+            Expression::Aggregate(function) => functions.push(function),
+            Expression::Column(name) => columns.push(name),
+            Expression::Literal(_) => {}
+            Expression::Not(inner)
+            | Expression::Negate(inner)
+            | Expression::IsTrue(inner)
+            | Expression::IsFalse(inner) => Self::collect_references(inner, functions, columns),
+            Expression::Cast { expr, .. } => Self::collect_references(expr, functions, columns),
+            Expression::Case { branches, else_ } => {
+                for (condition, result) in branches {
+                    Self::collect_references(condition, functions, columns);
+                    Self::collect_references(result, functions, columns);
+                }
+                if let Some(else_) = else_ {
+                    Self::collect_references(else_, functions, columns);
+                }
+            }
+            Expression::FunctionCall { args, .. } => {
+                for arg in args {
+                    Self::collect_references(arg, functions, columns);
+                }
+            }
+            Expression::In(left, list) => {
+                Self::collect_references(left, functions, columns);
+                for item in list {
+                    Self::collect_references(item, functions, columns);
+                }
+            }
+            Expression::Or(left, right)
+            | Expression::And(left, right)
+            | Expression::Equal(left, right)
+            | Expression::NotEqual(left, right)
+            | Expression::GreaterThan(left, right)
+            | Expression::GreaterThanOrEqual(left, right)
+            | Expression::LessThan(left, right)
+            | Expression::LessThanOrEqual(left, right)
+            | Expression::Like(left, right, _)
+            | Expression::ILike(left, right, _)
+            | Expression::Add(left, right)
+            | Expression::Subtract(left, right)
+            | Expression::Multiply(left, right)
+            | Expression::Divide(left, right)
+            | Expression::Modulo(left, right)
+            | Expression::Concat(left, right) => {
+                Self::collect_references(left, functions, columns);
+                Self::collect_references(right, functions, columns);
+            }
+        }
+    }
+
+    fn is_grouped(&self, name: &str, group_by: &[Column]) -> bool {
+        group_by
+            .iter()
+            .any(|col| self.column_matches(&col.name, name))
+    }
+
+    /// finds the aggregate output column for an aggregate call
+    fn find_aggregate_column<'a>(
+        &self,
+        function: &AggregateFunction,
+        schema: &'a Schema,
+    ) -> BindResult<&'a Column> {
+        let label = function.to_string();
+        schema
+            .columns
+            .iter()
+            .find(|c| c.name == label)
+            .ok_or_else(|| BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!("Aggregate function {} is not allowed here", label),
+            })
+    }
+
+    /// checks whether a column reference names a schema column
+    fn column_matches(&self, column_name: &str, reference: &str) -> bool {
+        if self.case_insensitive_columns {
+            column_name.to_lowercase() == reference.to_lowercase()
+        } else {
+            column_name == reference
+        }
+    }
+
+    /// a qualifier is only valid if it is the stem of a FROM file
+    /// ('data' for 'data.csv')
+    fn check_qualifiers(&self, query: &Query) -> BindResult<()> {
+        let sources: Vec<String> = query
+            .from
+            .files
+            .iter()
+            .map(|file| {
+                Path::new(file)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default()
+            })
+            .collect();
+        match query.qualifiers.iter().find(|qualifier| {
+            !sources
+                .iter()
+                .any(|source| self.column_matches(source, qualifier))
+        }) {
+            Some(qualifier) => Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!(
+                    "Table qualifier '{}' does not match the source '{}'",
+                    qualifier,
+                    sources.join("', '")
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// finds a column by name. an exact match always wins; otherwise, when
+    /// matching ignores case, the name must match exactly one column.
+    fn find_column<'a>(&self, name: &str, schema: &'a Schema) -> BindResult<&'a Column> {
+        if let Some(col) = schema.columns.iter().find(|c| c.name == name) {
+            return Ok(col);
+        }
+
+        let mut candidates = schema
+            .columns
+            .iter()
+            .filter(|c| self.column_matches(&c.name, name));
+        match (candidates.next(), candidates.next()) {
+            (Some(col), None) => Ok(col),
+            (Some(first), Some(second)) => Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!(
+                    "Column reference '{}' is ambiguous (matches '{}' and '{}')",
+                    name, first.name, second.name
+                ),
+            }),
+            _ => Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!("Column '{}' not found in schema", name),
+            }),
+        }
+    }
+
     /// validates SELECT columns against the schema.
-    /// - Expands `SELECT *` to all columns
+    /// - Expands `SELECT *` to all columns, in file order, where the `*` appears
     /// - Validates that specified columns exist in the schema
     /// - Returns error if any column doesn't exist
     /// - Returns both regular columns and aggregates
@@ -139,55 +1582,82 @@ impl Binder {
         for col in select_columns {
             match col {
                 SelectColumn::All => {
-                    // expand * to all columns
+                    // expand * to all columns, in the file's column order
                     validated_columns.extend(schema.columns.clone());
                 }
-                SelectColumn::Column(name) => {
+                SelectColumn::Expression(Expression::Column(name), _) => {
                     // find column in schema
-                    let found_column =
-                        schema
-                            .columns
-                            .iter()
-                            .find(|c| c.name == *name)
-                            .ok_or_else(|| BinderError {
-                                message: format!("Column '{}' not found in schema", name),
-                            })?;
+                    let found_column = self.find_column(name, schema)?;
                     validated_columns.push(found_column.clone());
                 }
-                SelectColumn::Aggregate(agg_func) => {
+                SelectColumn::Aggregate(agg_func, _) => {
                     // bind aggregate function
                     let bound_agg = self.bind_aggregate_function(agg_func, schema)?;
                     aggregates.push(bound_agg);
                 }
+                SelectColumn::Expression(..) => {
+                    // computed expressions are bound with the rest of the SELECT
+                    // list (bind_select_list / bind_grouping)
+                }
             }
         }
 
+        // a column listed again (by name or through *) is output again,
+        // unless deduplication is enabled
+        if self.dedupe_columns {
+            let mut seen = std::collections::HashSet::new();
+            validated_columns.retain(|col: &Column| seen.insert(col.index));
+        }
+
         Ok((validated_columns, aggregates))
     }
 
-    /// binds an aggregate function and validates column references
+    /// binds an aggregate function and validates its argument expression
     fn bind_aggregate_function(
         &self,
         agg_func: &AggregateFunction,
         schema: &Schema,
     ) -> BindResult<BoundAggregateExpression> {
-        match agg_func {
-            AggregateFunction::CountStar => Ok(BoundAggregateExpression::CountStar),
-            AggregateFunction::Count(column_name) => {
-                // find column in schema
-                let found_column = schema
-                    .columns
-                    .iter()
-                    .find(|c| c.name == *column_name)
-                    .ok_or_else(|| BinderError {
-                        message: format!("Column '{}' not found in schema", column_name),
-                    })?;
+        let (name, argument) = match agg_func {
+            AggregateFunction::CountStar => return Ok(BoundAggregateExpression::CountStar),
+            AggregateFunction::Count(arg) => ("COUNT", arg),
+            AggregateFunction::Sum(arg) => ("SUM", arg),
+            AggregateFunction::Min(arg) => ("MIN", arg),
+            AggregateFunction::Max(arg) => ("MAX", arg),
+            AggregateFunction::Avg(arg) => ("AVG", arg),
+        };
 
-                Ok(BoundAggregateExpression::Count {
-                    column: found_column.clone(),
-                })
-            }
+        self.validate_where_expression(argument, schema)?;
+        let expression = self.bind_expression(argument, schema)?;
+
+        // SUM/AVG need numbers, MIN/MAX need an orderable type
+        let arg_type = expression.result_type();
+        let supported = match name {
+            "SUM" | "AVG" => matches!(
+                arg_type,
+                ColumnType::Integer | ColumnType::Float | ColumnType::Null
+            ),
+            "MIN" | "MAX" => arg_type != ColumnType::Boolean,
+            _ => true,
+        };
+        if !supported {
+            return Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!(
+                    "{} does not support {} arguments",
+                    name,
+                    self.type_to_string(&arg_type)
+                ),
+            });
         }
+
+        Ok(match agg_func {
+            AggregateFunction::Sum(_) => BoundAggregateExpression::Sum { expression },
+            AggregateFunction::Min(_) => BoundAggregateExpression::Min { expression },
+            AggregateFunction::Max(_) => BoundAggregateExpression::Max { expression },
+            AggregateFunction::Avg(_) => BoundAggregateExpression::Avg { expression },
+            _ => BoundAggregateExpression::Count { expression },
+        })
     }
 
     /// validates WHERE clause expressions:
@@ -215,6 +1685,26 @@ impl Binder {
                 // recursively validate the inner expression
                 self.validate_where_expression(inner, schema)
             }
+            Expression::IsTrue(inner) | Expression::IsFalse(inner) => {
+                self.validate_where_expression(inner, schema)?;
+                let inner_type = self.get_expression_type(inner, schema)?;
+                if !matches!(inner_type, ColumnType::Boolean | ColumnType::Null) {
+                    let test = match expression {
+                        Expression::IsTrue(_) => "IS TRUE",
+                        _ => "IS FALSE",
+                    };
+                    return Err(BinderError {
+                        kind: BinderErrorKind::Query,
+                        message: format!(
+                            "{} requires a Boolean operand, got {}",
+                            test,
+                            self.type_to_string(&inner_type)
+                        ),
+                    });
+                }
+
+                Ok(())
+            }
             Expression::Equal(left, right)
             | Expression::NotEqual(left, right)
             | Expression::GreaterThan(left, right)
@@ -222,55 +1712,382 @@ impl Binder {
             | Expression::LessThan(left, right)
             | Expression::LessThanOrEqual(left, right) => {
                 // validate both sides
+                self.validate_where_expression(left, schema)?;
+                self.validate_where_expression(right, schema)?;
                 let left_type = self.get_expression_type(left, schema)?;
                 let right_type = self.get_expression_type(right, schema)?;
 
-                // check type compatibility (strict - must match exactly or be compatible)
-                if !self.are_types_compatible(&left_type, &right_type) {
+                // check type compatibility (strict - must match exactly or be compatible)
+                if !self.are_types_compatible(&left_type, &right_type) {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::Query,
+                        message: format!(
+                            "Cannot compare {} and {} - types must match",
+                            self.type_to_string(&left_type),
+                            self.type_to_string(&right_type)
+                        ),
+                    });
+                }
+
+                // booleans have no order, only equality
+                let ordering = match expression {
+                    Expression::GreaterThan(..) => Some(">"),
+                    Expression::GreaterThanOrEqual(..) => Some(">="),
+                    Expression::LessThan(..) => Some("<"),
+                    Expression::LessThanOrEqual(..) => Some("<="),
+                    _ => None,
+                };
+                let is_boolean = |ty: &ColumnType| *ty == ColumnType::Boolean;
+                if let Some(operator) =
+                    ordering.filter(|_| is_boolean(&left_type) || is_boolean(&right_type))
+                {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::Query,
+                        message: format!(
+                            "Cannot compare Boolean values with {} - only = and != are supported",
+                            operator
+                        ),
+                    });
+                }
+
+                Ok(())
+            }
+            Expression::Like(left, right, _) | Expression::ILike(left, right, _) => {
+                // like only works on strings
+                let op = match expression {
+                    Expression::ILike(..) => "ILIKE",
+                    _ => "LIKE",
+                };
+                for side in [left, right] {
+                    self.validate_where_expression(side, schema)?;
+                    let side_type = self.get_expression_type(side, schema)?;
+                    if !matches!(side_type, ColumnType::Varchar | ColumnType::Null) {
+                        return Err(BinderError {
+                            kind: BinderErrorKind::Query,
+                            message: format!(
+                                "{} requires Varchar operands, got {}",
+                                op,
+                                self.type_to_string(&side_type)
+                            ),
+                        });
+                    }
+                }
+
+                Ok(())
+            }
+            Expression::In(left, list) => {
+                // every list item must be comparable with the left side
+                self.validate_where_expression(left, schema)?;
+                let left_type = self.get_expression_type(left, schema)?;
+                for item in list {
+                    self.validate_where_expression(item, schema)?;
+                    let item_type = self.get_expression_type(item, schema)?;
+                    if !self.are_types_compatible(&left_type, &item_type) {
+                        return Err(BinderError {
+                            kind: BinderErrorKind::Query,
+                            message: format!(
+                                "Cannot compare {} and {} in IN list - types must match",
+                                self.type_to_string(&left_type),
+                                self.type_to_string(&item_type)
+                            ),
+                        });
+                    }
+                }
+
+                Ok(())
+            }
+            Expression::Add(left, right)
+            | Expression::Subtract(left, right)
+            | Expression::Multiply(left, right)
+            | Expression::Divide(left, right) => {
+                // arithmetic only works on numbers
+                for side in [left, right] {
+                    self.validate_where_expression(side, schema)?;
+                    let side_type = self.get_expression_type(side, schema)?;
+                    if !matches!(
+                        side_type,
+                        ColumnType::Integer | ColumnType::Float | ColumnType::Null
+                    ) {
+                        return Err(BinderError {
+                            kind: BinderErrorKind::Query,
+                            message: format!(
+                                "Arithmetic requires numeric operands, got {}",
+                                self.type_to_string(&side_type)
+                            ),
+                        });
+                    }
+                }
+
+                Ok(())
+            }
+            Expression::Modulo(left, right) => {
+                // integer-only: there's no float remainder, so 5.5 % 2 is an error
+                for side in [left, right] {
+                    self.validate_where_expression(side, schema)?;
+                    let side_type = self.get_expression_type(side, schema)?;
+                    if !matches!(side_type, ColumnType::Integer | ColumnType::Null) {
+                        return Err(BinderError {
+                            kind: BinderErrorKind::Query,
+                            message: format!(
+                                "Modulo requires Integer operands, got {}",
+                                self.type_to_string(&side_type)
+                            ),
+                        });
+                    }
+                }
+
+                Ok(())
+            }
+            Expression::Negate(inner) => {
+                self.validate_where_expression(inner, schema)?;
+                let inner_type = self.get_expression_type(inner, schema)?;
+                if !matches!(
+                    inner_type,
+                    ColumnType::Integer | ColumnType::Float | ColumnType::Null
+                ) {
                     return Err(BinderError {
+                        kind: BinderErrorKind::Query,
                         message: format!(
-                            "Cannot compare {} and {} - types must match",
-                            self.type_to_string(&left_type),
-                            self.type_to_string(&right_type)
+                            "Arithmetic requires numeric operands, got {}",
+                            self.type_to_string(&inner_type)
                         ),
                     });
                 }
 
                 Ok(())
             }
+            Expression::Concat(left, right) => {
+                // any type can be concatenated: numbers and booleans use their text form
+                self.validate_where_expression(left, schema)?;
+                self.validate_where_expression(right, schema)
+            }
+            Expression::FunctionCall { name, args } => {
+                let function = self.find_scalar_function(name)?;
+                for arg in args {
+                    self.validate_where_expression(arg, schema)?;
+                }
+                self.check_function_arguments(function, args, schema)
+            }
+            Expression::Cast { expr, .. } => {
+                // every type converts to every other; failures become NULL at run time
+                self.validate_where_expression(expr, schema)
+            }
+            Expression::Case { branches, else_ } => {
+                let mut result_types = Vec::new();
+                for (condition, result) in branches {
+                    self.validate_where_expression(condition, schema)?;
+                    self.validate_predicate(condition, schema)?;
+                    self.validate_where_expression(result, schema)?;
+                    result_types.push(self.get_expression_type(result, schema)?);
+                }
+                if let Some(else_) = else_ {
+                    self.validate_where_expression(else_, schema)?;
+                    result_types.push(self.get_expression_type(else_, schema)?);
+                }
+                self.check_common_type("CASE results", &result_types)
+            }
             Expression::Column(name) => {
                 // validate column exists
-                schema
-                    .columns
-                    .iter()
-                    .find(|c| c.name == *name)
-                    .ok_or_else(|| BinderError {
-                        message: format!("Column '{}' not found in schema", name),
-                    })?;
+                self.find_column(name, schema)?;
                 Ok(())
             }
             Expression::Literal(_) => {
                 // literals are always valid
                 Ok(())
             }
+            Expression::Aggregate(function) => {
+                // only the HAVING output schema has columns for aggregates
+                self.find_aggregate_column(function, schema)?;
+                Ok(())
+            }
+        }
+    }
+
+    /// checks that a WHERE/HAVING condition (and every AND/OR/NOT operand) is boolean.
+    /// a bare Boolean column is allowed and is read as `column = true`
+    fn validate_predicate(&self, expression: &Expression, schema: &Schema) -> BindResult<()> {
+        match expression {
+            Expression::Or(left, right) | Expression::And(left, right) => {
+                self.validate_predicate(left, schema)?;
+                self.validate_predicate(right, schema)
+            }
+            Expression::Not(inner) => self.validate_predicate(inner, schema),
+            _ => match self.get_expression_type(expression, schema)? {
+                ColumnType::Boolean | ColumnType::Null => Ok(()),
+                other => {
+                    let subject = match expression {
+                        Expression::Column(name) => format!("column '{}'", name),
+                        _ => format!("expression '{}'", expression),
+                    };
+                    Err(BinderError {
+                        kind: BinderErrorKind::Query,
+                        message: format!(
+                            "Cannot use {} {} as a predicate - only Boolean values can be filtered on directly",
+                            self.type_to_string(&other),
+                            subject
+                        ),
+                    })
+                }
+            },
+        }
+    }
+
+    /// binds a WHERE/HAVING condition, rewriting bare Boolean columns to `column = true`
+    fn bind_predicate(
+        &self,
+        expression: &Expression,
+        schema: &Schema,
+    ) -> BindResult<BoundExpression> {
+        match expression {
+            Expression::Or(left, right) => Ok(BoundExpression::Or(
+                Box::new(self.bind_predicate(left, schema)?),
+                Box::new(self.bind_predicate(right, schema)?),
+            )),
+            Expression::And(left, right) => Ok(BoundExpression::And(
+                Box::new(self.bind_predicate(left, schema)?),
+                Box::new(self.bind_predicate(right, schema)?),
+            )),
+            Expression::Not(inner) => Ok(BoundExpression::Not(Box::new(
+                self.bind_predicate(inner, schema)?,
+            ))),
+            Expression::Column(_) => Ok(BoundExpression::Equal(
+                Box::new(self.bind_expression(expression, schema)?),
+                Box::new(BoundExpression::Literal {
+                    value: LiteralValue::Boolean(true),
+                    type_: ColumnType::Boolean,
+                }),
+            )),
+            _ => self.bind_expression(expression, schema),
+        }
+    }
+
+    /// looks up a scalar function by name
+    fn find_scalar_function(&self, name: &str) -> BindResult<ScalarFunction> {
+        ScalarFunction::from_name(name).ok_or_else(|| {
+            let supported: Vec<&str> = ScalarFunction::ALL.iter().map(|f| f.name()).collect();
+            BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!(
+                    "Unknown function '{}' - supported functions are {}",
+                    name,
+                    supported.join(", ")
+                ),
+            }
+        })
+    }
+
+    /// checks the number and types of a scalar function's arguments
+    fn check_function_arguments(
+        &self,
+        function: ScalarFunction,
+        args: &[Expression],
+        schema: &Schema,
+    ) -> BindResult<()> {
+        let types = args
+            .iter()
+            .map(|arg| self.get_expression_type(arg, schema))
+            .collect::<BindResult<Vec<_>>>()?;
+
+        if function == ScalarFunction::Coalesce {
+            return self.check_coalesce_arguments(&types);
+        }
+
+        // the string functions take a string first; SUBSTR adds a start and an optional length
+        let (arity_ok, expected) = match function {
+            ScalarFunction::Substr => ((2..=3).contains(&types.len()), "2 or 3 arguments"),
+            _ => (types.len() == 1, "1 argument"),
+        };
+        if !arity_ok {
+            return Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!(
+                    "{} takes {}, got {}",
+                    function.name(),
+                    expected,
+                    types.len()
+                ),
+            });
+        }
+
+        if !matches!(types[0], ColumnType::Varchar | ColumnType::Null) {
+            return Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!(
+                    "{} requires a Varchar argument, got {}",
+                    function.name(),
+                    self.type_to_string(&types[0])
+                ),
+            });
+        }
+        if let Some(other) = types[1..]
+            .iter()
+            .find(|t| !matches!(t, ColumnType::Integer | ColumnType::Null))
+        {
+            return Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: format!(
+                    "{} positions must be Integer, got {}",
+                    function.name(),
+                    self.type_to_string(other)
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// COALESCE takes one or more arguments that share a type (numbers mix, NULL fits any)
+    fn check_coalesce_arguments(&self, types: &[ColumnType]) -> BindResult<()> {
+        if types.is_empty() {
+            return Err(BinderError {
+                kind: BinderErrorKind::Query,
+                message: "COALESCE takes at least 1 argument, got 0".to_string(),
+            });
+        }
+
+        self.check_common_type("COALESCE arguments", types)
+    }
+
+    /// values that end up in one column (numbers mix, NULL fits any); `what`
+    /// names them in the error
+    fn check_common_type(&self, what: &str, types: &[ColumnType]) -> BindResult<()> {
+        let mut common = ColumnType::Null;
+        for other in types {
+            common = match (&common, other) {
+                (_, ColumnType::Null) => continue,
+                (ColumnType::Null, other) => other.clone(),
+                (left, right) if left == right => continue,
+                (ColumnType::Integer, ColumnType::Float)
+                | (ColumnType::Float, ColumnType::Integer) => ColumnType::Float,
+                (left, right) => {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::Query,
+                        message: format!(
+                            "{} must have compatible types, got {} and {}",
+                            what,
+                            self.type_to_string(left),
+                            self.type_to_string(right)
+                        ),
+                    });
+                }
+            };
         }
+        Ok(())
     }
 
     /// gets the type of an expression.
     fn get_expression_type(&self, expr: &Expression, schema: &Schema) -> BindResult<ColumnType> {
         match expr {
-            Expression::Or(_, _) | Expression::And(_, _) | Expression::Not(_) => {
-                // logical operators return boolean
+            Expression::Or(_, _)
+            | Expression::And(_, _)
+            | Expression::Not(_)
+            | Expression::IsTrue(_)
+            | Expression::IsFalse(_) => {
+                // logical operators and truth tests return boolean
                 Ok(ColumnType::Boolean)
             }
             Expression::Column(name) => {
-                let col = schema
-                    .columns
-                    .iter()
-                    .find(|c| c.name == *name)
-                    .ok_or_else(|| BinderError {
-                        message: format!("Column '{}' not found in schema", name),
-                    })?;
+                let col = self.find_column(name, schema)?;
                 Ok(col.type_.clone())
             }
             Expression::Literal(lit) => Ok(match lit {
@@ -278,6 +2095,8 @@ impl Binder {
                 LiteralValue::Float(_) => ColumnType::Float,
                 LiteralValue::String(_) => ColumnType::Varchar,
                 LiteralValue::Boolean(_) => ColumnType::Boolean,
+                LiteralValue::Date(_) => ColumnType::Date,
+                LiteralValue::Timestamp(_) => ColumnType::Timestamp,
                 LiteralValue::Null => ColumnType::Null,
             }),
             Expression::Equal(_, _)
@@ -285,22 +2104,72 @@ impl Binder {
             | Expression::GreaterThan(_, _)
             | Expression::GreaterThanOrEqual(_, _)
             | Expression::LessThan(_, _)
-            | Expression::LessThanOrEqual(_, _) => {
+            | Expression::LessThanOrEqual(_, _)
+            | Expression::Like(..)
+            | Expression::ILike(..)
+            | Expression::In(_, _) => {
                 // comparison expressions return boolean
                 Ok(ColumnType::Boolean)
             }
+            Expression::Aggregate(function) => {
+                Ok(self.find_aggregate_column(function, schema)?.type_.clone())
+            }
+            Expression::Add(left, right)
+            | Expression::Subtract(left, right)
+            | Expression::Multiply(left, right)
+            | Expression::Divide(left, right) => {
+                let left_type = self.get_expression_type(left, schema)?;
+                let right_type = self.get_expression_type(right, schema)?;
+                Ok(match (left_type, right_type) {
+                    (ColumnType::Integer, ColumnType::Integer) => ColumnType::Integer,
+                    (ColumnType::Null, ColumnType::Null) => ColumnType::Null,
+                    _ => ColumnType::Float,
+                })
+            }
+            Expression::Modulo(left, right) => {
+                let left_type = self.get_expression_type(left, schema)?;
+                let right_type = self.get_expression_type(right, schema)?;
+                Ok(match (left_type, right_type) {
+                    (ColumnType::Null, ColumnType::Null) => ColumnType::Null,
+                    _ => ColumnType::Integer,
+                })
+            }
+            Expression::Negate(inner) => self.get_expression_type(inner, schema),
+            Expression::Concat(_, _) => Ok(ColumnType::Varchar),
+            Expression::Cast { target, .. } => Ok(target.clone()),
+            Expression::Case { branches, else_ } => {
+                let result_types = branches
+                    .iter()
+                    .map(|(_, result)| result)
+                    .chain(else_.as_deref())
+                    .map(|result| self.get_expression_type(result, schema))
+                    .collect::<BindResult<Vec<_>>>()?;
+                Ok(common_type(result_types.iter()))
+            }
+            Expression::FunctionCall { name, args } => {
+                let function = self.find_scalar_function(name)?;
+                let arg_types = args
+                    .iter()
+                    .map(|arg| self.get_expression_type(arg, schema))
+                    .collect::<BindResult<Vec<_>>>()?;
+                Ok(function.result_type(&arg_types))
+            }
         }
     }
 
-    /// checks if two types are compatible for comparison (strict).
+    /// checks if two types are compatible for comparison.
     /// only allows:
     /// - Same types
-    /// - Integer and Float (numeric comparison)
+    /// - Integer and Float (numeric comparison), unless strict_types is set
+    /// - Null with anything, unless strict_types is set
     fn are_types_compatible(&self, left: &ColumnType, right: &ColumnType) -> bool {
         match (left, right) {
             // same types are always compatible
             (l, r) if l == r => true,
 
+            // strict mode: nothing else is
+            _ if self.strict_types => false,
+
             // integer and Float are compatible (numeric comparison)
             (ColumnType::Integer, ColumnType::Float) => true,
             (ColumnType::Float, ColumnType::Integer) => true,
@@ -321,6 +2190,8 @@ impl Binder {
             ColumnType::Float => "Float",
             ColumnType::Boolean => "Boolean",
             ColumnType::Varchar => "Varchar",
+            ColumnType::Date => "Date",
+            ColumnType::Timestamp => "Timestamp",
             ColumnType::Null => "Null",
         }
     }
@@ -356,18 +2227,18 @@ impl Binder {
                 let bound_inner = self.bind_expression(inner, schema)?;
                 Ok(BoundExpression::Not(Box::new(bound_inner)))
             }
+            Expression::IsTrue(inner) => Ok(BoundExpression::IsTrue(Box::new(
+                self.bind_expression(inner, schema)?,
+            ))),
+            Expression::IsFalse(inner) => Ok(BoundExpression::IsFalse(Box::new(
+                self.bind_expression(inner, schema)?,
+            ))),
             Expression::Column(name) => {
                 // look up column in schema
-                let col = schema
-                    .columns
-                    .iter()
-                    .find(|c| c.name == *name)
-                    .ok_or_else(|| BinderError {
-                        message: format!("Column '{}' not found in schema", name),
-                    })?;
+                let col = self.find_column(name, schema)?;
 
                 Ok(BoundExpression::ColumnRef {
-                    name: name.clone(),
+                    name: col.name.clone(),
                     index: col.index,
                     type_: col.type_.clone(),
                 })
@@ -380,6 +2251,30 @@ impl Binder {
                     LiteralValue::Float(_) => ColumnType::Float,
                     LiteralValue::String(_) => ColumnType::Varchar,
                     LiteralValue::Boolean(_) => ColumnType::Boolean,
+                    LiteralValue::Date(text) => {
+                        if parse_date(text).is_none() {
+                            return Err(BinderError {
+                                kind: BinderErrorKind::Query,
+                                message: format!(
+                                    "Invalid DATE literal '{}' - expected YYYY-MM-DD",
+                                    text
+                                ),
+                            });
+                        }
+                        ColumnType::Date
+                    }
+                    LiteralValue::Timestamp(text) => {
+                        if parse_timestamp(text).is_none() {
+                            return Err(BinderError {
+                                kind: BinderErrorKind::Query,
+                                message: format!(
+                                    "Invalid TIMESTAMP literal '{}' - expected YYYY-MM-DD HH:MM:SS",
+                                    text
+                                ),
+                            });
+                        }
+                        ColumnType::Timestamp
+                    }
                     LiteralValue::Null => ColumnType::Null,
                 };
 
@@ -442,6 +2337,110 @@ impl Binder {
                     Box::new(bound_right),
                 ))
             }
+
+            Expression::Like(left, right, escape) => {
+                let bound_left = self.bind_expression(left, schema)?;
+                let bound_right = self.bind_expression(right, schema)?;
+                Ok(BoundExpression::Like(
+                    Box::new(bound_left),
+                    Box::new(bound_right),
+                    *escape,
+                ))
+            }
+
+            // a LIKE between the lowercased operands
+            Expression::ILike(left, right, escape) => {
+                let lower = |side: &Expression| -> BindResult<BoundExpression> {
+                    Ok(BoundExpression::FunctionCall {
+                        function: ScalarFunction::Lower,
+                        args: vec![self.bind_expression(side, schema)?],
+                    })
+                };
+                Ok(BoundExpression::Like(
+                    Box::new(lower(left)?),
+                    Box::new(lower(right)?),
+                    escape.map(|escape| escape.to_lowercase().next().unwrap_or(escape)),
+                ))
+            }
+
+            Expression::In(left, list) => {
+                let bound_left = self.bind_expression(left, schema)?;
+                let bound_list = list
+                    .iter()
+                    .map(|item| self.bind_expression(item, schema))
+                    .collect::<BindResult<Vec<_>>>()?;
+                Ok(BoundExpression::In(Box::new(bound_left), bound_list))
+            }
+
+            Expression::Aggregate(function) => {
+                let col = self.find_aggregate_column(function, schema)?;
+                Ok(BoundExpression::ColumnRef {
+                    name: col.name.clone(),
+                    index: col.index,
+                    type_: col.type_.clone(),
+                })
+            }
+
+            Expression::Add(left, right)
+            | Expression::Subtract(left, right)
+            | Expression::Multiply(left, right)
+            | Expression::Divide(left, right) => {
+                let bound_left = Box::new(self.bind_expression(left, schema)?);
+                let bound_right = Box::new(self.bind_expression(right, schema)?);
+                Ok(match expr {
+                    Expression::Add(_, _) => BoundExpression::Add(bound_left, bound_right),
+                    Expression::Subtract(_, _) => {
+                        BoundExpression::Subtract(bound_left, bound_right)
+                    }
+                    Expression::Multiply(_, _) => {
+                        BoundExpression::Multiply(bound_left, bound_right)
+                    }
+                    _ => BoundExpression::Divide(bound_left, bound_right),
+                })
+            }
+
+            Expression::Modulo(left, right) => Ok(BoundExpression::Modulo(
+                Box::new(self.bind_expression(left, schema)?),
+                Box::new(self.bind_expression(right, schema)?),
+            )),
+
+            Expression::Negate(inner) => Ok(BoundExpression::Negate(Box::new(
+                self.bind_expression(inner, schema)?,
+            ))),
+
+            Expression::Concat(left, right) => Ok(BoundExpression::Concat(
+                Box::new(self.bind_expression(left, schema)?),
+                Box::new(self.bind_expression(right, schema)?),
+            )),
+
+            Expression::Cast { expr, target } => Ok(BoundExpression::Cast {
+                expr: Box::new(self.bind_expression(expr, schema)?),
+                target: target.clone(),
+            }),
+
+            Expression::Case { branches, else_ } => Ok(BoundExpression::Case {
+                branches: branches
+                    .iter()
+                    .map(|(condition, result)| {
+                        Ok((
+                            self.bind_expression(condition, schema)?,
+                            self.bind_expression(result, schema)?,
+                        ))
+                    })
+                    .collect::<BindResult<Vec<_>>>()?,
+                else_: match else_ {
+                    Some(else_) => Some(Box::new(self.bind_expression(else_, schema)?)),
+                    None => None,
+                },
+            }),
+
+            Expression::FunctionCall { name, args } => Ok(BoundExpression::FunctionCall {
+                function: self.find_scalar_function(name)?,
+                args: args
+                    .iter()
+                    .map(|arg| self.bind_expression(arg, schema))
+                    .collect::<BindResult<Vec<_>>>()?,
+            }),
         }
     }
 
@@ -479,31 +2478,51 @@ impl Binder {
 
         // step 5: Validate WHERE expression (if present)
         if let Some(where_expr) = where_clause {
+            Self::reject_where_aggregates(where_expr)?;
             self.validate_where_expression(where_expr, &schema)?;
         }
 
         Ok((file_path, schema, validated_select_columns))
     }
 
+    /// resolves a FROM entry to the files it names: a glob pattern
+    /// ('data/*.csv') expands to its matches in sorted order, anything else
+    /// goes through resolve_file_name
+    pub fn resolve_file_names(&self, file_name: &str) -> BindResult<Vec<PathBuf>> {
+        // an existing file is taken literally even if its name has a metacharacter
+        if !file_name.contains(['*', '?', '[']) || Path::new(file_name).exists() {
+            return Ok(vec![self.resolve_file_name(file_name)?]);
+        }
+
+        let pattern = self.absolute_path(file_name)?;
+        let matches = glob::glob(&pattern.to_string_lossy()).map_err(|e| BinderError {
+            kind: BinderErrorKind::Query,
+            message: format!("Invalid file pattern '{}': {}", file_name, e),
+        })?;
+        let mut paths: Vec<PathBuf> = matches
+            .filter_map(Result::ok)
+            .filter(|path| path.is_file())
+            .collect();
+        paths.sort();
+
+        if paths.is_empty() {
+            return Err(BinderError {
+                kind: BinderErrorKind::FileNotFound,
+                message: format!("No files match {}", pattern.display()),
+            });
+        }
+        Ok(paths)
+    }
+
     /// resolves a file name from the AST to an actual CSV file path.
     /// validates that the file exists.
     pub fn resolve_file_name(&self, file_name: &str) -> BindResult<PathBuf> {
-        let path = Path::new(file_name);
-
-        // resolve relative paths to absolute
-        let resolved_path = if path.is_absolute() {
-            path.to_path_buf()
-        } else {
-            std::env::current_dir()
-                .map_err(|e| BinderError {
-                    message: format!("Failed to get current directory: {}", e),
-                })?
-                .join(path)
-        };
+        let resolved_path = self.absolute_path(file_name)?;
 
         // validate file exists
         if !resolved_path.exists() {
             return Err(BinderError {
+                kind: BinderErrorKind::FileNotFound,
                 message: format!("File not found: {}", resolved_path.display()),
             });
         }
@@ -511,31 +2530,51 @@ impl Binder {
         Ok(resolved_path)
     }
 
+    /// relative paths are resolved against the current directory; on Windows a
+    /// drive path (C:\data\file.csv) is already absolute
+    fn absolute_path(&self, file_name: &str) -> BindResult<PathBuf> {
+        let path = Path::new(file_name);
+        if path.is_absolute() {
+            return Ok(path.to_path_buf());
+        }
+        Ok(std::env::current_dir()
+            .map_err(|e| BinderError {
+                kind: BinderErrorKind::of_io(&e),
+                message: format!("Failed to get current directory: {}", e),
+            })?
+            .join(path))
+    }
+
     /// reads CSV file headers (first row) and returns column names.
     /// assumes the first row contains column headers.
     pub fn read_csv_headers(&self, file_path: &PathBuf) -> BindResult<Schema> {
-        // read first line of file
-        let content = fs::read_to_string(file_path).map_err(|e| BinderError {
-            message: format!("Failed to read file: {}", e),
-        })?;
+        self.read_csv_headers_from_str(&Self::read_file(file_path)?)
+    }
 
+    fn read_csv_headers_from_str(&self, content: &str) -> BindResult<Schema> {
         // get first line
         let first_line = content.lines().next().ok_or_else(|| BinderError {
+            kind: BinderErrorKind::EmptyFile,
             message: "CSV file is empty".to_string(),
         })?;
 
         // parse CSV header: split by comma and trim whitespace
+        // (a UTF-8 BOM is not part of the first column name)
         let column_names: Vec<String> = first_line
+            .trim_start_matches('\u{feff}')
             .split(',')
             .map(|s| s.trim().to_string())
             .collect();
 
         if column_names.is_empty() {
             return Err(BinderError {
+                kind: BinderErrorKind::EmptyFile,
                 message: "CSV header has no columns".to_string(),
             });
         }
 
+        let column_names = self.check_duplicate_headers(column_names)?;
+
         // create columns with indices (types will be inferred separately)
         let columns: Vec<Column> = column_names
             .into_iter()
@@ -550,14 +2589,172 @@ impl Binder {
         Ok(Schema { columns })
     }
 
+    /// a repeated header name would shadow the later column, so either fail
+    /// or rename the repeats to `name_2`, `name_3`, ...
+    fn check_duplicate_headers(&self, names: Vec<String>) -> BindResult<Vec<String>> {
+        let mut seen: Vec<String> = Vec::with_capacity(names.len());
+        for name in &names {
+            if !seen.contains(name) {
+                seen.push(name.clone());
+                continue;
+            }
+            if !self.rename_duplicate_headers {
+                return Err(BinderError {
+                    kind: BinderErrorKind::MalformedFile,
+                    message: format!("duplicate column '{}' in CSV header", name),
+                });
+            }
+
+            // first free suffix, skipping names the header already uses
+            let renamed = (2..)
+                .map(|n| format!("{}_{}", name, n))
+                .find(|candidate| !seen.contains(candidate) && !names.contains(candidate))
+                .unwrap();
+            seen.push(renamed);
+        }
+        Ok(seen)
+    }
+
+    /// resolves the header mode for a file. in Auto mode the first line is a
+    /// header if any column whose sampled data is Integer, Float, Boolean, Date or
+    /// Timestamp has a first-line value that isn't; all-Varchar files are assumed
+    /// to have one
+    pub fn detect_header(&self, file_path: &PathBuf) -> BindResult<bool> {
+        match self.header {
+            HeaderMode::Always => Ok(true),
+            HeaderMode::Never => Ok(false),
+            HeaderMode::Auto => self.detect_header_from_str(&Self::read_file(file_path)?),
+        }
+    }
+
+    fn detect_header_from_str(&self, content: &str) -> BindResult<bool> {
+        match self.header {
+            HeaderMode::Always => return Ok(true),
+            HeaderMode::Never => return Ok(false),
+            HeaderMode::Auto => {}
+        }
+
+        let lines: Vec<&str> = content
+            .lines()
+            .take(self.sample_limit().saturating_add(1))
+            .collect();
+        let Some((first, sample_rows)) = lines.split_first() else {
+            return Ok(true);
+        };
+        let first_values: Vec<&str> = first
+            .trim_start_matches('\u{feff}')
+            .split(',')
+            .map(|s| s.trim())
+            .collect();
+
+        let mut typed_columns = 0;
+        for (index, value) in first_values.iter().enumerate() {
+            let fits = match self.infer_type_for_column(sample_rows, index)? {
+                ColumnType::Integer => value.parse::<i64>().is_ok(),
+                ColumnType::Float => value.parse::<f64>().is_ok(),
+                ColumnType::Boolean => {
+                    value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false")
+                }
+                ColumnType::Date => parse_date(value).is_some(),
+                ColumnType::Timestamp => parse_timestamp(value).is_some(),
+                ColumnType::Varchar | ColumnType::Null => continue,
+            };
+            if !fits && !self.is_null_text(value) {
+                return Ok(true);
+            }
+            typed_columns += 1;
+        }
+
+        // every typed column accepts the first line as data
+        Ok(typed_columns == 0)
+    }
+
+    /// schema of a JSON-lines file: the union of the keys of the sampled
+    /// objects (the first 20 by default) in first-seen order, typed from their JSON values
+    pub fn read_json_lines_schema(&self, file_path: &PathBuf) -> BindResult<Schema> {
+        self.read_json_lines_schema_from_str(&Self::read_file(file_path)?)
+    }
+
+    fn read_json_lines_schema_from_str(&self, content: &str) -> BindResult<Schema> {
+        let mut columns: Vec<Column> = Vec::new();
+        let mut seen_value: Vec<bool> = Vec::new(); // column has a non-null sample
+        let rows = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        for (line_index, line) in rows.take(self.sample_limit()) {
+            let object = match serde_json::from_str::<serde_json::Value>(line) {
+                Ok(serde_json::Value::Object(object)) => object,
+                _ => {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::MalformedFile,
+                        message: format!("Line {} is not a JSON object", line_index + 1),
+                    });
+                }
+            };
+
+            for (key, value) in object {
+                let index = match columns.iter().position(|col| col.name == key) {
+                    Some(index) => index,
+                    None => {
+                        columns.push(Column {
+                            name: key,
+                            type_: ColumnType::Null,
+                            index: columns.len(),
+                        });
+                        seen_value.push(false);
+                        columns.len() - 1
+                    }
+                };
+                let Some(value_type) = Self::json_value_type(&value) else {
+                    continue; // null doesn't break type detection
+                };
+                let column = &mut columns[index];
+                column.type_ = if !seen_value[index] || column.type_ == value_type {
+                    value_type
+                } else if matches!(
+                    (&column.type_, &value_type),
+                    (ColumnType::Integer, ColumnType::Float)
+                        | (ColumnType::Float, ColumnType::Integer)
+                ) {
+                    ColumnType::Float
+                } else {
+                    ColumnType::Varchar
+                };
+                seen_value[index] = true;
+            }
+        }
+
+        if columns.is_empty() {
+            return Err(BinderError {
+                kind: BinderErrorKind::EmptyFile,
+                message: "JSON lines file has no keys".to_string(),
+            });
+        }
+        Ok(Schema { columns })
+    }
+
+    /// column type for a JSON value (None for null); arrays and objects are
+    /// kept as their JSON text
+    fn json_value_type(value: &serde_json::Value) -> Option<ColumnType> {
+        match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::Bool(_) => Some(ColumnType::Boolean),
+            serde_json::Value::Number(n) if n.is_i64() => Some(ColumnType::Integer),
+            serde_json::Value::Number(_) => Some(ColumnType::Float),
+            _ => Some(ColumnType::Varchar),
+        }
+    }
+
     /// reads CSV file without headers and generates column names (column1, column2, etc.).
     pub fn read_csv_without_headers(&self, file_path: &PathBuf) -> BindResult<Schema> {
-        let content = fs::read_to_string(file_path).map_err(|e| BinderError {
-            message: format!("Failed to read file: {}", e),
-        })?;
+        self.read_csv_without_headers_from_str(&Self::read_file(file_path)?)
+    }
 
+    fn read_csv_without_headers_from_str(&self, content: &str) -> BindResult<Schema> {
         // get first line to determine number of columns
         let first_line = content.lines().next().ok_or_else(|| BinderError {
+            kind: BinderErrorKind::EmptyFile,
             message: "CSV file is empty".to_string(),
         })?;
 
@@ -566,6 +2763,7 @@ impl Binder {
 
         if column_count == 0 {
             return Err(BinderError {
+                kind: BinderErrorKind::EmptyFile,
                 message: "CSV file has no columns".to_string(),
             });
         }
@@ -583,7 +2781,8 @@ impl Binder {
     }
 
     /// infers column types by reading sample data rows.
-    /// reads first 20 rows (excluding header if has_header=true) and tries casting to types in order:
+    /// reads the first sample_size rows (20 by default, excluding header if has_header=true)
+    /// and tries casting to types in order:
     /// integer → FLOAT → BOOLEAN → VARCHAR (fallback)
     pub fn infer_column_types(
         &self,
@@ -591,22 +2790,27 @@ impl Binder {
         schema: &mut Schema,
         has_header: bool,
     ) -> BindResult<()> {
-        let content = fs::read_to_string(file_path).map_err(|e| BinderError {
-            message: format!("Failed to read file: {}", e),
-        })?;
+        self.infer_column_types_from_str(&Self::read_file(file_path)?, schema, has_header)
+    }
 
+    fn infer_column_types_from_str(
+        &self,
+        content: &str,
+        schema: &mut Schema,
+        has_header: bool,
+    ) -> BindResult<()> {
+        // read sample rows (skip header only if has_header=true, max sample_size rows)
+        let skip_count = if has_header { 1 } else { 0 };
         let lines: Vec<&str> = content.lines().collect();
-        if lines.len() < 2 {
+        if lines.len() <= skip_count {
             // only header, no data rows - all columns remain VARCHAR
             return Ok(());
         }
 
-        // read sample rows (skip header only if has_header=true, max 20 rows)
-        let skip_count = if has_header { 1 } else { 0 };
         let sample_rows: Vec<&str> = lines
             .iter()
             .skip(skip_count) // skip header only if has_header=true
-            .take(20) // sample first 20 rows
+            .take(self.sample_limit()) // sample the first rows
             .copied()
             .collect();
 
@@ -614,38 +2818,87 @@ impl Binder {
             return Ok(());
         }
 
+        if self.strict {
+            for (i, row) in sample_rows.iter().enumerate() {
+                let field_count = row.split(',').count();
+                if !row.trim().is_empty() && field_count != schema.columns.len() {
+                    return Err(BinderError {
+                        kind: BinderErrorKind::MalformedFile,
+                        message: format!(
+                            "Row on line {} has {} fields, expected {}",
+                            i + skip_count + 1,
+                            field_count,
+                            schema.columns.len()
+                        ),
+                    });
+                }
+            }
+        }
+
         // infer type for each column
         for col in &mut schema.columns {
-            let inferred_type = self.infer_type_for_column(&sample_rows, col.index)?;
+            let mut inferred_type = self.infer_type_for_column(&sample_rows, col.index)?;
+            if inferred_type == ColumnType::Boolean
+                && self.boolean_header_hint
+                && !Self::is_boolean_header(&col.name)
+            {
+                inferred_type = ColumnType::Varchar;
+            }
             col.type_ = inferred_type;
         }
 
         Ok(())
     }
 
+    /// checks if a header name hints at a boolean column (is_*, has_*, active, ...)
+    fn is_boolean_header(name: &str) -> bool {
+        const PREFIXES: [&str; 4] = ["is_", "has_", "can_", "should_"];
+        const NAMES: [&str; 7] = [
+            "active", "enabled", "disabled", "deleted", "verified", "visible", "flag",
+        ];
+
+        let name = name.to_lowercase();
+        PREFIXES.iter().any(|prefix| name.starts_with(prefix)) || NAMES.contains(&name.as_str())
+    }
+
+    /// a sampled field as the scan reads it: trimmed, without enclosing quotes
+    /// (so `"42"` still counts as a number)
+    fn sample_field(field: &str) -> &str {
+        let field = field.trim();
+        if field.len() >= 2 && field.starts_with('"') && field.ends_with('"') {
+            field[1..field.len() - 1].trim()
+        } else {
+            field
+        }
+    }
+
     /// infers the type for a single column by trying casts in order.
     fn infer_type_for_column(
         &self,
         sample_rows: &[&str],
         col_index: usize,
     ) -> BindResult<ColumnType> {
-        // try types in order: INTEGER → FLOAT → BOOLEAN → VARCHAR (fallback)
+        // try types in order: INTEGER → FLOAT → BOOLEAN → DATE → TIMESTAMP → VARCHAR (fallback)
         let mut all_null = true;
+        let mut has_empty = false;
 
         // try INTEGER first
         let mut all_integer = true;
         let mut has_valid_value = false;
         for row in sample_rows {
-            let values: Vec<&str> = row.split(',').map(|s| s.trim()).collect();
+            let values: Vec<&str> = row.split(',').map(Self::sample_field).collect();
             if col_index >= values.len() {
                 continue; // skip rows with missing columns
             }
             let value = values[col_index];
-            if value.is_empty() || value.eq_ignore_ascii_case("null") {
+            has_empty |= value.is_empty();
+            if self.is_null_text(value) {
                 continue; // null doesn't break type detection
             }
             all_null = false;
             has_valid_value = true;
+            // a number past the i64 range fails here too, so the column is read
+            // as Float instead of failing the scan later
             if value.parse::<i64>().is_err() {
                 all_integer = false;
                 break;
@@ -655,6 +2908,10 @@ impl Binder {
             all_integer = false;
         }
         if all_null {
+            // empty cells kept as "" make the column text
+            if has_empty && !self.empty_as_null {
+                return Ok(ColumnType::Varchar);
+            }
             return Ok(ColumnType::Null);
         }
         if all_integer {
@@ -665,12 +2922,12 @@ impl Binder {
         let mut all_float = true;
         let mut has_valid_value = false;
         for row in sample_rows {
-            let values: Vec<&str> = row.split(',').map(|s| s.trim()).collect();
+            let values: Vec<&str> = row.split(',').map(Self::sample_field).collect();
             if col_index >= values.len() {
                 continue; // skip rows with missing columns
             }
             let value = values[col_index];
-            if value.is_empty() || value.eq_ignore_ascii_case("null") {
+            if self.is_null_text(value) {
                 continue;
             }
             has_valid_value = true;
@@ -690,12 +2947,12 @@ impl Binder {
         let mut all_boolean = true;
         let mut has_valid_value = false;
         for row in sample_rows {
-            let values: Vec<&str> = row.split(',').map(|s| s.trim()).collect();
+            let values: Vec<&str> = row.split(',').map(Self::sample_field).collect();
             if col_index >= values.len() {
                 continue; // skip rows with missing columns
             }
             let value = values[col_index];
-            if value.is_empty() || value.eq_ignore_ascii_case("null") {
+            if self.is_null_text(value) {
                 continue;
             }
             has_valid_value = true;
@@ -713,6 +2970,46 @@ impl Binder {
             return Ok(ColumnType::Boolean);
         }
 
+        // try DATE (YYYY-MM-DD only)
+        let mut all_date = true;
+        for row in sample_rows {
+            let values: Vec<&str> = row.split(',').map(Self::sample_field).collect();
+            if col_index >= values.len() {
+                continue; // skip rows with missing columns
+            }
+            let value = values[col_index];
+            if self.is_null_text(value) {
+                continue;
+            }
+            if parse_date(value).is_none() {
+                all_date = false;
+                break;
+            }
+        }
+        if all_date {
+            return Ok(ColumnType::Date);
+        }
+
+        // try TIMESTAMP (dates without a time of day are midnight)
+        let mut all_timestamp = true;
+        for row in sample_rows {
+            let values: Vec<&str> = row.split(',').map(Self::sample_field).collect();
+            if col_index >= values.len() {
+                continue; // skip rows with missing columns
+            }
+            let value = values[col_index];
+            if self.is_null_text(value) {
+                continue;
+            }
+            if parse_timestamp(value).is_none() {
+                all_timestamp = false;
+                break;
+            }
+        }
+        if all_timestamp {
+            return Ok(ColumnType::Timestamp);
+        }
+
         // fallback to VARCHAR
         Ok(ColumnType::Varchar)
     }
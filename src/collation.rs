@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// string collation used when comparing varchar values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Collation {
+    /// compare strings byte by byte (default)
+    #[default]
+    Binary,
+    /// ignore letter case ('ABC' = 'abc')
+    CaseInsensitive,
+    /// ignore letter case and common latin accents ('Café' = 'cafe')
+    AccentInsensitive,
+}
+
+impl Collation {
+    /// compare two strings under this collation
+    pub fn compare(&self, left: &str, right: &str) -> Ordering {
+        match self {
+            Collation::Binary => left.cmp(right),
+            _ => self.key(left).cmp(&self.key(right)),
+        }
+    }
+
+    /// check two strings for equality under this collation
+    pub fn equals(&self, left: &str, right: &str) -> bool {
+        self.compare(left, right) == Ordering::Equal
+    }
+
+    /// normalized form of a string; two strings are equal under this
+    /// collation iff their keys are byte-equal
+    pub fn key<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self {
+            Collation::Binary => Cow::Borrowed(s),
+            Collation::CaseInsensitive => Cow::Owned(s.to_lowercase()),
+            Collation::AccentInsensitive => {
+                let mut folded = String::with_capacity(s.len());
+                for c in s.to_lowercase().chars() {
+                    match fold_accent(c) {
+                        Some(base) => folded.push_str(base),
+                        None => folded.push(c),
+                    }
+                }
+                Cow::Owned(folded)
+            }
+        }
+    }
+}
+
+/// map an accented lowercase latin letter to its unaccented spelling
+fn fold_accent(c: char) -> Option<&'static str> {
+    let base = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collation_keys() {
+        assert!(!Collation::Binary.equals("Cafe", "cafe"));
+        assert!(Collation::CaseInsensitive.equals("Cafe", "cafe"));
+        assert!(!Collation::CaseInsensitive.equals("café", "cafe"));
+        assert!(Collation::AccentInsensitive.equals("CAFÉ", "cafe"));
+        assert!(Collation::AccentInsensitive.equals("straße", "STRASSE"));
+        assert_eq!(
+            Collation::CaseInsensitive.compare("apple", "Banana"),
+            Ordering::Less
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use flate2::read::MultiGzDecoder;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+
+/// first two bytes of every gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// check whether a file is gzip-compressed, by its `.gz` extension or magic header
+pub fn is_gzip<P: AsRef<Path>>(path: P) -> bool {
+    let path = path.as_ref();
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gz"))
+    {
+        return true;
+    }
+    let mut magic = [0u8; 2];
+    File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .is_ok_and(|_| magic == GZIP_MAGIC)
+}
+
+/// open a file for reading, decompressing gzip files on the fly
+pub fn open_file<P: AsRef<Path>>(path: P) -> io::Result<Box<dyn Read + Send>> {
+    let path = path.as_ref();
+    let file = File::open(path)?;
+    if is_gzip(path) {
+        Ok(Box::new(MultiGzDecoder::new(BufReader::new(file))))
+    } else {
+        Ok(Box::new(file))
+    }
+}
+
+/// read a whole (possibly gzip-compressed) file as text
+pub fn read_to_string<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let mut content = String::new();
+    open_file(path)?.read_to_string(&mut content)?;
+    Ok(content)
+}
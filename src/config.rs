@@ -1,3 +1,2 @@
 /// celect configuration constants
 pub const VERSION: &str = "0.0.2";
-
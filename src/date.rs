@@ -0,0 +1,199 @@
+/// parse a `YYYY-MM-DD` date into days since 1970-01-01 (negative before).
+/// surrounding whitespace is ignored; anything else, or a day that doesn't
+/// exist (2023-02-29), is None
+pub fn parse_date(text: &str) -> Option<i32> {
+    let text = text.trim();
+    let bytes = text.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let (year, month, day) = (
+        digits(&text[0..4])?,
+        digits(&text[5..7])?,
+        digits(&text[8..10])?,
+    );
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(days_from_civil(year as i64, month as i64, day as i64) as i32)
+}
+
+/// `YYYY-MM-DD` text of a day count from parse_date
+pub fn format_date(days: i32) -> String {
+    let (year, month, day) = civil_from_days(days as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+/// length of a day in timestamp units
+pub const MICROS_PER_DAY: i64 = 86_400 * MICROS_PER_SECOND;
+
+/// parse a `YYYY-MM-DD HH:MM:SS[.ffffff]` timestamp (or `T` between date and
+/// time) into microseconds since 1970-01-01 00:00:00. a bare date is midnight
+pub fn parse_timestamp(text: &str) -> Option<i64> {
+    let text = text.trim();
+    let days = i64::from(parse_date(text.get(..10)?)?);
+    let time = &text[10..];
+    if time.is_empty() {
+        return Some(days * MICROS_PER_DAY);
+    }
+
+    let time = time.strip_prefix([' ', 'T'])?;
+    let (clock, fraction) = match time.split_once('.') {
+        Some((clock, fraction)) => (clock, Some(fraction)),
+        None => (time, None),
+    };
+    let bytes = clock.as_bytes();
+    if bytes.len() != 8 || bytes[2] != b':' || bytes[5] != b':' {
+        return None;
+    }
+    let (hour, minute, second) = (
+        digits(&clock[0..2])?,
+        digits(&clock[3..5])?,
+        digits(&clock[6..8])?,
+    );
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    // up to microseconds: .5 is 500000
+    let micros = match fraction {
+        Some(fraction) if (1..=6).contains(&fraction.len()) => {
+            digits(fraction)? as i64 * 10i64.pow(6 - fraction.len() as u32)
+        }
+        Some(_) => return None,
+        None => 0,
+    };
+    let seconds = i64::from(hour * 3600 + minute * 60 + second);
+    Some(days * MICROS_PER_DAY + seconds * MICROS_PER_SECOND + micros)
+}
+
+/// `YYYY-MM-DD HH:MM:SS` text of a timestamp from parse_timestamp, with the
+/// fraction of a second only when there is one
+pub fn format_timestamp(micros: i64) -> String {
+    let days = micros.div_euclid(MICROS_PER_DAY) as i32;
+    let time = micros.rem_euclid(MICROS_PER_DAY);
+    let seconds = time / MICROS_PER_SECOND;
+    let mut text = format!(
+        "{} {:02}:{:02}:{:02}",
+        format_date(days),
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    let fraction = time % MICROS_PER_SECOND;
+    if fraction != 0 {
+        text.push_str(format!(".{:06}", fraction).trim_end_matches('0'));
+    }
+    text
+}
+
+/// a run of ASCII digits (no sign, unlike str::parse)
+fn digits(text: &str) -> Option<u32> {
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// days since 1970-01-01 of a proleptic Gregorian date (see
+/// http://howardhinnant.github.io/date_algorithms.html)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// inverse of days_from_civil: (year, month, day)
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_dates() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date(" 1970-01-02 "), Some(1));
+        assert_eq!(parse_date("1969-12-31"), Some(-1));
+        assert_eq!(parse_date("2000-03-01"), Some(11_017));
+        for text in ["2024-02-29", "1900-02-28", "0001-01-01", "9999-12-31"] {
+            assert_eq!(format_date(parse_date(text).unwrap()), text);
+        }
+
+        for text in [
+            "2023-02-29",
+            "1900-02-29",
+            "2024-13-01",
+            "2024-00-10",
+            "2024-1-5",
+            "24-01-05",
+        ] {
+            assert_eq!(parse_date(text), None, "{}", text);
+        }
+        assert_eq!(parse_date("2024/01/05"), None);
+        assert_eq!(parse_date("2024-01-05T10:00"), None);
+    }
+
+    #[test]
+    fn test_parse_and_format_timestamps() {
+        assert_eq!(parse_timestamp("1970-01-01 00:00:00"), Some(0));
+        assert_eq!(parse_timestamp("1970-01-01T00:00:01"), Some(1_000_000));
+        assert_eq!(parse_timestamp("1970-01-02"), Some(MICROS_PER_DAY));
+        assert_eq!(parse_timestamp("1969-12-31 23:59:59.5"), Some(-500_000));
+        for text in [
+            "2024-01-15 13:45:00",
+            "2024-02-29 23:59:59.123456",
+            "1900-03-01 00:00:00.25",
+        ] {
+            assert_eq!(format_timestamp(parse_timestamp(text).unwrap()), text);
+        }
+        assert_eq!(
+            format_timestamp(parse_timestamp("2024-01-15").unwrap()),
+            "2024-01-15 00:00:00"
+        );
+
+        for text in [
+            "2024-01-15 24:00:00",
+            "2024-01-15 13:60:00",
+            "2024-01-15 13:45",
+            "2024-01-15 13:45:00.",
+            "2024-01-15 13:45:00.1234567",
+            "2024-01-15  13:45:00",
+            "2024-02-30 13:45:00",
+        ] {
+            assert_eq!(parse_timestamp(text), None, "{}", text);
+        }
+    }
+}
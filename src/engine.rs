@@ -0,0 +1,144 @@
+use crate::binder::{Binder, Schema};
+use crate::error::CelectError;
+use crate::execution::{DataChunk, PhysicalPlanner, PipelineExecutor};
+use crate::optimizer::Optimizer;
+use crate::parser::Parser;
+use crate::planner::{LogicalOperator, Planner};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// plans kept when no capacity is given
+const DEFAULT_CAPACITY: usize = 64;
+
+/// runs SQL end to end, keeping the optimized plans of recent queries so a
+/// repeated query goes straight to physical planning. a plan is rebuilt when
+/// one of the files it reads has been modified since it was cached
+#[derive(Debug)]
+pub struct QueryEngine {
+    cache: VecDeque<CachedPlan>, // most recently used first
+    capacity: usize,
+    strict: bool,
+    hits: usize,
+    misses: usize,
+}
+
+/// an optimized plan and the modification times of the files it reads
+#[derive(Debug)]
+struct CachedPlan {
+    sql: String,
+    plan: LogicalOperator,
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl CachedPlan {
+    /// none of the files changed since the plan was built
+    fn is_fresh(&self) -> bool {
+        self.files
+            .iter()
+            .all(|(path, modified)| modified_time(path) == *modified)
+    }
+}
+
+impl QueryEngine {
+    pub fn new() -> Self {
+        Self {
+            cache: VecDeque::new(),
+            capacity: DEFAULT_CAPACITY,
+            strict: false,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// keep at most `capacity` plans, dropping the least recently used (0 disables caching)
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self.cache.truncate(capacity);
+        self
+    }
+
+    /// bind and scan in strict mode (see Binder::with_strict)
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self.cache.clear();
+        self
+    }
+
+    /// the optimized plan for `sql`, from the cache when the same text was
+    /// planned before and its files are unchanged
+    pub fn plan(&mut self, sql: &str) -> Result<LogicalOperator, CelectError> {
+        if let Some(position) = self.cache.iter().position(|entry| entry.sql == sql) {
+            let entry = self.cache.remove(position).unwrap();
+            if entry.is_fresh() {
+                self.hits += 1;
+                let plan = entry.plan.clone();
+                self.cache.push_front(entry);
+                return Ok(plan);
+            }
+            // a modified file may have new columns or types: plan again
+        }
+
+        self.misses += 1;
+        let query = Parser::new().parse(sql)?;
+        let bound_query = Binder::new().with_strict(self.strict).bind(query)?;
+        let files = bound_query
+            .files()
+            .into_iter()
+            .map(|path| (path.clone(), modified_time(path)))
+            .collect();
+        let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+
+        if self.capacity > 0 {
+            self.cache.truncate(self.capacity - 1);
+            self.cache.push_front(CachedPlan {
+                sql: sql.to_string(),
+                plan: plan.clone(),
+                files,
+            });
+        }
+        Ok(plan)
+    }
+
+    /// runs `sql`, returning the result chunks and their schema
+    pub fn execute(&mut self, sql: &str) -> Result<(Vec<DataChunk>, Schema), CelectError> {
+        let plan = self.plan(sql)?;
+        let (operators, schemas) = PhysicalPlanner::new().with_strict(self.strict).plan(plan);
+        let mut executor = PipelineExecutor::new(operators, schemas);
+        let results = executor.try_execute()?;
+        Ok((results, executor.output_schema().clone()))
+    }
+
+    /// queries answered from a cached plan
+    pub fn cache_hits(&self) -> usize {
+        self.hits
+    }
+
+    /// queries that had to be parsed, bound and optimized
+    pub fn cache_misses(&self) -> usize {
+        self.misses
+    }
+
+    /// number of plans currently cached
+    pub fn cached_plans(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl Default for QueryEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// None when the file is missing or the platform has no modification times
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
@@ -0,0 +1,82 @@
+use crate::binder::BinderError;
+use crate::execution::ExecutionError;
+use crate::parser::ParseError;
+use std::fmt;
+
+/// any error a query can fail with, by the stage that raised it
+#[derive(Debug, Clone)]
+pub enum CelectError {
+    Parse(ParseError),
+    Bind(BinderError),
+    Execution(ExecutionError),
+}
+
+impl CelectError {
+    /// the underlying error's message
+    pub fn message(&self) -> &str {
+        match self {
+            CelectError::Parse(e) => &e.message,
+            CelectError::Bind(e) => &e.message,
+            CelectError::Execution(e) => &e.message,
+        }
+    }
+}
+
+impl fmt::Display for CelectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for CelectError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CelectError::Parse(e) => Some(e),
+            CelectError::Bind(e) => Some(e),
+            CelectError::Execution(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseError> for CelectError {
+    fn from(error: ParseError) -> Self {
+        CelectError::Parse(error)
+    }
+}
+
+impl From<BinderError> for CelectError {
+    fn from(error: BinderError) -> Self {
+        CelectError::Bind(error)
+    }
+}
+
+impl From<ExecutionError> for CelectError {
+    fn from(error: ExecutionError) -> Self {
+        CelectError::Execution(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binder::BinderErrorKind;
+    use std::error::Error;
+
+    fn parse(sql: &str) -> Result<crate::parser::Query, Box<dyn Error>> {
+        let query = crate::Parser::new().parse(sql).map_err(CelectError::from)?;
+        Ok(query)
+    }
+
+    #[test]
+    fn test_errors_box_into_dyn_error() {
+        let err = parse("SELEC name FROM people").unwrap_err();
+        assert_eq!(err.to_string(), "Parse error: invalid SQL syntax");
+        assert!(err.source().is_some());
+
+        let boxed: Box<dyn Error> = Box::new(CelectError::from(BinderError {
+            kind: BinderErrorKind::Query,
+            message: "Column 'salary' not found in schema".to_string(),
+        }));
+        assert_eq!(boxed.to_string(), "Column 'salary' not found in schema");
+    }
+}
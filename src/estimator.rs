@@ -0,0 +1,260 @@
+use crate::binder::BoundExpression;
+use crate::compression;
+use crate::parser::LiteralValue;
+use crate::planner::{
+    LogicalAggregate, LogicalDistinct, LogicalExplain, LogicalFilter, LogicalGet, LogicalLimit,
+    LogicalOperator, LogicalProjection, LogicalSample, LogicalSort, LogicalTopN, LogicalUnion,
+};
+use std::io::Read;
+
+/// fills in `estimated_rows` on every operator of a logical plan.
+/// this reads the whole file once to count lines, so it only runs for EXPLAIN
+pub struct Estimator;
+
+impl Estimator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// annotate a plan with row estimates (operators stay None if the file can't be read)
+    pub fn estimate(&self, plan: LogicalOperator) -> LogicalOperator {
+        self.annotate(plan).0
+    }
+
+    fn annotate(&self, plan: LogicalOperator) -> (LogicalOperator, Option<usize>) {
+        match plan {
+            LogicalOperator::Get(get) => {
+                let rows = match &get.source {
+                    Some(source) => source
+                        .open()
+                        .ok()
+                        .and_then(|reader| count_rows(reader, get.has_header)),
+                    None => std::iter::once(&get.file_path)
+                        .chain(&get.union_files)
+                        .map(|path| {
+                            let file = compression::open_file(path).ok()?;
+                            count_rows(file, get.has_header)
+                        })
+                        .sum(),
+                };
+                let get = LogicalGet {
+                    estimated_rows: rows,
+                    ..get
+                };
+                (LogicalOperator::Get(get), rows)
+            }
+            LogicalOperator::Filter(filter) => {
+                let (child, child_rows) = self.annotate(*filter.child);
+                let fraction = selectivity(&filter.expression);
+                let rows = child_rows.map(|n| (n as f64 * fraction).ceil() as usize);
+                let filter = LogicalFilter {
+                    expression: filter.expression,
+                    child: Box::new(child),
+                    estimated_rows: rows,
+                };
+                (LogicalOperator::Filter(filter), rows)
+            }
+            LogicalOperator::Projection(proj) => {
+                let (child, rows) = self.annotate(*proj.child);
+                let proj = LogicalProjection {
+                    expressions: proj.expressions,
+                    aliases: proj.aliases,
+                    child: Box::new(child),
+                    estimated_rows: rows,
+                };
+                (LogicalOperator::Projection(proj), rows)
+            }
+            LogicalOperator::Limit(limit) => {
+                let (child, child_rows) = self.annotate(*limit.child);
+                let rows = child_rows.map(|n| {
+                    let remaining = n.saturating_sub(limit.offset.unwrap_or(0));
+                    limit.limit.map_or(remaining, |cap| remaining.min(cap))
+                });
+                let limit = LogicalLimit {
+                    limit: limit.limit,
+                    offset: limit.offset,
+                    child: Box::new(child),
+                    estimated_rows: rows,
+                };
+                (LogicalOperator::Limit(limit), rows)
+            }
+            LogicalOperator::Aggregate(agg) => {
+                let (child, child_rows) = self.annotate(*agg.child);
+                // one row without GROUP BY; at most one group per input row otherwise
+                let rows = if agg.groups.is_empty() {
+                    Some(1)
+                } else {
+                    child_rows
+                };
+                let agg = LogicalAggregate {
+                    groups: agg.groups,
+                    aggregates: agg.aggregates,
+                    child: Box::new(child),
+                    estimated_rows: rows,
+                };
+                (LogicalOperator::Aggregate(agg), rows)
+            }
+            LogicalOperator::Sort(sort) => {
+                // sorting keeps every row
+                let (child, rows) = self.annotate(*sort.child);
+                let sort = LogicalSort {
+                    keys: sort.keys,
+                    child: Box::new(child),
+                    estimated_rows: rows,
+                };
+                (LogicalOperator::Sort(sort), rows)
+            }
+            LogicalOperator::TopN(top_n) => {
+                let (child, child_rows) = self.annotate(*top_n.child);
+                let rows = child_rows.map(|n| n.saturating_sub(top_n.offset).min(top_n.limit));
+                let top_n = LogicalTopN {
+                    keys: top_n.keys,
+                    limit: top_n.limit,
+                    offset: top_n.offset,
+                    child: Box::new(child),
+                    estimated_rows: rows,
+                };
+                (LogicalOperator::TopN(top_n), rows)
+            }
+            LogicalOperator::Sample(sample) => {
+                let (child, child_rows) = self.annotate(*sample.child);
+                let rows = child_rows.map(|n| n.min(sample.size));
+                let sample = LogicalSample {
+                    size: sample.size,
+                    seed: sample.seed,
+                    child: Box::new(child),
+                    estimated_rows: rows,
+                };
+                (LogicalOperator::Sample(sample), rows)
+            }
+            LogicalOperator::Union(union) => {
+                // every row of every child (unknown if one child's is)
+                let (children, rows): (Vec<_>, Vec<_>) = union
+                    .children
+                    .into_iter()
+                    .map(|child| self.annotate(child))
+                    .unzip();
+                let rows = rows.into_iter().sum();
+                let union = LogicalUnion {
+                    children,
+                    estimated_rows: rows,
+                };
+                (LogicalOperator::Union(union), rows)
+            }
+            LogicalOperator::Distinct(distinct) => {
+                // at most every input row is distinct
+                let (child, rows) = self.annotate(*distinct.child);
+                let distinct = LogicalDistinct {
+                    child: Box::new(child),
+                    estimated_rows: rows,
+                };
+                (LogicalOperator::Distinct(distinct), rows)
+            }
+            LogicalOperator::Explain(explain) => {
+                let (child, rows) = self.annotate(*explain.child);
+                let explain = LogicalExplain {
+                    child: Box::new(child),
+                    analyze: explain.analyze,
+                };
+                (LogicalOperator::Explain(explain), rows)
+            }
+        }
+    }
+}
+
+impl Default for Estimator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// estimated fraction of rows a predicate keeps (fixed guesses, no statistics)
+pub fn selectivity(expression: &BoundExpression) -> f64 {
+    match expression {
+        BoundExpression::And(left, right) => selectivity(left) * selectivity(right),
+        BoundExpression::Or(left, right) => {
+            let (l, r) = (selectivity(left), selectivity(right));
+            l + r - l * r
+        }
+        BoundExpression::Not(inner) => 1.0 - selectivity(inner),
+        BoundExpression::Literal {
+            value: LiteralValue::Boolean(b),
+            ..
+        } => {
+            if *b {
+                1.0
+            } else {
+                0.0
+            }
+        }
+        BoundExpression::Equal(..) => 0.1,
+        BoundExpression::NotEqual(..) => 0.9,
+        BoundExpression::GreaterThan(..)
+        | BoundExpression::GreaterThanOrEqual(..)
+        | BoundExpression::LessThan(..)
+        | BoundExpression::LessThanOrEqual(..) => 1.0 / 3.0,
+        BoundExpression::Like(..) => 0.25,
+        BoundExpression::In(_, list) => (0.1 * list.len() as f64).min(1.0),
+        BoundExpression::IsTrue(..) | BoundExpression::IsFalse(..) => 0.5,
+        _ => 1.0,
+    }
+}
+
+/// number of data rows in CSV text: newlines counted in one buffered pass
+/// (after decompression), minus the header. quoted newlines inside fields are
+/// over-counted
+fn count_rows(mut file: impl Read, has_header: bool) -> Option<usize> {
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut lines = 0;
+    let mut last = b'\n';
+    loop {
+        let n = file.read(&mut buffer).ok()?;
+        if n == 0 {
+            break;
+        }
+        lines += buffer[..n].iter().filter(|&&b| b == b'\n').count();
+        last = buffer[n - 1];
+    }
+    // a final line without a trailing newline still counts
+    if last != b'\n' {
+        lines += 1;
+    }
+    Some(lines.saturating_sub(usize::from(has_header)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binder::ColumnType;
+
+    fn column() -> Box<BoundExpression> {
+        Box::new(BoundExpression::ColumnRef {
+            name: "age".to_string(),
+            index: 0,
+            type_: ColumnType::Integer,
+        })
+    }
+
+    fn literal(n: i64) -> Box<BoundExpression> {
+        Box::new(BoundExpression::Literal {
+            value: LiteralValue::Integer(n),
+            type_: ColumnType::Integer,
+        })
+    }
+
+    #[test]
+    fn test_selectivity_combines_predicates() {
+        let eq = BoundExpression::Equal(column(), literal(1));
+        let gt = BoundExpression::GreaterThan(column(), literal(1));
+        assert!((selectivity(&eq) - 0.1).abs() < 1e-9);
+
+        let and = BoundExpression::And(Box::new(eq.clone()), Box::new(gt.clone()));
+        assert!((selectivity(&and) - 0.1 / 3.0).abs() < 1e-9);
+
+        let or = BoundExpression::Or(Box::new(eq.clone()), Box::new(eq.clone()));
+        assert!((selectivity(&or) - 0.19).abs() < 1e-9);
+
+        let not = BoundExpression::Not(Box::new(eq));
+        assert!((selectivity(&not) - 0.9).abs() < 1e-9);
+    }
+}
@@ -1,7 +1,7 @@
 /// compact bitmap for tracking NULL values (validity)
 /// uses 1 bit per value: 1 = valid, 0 = NULL
 /// stored as u64 words for efficient operations
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct Bitmap {
     /// data stored as u64 words (64 bits each)
     words: Vec<u64>,
@@ -208,6 +208,21 @@ impl Bitmap {
     }
 }
 
+impl Clone for Bitmap {
+    fn clone(&self) -> Self {
+        Self {
+            words: self.words.clone(),
+            len: self.len,
+        }
+    }
+
+    /// copies into the existing words, without reallocating when they fit
+    fn clone_from(&mut self, source: &Self) {
+        self.words.clone_from(&source.words);
+        self.len = source.len;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
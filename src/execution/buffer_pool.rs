@@ -44,15 +44,8 @@ impl BufferPool {
         let mut pool = self.pool.lock().unwrap();
 
         if let Some(mut chunk) = pool.pop() {
-            // reuse existing chunk - reset and set up new schema
-            chunk.reset();
-            chunk.columns = column_types
-                .iter()
-                .map(|col_type| {
-                    crate::execution::data_chunk::Vector::new(col_type, self.chunk_size)
-                })
-                .collect();
-            chunk.capacity = self.chunk_size;
+            // reuse existing chunk - vectors of a matching type keep their memory
+            chunk.reset_with_types(&column_types, self.chunk_size);
             chunk
         } else {
             // pool is empty - create new chunk with schema
@@ -51,17 +51,171 @@ pub enum Value {
     Float(f64),
     Boolean(bool),
     Varchar(String),
+    Date(i32),      // days since 1970-01-01
+    Timestamp(i64), // microseconds since 1970-01-01 00:00:00
     Null,
 }
 
+/// error converting a Value to a Rust primitive (wrong type or NULL)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueError {
+    pub message: String,
+}
+
+impl Value {
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// float value; integers are widened like in numeric comparisons
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Float(f) => Some(*f),
+            Value::Integer(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Varchar(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// the type of a vector that can hold this value
+    pub fn column_type(&self) -> ColumnType {
+        match self {
+            Value::Integer(_) => ColumnType::Integer,
+            Value::Float(_) => ColumnType::Float,
+            Value::Boolean(_) => ColumnType::Boolean,
+            Value::Varchar(_) => ColumnType::Varchar,
+            Value::Date(_) => ColumnType::Date,
+            Value::Timestamp(_) => ColumnType::Timestamp,
+            Value::Null => ColumnType::Null,
+        }
+    }
+
+    /// error for a value that isn't of the requested type
+    fn mismatch(&self, expected: &str) -> ValueError {
+        ValueError {
+            message: format!("expected {}, found {:?}", expected, self),
+        }
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Integer(value)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Boolean(value)
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Varchar(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Varchar(value.to_string())
+    }
+}
+
+impl TryFrom<Value> for i64 {
+    type Error = ValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value.as_i64().ok_or_else(|| value.mismatch("Integer"))
+    }
+}
+
+impl TryFrom<Value> for f64 {
+    type Error = ValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value.as_f64().ok_or_else(|| value.mismatch("Float"))
+    }
+}
+
+impl TryFrom<Value> for bool {
+    type Error = ValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value.as_bool().ok_or_else(|| value.mismatch("Boolean"))
+    }
+}
+
+impl TryFrom<Value> for String {
+    type Error = ValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Varchar(s) => Ok(s),
+            other => Err(other.mismatch("Varchar")),
+        }
+    }
+}
+
 /// a columnar vector storing values for a single column
 /// uses separate data array + validity bitmap for efficient NULL handling
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub enum Vector {
-    Integer { data: Vec<i64>, validity: Bitmap },
-    Float { data: Vec<f64>, validity: Bitmap },
-    Boolean { data: Vec<bool>, validity: Bitmap },
-    Varchar { data: Vec<String>, validity: Bitmap },
+    Integer {
+        data: Vec<i64>,
+        validity: Bitmap,
+    },
+    Float {
+        data: Vec<f64>,
+        validity: Bitmap,
+    },
+    Boolean {
+        data: Vec<bool>,
+        validity: Bitmap,
+    },
+    Varchar {
+        data: Vec<String>,
+        validity: Bitmap,
+    },
+    Date {
+        data: Vec<i32>,
+        validity: Bitmap,
+    },
+    Timestamp {
+        data: Vec<i64>,
+        validity: Bitmap,
+    },
+    /// a column with no values at all (every row is NULL); the bitmap is all
+    /// NULL and its length is the row count
+    Null {
+        validity: Bitmap,
+    },
 }
 
 impl Vector {
@@ -84,10 +238,17 @@ impl Vector {
                 data: Vec::with_capacity(capacity),
                 validity: Bitmap::new(0),
             },
-            ColumnType::Null => Vector::Integer {
+            ColumnType::Date => Vector::Date {
+                data: Vec::with_capacity(capacity),
+                validity: Bitmap::new(0),
+            },
+            ColumnType::Timestamp => Vector::Timestamp {
                 data: Vec::with_capacity(capacity),
                 validity: Bitmap::new(0),
             },
+            ColumnType::Null => Vector::Null {
+                validity: Bitmap::new_all_null(0),
+            },
         }
     }
 
@@ -98,6 +259,9 @@ impl Vector {
             Vector::Float { data, .. } => data.len(),
             Vector::Boolean { data, .. } => data.len(),
             Vector::Varchar { data, .. } => data.len(),
+            Vector::Date { data, .. } => data.len(),
+            Vector::Timestamp { data, .. } => data.len(),
+            Vector::Null { validity } => validity.len(),
         }
     }
 
@@ -113,6 +277,9 @@ impl Vector {
             Vector::Float { validity, .. } => validity,
             Vector::Boolean { validity, .. } => validity,
             Vector::Varchar { validity, .. } => validity,
+            Vector::Date { validity, .. } => validity,
+            Vector::Timestamp { validity, .. } => validity,
+            Vector::Null { validity } => validity,
         }
     }
 
@@ -162,11 +329,54 @@ impl Vector {
                     Some(Value::Null)
                 }
             }
+            Vector::Date { data, validity } => {
+                if index >= data.len() {
+                    return None;
+                }
+                if validity.is_valid(index) {
+                    Some(Value::Date(data[index]))
+                } else {
+                    Some(Value::Null)
+                }
+            }
+            Vector::Timestamp { data, validity } => {
+                if index >= data.len() {
+                    return None;
+                }
+                if validity.is_valid(index) {
+                    Some(Value::Timestamp(data[index]))
+                } else {
+                    Some(Value::Null)
+                }
+            }
+            Vector::Null { validity } => (index < validity.len()).then_some(Value::Null),
         }
     }
 
     /// push a value to the vector
+    /// panics if the value's type doesn't match the vector (see try_push)
     pub fn push(&mut self, value: Value) {
+        if let Err(e) = self.try_push(value) {
+            panic!("Type mismatch when pushing value to vector: {}", e.message);
+        }
+    }
+
+    /// whether a value can be pushed: its own type, or NULL
+    pub fn accepts(&self, value: &Value) -> bool {
+        matches!(
+            (self, value),
+            (_, Value::Null)
+                | (Vector::Integer { .. }, Value::Integer(_))
+                | (Vector::Float { .. }, Value::Float(_))
+                | (Vector::Boolean { .. }, Value::Boolean(_))
+                | (Vector::Varchar { .. }, Value::Varchar(_))
+                | (Vector::Date { .. }, Value::Date(_))
+                | (Vector::Timestamp { .. }, Value::Timestamp(_))
+        )
+    }
+
+    /// push a value to the vector, failing if its type doesn't match
+    pub fn try_push(&mut self, value: Value) -> Result<(), ValueError> {
         match (self, value) {
             (Vector::Integer { data, validity }, Value::Integer(i)) => {
                 data.push(i);
@@ -216,8 +426,46 @@ impl Vector {
                 validity.resize(data.len());
                 validity.set_null(index);
             }
-            _ => panic!("Type mismatch when pushing value to vector"),
+            (Vector::Date { data, validity }, Value::Date(d)) => {
+                data.push(d);
+                let index = data.len() - 1;
+                validity.resize(data.len());
+                validity.set_valid(index);
+            }
+            (Vector::Date { data, validity }, Value::Null) => {
+                data.push(0); // push garbage for NULL
+                let index = data.len() - 1;
+                validity.resize(data.len());
+                validity.set_null(index);
+            }
+            (Vector::Timestamp { data, validity }, Value::Timestamp(t)) => {
+                data.push(t);
+                let index = data.len() - 1;
+                validity.resize(data.len());
+                validity.set_valid(index);
+            }
+            (Vector::Timestamp { data, validity }, Value::Null) => {
+                data.push(0); // push garbage for NULL
+                let index = data.len() - 1;
+                validity.resize(data.len());
+                validity.set_null(index);
+            }
+            (Vector::Null { validity }, Value::Null) => {
+                let index = validity.len();
+                validity.resize(index + 1);
+                validity.set_null(index);
+            }
+            (vector, value) => {
+                return Err(ValueError {
+                    message: format!(
+                        "cannot store {:?} in a {:?} column",
+                        value,
+                        vector.column_type()
+                    ),
+                });
+            }
         }
+        Ok(())
     }
 
     /// clear the vector (keep capacity)
@@ -239,6 +487,15 @@ impl Vector {
                 data.clear();
                 validity.resize(0);
             }
+            Vector::Date { data, validity } => {
+                data.clear();
+                validity.resize(0);
+            }
+            Vector::Timestamp { data, validity } => {
+                data.clear();
+                validity.resize(0);
+            }
+            Vector::Null { validity } => validity.resize(0),
         }
     }
 
@@ -249,6 +506,118 @@ impl Vector {
             Vector::Float { .. } => ColumnType::Float,
             Vector::Boolean { .. } => ColumnType::Boolean,
             Vector::Varchar { .. } => ColumnType::Varchar,
+            Vector::Date { .. } => ColumnType::Date,
+            Vector::Timestamp { .. } => ColumnType::Timestamp,
+            Vector::Null { .. } => ColumnType::Null,
+        }
+    }
+}
+
+impl Clone for Vector {
+    fn clone(&self) -> Self {
+        match self {
+            Vector::Integer { data, validity } => Vector::Integer {
+                data: data.clone(),
+                validity: validity.clone(),
+            },
+            Vector::Float { data, validity } => Vector::Float {
+                data: data.clone(),
+                validity: validity.clone(),
+            },
+            Vector::Boolean { data, validity } => Vector::Boolean {
+                data: data.clone(),
+                validity: validity.clone(),
+            },
+            Vector::Varchar { data, validity } => Vector::Varchar {
+                data: data.clone(),
+                validity: validity.clone(),
+            },
+            Vector::Date { data, validity } => Vector::Date {
+                data: data.clone(),
+                validity: validity.clone(),
+            },
+            Vector::Timestamp { data, validity } => Vector::Timestamp {
+                data: data.clone(),
+                validity: validity.clone(),
+            },
+            Vector::Null { validity } => Vector::Null {
+                validity: validity.clone(),
+            },
+        }
+    }
+
+    /// copies into this vector's memory when both have the same type
+    /// (strings reuse their buffers too), so pass-through operators don't
+    /// allocate for every chunk
+    fn clone_from(&mut self, source: &Self) {
+        match (self, source) {
+            (
+                Vector::Integer { data, validity },
+                Vector::Integer {
+                    data: source_data,
+                    validity: source_validity,
+                },
+            ) => {
+                data.clone_from(source_data);
+                validity.clone_from(source_validity);
+            }
+            (
+                Vector::Float { data, validity },
+                Vector::Float {
+                    data: source_data,
+                    validity: source_validity,
+                },
+            ) => {
+                data.clone_from(source_data);
+                validity.clone_from(source_validity);
+            }
+            (
+                Vector::Boolean { data, validity },
+                Vector::Boolean {
+                    data: source_data,
+                    validity: source_validity,
+                },
+            ) => {
+                data.clone_from(source_data);
+                validity.clone_from(source_validity);
+            }
+            (
+                Vector::Varchar { data, validity },
+                Vector::Varchar {
+                    data: source_data,
+                    validity: source_validity,
+                },
+            ) => {
+                data.clone_from(source_data);
+                validity.clone_from(source_validity);
+            }
+            (
+                Vector::Date { data, validity },
+                Vector::Date {
+                    data: source_data,
+                    validity: source_validity,
+                },
+            ) => {
+                data.clone_from(source_data);
+                validity.clone_from(source_validity);
+            }
+            (
+                Vector::Timestamp { data, validity },
+                Vector::Timestamp {
+                    data: source_data,
+                    validity: source_validity,
+                },
+            ) => {
+                data.clone_from(source_data);
+                validity.clone_from(source_validity);
+            }
+            (
+                Vector::Null { validity },
+                Vector::Null {
+                    validity: source_validity,
+                },
+            ) => validity.clone_from(source_validity),
+            (this, source) => *this = source.clone(),
         }
     }
 }
@@ -256,7 +625,7 @@ impl Vector {
 /// a batch of rows in columnar format
 /// each column is stored as a separate Vector
 /// represents a horizontal slice of a table (e.g., 2048 rows)
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct DataChunk {
     /// column vectors
     pub columns: Vec<Vector>,
@@ -267,6 +636,9 @@ pub struct DataChunk {
     /// optional selection vector for zero-copy filtering
     /// if present, only rows at sel.indices are valid
     pub selection: Option<SelectionVector>,
+    /// the part of the source the rows came from: a parallel scan numbers its
+    /// byte ranges from 0, every other source is partition 0
+    pub partition: usize,
 }
 
 impl DataChunk {
@@ -285,6 +657,7 @@ impl DataChunk {
             count: 0,
             capacity,
             selection: None,
+            partition: 0,
         }
     }
 
@@ -295,6 +668,7 @@ impl DataChunk {
             count: 0,
             capacity: 0,
             selection: None,
+            partition: 0,
         }
     }
 
@@ -306,6 +680,24 @@ impl DataChunk {
         }
         self.count = 0;
         self.selection = None;
+        self.partition = 0;
+    }
+
+    /// reset the chunk to hold rows of the given column types. vectors that
+    /// already have the right type keep their memory; the others are replaced
+    pub fn reset_with_types(&mut self, column_types: &[ColumnType], capacity: usize) {
+        self.columns.truncate(column_types.len());
+        for (i, col_type) in column_types.iter().enumerate() {
+            match self.columns.get_mut(i) {
+                Some(col) if col.column_type() == *col_type => col.clear(),
+                Some(col) => *col = Vector::new(col_type, capacity),
+                None => self.columns.push(Vector::new(col_type, capacity)),
+            }
+        }
+        self.count = 0;
+        self.capacity = capacity;
+        self.selection = None;
+        self.partition = 0;
     }
 
     /// check if the chunk is empty
@@ -347,6 +739,31 @@ impl DataChunk {
         }
     }
 
+    /// materialize all selected rows at once, row-major (one Vec<Value> per row)
+    pub fn selected_rows_as_values(&self) -> Vec<Vec<Value>> {
+        self.rows().collect()
+    }
+
+    /// iterate the selected rows, one Vec<Value> per row
+    pub fn rows(&self) -> impl Iterator<Item = Vec<Value>> + '_ {
+        (0..self.selected_count()).map(move |row_idx| {
+            (0..self.column_count())
+                .map(|col_idx| self.get_value(col_idx, row_idx).unwrap_or(Value::Null))
+                .collect()
+        })
+    }
+
+    /// iterate one column's values over the selected rows
+    /// (empty if the column doesn't exist)
+    pub fn iter_column(&self, column_idx: usize) -> impl Iterator<Item = Value> + '_ {
+        let count = if column_idx < self.column_count() {
+            self.selected_count()
+        } else {
+            0
+        };
+        (0..count).map(move |row_idx| self.get_value(column_idx, row_idx).unwrap_or(Value::Null))
+    }
+
     /// set a selection vector (for zero-copy filtering)
     pub fn set_selection(&mut self, selection: SelectionVector) {
         self.selection = Some(selection);
@@ -369,6 +786,43 @@ impl DataChunk {
         self.count += 1;
     }
 
+    /// append a row to the chunk, failing (and leaving the chunk unchanged)
+    /// if it has the wrong length or a value doesn't match its column's type
+    pub fn try_append_row(&mut self, row: Vec<Value>) -> Result<(), ValueError> {
+        if row.len() != self.columns.len() {
+            return Err(ValueError {
+                message: format!(
+                    "row has {} values, expected {}",
+                    row.len(),
+                    self.columns.len()
+                ),
+            });
+        }
+        if self.count >= self.capacity {
+            return Err(ValueError {
+                message: "DataChunk is full".to_string(),
+            });
+        }
+        // check every value first so a bad row isn't half-appended
+        for (column, value) in self.columns.iter().zip(&row) {
+            if !column.accepts(value) {
+                return Err(ValueError {
+                    message: format!(
+                        "cannot store {:?} in a {:?} column",
+                        value,
+                        column.column_type()
+                    ),
+                });
+            }
+        }
+
+        for (i, value) in row.into_iter().enumerate() {
+            self.columns[i].push(value);
+        }
+        self.count += 1;
+        Ok(())
+    }
+
     /// remove the first `n` rows by updating the selection vector
     /// used for OFFSET implementation
     pub fn apply_offset(&mut self, n: usize) {
@@ -423,3 +877,153 @@ impl DataChunk {
         }
     }
 }
+
+impl Clone for DataChunk {
+    fn clone(&self) -> Self {
+        DataChunk {
+            columns: self.columns.clone(),
+            count: self.count,
+            capacity: self.capacity,
+            selection: self.selection.clone(),
+            partition: self.partition,
+        }
+    }
+
+    /// copies into this chunk's vectors, reusing their memory
+    fn clone_from(&mut self, source: &Self) {
+        self.columns.clone_from(&source.columns);
+        self.count = source.count;
+        self.capacity = source.capacity;
+        self.selection.clone_from(&source.selection);
+        self.partition = source.partition;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selected_rows_as_values_on_filtered_chunk() {
+        let mut chunk = DataChunk::new(
+            vec![ColumnType::Integer, ColumnType::Varchar],
+            DataChunk::STANDARD_VECTOR_SIZE,
+        );
+        chunk.append_row(vec![Value::Integer(1), Value::Varchar("a".to_string())]);
+        chunk.append_row(vec![Value::Integer(2), Value::Null]);
+        chunk.append_row(vec![Value::Integer(3), Value::Varchar("c".to_string())]);
+        chunk.append_row(vec![Value::Integer(4), Value::Varchar("d".to_string())]);
+
+        // keep rows 1 and 3 only
+        let mut selection = SelectionVector::new(2);
+        selection.push(1);
+        selection.push(3);
+        chunk.set_selection(selection);
+
+        let rows = chunk.selected_rows_as_values();
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|row| row.len() == 2));
+        assert_eq!(
+            rows,
+            vec![
+                vec![Value::Integer(2), Value::Null],
+                vec![Value::Integer(4), Value::Varchar("d".to_string())],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_value_conversions_round_trip() {
+        assert_eq!(i64::try_from(Value::from(42)), Ok(42));
+        assert_eq!(f64::try_from(Value::from(2.5)), Ok(2.5));
+        assert_eq!(bool::try_from(Value::from(true)), Ok(true));
+        assert_eq!(
+            String::try_from(Value::from("abc".to_string())),
+            Ok("abc".to_string())
+        );
+        assert_eq!(Value::from("abc").as_str(), Some("abc"));
+
+        // integers widen to floats, nothing else converts
+        assert_eq!(Value::Integer(3).as_f64(), Some(3.0));
+        assert_eq!(Value::Float(3.0).as_i64(), None);
+        assert!(Value::Null.is_null());
+
+        let err = i64::try_from(Value::Null).unwrap_err();
+        assert_eq!(err.message, "expected Integer, found Null");
+        let err = String::try_from(Value::Boolean(false)).unwrap_err();
+        assert_eq!(err.message, "expected Varchar, found Boolean(false)");
+    }
+
+    #[test]
+    fn test_rows_and_iter_column_follow_selection() {
+        let mut chunk = DataChunk::new(
+            vec![ColumnType::Integer, ColumnType::Float],
+            DataChunk::STANDARD_VECTOR_SIZE,
+        );
+        for i in 0..5 {
+            chunk.append_row(vec![Value::Integer(i), Value::Float(i as f64 / 2.0)]);
+        }
+        let mut selection = SelectionVector::new(3);
+        selection.push(0);
+        selection.push(2);
+        selection.push(4);
+        chunk.set_selection(selection);
+
+        let manual: Vec<Vec<Value>> = (0..chunk.selected_count())
+            .map(|row| {
+                (0..chunk.column_count())
+                    .map(|col| chunk.get_value(col, row).unwrap())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(chunk.rows().collect::<Vec<_>>(), manual);
+
+        let ids: Vec<Value> = chunk.iter_column(0).collect();
+        assert_eq!(
+            ids,
+            vec![Value::Integer(0), Value::Integer(2), Value::Integer(4)]
+        );
+        assert_eq!(chunk.iter_column(1).nth(1), chunk.get_value(1, 1));
+        assert_eq!(chunk.iter_column(2).count(), 0);
+    }
+
+    #[test]
+    fn test_wrong_typed_values_are_errors() {
+        let mut vector = Vector::new(&ColumnType::Integer, 4);
+        assert!(vector.try_push(Value::Integer(1)).is_ok());
+        assert!(vector.try_push(Value::Null).is_ok());
+        let err = vector
+            .try_push(Value::Varchar("x".to_string()))
+            .unwrap_err();
+        assert_eq!(
+            err.message,
+            "cannot store Varchar(\"x\") in a Integer column"
+        );
+        assert_eq!(vector.len(), 2);
+
+        let mut chunk = DataChunk::new(vec![ColumnType::Integer, ColumnType::Boolean], 4);
+        assert!(
+            chunk
+                .try_append_row(vec![Value::Integer(1), Value::Boolean(true)])
+                .is_ok()
+        );
+        // the bad second value leaves the first column untouched
+        let err = chunk
+            .try_append_row(vec![Value::Integer(2), Value::Float(1.5)])
+            .unwrap_err();
+        assert_eq!(err.message, "cannot store Float(1.5) in a Boolean column");
+        let err = chunk.try_append_row(vec![Value::Integer(3)]).unwrap_err();
+        assert_eq!(err.message, "row has 1 values, expected 2");
+        assert_eq!(chunk.count, 1);
+        assert_eq!(chunk.columns[0].len(), 1);
+    }
+
+    #[test]
+    fn test_selected_rows_as_values_empty_selection() {
+        let mut chunk = DataChunk::new(vec![ColumnType::Integer], DataChunk::STANDARD_VECTOR_SIZE);
+        chunk.append_row(vec![Value::Integer(1)]);
+        chunk.set_selection(SelectionVector::new(0));
+
+        assert!(chunk.selected_rows_as_values().is_empty());
+    }
+}
@@ -1,54 +1,166 @@
 use super::buffer_pool::BufferPool;
 use super::data_chunk::DataChunk;
-use super::operators::{ExecuteResult, PhysicalOperator};
-use crate::binder::ColumnType;
+use super::operators::{ExecuteResult, ExecutionError, PhysicalOperator};
+use crate::binder::{ColumnType, Schema};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// pipeline executor that drives push-based execution
 /// coordinates data flow between physical operators
 pub struct PipelineExecutor {
     operators: Vec<Box<dyn PhysicalOperator>>,
     schemas: Vec<Vec<ColumnType>>,
+    output_schema: Schema, // names and types of the final operator's output
     buffer_pool: Arc<BufferPool>,
+    rows_scanned: usize,           // rows produced by the source operator
+    metrics: Vec<OperatorMetrics>, // one per operator, in the same order
+    progress: Option<Progress>,
+}
+
+/// what one operator did during a run (see EXPLAIN ANALYZE)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OperatorMetrics {
+    pub rows: usize,       // rows it output
+    pub elapsed: Duration, // time spent in its execute calls
+}
+
+impl OperatorMetrics {
+    /// count one execute call that started at `started` and output `rows`
+    fn record(&mut self, started: Instant, rows: usize) {
+        self.elapsed += started.elapsed();
+        self.rows += rows;
+    }
+}
+
+/// progress callback: called with the rows scanned so far every `every` source chunks
+struct Progress {
+    every: usize,
+    chunks: usize,           // source chunks since the last report
+    reported: Option<usize>, // rows scanned at the last report
+    callback: Box<dyn FnMut(usize)>,
 }
 
 impl PipelineExecutor {
     /// create a new pipeline executor with the given operators
     /// operators are in execution order: [Source, Filter, Projection, ...]
-    pub fn new(operators: Vec<Box<dyn PhysicalOperator>>, schema: Vec<Vec<ColumnType>>) -> Self {
+    pub fn new(operators: Vec<Box<dyn PhysicalOperator>>, schemas: Vec<Schema>) -> Self {
         // create buffer pool for reusing chunks during execution
         let buffer_pool = Arc::new(BufferPool::new(100, DataChunk::STANDARD_VECTOR_SIZE));
 
+        let output_schema = schemas
+            .last()
+            .cloned()
+            .unwrap_or(Schema { columns: vec![] });
         Self {
+            metrics: vec![OperatorMetrics::default(); operators.len()],
             operators,
-            schemas: schema,
+            schemas: schemas.iter().map(Schema::types).collect(),
+            output_schema,
             buffer_pool,
+            rows_scanned: 0,
+            progress: None,
         }
     }
 
+    /// call `callback` with the number of rows scanned so far after every
+    /// `every_chunks` chunks the source produces, and once more at the end
+    pub fn with_progress(
+        mut self,
+        every_chunks: usize,
+        callback: impl FnMut(usize) + 'static,
+    ) -> Self {
+        self.progress = Some(Progress {
+            every: every_chunks.max(1),
+            chunks: 0,
+            reported: None,
+            callback: Box::new(callback),
+        });
+        self
+    }
+
+    /// count a source chunk (or the end of the scan) and call the progress
+    /// callback when a report is due
+    fn report_progress(&mut self, end_of_scan: bool) {
+        let rows_scanned = self.rows_scanned;
+        let Some(progress) = self.progress.as_mut() else {
+            return;
+        };
+        let due = if end_of_scan {
+            progress.reported != Some(rows_scanned)
+        } else {
+            progress.chunks += 1;
+            progress.chunks >= progress.every
+        };
+        if due {
+            progress.chunks = 0;
+            progress.reported = Some(rows_scanned);
+            (progress.callback)(rows_scanned);
+        }
+    }
+
+    /// column names and types of the result chunks
+    pub fn output_schema(&self) -> &Schema {
+        &self.output_schema
+    }
+
     /// execute the entire pipeline and collect results
+    /// (panics if an operator fails; use try_execute to handle the error)
     pub fn execute(&mut self) -> Vec<DataChunk> {
+        self.try_execute()
+            .unwrap_or_else(|error| panic!("query failed: {}", error))
+    }
+
+    /// execute the entire pipeline, stopping at the first operator error
+    pub fn try_execute(&mut self) -> Result<Vec<DataChunk>, ExecutionError> {
+        // one output buffer per operator, reset and refilled for every chunk;
+        // only the final results are cloned
+        let mut buffers: Vec<DataChunk> = self
+            .schemas
+            .iter()
+            .map(|schema| self.buffer_pool.get_chunk_with_schema(schema.clone()))
+            .collect();
         let mut results = Vec::new();
+        let outcome = self.run(&mut buffers, &mut results);
+        for buffer in buffers {
+            self.buffer_pool.return_chunk(buffer);
+        }
+        outcome?;
+
+        self.report_progress(true);
+        Ok(results)
+    }
+
+    /// pull chunks from the source and push them through the pipeline until
+    /// the source or a downstream operator finishes
+    fn run(
+        &mut self,
+        buffers: &mut [DataChunk],
+        results: &mut Vec<DataChunk>,
+    ) -> Result<(), ExecutionError> {
         let mut source_finished = false;
 
         loop {
-            // get buffers from pool for this iteration
-            let mut buffers: Vec<DataChunk> = self
-                .schemas
-                .iter()
-                .map(|schema| self.buffer_pool.get_chunk_with_schema(schema.clone()))
-                .collect();
+            for buffer in buffers.iter_mut() {
+                buffer.reset();
+            }
 
             // source operator produces data into buffer[0]
+            let started = Instant::now();
             let result = self.operators[0].execute(&DataChunk::empty(), &mut buffers[0]);
+            self.metrics[0].record(started, buffers[0].count);
+            self.rows_scanned += buffers[0].count;
+            if buffers[0].count > 0 {
+                self.report_progress(false);
+            }
+
+            if let Some(error) = self.operators[0].take_error() {
+                return Err(error);
+            }
 
             if buffers[0].is_empty() {
                 if source_finished {
                     // already did finalization pass, break
-                    for buffer in buffers {
-                        self.buffer_pool.return_chunk(buffer);
-                    }
-                    break;
+                    return Ok(());
                 }
                 // source finished, but we need to pass empty chunk through pipeline
                 // to let aggregates finalize
@@ -56,11 +168,70 @@ impl PipelineExecutor {
             }
 
             // push through the pipeline
-            for i in 1..self.operators.len() {
+            // a downstream operator returning Finished (e.g. LIMIT reached) stops the scan early
+            let pipeline_finished = self.push_through(1, buffers, source_finished, results);
+
+            // blocking operators can fail too (a sort that cannot spill)
+            if let Some(error) = self.operators[1..]
+                .iter_mut()
+                .find_map(|op| op.take_error())
+            {
+                return Err(error);
+            }
+
+            if pipeline_finished || (result == ExecuteResult::Finished && source_finished) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// push the chunk in buffers[start - 1] through operators[start..], collecting the
+    /// final output; an operator that has more output for the same input is re-run
+    /// until it is drained. returns true if a downstream operator finished.
+    ///
+    /// operators after one that finished get an empty input, the same end-of-data
+    /// signal the source's end gives, so blocking operators (a sort above an
+    /// aggregate) can emit what they buffered
+    fn push_through(
+        &mut self,
+        start: usize,
+        buffers: &mut [DataChunk],
+        source_finished: bool,
+        results: &mut Vec<DataChunk>,
+    ) -> bool {
+        let mut start = start;
+        let mut end_of_data = source_finished;
+        let mut pipeline_finished = false;
+
+        loop {
+            let mut more_output_at = None;
+            let mut finished_at = None;
+            for i in start..self.operators.len() {
                 let (left, right) = buffers.split_at_mut(i);
                 let input = &left[i - 1];
                 let output = &mut right[0];
-                self.operators[i].execute(input, output);
+
+                // nothing to push further this round, operators only see
+                // an empty input on the (last) finalization pass
+                if input.is_empty() && (!end_of_data || more_output_at.is_some()) {
+                    for buffer in right.iter_mut() {
+                        buffer.reset();
+                    }
+                    break;
+                }
+
+                let started = Instant::now();
+                let result = self.operators[i].execute(input, output);
+                // rows keep the scan partition they came from (see DataChunk::partition)
+                output.partition = input.partition;
+                self.metrics[i].record(started, output.selected_count());
+                match result {
+                    ExecuteResult::Finished => finished_at = Some(i),
+                    ExecuteResult::HaveMoreOutput => {
+                        more_output_at.get_or_insert(i);
+                    }
+                    ExecuteResult::NeedMoreInput => {}
+                }
             }
 
             // collect final output (last buffer)
@@ -70,23 +241,62 @@ impl PipelineExecutor {
                 }
             }
 
-            // return buffers to pool
-            for buffer in buffers {
-                self.buffer_pool.return_chunk(buffer);
-            }
-
-            if result == ExecuteResult::Finished && source_finished {
-                break;
+            match (more_output_at, finished_at) {
+                // output left above the finished operator (or nothing finished)
+                (Some(i), finished) if finished.is_none_or(|f| i > f) => start = i,
+                (_, Some(f)) => {
+                    pipeline_finished = true;
+                    if f + 1 == self.operators.len() {
+                        return true;
+                    }
+                    // the operators above run again: clear what they output
+                    // for the last chunk so it isn't collected twice
+                    for buffer in &mut buffers[f..] {
+                        buffer.reset();
+                    }
+                    start = f + 1;
+                    end_of_data = true;
+                }
+                _ => return pipeline_finished,
             }
         }
+    }
+
+    /// number of rows the source operator has produced so far
+    pub fn rows_scanned(&self) -> usize {
+        self.rows_scanned
+    }
 
-        results
+    /// rows output and time spent by each operator so far, in execution
+    /// order (source first)
+    pub fn operator_metrics(&self) -> &[OperatorMetrics] {
+        &self.metrics
     }
 
-    /// reset all operators (for re-execution)
+    /// the metrics in the order EXPLAIN lists the operators: the root first,
+    /// each followed by the pipelines it runs itself
+    pub fn explain_metrics(&self) -> Vec<OperatorMetrics> {
+        self.operators
+            .iter()
+            .zip(&self.metrics)
+            .rev()
+            .flat_map(|(operator, metrics)| {
+                std::iter::once(*metrics).chain(operator.nested_metrics())
+            })
+            .collect()
+    }
+
+    /// reset all operators so the same plan can be executed again
+    /// (the scan re-opens its file on the next execute)
     pub fn reset(&mut self) {
         for op in &mut self.operators {
             op.reset();
         }
+        self.rows_scanned = 0;
+        self.metrics.fill(OperatorMetrics::default());
+        if let Some(progress) = self.progress.as_mut() {
+            progress.chunks = 0;
+            progress.reported = None;
+        }
     }
 }
@@ -0,0 +1,524 @@
+use crate::binder::{BoundExpression, ColumnType, ScalarFunction};
+use crate::collation::Collation;
+use crate::date::{MICROS_PER_DAY, format_date, format_timestamp, parse_date, parse_timestamp};
+use crate::execution::data_chunk::{DataChunk, Value};
+use crate::format::format_float;
+
+/// row-at-a-time evaluator for bound expressions, shared by the operators
+/// that need per-row values (filter predicates, computed projections,
+/// aggregate arguments)
+pub struct ExpressionEvaluator {
+    collation: Collation,
+}
+
+impl ExpressionEvaluator {
+    pub fn new(collation: Collation) -> Self {
+        Self { collation }
+    }
+
+    /// recursively evaluate an expression on a specific row
+    /// returns None when the result is unknown (NULL operand, failed arithmetic)
+    pub fn evaluate(
+        &self,
+        expr: &BoundExpression,
+        chunk: &DataChunk,
+        row_idx: usize,
+    ) -> Option<Value> {
+        match expr {
+            BoundExpression::ColumnRef { index, .. } => chunk.get_value(*index, row_idx),
+            BoundExpression::Literal { value, .. } => Some(match value {
+                crate::parser::LiteralValue::Integer(i) => Value::Integer(*i),
+                crate::parser::LiteralValue::Float(f) => Value::Float(*f),
+                crate::parser::LiteralValue::String(s) => Value::Varchar(s.clone()),
+                crate::parser::LiteralValue::Boolean(b) => Value::Boolean(*b),
+                // checked by the binder
+                crate::parser::LiteralValue::Date(text) => parse_date(text).map(Value::Date)?,
+                crate::parser::LiteralValue::Timestamp(text) => {
+                    parse_timestamp(text).map(Value::Timestamp)?
+                }
+                crate::parser::LiteralValue::Null => Value::Null,
+            }),
+            BoundExpression::Equal(left, right) => {
+                let (left_val, right_val) =
+                    self.comparison_operands(left, right, chunk, row_idx)?;
+                Some(Value::Boolean(self.compare_equal(&left_val, &right_val)))
+            }
+            BoundExpression::NotEqual(left, right) => {
+                let (left_val, right_val) =
+                    self.comparison_operands(left, right, chunk, row_idx)?;
+                Some(Value::Boolean(!self.compare_equal(&left_val, &right_val)))
+            }
+            BoundExpression::GreaterThan(left, right) => {
+                let (left_val, right_val) =
+                    self.comparison_operands(left, right, chunk, row_idx)?;
+                Some(Value::Boolean(self.compare_greater(&left_val, &right_val)))
+            }
+            BoundExpression::GreaterThanOrEqual(left, right) => {
+                let (left_val, right_val) =
+                    self.comparison_operands(left, right, chunk, row_idx)?;
+                Some(Value::Boolean(
+                    self.compare_greater_equal(&left_val, &right_val),
+                ))
+            }
+            BoundExpression::LessThan(left, right) => {
+                let (left_val, right_val) =
+                    self.comparison_operands(left, right, chunk, row_idx)?;
+                Some(Value::Boolean(self.compare_less(&left_val, &right_val)))
+            }
+            BoundExpression::LessThanOrEqual(left, right) => {
+                let (left_val, right_val) =
+                    self.comparison_operands(left, right, chunk, row_idx)?;
+                Some(Value::Boolean(
+                    self.compare_less_equal(&left_val, &right_val),
+                ))
+            }
+            BoundExpression::Like(left, right, escape) => {
+                let (left_val, right_val) =
+                    self.comparison_operands(left, right, chunk, row_idx)?;
+                Some(Value::Boolean(
+                    self.compare_like(&left_val, &right_val, *escape),
+                ))
+            }
+            BoundExpression::In(left, list) => {
+                let left_val = self.evaluate(left, chunk, row_idx)?;
+                // null IN (...) is unknown
+                if left_val == Value::Null {
+                    return None;
+                }
+
+                let mut saw_null = false;
+                for item in list {
+                    let item_val = self.evaluate(item, chunk, row_idx)?;
+                    if item_val == Value::Null {
+                        saw_null = true;
+                    } else if self.compare_equal(&left_val, &item_val) {
+                        return Some(Value::Boolean(true));
+                    }
+                }
+
+                // no match, but a NULL in the list makes the result unknown
+                if saw_null {
+                    None
+                } else {
+                    Some(Value::Boolean(false))
+                }
+            }
+            BoundExpression::Add(left, right)
+            | BoundExpression::Subtract(left, right)
+            | BoundExpression::Multiply(left, right)
+            | BoundExpression::Divide(left, right)
+            | BoundExpression::Modulo(left, right) => {
+                let left_val = self.evaluate(left, chunk, row_idx)?;
+                let right_val = self.evaluate(right, chunk, row_idx)?;
+                Self::arithmetic(expr, &left_val, &right_val)
+            }
+            BoundExpression::Concat(left, right) => {
+                // null || x is NULL
+                let left_val = self.evaluate(left, chunk, row_idx)?;
+                let right_val = self.evaluate(right, chunk, row_idx)?;
+                let mut text = Self::text_form(&left_val)?;
+                text.push_str(&Self::text_form(&right_val)?);
+                Some(Value::Varchar(text))
+            }
+            BoundExpression::Cast { expr, target } => {
+                let value = self.evaluate(expr, chunk, row_idx)?;
+                Self::cast(&value, target)
+            }
+            BoundExpression::FunctionCall {
+                function: ScalarFunction::Coalesce,
+                args,
+            } => {
+                // the first non-NULL argument; later ones aren't evaluated
+                let value = args.iter().find_map(|arg| {
+                    self.evaluate(arg, chunk, row_idx)
+                        .filter(|value| *value != Value::Null)
+                })?;
+                // an Integer argument is widened when another one is Float
+                Some(Self::widen(value, &expr.result_type()))
+            }
+            BoundExpression::Case { branches, else_ } => {
+                // an unknown condition counts as false; no match and no ELSE is NULL
+                let result = branches
+                    .iter()
+                    .find(|(condition, _)| {
+                        self.evaluate(condition, chunk, row_idx) == Some(Value::Boolean(true))
+                    })
+                    .map(|(_, result)| result)
+                    .or(else_.as_deref())?;
+                let value = self.evaluate(result, chunk, row_idx)?;
+                Some(Self::widen(value, &expr.result_type()))
+            }
+            BoundExpression::FunctionCall { function, args } => {
+                let args = args
+                    .iter()
+                    .map(|arg| self.evaluate(arg, chunk, row_idx))
+                    .collect::<Option<Vec<_>>>()?;
+                Self::call_function(*function, &args)
+            }
+            BoundExpression::Negate(inner) => match self.evaluate(inner, chunk, row_idx)? {
+                // -i64::MIN doesn't fit, so it's NULL like other overflows
+                Value::Integer(i) => i.checked_neg().map(Value::Integer),
+                Value::Float(f) => Some(Value::Float(-f)),
+                _ => None,
+            },
+            // three-valued logic: false AND x is false and true OR x is true even
+            // when x is unknown, so the right side is skipped once the left decides
+            BoundExpression::And(left, right) => {
+                let left_val = self.evaluate(left, chunk, row_idx);
+                if left_val == Some(Value::Boolean(false)) {
+                    return left_val;
+                }
+                match (left_val, self.evaluate(right, chunk, row_idx)) {
+                    (_, Some(Value::Boolean(false))) => Some(Value::Boolean(false)),
+                    (Some(Value::Boolean(true)), Some(Value::Boolean(true))) => {
+                        Some(Value::Boolean(true))
+                    }
+                    _ => None,
+                }
+            }
+            BoundExpression::Or(left, right) => {
+                let left_val = self.evaluate(left, chunk, row_idx);
+                if left_val == Some(Value::Boolean(true)) {
+                    return left_val;
+                }
+                match (left_val, self.evaluate(right, chunk, row_idx)) {
+                    (_, Some(Value::Boolean(true))) => Some(Value::Boolean(true)),
+                    (Some(Value::Boolean(false)), Some(Value::Boolean(false))) => {
+                        Some(Value::Boolean(false))
+                    }
+                    _ => None,
+                }
+            }
+            BoundExpression::Not(inner) => {
+                let val = self.evaluate(inner, chunk, row_idx)?;
+                match val {
+                    Value::Boolean(b) => Some(Value::Boolean(!b)),
+                    _ => None,
+                }
+            }
+            // never unknown: a NULL operand is neither true nor false
+            BoundExpression::IsTrue(inner) => Some(Value::Boolean(
+                self.evaluate(inner, chunk, row_idx) == Some(Value::Boolean(true)),
+            )),
+            BoundExpression::IsFalse(inner) => Some(Value::Boolean(
+                self.evaluate(inner, chunk, row_idx) == Some(Value::Boolean(false)),
+            )),
+        }
+    }
+
+    /// evaluate both sides of a comparison; a NULL on either side makes the
+    /// comparison unknown (None), so `a != b` doesn't match rows where a is NULL
+    fn comparison_operands(
+        &self,
+        left: &BoundExpression,
+        right: &BoundExpression,
+        chunk: &DataChunk,
+        row_idx: usize,
+    ) -> Option<(Value, Value)> {
+        let left_val = self.evaluate(left, chunk, row_idx)?;
+        let right_val = self.evaluate(right, chunk, row_idx)?;
+        if left_val == Value::Null || right_val == Value::Null {
+            return None;
+        }
+        Some((left_val, right_val))
+    }
+
+    /// text of a value for concatenation (None for NULL)
+    fn text_form(value: &Value) -> Option<String> {
+        match value {
+            Value::Varchar(s) => Some(s.clone()),
+            Value::Integer(i) => Some(i.to_string()),
+            Value::Float(f) => Some(format_float(*f, None)),
+            Value::Boolean(b) => Some(b.to_string()),
+            Value::Date(d) => Some(format_date(*d)),
+            Value::Timestamp(t) => Some(format_timestamp(*t)),
+            Value::Null => None,
+        }
+    }
+
+    /// convert a value for CAST. strings are parsed (surrounding whitespace
+    /// ignored), floats round to the nearest integer, booleans are 1/0, and any
+    /// non-zero number is true. anything that doesn't convert is NULL
+    fn cast(value: &Value, target: &ColumnType) -> Option<Value> {
+        match (value, target) {
+            (Value::Null, _) => None,
+            (_, ColumnType::Varchar) => Self::text_form(value).map(Value::Varchar),
+            (Value::Integer(i), ColumnType::Integer) => Some(Value::Integer(*i)),
+            (Value::Integer(i), ColumnType::Float) => Some(Value::Float(*i as f64)),
+            (Value::Integer(i), ColumnType::Boolean) => Some(Value::Boolean(*i != 0)),
+            (Value::Float(f), ColumnType::Integer) => {
+                let rounded = f.round();
+                // outside i64 (or NaN) doesn't convert
+                if rounded >= -(2f64.powi(63)) && rounded < 2f64.powi(63) {
+                    Some(Value::Integer(rounded as i64))
+                } else {
+                    None
+                }
+            }
+            (Value::Float(f), ColumnType::Float) => Some(Value::Float(*f)),
+            (Value::Float(f), ColumnType::Boolean) => Some(Value::Boolean(*f != 0.0)),
+            (Value::Boolean(b), ColumnType::Integer) => Some(Value::Integer(i64::from(*b))),
+            (Value::Boolean(b), ColumnType::Float) => Some(Value::Float(f64::from(u8::from(*b)))),
+            (Value::Boolean(b), ColumnType::Boolean) => Some(Value::Boolean(*b)),
+            (Value::Varchar(s), ColumnType::Integer) => s.trim().parse().ok().map(Value::Integer),
+            (Value::Varchar(s), ColumnType::Float) => s.trim().parse().ok().map(Value::Float),
+            (Value::Varchar(s), ColumnType::Boolean) => match s.trim().to_lowercase().as_str() {
+                "true" => Some(Value::Boolean(true)),
+                "false" => Some(Value::Boolean(false)),
+                _ => None,
+            },
+            (Value::Date(d), ColumnType::Date) => Some(Value::Date(*d)),
+            (Value::Varchar(s), ColumnType::Date) => parse_date(s).map(Value::Date),
+            // a timestamp's date is the day it falls on, a date's timestamp is midnight
+            (Value::Timestamp(t), ColumnType::Date) => {
+                Some(Value::Date(t.div_euclid(MICROS_PER_DAY) as i32))
+            }
+            (Value::Date(d), ColumnType::Timestamp) => {
+                Some(Value::Timestamp(i64::from(*d) * MICROS_PER_DAY))
+            }
+            (Value::Timestamp(t), ColumnType::Timestamp) => Some(Value::Timestamp(*t)),
+            (Value::Varchar(s), ColumnType::Timestamp) => parse_timestamp(s).map(Value::Timestamp),
+            (Value::Date(_) | Value::Timestamp(_), _)
+            | (_, ColumnType::Date | ColumnType::Timestamp | ColumnType::Null) => None,
+        }
+    }
+
+    /// an Integer value in a Float result (COALESCE, CASE) becomes a Float
+    fn widen(value: Value, result_type: &ColumnType) -> Value {
+        match (value, result_type) {
+            (Value::Integer(i), ColumnType::Float) => Value::Float(i as f64),
+            (value, _) => value,
+        }
+    }
+
+    /// apply a scalar function to its evaluated arguments (types were checked
+    /// by the binder; a NULL argument gives NULL)
+    fn call_function(function: ScalarFunction, args: &[Value]) -> Option<Value> {
+        match (function, args) {
+            (ScalarFunction::Upper, [Value::Varchar(s)]) => Some(Value::Varchar(s.to_uppercase())),
+            (ScalarFunction::Lower, [Value::Varchar(s)]) => Some(Value::Varchar(s.to_lowercase())),
+            (ScalarFunction::Length, [Value::Varchar(s)]) => {
+                Some(Value::Integer(s.chars().count() as i64))
+            }
+            (ScalarFunction::Substr, [Value::Varchar(s), Value::Integer(start)]) => {
+                Some(Value::Varchar(substring(s, *start, None)))
+            }
+            (
+                ScalarFunction::Substr,
+                [
+                    Value::Varchar(s),
+                    Value::Integer(start),
+                    Value::Integer(length),
+                ],
+            ) => Some(Value::Varchar(substring(s, *start, Some(*length)))),
+            (ScalarFunction::Trim, [Value::Varchar(s)]) => {
+                Some(Value::Varchar(s.trim().to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    /// apply the arithmetic operator of expr to two evaluated operands.
+    /// integer overflow and division (or modulo) by zero yield NULL
+    fn arithmetic(expr: &BoundExpression, left: &Value, right: &Value) -> Option<Value> {
+        match (left, right) {
+            (Value::Integer(l), Value::Integer(r)) => {
+                let result = match expr {
+                    BoundExpression::Add(_, _) => l.checked_add(*r),
+                    BoundExpression::Subtract(_, _) => l.checked_sub(*r),
+                    BoundExpression::Multiply(_, _) => l.checked_mul(*r),
+                    BoundExpression::Divide(_, _) => l.checked_div(*r),
+                    BoundExpression::Modulo(_, _) => l.checked_rem(*r),
+                    _ => None,
+                };
+                result.map(Value::Integer)
+            }
+            (Value::Integer(_) | Value::Float(_), Value::Integer(_) | Value::Float(_)) => {
+                let as_float = |v: &Value| match v {
+                    Value::Integer(i) => *i as f64,
+                    Value::Float(f) => *f,
+                    _ => unreachable!(),
+                };
+                let (l, r) = (as_float(left), as_float(right));
+                match expr {
+                    BoundExpression::Add(_, _) => Some(Value::Float(l + r)),
+                    BoundExpression::Subtract(_, _) => Some(Value::Float(l - r)),
+                    BoundExpression::Multiply(_, _) => Some(Value::Float(l * r)),
+                    BoundExpression::Divide(_, _) if r != 0.0 => Some(Value::Float(l / r)),
+                    _ => None,
+                }
+            }
+            // null or non-numeric operand
+            _ => None,
+        }
+    }
+
+    fn compare_equal(&self, left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::Integer(l), Value::Integer(r)) => l == r,
+            (Value::Float(l), Value::Float(r)) => l == r,
+            (Value::Integer(l), Value::Float(r)) => (*l as f64) == *r,
+            (Value::Float(l), Value::Integer(r)) => *l == (*r as f64),
+            (Value::Boolean(l), Value::Boolean(r)) => l == r,
+            (Value::Varchar(l), Value::Varchar(r)) => self.collation.equals(l, r),
+            (Value::Date(l), Value::Date(r)) => l == r,
+            (Value::Timestamp(l), Value::Timestamp(r)) => l == r,
+            (Value::Null, Value::Null) => true,
+            _ => false,
+        }
+    }
+
+    fn compare_greater(&self, left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::Integer(l), Value::Integer(r)) => l > r,
+            (Value::Float(l), Value::Float(r)) => l > r,
+            (Value::Integer(l), Value::Float(r)) => (*l as f64) > *r,
+            (Value::Float(l), Value::Integer(r)) => *l > (*r as f64),
+            (Value::Varchar(l), Value::Varchar(r)) => self.collation.compare(l, r).is_gt(),
+            (Value::Date(l), Value::Date(r)) => l > r,
+            (Value::Timestamp(l), Value::Timestamp(r)) => l > r,
+            _ => false,
+        }
+    }
+
+    fn compare_greater_equal(&self, left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::Integer(l), Value::Integer(r)) => l >= r,
+            (Value::Float(l), Value::Float(r)) => l >= r,
+            (Value::Integer(l), Value::Float(r)) => (*l as f64) >= *r,
+            (Value::Float(l), Value::Integer(r)) => *l >= (*r as f64),
+            (Value::Varchar(l), Value::Varchar(r)) => self.collation.compare(l, r).is_ge(),
+            (Value::Date(l), Value::Date(r)) => l >= r,
+            (Value::Timestamp(l), Value::Timestamp(r)) => l >= r,
+            _ => false,
+        }
+    }
+
+    fn compare_less(&self, left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::Integer(l), Value::Integer(r)) => l < r,
+            (Value::Float(l), Value::Float(r)) => l < r,
+            (Value::Integer(l), Value::Float(r)) => (*l as f64) < *r,
+            (Value::Float(l), Value::Integer(r)) => *l < (*r as f64),
+            (Value::Varchar(l), Value::Varchar(r)) => self.collation.compare(l, r).is_lt(),
+            (Value::Date(l), Value::Date(r)) => l < r,
+            (Value::Timestamp(l), Value::Timestamp(r)) => l < r,
+            _ => false,
+        }
+    }
+
+    fn compare_less_equal(&self, left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::Integer(l), Value::Integer(r)) => l <= r,
+            (Value::Float(l), Value::Float(r)) => l <= r,
+            (Value::Integer(l), Value::Float(r)) => (*l as f64) <= *r,
+            (Value::Float(l), Value::Integer(r)) => *l <= (*r as f64),
+            (Value::Varchar(l), Value::Varchar(r)) => self.collation.compare(l, r).is_le(),
+            (Value::Date(l), Value::Date(r)) => l <= r,
+            (Value::Timestamp(l), Value::Timestamp(r)) => l <= r,
+            _ => false,
+        }
+    }
+
+    fn compare_like(&self, left: &Value, right: &Value, escape: Option<char>) -> bool {
+        match (left, right) {
+            (Value::Varchar(text), Value::Varchar(pattern)) => Self::like_match(
+                &self.collation.key(text),
+                &self.collation.key(pattern),
+                // folded like the pattern, so a letter escape still matches
+                escape.and_then(|escape| self.collation.key(&escape.to_string()).chars().next()),
+            ),
+            _ => false,
+        }
+    }
+
+    /// match text against a LIKE pattern (% = any sequence, _ = any single character).
+    /// a character after `escape` is matched literally; a trailing escape matches itself
+    fn like_match(text: &str, pattern: &str, escape: Option<char>) -> bool {
+        let text: Vec<char> = text.chars().collect();
+        let pattern = like_pattern(pattern, escape);
+
+        let (mut t, mut p) = (0, 0);
+        // position of the last % seen and the text position it was tried at
+        let mut backtrack: Option<(usize, usize)> = None;
+
+        while t < text.len() {
+            if p < pattern.len() && pattern[p] == LikeToken::AnySequence {
+                backtrack = Some((p, t));
+                p += 1;
+            } else if p < pattern.len()
+                && (pattern[p] == LikeToken::AnyChar || pattern[p] == LikeToken::Char(text[t]))
+            {
+                t += 1;
+                p += 1;
+            } else if let Some((star_p, star_t)) = backtrack {
+                // let the last % absorb one more character
+                p = star_p + 1;
+                t = star_t + 1;
+                backtrack = Some((star_p, star_t + 1));
+            } else {
+                return false;
+            }
+        }
+
+        // remaining pattern must be all %
+        pattern[p..]
+            .iter()
+            .all(|token| *token == LikeToken::AnySequence)
+    }
+}
+
+/// one element of a LIKE pattern
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LikeToken {
+    AnySequence, // %
+    AnyChar,     // _
+    Char(char),  // itself, including an escaped % or _
+}
+
+/// split a LIKE pattern into tokens, resolving the escape character
+fn like_pattern(pattern: &str, escape: Option<char>) -> Vec<LikeToken> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            // the character after the escape, or the escape itself at the end
+            c if Some(c) == escape => LikeToken::Char(chars.next().unwrap_or(c)),
+            '%' => LikeToken::AnySequence,
+            '_' => LikeToken::AnyChar,
+            c => LikeToken::Char(c),
+        });
+    }
+    tokens
+}
+
+/// evaluate a predicate on a row held outside a DataChunk, for filtering
+/// data already in memory with WHERE semantics. column references index into
+/// `row`. three-valued: None when the result is unknown (a comparison with
+/// NULL) or the expression isn't Boolean
+pub fn evaluate_predicate(expr: &BoundExpression, row: &[Value]) -> Option<bool> {
+    let types = row.iter().map(Value::column_type).collect();
+    let mut chunk = DataChunk::new(types, 1);
+    chunk.append_row(row.to_vec());
+    ExpressionEvaluator::new(Collation::Binary)
+        .evaluate(expr, &chunk, 0)?
+        .as_bool()
+}
+
+/// SQL SUBSTR: the characters at 1-based positions start, start + 1, ... (length
+/// of them, or up to the end). positions outside the string are skipped, so
+/// SUBSTR('alice', 0, 3) is 'al' and a negative length gives ''
+fn substring(s: &str, start: i64, length: Option<i64>) -> String {
+    let end = match length {
+        Some(length) => start.saturating_add(length.max(0)),
+        None => i64::MAX,
+    };
+    let first = start.max(1);
+    if end <= first {
+        return String::new();
+    }
+    s.chars()
+        .skip((first - 1) as usize)
+        .take((end - first).try_into().unwrap_or(usize::MAX))
+        .collect()
+}
@@ -2,13 +2,16 @@ pub mod bitmap;
 pub mod buffer_pool;
 pub mod data_chunk;
 pub mod executor;
+pub mod expression;
 pub mod operators;
 pub mod physical_planner;
 
 pub use bitmap::Bitmap;
-pub use data_chunk::{DataChunk, SelectionVector, Value, Vector};
-pub use executor::PipelineExecutor;
+pub use data_chunk::{DataChunk, SelectionVector, Value, ValueError, Vector};
+pub use executor::{OperatorMetrics, PipelineExecutor};
+pub use expression::evaluate_predicate;
 pub use operators::{
-    ExecuteResult, PhysicalFilter, PhysicalOperator, PhysicalProjection, PhysicalScan,
+    ExecuteResult, ExecutionError, PhysicalDistinct, PhysicalFilter, PhysicalOperator,
+    PhysicalProjection, PhysicalScan, PhysicalUnionAll, PhysicalUnionScan,
 };
 pub use physical_planner::PhysicalPlanner;
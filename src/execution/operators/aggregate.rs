@@ -1,22 +1,230 @@
 use super::{ExecuteResult, PhysicalOperator};
-use crate::binder::{BoundAggregateExpression, ColumnType};
+use crate::binder::{BoundAggregateExpression, BoundExpression, ColumnType};
+use crate::collation::Collation;
 use crate::execution::data_chunk::{DataChunk, Value};
+use crate::execution::expression::ExpressionEvaluator;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+/// compensated (Kahan-Babuska) float sum: the compensation term keeps the
+/// low-order bits a plain running sum loses, so a long SUM stays close to the
+/// exact total
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct CompensatedSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl CompensatedSum {
+    fn add(&mut self, value: f64) {
+        let total = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - total) + value;
+        } else {
+            self.compensation += (value - total) + self.sum;
+        }
+        self.sum = total;
+    }
+
+    fn value(&self) -> f64 {
+        self.sum + self.compensation
+    }
+
+    fn merge(&mut self, other: &CompensatedSum) {
+        self.add(other.sum);
+        self.compensation += other.compensation;
+    }
+}
+
+/// running state of a single aggregate
+#[derive(Debug, Clone, PartialEq)]
+enum AggregateState {
+    Count(i64),
+    SumInteger(Option<i64>),
+    SumFloat(Option<CompensatedSum>),
+    Min(Option<Value>),
+    Max(Option<Value>),
+    Avg { sum: CompensatedSum, count: i64 },
+}
+
+impl AggregateState {
+    fn new(aggregate: &BoundAggregateExpression) -> Self {
+        match aggregate {
+            BoundAggregateExpression::CountStar | BoundAggregateExpression::Count { .. } => {
+                AggregateState::Count(0)
+            }
+            BoundAggregateExpression::Sum { .. } => match aggregate.result_type() {
+                ColumnType::Float => AggregateState::SumFloat(None),
+                _ => AggregateState::SumInteger(None),
+            },
+            BoundAggregateExpression::Min { .. } => AggregateState::Min(None),
+            BoundAggregateExpression::Max { .. } => AggregateState::Max(None),
+            BoundAggregateExpression::Avg { .. } => AggregateState::Avg {
+                sum: CompensatedSum::default(),
+                count: 0,
+            },
+        }
+    }
+
+    /// fold one non-NULL argument value into the state
+    fn update(&mut self, value: Value) {
+        match self {
+            AggregateState::Count(count) => *count += 1,
+            AggregateState::SumInteger(sum) => {
+                if let Value::Integer(i) = value {
+                    *sum = Some(sum.unwrap_or(0).saturating_add(i));
+                }
+            }
+            AggregateState::SumFloat(sum) => {
+                if let Some(f) = Self::as_float(&value) {
+                    sum.get_or_insert_with(CompensatedSum::default).add(f);
+                }
+            }
+            AggregateState::Min(current) => {
+                if current
+                    .as_ref()
+                    .is_none_or(|c| Self::compare(&value, c) == Ordering::Less)
+                {
+                    *current = Some(value);
+                }
+            }
+            AggregateState::Max(current) => {
+                if current
+                    .as_ref()
+                    .is_none_or(|c| Self::compare(&value, c) == Ordering::Greater)
+                {
+                    *current = Some(value);
+                }
+            }
+            AggregateState::Avg { sum, count } => {
+                if let Some(f) = Self::as_float(&value) {
+                    sum.add(f);
+                    *count += 1;
+                }
+            }
+        }
+    }
+
+    /// fold in another partition's state of the same aggregate
+    fn merge(&mut self, other: &AggregateState) {
+        if let AggregateState::Min(Some(value)) | AggregateState::Max(Some(value)) = other {
+            self.update(value.clone());
+            return;
+        }
+        match (self, other) {
+            (AggregateState::Count(count), AggregateState::Count(other)) => *count += other,
+            (AggregateState::SumInteger(sum), AggregateState::SumInteger(Some(other))) => {
+                *sum = Some(sum.unwrap_or(0).saturating_add(*other));
+            }
+            (AggregateState::SumFloat(sum), AggregateState::SumFloat(Some(other))) => {
+                sum.get_or_insert_with(CompensatedSum::default).merge(other);
+            }
+            (
+                AggregateState::Avg { sum, count },
+                AggregateState::Avg {
+                    sum: other_sum,
+                    count: other_count,
+                },
+            ) => {
+                sum.merge(other_sum);
+                *count += other_count;
+            }
+            _ => {}
+        }
+    }
+
+    /// final value (NULL when no non-NULL input was seen, except for COUNT)
+    fn finalize(&self) -> Value {
+        match self {
+            AggregateState::Count(count) => Value::Integer(*count),
+            AggregateState::SumInteger(sum) => sum.map_or(Value::Null, Value::Integer),
+            AggregateState::SumFloat(sum) => sum.map_or(Value::Null, |s| Value::Float(s.value())),
+            AggregateState::Min(value) | AggregateState::Max(value) => {
+                value.clone().unwrap_or(Value::Null)
+            }
+            AggregateState::Avg { sum, count } => {
+                if *count == 0 {
+                    Value::Null
+                } else {
+                    Value::Float(sum.value() / *count as f64)
+                }
+            }
+        }
+    }
+
+    fn as_float(value: &Value) -> Option<f64> {
+        match value {
+            Value::Integer(i) => Some(*i as f64),
+            Value::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    /// ordering for MIN/MAX; both values come from the same expression
+    fn compare(left: &Value, right: &Value) -> Ordering {
+        match (left, right) {
+            (Value::Integer(l), Value::Integer(r)) => l.cmp(r),
+            (Value::Varchar(l), Value::Varchar(r)) => l.cmp(r),
+            (Value::Boolean(l), Value::Boolean(r)) => l.cmp(r),
+            (Value::Date(l), Value::Date(r)) => l.cmp(r),
+            (Value::Timestamp(l), Value::Timestamp(r)) => l.cmp(r),
+            _ => match (Self::as_float(left), Self::as_float(right)) {
+                (Some(l), Some(r)) => l.partial_cmp(&r).unwrap_or(Ordering::Equal),
+                _ => Ordering::Equal,
+            },
+        }
+    }
+}
+
+/// aggregate states kept per scan partition (see DataChunk::partition) and
+/// merged in partition order at the end. a parallel scan's workers deliver
+/// chunks in whatever order they finish, but each partition's chunks arrive in
+/// file order, so a float SUM adds the same values in the same order every run
+#[derive(Debug, Clone, Default)]
+struct PartitionedStates {
+    partitions: BTreeMap<usize, Vec<AggregateState>>,
+}
+
+impl PartitionedStates {
+    /// the states of one partition, created on its first row
+    fn partition(
+        &mut self,
+        partition: usize,
+        aggregates: &[BoundAggregateExpression],
+    ) -> &mut Vec<AggregateState> {
+        self.partitions
+            .entry(partition)
+            .or_insert_with(|| aggregates.iter().map(AggregateState::new).collect())
+    }
+
+    /// final value of every aggregate
+    fn finalize(&self, aggregates: &[BoundAggregateExpression]) -> Vec<Value> {
+        let mut merged: Vec<AggregateState> = aggregates.iter().map(AggregateState::new).collect();
+        for states in self.partitions.values() {
+            for (state, partial) in merged.iter_mut().zip(states) {
+                state.merge(partial);
+            }
+        }
+        merged.iter().map(AggregateState::finalize).collect()
+    }
+}
 
 /// physical operator for ungrouped aggregation (e.g., SELECT COUNT(*) FROM table)
 /// consumes all input rows and produces a single output row with aggregate results
 pub struct PhysicalUngroupedAggregate {
     aggregates: Vec<BoundAggregateExpression>,
-    states: Vec<i64>, // one counter per aggregate
+    states: PartitionedStates, // one state per aggregate and partition
+    evaluator: ExpressionEvaluator,
     finished: bool,
     has_emitted: bool, // track if we've already emitted the result
 }
 
 impl PhysicalUngroupedAggregate {
     pub fn new(aggregates: Vec<BoundAggregateExpression>) -> Self {
-        let num_aggregates = aggregates.len();
         Self {
             aggregates,
-            states: vec![0; num_aggregates],
+            states: PartitionedStates::default(),
+            evaluator: ExpressionEvaluator::new(Collation::Binary),
             finished: false,
             has_emitted: false,
         }
@@ -24,47 +232,38 @@ impl PhysicalUngroupedAggregate {
 
     /// update aggregate states with a new chunk of data
     fn update_states(&mut self, chunk: &DataChunk) {
-        for (i, aggregate) in self.aggregates.iter().enumerate() {
-            match aggregate {
-                BoundAggregateExpression::CountStar => {
+        let states = self.states.partition(chunk.partition, &self.aggregates);
+        for (aggregate, state) in self.aggregates.iter().zip(states.iter_mut()) {
+            match aggregate.expression() {
+                None => {
                     // count(*): just count selected rows
-                    self.states[i] += chunk.selected_count() as i64;
+                    if let AggregateState::Count(count) = state {
+                        *count += chunk.selected_count() as i64;
+                    }
                 }
-                BoundAggregateExpression::Count { column } => {
-                    // count(column): count non-NULL values
-                    let column_idx = column.index;
-
-                    // get the vector for this column
-                    if column_idx >= chunk.column_count() {
-                        continue; // column not in chunk, skip
+                Some(expression) => {
+                    // evaluate the argument per selected row, skipping NULLs
+                    for row_idx in 0..chunk.selected_count() {
+                        match self.evaluator.evaluate(expression, chunk, row_idx) {
+                            None | Some(Value::Null) => {}
+                            Some(value) => state.update(value),
+                        }
                     }
-
-                    let vector = &chunk.columns[column_idx];
-                    let validity = vector.validity();
-
-                    // count non-NULL values using the validity bitmap
-                    let count = validity.count_valid(chunk.selected_count());
-
-                    self.states[i] += count as i64;
                 }
             }
         }
     }
 
-    /// emit the final aggregate results as a single-row DataChunk
-    fn emit_result(&self) -> DataChunk {
-        // create output schema: one INTEGER column per aggregate
-        let output_types = vec![ColumnType::Integer; self.aggregates.len()];
-        let mut output_chunk = DataChunk::new(output_types, 1);
+    /// write the final aggregate results to the output as a single row
+    fn emit_result(&self, output: &mut DataChunk) {
+        // one column per aggregate, typed by the aggregate's result type
+        let output_types: Vec<_> = self.aggregates.iter().map(|a| a.result_type()).collect();
+        output.reset_with_types(&output_types, 1);
 
         // create a single row with all aggregate results
-        let mut row = Vec::new();
-        for &state in &self.states {
-            row.push(Value::Integer(state));
-        }
+        let row = self.states.finalize(&self.aggregates);
 
-        output_chunk.append_row(row);
-        output_chunk
+        output.append_row(row);
     }
 }
 
@@ -84,8 +283,7 @@ impl PhysicalOperator for PhysicalUngroupedAggregate {
 
         // if input is empty, we're at the end of data - emit result
         if input.is_empty() {
-            let result = self.emit_result();
-            *output = result;
+            self.emit_result(output);
             self.has_emitted = true;
             self.finished = true; // mark as finished after emitting
             return ExecuteResult::Finished;
@@ -100,16 +298,176 @@ impl PhysicalOperator for PhysicalUngroupedAggregate {
     }
 
     fn reset(&mut self) {
-        self.states.fill(0);
+        self.states = PartitionedStates::default();
         self.finished = false;
         self.has_emitted = false;
     }
 }
 
+/// hashable form of a grouping value (floats are keyed by their bits)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) enum GroupKey {
+    Integer(i64),
+    Float(u64),
+    Boolean(bool),
+    Varchar(String),
+    Date(i32),
+    Timestamp(i64),
+    Null,
+}
+
+impl From<&Value> for GroupKey {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Integer(i) => GroupKey::Integer(*i),
+            Value::Float(f) => GroupKey::Float(f.to_bits()),
+            Value::Boolean(b) => GroupKey::Boolean(*b),
+            Value::Varchar(s) => GroupKey::Varchar(s.clone()),
+            Value::Date(d) => GroupKey::Date(*d),
+            Value::Timestamp(t) => GroupKey::Timestamp(*t),
+            Value::Null => GroupKey::Null,
+        }
+    }
+}
+
+/// physical operator for grouped aggregation (GROUP BY)
+/// consumes all input rows and produces one row per group:
+/// the group values followed by the aggregate results
+pub struct PhysicalHashAggregate {
+    groups: Vec<BoundExpression>,
+    aggregates: Vec<BoundAggregateExpression>,
+    group_index: HashMap<Vec<GroupKey>, usize>, // group key -> position in `entries`
+    entries: Vec<(Vec<Value>, PartitionedStates)>, // groups in first-seen order
+    evaluator: ExpressionEvaluator,
+    emitted: usize, // groups already written to the output
+    finished: bool,
+}
+
+impl PhysicalHashAggregate {
+    pub fn new(groups: Vec<BoundExpression>, aggregates: Vec<BoundAggregateExpression>) -> Self {
+        Self {
+            groups,
+            aggregates,
+            group_index: HashMap::new(),
+            entries: Vec::new(),
+            evaluator: ExpressionEvaluator::new(Collation::Binary),
+            emitted: 0,
+            finished: false,
+        }
+    }
+
+    /// route every selected row to its group and update that group's states
+    fn update_groups(&mut self, chunk: &DataChunk) {
+        for row_idx in 0..chunk.selected_count() {
+            let values: Vec<Value> = self
+                .groups
+                .iter()
+                .map(|group| {
+                    self.evaluator
+                        .evaluate(group, chunk, row_idx)
+                        .unwrap_or(Value::Null)
+                })
+                .collect();
+            let key: Vec<GroupKey> = values.iter().map(GroupKey::from).collect();
+
+            let position = match self.group_index.get(&key) {
+                Some(position) => *position,
+                None => {
+                    self.entries.push((values, PartitionedStates::default()));
+                    self.group_index.insert(key, self.entries.len() - 1);
+                    self.entries.len() - 1
+                }
+            };
+
+            let states = self.entries[position]
+                .1
+                .partition(chunk.partition, &self.aggregates);
+            for (aggregate, state) in self.aggregates.iter().zip(states.iter_mut()) {
+                match aggregate.expression() {
+                    None => {
+                        if let AggregateState::Count(count) = state {
+                            *count += 1;
+                        }
+                    }
+                    Some(expression) => match self.evaluator.evaluate(expression, chunk, row_idx) {
+                        None | Some(Value::Null) => {}
+                        Some(value) => state.update(value),
+                    },
+                }
+            }
+        }
+    }
+
+    /// write the next batch of finished groups to the output
+    fn emit_batch(&mut self, output: &mut DataChunk) {
+        let output_types = self
+            .groups
+            .iter()
+            .map(|g| g.result_type())
+            .chain(self.aggregates.iter().map(|a| a.result_type()))
+            .collect::<Vec<_>>();
+        let batch_end = (self.emitted + DataChunk::STANDARD_VECTOR_SIZE).min(self.entries.len());
+        output.reset_with_types(&output_types, batch_end - self.emitted);
+
+        for (values, states) in &self.entries[self.emitted..batch_end] {
+            let mut row = values.clone();
+            row.extend(states.finalize(&self.aggregates));
+            output.append_row(row);
+        }
+
+        self.emitted = batch_end;
+    }
+}
+
+impl PhysicalOperator for PhysicalHashAggregate {
+    fn execute(&mut self, input: &DataChunk, output: &mut DataChunk) -> ExecuteResult {
+        if self.finished {
+            output.reset();
+            return ExecuteResult::Finished;
+        }
+
+        // keep consuming input until the end of data
+        if !input.is_empty() {
+            self.update_groups(input);
+            output.reset();
+            return ExecuteResult::NeedMoreInput;
+        }
+
+        // end of data - emit the groups one vector at a time
+        if self.entries.is_empty() {
+            output.reset();
+            self.finished = true;
+            return ExecuteResult::Finished;
+        }
+
+        self.emit_batch(output);
+        if self.emitted < self.entries.len() {
+            ExecuteResult::HaveMoreOutput
+        } else {
+            self.finished = true;
+            ExecuteResult::Finished
+        }
+    }
+
+    fn reset(&mut self) {
+        self.group_index.clear();
+        self.entries.clear();
+        self.emitted = 0;
+        self.finished = false;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::binder::Column;
+
+    fn column_ref(index: usize, type_: ColumnType) -> BoundExpression {
+        BoundExpression::ColumnRef {
+            name: "test".to_string(),
+            index,
+            type_,
+        }
+    }
 
     fn create_test_chunk(rows: usize, has_nulls: bool) -> DataChunk {
         let mut chunk = DataChunk::new(vec![ColumnType::Integer], DataChunk::STANDARD_VECTOR_SIZE);
@@ -151,12 +509,8 @@ mod tests {
 
     #[test]
     fn test_count_column_with_nulls() {
-        let column = Column {
-            name: "test".to_string(),
-            type_: ColumnType::Integer,
-            index: 0,
-        };
-        let aggregates = vec![BoundAggregateExpression::Count { column }];
+        let expression = column_ref(0, ColumnType::Integer);
+        let aggregates = vec![BoundAggregateExpression::Count { expression }];
         let mut agg_op = PhysicalUngroupedAggregate::new(aggregates);
 
         // process chunk with NULL values (every other row is NULL)
@@ -175,14 +529,10 @@ mod tests {
 
     #[test]
     fn test_multiple_aggregates() {
-        let column = Column {
-            name: "test".to_string(),
-            type_: ColumnType::Integer,
-            index: 0,
-        };
+        let expression = column_ref(0, ColumnType::Integer);
         let aggregates = vec![
             BoundAggregateExpression::CountStar,
-            BoundAggregateExpression::Count { column },
+            BoundAggregateExpression::Count { expression },
         ];
         let mut agg_op = PhysicalUngroupedAggregate::new(aggregates);
 
@@ -199,4 +549,98 @@ mod tests {
         assert_eq!(output.get_value(0, 0), Some(Value::Integer(10))); // count(*) = 10
         assert_eq!(output.get_value(1, 0), Some(Value::Integer(5))); // count(col) = 5
     }
+
+    #[test]
+    fn test_sum_over_expression() {
+        let mut chunk = DataChunk::new(
+            vec![ColumnType::Integer, ColumnType::Float],
+            DataChunk::STANDARD_VECTOR_SIZE,
+        );
+        chunk.append_row(vec![Value::Integer(2), Value::Float(1.5)]);
+        chunk.append_row(vec![Value::Integer(4), Value::Null]);
+        chunk.append_row(vec![Value::Integer(3), Value::Float(2.0)]);
+
+        // sum(qty * price), where the NULL price row is skipped
+        let product = BoundExpression::Multiply(
+            Box::new(column_ref(0, ColumnType::Integer)),
+            Box::new(column_ref(1, ColumnType::Float)),
+        );
+        let aggregates = vec![
+            BoundAggregateExpression::Sum {
+                expression: product,
+            },
+            BoundAggregateExpression::Max {
+                expression: column_ref(0, ColumnType::Integer),
+            },
+        ];
+        let mut agg_op = PhysicalUngroupedAggregate::new(aggregates);
+
+        let mut output = DataChunk::empty();
+        agg_op.execute(&chunk, &mut output);
+        let result = agg_op.execute(&DataChunk::empty(), &mut output);
+        assert_eq!(result, ExecuteResult::Finished);
+        assert_eq!(output.get_value(0, 0), Some(Value::Float(9.0)));
+        assert_eq!(output.get_value(1, 0), Some(Value::Integer(4)));
+    }
+
+    #[test]
+    fn test_compensated_sum_keeps_small_values() {
+        let values = [0.1, 1e16, 0.2, -1e16, 0.3, 3.7, 1e-3];
+
+        let mut compensated = CompensatedSum::default();
+        values.iter().for_each(|v| compensated.add(*v));
+        let plain: f64 = values.iter().sum();
+
+        // a plain running sum loses the small values next to 1e16
+        assert!((compensated.value() - 4.301).abs() < 1e-12);
+        assert!((plain - 4.301).abs() > 0.1);
+    }
+
+    #[test]
+    fn test_float_sum_merges_partitions_in_order() {
+        // two partitions' chunks, delivered in different interleavings
+        let chunk = |partition: usize, values: &[f64]| {
+            let mut chunk = DataChunk::new(vec![ColumnType::Float], values.len());
+            for value in values {
+                chunk.append_row(vec![Value::Float(*value)]);
+            }
+            chunk.partition = partition;
+            chunk
+        };
+        let first = [chunk(0, &[0.1, 1e17]), chunk(0, &[0.7, -3.3])];
+        let second = [chunk(1, &[-1e17, 0.2]), chunk(1, &[1e-9, 5.5])];
+        let arrivals = [
+            [&first[0], &first[1], &second[0], &second[1]],
+            [&second[0], &first[0], &second[1], &first[1]],
+            [&second[0], &second[1], &first[0], &first[1]],
+        ];
+
+        let sums: Vec<Value> = arrivals
+            .iter()
+            .map(|chunks| {
+                let expression = column_ref(0, ColumnType::Float);
+                let mut agg_op =
+                    PhysicalUngroupedAggregate::new(vec![BoundAggregateExpression::Sum {
+                        expression,
+                    }]);
+                let mut output = DataChunk::empty();
+                for chunk in chunks {
+                    agg_op.execute(chunk, &mut output);
+                }
+                agg_op.execute(&DataChunk::empty(), &mut output);
+                output.get_value(0, 0).unwrap()
+            })
+            .collect();
+
+        // the same bits whichever partition's chunks came first
+        let Value::Float(sum) = sums[0] else {
+            panic!("expected a float sum, got {:?}", sums[0]);
+        };
+        for other in &sums {
+            let Value::Float(other) = other else {
+                panic!("expected a float sum, got {:?}", other);
+            };
+            assert_eq!(other.to_bits(), sum.to_bits(), "{:?}", sums);
+        }
+    }
 }
@@ -0,0 +1,43 @@
+use super::aggregate::GroupKey;
+use super::{ExecuteResult, PhysicalOperator};
+use crate::binder::ColumnType;
+use crate::execution::data_chunk::{DataChunk, Value};
+use std::collections::HashSet;
+
+/// physical operator for UNION (without ALL)
+/// passes on the first occurrence of each row and drops the rest; rows are
+/// remembered across chunks, so duplicates are found wherever they are
+pub struct PhysicalDistinct {
+    types: Vec<ColumnType>,
+    seen: HashSet<Vec<GroupKey>>, // every row emitted so far
+}
+
+impl PhysicalDistinct {
+    pub fn new(types: Vec<ColumnType>) -> Self {
+        Self {
+            types,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl PhysicalOperator for PhysicalDistinct {
+    fn execute(&mut self, input: &DataChunk, output: &mut DataChunk) -> ExecuteResult {
+        let row_count = input.selected_count();
+        output.reset_with_types(&self.types, row_count);
+        for row_idx in 0..row_count {
+            let row: Vec<Value> = (0..self.types.len())
+                .map(|column| input.get_value(column, row_idx).unwrap_or(Value::Null))
+                .collect();
+            if self.seen.insert(row.iter().map(GroupKey::from).collect()) {
+                output.append_row(row);
+            }
+        }
+
+        ExecuteResult::NeedMoreInput
+    }
+
+    fn reset(&mut self) {
+        self.seen.clear();
+    }
+}
@@ -0,0 +1,90 @@
+use super::{ExecuteResult, ExecutionError, PhysicalOperator};
+use crate::binder::ColumnType;
+use crate::execution::data_chunk::{DataChunk, Value};
+use crate::execution::executor::PipelineExecutor;
+
+/// source operator for EXPLAIN
+/// emits the plan description as a single Varchar row instead of reading data
+pub struct PhysicalExplain {
+    plan: String,
+    analyzed: Option<PipelineExecutor>, // EXPLAIN ANALYZE: the plan, run before describing it
+    emitted: bool,
+    error: Option<ExecutionError>,
+}
+
+impl PhysicalExplain {
+    pub fn new(plan: String) -> Self {
+        Self {
+            plan,
+            analyzed: None,
+            emitted: false,
+            error: None,
+        }
+    }
+
+    /// EXPLAIN ANALYZE: run `executor` (the pipeline `plan` describes) and
+    /// append to each line of the plan the rows that operator output and the
+    /// time it took. the plan lists the root first, the pipeline the source
+    pub fn analyze(plan: String, executor: PipelineExecutor) -> Self {
+        Self {
+            analyzed: Some(executor),
+            ..Self::new(plan)
+        }
+    }
+
+    /// the plan text, annotated with the metrics of the run when analyzing
+    fn describe(&mut self) -> Result<String, ExecutionError> {
+        let Some(executor) = self.analyzed.as_mut() else {
+            return Ok(self.plan.clone());
+        };
+        executor.try_execute()?;
+        let lines: Vec<String> = self
+            .plan
+            .lines()
+            .zip(executor.explain_metrics())
+            .map(|(line, metrics)| {
+                format!(
+                    "{} rows={} time={:.3}ms",
+                    line,
+                    metrics.rows,
+                    metrics.elapsed.as_secs_f64() * 1000.0
+                )
+            })
+            .collect();
+        Ok(lines.join("\n"))
+    }
+}
+
+impl PhysicalOperator for PhysicalExplain {
+    fn execute(&mut self, _input: &DataChunk, output: &mut DataChunk) -> ExecuteResult {
+        if self.emitted {
+            output.reset();
+            return ExecuteResult::Finished;
+        }
+        self.emitted = true;
+
+        let plan = match self.describe() {
+            Ok(plan) => plan,
+            Err(error) => {
+                self.error = Some(error);
+                output.reset();
+                return ExecuteResult::Finished;
+            }
+        };
+        output.reset_with_types(&[ColumnType::Varchar], 1);
+        output.append_row(vec![Value::Varchar(plan)]);
+        ExecuteResult::Finished
+    }
+
+    fn take_error(&mut self) -> Option<ExecutionError> {
+        self.error.take()
+    }
+
+    fn reset(&mut self) {
+        self.emitted = false;
+        self.error = None;
+        if let Some(executor) = self.analyzed.as_mut() {
+            executor.reset();
+        }
+    }
+}
@@ -28,17 +28,20 @@ impl PhysicalOperator for PhysicalLimit {
         // if we've already hit the limit, we're done
         if let Some(limit) = self.limit {
             if self.rows_emitted >= limit {
+                output.reset();
                 return ExecuteResult::Finished;
             }
         }
 
         // if input is empty, we're done
         if input.is_empty() {
+            output.reset();
             return ExecuteResult::Finished;
         }
 
-        // clone input to output first (we'll apply offset/limit via selection vector)
-        *output = input.clone();
+        // clone input to output first (we'll apply offset/limit via selection vector),
+        // reusing the output's vectors
+        output.clone_from(input);
 
         // apply OFFSET (skip rows)
         if self.offset_remaining > 0 {
@@ -86,7 +89,7 @@ impl PhysicalOperator for PhysicalLimit {
 mod tests {
     use super::*;
     use crate::binder::ColumnType;
-    use crate::execution::data_chunk::{DataChunk, Value};
+    use crate::execution::data_chunk::{DataChunk, SelectionVector, Value};
 
     fn create_test_chunk(rows: Vec<i64>) -> DataChunk {
         let mut chunk = DataChunk::new(vec![ColumnType::Integer], DataChunk::STANDARD_VECTOR_SIZE);
@@ -184,4 +187,52 @@ mod tests {
         assert_eq!(output3.selected_count(), 3); // return 3 to hit limit
         assert_eq!(result3, ExecuteResult::Finished); // hit limit of 5
     }
+
+    #[test]
+    fn test_window_across_small_chunks() {
+        // LIMIT 3 OFFSET 2 over rows 0..10 fed as 2-row chunks keeps rows 2, 3, 4
+        let mut limit_op = PhysicalLimit::new(Some(3), Some(2));
+        let mut emitted = Vec::new();
+        let mut finished = false;
+
+        for pair in (0..10).collect::<Vec<i64>>().chunks(2) {
+            let mut input = DataChunk::new(vec![ColumnType::Integer], 2);
+            for &val in pair {
+                input.append_row(vec![Value::Integer(val)]);
+            }
+            let mut output = DataChunk::new(vec![ColumnType::Integer], 2);
+            let result = limit_op.execute(&input, &mut output);
+            emitted.extend(output.iter_column(0));
+            if result == ExecuteResult::Finished {
+                finished = true;
+                break;
+            }
+        }
+
+        assert!(finished);
+        assert_eq!(
+            emitted,
+            vec![Value::Integer(2), Value::Integer(3), Value::Integer(4)]
+        );
+    }
+
+    #[test]
+    fn test_offset_counts_only_selected_rows() {
+        // a filter upstream left rows 1, 3, 5 selected; OFFSET 1 LIMIT 1 keeps row 3
+        let mut input = create_test_chunk(vec![0, 1, 2, 3, 4, 5]);
+        let mut selection = SelectionVector::new(3);
+        for row in [1, 3, 5] {
+            selection.push(row);
+        }
+        input.set_selection(selection);
+        let mut output = DataChunk::new(vec![ColumnType::Integer], DataChunk::STANDARD_VECTOR_SIZE);
+
+        let mut limit_op = PhysicalLimit::new(Some(1), Some(1));
+        let result = limit_op.execute(&input, &mut output);
+        assert_eq!(result, ExecuteResult::Finished);
+        assert_eq!(
+            output.iter_column(0).collect::<Vec<_>>(),
+            vec![Value::Integer(3)]
+        );
+    }
 }
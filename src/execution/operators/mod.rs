@@ -1,26 +1,58 @@
 mod aggregate;
+mod distinct;
+mod explain;
 mod filter;
 mod limit;
 mod projection;
+mod sample;
 mod scan;
+mod sort;
+mod top_n;
+mod union_all;
+mod union_scan;
 
-pub use aggregate::PhysicalUngroupedAggregate;
+pub use aggregate::{PhysicalHashAggregate, PhysicalUngroupedAggregate};
+pub use distinct::PhysicalDistinct;
+pub use explain::PhysicalExplain;
 pub use filter::PhysicalFilter;
 pub use limit::PhysicalLimit;
 pub use projection::PhysicalProjection;
+pub use sample::PhysicalSample;
 pub use scan::PhysicalScan;
+pub use sort::PhysicalSort;
+pub use top_n::PhysicalTopN;
+pub use union_all::PhysicalUnionAll;
+pub use union_scan::PhysicalUnionScan;
 
 use super::data_chunk::DataChunk;
+use super::executor::OperatorMetrics;
+use std::fmt;
 
 /// result of executing a physical operator
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExecuteResult {
     /// operator produced output and can produce more
     NeedMoreInput,
+    /// operator has more output for the same input (call again before pushing new data)
+    HaveMoreOutput,
     /// operator is finished (no more data)
     Finished,
 }
 
+/// error raised while executing a pipeline (e.g. a bad cell in a strict scan)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionError {
+    pub message: String,
+}
+
+impl fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ExecutionError {}
+
 /// physical operator trait (push-based execution)
 /// each operator transforms input DataChunk → output DataChunk
 pub trait PhysicalOperator {
@@ -31,9 +63,24 @@ pub trait PhysicalOperator {
     ///
     /// returns:
     /// - ExecuteResult::NeedMoreInput if more data can be produced
+    /// - ExecuteResult::HaveMoreOutput if it must be called again with the same input
     /// - ExecuteResult::Finished if no more data is available
+    ///
+    /// a call that produces no rows must leave `output` empty (`output.reset()`):
+    /// the executor reuses the chunk, and collects whatever it holds
     fn execute(&mut self, input: &DataChunk, output: &mut DataChunk) -> ExecuteResult;
 
+    /// error that stopped this operator, if any (taken once by the executor)
+    fn take_error(&mut self) -> Option<ExecutionError> {
+        None
+    }
+
+    /// metrics of the pipelines the operator runs itself (the SELECTs of a
+    /// UNION), in the order EXPLAIN lists their operators
+    fn nested_metrics(&self) -> Vec<OperatorMetrics> {
+        Vec::new()
+    }
+
     /// reset the operator state (for restarting execution)
     fn reset(&mut self);
 }
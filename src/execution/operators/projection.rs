@@ -1,63 +1,53 @@
 use super::{ExecuteResult, PhysicalOperator};
-use crate::binder::BoundExpression;
-use crate::execution::data_chunk::{DataChunk, Value, Vector};
+use crate::binder::{BoundExpression, ColumnType};
+use crate::collation::Collation;
+use crate::execution::data_chunk::{DataChunk, Value};
+use crate::execution::expression::ExpressionEvaluator;
 
 /// physical operator for projecting columns
-/// selects specific columns from input and outputs them
+/// selects specific columns from input and computes expressions over them
 pub struct PhysicalProjection {
     expressions: Vec<BoundExpression>,
+    types: Vec<ColumnType>, // result type of each expression
+    evaluator: ExpressionEvaluator,
 }
 
 impl PhysicalProjection {
     pub fn new(expressions: Vec<BoundExpression>) -> Self {
-        Self { expressions }
+        Self {
+            types: expressions.iter().map(|expr| expr.result_type()).collect(),
+            expressions,
+            evaluator: ExpressionEvaluator::new(Collation::Binary),
+        }
+    }
+
+    /// compare varchar values in computed expressions using the given collation
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.evaluator = ExpressionEvaluator::new(collation);
+        self
     }
 }
 
 impl PhysicalOperator for PhysicalProjection {
     fn execute(&mut self, input: &DataChunk, output: &mut DataChunk) -> ExecuteResult {
-        output.reset();
-
-        // build new column vectors for projected columns
         // if input has selection, we materialize only selected rows
         let row_count = input.selected_count();
 
-        let mut projected_columns = Vec::with_capacity(self.expressions.len());
-
-        for expr in &self.expressions {
-            match expr {
-                BoundExpression::ColumnRef { index, type_, .. } => {
-                    // create a new vector for this projected column
-                    let mut new_col = Vector::new(type_, row_count);
-
+        // fill the output's vectors in place (they keep their memory between chunks)
+        output.reset_with_types(&self.types, row_count);
+        for (expr, column) in self.expressions.iter().zip(output.columns.iter_mut()) {
+            for row_idx in 0..row_count {
+                let value = match expr {
                     // copy data respecting selection vector
-                    for row_idx in 0..row_count {
-                        if let Some(value) = input.get_value(*index, row_idx) {
-                            new_col.push(value);
-                        } else {
-                            new_col.push(Value::Null);
-                        }
-                    }
-
-                    projected_columns.push(new_col);
-                }
-                // todo: Support computed expressions (e.g., SELECT age + 1)
-                _ => {
-                    // unsupported expression type for now - create null column
-                    let mut new_col = Vector::new(&crate::binder::ColumnType::Varchar, row_count);
-                    for _ in 0..row_count {
-                        new_col.push(Value::Null);
-                    }
-                    projected_columns.push(new_col);
-                }
+                    BoundExpression::ColumnRef { index, .. } => input.get_value(*index, row_idx),
+                    // computed expression (e.g. UPPER(name)): evaluated row by row
+                    _ => self.evaluator.evaluate(expr, input, row_idx),
+                };
+                column.push(value.unwrap_or(Value::Null));
             }
         }
-
-        output.columns = projected_columns;
         output.count = row_count;
-        output.capacity = row_count;
-        // projection materializes the selection, so clear it
-        output.clear_selection();
+        // projection materializes the selection, so the output has none
 
         ExecuteResult::NeedMoreInput
     }
@@ -0,0 +1,135 @@
+use super::{ExecuteResult, PhysicalOperator};
+use crate::binder::ColumnType;
+use crate::execution::data_chunk::{DataChunk, Value};
+
+/// physical operator for SAMPLE with a reservoir
+/// keeps a uniform random choice of size rows while consuming the input
+/// (algorithm R), then emits them in input order. the generator is seeded,
+/// so the same seed over the same file always picks the same rows
+pub struct PhysicalSample {
+    size: usize,
+    seed: u64,
+    types: Vec<ColumnType>,
+    reservoir: Vec<(usize, Vec<Value>)>, // (input position, row values)
+    seen: usize,                         // input rows consumed so far
+    rng: SplitMix64,
+    emitted: usize, // reservoir rows already written to the output
+    sorted: bool,
+    finished: bool,
+}
+
+impl PhysicalSample {
+    pub fn new(size: usize, seed: u64, types: Vec<ColumnType>) -> Self {
+        Self {
+            size,
+            seed,
+            types,
+            reservoir: Vec::new(),
+            seen: 0,
+            rng: SplitMix64(seed),
+            emitted: 0,
+            sorted: false,
+            finished: false,
+        }
+    }
+
+    fn consume(&mut self, chunk: &DataChunk) {
+        for row_idx in 0..chunk.selected_count() {
+            let position = self.seen;
+            self.seen += 1;
+
+            // row i (0-based) replaces a kept row with probability size / (i + 1)
+            let slot = if self.reservoir.len() < self.size {
+                self.reservoir.len()
+            } else {
+                let pick = self.rng.below(self.seen as u64) as usize;
+                if pick >= self.size {
+                    continue;
+                }
+                pick
+            };
+
+            let values = (0..chunk.column_count())
+                .map(|col_idx| chunk.get_value(col_idx, row_idx).unwrap_or(Value::Null))
+                .collect();
+            if slot == self.reservoir.len() {
+                self.reservoir.push((position, values));
+            } else {
+                self.reservoir[slot] = (position, values);
+            }
+        }
+    }
+
+    /// write the next batch of kept rows to the output
+    fn emit_batch(&mut self, output: &mut DataChunk) {
+        let batch_end = (self.emitted + DataChunk::STANDARD_VECTOR_SIZE).min(self.reservoir.len());
+        output.reset_with_types(&self.types, batch_end - self.emitted);
+        for (_, values) in &self.reservoir[self.emitted..batch_end] {
+            output.append_row(values.clone());
+        }
+
+        self.emitted = batch_end;
+    }
+}
+
+/// small seeded generator (splitmix64); good enough to pick sample rows
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// a number in 0..n (n > 0); the modulo bias is negligible for row counts
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+impl PhysicalOperator for PhysicalSample {
+    fn execute(&mut self, input: &DataChunk, output: &mut DataChunk) -> ExecuteResult {
+        if self.finished || self.size == 0 {
+            output.reset();
+            self.finished = true;
+            return ExecuteResult::Finished;
+        }
+
+        // keep consuming input until the end of data
+        if !input.is_empty() {
+            self.consume(input);
+            output.reset();
+            return ExecuteResult::NeedMoreInput;
+        }
+
+        if !self.sorted {
+            self.reservoir.sort_by_key(|(position, _)| *position);
+            self.sorted = true;
+        }
+        if self.reservoir.is_empty() {
+            output.reset();
+            self.finished = true;
+            return ExecuteResult::Finished;
+        }
+
+        self.emit_batch(output);
+        if self.emitted < self.reservoir.len() {
+            ExecuteResult::HaveMoreOutput
+        } else {
+            self.finished = true;
+            ExecuteResult::Finished
+        }
+    }
+
+    fn reset(&mut self) {
+        self.reservoir.clear();
+        self.seen = 0;
+        self.rng = SplitMix64(self.seed);
+        self.emitted = 0;
+        self.sorted = false;
+        self.finished = false;
+    }
+}
@@ -1,30 +1,40 @@
-use super::{ExecuteResult, PhysicalOperator};
-use crate::binder::{ColumnType, Schema};
+use super::{ExecuteResult, ExecutionError, PhysicalOperator};
+use crate::binder::{ColumnType, Schema, SourceFormat};
+use crate::date::{parse_date, parse_timestamp};
 use crate::execution::data_chunk::{DataChunk, Value};
+use crate::source::{CsvFileSource, DataSource};
 use std::fs::File;
-use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
 use std::path::PathBuf;
-use std::sync::mpsc::{Receiver, channel};
+use std::sync::mpsc::{Receiver, SyncSender, sync_channel};
 use std::sync::{
     Arc,
     atomic::{AtomicUsize, Ordering},
 };
 use std::thread::{JoinHandle, spawn};
 
-/// physical operator for scanning CSV files
-/// reads CSV file and produces DataChunks in columnar format
+/// physical operator for scanning CSV (or JSON-lines) files
+/// reads the file and produces DataChunks in columnar format
 /// uses parallel workers with csv crate for robust parsing
 pub struct PhysicalScan {
-    file_path: PathBuf,
+    source: Arc<dyn DataSource>,
     schema: Schema,
     finished: bool,
     max_rows: Option<usize>, // maximum rows to read (from LIMIT pushdown)
     rows_read: usize,        // track rows read so far
+    strict: bool,            // fail on cells that don't parse as the column type
+    has_header: bool,        // skip the first line of the file
+    nulls: NullText,         // cell texts that read as NULL
+    format: SourceFormat,
+    error: Option<ExecutionError>,
     // parallel CSV scanning fields
     receiver: Option<Receiver<DataChunk>>,
     handles: Option<Vec<JoinHandle<()>>>,
     // single-threaded CSV scanning fields
-    csv_reader: Option<csv::Reader<File>>,
+    csv_reader: Option<csv::Reader<Box<dyn Read + Send>>>,
+    // JSON-lines scanning fields
+    json_reader: Option<BufReader<Box<dyn Read + Send>>>,
+    json_line: usize, // lines consumed so far, for error messages
 }
 
 impl PhysicalScan {
@@ -35,19 +45,79 @@ impl PhysicalScan {
         max_rows: Option<usize>,
     ) -> Self {
         Self {
-            file_path,
+            source: Arc::new(CsvFileSource::new(file_path)),
             schema,
             finished: false,
             max_rows,
             rows_read: 0,
+            strict: false,
+            has_header: true,
+            nulls: NullText::default(),
+            format: SourceFormat::Csv,
+            error: None,
             receiver: None,
             handles: None,
             csv_reader: None,
+            json_reader: None,
+            json_line: 0,
         }
     }
 
+    /// abort the scan on the first cell that doesn't parse as its column's type
+    /// or row whose field count differs from the header (by default such cells
+    /// read as NULL). strict scans read the file sequentially so errors can
+    /// report the exact line.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// whether the file's first line is a header (true by default)
+    pub fn with_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// whether empty cells in Varchar columns read as NULL (the default) or as
+    /// empty strings. empty cells of other types are always NULL
+    pub fn with_empty_as_null(mut self, empty_as_null: bool) -> Self {
+        self.nulls.empty = empty_as_null;
+        self
+    }
+
+    /// cell texts (matched case-insensitively, ignoring surrounding spaces)
+    /// that read as NULL in a column of any type. `null` by default
+    pub fn with_null_tokens(mut self, tokens: Vec<String>) -> Self {
+        self.nulls.tokens = tokens.into();
+        self
+    }
+
+    /// read the file as CSV (default) or JSON lines
+    pub fn with_format(mut self, format: SourceFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// read rows from this source instead of the file given to new()
+    pub fn with_source(mut self, source: Arc<dyn DataSource>) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// rows read from the file so far. the sequential scan stops at max_rows;
+    /// the parallel scan counts the rows its workers have handed over
+    pub fn rows_read(&self) -> usize {
+        self.rows_read
+    }
+
     /// determine if we should use single-threaded scan
     fn should_use_single_threaded(&self) -> bool {
+        // strict scans report exact lines, and only an uncompressed file can
+        // be split into byte ranges
+        if self.strict || self.source.seekable_path().is_none() {
+            return true;
+        }
+
         // use single-threaded for small limits (< 5000 rows)
         // this allows immediate early termination with no coordination overhead
         if let Some(max_rows) = self.max_rows {
@@ -57,34 +127,74 @@ impl PhysicalScan {
         }
     }
 
+    /// stop the scan with an error (surfaced through take_error)
+    fn fail(&mut self, message: String, output: &mut DataChunk) -> ExecuteResult {
+        self.error = Some(ExecutionError { message });
+        self.finished = true;
+        output.reset();
+        ExecuteResult::Finished
+    }
+
+    /// stop the scan because the source can't be read at all
+    fn fail_to_open(&mut self, error: std::io::Error, output: &mut DataChunk) -> ExecuteResult {
+        let message = format!("cannot open {}: {}", self.source.name(), error);
+        self.fail(message, output)
+    }
+
     /// parse a CSV value and convert it to the appropriate type
-    fn parse_value(value: &str, column_type: &ColumnType) -> Value {
+    /// (values that don't parse become NULL)
+    fn parse_value(value: &str, column_type: &ColumnType, nulls: &NullText) -> Value {
+        Self::read_field(value, column_type, nulls).unwrap_or(Value::Null)
+    }
+
+    /// try_parse_value, except that NULL tokens are NULL and an empty Varchar
+    /// cell is an empty string when empty cells aren't NULL
+    fn read_field(value: &str, column_type: &ColumnType, nulls: &NullText) -> Option<Value> {
         let trimmed = value.trim();
+        if trimmed.is_empty() {
+            if !nulls.empty && *column_type == ColumnType::Varchar {
+                return Some(Value::Varchar(String::new()));
+            }
+        } else if nulls.is_token(trimmed) {
+            return Some(Value::Null);
+        }
+        Self::try_parse_value(value, column_type)
+    }
+
+    /// parse a CSV value, returning None if it isn't valid for the column type.
+    /// empty cells are NULL for every type. values are coerced to the column's
+    /// type: `42` in a Float column is 42.0, `+5` and `007` are integers, and a
+    /// number left in quotes by the line splitter (`"42"`) is still a number.
+    fn try_parse_value(value: &str, column_type: &ColumnType) -> Option<Value> {
+        let mut trimmed = value.trim();
+        if *column_type != ColumnType::Varchar
+            && trimmed.len() >= 2
+            && trimmed.starts_with('"')
+            && trimmed.ends_with('"')
+        {
+            trimmed = trimmed[1..trimmed.len() - 1].trim();
+        }
 
         if trimmed.is_empty() {
-            return Value::Null;
+            return Some(Value::Null);
         }
 
         match column_type {
-            ColumnType::Integer => trimmed
-                .parse::<i64>()
-                .map(Value::Integer)
-                .unwrap_or(Value::Null),
-            ColumnType::Float => trimmed
-                .parse::<f64>()
-                .map(Value::Float)
-                .unwrap_or(Value::Null),
+            ColumnType::Integer => trimmed.parse::<i64>().ok().map(Value::Integer),
+            ColumnType::Float => trimmed.parse::<f64>().ok().map(Value::Float),
             ColumnType::Boolean => {
                 if trimmed.eq_ignore_ascii_case("true") {
-                    Value::Boolean(true)
+                    Some(Value::Boolean(true))
                 } else if trimmed.eq_ignore_ascii_case("false") {
-                    Value::Boolean(false)
+                    Some(Value::Boolean(false))
                 } else {
-                    Value::Null
+                    None
                 }
             }
-            ColumnType::Varchar => Value::Varchar(trimmed.to_string()),
-            ColumnType::Null => Value::Null,
+            ColumnType::Varchar => Some(Value::Varchar(trimmed.to_string())),
+            ColumnType::Date => parse_date(trimmed).map(Value::Date),
+            ColumnType::Timestamp => parse_timestamp(trimmed).map(Value::Timestamp),
+            ColumnType::Null => Some(Value::Null),
         }
     }
 
@@ -93,13 +203,18 @@ impl PhysicalScan {
     fn execute_single_threaded(&mut self, output: &mut DataChunk) -> ExecuteResult {
         // initialize CSV reader on first call
         if self.csv_reader.is_none() {
-            match csv::Reader::from_path(&self.file_path) {
+            // ragged rows: short rows read NULL for missing trailing columns and
+            // extra fields are ignored, unless strict mode asks for an error
+            // gzip files are decompressed as they're read
+            let reader = self.source.open().map(|file| {
+                csv::ReaderBuilder::new()
+                    .has_headers(self.has_header)
+                    .flexible(!self.strict)
+                    .from_reader(file)
+            });
+            match reader {
                 Ok(reader) => self.csv_reader = Some(reader),
-                Err(_) => {
-                    self.finished = true;
-                    output.reset();
-                    return ExecuteResult::Finished;
-                }
+                Err(e) => return self.fail_to_open(e, output),
             }
         }
 
@@ -110,68 +225,200 @@ impl PhysicalScan {
             .iter()
             .map(|c| c.type_.clone())
             .collect();
-        let mut chunk = DataChunk::new(column_types.clone(), DataChunk::STANDARD_VECTOR_SIZE);
+        // rows go straight into the output chunk, reusing its vectors
+        output.reset_with_types(&column_types, DataChunk::STANDARD_VECTOR_SIZE);
 
         // read rows until chunk is full or limit is reached
-        for result in reader.records() {
-            // check if we've hit the limit
+        let mut records = reader.records();
+        loop {
+            // check if we've hit the limit before reading another record,
+            // so a LIMIT n scan never reads more than n rows of the file
             if let Some(max_rows) = self.max_rows {
                 if self.rows_read >= max_rows {
                     self.finished = true;
-                    if chunk.count > 0 {
-                        *output = chunk;
-                        return ExecuteResult::Finished;
-                    } else {
-                        output.reset();
-                        return ExecuteResult::Finished;
-                    }
+                    return ExecuteResult::Finished;
                 }
             }
 
+            let Some(result) = records.next() else {
+                break;
+            };
             match result {
                 Ok(record) => {
                     let mut row = Vec::new();
                     for col in self.schema.columns.iter() {
                         let file_index = col.index;
                         if let Some(field) = record.get(file_index) {
-                            let value = Self::parse_value(field, &col.type_);
-                            row.push(value);
+                            match Self::read_field(field, &col.type_, &self.nulls) {
+                                Some(value) => row.push(value),
+                                None if self.strict => {
+                                    // header is line 1, so record lines are already 1-based
+                                    let line = record.position().map_or(0, |p| p.line());
+                                    let message = format!(
+                                        "{}:{}: column '{}' has value '{}', expected {:?}",
+                                        self.source.name(),
+                                        line,
+                                        col.name,
+                                        field.trim(),
+                                        col.type_
+                                    );
+                                    return self.fail(message, output);
+                                }
+                                None => row.push(Value::Null),
+                            }
                         } else {
                             row.push(Value::Null);
                         }
                     }
 
-                    chunk.append_row(row);
+                    if let Err(e) = output.try_append_row(row) {
+                        let line = record.position().map_or(0, |p| p.line());
+                        let message = format!("{}:{}: {}", self.source.name(), line, e.message);
+                        return self.fail(message, output);
+                    }
                     self.rows_read += 1;
 
                     // chunk is full, send it back
-                    if chunk.count >= DataChunk::STANDARD_VECTOR_SIZE {
-                        *output = chunk;
+                    if output.count >= DataChunk::STANDARD_VECTOR_SIZE {
                         return ExecuteResult::NeedMoreInput;
                     }
                 }
+                Err(e) if self.strict => {
+                    let message = match e.kind() {
+                        csv::ErrorKind::UnequalLengths {
+                            pos,
+                            expected_len,
+                            len,
+                        } => format!(
+                            "{}:{}: expected {} fields, found {}",
+                            self.source.name(),
+                            pos.as_ref().map_or(0, |p| p.line()),
+                            expected_len,
+                            len
+                        ),
+                        _ => format!("{}: {}", self.source.name(), e),
+                    };
+                    return self.fail(message, output);
+                }
                 Err(_) => {
                     // error reading, stop
                     self.finished = true;
-                    if chunk.count > 0 {
-                        *output = chunk;
-                        return ExecuteResult::Finished;
-                    } else {
-                        output.reset();
-                        return ExecuteResult::Finished;
-                    }
+                    return ExecuteResult::Finished;
                 }
             }
         }
 
         // eof reached
         self.finished = true;
-        if chunk.count > 0 {
-            *output = chunk;
-            ExecuteResult::Finished
-        } else {
-            output.reset();
-            ExecuteResult::Finished
+        ExecuteResult::Finished
+    }
+
+    /// sequential JSON-lines scan: one object per line, keys matched to column
+    /// names. missing keys and values of the wrong type read as NULL (strict
+    /// mode reports them, and lines that aren't JSON objects)
+    fn execute_json_lines(&mut self, output: &mut DataChunk) -> ExecuteResult {
+        if self.json_reader.is_none() {
+            match self.source.open() {
+                Ok(file) => self.json_reader = Some(BufReader::new(file)),
+                Err(e) => return self.fail_to_open(e, output),
+            }
+        }
+
+        let reader = self.json_reader.as_mut().unwrap();
+        let column_types: Vec<ColumnType> = self
+            .schema
+            .columns
+            .iter()
+            .map(|c| c.type_.clone())
+            .collect();
+        output.reset_with_types(&column_types, DataChunk::STANDARD_VECTOR_SIZE);
+
+        let mut line = String::new();
+        loop {
+            if self
+                .max_rows
+                .is_some_and(|max_rows| self.rows_read >= max_rows)
+            {
+                break;
+            }
+
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => self.json_line += 1,
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let object = match serde_json::from_str::<serde_json::Value>(&line) {
+                Ok(serde_json::Value::Object(object)) => object,
+                _ if self.strict => {
+                    let message = format!(
+                        "{}:{}: not a JSON object",
+                        self.source.name(),
+                        self.json_line
+                    );
+                    return self.fail(message, output);
+                }
+                _ => continue,
+            };
+
+            let mut row = Vec::with_capacity(self.schema.columns.len());
+            for col in &self.schema.columns {
+                let field = object.get(&col.name);
+                match Self::json_to_value(field, &col.type_) {
+                    Some(value) => row.push(value),
+                    None if self.strict => {
+                        let message = format!(
+                            "{}:{}: column '{}' has value '{}', expected {:?}",
+                            self.source.name(),
+                            self.json_line,
+                            col.name,
+                            field.map_or(String::new(), |v| v.to_string()),
+                            col.type_
+                        );
+                        return self.fail(message, output);
+                    }
+                    None => row.push(Value::Null),
+                }
+            }
+
+            if let Err(e) = output.try_append_row(row) {
+                let message = format!("{}:{}: {}", self.source.name(), self.json_line, e.message);
+                return self.fail(message, output);
+            }
+            self.rows_read += 1;
+            if output.count >= DataChunk::STANDARD_VECTOR_SIZE {
+                return ExecuteResult::NeedMoreInput;
+            }
+        }
+
+        self.finished = true;
+        ExecuteResult::Finished
+    }
+
+    /// convert a JSON value to the column's type, None if it doesn't fit.
+    /// Varchar columns take any value (non-strings as their JSON text)
+    fn json_to_value(field: Option<&serde_json::Value>, column_type: &ColumnType) -> Option<Value> {
+        let value = match field {
+            None | Some(serde_json::Value::Null) => return Some(Value::Null),
+            Some(value) => value,
+        };
+        match column_type {
+            ColumnType::Integer => value.as_i64().map(Value::Integer),
+            ColumnType::Float => value.as_f64().map(Value::Float),
+            ColumnType::Boolean => value.as_bool().map(Value::Boolean),
+            ColumnType::Varchar => Some(Value::Varchar(match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })),
+            ColumnType::Date => value.as_str().and_then(parse_date).map(Value::Date),
+            ColumnType::Timestamp => value
+                .as_str()
+                .and_then(parse_timestamp)
+                .map(Value::Timestamp),
+            ColumnType::Null => Some(Value::Null),
         }
     }
 
@@ -179,11 +426,11 @@ impl PhysicalScan {
     /// now supports early termination via shared atomic counter
     fn parallel_csv_worker(
         path: PathBuf,
-        start: u64,
-        end: u64,
-        sender: std::sync::mpsc::Sender<DataChunk>,
+        range: ByteRange,
+        sender: SyncSender<DataChunk>,
         schema: Schema,
-        is_first: bool,
+        has_header: bool,
+        nulls: NullText,
         rows_counter: Option<Arc<AtomicUsize>>,
         max_rows: Option<usize>,
     ) {
@@ -192,24 +439,31 @@ impl PhysicalScan {
             Err(_) => return,
         };
 
+        let ByteRange {
+            partition,
+            start,
+            end,
+        } = range;
         let mut reader = BufReader::new(file);
+        let is_first = start == 0;
 
         // seek to start position
         if reader.seek(SeekFrom::Start(start)).is_err() {
             return;
         }
 
-        // if not first thread, skip partial line to align to row boundary
+        // if not first thread, skip partial line to align to row boundary.
+        // the range may start inside a multibyte character, so the partial
+        // line is skipped as bytes (read_line would reject it as invalid UTF-8)
         if !is_first {
-            let mut line = String::new();
-            if reader.read_line(&mut line).is_err() {
+            let mut partial = Vec::new();
+            if reader.read_until(b'\n', &mut partial).is_err() {
                 return;
             }
-            // discarded partial line
         }
 
         // if first thread, skip header
-        if is_first {
+        if is_first && has_header {
             let mut header = String::new();
             if reader.read_line(&mut header).is_err() {
                 return;
@@ -222,6 +476,7 @@ impl PhysicalScan {
         let column_types: Vec<ColumnType> =
             schema.columns.iter().map(|c| c.type_.clone()).collect();
         let mut chunk = DataChunk::new(column_types.clone(), DataChunk::STANDARD_VECTOR_SIZE);
+        chunk.partition = partition;
 
         // read lines until we exceed our byte range
         let mut line = String::new();
@@ -251,7 +506,7 @@ impl PhysicalScan {
                     for col in schema.columns.iter() {
                         let file_index = col.index;
                         if file_index < fields.len() {
-                            let value = Self::parse_value(fields[file_index], &col.type_);
+                            let value = Self::parse_value(fields[file_index], &col.type_, &nulls);
                             row.push(value);
                         } else {
                             row.push(Value::Null);
@@ -271,6 +526,7 @@ impl PhysicalScan {
                         }
                         chunk =
                             DataChunk::new(column_types.clone(), DataChunk::STANDARD_VECTOR_SIZE);
+                        chunk.partition = partition;
                     }
 
                     // stop when we've exceeded our range
@@ -289,7 +545,10 @@ impl PhysicalScan {
 
     /// spawn parallel worker threads for CSV processing
     fn spawn_workers(&mut self) -> std::io::Result<()> {
-        let file_size = std::fs::metadata(&self.file_path)?.len();
+        let path = self.source.seekable_path().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::Unsupported, "source can't be split")
+        })?;
+        let file_size = std::fs::metadata(path)?.len();
 
         // use single-threaded mode for small files (< 1MB) to avoid boundary issues
         let num_threads = if file_size < 1_000_000 {
@@ -302,7 +561,9 @@ impl PhysicalScan {
 
         let chunk_size = file_size / num_threads as u64;
 
-        let (chunk_tx, chunk_rx) = channel();
+        // bounded so workers don't read far ahead of the consumer,
+        // an early-finishing pipeline then leaves most of the file unread
+        let (chunk_tx, chunk_rx) = sync_channel(num_threads * 2);
 
         // create shared atomic counter for LIMIT pushdown
         let rows_counter = if self.max_rows.is_some() {
@@ -314,23 +575,27 @@ impl PhysicalScan {
         let mut handles = Vec::new();
 
         for i in 0..num_threads {
-            let start = i as u64 * chunk_size;
-            let end = if i == num_threads - 1 {
-                file_size
-            } else {
-                (i + 1) as u64 * chunk_size
+            let range = ByteRange {
+                partition: i,
+                start: i as u64 * chunk_size,
+                end: if i == num_threads - 1 {
+                    file_size
+                } else {
+                    (i + 1) as u64 * chunk_size
+                },
             };
 
-            let path = self.file_path.clone();
+            let path = path.to_path_buf();
             let schema = self.schema.clone();
             let sender = chunk_tx.clone();
-            let is_first = i == 0;
+            let has_header = self.has_header;
+            let nulls = self.nulls.clone();
             let counter = rows_counter.clone();
             let max_rows = self.max_rows;
 
             let handle = spawn(move || {
                 Self::parallel_csv_worker(
-                    path, start, end, sender, schema, is_first, counter, max_rows,
+                    path, range, sender, schema, has_header, nulls, counter, max_rows,
                 );
             });
 
@@ -353,6 +618,17 @@ impl PhysicalOperator for PhysicalScan {
             return ExecuteResult::Finished;
         }
 
+        // a scan capped at zero rows (under a constant-false filter) never opens the file
+        if self.max_rows == Some(0) {
+            self.finished = true;
+            output.reset();
+            return ExecuteResult::Finished;
+        }
+
+        if self.format == SourceFormat::JsonLines {
+            return self.execute_json_lines(output);
+        }
+
         // choose execution strategy based on max_rows
         if self.should_use_single_threaded() {
             // single-threaded scan for small limits
@@ -362,9 +638,8 @@ impl PhysicalOperator for PhysicalScan {
         // parallel scan for large limits or no limit
         // spawn workers on first call if not already done
         if self.receiver.is_none() {
-            if let Err(_) = self.spawn_workers() {
-                self.finished = true;
-                return ExecuteResult::Finished;
+            if let Err(e) = self.spawn_workers() {
+                return self.fail_to_open(e, output);
             }
         }
 
@@ -373,6 +648,7 @@ impl PhysicalOperator for PhysicalScan {
         match receiver.recv() {
             Ok(chunk) => {
                 // copy chunk to output
+                self.rows_read += chunk.count;
                 *output = chunk;
                 ExecuteResult::NeedMoreInput
             }
@@ -385,9 +661,14 @@ impl PhysicalOperator for PhysicalScan {
         }
     }
 
+    fn take_error(&mut self) -> Option<ExecutionError> {
+        self.error.take()
+    }
+
     fn reset(&mut self) {
         self.finished = false;
         self.rows_read = 0;
+        self.error = None;
         // clean up parallel resources
         self.receiver = None;
         // join worker threads if they exist
@@ -398,5 +679,119 @@ impl PhysicalOperator for PhysicalScan {
         }
         // clean up single-threaded resources
         self.csv_reader = None;
+        self.json_reader = None;
+        self.json_line = 0;
+    }
+}
+
+/// the part of the file one parallel scan worker reads: lines starting in
+/// [start, end), sent in chunks tagged with the partition index
+#[derive(Debug, Clone, Copy)]
+struct ByteRange {
+    partition: usize,
+    start: u64,
+    end: u64,
+}
+
+/// which cell texts a scan reads as NULL
+#[derive(Debug, Clone)]
+struct NullText {
+    empty: bool,           // empty Varchar cells are NULL rather than ""
+    tokens: Arc<[String]>, // spellings of NULL, such as `NA` or `\N`
+}
+
+impl Default for NullText {
+    fn default() -> Self {
+        Self {
+            empty: true,
+            tokens: Arc::new(["null".to_string()]),
+        }
+    }
+}
+
+impl NullText {
+    fn is_token(&self, value: &str) -> bool {
+        self.tokens
+            .iter()
+            .any(|token| token.eq_ignore_ascii_case(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values_coerce_to_column_type() {
+        let parse = PhysicalScan::try_parse_value;
+        assert_eq!(parse("42", &ColumnType::Float), Some(Value::Float(42.0)));
+        assert_eq!(parse("42.0", &ColumnType::Float), Some(Value::Float(42.0)));
+        assert_eq!(
+            parse(" \"42\" ", &ColumnType::Float),
+            Some(Value::Float(42.0))
+        );
+        assert_eq!(parse("+5", &ColumnType::Integer), Some(Value::Integer(5)));
+        assert_eq!(parse("007", &ColumnType::Integer), Some(Value::Integer(7)));
+        assert_eq!(
+            parse("\"-3\"", &ColumnType::Integer),
+            Some(Value::Integer(-3))
+        );
+        assert_eq!(parse("4.5", &ColumnType::Integer), None);
+        assert_eq!(
+            parse("\"42\"", &ColumnType::Varchar),
+            Some(Value::Varchar("\"42\"".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_worker_range_starting_inside_a_multibyte_character() {
+        let path = std::env::temp_dir().join("celect_scan_utf8_range.csv");
+        let mut contents = String::from("id,name\n");
+        for i in 0..100 {
+            contents.push_str(&format!("{},名前{}\n", i, i));
+        }
+        std::fs::write(&path, &contents).unwrap();
+
+        // split the file one byte into a three-byte character
+        let mid = contents.find("名前50").unwrap() + 1;
+        assert!(!contents.is_char_boundary(mid));
+
+        let schema = Schema::from_columns([
+            ("id".to_string(), ColumnType::Integer),
+            ("name".to_string(), ColumnType::Varchar),
+        ]);
+        let (sender, receiver) = sync_channel(16);
+        let ranges = [(0, mid as u64), (mid as u64, contents.len() as u64)];
+        for (partition, (start, end)) in ranges.into_iter().enumerate() {
+            let (path, schema, sender) = (path.clone(), schema.clone(), sender.clone());
+            PhysicalScan::parallel_csv_worker(
+                path,
+                ByteRange {
+                    partition,
+                    start,
+                    end,
+                },
+                sender,
+                schema,
+                true,
+                NullText::default(),
+                None,
+                None,
+            );
+        }
+        drop(sender);
+
+        let mut names = Vec::new();
+        let mut partitions = Vec::new();
+        for chunk in receiver {
+            names.extend(chunk.iter_column(1));
+            partitions.push(chunk.partition);
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        // every row is read exactly once, the split one included
+        assert_eq!(names.len(), 100);
+        assert_eq!(names[50], Value::Varchar("名前50".to_string()));
+        assert_eq!(partitions, vec![0, 1]);
     }
 }
@@ -0,0 +1,576 @@
+use super::{ExecuteResult, ExecutionError, PhysicalOperator};
+use crate::binder::{BoundOrderBy, ColumnType};
+use crate::collation::Collation;
+use crate::execution::data_chunk::{DataChunk, Value};
+use crate::execution::expression::ExpressionEvaluator;
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+/// a buffered row: (key values, row values)
+type SortRow = (Vec<Value>, Vec<Value>);
+
+/// physical operator for ORDER BY
+/// consumes all input rows, then emits them ordered by the keys one vector at
+/// a time. the sort is stable (rows with equal keys keep their input order)
+/// and NULL sorts after every value, so it comes first in descending order.
+///
+/// rows are buffered up to a memory budget; past it the buffer is sorted and
+/// written to a temporary file (a run), and the runs are merged at the end
+pub struct PhysicalSort {
+    keys: Vec<BoundOrderBy>,
+    types: Vec<ColumnType>, // input (and output) column types
+    rows: Vec<SortRow>,     // rows not spilled yet, in input order
+    buffered_bytes: usize,  // approximate size of rows
+    memory_budget: usize,
+    runs: Vec<SpillFile>, // spilled runs, each sorted, in input order
+    merge: Option<Merge>, // set once input ended and there are runs
+    evaluator: ExpressionEvaluator,
+    collation: Collation,
+    sorted: bool,
+    emitted: usize, // rows already written to the output (in-memory sort)
+    finished: bool,
+    error: Option<ExecutionError>,
+}
+
+impl PhysicalSort {
+    pub fn new(keys: Vec<BoundOrderBy>, types: Vec<ColumnType>) -> Self {
+        Self {
+            keys,
+            types,
+            rows: Vec::new(),
+            buffered_bytes: 0,
+            memory_budget: usize::MAX,
+            runs: Vec::new(),
+            merge: None,
+            evaluator: ExpressionEvaluator::new(Collation::Binary),
+            collation: Collation::Binary,
+            sorted: false,
+            emitted: 0,
+            finished: false,
+            error: None,
+        }
+    }
+
+    /// order varchar keys using the given collation
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.evaluator = ExpressionEvaluator::new(collation);
+        self.collation = collation;
+        self
+    }
+
+    /// spill sorted runs to disk once the buffered rows take about this many bytes
+    pub fn with_memory_budget(mut self, bytes: usize) -> Self {
+        self.memory_budget = bytes;
+        self
+    }
+
+    /// copy every selected row and its key values, spilling past the budget
+    fn buffer_rows(&mut self, chunk: &DataChunk) -> io::Result<()> {
+        for row_idx in 0..chunk.selected_count() {
+            let keys: Vec<Value> = self
+                .keys
+                .iter()
+                .map(|key| {
+                    self.evaluator
+                        .evaluate(&key.expression, chunk, row_idx)
+                        .unwrap_or(Value::Null)
+                })
+                .collect();
+            let values: Vec<Value> = (0..chunk.column_count())
+                .map(|col_idx| chunk.get_value(col_idx, row_idx).unwrap_or(Value::Null))
+                .collect();
+            self.buffered_bytes += row_size(&keys) + row_size(&values);
+            self.rows.push((keys, values));
+
+            if self.buffered_bytes > self.memory_budget {
+                self.spill()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn sort_rows(&mut self) {
+        let keys = &self.keys;
+        let collation = self.collation;
+        self.rows
+            .sort_by(|(left, _), (right, _)| compare_keys(collation, keys, left, right));
+        self.sorted = true;
+    }
+
+    /// sort the buffered rows and write them out as a new run
+    fn spill(&mut self) -> io::Result<()> {
+        self.sort_rows();
+        let run = SpillFile::create()?;
+        let mut writer = BufWriter::new(File::create(&run.path)?);
+        for (keys, values) in self.rows.drain(..) {
+            for value in keys.iter().chain(&values) {
+                write_value(&mut writer, value)?;
+            }
+        }
+        writer.flush()?;
+
+        self.runs.push(run);
+        self.buffered_bytes = 0;
+        self.sorted = false;
+        Ok(())
+    }
+
+    /// open every run for merging; the rows still in memory are the last run
+    fn start_merge(&mut self) -> io::Result<()> {
+        self.sort_rows();
+        let mut sources = self
+            .runs
+            .iter()
+            .map(|run| Ok(RunSource::File(BufReader::new(File::open(&run.path)?))))
+            .collect::<io::Result<Vec<_>>>()?;
+        sources.push(RunSource::Memory(
+            std::mem::take(&mut self.rows).into_iter(),
+        ));
+
+        let shape = (self.keys.len(), self.types.len());
+        let heads = sources
+            .iter_mut()
+            .map(|source| source.next_row(shape))
+            .collect::<io::Result<Vec<_>>>()?;
+        self.merge = Some(Merge { sources, heads });
+        Ok(())
+    }
+
+    /// write the next batch of sorted rows to the output
+    fn emit_batch(&mut self, output: &mut DataChunk) {
+        let batch_end = (self.emitted + DataChunk::STANDARD_VECTOR_SIZE).min(self.rows.len());
+        output.reset_with_types(&self.types, batch_end - self.emitted);
+        for (_, values) in &self.rows[self.emitted..batch_end] {
+            output.append_row(values.clone());
+        }
+
+        self.emitted = batch_end;
+    }
+
+    /// write the next batch of merged rows to the output; false once the runs are drained
+    fn emit_merged_batch(&mut self, output: &mut DataChunk) -> io::Result<bool> {
+        let shape = (self.keys.len(), self.types.len());
+        let merge = self.merge.as_mut().unwrap();
+        output.reset_with_types(&self.types, DataChunk::STANDARD_VECTOR_SIZE);
+        for _ in 0..DataChunk::STANDARD_VECTOR_SIZE {
+            // the smallest head; ties go to the earlier run, which keeps the sort stable
+            let smallest = merge
+                .heads
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, head)| head.as_ref().map(|(keys, _)| (idx, keys)))
+                .reduce(|best, candidate| {
+                    match compare_keys(self.collation, &self.keys, candidate.1, best.1) {
+                        Ordering::Less => candidate,
+                        _ => best,
+                    }
+                })
+                .map(|(idx, _)| idx);
+            let Some(idx) = smallest else {
+                break;
+            };
+
+            let next = merge.sources[idx].next_row(shape)?;
+            if let Some((_, values)) = std::mem::replace(&mut merge.heads[idx], next) {
+                output.append_row(values);
+            }
+        }
+
+        Ok(merge.heads.iter().any(Option::is_some))
+    }
+
+    /// stop with an error (surfaced through take_error)
+    fn fail(&mut self, error: io::Error, output: &mut DataChunk) -> ExecuteResult {
+        self.error = Some(ExecutionError {
+            message: format!("cannot spill sort run: {}", error),
+        });
+        self.finished = true;
+        output.reset();
+        ExecuteResult::Finished
+    }
+}
+
+/// order of two rows' key values, most significant key first
+pub(super) fn compare_keys(
+    collation: Collation,
+    keys: &[BoundOrderBy],
+    left: &[Value],
+    right: &[Value],
+) -> Ordering {
+    for ((l, r), key) in left.iter().zip(right).zip(keys) {
+        let ordering = compare(collation, l, r);
+        let ordering = if key.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/// ascending order of two key values; NULL is greater than everything
+fn compare(collation: Collation, left: &Value, right: &Value) -> Ordering {
+    match (left, right) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Greater,
+        (_, Value::Null) => Ordering::Less,
+        (Value::Integer(l), Value::Integer(r)) => l.cmp(r),
+        (Value::Varchar(l), Value::Varchar(r)) => collation.compare(l, r),
+        (Value::Boolean(l), Value::Boolean(r)) => l.cmp(r),
+        (Value::Date(l), Value::Date(r)) => l.cmp(r),
+        (Value::Timestamp(l), Value::Timestamp(r)) => l.cmp(r),
+        (Value::Float(l), Value::Float(r)) => l.total_cmp(r),
+        (Value::Integer(l), Value::Float(r)) => (*l as f64).total_cmp(r),
+        (Value::Float(l), Value::Integer(r)) => l.total_cmp(&(*r as f64)),
+        // one key expression has one type
+        _ => Ordering::Equal,
+    }
+}
+
+/// approximate memory taken by a buffered row's values
+fn row_size(values: &[Value]) -> usize {
+    let inline = std::mem::size_of::<Vec<Value>>() + std::mem::size_of_val(values);
+    let strings: usize = values
+        .iter()
+        .map(|value| match value {
+            Value::Varchar(s) => s.len(),
+            _ => 0,
+        })
+        .sum();
+    inline + strings
+}
+
+/// a temporary run file, removed when dropped
+struct SpillFile {
+    path: PathBuf,
+}
+
+impl SpillFile {
+    fn create() -> io::Result<Self> {
+        static NEXT_RUN: AtomicUsize = AtomicUsize::new(0);
+        let run = NEXT_RUN.fetch_add(1, AtomicOrdering::Relaxed);
+        let name = format!("celect_sort_{}_{}.run", std::process::id(), run);
+        let path = std::env::temp_dir().join(name);
+        File::create(&path)?;
+        Ok(Self { path })
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// k-way merge state: one source per run and its next row
+struct Merge {
+    sources: Vec<RunSource>,
+    heads: Vec<Option<SortRow>>,
+}
+
+enum RunSource {
+    File(BufReader<File>),
+    Memory(std::vec::IntoIter<SortRow>),
+}
+
+impl RunSource {
+    /// the next row of the run, given (key count, column count)
+    fn next_row(&mut self, shape: (usize, usize)) -> io::Result<Option<SortRow>> {
+        let (key_count, column_count) = shape;
+        match self {
+            RunSource::Memory(rows) => Ok(rows.next()),
+            RunSource::File(reader) => {
+                let Some(first) = read_value(reader)? else {
+                    return Ok(None);
+                };
+                let mut values = vec![first];
+                for _ in 1..key_count + column_count {
+                    let value = read_value(reader)?.ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::UnexpectedEof, "truncated sort run")
+                    })?;
+                    values.push(value);
+                }
+                let row_values = values.split_off(key_count);
+                Ok(Some((values, row_values)))
+            }
+        }
+    }
+}
+
+// run file encoding: a tag byte per value, then its little-endian payload
+const TAG_NULL: u8 = 0;
+const TAG_INTEGER: u8 = 1;
+const TAG_FLOAT: u8 = 2;
+const TAG_BOOLEAN: u8 = 3;
+const TAG_VARCHAR: u8 = 4;
+const TAG_DATE: u8 = 5;
+const TAG_TIMESTAMP: u8 = 6;
+
+fn write_value(writer: &mut impl Write, value: &Value) -> io::Result<()> {
+    match value {
+        Value::Null => writer.write_all(&[TAG_NULL]),
+        Value::Integer(n) => {
+            writer.write_all(&[TAG_INTEGER])?;
+            writer.write_all(&n.to_le_bytes())
+        }
+        Value::Float(f) => {
+            writer.write_all(&[TAG_FLOAT])?;
+            writer.write_all(&f.to_le_bytes())
+        }
+        Value::Boolean(b) => writer.write_all(&[TAG_BOOLEAN, *b as u8]),
+        Value::Varchar(s) => {
+            writer.write_all(&[TAG_VARCHAR])?;
+            writer.write_all(&(s.len() as u64).to_le_bytes())?;
+            writer.write_all(s.as_bytes())
+        }
+        Value::Date(d) => {
+            writer.write_all(&[TAG_DATE])?;
+            writer.write_all(&d.to_le_bytes())
+        }
+        Value::Timestamp(t) => {
+            writer.write_all(&[TAG_TIMESTAMP])?;
+            writer.write_all(&t.to_le_bytes())
+        }
+    }
+}
+
+/// the next value, or None at the end of the run
+fn read_value(reader: &mut impl Read) -> io::Result<Option<Value>> {
+    let mut tag = [0u8; 1];
+    if reader.read(&mut tag)? == 0 {
+        return Ok(None);
+    }
+    let mut word = [0u8; 8];
+    let value = match tag[0] {
+        TAG_NULL => Value::Null,
+        TAG_INTEGER => {
+            reader.read_exact(&mut word)?;
+            Value::Integer(i64::from_le_bytes(word))
+        }
+        TAG_FLOAT => {
+            reader.read_exact(&mut word)?;
+            Value::Float(f64::from_le_bytes(word))
+        }
+        TAG_BOOLEAN => {
+            reader.read_exact(&mut tag)?;
+            Value::Boolean(tag[0] != 0)
+        }
+        TAG_VARCHAR => {
+            reader.read_exact(&mut word)?;
+            let mut bytes = vec![0u8; u64::from_le_bytes(word) as usize];
+            reader.read_exact(&mut bytes)?;
+            let text = String::from_utf8(bytes)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            Value::Varchar(text)
+        }
+        TAG_DATE => {
+            let mut day = [0u8; 4];
+            reader.read_exact(&mut day)?;
+            Value::Date(i32::from_le_bytes(day))
+        }
+        TAG_TIMESTAMP => {
+            reader.read_exact(&mut word)?;
+            Value::Timestamp(i64::from_le_bytes(word))
+        }
+        other => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unknown value tag {} in sort run", other),
+            ));
+        }
+    };
+    Ok(Some(value))
+}
+
+impl PhysicalOperator for PhysicalSort {
+    fn execute(&mut self, input: &DataChunk, output: &mut DataChunk) -> ExecuteResult {
+        if self.finished {
+            output.reset();
+            return ExecuteResult::Finished;
+        }
+
+        // keep consuming input until the end of data
+        if !input.is_empty() {
+            if let Err(error) = self.buffer_rows(input) {
+                return self.fail(error, output);
+            }
+            output.reset();
+            return ExecuteResult::NeedMoreInput;
+        }
+
+        // spilled: merge the runs
+        if !self.runs.is_empty() {
+            let merged = match self.merge {
+                Some(_) => self.emit_merged_batch(output),
+                None => self
+                    .start_merge()
+                    .and_then(|_| self.emit_merged_batch(output)),
+            };
+            return match merged {
+                Ok(true) => ExecuteResult::HaveMoreOutput,
+                Ok(false) => {
+                    self.finished = true;
+                    ExecuteResult::Finished
+                }
+                Err(error) => self.fail(error, output),
+            };
+        }
+
+        if !self.sorted {
+            self.sort_rows();
+        }
+        if self.rows.is_empty() {
+            output.reset();
+            self.finished = true;
+            return ExecuteResult::Finished;
+        }
+
+        self.emit_batch(output);
+        if self.emitted < self.rows.len() {
+            ExecuteResult::HaveMoreOutput
+        } else {
+            self.finished = true;
+            ExecuteResult::Finished
+        }
+    }
+
+    fn take_error(&mut self) -> Option<ExecutionError> {
+        self.error.take()
+    }
+
+    fn reset(&mut self) {
+        self.rows.clear();
+        self.buffered_bytes = 0;
+        self.merge = None;
+        self.runs.clear(); // removes the run files
+        self.sorted = false;
+        self.emitted = 0;
+        self.finished = false;
+        self.error = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binder::BoundExpression;
+
+    fn key(index: usize, type_: ColumnType, descending: bool) -> BoundOrderBy {
+        BoundOrderBy {
+            expression: BoundExpression::ColumnRef {
+                name: "test".to_string(),
+                index,
+                type_,
+            },
+            descending,
+        }
+    }
+
+    fn drain(sort: &mut PhysicalSort) -> Vec<Vec<Value>> {
+        let mut rows = Vec::new();
+        loop {
+            let mut output = DataChunk::empty();
+            let result = sort.execute(&DataChunk::empty(), &mut output);
+            for row_idx in 0..output.selected_count() {
+                rows.push(
+                    (0..output.column_count())
+                        .map(|col_idx| output.get_value(col_idx, row_idx).unwrap_or(Value::Null))
+                        .collect(),
+                );
+            }
+            if result == ExecuteResult::Finished {
+                return rows;
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_is_stable_with_nulls_last() {
+        let types = vec![ColumnType::Integer, ColumnType::Varchar];
+        let mut chunk = DataChunk::new(types.clone(), 8);
+        for (n, s) in [(Some(2), "a"), (None, "b"), (Some(1), "c"), (Some(2), "d")] {
+            let n = n.map_or(Value::Null, Value::Integer);
+            chunk.append_row(vec![n, Value::Varchar(s.to_string())]);
+        }
+        let names = |rows: Vec<Vec<Value>>| -> Vec<Value> {
+            rows.into_iter().map(|row| row[1].clone()).collect()
+        };
+        let text = |s: &str| Value::Varchar(s.to_string());
+
+        let mut sort = PhysicalSort::new(vec![key(0, ColumnType::Integer, false)], types.clone());
+        let mut output = DataChunk::empty();
+        assert_eq!(
+            sort.execute(&chunk, &mut output),
+            ExecuteResult::NeedMoreInput
+        );
+        assert!(output.is_empty());
+        assert_eq!(
+            names(drain(&mut sort)),
+            vec![text("c"), text("a"), text("d"), text("b")]
+        );
+
+        let mut sort = PhysicalSort::new(vec![key(0, ColumnType::Integer, true)], types);
+        sort.execute(&chunk, &mut output);
+        assert_eq!(
+            names(drain(&mut sort)),
+            vec![text("b"), text("a"), text("d"), text("c")]
+        );
+    }
+
+    #[test]
+    fn test_sort_spills_runs_past_the_memory_budget() {
+        let types = vec![
+            ColumnType::Integer,
+            ColumnType::Varchar,
+            ColumnType::Float,
+            ColumnType::Boolean,
+        ];
+        let chunks: Vec<DataChunk> = (0..4)
+            .map(|chunk_idx| {
+                let mut chunk = DataChunk::new(types.clone(), 1000);
+                for i in 0..1000 {
+                    let id = chunk_idx * 1000 + i;
+                    let score = match (id * 7919) % 101 {
+                        0 => Value::Null,
+                        s => Value::Integer(s),
+                    };
+                    chunk.append_row(vec![
+                        score,
+                        Value::Varchar(format!("row {}", id)),
+                        Value::Float(id as f64 / 2.0),
+                        Value::Boolean(id % 3 == 0),
+                    ]);
+                }
+                chunk
+            })
+            .collect();
+
+        let keys = vec![key(0, ColumnType::Integer, true)];
+        let mut in_memory = PhysicalSort::new(keys.clone(), types.clone());
+        let mut spilling = PhysicalSort::new(keys, types).with_memory_budget(64 * 1024);
+        let mut output = DataChunk::empty();
+        for chunk in &chunks {
+            in_memory.execute(chunk, &mut output);
+            spilling.execute(chunk, &mut output);
+        }
+        assert!(in_memory.runs.is_empty());
+        assert!(spilling.runs.len() >= 2, "{} runs", spilling.runs.len());
+        let paths: Vec<PathBuf> = spilling.runs.iter().map(|run| run.path.clone()).collect();
+
+        // the merged runs match the in-memory sort, ties included
+        let merged = drain(&mut spilling);
+        assert_eq!(merged.len(), 4000);
+        assert_eq!(merged, drain(&mut in_memory));
+        assert!(spilling.take_error().is_none());
+
+        // the run files go away with the operator's state
+        spilling.reset();
+        assert!(paths.iter().all(|path| !path.exists()));
+    }
+}
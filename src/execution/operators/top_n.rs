@@ -0,0 +1,273 @@
+use super::sort::compare_keys;
+use super::{ExecuteResult, PhysicalOperator};
+use crate::binder::{BoundOrderBy, ColumnType};
+use crate::collation::Collation;
+use crate::execution::data_chunk::{DataChunk, Value};
+use crate::execution::expression::ExpressionEvaluator;
+use std::cmp::Ordering;
+
+/// a buffered row: its key values, its input position and its column values
+struct HeapRow {
+    keys: Vec<Value>,
+    position: usize,
+    values: Vec<Value>,
+}
+
+/// physical operator for ORDER BY ... LIMIT (and OFFSET)
+/// keeps only the best limit + offset rows seen so far in a max-heap whose root
+/// is the worst of them, so memory stays bounded however large the input is.
+/// once the input ends it emits the kept rows in order, skipping the offset.
+/// ties are broken by input position, so the result matches a stable sort
+pub struct PhysicalTopN {
+    keys: Vec<BoundOrderBy>,
+    limit: usize,
+    offset: usize,
+    types: Vec<ColumnType>, // input (and output) column types
+    heap: Vec<HeapRow>,     // binary max-heap, at most limit + offset rows
+    seen: usize,            // input rows consumed so far
+    evaluator: ExpressionEvaluator,
+    collation: Collation,
+    sorted: Option<Vec<HeapRow>>, // the kept rows in order, once input ended
+    emitted: usize,               // sorted rows already written (offset included)
+    finished: bool,
+}
+
+impl PhysicalTopN {
+    pub fn new(
+        keys: Vec<BoundOrderBy>,
+        limit: usize,
+        offset: usize,
+        types: Vec<ColumnType>,
+    ) -> Self {
+        Self {
+            keys,
+            limit,
+            offset,
+            types,
+            heap: Vec::new(),
+            seen: 0,
+            evaluator: ExpressionEvaluator::new(Collation::Binary),
+            collation: Collation::Binary,
+            sorted: None,
+            emitted: 0,
+            finished: false,
+        }
+    }
+
+    /// order varchar keys using the given collation
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.evaluator = ExpressionEvaluator::new(collation);
+        self.collation = collation;
+        self
+    }
+
+    fn bound(&self) -> usize {
+        self.limit.saturating_add(self.offset)
+    }
+
+    fn compare(&self, left: &HeapRow, right: &HeapRow) -> Ordering {
+        compare_keys(self.collation, &self.keys, &left.keys, &right.keys)
+            .then(left.position.cmp(&right.position))
+    }
+
+    /// offer every selected row to the heap
+    fn consume(&mut self, chunk: &DataChunk) {
+        for row_idx in 0..chunk.selected_count() {
+            let keys = self
+                .keys
+                .iter()
+                .map(|key| {
+                    self.evaluator
+                        .evaluate(&key.expression, chunk, row_idx)
+                        .unwrap_or(Value::Null)
+                })
+                .collect();
+            // the row values are only copied once the row is known to be kept
+            let mut row = HeapRow {
+                keys,
+                position: self.seen,
+                values: Vec::new(),
+            };
+            self.seen += 1;
+
+            if self.heap.len() < self.bound() {
+                row.values = Self::row_values(chunk, row_idx);
+                self.heap.push(row);
+                self.sift_up(self.heap.len() - 1);
+            } else if self.compare(&row, &self.heap[0]) == Ordering::Less {
+                row.values = Self::row_values(chunk, row_idx);
+                self.heap[0] = row;
+                self.sift_down(0);
+            }
+        }
+    }
+
+    fn row_values(chunk: &DataChunk, row_idx: usize) -> Vec<Value> {
+        (0..chunk.column_count())
+            .map(|col_idx| chunk.get_value(col_idx, row_idx).unwrap_or(Value::Null))
+            .collect()
+    }
+
+    fn sift_up(&mut self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if self.compare(&self.heap[idx], &self.heap[parent]) != Ordering::Greater {
+                break;
+            }
+            self.heap.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut idx: usize) {
+        loop {
+            let largest = [2 * idx + 1, 2 * idx + 2]
+                .into_iter()
+                .filter(|&child| child < self.heap.len())
+                .fold(idx, |largest, child| {
+                    match self.compare(&self.heap[child], &self.heap[largest]) {
+                        Ordering::Greater => child,
+                        _ => largest,
+                    }
+                });
+            if largest == idx {
+                break;
+            }
+            self.heap.swap(idx, largest);
+            idx = largest;
+        }
+    }
+
+    /// write the next batch of sorted rows to the output
+    fn emit_batch(&mut self, output: &mut DataChunk) {
+        let rows = self.sorted.as_ref().unwrap();
+        let batch_end = (self.emitted + DataChunk::STANDARD_VECTOR_SIZE).min(rows.len());
+        output.reset_with_types(&self.types, batch_end - self.emitted);
+        for row in &rows[self.emitted..batch_end] {
+            output.append_row(row.values.clone());
+        }
+
+        self.emitted = batch_end;
+    }
+}
+
+impl PhysicalOperator for PhysicalTopN {
+    fn execute(&mut self, input: &DataChunk, output: &mut DataChunk) -> ExecuteResult {
+        if self.finished || self.limit == 0 {
+            output.reset();
+            self.finished = true;
+            return ExecuteResult::Finished;
+        }
+
+        // keep consuming input until the end of data
+        if !input.is_empty() {
+            self.consume(input);
+            output.reset();
+            return ExecuteResult::NeedMoreInput;
+        }
+
+        if self.sorted.is_none() {
+            let mut rows = std::mem::take(&mut self.heap);
+            rows.sort_by(|left, right| self.compare(left, right));
+            // the offset rows are only kept to find the ones after them
+            self.emitted = self.offset.min(rows.len());
+            self.sorted = Some(rows);
+        }
+        if self.emitted == self.sorted.as_ref().map_or(0, Vec::len) {
+            output.reset();
+            self.finished = true;
+            return ExecuteResult::Finished;
+        }
+
+        self.emit_batch(output);
+        if self.emitted < self.sorted.as_ref().map_or(0, Vec::len) {
+            ExecuteResult::HaveMoreOutput
+        } else {
+            self.finished = true;
+            ExecuteResult::Finished
+        }
+    }
+
+    fn reset(&mut self) {
+        self.heap.clear();
+        self.seen = 0;
+        self.sorted = None;
+        self.emitted = 0;
+        self.finished = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binder::BoundExpression;
+    use crate::execution::operators::PhysicalSort;
+
+    fn key(descending: bool) -> BoundOrderBy {
+        BoundOrderBy {
+            expression: BoundExpression::ColumnRef {
+                name: "score".to_string(),
+                index: 0,
+                type_: ColumnType::Integer,
+            },
+            descending,
+        }
+    }
+
+    /// feed the chunks, then collect every row the operator emits
+    fn run(op: &mut dyn PhysicalOperator, chunks: &[DataChunk]) -> Vec<Vec<Value>> {
+        let mut output = DataChunk::empty();
+        for chunk in chunks {
+            op.execute(chunk, &mut output);
+        }
+        let mut rows = Vec::new();
+        loop {
+            let mut output = DataChunk::empty();
+            let result = op.execute(&DataChunk::empty(), &mut output);
+            for row_idx in 0..output.selected_count() {
+                rows.push(PhysicalTopN::row_values(&output, row_idx));
+            }
+            if result == ExecuteResult::Finished {
+                return rows;
+            }
+        }
+    }
+
+    #[test]
+    fn test_top_n_matches_sort_then_limit() {
+        let types = vec![ColumnType::Integer, ColumnType::Integer];
+        // several chunks of scores with duplicates and NULLs
+        let chunks: Vec<DataChunk> = (0..5)
+            .map(|chunk_idx| {
+                let mut chunk = DataChunk::new(types.clone(), 100);
+                for i in 0..100 {
+                    let id = chunk_idx * 100 + i;
+                    let score = match (id * 37) % 50 {
+                        0 => Value::Null,
+                        s => Value::Integer(s),
+                    };
+                    chunk.append_row(vec![score, Value::Integer(id)]);
+                }
+                chunk
+            })
+            .collect();
+
+        for descending in [false, true] {
+            let sorted = run(
+                &mut PhysicalSort::new(vec![key(descending)], types.clone()),
+                &chunks,
+            );
+            for (limit, offset) in [(0, 0), (1, 0), (10, 0), (7, 3), (25, 490), (1000, 0)] {
+                let mut top_n =
+                    PhysicalTopN::new(vec![key(descending)], limit, offset, types.clone());
+                let mut output = DataChunk::empty();
+                for chunk in &chunks {
+                    top_n.execute(chunk, &mut output);
+                    assert!(top_n.heap.len() <= limit + offset);
+                }
+                let expected: Vec<_> = sorted.iter().skip(offset).take(limit).cloned().collect();
+                assert_eq!(run(&mut top_n, &[]), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use super::{ExecuteResult, ExecutionError, PhysicalOperator};
+use crate::execution::data_chunk::DataChunk;
+use crate::execution::executor::{OperatorMetrics, PipelineExecutor};
+use std::collections::VecDeque;
+
+/// source operator for UNION ALL
+/// runs the pipeline of each SELECT in turn and emits its result chunks,
+/// one per call, before starting the next SELECT
+pub struct PhysicalUnionAll {
+    branches: Vec<PipelineExecutor>,
+    current: usize,               // index of the next branch to run
+    pending: VecDeque<DataChunk>, // results of the last branch run, not emitted yet
+    error: Option<ExecutionError>,
+}
+
+impl PhysicalUnionAll {
+    pub fn new(branches: Vec<PipelineExecutor>) -> Self {
+        Self {
+            branches,
+            current: 0,
+            pending: VecDeque::new(),
+            error: None,
+        }
+    }
+}
+
+impl PhysicalOperator for PhysicalUnionAll {
+    fn execute(&mut self, _input: &DataChunk, output: &mut DataChunk) -> ExecuteResult {
+        loop {
+            if let Some(chunk) = self.pending.pop_front() {
+                *output = chunk;
+                return ExecuteResult::NeedMoreInput;
+            }
+            if self.current == self.branches.len() {
+                output.reset();
+                return ExecuteResult::Finished;
+            }
+
+            let branch = &mut self.branches[self.current];
+            self.current += 1;
+            match branch.try_execute() {
+                Ok(chunks) => self.pending = chunks.into(),
+                Err(error) => {
+                    self.error = Some(error);
+                    self.current = self.branches.len();
+                    output.reset();
+                    return ExecuteResult::Finished;
+                }
+            }
+        }
+    }
+
+    fn take_error(&mut self) -> Option<ExecutionError> {
+        self.error.take()
+    }
+
+    fn nested_metrics(&self) -> Vec<OperatorMetrics> {
+        self.branches
+            .iter()
+            .flat_map(PipelineExecutor::explain_metrics)
+            .collect()
+    }
+
+    fn reset(&mut self) {
+        for branch in &mut self.branches {
+            branch.reset();
+        }
+        self.current = 0;
+        self.pending.clear();
+        self.error = None;
+    }
+}
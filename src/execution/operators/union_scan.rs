@@ -0,0 +1,68 @@
+use super::{ExecuteResult, ExecutionError, PhysicalOperator, PhysicalScan};
+use crate::execution::data_chunk::DataChunk;
+
+/// source operator for `FROM 'a.csv', 'b.csv'`
+/// runs one scan per file and emits their chunks one file after another
+pub struct PhysicalUnionScan {
+    scans: Vec<PhysicalScan>,
+    current: usize,          // index of the scan being read
+    max_rows: Option<usize>, // stop opening files once this many rows were emitted
+    rows_emitted: usize,
+    error: Option<ExecutionError>,
+}
+
+impl PhysicalUnionScan {
+    pub fn new(scans: Vec<PhysicalScan>, max_rows: Option<usize>) -> Self {
+        Self {
+            scans,
+            current: 0,
+            max_rows,
+            rows_emitted: 0,
+            error: None,
+        }
+    }
+}
+
+impl PhysicalOperator for PhysicalUnionScan {
+    fn execute(&mut self, input: &DataChunk, output: &mut DataChunk) -> ExecuteResult {
+        // an empty output tells the executor the source is done, so move on
+        // to the next file until one produces rows
+        while self.current < self.scans.len() {
+            let scan = &mut self.scans[self.current];
+            scan.execute(input, output);
+
+            if let Some(error) = scan.take_error() {
+                self.error = Some(error);
+                self.current = self.scans.len();
+                output.reset();
+                return ExecuteResult::Finished;
+            }
+
+            if !output.is_empty() {
+                self.rows_emitted += output.count;
+                return ExecuteResult::NeedMoreInput;
+            }
+
+            self.current += 1;
+            if self.max_rows.is_some_and(|max| self.rows_emitted >= max) {
+                self.current = self.scans.len();
+            }
+        }
+
+        output.reset();
+        ExecuteResult::Finished
+    }
+
+    fn take_error(&mut self) -> Option<ExecutionError> {
+        self.error.take()
+    }
+
+    fn reset(&mut self) {
+        for scan in &mut self.scans {
+            scan.reset();
+        }
+        self.current = 0;
+        self.rows_emitted = 0;
+        self.error = None;
+    }
+}
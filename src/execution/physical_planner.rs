@@ -1,28 +1,61 @@
+use super::executor::PipelineExecutor;
 use super::operators::{
-    PhysicalFilter, PhysicalLimit, PhysicalOperator, PhysicalProjection, PhysicalScan,
-    PhysicalUngroupedAggregate,
+    PhysicalDistinct, PhysicalExplain, PhysicalFilter, PhysicalHashAggregate, PhysicalLimit,
+    PhysicalOperator, PhysicalProjection, PhysicalSample, PhysicalScan, PhysicalSort, PhysicalTopN,
+    PhysicalUngroupedAggregate, PhysicalUnionAll, PhysicalUnionScan,
 };
-use crate::binder::ColumnType;
+use crate::binder::{BoundOrderBy, ColumnType, Schema};
+use crate::collation::Collation;
 use crate::planner::{LogicalGet, LogicalOperator};
 
+/// bytes of rows a sort buffers before spilling sorted runs to disk
+pub const DEFAULT_SORT_MEMORY_BUDGET: usize = 256 * 1024 * 1024;
+
 /// physical plan generator
 /// converts logical operators into physical operators
-pub struct PhysicalPlanner;
+pub struct PhysicalPlanner {
+    collation: Collation,
+    strict: bool,
+    sort_memory_budget: usize,
+}
 
 impl PhysicalPlanner {
     pub fn new() -> Self {
-        Self
+        Self {
+            collation: Collation::Binary,
+            strict: false,
+            sort_memory_budget: DEFAULT_SORT_MEMORY_BUDGET,
+        }
+    }
+
+    /// fail the query on cells that don't parse as their column's type
+    /// instead of reading them as NULL
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// collation used by filters when comparing varchar values
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.collation = collation;
+        self
+    }
+
+    /// memory (in bytes) an ORDER BY may use before it sorts on disk
+    pub fn with_sort_memory_budget(mut self, bytes: usize) -> Self {
+        self.sort_memory_budget = bytes;
+        self
     }
 
     /// convert a logical plan into a physical plan
     /// returns a vector of operators in execution order (source first, sink last)
-    /// and the schema for each operator's output
+    /// and the schema (column names and types) of each operator's output
     pub fn plan(
         &self,
         logical_plan: LogicalOperator,
-    ) -> (Vec<Box<dyn PhysicalOperator>>, Vec<Vec<ColumnType>>) {
+    ) -> (Vec<Box<dyn PhysicalOperator>>, Vec<Schema>) {
         let mut operators: Vec<Box<dyn PhysicalOperator>> = Vec::new();
-        let mut schemas: Vec<Vec<ColumnType>> = Vec::new();
+        let mut schemas: Vec<Schema> = Vec::new();
 
         self.build_pipeline(logical_plan, &mut operators, &mut schemas);
 
@@ -34,7 +67,7 @@ impl PhysicalPlanner {
         &self,
         operator: LogicalOperator,
         operators: &mut Vec<Box<dyn PhysicalOperator>>,
-        schemas: &mut Vec<Vec<ColumnType>>,
+        schemas: &mut Vec<Schema>,
     ) {
         match operator {
             LogicalOperator::Get(get) => {
@@ -53,10 +86,11 @@ impl PhysicalPlanner {
                 // recurse to child first (build bottom-up)
                 let child = *projection.child;
                 let expressions = projection.expressions;
+                let aliases = projection.aliases;
                 self.build_pipeline(child, operators, schemas);
 
                 // then add projection
-                self.build_projection_with_exprs(expressions, operators, schemas);
+                self.build_projection_with_exprs(expressions, aliases, operators, schemas);
             }
             LogicalOperator::Limit(limit) => {
                 // recurse to child first (build bottom-up)
@@ -68,14 +102,100 @@ impl PhysicalPlanner {
                 // then add limit
                 self.build_limit(limit_value, offset_value, operators, schemas);
             }
+            LogicalOperator::Sort(sort) => {
+                // recurse to child first (build bottom-up)
+                self.build_pipeline(*sort.child, operators, schemas);
+
+                // then add sort
+                self.build_sort(sort.keys, operators, schemas);
+            }
+            LogicalOperator::TopN(top_n) => {
+                // recurse to child first (build bottom-up)
+                self.build_pipeline(*top_n.child, operators, schemas);
+
+                // then add top-n
+                self.build_top_n(top_n.keys, top_n.limit, top_n.offset, operators, schemas);
+            }
+            LogicalOperator::Sample(sample) => {
+                // recurse to child first (build bottom-up)
+                self.build_pipeline(*sample.child, operators, schemas);
+
+                // then add sample; it keeps the input schema
+                let input_schema = schemas.last().unwrap().clone();
+                operators.push(Box::new(PhysicalSample::new(
+                    sample.size,
+                    sample.seed,
+                    input_schema.types(),
+                )));
+                schemas.push(input_schema);
+            }
+            LogicalOperator::Union(union) => {
+                // each SELECT runs as a pipeline of its own inside the union;
+                // the columns are named like the first SELECT's
+                let mut output_schema: Option<Schema> = None;
+                let branches = union
+                    .children
+                    .into_iter()
+                    .map(|child| {
+                        let (child_operators, child_schemas) = self.plan(child);
+                        let executor = PipelineExecutor::new(child_operators, child_schemas);
+                        let branch_schema = executor.output_schema();
+                        match output_schema.as_mut() {
+                            // an all-NULL column takes a later SELECT's type
+                            Some(schema) => {
+                                for (column, other) in
+                                    schema.columns.iter_mut().zip(&branch_schema.columns)
+                                {
+                                    if column.type_ == ColumnType::Null {
+                                        column.type_ = other.type_.clone();
+                                    }
+                                }
+                            }
+                            None => output_schema = Some(branch_schema.clone()),
+                        }
+                        executor
+                    })
+                    .collect();
+                operators.push(Box::new(PhysicalUnionAll::new(branches)));
+                schemas.push(output_schema.unwrap_or(Schema { columns: vec![] }));
+            }
+            LogicalOperator::Distinct(distinct) => {
+                // recurse to child first (build bottom-up)
+                self.build_pipeline(*distinct.child, operators, schemas);
+
+                // then drop the duplicates; the schema stays the same
+                let input_schema = schemas.last().unwrap().clone();
+                operators.push(Box::new(PhysicalDistinct::new(input_schema.types())));
+                schemas.push(input_schema);
+            }
+            LogicalOperator::Explain(explain) => {
+                // the plan text replaces the whole pipeline; ANALYZE runs
+                // that pipeline inside the explain operator first
+                let plan = explain.child.explain();
+                let physical_explain = if explain.analyze {
+                    let (child_operators, child_schemas) = self.plan(*explain.child);
+                    PhysicalExplain::analyze(
+                        plan,
+                        PipelineExecutor::new(child_operators, child_schemas),
+                    )
+                } else {
+                    PhysicalExplain::new(plan)
+                };
+                operators.push(Box::new(physical_explain));
+                schemas.push(Schema::from_columns([(
+                    "plan".to_string(),
+                    ColumnType::Varchar,
+                )]));
+            }
             LogicalOperator::Aggregate(agg_op) => {
                 // recurse to child first (build bottom-up)
                 let child = *agg_op.child;
+                let groups = agg_op.groups;
                 let aggregates = agg_op.aggregates;
                 self.build_pipeline(child, operators, schemas);
 
                 // then add aggregate
-                self.build_aggregate(aggregates, operators, schemas);
+                self.build_aggregate(groups, aggregates, operators, schemas);
             }
         }
     }
@@ -84,26 +204,54 @@ impl PhysicalPlanner {
         &self,
         get: LogicalGet,
         operators: &mut Vec<Box<dyn PhysicalOperator>>,
-        schemas: &mut Vec<Vec<ColumnType>>,
+        schemas: &mut Vec<Schema>,
     ) {
         // the optimizer has already filtered the columns list
         // extract the original indices for projection pushdown
         let projected_columns: Vec<usize> = get.columns.iter().map(|col| col.index).collect();
 
-        // output schema matches the projected columns
-        let output_schema: Vec<ColumnType> =
-            get.columns.iter().map(|col| col.type_.clone()).collect();
+        // output schema matches the projected columns, renumbered from 0
+        let output_schema = Schema::from_columns(
+            get.columns
+                .iter()
+                .map(|col| (col.name.clone(), col.type_.clone())),
+        );
 
         // create schema object for PhysicalScan
         // note: We need the FULL schema here (all columns from the file)
         // but we only have the projected ones. For now, use projected as schema.
         // todo: Pass full schema from binder
-        let schema = crate::binder::Schema {
+        let schema = Schema {
             columns: get.columns.clone(),
         };
 
-        let scan = PhysicalScan::new(get.file_path, schema, Some(projected_columns), get.max_rows);
-        operators.push(Box::new(scan));
+        // one scan per FROM file; several are read one after another
+        let scans: Vec<PhysicalScan> = std::iter::once(get.file_path)
+            .chain(get.union_files)
+            .map(|file_path| {
+                let scan = PhysicalScan::new(
+                    file_path,
+                    schema.clone(),
+                    Some(projected_columns.clone()),
+                    get.max_rows,
+                )
+                .with_strict(self.strict)
+                .with_header(get.has_header)
+                .with_format(get.format)
+                .with_empty_as_null(get.empty_as_null)
+                .with_null_tokens(get.null_tokens.clone());
+                // a bound source replaces the file
+                match &get.source {
+                    Some(source) => scan.with_source(source.clone()),
+                    None => scan,
+                }
+            })
+            .collect();
+        if scans.len() == 1 {
+            operators.push(Box::new(scans.into_iter().next().unwrap()));
+        } else {
+            operators.push(Box::new(PhysicalUnionScan::new(scans, get.max_rows)));
+        }
         schemas.push(output_schema);
     }
 
@@ -111,12 +259,12 @@ impl PhysicalPlanner {
         &self,
         expression: crate::binder::BoundExpression,
         operators: &mut Vec<Box<dyn PhysicalOperator>>,
-        schemas: &mut Vec<Vec<ColumnType>>,
+        schemas: &mut Vec<Schema>,
     ) {
         // filter doesn't change the schema - output schema is same as input
         let input_schema = schemas.last().unwrap().clone();
 
-        let physical_filter = PhysicalFilter::new(expression);
+        let physical_filter = PhysicalFilter::new(expression).with_collation(self.collation);
         operators.push(Box::new(physical_filter));
         schemas.push(input_schema);
     }
@@ -124,33 +272,70 @@ impl PhysicalPlanner {
     fn build_projection_with_exprs(
         &self,
         expressions: Vec<crate::binder::BoundExpression>,
+        aliases: Vec<Option<String>>,
         operators: &mut Vec<Box<dyn PhysicalOperator>>,
-        schemas: &mut Vec<Vec<ColumnType>>,
+        schemas: &mut Vec<Schema>,
     ) {
-        // projection output schema is determined by the expressions
-        let output_schema: Vec<ColumnType> = expressions
-            .iter()
-            .map(|expr| {
-                // for now, we only support ColumnRef
-                if let crate::binder::BoundExpression::ColumnRef { type_, .. } = expr {
-                    type_.clone()
-                } else {
-                    ColumnType::Null // fallback for unsupported expressions
+        // projection output schema is determined by the expressions;
+        // computed columns are named by their SQL text unless renamed with AS
+        // (hand-built plans may leave aliases empty)
+        let aliases = aliases.into_iter().chain(std::iter::repeat(None));
+        let output_schema =
+            Schema::from_columns(expressions.iter().zip(aliases).map(|(expr, alias)| {
+                match (expr, alias) {
+                    (_, Some(alias)) => (alias, expr.result_type()),
+                    (crate::binder::BoundExpression::ColumnRef { name, type_, .. }, None) => {
+                        (name.clone(), type_.clone())
+                    }
+                    (_, None) => (expr.to_string(), expr.result_type()),
                 }
-            })
-            .collect();
+            }));
 
-        let physical_projection = PhysicalProjection::new(expressions);
+        let physical_projection =
+            PhysicalProjection::new(expressions).with_collation(self.collation);
         operators.push(Box::new(physical_projection));
         schemas.push(output_schema);
     }
 
+    fn build_sort(
+        &self,
+        keys: Vec<BoundOrderBy>,
+        operators: &mut Vec<Box<dyn PhysicalOperator>>,
+        schemas: &mut Vec<Schema>,
+    ) {
+        // sort reorders rows but doesn't change the schema
+        let input_schema = schemas.last().unwrap().clone();
+
+        let physical_sort = PhysicalSort::new(keys, input_schema.types())
+            .with_collation(self.collation)
+            .with_memory_budget(self.sort_memory_budget);
+        operators.push(Box::new(physical_sort));
+        schemas.push(input_schema);
+    }
+
+    fn build_top_n(
+        &self,
+        keys: Vec<BoundOrderBy>,
+        limit: usize,
+        offset: usize,
+        operators: &mut Vec<Box<dyn PhysicalOperator>>,
+        schemas: &mut Vec<Schema>,
+    ) {
+        // like sort, top-n keeps the input schema
+        let input_schema = schemas.last().unwrap().clone();
+
+        let physical_top_n = PhysicalTopN::new(keys, limit, offset, input_schema.types())
+            .with_collation(self.collation);
+        operators.push(Box::new(physical_top_n));
+        schemas.push(input_schema);
+    }
+
     fn build_limit(
         &self,
         limit: Option<usize>,
         offset: Option<usize>,
         operators: &mut Vec<Box<dyn PhysicalOperator>>,
-        schemas: &mut Vec<Vec<ColumnType>>,
+        schemas: &mut Vec<Schema>,
     ) {
         // limit doesn't change the schema - output schema is same as input
         let input_schema = schemas.last().unwrap().clone();
@@ -162,15 +347,24 @@ impl PhysicalPlanner {
 
     fn build_aggregate(
         &self,
+        groups: Vec<crate::binder::BoundExpression>,
         aggregates: Vec<crate::binder::BoundAggregateExpression>,
         operators: &mut Vec<Box<dyn PhysicalOperator>>,
-        schemas: &mut Vec<Vec<ColumnType>>,
+        schemas: &mut Vec<Schema>,
     ) {
-        // aggregate produces one INTEGER column per aggregate function
-        let output_schema = vec![ColumnType::Integer; aggregates.len()];
+        // aggregate produces one column per group expression, then one per aggregate function
+        let output_schema = Schema::from_columns(
+            groups
+                .iter()
+                .map(|g| (g.to_string(), g.result_type()))
+                .chain(aggregates.iter().map(|a| (a.to_string(), a.result_type()))),
+        );
 
-        let physical_aggregate = PhysicalUngroupedAggregate::new(aggregates);
-        operators.push(Box::new(physical_aggregate));
+        if groups.is_empty() {
+            operators.push(Box::new(PhysicalUngroupedAggregate::new(aggregates)));
+        } else {
+            operators.push(Box::new(PhysicalHashAggregate::new(groups, aggregates)));
+        }
         schemas.push(output_schema);
     }
 }
@@ -0,0 +1,137 @@
+use crate::date::{format_date, format_timestamp};
+use crate::execution::{DataChunk, Value};
+use std::io::{self, Write};
+
+/// how result values are rendered as text by the table and CSV writers
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueFormat {
+    pub precision: Option<usize>, // decimals for floats (see format_float)
+    pub null_token: String,       // text written for NULL
+}
+
+impl ValueFormat {
+    /// table output: NULL is shown as `NULL`
+    pub fn table() -> Self {
+        Self {
+            precision: None,
+            null_token: "NULL".to_string(),
+        }
+    }
+
+    /// CSV output: NULL is written as an empty field
+    pub fn csv() -> Self {
+        Self {
+            precision: None,
+            null_token: String::new(),
+        }
+    }
+
+    pub fn with_precision(mut self, precision: Option<usize>) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn with_null_token(mut self, null_token: impl Into<String>) -> Self {
+        self.null_token = null_token.into();
+        self
+    }
+
+    /// text form of a value (None is a NULL cell, as returned by DataChunk::get_value)
+    pub fn format(&self, value: Option<&Value>) -> String {
+        match value {
+            Some(Value::Integer(i)) => i.to_string(),
+            Some(Value::Float(f)) => format_float(*f, self.precision),
+            Some(Value::Boolean(b)) => b.to_string(),
+            Some(Value::Varchar(s)) => s.clone(),
+            Some(Value::Date(d)) => format_date(*d),
+            Some(Value::Timestamp(t)) => format_timestamp(*t),
+            Some(Value::Null) | None => self.null_token.clone(),
+        }
+    }
+}
+
+/// write query results as CSV: a header line, then one line per selected row
+pub fn write_csv<W: Write>(
+    out: W,
+    column_names: &[String],
+    chunks: &[DataChunk],
+    format: &ValueFormat,
+) -> io::Result<()> {
+    let mut writer = csv::Writer::from_writer(out);
+    writer.write_record(column_names)?;
+    for chunk in chunks {
+        for row_idx in 0..chunk.selected_count() {
+            let row = (0..chunk.column_count())
+                .map(|col_idx| format.format(chunk.get_value(col_idx, row_idx).as_ref()));
+            writer.write_record(row)?;
+        }
+    }
+    writer.flush()
+}
+
+/// text form of a float for result output.
+///
+/// with a precision, the value is rounded to exactly that many decimals
+/// (`45000.0` at 2 is `45000.00`, at 0 is `45000`). without one, the shortest
+/// text that reads back as the same value is used, and whole values keep a
+/// trailing `.0` so they can't be mistaken for integers (`45000.0`, `0.5`,
+/// `0.30000000000000004`).
+pub fn format_float(value: f64, precision: Option<usize>) -> String {
+    match precision {
+        Some(digits) => format!("{:.*}", digits, value),
+        None if value.is_finite() && value.fract() == 0.0 => format!("{:.1}", value),
+        None => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binder::ColumnType;
+
+    #[test]
+    fn test_format_float() {
+        assert_eq!(format_float(45000.0, None), "45000.0");
+        assert_eq!(format_float(50000.5, None), "50000.5");
+        assert_eq!(format_float(0.1 + 0.2, None), "0.30000000000000004");
+        assert_eq!(format_float(-3.0, None), "-3.0");
+        assert_eq!(format_float(f64::NAN, None), "NaN");
+
+        assert_eq!(format_float(0.1 + 0.2, Some(2)), "0.30");
+        assert_eq!(format_float(45000.0, Some(2)), "45000.00");
+        assert_eq!(format_float(45000.0, Some(0)), "45000");
+        assert_eq!(format_float(2.675, Some(1)), "2.7");
+    }
+
+    #[test]
+    fn test_null_token() {
+        assert_eq!(ValueFormat::table().format(None), "NULL");
+        assert_eq!(ValueFormat::csv().format(Some(&Value::Null)), "");
+        let format = ValueFormat::table().with_null_token("-");
+        assert_eq!(format.format(None), "-");
+        assert_eq!(format.format(Some(&Value::Integer(7))), "7");
+    }
+
+    #[test]
+    fn test_write_csv_with_null_token() {
+        let mut chunk = DataChunk::new(vec![ColumnType::Varchar, ColumnType::Float], 4);
+        chunk.append_row(vec![Value::Varchar("a, b".to_string()), Value::Float(1.0)]);
+        chunk.append_row(vec![Value::Null, Value::Float(0.25)]);
+        let names = vec!["name".to_string(), "score".to_string()];
+
+        let mut out = Vec::new();
+        write_csv(&mut out, &names, &[chunk.clone()], &ValueFormat::csv()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "name,score\n\"a, b\",1.0\n,0.25\n"
+        );
+
+        let mut out = Vec::new();
+        let format = ValueFormat::csv().with_null_token("\\N");
+        write_csv(&mut out, &names, &[chunk], &format).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "name,score\n\"a, b\",1.0\n\\N,0.25\n"
+        );
+    }
+}
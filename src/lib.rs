@@ -1,14 +1,41 @@
 pub mod binder;
+pub mod collation;
+pub mod compression;
 pub mod config;
+pub mod date;
+pub mod engine;
+pub mod error;
+pub mod estimator;
 pub mod execution;
+pub mod format;
 pub mod optimizer;
 pub mod parser;
 pub mod planner;
+pub mod source;
 
-pub use binder::{Binder, BoundExpression, BoundQuery, Column, ColumnType, Schema};
+pub use binder::{
+    Binder, BoundExpression, BoundQuery, Column, ColumnType, HeaderMode, ScalarFunction, Schema,
+    SourceFormat,
+};
+pub use collation::Collation;
+pub use engine::QueryEngine;
+pub use error::CelectError;
+pub use estimator::Estimator;
 pub use execution::{
-    DataChunk, ExecuteResult, PhysicalOperator, PhysicalPlanner, PipelineExecutor, Value, Vector,
+    DataChunk, ExecuteResult, ExecutionError, PhysicalOperator, PhysicalPlanner, PipelineExecutor,
+    Value, ValueError, Vector,
 };
 pub use optimizer::Optimizer;
 pub use parser::Parser;
-pub use planner::{LogicalFilter, LogicalGet, LogicalOperator, LogicalProjection, Planner};
+pub use planner::{
+    LogicalFilter, LogicalGet, LogicalOperator, LogicalProjection, Planner, SampleMethod,
+};
+pub use source::{CsvFileSource, DataSource, StringSource};
+
+/// parse and bind a query without running it: the result carries the resolved
+/// file, its schema and the output columns. only the file's header and type
+/// sample are read
+pub fn validate_query(sql: &str) -> Result<BoundQuery, CelectError> {
+    let query = Parser::new().parse(sql)?;
+    Ok(Binder::new().bind(query)?)
+}
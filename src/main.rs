@@ -1,21 +1,159 @@
 use celect::config::VERSION;
-use celect::{Binder, Optimizer, Parser, PhysicalPlanner, PipelineExecutor, Planner, Value};
+use celect::format::{ValueFormat, write_csv};
+use celect::{
+    Binder, Optimizer, Parser, PhysicalPlanner, PipelineExecutor, Planner, SampleMethod, Value,
+};
 use colored::*;
-use comfy_table::{Table, Cell, ContentArrangement, presets::ASCII_FULL};
-use rustyline::error::ReadlineError;
+use comfy_table::{Cell, ContentArrangement, Table, presets::ASCII_FULL};
 use rustyline::DefaultEditor;
-use std::sync::atomic::{AtomicBool, Ordering};
+use rustyline::error::ReadlineError;
 use std::sync::Arc;
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// command-line options shared by one-shot and REPL runs
+#[derive(Debug, Clone, Default)]
+struct Options {
+    verbose: bool,              // print per-stage timings
+    strict: bool,               // fail on bad cells and ragged rows
+    precision: Option<usize>,   // decimals shown for floats (--precision N)
+    csv: bool,                  // write results as CSV instead of a table
+    null_token: Option<String>, // text for NULL (default NULL in tables, empty in CSV)
+    sample_seed: Option<u64>,   // SAMPLE picks random rows with this seed (--sample-seed N)
+}
+
+impl Options {
+    /// how values are rendered for the selected output mode
+    fn value_format(&self) -> ValueFormat {
+        let format = if self.csv {
+            ValueFormat::csv()
+        } else {
+            ValueFormat::table()
+        };
+        let format = format.with_precision(self.precision);
+        match &self.null_token {
+            Some(token) => format.with_null_token(token.clone()),
+            None => format,
+        }
+    }
+}
 
 fn main() {
-    println!("{} {}", "Celect SQL Engine".bright_cyan().bold(), format!("v{}", VERSION).dimmed());
+    let mut options = Options::default();
+    let mut query: Option<String> = None;
+    let mut query_file: Option<String> = None;
+    let mut schema_file: Option<String> = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--repl" => {} // interactive prompt (the default)
+            "--verbose" | "-v" => options.verbose = true,
+            "--strict" => options.strict = true,
+            "--precision" => match args.next().and_then(|n| n.parse().ok()) {
+                Some(digits) => options.precision = Some(digits),
+                None => usage_error("--precision requires a number of decimal places"),
+            },
+            "--sample-seed" => match args.next().and_then(|n| n.parse().ok()) {
+                Some(seed) => options.sample_seed = Some(seed),
+                None => usage_error("--sample-seed requires a number"),
+            },
+            "--csv" => options.csv = true,
+            "--null" => match args.next() {
+                Some(token) => options.null_token = Some(token),
+                None => usage_error("--null requires a token"),
+            },
+            "--file" | "-f" => match args.next() {
+                Some(path) => query_file = Some(path),
+                None => usage_error("--file requires a path"),
+            },
+            "--schema" => match args.next() {
+                Some(path) => schema_file = Some(path),
+                None => usage_error("--schema requires a path"),
+            },
+            _ if arg.starts_with('-') => usage_error(&format!("unknown argument '{}'", arg)),
+            _ if query.is_some() => usage_error(&format!("unexpected argument '{}'", arg)),
+            _ => query = Some(arg),
+        }
+    }
+
+    if let Some(path) = schema_file {
+        if query.is_some() || query_file.is_some() {
+            usage_error("pass either --schema or a query, not both");
+        }
+        match describe_file(&path, &options) {
+            QueryStatus::Done => return,
+            _ => std::process::exit(1),
+        }
+    }
+
+    let sql = match (query, query_file) {
+        (Some(_), Some(_)) => usage_error("pass either a query or --file, not both"),
+        (Some(sql), None) => sql,
+        (None, Some(path)) => match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                eprintln!(
+                    "{} cannot read query file '{}': {}",
+                    "error:".red().bold(),
+                    path,
+                    e
+                );
+                std::process::exit(1);
+            }
+        },
+        (None, None) => {
+            run_repl(options);
+            return;
+        }
+    };
+
+    let sql = sql.trim();
+    if sql.is_empty() {
+        eprintln!("{} query is empty", "error:".red().bold());
+        eprintln!(
+            "  {} SELECT ... FROM '...' [WHERE ...] [LIMIT ...]",
+            "hint:".dimmed()
+        );
+        std::process::exit(1);
+    }
+
+    // one-shot mode: run the query and exit
+    let interrupted = Arc::new(AtomicBool::new(false));
+    match execute_query(sql, &interrupted, &options) {
+        QueryStatus::Done => {}
+        QueryStatus::Failed => std::process::exit(1),
+        QueryStatus::Interrupted => std::process::exit(130),
+    }
+}
+
+/// print an argument error with usage and exit
+fn usage_error(message: &str) -> ! {
+    eprintln!("{} {}", "error:".red().bold(), message);
+    eprintln!(
+        "  {} celect [--verbose] [--strict] [--precision N] [--sample-seed N] [--csv] [--null TOKEN] [QUERY | --file PATH]",
+        "usage:".dimmed()
+    );
+    eprintln!(
+        "         celect [--repl] [--verbose] [--strict] [--precision N] [--sample-seed N] [--csv] [--null TOKEN]"
+    );
+    eprintln!("         celect [--csv] --schema PATH");
+    std::process::exit(2);
+}
+
+/// interactive prompt: reads SQL lines until EOF or exit
+fn run_repl(options: Options) {
+    println!(
+        "{} {}",
+        "Celect SQL Engine".bright_cyan().bold(),
+        format!("v{}", VERSION).dimmed()
+    );
     println!("Press Ctrl+D to exit, {} for help\n", ".help".green());
-    
+
     // setup Ctrl+C handler
     let interrupted = Arc::new(AtomicBool::new(false));
     let interrupted_clone = interrupted.clone();
-    
+
     ctrlc::set_handler(move || {
         interrupted_clone.store(true, Ordering::SeqCst);
     })
@@ -28,13 +166,13 @@ fn main() {
             return;
         }
     };
-    
+
     let mut ctrl_c_count = 0;
-    
+
     loop {
         // reset interrupt flag before each command
         interrupted.store(false, Ordering::SeqCst);
-        
+
         let readline = rl.readline("celect> ");
 
         match readline {
@@ -52,6 +190,11 @@ fn main() {
                 // add to history
                 let _ = rl.add_history_entry(sql);
 
+                if sql.eq_ignore_ascii_case("exit") || sql.eq_ignore_ascii_case("quit") {
+                    println!("exit");
+                    break;
+                }
+
                 // handle meta commands
                 if sql.starts_with('.') {
                     handle_meta_command(sql);
@@ -59,7 +202,7 @@ fn main() {
                 }
 
                 // execute query
-                if execute_query(sql, &interrupted) {
+                if execute_query(sql, &interrupted, &options) != QueryStatus::Interrupted {
                     // query completed normally
                     println!();
                 } else {
@@ -71,7 +214,7 @@ fn main() {
             Err(ReadlineError::Interrupted) => {
                 // Ctrl+C at prompt - increment counter
                 ctrl_c_count += 1;
-                
+
                 if ctrl_c_count >= 2 {
                     eprintln!("{}", "To exit, press Ctrl+D".dimmed());
                 }
@@ -90,15 +233,26 @@ fn main() {
     }
 }
 
-fn execute_query(sql: &str, interrupted: &Arc<AtomicBool>) -> bool {
+/// how a single query run ended
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum QueryStatus {
+    Done,
+    Failed, // error already printed
+    Interrupted,
+}
+
+fn execute_query(sql: &str, interrupted: &Arc<AtomicBool>, options: &Options) -> QueryStatus {
     let start_time = Instant::now();
-    
+    // per-stage timings, printed with --verbose
+    let mut timings: Vec<(&str, Duration)> = Vec::new();
+
     // check for interrupt
     if interrupted.load(Ordering::SeqCst) {
-        return false;
+        return QueryStatus::Interrupted;
     }
 
     // step 1: parse
+    let stage_start = Instant::now();
     let mut parser = Parser::new();
     let query = match parser.parse(sql) {
         Ok(q) => q,
@@ -107,62 +261,81 @@ fn execute_query(sql: &str, interrupted: &Arc<AtomicBool>) -> bool {
             if e.offset > 0 {
                 eprintln!("  {} offset {}", "at".dimmed(), e.offset);
             }
-            eprintln!("  {} SELECT ... FROM '...' [WHERE ...] [LIMIT ...]", "hint:".dimmed());
-            return true;
+            eprintln!(
+                "  {} SELECT ... FROM '...' [WHERE ...] [LIMIT ...]",
+                "hint:".dimmed()
+            );
+            return QueryStatus::Failed;
         }
     };
+    timings.push(("parse", stage_start.elapsed()));
 
     // check for interrupt
     if interrupted.load(Ordering::SeqCst) {
-        return false;
-            }
-            
+        return QueryStatus::Interrupted;
+    }
+
     // step 2: bind
-    let binder = Binder::new();
+    let stage_start = Instant::now();
+    let binder = Binder::new().with_strict(options.strict);
     let bound_query = match binder.bind(query) {
         Ok(bq) => bq,
         Err(e) => {
             eprintln!("{} {}", "error:".red().bold(), e.message);
-            return true;
+            return QueryStatus::Failed;
         }
     };
+    timings.push(("bind", stage_start.elapsed()));
 
     // check for interrupt
     if interrupted.load(Ordering::SeqCst) {
-        return false;
+        return QueryStatus::Interrupted;
     }
 
-    // extract column names for display
-    let column_names: Vec<String> = bound_query
-        .select_columns
-        .iter()
-        .map(|col| col.name.clone())
-        .collect();
-
     // step 3: plan
-    let planner = Planner::new();
+    let stage_start = Instant::now();
+    let planner = match options.sample_seed {
+        Some(seed) => Planner::new().with_sample_method(SampleMethod::Reservoir { seed }),
+        None => Planner::new(),
+    };
     let logical_plan = planner.plan(bound_query);
-    
+    timings.push(("plan", stage_start.elapsed()));
+
     // step 4: optimize
+    let stage_start = Instant::now();
     let optimizer = Optimizer::new();
     let optimized_plan = optimizer.optimize(logical_plan);
+    timings.push(("optimize", stage_start.elapsed()));
 
     // step 5: physical plan
-    let physical_planner = PhysicalPlanner::new();
+    let stage_start = Instant::now();
+    let physical_planner = PhysicalPlanner::new().with_strict(options.strict);
     let (operators, schemas) = physical_planner.plan(optimized_plan);
+    timings.push(("physical plan", stage_start.elapsed()));
 
     // check for interrupt before execution
     if interrupted.load(Ordering::SeqCst) {
-        return false;
+        return QueryStatus::Interrupted;
     }
 
     // step 6: execute
+    let stage_start = Instant::now();
     let mut executor = PipelineExecutor::new(operators, schemas);
-    let results = executor.execute();
-    
+    let results = match executor.try_execute() {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("{} {}", "error:".red().bold(), e.message);
+            return QueryStatus::Failed;
+        }
+    };
+    timings.push(("execute", stage_start.elapsed()));
+
+    // column names for display, from the final operator's output schema
+    let column_names = executor.output_schema().names();
+
     // check for interrupt after execution
     if interrupted.load(Ordering::SeqCst) {
-        return false;
+        return QueryStatus::Interrupted;
     }
 
     let duration = start_time.elapsed();
@@ -172,16 +345,34 @@ fn execute_query(sql: &str, interrupted: &Arc<AtomicBool>) -> bool {
         format!("{}ms", duration.as_millis())
     };
 
+    let value_format = options.value_format();
+
+    // CSV output: just the header and rows, so it can be redirected to a file
+    if options.csv {
+        if let Err(e) = write_csv(
+            std::io::stdout().lock(),
+            &column_names,
+            &results,
+            &value_format,
+        ) {
+            eprintln!("{} {}", "error:".red().bold(), e);
+            return QueryStatus::Failed;
+        }
+        print_timings(&timings, options.verbose);
+        return QueryStatus::Done;
+    }
+
     // display results
     if results.is_empty() {
         println!("\n{}", format!("(0 rows in {})", time_str).dimmed());
-        return true;
+        print_timings(&timings, options.verbose);
+        return QueryStatus::Done;
     }
 
-    let total_rows: usize = results.iter().map(|chunk| chunk.count).sum();
+    let total_rows: usize = results.iter().map(|chunk| chunk.selected_count()).sum();
 
     // create table
-    println!();  // blank line before table
+    println!(); // blank line before table
     if let Some(first_chunk) = results.first() {
         let mut table = Table::new();
         table
@@ -204,29 +395,87 @@ fn execute_query(sql: &str, interrupted: &Arc<AtomicBool>) -> bool {
 
         // add rows
         for chunk in &results {
-            for row_idx in 0..chunk.count {
+            for row_idx in 0..chunk.selected_count() {
                 // check for interrupt while printing
                 if interrupted.load(Ordering::SeqCst) {
-                    return false;
+                    return QueryStatus::Interrupted;
                 }
-                
-                let row: Vec<Cell> = chunk
-                    .columns
-                    .iter()
-                    .map(|col| match col.get(row_idx) {
-                        Some(value) => Cell::new(format_value(&value)),
-                        None => Cell::new("NULL").fg(comfy_table::Color::DarkGrey),
+
+                // get_value respects the selection vector (e.g. LIMIT/OFFSET)
+                let row: Vec<Cell> = (0..chunk.column_count())
+                    .map(|col_idx| match chunk.get_value(col_idx, row_idx) {
+                        Some(Value::Null) | None => {
+                            Cell::new(value_format.format(None)).fg(comfy_table::Color::DarkGrey)
+                        }
+                        value => Cell::new(value_format.format(value.as_ref())),
                     })
                     .collect();
                 table.add_row(row);
             }
         }
-        
+
         println!("{}", table);
     }
-    
-    println!("{}", format!("({} rows in {})", total_rows, time_str).dimmed());
-    true
+
+    println!(
+        "{}",
+        format!("({} rows in {})", total_rows, time_str).dimmed()
+    );
+    print_timings(&timings, options.verbose);
+    QueryStatus::Done
+}
+
+/// print the column names and inferred types of a file (--schema PATH)
+fn describe_file(path: &str, options: &Options) -> QueryStatus {
+    let schema = match Binder::new().describe(path) {
+        Ok(schema) => schema,
+        Err(e) => {
+            eprintln!("{} {}", "error:".red().bold(), e.message);
+            return QueryStatus::Failed;
+        }
+    };
+    let rows: Vec<[String; 2]> = schema
+        .columns
+        .iter()
+        .map(|col| [col.name.clone(), format!("{:?}", col.type_)])
+        .collect();
+
+    if options.csv {
+        let mut writer = csv::Writer::from_writer(std::io::stdout().lock());
+        let written = std::iter::once(&["column".to_string(), "type".to_string()])
+            .chain(&rows)
+            .try_for_each(|row| writer.write_record(row))
+            .and_then(|_| writer.flush().map_err(csv::Error::from));
+        if let Err(e) = written {
+            eprintln!("{} {}", "error:".red().bold(), e);
+            return QueryStatus::Failed;
+        }
+        return QueryStatus::Done;
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(ASCII_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(["column", "type"].map(|name| Cell::new(name).fg(comfy_table::Color::Cyan)));
+    for row in rows {
+        table.add_row(row);
+    }
+    println!("{}", table);
+    QueryStatus::Done
+}
+
+/// print how long each pipeline stage took (only with --verbose)
+fn print_timings(timings: &[(&str, Duration)], verbose: bool) {
+    if !verbose {
+        return;
+    }
+
+    let stages: Vec<String> = timings
+        .iter()
+        .map(|(stage, duration)| format!("{} {:.2}ms", stage, duration.as_secs_f64() * 1000.0))
+        .collect();
+    println!("{}", stages.join(", ").dimmed());
 }
 
 fn handle_meta_command(cmd: &str) {
@@ -234,25 +483,55 @@ fn handle_meta_command(cmd: &str) {
         ".help" => {
             println!("\n{}", "Meta Commands:".bright_cyan().bold());
             println!("  {} - Show this help message", ".help".green());
-            println!("  {} - Exit the REPL", ".exit".green());
-            
+            println!(
+                "  {} - Exit the REPL (or type {})",
+                ".exit".green(),
+                "exit".green()
+            );
+
             println!("\n{}", "SQL Syntax:".bright_cyan().bold());
-            println!("  {}", "SELECT column1, column2, ... FROM 'file.csv' [WHERE condition] [LIMIT n] [OFFSET n]".dimmed());
-            
+            println!("  {}", "SELECT column1, column2, ... FROM 'file.csv' [WHERE condition] [GROUP BY columns [HAVING condition]] [LIMIT n] [OFFSET n]".dimmed());
+
             println!("\n{}", "Operators:".bright_cyan().bold());
             println!("  {} =, <>, <, >, <=, >=", "Comparison:".dimmed());
             println!("  {} AND, OR, NOT", "Logical:".dimmed());
-            println!("  {} COUNT(*), COUNT(column)", "Aggregates:".dimmed());
-            
+            println!("  {} LIKE, IN (...), NOT IN (...)", "Matching:".dimmed());
+            println!("  {} +, -, *, /", "Arithmetic:".dimmed());
+            println!(
+                "  {} COUNT(*), COUNT(expr), SUM(expr), MIN(expr), MAX(expr), AVG(expr)",
+                "Aggregates:".dimmed()
+            );
+
             println!("\n{}", "Examples:".bright_cyan().bold());
             println!("  {}", "-- Select all columns from a file".dimmed());
             println!("  {}", "SELECT * FROM 'data.csv' LIMIT 10".yellow());
             println!();
             println!("  {}", "-- Filter with conditions".dimmed());
-            println!("  {}", "SELECT name, age FROM 'users.csv' WHERE age > 25 AND active = true".yellow());
+            println!(
+                "  {}",
+                "SELECT name, age FROM 'users.csv' WHERE age > 25 AND active = true".yellow()
+            );
             println!();
             println!("  {}", "-- Count rows matching a condition".dimmed());
-            println!("  {}", "SELECT COUNT(*) FROM 'sales.csv' WHERE region = 'West'".yellow());
+            println!(
+                "  {}",
+                "SELECT COUNT(*) FROM 'sales.csv' WHERE region = 'West'".yellow()
+            );
+            println!();
+            println!("  {}", "-- Filter groups by an aggregate".dimmed());
+            println!(
+                "  {}",
+                "SELECT city FROM 'sales.csv' GROUP BY city HAVING SUM(sales) > 1000".yellow()
+            );
+            println!();
+            println!(
+                "  {}",
+                "-- Show the optimized plan instead of running the query".dimmed()
+            );
+            println!(
+                "  {}",
+                "EXPLAIN SELECT name FROM 'users.csv' WHERE age > 25".yellow()
+            );
             println!();
         }
         ".exit" | ".quit" => {
@@ -265,14 +544,3 @@ fn handle_meta_command(cmd: &str) {
         }
     }
 }
-
-fn format_value(value: &Value) -> String {
-    match value {
-        Value::Integer(i) => i.to_string(),
-        Value::Float(f) => f.to_string(),
-        Value::Boolean(b) => b.to_string(),
-        Value::Varchar(s) => s.clone(),
-        Value::Null => "NULL".dimmed().to_string(),
-    }
-}
-
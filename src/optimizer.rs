@@ -1,29 +1,126 @@
-use crate::binder::{BoundExpression, ColumnType};
+use crate::binder::{BoundExpression, BoundOrderBy, ColumnType};
+use crate::collation::Collation;
+use crate::date::{parse_date, parse_timestamp};
+use crate::estimator::Estimator;
 use crate::parser::LiteralValue;
-use crate::planner::{LogicalFilter, LogicalGet, LogicalLimit, LogicalOperator, LogicalProjection};
+use crate::planner::{
+    LogicalDistinct, LogicalExplain, LogicalFilter, LogicalGet, LogicalLimit, LogicalOperator,
+    LogicalProjection, LogicalSample, LogicalSort, LogicalTopN, LogicalUnion,
+};
 use std::collections::{HashMap, HashSet};
 
-pub struct Optimizer;
+pub struct Optimizer {
+    collation: Collation,
+}
 
 impl Optimizer {
     pub fn new() -> Self {
-        Self
+        Self {
+            collation: Collation::Binary,
+        }
+    }
+
+    /// collation used when folding string comparisons; must match the one
+    /// the physical planner executes with
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.collation = collation;
+        self
     }
 
     /// optimize a logical plan by applying multiple optimization passes:
     /// 1. Dead Code Elimination - simplify boolean literals in expressions
     /// 2. Projection Pushdown - prune unnecessary columns
     /// 3. Limit Pushdown - push LIMIT down to scan for early termination
+    /// 4. Identity Projection Removal - drop projections that only copy their input
+    ///
+    /// prefix LIKE patterns are also rewritten into range comparisons, and
+    /// AND/OR operands are reordered so the cheaper side is evaluated first.
+    /// each SELECT of a UNION is optimized on its own
     pub fn optimize(&self, plan: LogicalOperator) -> LogicalOperator {
+        // EXPLAIN describes the optimized form of the query it wraps,
+        // annotated with row estimates (only paid for when explaining)
+        if let LogicalOperator::Explain(explain) = plan {
+            let optimized = self.optimize(*explain.child);
+            return LogicalOperator::Explain(LogicalExplain {
+                child: Box::new(Estimator::new().estimate(optimized)),
+                analyze: explain.analyze,
+            });
+        }
+
+        // UNION branches first; the passes below leave a union as it is
+        let plan = self.optimize_union_branches(plan);
+
         // first: Eliminate dead code (simplify boolean literals)
         let plan = self.eliminate_dead_code(plan);
 
+        // rewrite prefix LIKE ('abc%') into a range predicate
+        let plan = self.rewrite_prefix_like(plan);
+
+        // evaluate cheap AND/OR operands first so they can short-circuit
+        let plan = self.reorder_predicates(plan);
+
         // second: Collect required columns and apply projection pushdown
         let required_columns = self.collect_required_columns(&plan);
         let plan = self.apply_projection_pushdown(plan, &required_columns);
 
         // third: Push down LIMIT to scan for early termination
-        self.push_down_limit(plan)
+        let plan = self.push_down_limit(plan);
+
+        // fuse ORDER BY + LIMIT into a bounded top-n
+        let plan = self.fuse_top_n(plan);
+
+        // finally: drop projections that would only copy their input
+        self.drop_identity_projections(plan)
+    }
+
+    /// optimize each SELECT of a UNION as a plan of its own. a LIMIT right above
+    /// a UNION ALL caps every branch at limit + offset rows, so their scans can
+    /// stop early too
+    fn optimize_union_branches(&self, plan: LogicalOperator) -> LogicalOperator {
+        match plan {
+            LogicalOperator::Union(union) => LogicalOperator::Union(LogicalUnion {
+                children: union
+                    .children
+                    .into_iter()
+                    .map(|child| self.optimize(child))
+                    .collect(),
+                estimated_rows: union.estimated_rows,
+            }),
+            LogicalOperator::Distinct(distinct) => LogicalOperator::Distinct(LogicalDistinct {
+                child: Box::new(self.optimize_union_branches(*distinct.child)),
+                ..distinct
+            }),
+            LogicalOperator::Sort(sort) => LogicalOperator::Sort(LogicalSort {
+                child: Box::new(self.optimize_union_branches(*sort.child)),
+                ..sort
+            }),
+            LogicalOperator::Limit(limit) => {
+                let child = match (*limit.child, limit.limit) {
+                    (LogicalOperator::Union(union), Some(n)) => {
+                        let max_rows = n.saturating_add(limit.offset.unwrap_or(0));
+                        let children = union
+                            .children
+                            .into_iter()
+                            .map(|child| {
+                                LogicalOperator::Limit(LogicalLimit {
+                                    limit: Some(max_rows),
+                                    offset: None,
+                                    child: Box::new(child),
+                                    estimated_rows: None,
+                                })
+                            })
+                            .collect();
+                        LogicalOperator::Union(LogicalUnion { children, ..union })
+                    }
+                    (child, _) => child,
+                };
+                LogicalOperator::Limit(LogicalLimit {
+                    child: Box::new(self.optimize_union_branches(child)),
+                    ..limit
+                })
+            }
+            plan => plan,
+        }
     }
 
     /// eliminate dead code by simplifying boolean literals in expressions.
@@ -32,13 +129,16 @@ impl Optimizer {
     /// - false OR x → x
     /// - NOT true → false
     /// - Filter with true condition → removed
+    /// - Filter with false condition → its scan reads no rows
     fn eliminate_dead_code(&self, plan: LogicalOperator) -> LogicalOperator {
         match plan {
             LogicalOperator::Projection(proj) => {
                 let optimized_child = self.eliminate_dead_code(*proj.child);
                 LogicalOperator::Projection(LogicalProjection {
                     expressions: proj.expressions,
+                    aliases: proj.aliases,
                     child: Box::new(optimized_child),
+                    estimated_rows: proj.estimated_rows,
                 })
             }
             LogicalOperator::Filter(filter) => {
@@ -52,11 +152,21 @@ impl Optimizer {
                 if self.is_constant_true(&simplified_expr) {
                     // filter always passes - remove it!
                     optimized_child
+                } else if self.is_constant_false(&simplified_expr) {
+                    // no row can pass: cap the scan below at zero rows so the
+                    // file isn't read. the filter stays for scans it can't reach
+                    // (under a reservoir sample the whole file is still read)
+                    LogicalOperator::Filter(LogicalFilter {
+                        expression: simplified_expr,
+                        child: Box::new(self.set_max_rows_on_get(optimized_child, 0)),
+                        estimated_rows: filter.estimated_rows,
+                    })
                 } else {
-                    // keep the filter (even if constant false - executor handles it)
+                    // keep the filter
                     LogicalOperator::Filter(LogicalFilter {
                         expression: simplified_expr,
                         child: Box::new(optimized_child),
+                        estimated_rows: filter.estimated_rows,
                     })
                 }
             }
@@ -71,16 +181,50 @@ impl Optimizer {
                     limit: limit.limit,
                     offset: limit.offset,
                     child: Box::new(optimized_child),
+                    estimated_rows: limit.estimated_rows,
+                })
+            }
+            LogicalOperator::Sort(sort) => {
+                let optimized_child = self.eliminate_dead_code(*sort.child);
+                LogicalOperator::Sort(LogicalSort {
+                    keys: sort.keys,
+                    child: Box::new(optimized_child),
+                    estimated_rows: sort.estimated_rows,
+                })
+            }
+            LogicalOperator::TopN(top_n) => {
+                let optimized_child = self.eliminate_dead_code(*top_n.child);
+                LogicalOperator::TopN(LogicalTopN {
+                    keys: top_n.keys,
+                    limit: top_n.limit,
+                    offset: top_n.offset,
+                    child: Box::new(optimized_child),
+                    estimated_rows: top_n.estimated_rows,
+                })
+            }
+            LogicalOperator::Sample(sample) => {
+                let optimized_child = self.eliminate_dead_code(*sample.child);
+                LogicalOperator::Sample(LogicalSample {
+                    size: sample.size,
+                    seed: sample.seed,
+                    child: Box::new(optimized_child),
+                    estimated_rows: sample.estimated_rows,
                 })
             }
             LogicalOperator::Aggregate(agg) => {
                 // optimize child first
                 let optimized_child = self.eliminate_dead_code(*agg.child);
                 LogicalOperator::Aggregate(crate::planner::LogicalAggregate {
+                    groups: agg.groups,
                     aggregates: agg.aggregates,
                     child: Box::new(optimized_child),
+                    estimated_rows: agg.estimated_rows,
                 })
             }
+            // UNION branches were optimized on their own
+            union @ (LogicalOperator::Union(_) | LogicalOperator::Distinct(_)) => union,
+            // EXPLAIN is unwrapped in optimize()
+            explain @ LogicalOperator::Explain(_) => explain,
         }
     }
 
@@ -249,11 +393,392 @@ impl Optimizer {
                 BoundExpression::LessThanOrEqual(Box::new(left), Box::new(right))
             }
 
+            BoundExpression::Like(left, right, escape) => BoundExpression::Like(
+                Box::new(self.simplify_expression(*left)),
+                Box::new(self.simplify_expression(*right)),
+                escape,
+            ),
+
+            // truth tests fold on a literal (NULL is neither true nor false)
+            BoundExpression::IsTrue(inner) => {
+                let inner = self.simplify_expression(*inner);
+                match self.extract_literal(&inner) {
+                    Some(value) => self.make_bool_literal(*value == LiteralValue::Boolean(true)),
+                    None => BoundExpression::IsTrue(Box::new(inner)),
+                }
+            }
+            BoundExpression::IsFalse(inner) => {
+                let inner = self.simplify_expression(*inner);
+                match self.extract_literal(&inner) {
+                    Some(value) => self.make_bool_literal(*value == LiteralValue::Boolean(false)),
+                    None => BoundExpression::IsFalse(Box::new(inner)),
+                }
+            }
+            BoundExpression::In(left, list) => BoundExpression::In(
+                Box::new(self.simplify_expression(*left)),
+                list.into_iter()
+                    .map(|item| self.simplify_expression(item))
+                    .collect(),
+            ),
+
+            // arithmetic - simplify operands only
+            BoundExpression::Add(left, right) => BoundExpression::Add(
+                Box::new(self.simplify_expression(*left)),
+                Box::new(self.simplify_expression(*right)),
+            ),
+            BoundExpression::Subtract(left, right) => BoundExpression::Subtract(
+                Box::new(self.simplify_expression(*left)),
+                Box::new(self.simplify_expression(*right)),
+            ),
+            BoundExpression::Multiply(left, right) => BoundExpression::Multiply(
+                Box::new(self.simplify_expression(*left)),
+                Box::new(self.simplify_expression(*right)),
+            ),
+            BoundExpression::Divide(left, right) => BoundExpression::Divide(
+                Box::new(self.simplify_expression(*left)),
+                Box::new(self.simplify_expression(*right)),
+            ),
+
+            // modulo and negation of literals are folded (7 % 2 → 1, -(3) → -3)
+            BoundExpression::Modulo(left, right) => {
+                let left = self.simplify_expression(*left);
+                let right = self.simplify_expression(*right);
+
+                if let (Some(LiteralValue::Integer(a)), Some(LiteralValue::Integer(b))) =
+                    (self.extract_literal(&left), self.extract_literal(&right))
+                {
+                    // x % 0 is left for the evaluator, which turns it into NULL
+                    if let Some(result) = a.checked_rem(*b) {
+                        return BoundExpression::Literal {
+                            value: LiteralValue::Integer(result),
+                            type_: ColumnType::Integer,
+                        };
+                    }
+                }
+
+                BoundExpression::Modulo(Box::new(left), Box::new(right))
+            }
+            BoundExpression::Negate(inner) => {
+                let inner = self.simplify_expression(*inner);
+
+                let folded = match self.extract_literal(&inner) {
+                    Some(LiteralValue::Integer(i)) => i.checked_neg().map(LiteralValue::Integer),
+                    Some(LiteralValue::Float(f)) => Some(LiteralValue::Float(-f)),
+                    _ => None,
+                };
+                if let Some(value) = folded {
+                    return BoundExpression::Literal {
+                        type_: inner.result_type(),
+                        value,
+                    };
+                }
+
+                BoundExpression::Negate(Box::new(inner))
+            }
+
+            // string operations - simplify operands only
+            BoundExpression::Concat(left, right) => BoundExpression::Concat(
+                Box::new(self.simplify_expression(*left)),
+                Box::new(self.simplify_expression(*right)),
+            ),
+            BoundExpression::FunctionCall { function, args } => BoundExpression::FunctionCall {
+                function,
+                args: args
+                    .into_iter()
+                    .map(|arg| self.simplify_expression(arg))
+                    .collect(),
+            },
+            BoundExpression::Cast { expr, target } => BoundExpression::Cast {
+                expr: Box::new(self.simplify_expression(*expr)),
+                target,
+            },
+            BoundExpression::Case { branches, else_ } => BoundExpression::Case {
+                branches: branches
+                    .into_iter()
+                    .map(|(condition, result)| {
+                        (
+                            self.simplify_expression(condition),
+                            self.simplify_expression(result),
+                        )
+                    })
+                    .collect(),
+                else_: else_.map(|else_| Box::new(self.simplify_expression(*else_))),
+            },
+
             // leaf nodes - no simplification needed
             BoundExpression::ColumnRef { .. } | BoundExpression::Literal { .. } => expr,
         }
     }
 
+    /// rewrite prefix LIKE patterns into range comparisons.
+    /// example: name LIKE 'abc%' → name >= 'abc' AND name < 'abd'
+    fn rewrite_prefix_like(&self, plan: LogicalOperator) -> LogicalOperator {
+        match plan {
+            LogicalOperator::Projection(proj) => {
+                let optimized_child = self.rewrite_prefix_like(*proj.child);
+                LogicalOperator::Projection(LogicalProjection {
+                    expressions: proj.expressions,
+                    aliases: proj.aliases,
+                    child: Box::new(optimized_child),
+                    estimated_rows: proj.estimated_rows,
+                })
+            }
+            LogicalOperator::Filter(filter) => {
+                let optimized_child = self.rewrite_prefix_like(*filter.child);
+                LogicalOperator::Filter(LogicalFilter {
+                    expression: self.rewrite_like_expression(filter.expression),
+                    child: Box::new(optimized_child),
+                    estimated_rows: filter.estimated_rows,
+                })
+            }
+            LogicalOperator::Get(get) => LogicalOperator::Get(get),
+            LogicalOperator::Limit(limit) => {
+                let optimized_child = self.rewrite_prefix_like(*limit.child);
+                LogicalOperator::Limit(LogicalLimit {
+                    limit: limit.limit,
+                    offset: limit.offset,
+                    child: Box::new(optimized_child),
+                    estimated_rows: limit.estimated_rows,
+                })
+            }
+            LogicalOperator::Sort(sort) => {
+                let optimized_child = self.rewrite_prefix_like(*sort.child);
+                LogicalOperator::Sort(LogicalSort {
+                    keys: sort.keys,
+                    child: Box::new(optimized_child),
+                    estimated_rows: sort.estimated_rows,
+                })
+            }
+            LogicalOperator::TopN(top_n) => {
+                let optimized_child = self.rewrite_prefix_like(*top_n.child);
+                LogicalOperator::TopN(LogicalTopN {
+                    keys: top_n.keys,
+                    limit: top_n.limit,
+                    offset: top_n.offset,
+                    child: Box::new(optimized_child),
+                    estimated_rows: top_n.estimated_rows,
+                })
+            }
+            LogicalOperator::Sample(sample) => {
+                let optimized_child = self.rewrite_prefix_like(*sample.child);
+                LogicalOperator::Sample(LogicalSample {
+                    size: sample.size,
+                    seed: sample.seed,
+                    child: Box::new(optimized_child),
+                    estimated_rows: sample.estimated_rows,
+                })
+            }
+            LogicalOperator::Aggregate(agg) => {
+                let optimized_child = self.rewrite_prefix_like(*agg.child);
+                LogicalOperator::Aggregate(crate::planner::LogicalAggregate {
+                    groups: agg.groups,
+                    aggregates: agg.aggregates,
+                    child: Box::new(optimized_child),
+                    estimated_rows: agg.estimated_rows,
+                })
+            }
+            union @ (LogicalOperator::Union(_) | LogicalOperator::Distinct(_)) => union,
+            explain @ LogicalOperator::Explain(_) => explain,
+        }
+    }
+
+    /// recursively rewrite prefix LIKE predicates through AND/OR/NOT
+    fn rewrite_like_expression(&self, expr: BoundExpression) -> BoundExpression {
+        match expr {
+            BoundExpression::And(left, right) => BoundExpression::And(
+                Box::new(self.rewrite_like_expression(*left)),
+                Box::new(self.rewrite_like_expression(*right)),
+            ),
+            BoundExpression::Or(left, right) => BoundExpression::Or(
+                Box::new(self.rewrite_like_expression(*left)),
+                Box::new(self.rewrite_like_expression(*right)),
+            ),
+            BoundExpression::Not(inner) => {
+                BoundExpression::Not(Box::new(self.rewrite_like_expression(*inner)))
+            }
+            // the range bounds are only valid under byte order
+            BoundExpression::Like(left, right, escape) if self.collation == Collation::Binary => {
+                let prefix = match (&*left, &*right) {
+                    (
+                        BoundExpression::ColumnRef {
+                            type_: ColumnType::Varchar,
+                            ..
+                        },
+                        BoundExpression::Literal {
+                            value: LiteralValue::String(pattern),
+                            ..
+                        },
+                    ) => self.extract_like_prefix(pattern, escape),
+                    _ => None,
+                };
+
+                match prefix {
+                    Some(prefix) => self.make_prefix_range(*left, prefix),
+                    None => BoundExpression::Like(left, right, escape),
+                }
+            }
+            other => other,
+        }
+    }
+
+    /// returns the prefix if the pattern is a literal prefix followed only by '%'
+    /// (escaped wildcards in the prefix are literal characters)
+    fn extract_like_prefix(&self, pattern: &str, escape: Option<char>) -> Option<String> {
+        let mut prefix = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                // a trailing escape is left to the matcher
+                c if Some(c) == escape => prefix.push(chars.next()?),
+                '%' => return chars.all(|c| c == '%').then_some(prefix),
+                '_' => return None,
+                c => prefix.push(c),
+            }
+        }
+        None
+    }
+
+    /// build column >= prefix AND column < successor(prefix)
+    fn make_prefix_range(&self, column: BoundExpression, prefix: String) -> BoundExpression {
+        let literal = |s: String| {
+            Box::new(BoundExpression::Literal {
+                value: LiteralValue::String(s),
+                type_: ColumnType::Varchar,
+            })
+        };
+
+        let upper = self.prefix_upper_bound(&prefix);
+        let lower_bound =
+            BoundExpression::GreaterThanOrEqual(Box::new(column.clone()), literal(prefix));
+
+        match upper {
+            Some(upper) => BoundExpression::And(
+                Box::new(lower_bound),
+                Box::new(BoundExpression::LessThan(Box::new(column), literal(upper))),
+            ),
+            // no string sorts above every string with this prefix
+            None => lower_bound,
+        }
+    }
+
+    /// smallest string greater than every string starting with prefix
+    fn prefix_upper_bound(&self, prefix: &str) -> Option<String> {
+        let mut chars: Vec<char> = prefix.chars().collect();
+        while let Some(last) = chars.pop() {
+            // next valid char (skips the surrogate range)
+            if let Some(next) = (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32) {
+                chars.push(next);
+                return Some(chars.into_iter().collect());
+            }
+        }
+        None
+    }
+
+    /// put the cheaper operand of every AND/OR in a filter first; the evaluator
+    /// skips the other side once the first one decides the result
+    fn reorder_predicates(&self, plan: LogicalOperator) -> LogicalOperator {
+        match plan {
+            LogicalOperator::Projection(proj) => {
+                let optimized_child = self.reorder_predicates(*proj.child);
+                LogicalOperator::Projection(LogicalProjection {
+                    expressions: proj.expressions,
+                    aliases: proj.aliases,
+                    child: Box::new(optimized_child),
+                    estimated_rows: proj.estimated_rows,
+                })
+            }
+            LogicalOperator::Filter(filter) => {
+                let optimized_child = self.reorder_predicates(*filter.child);
+                LogicalOperator::Filter(LogicalFilter {
+                    expression: self.reorder_operands(filter.expression),
+                    child: Box::new(optimized_child),
+                    estimated_rows: filter.estimated_rows,
+                })
+            }
+            LogicalOperator::Get(get) => LogicalOperator::Get(get),
+            LogicalOperator::Limit(limit) => {
+                let optimized_child = self.reorder_predicates(*limit.child);
+                LogicalOperator::Limit(LogicalLimit {
+                    limit: limit.limit,
+                    offset: limit.offset,
+                    child: Box::new(optimized_child),
+                    estimated_rows: limit.estimated_rows,
+                })
+            }
+            LogicalOperator::Sort(sort) => {
+                let optimized_child = self.reorder_predicates(*sort.child);
+                LogicalOperator::Sort(LogicalSort {
+                    keys: sort.keys,
+                    child: Box::new(optimized_child),
+                    estimated_rows: sort.estimated_rows,
+                })
+            }
+            LogicalOperator::TopN(top_n) => {
+                let optimized_child = self.reorder_predicates(*top_n.child);
+                LogicalOperator::TopN(LogicalTopN {
+                    keys: top_n.keys,
+                    limit: top_n.limit,
+                    offset: top_n.offset,
+                    child: Box::new(optimized_child),
+                    estimated_rows: top_n.estimated_rows,
+                })
+            }
+            LogicalOperator::Sample(sample) => {
+                let optimized_child = self.reorder_predicates(*sample.child);
+                LogicalOperator::Sample(LogicalSample {
+                    size: sample.size,
+                    seed: sample.seed,
+                    child: Box::new(optimized_child),
+                    estimated_rows: sample.estimated_rows,
+                })
+            }
+            LogicalOperator::Aggregate(agg) => {
+                let optimized_child = self.reorder_predicates(*agg.child);
+                LogicalOperator::Aggregate(crate::planner::LogicalAggregate {
+                    groups: agg.groups,
+                    aggregates: agg.aggregates,
+                    child: Box::new(optimized_child),
+                    estimated_rows: agg.estimated_rows,
+                })
+            }
+            union @ (LogicalOperator::Union(_) | LogicalOperator::Distinct(_)) => union,
+            explain @ LogicalOperator::Explain(_) => explain,
+        }
+    }
+
+    /// recursively swap AND/OR operands whose right side is cheaper.
+    /// equal costs keep the written order
+    fn reorder_operands(&self, expr: BoundExpression) -> BoundExpression {
+        match expr {
+            BoundExpression::And(left, right) => {
+                let (left, right) = self.cheaper_first(*left, *right);
+                BoundExpression::And(Box::new(left), Box::new(right))
+            }
+            BoundExpression::Or(left, right) => {
+                let (left, right) = self.cheaper_first(*left, *right);
+                BoundExpression::Or(Box::new(left), Box::new(right))
+            }
+            BoundExpression::Not(inner) => {
+                BoundExpression::Not(Box::new(self.reorder_operands(*inner)))
+            }
+            other => other,
+        }
+    }
+
+    fn cheaper_first(
+        &self,
+        left: BoundExpression,
+        right: BoundExpression,
+    ) -> (BoundExpression, BoundExpression) {
+        let left = self.reorder_operands(left);
+        let right = self.reorder_operands(right);
+        if evaluation_cost(&right) < evaluation_cost(&left) {
+            (right, left)
+        } else {
+            (left, right)
+        }
+    }
+
     /// check if expression is constant true
     fn is_constant_true(&self, expr: &BoundExpression) -> bool {
         matches!(
@@ -289,8 +814,14 @@ impl Optimizer {
         match (left, right) {
             (LiteralValue::Integer(a), LiteralValue::Integer(b)) => Some(a == b),
             (LiteralValue::Float(a), LiteralValue::Float(b)) => Some(a == b),
-            (LiteralValue::String(a), LiteralValue::String(b)) => Some(a == b),
+            (LiteralValue::String(a), LiteralValue::String(b)) => Some(self.collation.equals(a, b)),
             (LiteralValue::Boolean(a), LiteralValue::Boolean(b)) => Some(a == b),
+            (LiteralValue::Date(a), LiteralValue::Date(b)) => {
+                Some(parse_date(a)? == parse_date(b)?)
+            }
+            (LiteralValue::Timestamp(a), LiteralValue::Timestamp(b)) => {
+                Some(parse_timestamp(a)? == parse_timestamp(b)?)
+            }
             (LiteralValue::Null, LiteralValue::Null) => Some(false), // null = NULL is false in SQL
             _ => None, // different types - can't evaluate
         }
@@ -304,7 +835,13 @@ impl Optimizer {
         match (left, right) {
             (LiteralValue::Integer(a), LiteralValue::Integer(b)) => Some(a > b),
             (LiteralValue::Float(a), LiteralValue::Float(b)) => Some(a > b),
-            (LiteralValue::String(a), LiteralValue::String(b)) => Some(a > b),
+            (LiteralValue::String(a), LiteralValue::String(b)) => {
+                Some(self.collation.compare(a, b).is_gt())
+            }
+            (LiteralValue::Date(a), LiteralValue::Date(b)) => Some(parse_date(a)? > parse_date(b)?),
+            (LiteralValue::Timestamp(a), LiteralValue::Timestamp(b)) => {
+                Some(parse_timestamp(a)? > parse_timestamp(b)?)
+            }
             _ => None,
         }
     }
@@ -317,7 +854,15 @@ impl Optimizer {
         match (left, right) {
             (LiteralValue::Integer(a), LiteralValue::Integer(b)) => Some(a >= b),
             (LiteralValue::Float(a), LiteralValue::Float(b)) => Some(a >= b),
-            (LiteralValue::String(a), LiteralValue::String(b)) => Some(a >= b),
+            (LiteralValue::String(a), LiteralValue::String(b)) => {
+                Some(self.collation.compare(a, b).is_ge())
+            }
+            (LiteralValue::Date(a), LiteralValue::Date(b)) => {
+                Some(parse_date(a)? >= parse_date(b)?)
+            }
+            (LiteralValue::Timestamp(a), LiteralValue::Timestamp(b)) => {
+                Some(parse_timestamp(a)? >= parse_timestamp(b)?)
+            }
             _ => None,
         }
     }
@@ -326,7 +871,13 @@ impl Optimizer {
         match (left, right) {
             (LiteralValue::Integer(a), LiteralValue::Integer(b)) => Some(a < b),
             (LiteralValue::Float(a), LiteralValue::Float(b)) => Some(a < b),
-            (LiteralValue::String(a), LiteralValue::String(b)) => Some(a < b),
+            (LiteralValue::String(a), LiteralValue::String(b)) => {
+                Some(self.collation.compare(a, b).is_lt())
+            }
+            (LiteralValue::Date(a), LiteralValue::Date(b)) => Some(parse_date(a)? < parse_date(b)?),
+            (LiteralValue::Timestamp(a), LiteralValue::Timestamp(b)) => {
+                Some(parse_timestamp(a)? < parse_timestamp(b)?)
+            }
             _ => None,
         }
     }
@@ -339,7 +890,15 @@ impl Optimizer {
         match (left, right) {
             (LiteralValue::Integer(a), LiteralValue::Integer(b)) => Some(a <= b),
             (LiteralValue::Float(a), LiteralValue::Float(b)) => Some(a <= b),
-            (LiteralValue::String(a), LiteralValue::String(b)) => Some(a <= b),
+            (LiteralValue::String(a), LiteralValue::String(b)) => {
+                Some(self.collation.compare(a, b).is_le())
+            }
+            (LiteralValue::Date(a), LiteralValue::Date(b)) => {
+                Some(parse_date(a)? <= parse_date(b)?)
+            }
+            (LiteralValue::Timestamp(a), LiteralValue::Timestamp(b)) => {
+                Some(parse_timestamp(a)? <= parse_timestamp(b)?)
+            }
             _ => None,
         }
     }
@@ -359,15 +918,20 @@ impl Optimizer {
         match plan {
             LogicalOperator::Projection(proj) => {
                 // collect columns from projection expressions
-                for expr in &proj.expressions {
-                    columns.extend(self.collect_columns_from_expression(expr));
+                // (above an aggregate they index its output, not the scan)
+                if !self.reads_aggregate_output(&proj.child) {
+                    for expr in &proj.expressions {
+                        columns.extend(self.collect_columns_from_expression(expr));
+                    }
                 }
                 // recurse into child
                 columns.extend(self.collect_required_columns(&proj.child));
             }
             LogicalOperator::Filter(filter) => {
                 // collect columns from filter expression
-                columns.extend(self.collect_columns_from_expression(&filter.expression));
+                if !self.reads_aggregate_output(&filter.child) {
+                    columns.extend(self.collect_columns_from_expression(&filter.expression));
+                }
                 // recurse into child
                 columns.extend(self.collect_required_columns(&filter.child));
             }
@@ -379,22 +943,65 @@ impl Optimizer {
                 // limit doesn't use any columns itself, just pass through to child
                 columns.extend(self.collect_required_columns(&limit.child));
             }
-            LogicalOperator::Aggregate(agg) => {
-                // aggregates read all columns they need (columns from COUNT(col), etc.)
-                // for now, collect columns from the child (scan needs to read them)
-                for aggregate in &agg.aggregates {
-                    if let crate::binder::BoundAggregateExpression::Count { column } = aggregate {
-                        columns.insert(column.index);
+            LogicalOperator::Sort(sort) => {
+                // sort keys may use columns the output doesn't (ORDER BY age)
+                if !self.reads_aggregate_output(&sort.child) {
+                    for key in &sort.keys {
+                        columns.extend(self.collect_columns_from_expression(&key.expression));
+                    }
+                }
+                columns.extend(self.collect_required_columns(&sort.child));
+            }
+            LogicalOperator::TopN(top_n) => {
+                if !self.reads_aggregate_output(&top_n.child) {
+                    for key in &top_n.keys {
+                        columns.extend(self.collect_columns_from_expression(&key.expression));
                     }
                 }
+                columns.extend(self.collect_required_columns(&top_n.child));
+            }
+            LogicalOperator::Sample(sample) => {
+                columns.extend(self.collect_required_columns(&sample.child));
+            }
+            LogicalOperator::Aggregate(agg) => {
+                // aggregates read every column referenced by their arguments
+                // (e.g. price and qty for SUM(price * qty))
+                for expression in agg.aggregates.iter().filter_map(|a| a.expression()) {
+                    columns.extend(self.collect_columns_from_expression(expression));
+                }
+                for group in &agg.groups {
+                    columns.extend(self.collect_columns_from_expression(group));
+                }
                 // also collect from child
                 columns.extend(self.collect_required_columns(&agg.child));
             }
+            LogicalOperator::Union(_) | LogicalOperator::Distinct(_) => {
+                // each UNION branch collected the columns it reads itself
+            }
+            LogicalOperator::Explain(explain) => {
+                columns.extend(self.collect_required_columns(&explain.child));
+            }
         }
 
         columns
     }
 
+    /// true if the operator's input rows come from an aggregate
+    fn reads_aggregate_output(&self, plan: &LogicalOperator) -> bool {
+        match plan {
+            LogicalOperator::Aggregate(_) => true,
+            LogicalOperator::Filter(filter) => self.reads_aggregate_output(&filter.child),
+            LogicalOperator::Projection(proj) => self.reads_aggregate_output(&proj.child),
+            LogicalOperator::Limit(limit) => self.reads_aggregate_output(&limit.child),
+            LogicalOperator::Sort(sort) => self.reads_aggregate_output(&sort.child),
+            LogicalOperator::TopN(top_n) => self.reads_aggregate_output(&top_n.child),
+            LogicalOperator::Sample(sample) => self.reads_aggregate_output(&sample.child),
+            LogicalOperator::Get(_) => false,
+            LogicalOperator::Union(_) | LogicalOperator::Distinct(_) => false,
+            LogicalOperator::Explain(_) => false,
+        }
+    }
+
     /// recursively traverse a BoundExpression tree to find all ColumnRef nodes.
     /// this handles complex expressions with AND/OR/NOT.
     fn collect_columns_from_expression(&self, expr: &BoundExpression) -> HashSet<usize> {
@@ -407,8 +1014,10 @@ impl Optimizer {
                 columns.extend(self.collect_columns_from_expression(right));
             }
 
-            // unary logical operator (recurse on child)
-            BoundExpression::Not(inner) => {
+            // unary logical operators (recurse on child)
+            BoundExpression::Not(inner)
+            | BoundExpression::IsTrue(inner)
+            | BoundExpression::IsFalse(inner) => {
                 columns.extend(self.collect_columns_from_expression(inner));
             }
 
@@ -418,11 +1027,49 @@ impl Optimizer {
             | BoundExpression::GreaterThan(left, right)
             | BoundExpression::GreaterThanOrEqual(left, right)
             | BoundExpression::LessThan(left, right)
-            | BoundExpression::LessThanOrEqual(left, right) => {
+            | BoundExpression::LessThanOrEqual(left, right)
+            | BoundExpression::Like(left, right, _)
+            | BoundExpression::Add(left, right)
+            | BoundExpression::Subtract(left, right)
+            | BoundExpression::Multiply(left, right)
+            | BoundExpression::Divide(left, right)
+            | BoundExpression::Modulo(left, right)
+            | BoundExpression::Concat(left, right) => {
                 columns.extend(self.collect_columns_from_expression(left));
                 columns.extend(self.collect_columns_from_expression(right));
             }
 
+            BoundExpression::FunctionCall { args, .. } => {
+                for arg in args {
+                    columns.extend(self.collect_columns_from_expression(arg));
+                }
+            }
+
+            BoundExpression::Negate(inner) => {
+                columns.extend(self.collect_columns_from_expression(inner));
+            }
+
+            BoundExpression::Cast { expr, .. } => {
+                columns.extend(self.collect_columns_from_expression(expr));
+            }
+
+            BoundExpression::Case { branches, else_ } => {
+                for (condition, result) in branches {
+                    columns.extend(self.collect_columns_from_expression(condition));
+                    columns.extend(self.collect_columns_from_expression(result));
+                }
+                if let Some(else_) = else_ {
+                    columns.extend(self.collect_columns_from_expression(else_));
+                }
+            }
+
+            BoundExpression::In(left, list) => {
+                columns.extend(self.collect_columns_from_expression(left));
+                for item in list {
+                    columns.extend(self.collect_columns_from_expression(item));
+                }
+            }
+
             // column reference (this is what we're looking for!)
             BoundExpression::ColumnRef { index, .. } => {
                 columns.insert(*index);
@@ -461,7 +1108,9 @@ impl Optimizer {
 
                 LogicalOperator::Projection(LogicalProjection {
                     expressions: remapped_expressions,
+                    aliases: proj.aliases,
                     child: Box::new(optimized_child),
+                    estimated_rows: proj.estimated_rows,
                 })
             }
             LogicalOperator::Filter(filter) => {
@@ -478,6 +1127,7 @@ impl Optimizer {
                 LogicalOperator::Filter(LogicalFilter {
                     expression: remapped_expression,
                     child: Box::new(optimized_child),
+                    estimated_rows: filter.estimated_rows,
                 })
             }
             LogicalOperator::Get(get) => {
@@ -492,8 +1142,15 @@ impl Optimizer {
 
                 LogicalOperator::Get(LogicalGet {
                     file_path: get.file_path,
+                    union_files: get.union_files,
                     columns: projected_columns,
                     max_rows: get.max_rows, // preserve max_rows from limit pushdown
+                    has_header: get.has_header,
+                    format: get.format,
+                    empty_as_null: get.empty_as_null,
+                    null_tokens: get.null_tokens,
+                    source: get.source,
+                    estimated_rows: get.estimated_rows,
                 })
             }
             LogicalOperator::Limit(limit) => {
@@ -505,6 +1162,59 @@ impl Optimizer {
 
                     offset: limit.offset,
                     child: Box::new(optimized_child),
+                    estimated_rows: limit.estimated_rows,
+                })
+            }
+            LogicalOperator::Sort(sort) => {
+                let optimized_child = self.apply_projection_pushdown(*sort.child, required_columns);
+
+                // remap column indices in the sort keys
+                let index_mapping = self.build_index_mapping(&optimized_child);
+                let keys = sort
+                    .keys
+                    .into_iter()
+                    .map(|key| BoundOrderBy {
+                        expression: self.remap_expression(key.expression, &index_mapping),
+                        descending: key.descending,
+                    })
+                    .collect();
+
+                LogicalOperator::Sort(LogicalSort {
+                    keys,
+                    child: Box::new(optimized_child),
+                    estimated_rows: sort.estimated_rows,
+                })
+            }
+            LogicalOperator::TopN(top_n) => {
+                let optimized_child =
+                    self.apply_projection_pushdown(*top_n.child, required_columns);
+
+                let index_mapping = self.build_index_mapping(&optimized_child);
+                let keys = top_n
+                    .keys
+                    .into_iter()
+                    .map(|key| BoundOrderBy {
+                        expression: self.remap_expression(key.expression, &index_mapping),
+                        descending: key.descending,
+                    })
+                    .collect();
+
+                LogicalOperator::TopN(LogicalTopN {
+                    keys,
+                    limit: top_n.limit,
+                    offset: top_n.offset,
+                    child: Box::new(optimized_child),
+                    estimated_rows: top_n.estimated_rows,
+                })
+            }
+            LogicalOperator::Sample(sample) => {
+                let optimized_child =
+                    self.apply_projection_pushdown(*sample.child, required_columns);
+                LogicalOperator::Sample(LogicalSample {
+                    size: sample.size,
+                    seed: sample.seed,
+                    child: Box::new(optimized_child),
+                    estimated_rows: sample.estimated_rows,
                 })
             }
             LogicalOperator::Aggregate(agg) => {
@@ -519,11 +1229,21 @@ impl Optimizer {
                     .map(|agg_expr| self.remap_aggregate(agg_expr, &mapping))
                     .collect();
 
+                let remapped_groups = agg
+                    .groups
+                    .into_iter()
+                    .map(|group| self.remap_expression(group, &mapping))
+                    .collect();
+
                 LogicalOperator::Aggregate(crate::planner::LogicalAggregate {
+                    groups: remapped_groups,
                     aggregates: remapped_aggregates,
                     child: Box::new(optimized_child),
+                    estimated_rows: agg.estimated_rows,
                 })
             }
+            union @ (LogicalOperator::Union(_) | LogicalOperator::Distinct(_)) => union,
+            explain @ LogicalOperator::Explain(_) => explain,
         }
     }
 
@@ -544,7 +1264,14 @@ impl Optimizer {
             LogicalOperator::Filter(filter) => self.build_index_mapping(&filter.child),
             LogicalOperator::Projection(proj) => self.build_index_mapping(&proj.child),
             LogicalOperator::Limit(limit) => self.build_index_mapping(&limit.child),
-            LogicalOperator::Aggregate(agg) => self.build_index_mapping(&agg.child),
+            LogicalOperator::Sort(sort) => self.build_index_mapping(&sort.child),
+            LogicalOperator::TopN(top_n) => self.build_index_mapping(&top_n.child),
+            LogicalOperator::Sample(sample) => self.build_index_mapping(&sample.child),
+            // aggregate and union output positions don't change with pushdown
+            LogicalOperator::Aggregate(_)
+            | LogicalOperator::Union(_)
+            | LogicalOperator::Distinct(_) => HashMap::new(),
+            LogicalOperator::Explain(explain) => self.build_index_mapping(&explain.child),
         }
     }
 
@@ -554,17 +1281,26 @@ impl Optimizer {
         agg: crate::binder::BoundAggregateExpression,
         mapping: &HashMap<usize, usize>,
     ) -> crate::binder::BoundAggregateExpression {
+        use crate::binder::BoundAggregateExpression as Agg;
+
+        // remap the column indices inside the argument expression
         match agg {
-            crate::binder::BoundAggregateExpression::CountStar => {
-                crate::binder::BoundAggregateExpression::CountStar
-            }
-            crate::binder::BoundAggregateExpression::Count { mut column } => {
-                // remap the column index
-                if let Some(&new_index) = mapping.get(&column.index) {
-                    column.index = new_index;
-                }
-                crate::binder::BoundAggregateExpression::Count { column }
-            }
+            Agg::CountStar => Agg::CountStar,
+            Agg::Count { expression } => Agg::Count {
+                expression: self.remap_expression(expression, mapping),
+            },
+            Agg::Sum { expression } => Agg::Sum {
+                expression: self.remap_expression(expression, mapping),
+            },
+            Agg::Min { expression } => Agg::Min {
+                expression: self.remap_expression(expression, mapping),
+            },
+            Agg::Max { expression } => Agg::Max {
+                expression: self.remap_expression(expression, mapping),
+            },
+            Agg::Avg { expression } => Agg::Avg {
+                expression: self.remap_expression(expression, mapping),
+            },
         }
     }
 
@@ -611,6 +1347,17 @@ impl Optimizer {
                 Box::new(self.remap_expression(*left, mapping)),
                 Box::new(self.remap_expression(*right, mapping)),
             ),
+            BoundExpression::Like(left, right, escape) => BoundExpression::Like(
+                Box::new(self.remap_expression(*left, mapping)),
+                Box::new(self.remap_expression(*right, mapping)),
+                escape,
+            ),
+            BoundExpression::In(left, list) => BoundExpression::In(
+                Box::new(self.remap_expression(*left, mapping)),
+                list.into_iter()
+                    .map(|item| self.remap_expression(item, mapping))
+                    .collect(),
+            ),
             BoundExpression::And(left, right) => BoundExpression::And(
                 Box::new(self.remap_expression(*left, mapping)),
                 Box::new(self.remap_expression(*right, mapping)),
@@ -622,6 +1369,62 @@ impl Optimizer {
             BoundExpression::Not(inner) => {
                 BoundExpression::Not(Box::new(self.remap_expression(*inner, mapping)))
             }
+            BoundExpression::IsTrue(inner) => {
+                BoundExpression::IsTrue(Box::new(self.remap_expression(*inner, mapping)))
+            }
+            BoundExpression::IsFalse(inner) => {
+                BoundExpression::IsFalse(Box::new(self.remap_expression(*inner, mapping)))
+            }
+            BoundExpression::Add(left, right) => BoundExpression::Add(
+                Box::new(self.remap_expression(*left, mapping)),
+                Box::new(self.remap_expression(*right, mapping)),
+            ),
+            BoundExpression::Subtract(left, right) => BoundExpression::Subtract(
+                Box::new(self.remap_expression(*left, mapping)),
+                Box::new(self.remap_expression(*right, mapping)),
+            ),
+            BoundExpression::Multiply(left, right) => BoundExpression::Multiply(
+                Box::new(self.remap_expression(*left, mapping)),
+                Box::new(self.remap_expression(*right, mapping)),
+            ),
+            BoundExpression::Divide(left, right) => BoundExpression::Divide(
+                Box::new(self.remap_expression(*left, mapping)),
+                Box::new(self.remap_expression(*right, mapping)),
+            ),
+            BoundExpression::Modulo(left, right) => BoundExpression::Modulo(
+                Box::new(self.remap_expression(*left, mapping)),
+                Box::new(self.remap_expression(*right, mapping)),
+            ),
+            BoundExpression::Negate(inner) => {
+                BoundExpression::Negate(Box::new(self.remap_expression(*inner, mapping)))
+            }
+            BoundExpression::Concat(left, right) => BoundExpression::Concat(
+                Box::new(self.remap_expression(*left, mapping)),
+                Box::new(self.remap_expression(*right, mapping)),
+            ),
+            BoundExpression::FunctionCall { function, args } => BoundExpression::FunctionCall {
+                function,
+                args: args
+                    .into_iter()
+                    .map(|arg| self.remap_expression(arg, mapping))
+                    .collect(),
+            },
+            BoundExpression::Cast { expr, target } => BoundExpression::Cast {
+                expr: Box::new(self.remap_expression(*expr, mapping)),
+                target,
+            },
+            BoundExpression::Case { branches, else_ } => BoundExpression::Case {
+                branches: branches
+                    .into_iter()
+                    .map(|(condition, result)| {
+                        (
+                            self.remap_expression(condition, mapping),
+                            self.remap_expression(result, mapping),
+                        )
+                    })
+                    .collect(),
+                else_: else_.map(|else_| Box::new(self.remap_expression(*else_, mapping))),
+            },
         }
     }
 
@@ -639,6 +1442,7 @@ impl Optimizer {
                         limit: limit_op.limit,
                         offset: limit_op.offset,
                         child: Box::new(optimized_child),
+                        estimated_rows: limit_op.estimated_rows,
                     })
                 } else {
                     // can't push down, just recurse
@@ -647,6 +1451,7 @@ impl Optimizer {
                         limit: limit_op.limit,
                         offset: limit_op.offset,
                         child: Box::new(optimized_child),
+                        estimated_rows: limit_op.estimated_rows,
                     })
                 }
             }
@@ -654,7 +1459,9 @@ impl Optimizer {
                 let optimized_child = self.push_down_limit(*proj.child);
                 LogicalOperator::Projection(LogicalProjection {
                     expressions: proj.expressions,
+                    aliases: proj.aliases,
                     child: Box::new(optimized_child),
+                    estimated_rows: proj.estimated_rows,
                 })
             }
             LogicalOperator::Filter(filter) => {
@@ -662,31 +1469,211 @@ impl Optimizer {
                 LogicalOperator::Filter(LogicalFilter {
                     expression: filter.expression,
                     child: Box::new(optimized_child),
+                    estimated_rows: filter.estimated_rows,
                 })
             }
             LogicalOperator::Get(get) => {
                 // base case - no recursion needed
                 LogicalOperator::Get(get)
             }
+            LogicalOperator::Sort(sort) => {
+                // the first rows in sort order can come from anywhere in the file
+                let optimized_child = self.push_down_limit(*sort.child);
+                LogicalOperator::Sort(LogicalSort {
+                    keys: sort.keys,
+                    child: Box::new(optimized_child),
+                    estimated_rows: sort.estimated_rows,
+                })
+            }
+            LogicalOperator::TopN(top_n) => {
+                let optimized_child = self.push_down_limit(*top_n.child);
+                LogicalOperator::TopN(LogicalTopN {
+                    keys: top_n.keys,
+                    limit: top_n.limit,
+                    offset: top_n.offset,
+                    child: Box::new(optimized_child),
+                    estimated_rows: top_n.estimated_rows,
+                })
+            }
+            LogicalOperator::Sample(sample) => {
+                let optimized_child = self.push_down_limit(*sample.child);
+                LogicalOperator::Sample(LogicalSample {
+                    size: sample.size,
+                    seed: sample.seed,
+                    child: Box::new(optimized_child),
+                    estimated_rows: sample.estimated_rows,
+                })
+            }
             LogicalOperator::Aggregate(agg) => {
                 // aggregate should not have limit pushed through it
                 let optimized_child = self.push_down_limit(*agg.child);
                 LogicalOperator::Aggregate(crate::planner::LogicalAggregate {
+                    groups: agg.groups,
                     aggregates: agg.aggregates,
                     child: Box::new(optimized_child),
+                    estimated_rows: agg.estimated_rows,
                 })
             }
+            union @ (LogicalOperator::Union(_) | LogicalOperator::Distinct(_)) => union,
+            explain @ LogicalOperator::Explain(_) => explain,
         }
     }
 
-    /// calculate max_rows = (limit + offset) * safety_factor
-    /// safety factor accounts for filter selectivity
+    /// replace a Sort directly under a Limit (possibly through the output
+    /// projection, which is per row) with a TopN that only keeps limit + offset
+    /// rows. the TopN applies the offset itself, so the Limit goes away
+    fn fuse_top_n(&self, plan: LogicalOperator) -> LogicalOperator {
+        let LogicalOperator::Limit(limit_op) = plan else {
+            return plan;
+        };
+        let Some(limit) = limit_op.limit else {
+            return LogicalOperator::Limit(limit_op);
+        };
+        let offset = limit_op.offset.unwrap_or(0);
+        let top_n = |sort: LogicalSort| {
+            LogicalOperator::TopN(LogicalTopN {
+                keys: sort.keys,
+                limit,
+                offset,
+                child: sort.child,
+                estimated_rows: limit_op.estimated_rows,
+            })
+        };
+
+        let relimit = |child: LogicalOperator| {
+            LogicalOperator::Limit(LogicalLimit {
+                limit: limit_op.limit,
+                offset: limit_op.offset,
+                child: Box::new(child),
+                estimated_rows: limit_op.estimated_rows,
+            })
+        };
+
+        match *limit_op.child {
+            LogicalOperator::Sort(sort) => top_n(sort),
+            LogicalOperator::Projection(proj) => {
+                let rebuild = |child: LogicalOperator| {
+                    LogicalOperator::Projection(LogicalProjection {
+                        expressions: proj.expressions,
+                        aliases: proj.aliases,
+                        child: Box::new(child),
+                        estimated_rows: proj.estimated_rows,
+                    })
+                };
+                match *proj.child {
+                    LogicalOperator::Sort(sort) => rebuild(top_n(sort)),
+                    child => relimit(rebuild(child)),
+                }
+            }
+            child => relimit(child),
+        }
+    }
+
+    /// remove projections whose output is exactly their input: the same columns,
+    /// under the same names, in the same order (`SELECT *` after pushdown).
+    /// such a projection only copies every chunk
+    fn drop_identity_projections(&self, plan: LogicalOperator) -> LogicalOperator {
+        match plan {
+            LogicalOperator::Projection(proj) => {
+                let child = self.drop_identity_projections(*proj.child);
+                if self.is_identity_projection(&proj.expressions, &proj.aliases, &child) {
+                    return child;
+                }
+                LogicalOperator::Projection(LogicalProjection {
+                    child: Box::new(child),
+                    ..proj
+                })
+            }
+            LogicalOperator::Filter(filter) => LogicalOperator::Filter(LogicalFilter {
+                child: Box::new(self.drop_identity_projections(*filter.child)),
+                ..filter
+            }),
+            LogicalOperator::Limit(limit) => LogicalOperator::Limit(LogicalLimit {
+                child: Box::new(self.drop_identity_projections(*limit.child)),
+                ..limit
+            }),
+            LogicalOperator::Sort(sort) => LogicalOperator::Sort(LogicalSort {
+                child: Box::new(self.drop_identity_projections(*sort.child)),
+                ..sort
+            }),
+            LogicalOperator::TopN(top_n) => LogicalOperator::TopN(LogicalTopN {
+                child: Box::new(self.drop_identity_projections(*top_n.child)),
+                ..top_n
+            }),
+            LogicalOperator::Sample(sample) => LogicalOperator::Sample(LogicalSample {
+                child: Box::new(self.drop_identity_projections(*sample.child)),
+                ..sample
+            }),
+            LogicalOperator::Aggregate(agg) => {
+                LogicalOperator::Aggregate(crate::planner::LogicalAggregate {
+                    child: Box::new(self.drop_identity_projections(*agg.child)),
+                    ..agg
+                })
+            }
+            get @ LogicalOperator::Get(_) => get,
+            union @ (LogicalOperator::Union(_) | LogicalOperator::Distinct(_)) => union,
+            explain @ LogicalOperator::Explain(_) => explain,
+        }
+    }
+
+    /// whether each projected expression is the child's column at the same
+    /// position, unrenamed, and nothing else is output
+    fn is_identity_projection(
+        &self,
+        expressions: &[BoundExpression],
+        aliases: &[Option<String>],
+        child: &LogicalOperator,
+    ) -> bool {
+        let Some(names) = self.output_names(child) else {
+            return false;
+        };
+        aliases.iter().all(Option::is_none)
+            && expressions.len() == names.len()
+            && expressions
+                .iter()
+                .zip(names)
+                .enumerate()
+                .all(|(position, (expr, column))| {
+                    matches!(expr, BoundExpression::ColumnRef { name, index, .. }
+                        if *index == position && name == column)
+                })
+    }
+
+    /// column names of a scan, possibly sorted or sampled, whose chunks a
+    /// projection would only copy. None for operators that compute their own
+    /// columns, and for filters and limits: they mark their rows in a selection
+    /// vector, which the projection compacts
+    fn output_names<'a>(&self, plan: &'a LogicalOperator) -> Option<Vec<&'a str>> {
+        match plan {
+            LogicalOperator::Get(get) => {
+                Some(get.columns.iter().map(|c| c.name.as_str()).collect())
+            }
+            LogicalOperator::Sort(sort) => self.output_names(&sort.child),
+            LogicalOperator::TopN(top_n) => self.output_names(&top_n.child),
+            LogicalOperator::Sample(sample) => self.output_names(&sample.child),
+            LogicalOperator::Filter(_)
+            | LogicalOperator::Limit(_)
+            | LogicalOperator::Projection(_)
+            | LogicalOperator::Aggregate(_)
+            | LogicalOperator::Union(_)
+            | LogicalOperator::Distinct(_)
+            | LogicalOperator::Explain(_) => None,
+        }
+    }
+
+    /// calculate max_rows = limit + offset
+    /// filters have unknown selectivity, so a capped scan could return too few rows.
+    /// in that case the scan stays uncapped and the executor stops once LIMIT is satisfied
     fn calculate_max_rows(&self, limit_op: &LogicalLimit) -> Option<usize> {
         // check if the child chain is simple enough for limit pushdown
         if !self.is_simple_scan_chain(&limit_op.child) {
             return None;
         }
 
+        if self.has_filters_in_chain(&limit_op.child) {
+            return None;
+        }
+
         // calculate total rows needed: limit + offset
         let limit_val = limit_op.limit.unwrap_or(usize::MAX);
         let offset_val = limit_op.offset.unwrap_or(0);
@@ -696,20 +1683,7 @@ impl Optimizer {
             return None;
         }
 
-        let base_rows = limit_val.saturating_add(offset_val);
-
-        // apply safety factor if there are filters in the chain
-        // this accounts for unknown filter selectivity
-        let has_filters = self.has_filters_in_chain(&limit_op.child);
-        let safety_factor = if has_filters {
-            // assume ~10% selectivity (read 10x more rows than needed)
-            // still much better than reading entire file
-            10
-        } else {
-            1 // no filters, read exact amount
-        };
-
-        Some(base_rows.saturating_mul(safety_factor))
+        Some(limit_val.saturating_add(offset_val))
     }
 
     /// check if the operator chain is simple (only Get, Filter, Projection)
@@ -721,6 +1695,12 @@ impl Optimizer {
             LogicalOperator::Projection(proj) => self.is_simple_scan_chain(&proj.child),
             LogicalOperator::Limit(_) => false, // nested limits - don't optimize
             LogicalOperator::Aggregate(_) => false, // don't push limit through aggregates
+            LogicalOperator::Sort(_) => false,  // nor through a sort
+            LogicalOperator::TopN(_) => false,
+            LogicalOperator::Sample(_) => false, // a reservoir needs every row
+            LogicalOperator::Union(_) => false,  // its branches get their own limits
+            LogicalOperator::Distinct(_) => false,
+            LogicalOperator::Explain(_) => false,
         }
     }
 
@@ -732,6 +1712,12 @@ impl Optimizer {
             LogicalOperator::Projection(proj) => self.has_filters_in_chain(&proj.child),
             LogicalOperator::Limit(_) => false,
             LogicalOperator::Aggregate(_) => false,
+            LogicalOperator::Sort(_) => false,
+            LogicalOperator::TopN(_) => false,
+            LogicalOperator::Sample(_) => false,
+            LogicalOperator::Union(_) => false,
+            LogicalOperator::Distinct(_) => false,
+            LogicalOperator::Explain(_) => false,
         }
     }
 
@@ -739,7 +1725,8 @@ impl Optimizer {
     fn set_max_rows_on_get(&self, plan: LogicalOperator, max_rows: usize) -> LogicalOperator {
         match plan {
             LogicalOperator::Get(mut get) => {
-                get.max_rows = Some(max_rows);
+                // a head SAMPLE may already cap the scan lower
+                get.max_rows = Some(get.max_rows.map_or(max_rows, |cap| cap.min(max_rows)));
                 LogicalOperator::Get(get)
             }
             LogicalOperator::Filter(filter) => {
@@ -747,13 +1734,16 @@ impl Optimizer {
                 LogicalOperator::Filter(LogicalFilter {
                     expression: filter.expression,
                     child: Box::new(optimized_child),
+                    estimated_rows: filter.estimated_rows,
                 })
             }
             LogicalOperator::Projection(proj) => {
                 let optimized_child = self.set_max_rows_on_get(*proj.child, max_rows);
                 LogicalOperator::Projection(LogicalProjection {
                     expressions: proj.expressions,
+                    aliases: proj.aliases,
                     child: Box::new(optimized_child),
+                    estimated_rows: proj.estimated_rows,
                 })
             }
             LogicalOperator::Limit(limit) => {
@@ -764,6 +1754,58 @@ impl Optimizer {
                 // shouldn't happen if is_simple_scan_chain works correctly
                 LogicalOperator::Aggregate(agg)
             }
+            LogicalOperator::Sort(sort) => {
+                // shouldn't happen if is_simple_scan_chain works correctly
+                LogicalOperator::Sort(sort)
+            }
+            top_n @ LogicalOperator::TopN(_) => top_n,
+            sample @ LogicalOperator::Sample(_) => sample,
+            union @ (LogicalOperator::Union(_) | LogicalOperator::Distinct(_)) => union,
+            explain @ LogicalOperator::Explain(_) => explain,
+        }
+    }
+}
+
+/// rough per-row cost of evaluating an expression: one per node, with
+/// pattern matching, casts and function calls weighted higher. a column
+/// compared with a literal costs 3, so it sorts before nested AND/OR
+fn evaluation_cost(expr: &BoundExpression) -> usize {
+    match expr {
+        BoundExpression::ColumnRef { .. } | BoundExpression::Literal { .. } => 1,
+        BoundExpression::Not(inner)
+        | BoundExpression::Negate(inner)
+        | BoundExpression::IsTrue(inner)
+        | BoundExpression::IsFalse(inner) => 1 + evaluation_cost(inner),
+        BoundExpression::Cast { expr, .. } => 2 + evaluation_cost(expr),
+        BoundExpression::Like(left, right, _) => 4 + evaluation_cost(left) + evaluation_cost(right),
+        BoundExpression::In(left, list) => {
+            1 + evaluation_cost(left) + list.iter().map(evaluation_cost).sum::<usize>()
+        }
+        BoundExpression::FunctionCall { args, .. } => {
+            4 + args.iter().map(evaluation_cost).sum::<usize>()
+        }
+        BoundExpression::Case { branches, else_ } => {
+            1 + branches
+                .iter()
+                .map(|(condition, result)| evaluation_cost(condition) + evaluation_cost(result))
+                .sum::<usize>()
+                + else_.as_deref().map_or(0, evaluation_cost)
+        }
+        BoundExpression::Or(left, right)
+        | BoundExpression::And(left, right)
+        | BoundExpression::Equal(left, right)
+        | BoundExpression::NotEqual(left, right)
+        | BoundExpression::GreaterThan(left, right)
+        | BoundExpression::GreaterThanOrEqual(left, right)
+        | BoundExpression::LessThan(left, right)
+        | BoundExpression::LessThanOrEqual(left, right)
+        | BoundExpression::Add(left, right)
+        | BoundExpression::Subtract(left, right)
+        | BoundExpression::Multiply(left, right)
+        | BoundExpression::Divide(left, right)
+        | BoundExpression::Modulo(left, right)
+        | BoundExpression::Concat(left, right) => {
+            1 + evaluation_cost(left) + evaluation_cost(right)
         }
     }
 }
@@ -7,86 +7,185 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 60
+#define STATE_COUNT 1043
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 53
+#define SYMBOL_COUNT 126
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 31
+#define TOKEN_COUNT 73
 #define EXTERNAL_TOKEN_COUNT 0
 #define FIELD_COUNT 0
-#define MAX_ALIAS_SEQUENCE_LENGTH 7
+#define MAX_ALIAS_SEQUENCE_LENGTH 13
 #define MAX_RESERVED_WORD_SET_SIZE 0
 #define PRODUCTION_ID_COUNT 1
 #define SUPERTYPE_COUNT 0
 
 enum ts_symbol_identifiers {
   anon_sym_SEMI = 1,
-  aux_sym_select_statement_token1 = 2,
-  aux_sym_select_statement_token2 = 3,
-  anon_sym_STAR = 4,
-  anon_sym_COMMA = 5,
-  anon_sym_LPAREN = 6,
-  anon_sym_RPAREN = 7,
-  aux_sym_aggregate_function_token1 = 8,
-  aux_sym_where_clause_token1 = 9,
-  aux_sym_limit_clause_token1 = 10,
-  aux_sym_offset_clause_token1 = 11,
-  aux_sym_or_expression_token1 = 12,
-  aux_sym_and_expression_token1 = 13,
-  aux_sym_not_expression_token1 = 14,
-  anon_sym_EQ = 15,
-  anon_sym_BANG_EQ = 16,
-  anon_sym_LT_GT = 17,
-  anon_sym_GT = 18,
-  anon_sym_GT_EQ = 19,
-  anon_sym_LT = 20,
-  anon_sym_LT_EQ = 21,
-  aux_sym_literal_token1 = 22,
-  anon_sym_SQUOTE = 23,
-  aux_sym_string_literal_token1 = 24,
-  anon_sym_DQUOTE = 25,
-  aux_sym_string_literal_token2 = 26,
-  sym_number_literal = 27,
-  aux_sym_boolean_literal_token1 = 28,
-  aux_sym_boolean_literal_token2 = 29,
-  sym__identifier = 30,
-  sym_source_file = 31,
-  sym__statement = 32,
-  sym_select_statement = 33,
-  sym_select_list = 34,
-  sym_column_list = 35,
-  sym_select_expression = 36,
-  sym_aggregate_function = 37,
-  sym_column_name = 38,
-  sym_file_name = 39,
-  sym_where_clause = 40,
-  sym_limit_clause = 41,
-  sym_offset_clause = 42,
-  sym_expression = 43,
-  sym_or_expression = 44,
-  sym_and_expression = 45,
-  sym_not_expression = 46,
-  sym_primary_expression = 47,
-  sym_comparison_expression = 48,
-  sym_literal = 49,
-  sym_string_literal = 50,
-  sym_boolean_literal = 51,
-  aux_sym_column_list_repeat1 = 52,
+  aux_sym_explain_token1 = 2,
+  sym_analyze = 3,
+  aux_sym_union_clause_token1 = 4,
+  sym_union_all = 5,
+  aux_sym_select_statement_token1 = 6,
+  aux_sym_select_statement_token2 = 7,
+  anon_sym_COMMA = 8,
+  anon_sym_STAR = 9,
+  aux_sym_select_expression_token1 = 10,
+  anon_sym_LPAREN = 11,
+  anon_sym_RPAREN = 12,
+  anon_sym_DOT = 13,
+  anon_sym_DQUOTE = 14,
+  aux_sym_column_name_token1 = 15,
+  anon_sym_LBRACK = 16,
+  aux_sym_column_name_token2 = 17,
+  anon_sym_RBRACK = 18,
+  anon_sym_BQUOTE = 19,
+  aux_sym_column_name_token3 = 20,
+  aux_sym_where_clause_token1 = 21,
+  aux_sym_group_by_clause_token1 = 22,
+  aux_sym_group_by_clause_token2 = 23,
+  aux_sym_having_clause_token1 = 24,
+  aux_sym_order_by_clause_token1 = 25,
+  aux_sym_sort_direction_token1 = 26,
+  aux_sym_sort_direction_token2 = 27,
+  aux_sym_sample_clause_token1 = 28,
+  aux_sym_limit_clause_token1 = 29,
+  aux_sym_limit_clause_token2 = 30,
+  aux_sym_offset_clause_token1 = 31,
+  aux_sym_offset_clause_token2 = 32,
+  aux_sym_offset_clause_token3 = 33,
+  aux_sym_fetch_clause_token1 = 34,
+  aux_sym_fetch_clause_token2 = 35,
+  aux_sym_fetch_clause_token3 = 36,
+  aux_sym_fetch_clause_token4 = 37,
+  aux_sym_or_expression_token1 = 38,
+  aux_sym_and_expression_token1 = 39,
+  aux_sym_not_expression_token1 = 40,
+  anon_sym_EQ = 41,
+  anon_sym_BANG_EQ = 42,
+  anon_sym_LT_GT = 43,
+  anon_sym_GT = 44,
+  anon_sym_GT_EQ = 45,
+  anon_sym_LT = 46,
+  anon_sym_LT_EQ = 47,
+  aux_sym_comparison_expression_token1 = 48,
+  aux_sym_comparison_expression_token2 = 49,
+  aux_sym_comparison_expression_token3 = 50,
+  aux_sym_comparison_expression_token4 = 51,
+  aux_sym_like_escape_token1 = 52,
+  anon_sym_PLUS = 53,
+  anon_sym_DASH = 54,
+  anon_sym_SLASH = 55,
+  anon_sym_PERCENT = 56,
+  aux_sym_cast_expression_token1 = 57,
+  aux_sym_case_expression_token1 = 58,
+  aux_sym_case_expression_token2 = 59,
+  aux_sym_when_clause_token1 = 60,
+  aux_sym_when_clause_token2 = 61,
+  aux_sym_else_clause_token1 = 62,
+  anon_sym_PIPE_PIPE = 63,
+  aux_sym_literal_token1 = 64,
+  anon_sym_SQUOTE = 65,
+  aux_sym_string_literal_token1 = 66,
+  aux_sym_date_literal_token1 = 67,
+  aux_sym_timestamp_literal_token1 = 68,
+  sym_number_literal = 69,
+  aux_sym_boolean_literal_token1 = 70,
+  aux_sym_boolean_literal_token2 = 71,
+  sym__identifier = 72,
+  sym_source_file = 73,
+  sym_explain = 74,
+  sym__statement = 75,
+  sym_union_statement = 76,
+  sym_union_clause = 77,
+  sym_select_statement = 78,
+  sym_select_list = 79,
+  sym_column_list = 80,
+  sym_select_expression = 81,
+  sym_alias = 82,
+  sym_function_call = 83,
+  sym_function_name = 84,
+  sym_column_name = 85,
+  sym_table_qualifier = 86,
+  sym_file_name = 87,
+  sym_where_clause = 88,
+  sym_group_by_clause = 89,
+  sym_having_clause = 90,
+  sym_order_by_clause = 91,
+  sym_order_by_item = 92,
+  sym_sort_direction = 93,
+  sym_sample_clause = 94,
+  sym_limit_clause = 95,
+  sym_offset_clause = 96,
+  sym_fetch_clause = 97,
+  sym_expression = 98,
+  sym_or_expression = 99,
+  sym_and_expression = 100,
+  sym_not_expression = 101,
+  sym_primary_expression = 102,
+  sym_comparison_expression = 103,
+  sym_like_escape = 104,
+  sym_arithmetic_expression = 105,
+  sym_cast_expression = 106,
+  sym_type_name = 107,
+  sym_case_expression = 108,
+  sym_when_clause = 109,
+  sym_else_clause = 110,
+  sym_concat_expression = 111,
+  sym_in_list = 112,
+  sym_literal = 113,
+  sym_string_literal = 114,
+  sym_date_literal = 115,
+  sym_timestamp_literal = 116,
+  sym_boolean_literal = 117,
+  aux_sym_union_statement_repeat1 = 118,
+  aux_sym_select_statement_repeat1 = 119,
+  aux_sym_column_list_repeat1 = 120,
+  aux_sym_function_call_repeat1 = 121,
+  aux_sym_group_by_clause_repeat1 = 122,
+  aux_sym_order_by_clause_repeat1 = 123,
+  aux_sym_case_expression_repeat1 = 124,
+  aux_sym_in_list_repeat1 = 125,
 };
 
 static const char * const ts_symbol_names[] = {
   [ts_builtin_sym_end] = "end",
   [anon_sym_SEMI] = ";",
+  [aux_sym_explain_token1] = "explain_token1",
+  [sym_analyze] = "analyze",
+  [aux_sym_union_clause_token1] = "union_clause_token1",
+  [sym_union_all] = "union_all",
   [aux_sym_select_statement_token1] = "select_statement_token1",
   [aux_sym_select_statement_token2] = "select_statement_token2",
-  [anon_sym_STAR] = "*",
   [anon_sym_COMMA] = ",",
+  [anon_sym_STAR] = "*",
+  [aux_sym_select_expression_token1] = "select_expression_token1",
   [anon_sym_LPAREN] = "(",
   [anon_sym_RPAREN] = ")",
-  [aux_sym_aggregate_function_token1] = "aggregate_function_token1",
+  [anon_sym_DOT] = ".",
+  [anon_sym_DQUOTE] = "\"",
+  [aux_sym_column_name_token1] = "column_name_token1",
+  [anon_sym_LBRACK] = "[",
+  [aux_sym_column_name_token2] = "column_name_token2",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_BQUOTE] = "`",
+  [aux_sym_column_name_token3] = "column_name_token3",
   [aux_sym_where_clause_token1] = "where_clause_token1",
+  [aux_sym_group_by_clause_token1] = "group_by_clause_token1",
+  [aux_sym_group_by_clause_token2] = "group_by_clause_token2",
+  [aux_sym_having_clause_token1] = "having_clause_token1",
+  [aux_sym_order_by_clause_token1] = "order_by_clause_token1",
+  [aux_sym_sort_direction_token1] = "sort_direction_token1",
+  [aux_sym_sort_direction_token2] = "sort_direction_token2",
+  [aux_sym_sample_clause_token1] = "sample_clause_token1",
   [aux_sym_limit_clause_token1] = "limit_clause_token1",
+  [aux_sym_limit_clause_token2] = "limit_clause_token2",
   [aux_sym_offset_clause_token1] = "offset_clause_token1",
+  [aux_sym_offset_clause_token2] = "offset_clause_token2",
+  [aux_sym_offset_clause_token3] = "offset_clause_token3",
+  [aux_sym_fetch_clause_token1] = "fetch_clause_token1",
+  [aux_sym_fetch_clause_token2] = "fetch_clause_token2",
+  [aux_sym_fetch_clause_token3] = "fetch_clause_token3",
+  [aux_sym_fetch_clause_token4] = "fetch_clause_token4",
   [aux_sym_or_expression_token1] = "or_expression_token1",
   [aux_sym_and_expression_token1] = "and_expression_token1",
   [aux_sym_not_expression_token1] = "not_expression_token1",
@@ -97,52 +196,125 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_GT_EQ] = ">=",
   [anon_sym_LT] = "<",
   [anon_sym_LT_EQ] = "<=",
+  [aux_sym_comparison_expression_token1] = "comparison_expression_token1",
+  [aux_sym_comparison_expression_token2] = "comparison_expression_token2",
+  [aux_sym_comparison_expression_token3] = "comparison_expression_token3",
+  [aux_sym_comparison_expression_token4] = "comparison_expression_token4",
+  [aux_sym_like_escape_token1] = "like_escape_token1",
+  [anon_sym_PLUS] = "+",
+  [anon_sym_DASH] = "-",
+  [anon_sym_SLASH] = "/",
+  [anon_sym_PERCENT] = "%",
+  [aux_sym_cast_expression_token1] = "cast_expression_token1",
+  [aux_sym_case_expression_token1] = "case_expression_token1",
+  [aux_sym_case_expression_token2] = "case_expression_token2",
+  [aux_sym_when_clause_token1] = "when_clause_token1",
+  [aux_sym_when_clause_token2] = "when_clause_token2",
+  [aux_sym_else_clause_token1] = "else_clause_token1",
+  [anon_sym_PIPE_PIPE] = "||",
   [aux_sym_literal_token1] = "literal_token1",
   [anon_sym_SQUOTE] = "'",
   [aux_sym_string_literal_token1] = "string_literal_token1",
-  [anon_sym_DQUOTE] = "\"",
-  [aux_sym_string_literal_token2] = "string_literal_token2",
+  [aux_sym_date_literal_token1] = "date_literal_token1",
+  [aux_sym_timestamp_literal_token1] = "timestamp_literal_token1",
   [sym_number_literal] = "number_literal",
   [aux_sym_boolean_literal_token1] = "boolean_literal_token1",
   [aux_sym_boolean_literal_token2] = "boolean_literal_token2",
   [sym__identifier] = "_identifier",
   [sym_source_file] = "source_file",
+  [sym_explain] = "explain",
   [sym__statement] = "_statement",
+  [sym_union_statement] = "union_statement",
+  [sym_union_clause] = "union_clause",
   [sym_select_statement] = "select_statement",
   [sym_select_list] = "select_list",
   [sym_column_list] = "column_list",
   [sym_select_expression] = "select_expression",
-  [sym_aggregate_function] = "aggregate_function",
+  [sym_alias] = "alias",
+  [sym_function_call] = "function_call",
+  [sym_function_name] = "function_name",
   [sym_column_name] = "column_name",
+  [sym_table_qualifier] = "table_qualifier",
   [sym_file_name] = "file_name",
   [sym_where_clause] = "where_clause",
+  [sym_group_by_clause] = "group_by_clause",
+  [sym_having_clause] = "having_clause",
+  [sym_order_by_clause] = "order_by_clause",
+  [sym_order_by_item] = "order_by_item",
+  [sym_sort_direction] = "sort_direction",
+  [sym_sample_clause] = "sample_clause",
   [sym_limit_clause] = "limit_clause",
   [sym_offset_clause] = "offset_clause",
+  [sym_fetch_clause] = "fetch_clause",
   [sym_expression] = "expression",
   [sym_or_expression] = "or_expression",
   [sym_and_expression] = "and_expression",
   [sym_not_expression] = "not_expression",
   [sym_primary_expression] = "primary_expression",
   [sym_comparison_expression] = "comparison_expression",
+  [sym_like_escape] = "like_escape",
+  [sym_arithmetic_expression] = "arithmetic_expression",
+  [sym_cast_expression] = "cast_expression",
+  [sym_type_name] = "type_name",
+  [sym_case_expression] = "case_expression",
+  [sym_when_clause] = "when_clause",
+  [sym_else_clause] = "else_clause",
+  [sym_concat_expression] = "concat_expression",
+  [sym_in_list] = "in_list",
   [sym_literal] = "literal",
   [sym_string_literal] = "string_literal",
+  [sym_date_literal] = "date_literal",
+  [sym_timestamp_literal] = "timestamp_literal",
   [sym_boolean_literal] = "boolean_literal",
+  [aux_sym_union_statement_repeat1] = "union_statement_repeat1",
+  [aux_sym_select_statement_repeat1] = "select_statement_repeat1",
   [aux_sym_column_list_repeat1] = "column_list_repeat1",
+  [aux_sym_function_call_repeat1] = "function_call_repeat1",
+  [aux_sym_group_by_clause_repeat1] = "group_by_clause_repeat1",
+  [aux_sym_order_by_clause_repeat1] = "order_by_clause_repeat1",
+  [aux_sym_case_expression_repeat1] = "case_expression_repeat1",
+  [aux_sym_in_list_repeat1] = "in_list_repeat1",
 };
 
 static const TSSymbol ts_symbol_map[] = {
   [ts_builtin_sym_end] = ts_builtin_sym_end,
   [anon_sym_SEMI] = anon_sym_SEMI,
+  [aux_sym_explain_token1] = aux_sym_explain_token1,
+  [sym_analyze] = sym_analyze,
+  [aux_sym_union_clause_token1] = aux_sym_union_clause_token1,
+  [sym_union_all] = sym_union_all,
   [aux_sym_select_statement_token1] = aux_sym_select_statement_token1,
   [aux_sym_select_statement_token2] = aux_sym_select_statement_token2,
-  [anon_sym_STAR] = anon_sym_STAR,
   [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_STAR] = anon_sym_STAR,
+  [aux_sym_select_expression_token1] = aux_sym_select_expression_token1,
   [anon_sym_LPAREN] = anon_sym_LPAREN,
   [anon_sym_RPAREN] = anon_sym_RPAREN,
-  [aux_sym_aggregate_function_token1] = aux_sym_aggregate_function_token1,
+  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [aux_sym_column_name_token1] = aux_sym_column_name_token1,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [aux_sym_column_name_token2] = aux_sym_column_name_token2,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_BQUOTE] = anon_sym_BQUOTE,
+  [aux_sym_column_name_token3] = aux_sym_column_name_token3,
   [aux_sym_where_clause_token1] = aux_sym_where_clause_token1,
+  [aux_sym_group_by_clause_token1] = aux_sym_group_by_clause_token1,
+  [aux_sym_group_by_clause_token2] = aux_sym_group_by_clause_token2,
+  [aux_sym_having_clause_token1] = aux_sym_having_clause_token1,
+  [aux_sym_order_by_clause_token1] = aux_sym_order_by_clause_token1,
+  [aux_sym_sort_direction_token1] = aux_sym_sort_direction_token1,
+  [aux_sym_sort_direction_token2] = aux_sym_sort_direction_token2,
+  [aux_sym_sample_clause_token1] = aux_sym_sample_clause_token1,
   [aux_sym_limit_clause_token1] = aux_sym_limit_clause_token1,
+  [aux_sym_limit_clause_token2] = aux_sym_limit_clause_token2,
   [aux_sym_offset_clause_token1] = aux_sym_offset_clause_token1,
+  [aux_sym_offset_clause_token2] = aux_sym_offset_clause_token2,
+  [aux_sym_offset_clause_token3] = aux_sym_offset_clause_token3,
+  [aux_sym_fetch_clause_token1] = aux_sym_fetch_clause_token1,
+  [aux_sym_fetch_clause_token2] = aux_sym_fetch_clause_token2,
+  [aux_sym_fetch_clause_token3] = aux_sym_fetch_clause_token3,
+  [aux_sym_fetch_clause_token4] = aux_sym_fetch_clause_token4,
   [aux_sym_or_expression_token1] = aux_sym_or_expression_token1,
   [aux_sym_and_expression_token1] = aux_sym_and_expression_token1,
   [aux_sym_not_expression_token1] = aux_sym_not_expression_token1,
@@ -153,37 +325,84 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_GT_EQ] = anon_sym_GT_EQ,
   [anon_sym_LT] = anon_sym_LT,
   [anon_sym_LT_EQ] = anon_sym_LT_EQ,
+  [aux_sym_comparison_expression_token1] = aux_sym_comparison_expression_token1,
+  [aux_sym_comparison_expression_token2] = aux_sym_comparison_expression_token2,
+  [aux_sym_comparison_expression_token3] = aux_sym_comparison_expression_token3,
+  [aux_sym_comparison_expression_token4] = aux_sym_comparison_expression_token4,
+  [aux_sym_like_escape_token1] = aux_sym_like_escape_token1,
+  [anon_sym_PLUS] = anon_sym_PLUS,
+  [anon_sym_DASH] = anon_sym_DASH,
+  [anon_sym_SLASH] = anon_sym_SLASH,
+  [anon_sym_PERCENT] = anon_sym_PERCENT,
+  [aux_sym_cast_expression_token1] = aux_sym_cast_expression_token1,
+  [aux_sym_case_expression_token1] = aux_sym_case_expression_token1,
+  [aux_sym_case_expression_token2] = aux_sym_case_expression_token2,
+  [aux_sym_when_clause_token1] = aux_sym_when_clause_token1,
+  [aux_sym_when_clause_token2] = aux_sym_when_clause_token2,
+  [aux_sym_else_clause_token1] = aux_sym_else_clause_token1,
+  [anon_sym_PIPE_PIPE] = anon_sym_PIPE_PIPE,
   [aux_sym_literal_token1] = aux_sym_literal_token1,
   [anon_sym_SQUOTE] = anon_sym_SQUOTE,
   [aux_sym_string_literal_token1] = aux_sym_string_literal_token1,
-  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
-  [aux_sym_string_literal_token2] = aux_sym_string_literal_token2,
+  [aux_sym_date_literal_token1] = aux_sym_date_literal_token1,
+  [aux_sym_timestamp_literal_token1] = aux_sym_timestamp_literal_token1,
   [sym_number_literal] = sym_number_literal,
   [aux_sym_boolean_literal_token1] = aux_sym_boolean_literal_token1,
   [aux_sym_boolean_literal_token2] = aux_sym_boolean_literal_token2,
   [sym__identifier] = sym__identifier,
   [sym_source_file] = sym_source_file,
+  [sym_explain] = sym_explain,
   [sym__statement] = sym__statement,
+  [sym_union_statement] = sym_union_statement,
+  [sym_union_clause] = sym_union_clause,
   [sym_select_statement] = sym_select_statement,
   [sym_select_list] = sym_select_list,
   [sym_column_list] = sym_column_list,
   [sym_select_expression] = sym_select_expression,
-  [sym_aggregate_function] = sym_aggregate_function,
+  [sym_alias] = sym_alias,
+  [sym_function_call] = sym_function_call,
+  [sym_function_name] = sym_function_name,
   [sym_column_name] = sym_column_name,
+  [sym_table_qualifier] = sym_table_qualifier,
   [sym_file_name] = sym_file_name,
   [sym_where_clause] = sym_where_clause,
+  [sym_group_by_clause] = sym_group_by_clause,
+  [sym_having_clause] = sym_having_clause,
+  [sym_order_by_clause] = sym_order_by_clause,
+  [sym_order_by_item] = sym_order_by_item,
+  [sym_sort_direction] = sym_sort_direction,
+  [sym_sample_clause] = sym_sample_clause,
   [sym_limit_clause] = sym_limit_clause,
   [sym_offset_clause] = sym_offset_clause,
+  [sym_fetch_clause] = sym_fetch_clause,
   [sym_expression] = sym_expression,
   [sym_or_expression] = sym_or_expression,
   [sym_and_expression] = sym_and_expression,
   [sym_not_expression] = sym_not_expression,
   [sym_primary_expression] = sym_primary_expression,
   [sym_comparison_expression] = sym_comparison_expression,
+  [sym_like_escape] = sym_like_escape,
+  [sym_arithmetic_expression] = sym_arithmetic_expression,
+  [sym_cast_expression] = sym_cast_expression,
+  [sym_type_name] = sym_type_name,
+  [sym_case_expression] = sym_case_expression,
+  [sym_when_clause] = sym_when_clause,
+  [sym_else_clause] = sym_else_clause,
+  [sym_concat_expression] = sym_concat_expression,
+  [sym_in_list] = sym_in_list,
   [sym_literal] = sym_literal,
   [sym_string_literal] = sym_string_literal,
+  [sym_date_literal] = sym_date_literal,
+  [sym_timestamp_literal] = sym_timestamp_literal,
   [sym_boolean_literal] = sym_boolean_literal,
+  [aux_sym_union_statement_repeat1] = aux_sym_union_statement_repeat1,
+  [aux_sym_select_statement_repeat1] = aux_sym_select_statement_repeat1,
   [aux_sym_column_list_repeat1] = aux_sym_column_list_repeat1,
+  [aux_sym_function_call_repeat1] = aux_sym_function_call_repeat1,
+  [aux_sym_group_by_clause_repeat1] = aux_sym_group_by_clause_repeat1,
+  [aux_sym_order_by_clause_repeat1] = aux_sym_order_by_clause_repeat1,
+  [aux_sym_case_expression_repeat1] = aux_sym_case_expression_repeat1,
+  [aux_sym_in_list_repeat1] = aux_sym_in_list_repeat1,
 };
 
 static const TSSymbolMetadata ts_symbol_metadata[] = {
@@ -195,6 +414,22 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [aux_sym_explain_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_analyze] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_union_clause_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_union_all] = {
+    .visible = true,
+    .named = true,
+  },
   [aux_sym_select_statement_token1] = {
     .visible = false,
     .named = false,
@@ -203,14 +438,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
-  [anon_sym_STAR] = {
+  [anon_sym_COMMA] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_COMMA] = {
+  [anon_sym_STAR] = {
     .visible = true,
     .named = false,
   },
+  [aux_sym_select_expression_token1] = {
+    .visible = false,
+    .named = false,
+  },
   [anon_sym_LPAREN] = {
     .visible = true,
     .named = false,
@@ -219,7 +458,35 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [aux_sym_aggregate_function_token1] = {
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_column_name_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_column_name_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_column_name_token3] = {
     .visible = false,
     .named = false,
   },
@@ -227,14 +494,70 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_group_by_clause_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_group_by_clause_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_having_clause_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_order_by_clause_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_sort_direction_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_sort_direction_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_sample_clause_token1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_limit_clause_token1] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_limit_clause_token2] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_offset_clause_token1] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_offset_clause_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_offset_clause_token3] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_fetch_clause_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_fetch_clause_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_fetch_clause_token3] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_fetch_clause_token4] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_or_expression_token1] = {
     .visible = false,
     .named = false,
@@ -275,6 +598,70 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [aux_sym_comparison_expression_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_comparison_expression_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_comparison_expression_token3] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_comparison_expression_token4] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_like_escape_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PERCENT] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_cast_expression_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_case_expression_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_case_expression_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_when_clause_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_when_clause_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_else_clause_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_PIPE_PIPE] = {
+    .visible = true,
+    .named = false,
+  },
   [aux_sym_literal_token1] = {
     .visible = false,
     .named = false,
@@ -287,11 +674,11 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
-  [anon_sym_DQUOTE] = {
-    .visible = true,
+  [aux_sym_date_literal_token1] = {
+    .visible = false,
     .named = false,
   },
-  [aux_sym_string_literal_token2] = {
+  [aux_sym_timestamp_literal_token1] = {
     .visible = false,
     .named = false,
   },
@@ -315,10 +702,22 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_explain] = {
+    .visible = true,
+    .named = true,
+  },
   [sym__statement] = {
     .visible = false,
     .named = true,
   },
+  [sym_union_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_union_clause] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_select_statement] = {
     .visible = true,
     .named = true,
@@ -335,7 +734,15 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
-  [sym_aggregate_function] = {
+  [sym_alias] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_function_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_function_name] = {
     .visible = true,
     .named = true,
   },
@@ -343,6 +750,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_table_qualifier] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_file_name] = {
     .visible = true,
     .named = true,
@@ -351,6 +762,30 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_group_by_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_having_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_order_by_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_order_by_item] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_sort_direction] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_sample_clause] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_limit_clause] = {
     .visible = true,
     .named = true,
@@ -359,6 +794,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_fetch_clause] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_expression] = {
     .visible = true,
     .named = true,
@@ -383,6 +822,42 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_like_escape] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_arithmetic_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_cast_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_case_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_when_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_else_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_concat_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_in_list] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_literal] = {
     .visible = true,
     .named = true,
@@ -391,14 +866,50 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_date_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_timestamp_literal] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_boolean_literal] = {
     .visible = true,
     .named = true,
   },
+  [aux_sym_union_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_select_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_column_list_repeat1] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_function_call_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_group_by_clause_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_order_by_clause_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_case_expression_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_in_list_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
 };
 
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
@@ -470,6 +981,989 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [57] = 57,
   [58] = 58,
   [59] = 59,
+  [60] = 60,
+  [61] = 61,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 94,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
+  [99] = 99,
+  [100] = 100,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 110,
+  [111] = 111,
+  [112] = 112,
+  [113] = 113,
+  [114] = 114,
+  [115] = 115,
+  [116] = 116,
+  [117] = 117,
+  [118] = 118,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 133,
+  [134] = 134,
+  [135] = 135,
+  [136] = 136,
+  [137] = 137,
+  [138] = 138,
+  [139] = 139,
+  [140] = 140,
+  [141] = 141,
+  [142] = 142,
+  [143] = 143,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 148,
+  [149] = 149,
+  [150] = 150,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 154,
+  [155] = 155,
+  [156] = 156,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
+  [160] = 160,
+  [161] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 164,
+  [165] = 165,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 184,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 189,
+  [190] = 190,
+  [191] = 191,
+  [192] = 192,
+  [193] = 193,
+  [194] = 194,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 199,
+  [200] = 200,
+  [201] = 201,
+  [202] = 202,
+  [203] = 203,
+  [204] = 204,
+  [205] = 205,
+  [206] = 206,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
+  [210] = 210,
+  [211] = 211,
+  [212] = 212,
+  [213] = 213,
+  [214] = 214,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 218,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 224,
+  [225] = 225,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 293,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
+  [317] = 317,
+  [318] = 318,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 322,
+  [323] = 323,
+  [324] = 324,
+  [325] = 325,
+  [326] = 326,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+  [330] = 330,
+  [331] = 331,
+  [332] = 332,
+  [333] = 333,
+  [334] = 334,
+  [335] = 335,
+  [336] = 336,
+  [337] = 337,
+  [338] = 338,
+  [339] = 339,
+  [340] = 340,
+  [341] = 341,
+  [342] = 342,
+  [343] = 343,
+  [344] = 344,
+  [345] = 345,
+  [346] = 346,
+  [347] = 347,
+  [348] = 348,
+  [349] = 349,
+  [350] = 350,
+  [351] = 351,
+  [352] = 352,
+  [353] = 353,
+  [354] = 354,
+  [355] = 355,
+  [356] = 356,
+  [357] = 357,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 361,
+  [362] = 362,
+  [363] = 363,
+  [364] = 364,
+  [365] = 365,
+  [366] = 366,
+  [367] = 367,
+  [368] = 368,
+  [369] = 369,
+  [370] = 370,
+  [371] = 371,
+  [372] = 372,
+  [373] = 373,
+  [374] = 374,
+  [375] = 375,
+  [376] = 376,
+  [377] = 377,
+  [378] = 378,
+  [379] = 379,
+  [380] = 380,
+  [381] = 381,
+  [382] = 382,
+  [383] = 383,
+  [384] = 384,
+  [385] = 385,
+  [386] = 386,
+  [387] = 387,
+  [388] = 388,
+  [389] = 389,
+  [390] = 390,
+  [391] = 391,
+  [392] = 392,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 396,
+  [397] = 397,
+  [398] = 398,
+  [399] = 399,
+  [400] = 400,
+  [401] = 401,
+  [402] = 402,
+  [403] = 403,
+  [404] = 404,
+  [405] = 405,
+  [406] = 406,
+  [407] = 407,
+  [408] = 408,
+  [409] = 409,
+  [410] = 410,
+  [411] = 411,
+  [412] = 412,
+  [413] = 413,
+  [414] = 414,
+  [415] = 415,
+  [416] = 416,
+  [417] = 417,
+  [418] = 418,
+  [419] = 419,
+  [420] = 420,
+  [421] = 421,
+  [422] = 422,
+  [423] = 423,
+  [424] = 424,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
+  [429] = 429,
+  [430] = 430,
+  [431] = 431,
+  [432] = 432,
+  [433] = 433,
+  [434] = 434,
+  [435] = 435,
+  [436] = 436,
+  [437] = 437,
+  [438] = 438,
+  [439] = 439,
+  [440] = 440,
+  [441] = 441,
+  [442] = 442,
+  [443] = 443,
+  [444] = 444,
+  [445] = 445,
+  [446] = 446,
+  [447] = 447,
+  [448] = 448,
+  [449] = 449,
+  [450] = 450,
+  [451] = 451,
+  [452] = 452,
+  [453] = 453,
+  [454] = 454,
+  [455] = 455,
+  [456] = 456,
+  [457] = 457,
+  [458] = 458,
+  [459] = 459,
+  [460] = 460,
+  [461] = 461,
+  [462] = 462,
+  [463] = 463,
+  [464] = 464,
+  [465] = 465,
+  [466] = 466,
+  [467] = 467,
+  [468] = 468,
+  [469] = 469,
+  [470] = 470,
+  [471] = 471,
+  [472] = 472,
+  [473] = 473,
+  [474] = 474,
+  [475] = 475,
+  [476] = 476,
+  [477] = 477,
+  [478] = 478,
+  [479] = 479,
+  [480] = 480,
+  [481] = 481,
+  [482] = 482,
+  [483] = 483,
+  [484] = 484,
+  [485] = 485,
+  [486] = 486,
+  [487] = 487,
+  [488] = 488,
+  [489] = 489,
+  [490] = 490,
+  [491] = 491,
+  [492] = 492,
+  [493] = 493,
+  [494] = 494,
+  [495] = 495,
+  [496] = 496,
+  [497] = 497,
+  [498] = 498,
+  [499] = 499,
+  [500] = 500,
+  [501] = 501,
+  [502] = 502,
+  [503] = 503,
+  [504] = 504,
+  [505] = 505,
+  [506] = 506,
+  [507] = 507,
+  [508] = 508,
+  [509] = 509,
+  [510] = 510,
+  [511] = 511,
+  [512] = 512,
+  [513] = 513,
+  [514] = 514,
+  [515] = 515,
+  [516] = 516,
+  [517] = 517,
+  [518] = 518,
+  [519] = 519,
+  [520] = 520,
+  [521] = 521,
+  [522] = 522,
+  [523] = 523,
+  [524] = 524,
+  [525] = 525,
+  [526] = 526,
+  [527] = 527,
+  [528] = 528,
+  [529] = 529,
+  [530] = 530,
+  [531] = 531,
+  [532] = 532,
+  [533] = 533,
+  [534] = 534,
+  [535] = 535,
+  [536] = 536,
+  [537] = 537,
+  [538] = 538,
+  [539] = 539,
+  [540] = 540,
+  [541] = 541,
+  [542] = 542,
+  [543] = 543,
+  [544] = 544,
+  [545] = 545,
+  [546] = 546,
+  [547] = 547,
+  [548] = 548,
+  [549] = 549,
+  [550] = 550,
+  [551] = 551,
+  [552] = 552,
+  [553] = 553,
+  [554] = 554,
+  [555] = 555,
+  [556] = 556,
+  [557] = 557,
+  [558] = 558,
+  [559] = 559,
+  [560] = 560,
+  [561] = 561,
+  [562] = 562,
+  [563] = 563,
+  [564] = 564,
+  [565] = 565,
+  [566] = 566,
+  [567] = 567,
+  [568] = 568,
+  [569] = 569,
+  [570] = 570,
+  [571] = 571,
+  [572] = 572,
+  [573] = 573,
+  [574] = 574,
+  [575] = 575,
+  [576] = 576,
+  [577] = 577,
+  [578] = 578,
+  [579] = 579,
+  [580] = 580,
+  [581] = 581,
+  [582] = 582,
+  [583] = 583,
+  [584] = 584,
+  [585] = 585,
+  [586] = 586,
+  [587] = 587,
+  [588] = 588,
+  [589] = 589,
+  [590] = 590,
+  [591] = 591,
+  [592] = 592,
+  [593] = 593,
+  [594] = 594,
+  [595] = 595,
+  [596] = 596,
+  [597] = 597,
+  [598] = 598,
+  [599] = 599,
+  [600] = 600,
+  [601] = 601,
+  [602] = 602,
+  [603] = 603,
+  [604] = 604,
+  [605] = 605,
+  [606] = 606,
+  [607] = 607,
+  [608] = 608,
+  [609] = 609,
+  [610] = 610,
+  [611] = 611,
+  [612] = 612,
+  [613] = 613,
+  [614] = 614,
+  [615] = 615,
+  [616] = 616,
+  [617] = 617,
+  [618] = 618,
+  [619] = 619,
+  [620] = 620,
+  [621] = 621,
+  [622] = 622,
+  [623] = 623,
+  [624] = 624,
+  [625] = 625,
+  [626] = 626,
+  [627] = 627,
+  [628] = 628,
+  [629] = 629,
+  [630] = 630,
+  [631] = 631,
+  [632] = 632,
+  [633] = 633,
+  [634] = 634,
+  [635] = 635,
+  [636] = 636,
+  [637] = 637,
+  [638] = 638,
+  [639] = 639,
+  [640] = 640,
+  [641] = 641,
+  [642] = 642,
+  [643] = 643,
+  [644] = 644,
+  [645] = 645,
+  [646] = 646,
+  [647] = 647,
+  [648] = 648,
+  [649] = 649,
+  [650] = 650,
+  [651] = 651,
+  [652] = 652,
+  [653] = 653,
+  [654] = 654,
+  [655] = 655,
+  [656] = 656,
+  [657] = 657,
+  [658] = 658,
+  [659] = 659,
+  [660] = 660,
+  [661] = 661,
+  [662] = 662,
+  [663] = 663,
+  [664] = 664,
+  [665] = 665,
+  [666] = 666,
+  [667] = 667,
+  [668] = 668,
+  [669] = 669,
+  [670] = 670,
+  [671] = 671,
+  [672] = 672,
+  [673] = 673,
+  [674] = 674,
+  [675] = 675,
+  [676] = 676,
+  [677] = 677,
+  [678] = 678,
+  [679] = 679,
+  [680] = 680,
+  [681] = 681,
+  [682] = 682,
+  [683] = 683,
+  [684] = 684,
+  [685] = 685,
+  [686] = 686,
+  [687] = 687,
+  [688] = 688,
+  [689] = 689,
+  [690] = 690,
+  [691] = 691,
+  [692] = 692,
+  [693] = 693,
+  [694] = 694,
+  [695] = 695,
+  [696] = 696,
+  [697] = 697,
+  [698] = 698,
+  [699] = 699,
+  [700] = 700,
+  [701] = 701,
+  [702] = 702,
+  [703] = 703,
+  [704] = 704,
+  [705] = 705,
+  [706] = 706,
+  [707] = 707,
+  [708] = 708,
+  [709] = 709,
+  [710] = 710,
+  [711] = 711,
+  [712] = 712,
+  [713] = 713,
+  [714] = 714,
+  [715] = 715,
+  [716] = 716,
+  [717] = 717,
+  [718] = 718,
+  [719] = 719,
+  [720] = 720,
+  [721] = 721,
+  [722] = 722,
+  [723] = 723,
+  [724] = 724,
+  [725] = 725,
+  [726] = 726,
+  [727] = 727,
+  [728] = 728,
+  [729] = 729,
+  [730] = 730,
+  [731] = 731,
+  [732] = 732,
+  [733] = 733,
+  [734] = 734,
+  [735] = 735,
+  [736] = 736,
+  [737] = 737,
+  [738] = 738,
+  [739] = 739,
+  [740] = 740,
+  [741] = 741,
+  [742] = 742,
+  [743] = 743,
+  [744] = 744,
+  [745] = 745,
+  [746] = 746,
+  [747] = 747,
+  [748] = 748,
+  [749] = 749,
+  [750] = 750,
+  [751] = 751,
+  [752] = 752,
+  [753] = 753,
+  [754] = 754,
+  [755] = 755,
+  [756] = 756,
+  [757] = 757,
+  [758] = 758,
+  [759] = 759,
+  [760] = 760,
+  [761] = 761,
+  [762] = 762,
+  [763] = 763,
+  [764] = 764,
+  [765] = 765,
+  [766] = 766,
+  [767] = 767,
+  [768] = 768,
+  [769] = 769,
+  [770] = 770,
+  [771] = 771,
+  [772] = 772,
+  [773] = 773,
+  [774] = 774,
+  [775] = 775,
+  [776] = 776,
+  [777] = 777,
+  [778] = 778,
+  [779] = 779,
+  [780] = 780,
+  [781] = 781,
+  [782] = 782,
+  [783] = 783,
+  [784] = 784,
+  [785] = 785,
+  [786] = 786,
+  [787] = 787,
+  [788] = 788,
+  [789] = 789,
+  [790] = 790,
+  [791] = 791,
+  [792] = 792,
+  [793] = 793,
+  [794] = 794,
+  [795] = 795,
+  [796] = 796,
+  [797] = 797,
+  [798] = 798,
+  [799] = 799,
+  [800] = 800,
+  [801] = 801,
+  [802] = 802,
+  [803] = 803,
+  [804] = 804,
+  [805] = 805,
+  [806] = 806,
+  [807] = 807,
+  [808] = 808,
+  [809] = 809,
+  [810] = 810,
+  [811] = 811,
+  [812] = 812,
+  [813] = 813,
+  [814] = 814,
+  [815] = 815,
+  [816] = 816,
+  [817] = 817,
+  [818] = 818,
+  [819] = 819,
+  [820] = 820,
+  [821] = 821,
+  [822] = 822,
+  [823] = 823,
+  [824] = 824,
+  [825] = 825,
+  [826] = 826,
+  [827] = 827,
+  [828] = 828,
+  [829] = 829,
+  [830] = 830,
+  [831] = 831,
+  [832] = 832,
+  [833] = 833,
+  [834] = 834,
+  [835] = 835,
+  [836] = 836,
+  [837] = 837,
+  [838] = 838,
+  [839] = 839,
+  [840] = 840,
+  [841] = 841,
+  [842] = 842,
+  [843] = 843,
+  [844] = 844,
+  [845] = 845,
+  [846] = 846,
+  [847] = 847,
+  [848] = 848,
+  [849] = 849,
+  [850] = 850,
+  [851] = 851,
+  [852] = 852,
+  [853] = 853,
+  [854] = 854,
+  [855] = 855,
+  [856] = 856,
+  [857] = 857,
+  [858] = 858,
+  [859] = 859,
+  [860] = 860,
+  [861] = 861,
+  [862] = 862,
+  [863] = 863,
+  [864] = 864,
+  [865] = 865,
+  [866] = 866,
+  [867] = 867,
+  [868] = 868,
+  [869] = 869,
+  [870] = 870,
+  [871] = 871,
+  [872] = 872,
+  [873] = 873,
+  [874] = 874,
+  [875] = 875,
+  [876] = 876,
+  [877] = 877,
+  [878] = 878,
+  [879] = 879,
+  [880] = 880,
+  [881] = 881,
+  [882] = 882,
+  [883] = 883,
+  [884] = 884,
+  [885] = 885,
+  [886] = 886,
+  [887] = 887,
+  [888] = 888,
+  [889] = 889,
+  [890] = 890,
+  [891] = 891,
+  [892] = 892,
+  [893] = 893,
+  [894] = 894,
+  [895] = 895,
+  [896] = 896,
+  [897] = 897,
+  [898] = 898,
+  [899] = 899,
+  [900] = 900,
+  [901] = 901,
+  [902] = 902,
+  [903] = 903,
+  [904] = 904,
+  [905] = 905,
+  [906] = 906,
+  [907] = 907,
+  [908] = 908,
+  [909] = 909,
+  [910] = 910,
+  [911] = 911,
+  [912] = 912,
+  [913] = 913,
+  [914] = 914,
+  [915] = 915,
+  [916] = 916,
+  [917] = 917,
+  [918] = 918,
+  [919] = 919,
+  [920] = 920,
+  [921] = 921,
+  [922] = 922,
+  [923] = 923,
+  [924] = 924,
+  [925] = 925,
+  [926] = 926,
+  [927] = 927,
+  [928] = 928,
+  [929] = 929,
+  [930] = 930,
+  [931] = 931,
+  [932] = 932,
+  [933] = 933,
+  [934] = 934,
+  [935] = 935,
+  [936] = 936,
+  [937] = 937,
+  [938] = 938,
+  [939] = 939,
+  [940] = 940,
+  [941] = 941,
+  [942] = 942,
+  [943] = 943,
+  [944] = 944,
+  [945] = 945,
+  [946] = 946,
+  [947] = 947,
+  [948] = 948,
+  [949] = 949,
+  [950] = 950,
+  [951] = 951,
+  [952] = 952,
+  [953] = 953,
+  [954] = 954,
+  [955] = 955,
+  [956] = 956,
+  [957] = 957,
+  [958] = 958,
+  [959] = 959,
+  [960] = 960,
+  [961] = 961,
+  [962] = 962,
+  [963] = 963,
+  [964] = 964,
+  [965] = 965,
+  [966] = 966,
+  [967] = 967,
+  [968] = 968,
+  [969] = 969,
+  [970] = 970,
+  [971] = 971,
+  [972] = 972,
+  [973] = 973,
+  [974] = 974,
+  [975] = 975,
+  [976] = 976,
+  [977] = 977,
+  [978] = 978,
+  [979] = 979,
+  [980] = 980,
+  [981] = 981,
+  [982] = 982,
+  [983] = 983,
+  [984] = 984,
+  [985] = 985,
+  [986] = 986,
+  [987] = 987,
+  [988] = 988,
+  [989] = 989,
+  [990] = 990,
+  [991] = 991,
+  [992] = 992,
+  [993] = 993,
+  [994] = 994,
+  [995] = 995,
+  [996] = 996,
+  [997] = 997,
+  [998] = 998,
+  [999] = 999,
+  [1000] = 1000,
+  [1001] = 1001,
+  [1002] = 1002,
+  [1003] = 1003,
+  [1004] = 1004,
+  [1005] = 1005,
+  [1006] = 1006,
+  [1007] = 1007,
+  [1008] = 1008,
+  [1009] = 1009,
+  [1010] = 1010,
+  [1011] = 1011,
+  [1012] = 1012,
+  [1013] = 1013,
+  [1014] = 1014,
+  [1015] = 1015,
+  [1016] = 1016,
+  [1017] = 1017,
+  [1018] = 1018,
+  [1019] = 1019,
+  [1020] = 1020,
+  [1021] = 1021,
+  [1022] = 1022,
+  [1023] = 1023,
+  [1024] = 1024,
+  [1025] = 1025,
+  [1026] = 1026,
+  [1027] = 1027,
+  [1028] = 1028,
+  [1029] = 1029,
+  [1030] = 1030,
+  [1031] = 1031,
+  [1032] = 1032,
+  [1033] = 1033,
+  [1034] = 1034,
+  [1035] = 1035,
+  [1036] = 1036,
+  [1037] = 1037,
+  [1038] = 1038,
+  [1039] = 1039,
+  [1040] = 1040,
+  [1041] = 1041,
+  [1042] = 1042,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -477,1413 +1971,24804 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(45);
+      if (eof) ADVANCE(74);
       ADVANCE_MAP(
-        '!', 5,
-        '"', 74,
-        '\'', 71,
-        '(', 51,
-        ')', 52,
-        '*', 49,
-        ',', 50,
-        '-', 43,
-        ';', 46,
-        '<', 67,
-        '=', 62,
-        '>', 65,
-        'A', 27,
-        'a', 27,
-        'C', 29,
-        'c', 29,
-        'F', 6,
-        'f', 6,
-        'L', 19,
-        'l', 19,
-        'N', 30,
-        'n', 30,
-        'O', 16,
-        'o', 16,
-        'S', 14,
-        's', 14,
-        'T', 32,
-        't', 32,
-        'W', 18,
-        'w', 18,
+        '!', 75,
+        '"', 76,
+        '%', 77,
+        '\'', 78,
+        '(', 79,
+        ')', 80,
+        '*', 81,
+        '+', 82,
+        ',', 83,
+        '-', 84,
+        '.', 85,
+        '/', 86,
+        ';', 88,
+        '<', 89,
+        '=', 90,
+        '>', 91,
+        'A', 92,
+        'a', 92,
+        'B', 93,
+        'b', 93,
+        'C', 94,
+        'c', 94,
+        'D', 95,
+        'd', 95,
+        'E', 96,
+        'e', 96,
+        'F', 97,
+        'f', 97,
+        'G', 98,
+        'g', 98,
+        'H', 99,
+        'h', 99,
+        'I', 100,
+        'i', 100,
+        'L', 102,
+        'l', 102,
+        'N', 103,
+        'n', 103,
+        'O', 104,
+        'o', 104,
+        'R', 105,
+        'r', 105,
+        'S', 106,
+        's', 106,
+        'T', 107,
+        't', 107,
+        'U', 108,
+        'u', 108,
+        'W', 109,
+        'w', 109,
+        '[', 110,
+        ']', 111,
+        '`', 112,
+        '|', 113,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(0);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(77);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('J' <= lookahead && lookahead <= 'K') ||
+          lookahead == 'M' ||
+          ('P' <= lookahead && lookahead <= 'Q') ||
+          lookahead == 'V' ||
+          ('X' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('j' <= lookahead && lookahead <= 'k') ||
+          lookahead == 'm' ||
+          ('p' <= lookahead && lookahead <= 'q') ||
+          lookahead == 'v' ||
+          ('x' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       END_STATE();
     case 1:
-      ADVANCE_MAP(
-        '"', 74,
-        '\'', 71,
-        '(', 51,
-        '-', 43,
-        'F', 83,
-        'f', 83,
-        'N', 90,
-        'n', 90,
-        'T', 92,
-        't', 92,
-      );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(1);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(77);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(114);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(115);
       END_STATE();
     case 2:
-      ADVANCE_MAP(
-        '"', 74,
-        '\'', 71,
-        '(', 51,
-        '-', 43,
-        'F', 83,
-        'f', 83,
-        'N', 98,
-        'n', 98,
-        'T', 92,
-        't', 92,
-      );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(2);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(77);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(116);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(115);
       END_STATE();
     case 3:
-      if (lookahead == '"') ADVANCE(74);
-      if (lookahead == '\'') ADVANCE(71);
-      if (lookahead == '*') ADVANCE(49);
+      ADVANCE_MAP(
+        '"', 76,
+        '\'', 78,
+        '(', 79,
+        '*', 81,
+        '-', 84,
+        'C', 94,
+        'c', 94,
+        'D', 117,
+        'd', 117,
+        'F', 118,
+        'f', 118,
+        'N', 119,
+        'n', 119,
+        'T', 120,
+        't', 120,
+        '[', 110,
+        '`', 112,
+      );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(3);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'B') ||
+          lookahead == 'E' ||
+          ('G' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'b') ||
+          lookahead == 'e' ||
+          ('g' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       END_STATE();
     case 4:
-      if (lookahead == '(') ADVANCE(51);
-      if (lookahead == '*') ADVANCE(49);
-      if (lookahead == 'C' ||
-          lookahead == 'c') ADVANCE(91);
+      if (eof) ADVANCE(74);
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(4);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
       END_STATE();
     case 5:
-      if (lookahead == '=') ADVANCE(63);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(5);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(115);
       END_STATE();
     case 6:
-      if (lookahead == 'A' ||
-          lookahead == 'a') ADVANCE(21);
-      if (lookahead == 'R' ||
-          lookahead == 'r') ADVANCE(31);
+      if (eof) ADVANCE(74);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(6);
+      if (lookahead == ';') ADVANCE(88);
       END_STATE();
     case 7:
-      if (lookahead == 'C' ||
-          lookahead == 'c') ADVANCE(40);
+      if (eof) ADVANCE(74);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(7);
+      if (lookahead == ';') ADVANCE(88);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(121);
       END_STATE();
     case 8:
-      if (lookahead == 'D' ||
-          lookahead == 'd') ADVANCE(59);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(8);
+      if (lookahead == ',') ADVANCE(83);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(122);
       END_STATE();
     case 9:
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(7);
+      ADVANCE_MAP(
+        '"', 76,
+        '\'', 78,
+        '(', 79,
+        '-', 84,
+        'C', 94,
+        'c', 94,
+        'D', 117,
+        'd', 117,
+        'F', 118,
+        'f', 118,
+        'N', 119,
+        'n', 119,
+        'T', 120,
+        't', 120,
+        '[', 110,
+        '`', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(9);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'B') ||
+          lookahead == 'E' ||
+          ('G' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'b') ||
+          lookahead == 'e' ||
+          ('g' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       END_STATE();
     case 10:
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(79);
+      ACCEPT_TOKEN(aux_sym_column_name_token1);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(10);
       END_STATE();
     case 11:
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(81);
+      ACCEPT_TOKEN(aux_sym_column_name_token2);
+      if (lookahead != 0 &&
+          lookahead != ']') ADVANCE(11);
       END_STATE();
     case 12:
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(55);
+      ACCEPT_TOKEN(aux_sym_column_name_token3);
+      if (lookahead != 0 &&
+          lookahead != '`') ADVANCE(12);
       END_STATE();
     case 13:
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(33);
+      ADVANCE_MAP(
+        '"', 76,
+        '\'', 78,
+        '(', 79,
+        '-', 84,
+        'C', 94,
+        'c', 94,
+        'D', 117,
+        'd', 117,
+        'F', 118,
+        'f', 118,
+        'N', 123,
+        'n', 123,
+        'T', 120,
+        't', 120,
+        '[', 110,
+        '`', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(13);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'B') ||
+          lookahead == 'E' ||
+          ('G' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'b') ||
+          lookahead == 'e' ||
+          ('g' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       END_STATE();
     case 14:
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(23);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(14);
+      if (lookahead == '(') ADVANCE(79);
       END_STATE();
     case 15:
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(39);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(15);
+      if (lookahead == 'W' ||
+          lookahead == 'w') ADVANCE(124);
       END_STATE();
     case 16:
-      if (lookahead == 'F' ||
-          lookahead == 'f') ADVANCE(17);
-      if (lookahead == 'R' ||
-          lookahead == 'r') ADVANCE(58);
+      ADVANCE_MAP(
+        '!', 75,
+        '%', 77,
+        ')', 80,
+        '*', 81,
+        '+', 82,
+        ',', 83,
+        '-', 125,
+        '/', 86,
+        '<', 89,
+        '=', 90,
+        '>', 91,
+        'A', 126,
+        'a', 126,
+        'E', 127,
+        'e', 127,
+        'F', 122,
+        'f', 122,
+        'I', 128,
+        'i', 128,
+        'L', 129,
+        'l', 129,
+        'N', 130,
+        'n', 130,
+        'O', 131,
+        'o', 131,
+        'T', 132,
+        't', 132,
+        'W', 124,
+        'w', 124,
+        '|', 113,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(16);
       END_STATE();
     case 17:
-      if (lookahead == 'F' ||
-          lookahead == 'f') ADVANCE(35);
+      ACCEPT_TOKEN(aux_sym_string_literal_token1);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(17);
       END_STATE();
     case 18:
-      if (lookahead == 'H' ||
-          lookahead == 'h') ADVANCE(13);
+      ADVANCE_MAP(
+        '!', 75,
+        '%', 77,
+        '(', 79,
+        ')', 80,
+        '*', 81,
+        '+', 82,
+        ',', 83,
+        '-', 125,
+        '.', 85,
+        '/', 86,
+        '<', 89,
+        '=', 90,
+        '>', 91,
+        'A', 126,
+        'a', 126,
+        'E', 127,
+        'e', 127,
+        'F', 122,
+        'f', 122,
+        'I', 128,
+        'i', 128,
+        'L', 129,
+        'l', 129,
+        'N', 130,
+        'n', 130,
+        'O', 131,
+        'o', 131,
+        'T', 132,
+        't', 132,
+        'W', 124,
+        'w', 124,
+        '|', 113,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(18);
       END_STATE();
     case 19:
-      if (lookahead == 'I' ||
-          lookahead == 'i') ADVANCE(26);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(19);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(122);
       END_STATE();
     case 20:
-      if (lookahead == 'I' ||
-          lookahead == 'i') ADVANCE(38);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(20);
+      if (lookahead == '.') ADVANCE(85);
       END_STATE();
     case 21:
-      if (lookahead == 'L' ||
-          lookahead == 'l') ADVANCE(34);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(21);
+      if (lookahead == ',') ADVANCE(83);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(133);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(122);
       END_STATE();
     case 22:
-      if (lookahead == 'L' ||
-          lookahead == 'l') ADVANCE(69);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ')', 80,
+        ',', 83,
+        ';', 88,
+        'A', 133,
+        'a', 133,
+        'E', 134,
+        'e', 134,
+        'F', 135,
+        'f', 135,
+        'G', 136,
+        'g', 136,
+        'H', 137,
+        'h', 137,
+        'L', 138,
+        'l', 138,
+        'O', 139,
+        'o', 139,
+        'T', 132,
+        't', 132,
+        'U', 121,
+        'u', 121,
+        'W', 124,
+        'w', 124,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(22);
       END_STATE();
     case 23:
-      if (lookahead == 'L' ||
-          lookahead == 'l') ADVANCE(9);
+      ADVANCE_MAP(
+        ')', 80,
+        ',', 83,
+        'A', 133,
+        'a', 133,
+        'E', 134,
+        'e', 134,
+        'F', 122,
+        'f', 122,
+        'O', 131,
+        'o', 131,
+        'T', 132,
+        't', 132,
+        'W', 124,
+        'w', 124,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(23);
       END_STATE();
     case 24:
-      if (lookahead == 'L' ||
-          lookahead == 'l') ADVANCE(22);
+      ADVANCE_MAP(
+        ')', 80,
+        ',', 83,
+        'A', 126,
+        'a', 126,
+        'E', 134,
+        'e', 134,
+        'F', 122,
+        'f', 122,
+        'O', 131,
+        'o', 131,
+        'T', 132,
+        't', 132,
+        'W', 124,
+        'w', 124,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(24);
       END_STATE();
     case 25:
-      if (lookahead == 'M' ||
-          lookahead == 'm') ADVANCE(48);
+      ADVANCE_MAP(
+        '!', 75,
+        '%', 77,
+        ')', 80,
+        '*', 81,
+        '+', 82,
+        ',', 83,
+        '-', 125,
+        '/', 86,
+        '<', 89,
+        '=', 90,
+        '>', 91,
+        'A', 126,
+        'a', 126,
+        'E', 134,
+        'e', 134,
+        'F', 122,
+        'f', 122,
+        'I', 128,
+        'i', 128,
+        'L', 129,
+        'l', 129,
+        'N', 130,
+        'n', 130,
+        'O', 131,
+        'o', 131,
+        'T', 132,
+        't', 132,
+        'W', 124,
+        'w', 124,
+        '|', 113,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(25);
       END_STATE();
     case 26:
-      if (lookahead == 'M' ||
-          lookahead == 'm') ADVANCE(20);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(26);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(140);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(115);
       END_STATE();
     case 27:
-      if (lookahead == 'N' ||
-          lookahead == 'n') ADVANCE(8);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(27);
+      if (lookahead == ')') ADVANCE(80);
       END_STATE();
     case 28:
-      if (lookahead == 'N' ||
-          lookahead == 'n') ADVANCE(37);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(28);
+      if (lookahead == '"') ADVANCE(76);
       END_STATE();
     case 29:
-      if (lookahead == 'O' ||
-          lookahead == 'o') ADVANCE(41);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(29);
+      if (lookahead == ']') ADVANCE(111);
       END_STATE();
     case 30:
-      if (lookahead == 'O' ||
-          lookahead == 'o') ADVANCE(36);
-      if (lookahead == 'U' ||
-          lookahead == 'u') ADVANCE(24);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(30);
+      if (lookahead == '`') ADVANCE(112);
       END_STATE();
     case 31:
-      if (lookahead == 'O' ||
-          lookahead == 'o') ADVANCE(25);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(31);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(134);
+      if (lookahead == 'W' ||
+          lookahead == 'w') ADVANCE(124);
       END_STATE();
     case 32:
-      if (lookahead == 'R' ||
-          lookahead == 'r') ADVANCE(42);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(32);
+      if (lookahead == '\'') ADVANCE(78);
       END_STATE();
     case 33:
-      if (lookahead == 'R' ||
-          lookahead == 'r') ADVANCE(12);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(33);
+      if (lookahead == '"') ADVANCE(76);
+      if (lookahead == '\'') ADVANCE(78);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       END_STATE();
     case 34:
-      if (lookahead == 'S' ||
-          lookahead == 's') ADVANCE(11);
+      ADVANCE_MAP(
+        '"', 76,
+        '\'', 78,
+        '(', 79,
+        ')', 80,
+        '*', 81,
+        '-', 84,
+        'C', 94,
+        'c', 94,
+        'D', 117,
+        'd', 117,
+        'F', 118,
+        'f', 118,
+        'N', 119,
+        'n', 119,
+        'T', 120,
+        't', 120,
+        '[', 110,
+        '`', 112,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(34);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'B') ||
+          lookahead == 'E' ||
+          ('G' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'b') ||
+          lookahead == 'e' ||
+          ('g' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       END_STATE();
     case 35:
-      if (lookahead == 'S' ||
-          lookahead == 's') ADVANCE(15);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(35);
+      if (lookahead == '"') ADVANCE(76);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == '[') ADVANCE(110);
+      if (lookahead == '`') ADVANCE(112);
       END_STATE();
     case 36:
-      if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(60);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(36);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       END_STATE();
     case 37:
-      if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(53);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(37);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(141);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(129);
       END_STATE();
     case 38:
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(38);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(142);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(130);
       if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(56);
+          lookahead == 't') ADVANCE(143);
       END_STATE();
     case 39:
-      if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(57);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(39);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(133);
       END_STATE();
     case 40:
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(40);
       if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(47);
+          lookahead == 't') ADVANCE(132);
       END_STATE();
     case 41:
-      if (lookahead == 'U' ||
-          lookahead == 'u') ADVANCE(28);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(41);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(144);
       END_STATE();
     case 42:
-      if (lookahead == 'U' ||
-          lookahead == 'u') ADVANCE(10);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ',', 83,
+        ';', 88,
+        'F', 145,
+        'f', 145,
+        'G', 136,
+        'g', 136,
+        'H', 137,
+        'h', 137,
+        'L', 138,
+        'l', 138,
+        'O', 139,
+        'o', 139,
+        'S', 146,
+        's', 146,
+        'U', 121,
+        'u', 121,
+        'W', 147,
+        'w', 147,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(42);
       END_STATE();
     case 43:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(77);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(43);
+      if (lookahead == ')') ADVANCE(80);
+      if (lookahead == ',') ADVANCE(83);
       END_STATE();
     case 44:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(78);
+      ADVANCE_MAP(
+        '\'', 78,
+        '-', 148,
+        'D', 149,
+        'd', 149,
+        'F', 142,
+        'f', 142,
+        'N', 150,
+        'n', 150,
+        'T', 151,
+        't', 151,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(44);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
       END_STATE();
     case 45:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(45);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(142);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(143);
       END_STATE();
     case 46:
-      ACCEPT_TOKEN(anon_sym_SEMI);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(46);
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(152);
       END_STATE();
     case 47:
-      ACCEPT_TOKEN(aux_sym_select_statement_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(47);
+      if (lookahead == '-') ADVANCE(148);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
       END_STATE();
     case 48:
-      ACCEPT_TOKEN(aux_sym_select_statement_token2);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(48);
+      if (lookahead == '-') ADVANCE(148);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(153);
       END_STATE();
     case 49:
-      ACCEPT_TOKEN(anon_sym_STAR);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(49);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(154);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(155);
       END_STATE();
     case 50:
-      ACCEPT_TOKEN(anon_sym_COMMA);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ';', 88,
+        'F', 145,
+        'f', 145,
+        'G', 136,
+        'g', 136,
+        'H', 137,
+        'h', 137,
+        'L', 138,
+        'l', 138,
+        'O', 139,
+        'o', 139,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(50);
       END_STATE();
     case 51:
-      ACCEPT_TOKEN(anon_sym_LPAREN);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ';', 88,
+        'F', 145,
+        'f', 145,
+        'H', 137,
+        'h', 137,
+        'L', 138,
+        'l', 138,
+        'O', 139,
+        'o', 139,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(51);
       END_STATE();
     case 52:
-      ACCEPT_TOKEN(anon_sym_RPAREN);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ';', 88,
+        'F', 145,
+        'f', 145,
+        'L', 138,
+        'l', 138,
+        'O', 139,
+        'o', 139,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(52);
       END_STATE();
     case 53:
-      ACCEPT_TOKEN(aux_sym_aggregate_function_token1);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ';', 88,
+        'F', 145,
+        'f', 145,
+        'L', 138,
+        'l', 138,
+        'O', 156,
+        'o', 156,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(53);
       END_STATE();
     case 54:
-      ACCEPT_TOKEN(aux_sym_aggregate_function_token1);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ';', 88,
+        'F', 145,
+        'f', 145,
+        'G', 136,
+        'g', 136,
+        'H', 137,
+        'h', 137,
+        'L', 138,
+        'l', 138,
+        'O', 139,
+        'o', 139,
+        'U', 121,
+        'u', 121,
+        'W', 147,
+        'w', 147,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(54);
       END_STATE();
     case 55:
-      ACCEPT_TOKEN(aux_sym_where_clause_token1);
+      if (eof) ADVANCE(74);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(55);
+      if (lookahead == ';') ADVANCE(88);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(145);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(156);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(121);
       END_STATE();
     case 56:
-      ACCEPT_TOKEN(aux_sym_limit_clause_token1);
+      if (eof) ADVANCE(74);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(56);
+      if (lookahead == ';') ADVANCE(88);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(145);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(121);
       END_STATE();
     case 57:
-      ACCEPT_TOKEN(aux_sym_offset_clause_token1);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        '!', 75,
+        '%', 77,
+        '*', 81,
+        '+', 82,
+        '-', 125,
+        '/', 86,
+        ';', 88,
+        '<', 89,
+        '=', 90,
+        '>', 91,
+        'A', 157,
+        'a', 157,
+        'E', 158,
+        'e', 158,
+        'F', 145,
+        'f', 145,
+        'G', 136,
+        'g', 136,
+        'H', 137,
+        'h', 137,
+        'I', 128,
+        'i', 128,
+        'L', 159,
+        'l', 159,
+        'N', 130,
+        'n', 130,
+        'O', 160,
+        'o', 160,
+        'U', 121,
+        'u', 121,
+        '|', 113,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(57);
       END_STATE();
     case 58:
-      ACCEPT_TOKEN(aux_sym_or_expression_token1);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        '!', 75,
+        '%', 77,
+        '(', 79,
+        '*', 81,
+        '+', 82,
+        '-', 125,
+        '.', 85,
+        '/', 86,
+        ';', 88,
+        '<', 89,
+        '=', 90,
+        '>', 91,
+        'A', 157,
+        'a', 157,
+        'E', 158,
+        'e', 158,
+        'F', 145,
+        'f', 145,
+        'G', 136,
+        'g', 136,
+        'H', 137,
+        'h', 137,
+        'I', 128,
+        'i', 128,
+        'L', 159,
+        'l', 159,
+        'N', 130,
+        'n', 130,
+        'O', 160,
+        'o', 160,
+        'U', 121,
+        'u', 121,
+        '|', 113,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(58);
       END_STATE();
     case 59:
-      ACCEPT_TOKEN(aux_sym_and_expression_token1);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ';', 88,
+        'F', 145,
+        'f', 145,
+        'G', 136,
+        'g', 136,
+        'H', 137,
+        'h', 137,
+        'L', 138,
+        'l', 138,
+        'O', 160,
+        'o', 160,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(59);
       END_STATE();
     case 60:
-      ACCEPT_TOKEN(aux_sym_not_expression_token1);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ';', 88,
+        'A', 157,
+        'a', 157,
+        'F', 145,
+        'f', 145,
+        'G', 136,
+        'g', 136,
+        'H', 137,
+        'h', 137,
+        'L', 138,
+        'l', 138,
+        'O', 160,
+        'o', 160,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(60);
       END_STATE();
     case 61:
-      ACCEPT_TOKEN(aux_sym_not_expression_token1);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        '!', 75,
+        '%', 77,
+        '*', 81,
+        '+', 82,
+        '-', 125,
+        '/', 86,
+        ';', 88,
+        '<', 89,
+        '=', 90,
+        '>', 91,
+        'A', 157,
+        'a', 157,
+        'F', 145,
+        'f', 145,
+        'G', 136,
+        'g', 136,
+        'H', 137,
+        'h', 137,
+        'I', 128,
+        'i', 128,
+        'L', 159,
+        'l', 159,
+        'N', 130,
+        'n', 130,
+        'O', 160,
+        'o', 160,
+        'U', 121,
+        'u', 121,
+        '|', 113,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(61);
       END_STATE();
     case 62:
-      ACCEPT_TOKEN(anon_sym_EQ);
+      if (eof) ADVANCE(74);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(62);
+      if (lookahead == ';') ADVANCE(88);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(145);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(161);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(121);
       END_STATE();
     case 63:
-      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ',', 83,
+        '.', 85,
+        ';', 88,
+        'F', 145,
+        'f', 145,
+        'H', 137,
+        'h', 137,
+        'L', 138,
+        'l', 138,
+        'O', 139,
+        'o', 139,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(63);
       END_STATE();
     case 64:
-      ACCEPT_TOKEN(anon_sym_LT_GT);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ',', 83,
+        ';', 88,
+        'F', 145,
+        'f', 145,
+        'H', 137,
+        'h', 137,
+        'L', 138,
+        'l', 138,
+        'O', 139,
+        'o', 139,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(64);
       END_STATE();
     case 65:
-      ACCEPT_TOKEN(anon_sym_GT);
-      if (lookahead == '=') ADVANCE(66);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        '!', 75,
+        '%', 77,
+        '*', 81,
+        '+', 82,
+        ',', 83,
+        '-', 125,
+        '/', 86,
+        ';', 88,
+        '<', 89,
+        '=', 90,
+        '>', 91,
+        'A', 162,
+        'a', 162,
+        'D', 163,
+        'd', 163,
+        'E', 158,
+        'e', 158,
+        'F', 145,
+        'f', 145,
+        'I', 128,
+        'i', 128,
+        'L', 159,
+        'l', 159,
+        'N', 130,
+        'n', 130,
+        'O', 164,
+        'o', 164,
+        'U', 121,
+        'u', 121,
+        '|', 113,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(65);
       END_STATE();
     case 66:
-      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        '!', 75,
+        '%', 77,
+        '(', 79,
+        '*', 81,
+        '+', 82,
+        ',', 83,
+        '-', 125,
+        '.', 85,
+        '/', 86,
+        ';', 88,
+        '<', 89,
+        '=', 90,
+        '>', 91,
+        'A', 162,
+        'a', 162,
+        'D', 163,
+        'd', 163,
+        'E', 158,
+        'e', 158,
+        'F', 145,
+        'f', 145,
+        'I', 128,
+        'i', 128,
+        'L', 159,
+        'l', 159,
+        'N', 130,
+        'n', 130,
+        'O', 164,
+        'o', 164,
+        'U', 121,
+        'u', 121,
+        '|', 113,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(66);
       END_STATE();
     case 67:
-      ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '=') ADVANCE(68);
-      if (lookahead == '>') ADVANCE(64);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ',', 83,
+        ';', 88,
+        'F', 145,
+        'f', 145,
+        'L', 138,
+        'l', 138,
+        'O', 156,
+        'o', 156,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(67);
       END_STATE();
     case 68:
-      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ',', 83,
+        ';', 88,
+        'A', 165,
+        'a', 165,
+        'D', 163,
+        'd', 163,
+        'F', 145,
+        'f', 145,
+        'L', 138,
+        'l', 138,
+        'O', 156,
+        'o', 156,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(68);
       END_STATE();
     case 69:
-      ACCEPT_TOKEN(aux_sym_literal_token1);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ',', 83,
+        ';', 88,
+        'A', 165,
+        'a', 165,
+        'D', 163,
+        'd', 163,
+        'F', 145,
+        'f', 145,
+        'L', 138,
+        'l', 138,
+        'O', 164,
+        'o', 164,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(69);
       END_STATE();
     case 70:
-      ACCEPT_TOKEN(aux_sym_literal_token1);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        ',', 83,
+        ';', 88,
+        'A', 162,
+        'a', 162,
+        'D', 163,
+        'd', 163,
+        'F', 145,
+        'f', 145,
+        'L', 138,
+        'l', 138,
+        'O', 164,
+        'o', 164,
+        'U', 121,
+        'u', 121,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(70);
       END_STATE();
     case 71:
-      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        '!', 75,
+        '%', 77,
+        '*', 81,
+        '+', 82,
+        ',', 83,
+        '-', 125,
+        '/', 86,
+        ';', 88,
+        '<', 89,
+        '=', 90,
+        '>', 91,
+        'A', 162,
+        'a', 162,
+        'D', 163,
+        'd', 163,
+        'F', 145,
+        'f', 145,
+        'I', 128,
+        'i', 128,
+        'L', 159,
+        'l', 159,
+        'N', 130,
+        'n', 130,
+        'O', 164,
+        'o', 164,
+        'U', 121,
+        'u', 121,
+        '|', 113,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(71);
       END_STATE();
     case 72:
-      ACCEPT_TOKEN(aux_sym_string_literal_token1);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(72);
-      if (lookahead != 0 &&
-          lookahead != '\'') ADVANCE(73);
+          lookahead == ' ') SKIP(72);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(161);
       END_STATE();
     case 73:
-      ACCEPT_TOKEN(aux_sym_string_literal_token1);
-      if (lookahead != 0 &&
-          lookahead != '\'') ADVANCE(73);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(73);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(166);
       END_STATE();
     case 74:
-      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      ACCEPT_TOKEN(ts_builtin_sym_end);
       END_STATE();
     case 75:
-      ACCEPT_TOKEN(aux_sym_string_literal_token2);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(75);
-      if (lookahead != 0 &&
-          lookahead != '"') ADVANCE(76);
+      if (lookahead == '=') ADVANCE(167);
       END_STATE();
     case 76:
-      ACCEPT_TOKEN(aux_sym_string_literal_token2);
-      if (lookahead != 0 &&
-          lookahead != '"') ADVANCE(76);
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
       END_STATE();
     case 77:
-      ACCEPT_TOKEN(sym_number_literal);
-      if (lookahead == '.') ADVANCE(44);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(77);
+      ACCEPT_TOKEN(anon_sym_PERCENT);
       END_STATE();
     case 78:
-      ACCEPT_TOKEN(sym_number_literal);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(78);
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
       END_STATE();
     case 79:
-      ACCEPT_TOKEN(aux_sym_boolean_literal_token1);
+      ACCEPT_TOKEN(anon_sym_LPAREN);
       END_STATE();
     case 80:
-      ACCEPT_TOKEN(aux_sym_boolean_literal_token1);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      ACCEPT_TOKEN(anon_sym_RPAREN);
       END_STATE();
     case 81:
-      ACCEPT_TOKEN(aux_sym_boolean_literal_token2);
+      ACCEPT_TOKEN(anon_sym_STAR);
       END_STATE();
     case 82:
-      ACCEPT_TOKEN(aux_sym_boolean_literal_token2);
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      END_STATE();
+    case 83:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 84:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      END_STATE();
+    case 85:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 86:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(sym_number_literal);
+      if (lookahead == '.') ADVANCE(168);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(169);
+      END_STATE();
+    case 88:
+      ACCEPT_TOKEN(anon_sym_SEMI);
+      END_STATE();
+    case 89:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '=') ADVANCE(170);
+      if (lookahead == '>') ADVANCE(171);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(172);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(sym__identifier);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          lookahead == 'M' ||
+          ('O' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'k') ||
+          lookahead == 'm' ||
+          ('o' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(173);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(174);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(175);
       END_STATE();
-    case 83:
+    case 93:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'X') ||
+          lookahead == 'Z' ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'x') ||
+          lookahead == 'z') ADVANCE(101);
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(176);
+      END_STATE();
+    case 94:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'A' ||
-          lookahead == 'a') ADVANCE(86);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('B' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(177);
       END_STATE();
-    case 84:
+    case 95:
       ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('B' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(178);
       if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(80);
+          lookahead == 'e') ADVANCE(179);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(sym__identifier);
+      ADVANCE_MAP(
+        'L', 180,
+        'l', 180,
+        'N', 181,
+        'n', 181,
+        'S', 182,
+        's', 182,
+        'X', 183,
+        'x', 183,
+      );
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          lookahead == 'M' ||
+          ('O' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'W') ||
+          ('Y' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'k') ||
+          lookahead == 'm' ||
+          ('o' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'w') ||
+          ('y' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       END_STATE();
-    case 85:
+    case 97:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(82);
+      ADVANCE_MAP(
+        'A', 184,
+        'a', 184,
+        'E', 185,
+        'e', 185,
+        'I', 186,
+        'i', 186,
+        'R', 187,
+        'r', 187,
+      );
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('B' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'H') ||
+          ('J' <= lookahead && lookahead <= 'Q') ||
+          ('S' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('b' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'h') ||
+          ('j' <= lookahead && lookahead <= 'q') ||
+          ('s' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       END_STATE();
-    case 86:
+    case 98:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'L' ||
-          lookahead == 'l') ADVANCE(93);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'Q') ||
+          ('S' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'q') ||
+          ('s' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(188);
       END_STATE();
-    case 87:
+    case 99:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'L' ||
-          lookahead == 'l') ADVANCE(70);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('B' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(189);
       END_STATE();
-    case 88:
+    case 100:
       ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          lookahead == 'M' ||
+          ('O' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          lookahead == 'm' ||
+          ('o' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       if (lookahead == 'L' ||
-          lookahead == 'l') ADVANCE(87);
+          lookahead == 'l') ADVANCE(190);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(191);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(192);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(sym__identifier);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
       END_STATE();
-    case 89:
+    case 102:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'N' ||
-          lookahead == 'n') ADVANCE(95);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'H') ||
+          ('J' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'h') ||
+          ('j' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(193);
       END_STATE();
-    case 90:
+    case 103:
       ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'N') ||
+          ('P' <= lookahead && lookahead <= 'T') ||
+          ('V' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'n') ||
+          ('p' <= lookahead && lookahead <= 't') ||
+          ('v' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(194);
       if (lookahead == 'O' ||
-          lookahead == 'o') ADVANCE(94);
+          lookahead == 'o') ADVANCE(195);
       if (lookahead == 'U' ||
-          lookahead == 'u') ADVANCE(88);
+          lookahead == 'u') ADVANCE(196);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(sym__identifier);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'E') ||
+          ('G' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'Q') ||
+          ('S' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 'q') ||
+          ('s' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(197);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(198);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(199);
       END_STATE();
-    case 91:
+    case 105:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'O' ||
-          lookahead == 'o') ADVANCE(97);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'N') ||
+          ('P' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'n') ||
+          ('p' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(200);
       END_STATE();
-    case 92:
+    case 106:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'R' ||
-          lookahead == 'r') ADVANCE(96);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('B' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('b' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(201);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(202);
       END_STATE();
-    case 93:
+    case 107:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'S' ||
-          lookahead == 's') ADVANCE(85);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'G') ||
+          ('J' <= lookahead && lookahead <= 'Q') ||
+          ('S' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'g') ||
+          ('j' <= lookahead && lookahead <= 'q') ||
+          ('s' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(203);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(204);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(205);
       END_STATE();
-    case 94:
+    case 108:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(61);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(206);
       END_STATE();
-    case 95:
+    case 109:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(54);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'G') ||
+          ('I' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'g') ||
+          ('i' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(207);
       END_STATE();
-    case 96:
+    case 110:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 111:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 112:
+      ACCEPT_TOKEN(anon_sym_BQUOTE);
+      END_STATE();
+    case 113:
+      if (lookahead == '|') ADVANCE(208);
+      END_STATE();
+    case 114:
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(209);
+      END_STATE();
+    case 115:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(210);
+      END_STATE();
+    case 116:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(211);
+      END_STATE();
+    case 117:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'U' ||
-          lookahead == 'u') ADVANCE(84);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('B' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(178);
       END_STATE();
-    case 97:
+    case 118:
       ACCEPT_TOKEN(sym__identifier);
-      if (lookahead == 'U' ||
-          lookahead == 'u') ADVANCE(89);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('B' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(184);
       END_STATE();
-    case 98:
+    case 119:
       ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'N') ||
+          ('P' <= lookahead && lookahead <= 'T') ||
+          ('V' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'n') ||
+          ('p' <= lookahead && lookahead <= 't') ||
+          ('v' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(195);
       if (lookahead == 'U' ||
-          lookahead == 'u') ADVANCE(88);
+          lookahead == 'u') ADVANCE(196);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(sym__identifier);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'H') ||
+          ('J' <= lookahead && lookahead <= 'Q') ||
+          ('S' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 'h') ||
+          ('j' <= lookahead && lookahead <= 'q') ||
+          ('s' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(204);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(205);
       END_STATE();
-    case 99:
+    case 121:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(212);
+      END_STATE();
+    case 122:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(213);
+      END_STATE();
+    case 123:
       ACCEPT_TOKEN(sym__identifier);
       if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('A' <= lookahead && lookahead <= 'T') ||
+          ('V' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+          ('a' <= lookahead && lookahead <= 't') ||
+          ('v' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(196);
       END_STATE();
-    default:
-      return false;
-  }
-}
-
-static const TSLexMode ts_lex_modes[STATE_COUNT] = {
-  [0] = {.lex_state = 0},
-  [1] = {.lex_state = 0},
-  [2] = {.lex_state = 1},
-  [3] = {.lex_state = 1},
-  [4] = {.lex_state = 1},
-  [5] = {.lex_state = 1},
-  [6] = {.lex_state = 1},
-  [7] = {.lex_state = 0},
-  [8] = {.lex_state = 0},
-  [9] = {.lex_state = 0},
-  [10] = {.lex_state = 0},
-  [11] = {.lex_state = 0},
-  [12] = {.lex_state = 0},
-  [13] = {.lex_state = 2},
-  [14] = {.lex_state = 0},
-  [15] = {.lex_state = 0},
-  [16] = {.lex_state = 4},
-  [17] = {.lex_state = 0},
-  [18] = {.lex_state = 0},
-  [19] = {.lex_state = 0},
-  [20] = {.lex_state = 0},
-  [21] = {.lex_state = 4},
-  [22] = {.lex_state = 0},
-  [23] = {.lex_state = 0},
-  [24] = {.lex_state = 0},
-  [25] = {.lex_state = 0},
-  [26] = {.lex_state = 0},
-  [27] = {.lex_state = 3},
-  [28] = {.lex_state = 0},
-  [29] = {.lex_state = 0},
-  [30] = {.lex_state = 0},
-  [31] = {.lex_state = 3},
-  [32] = {.lex_state = 0},
-  [33] = {.lex_state = 0},
-  [34] = {.lex_state = 0},
-  [35] = {.lex_state = 0},
-  [36] = {.lex_state = 0},
-  [37] = {.lex_state = 0},
-  [38] = {.lex_state = 0},
-  [39] = {.lex_state = 0},
-  [40] = {.lex_state = 0},
-  [41] = {.lex_state = 0},
-  [42] = {.lex_state = 0},
-  [43] = {.lex_state = 0},
-  [44] = {.lex_state = 0},
-  [45] = {.lex_state = 3},
-  [46] = {.lex_state = 72},
-  [47] = {.lex_state = 0},
-  [48] = {.lex_state = 0},
-  [49] = {.lex_state = 0},
-  [50] = {.lex_state = 75},
-  [51] = {.lex_state = 0},
-  [52] = {.lex_state = 0},
-  [53] = {.lex_state = 0},
-  [54] = {.lex_state = 0},
-  [55] = {.lex_state = 0},
-  [56] = {.lex_state = 0},
-  [57] = {.lex_state = 0},
-  [58] = {.lex_state = 0},
-  [59] = {.lex_state = 0},
-};
-
-static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
-  [STATE(0)] = {
-    [ts_builtin_sym_end] = ACTIONS(1),
-    [anon_sym_SEMI] = ACTIONS(1),
-    [aux_sym_select_statement_token1] = ACTIONS(1),
-    [aux_sym_select_statement_token2] = ACTIONS(1),
-    [anon_sym_STAR] = ACTIONS(1),
-    [anon_sym_COMMA] = ACTIONS(1),
-    [anon_sym_LPAREN] = ACTIONS(1),
-    [anon_sym_RPAREN] = ACTIONS(1),
-    [aux_sym_aggregate_function_token1] = ACTIONS(1),
-    [aux_sym_where_clause_token1] = ACTIONS(1),
-    [aux_sym_limit_clause_token1] = ACTIONS(1),
-    [aux_sym_offset_clause_token1] = ACTIONS(1),
-    [aux_sym_or_expression_token1] = ACTIONS(1),
-    [aux_sym_and_expression_token1] = ACTIONS(1),
-    [aux_sym_not_expression_token1] = ACTIONS(1),
-    [anon_sym_EQ] = ACTIONS(1),
-    [anon_sym_BANG_EQ] = ACTIONS(1),
-    [anon_sym_LT_GT] = ACTIONS(1),
-    [anon_sym_GT] = ACTIONS(1),
-    [anon_sym_GT_EQ] = ACTIONS(1),
-    [anon_sym_LT] = ACTIONS(1),
-    [anon_sym_LT_EQ] = ACTIONS(1),
-    [aux_sym_literal_token1] = ACTIONS(1),
-    [anon_sym_SQUOTE] = ACTIONS(1),
-    [anon_sym_DQUOTE] = ACTIONS(1),
-    [sym_number_literal] = ACTIONS(1),
-    [aux_sym_boolean_literal_token1] = ACTIONS(1),
-    [aux_sym_boolean_literal_token2] = ACTIONS(1),
-  },
-  [STATE(1)] = {
-    [sym_source_file] = STATE(49),
-    [sym__statement] = STATE(40),
-    [sym_select_statement] = STATE(40),
-    [aux_sym_select_statement_token1] = ACTIONS(3),
-  },
-};
-
-static const uint16_t ts_small_parse_table[] = {
-  [0] = 15,
-    ACTIONS(5), 1,
-      anon_sym_LPAREN,
-    ACTIONS(7), 1,
-      aux_sym_not_expression_token1,
-    ACTIONS(9), 1,
-      aux_sym_literal_token1,
-    ACTIONS(11), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(15), 1,
-      sym_number_literal,
-    ACTIONS(19), 1,
-      sym__identifier,
-    STATE(14), 1,
-      sym_primary_expression,
-    STATE(18), 1,
-      sym_not_expression,
-    STATE(22), 1,
-      sym_and_expression,
-    STATE(25), 1,
-      sym_or_expression,
-    STATE(29), 1,
-      sym_expression,
-    ACTIONS(17), 2,
-      aux_sym_boolean_literal_token1,
-      aux_sym_boolean_literal_token2,
-    STATE(9), 2,
-      sym_string_literal,
-      sym_boolean_literal,
-    STATE(10), 3,
-      sym_column_name,
-      sym_comparison_expression,
-      sym_literal,
-  [50] = 15,
-    ACTIONS(5), 1,
-      anon_sym_LPAREN,
-    ACTIONS(7), 1,
-      aux_sym_not_expression_token1,
-    ACTIONS(9), 1,
-      aux_sym_literal_token1,
-    ACTIONS(11), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(15), 1,
-      sym_number_literal,
-    ACTIONS(19), 1,
-      sym__identifier,
-    STATE(14), 1,
-      sym_primary_expression,
-    STATE(18), 1,
-      sym_not_expression,
-    STATE(22), 1,
-      sym_and_expression,
-    STATE(25), 1,
-      sym_or_expression,
-    STATE(52), 1,
-      sym_expression,
-    ACTIONS(17), 2,
-      aux_sym_boolean_literal_token1,
-      aux_sym_boolean_literal_token2,
-    STATE(9), 2,
-      sym_string_literal,
-      sym_boolean_literal,
-    STATE(10), 3,
-      sym_column_name,
-      sym_comparison_expression,
-      sym_literal,
-  [100] = 14,
-    ACTIONS(5), 1,
-      anon_sym_LPAREN,
-    ACTIONS(7), 1,
-      aux_sym_not_expression_token1,
-    ACTIONS(9), 1,
+    case 124:
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(214);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      END_STATE();
+    case 126:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(215);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(216);
+      END_STATE();
+    case 127:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(217);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(218);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(219);
+      END_STATE();
+    case 128:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(220);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(221);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(222);
+      END_STATE();
+    case 129:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(223);
+      END_STATE();
+    case 130:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(224);
+      END_STATE();
+    case 131:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(225);
+      END_STATE();
+    case 132:
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(226);
+      END_STATE();
+    case 133:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(216);
+      END_STATE();
+    case 134:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(217);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(218);
+      END_STATE();
+    case 135:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(227);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(213);
+      END_STATE();
+    case 136:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(228);
+      END_STATE();
+    case 137:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(229);
+      END_STATE();
+    case 138:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(230);
+      END_STATE();
+    case 139:
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(231);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(232);
+      END_STATE();
+    case 140:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(233);
+      END_STATE();
+    case 141:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(220);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(221);
+      END_STATE();
+    case 142:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(234);
+      END_STATE();
+    case 143:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(235);
+      END_STATE();
+    case 144:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(218);
+      END_STATE();
+    case 145:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(227);
+      END_STATE();
+    case 146:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(236);
+      END_STATE();
+    case 147:
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(237);
+      END_STATE();
+    case 148:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      END_STATE();
+    case 149:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(238);
+      END_STATE();
+    case 150:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(239);
+      END_STATE();
+    case 151:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(240);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(235);
+      END_STATE();
+    case 152:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(241);
+      END_STATE();
+    case 153:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(242);
+      END_STATE();
+    case 154:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(243);
+      END_STATE();
+    case 155:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(244);
+      END_STATE();
+    case 156:
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(231);
+      END_STATE();
+    case 157:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(215);
+      END_STATE();
+    case 158:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(219);
+      END_STATE();
+    case 159:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(245);
+      END_STATE();
+    case 160:
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(231);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(246);
+      END_STATE();
+    case 161:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(247);
+      END_STATE();
+    case 162:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(215);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(248);
+      END_STATE();
+    case 163:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(249);
+      END_STATE();
+    case 164:
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(231);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(225);
+      END_STATE();
+    case 165:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(248);
+      END_STATE();
+    case 166:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(250);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      END_STATE();
+    case 168:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(251);
+      END_STATE();
+    case 169:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(252);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(253);
+      END_STATE();
+    case 170:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      END_STATE();
+    case 171:
+      ACCEPT_TOKEN(anon_sym_LT_GT);
+      END_STATE();
+    case 172:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(254);
+      END_STATE();
+    case 174:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('B' <= lookahead && lookahead <= 'C') ||
+          ('E' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'c') ||
+          ('e' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(255);
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(256);
+      END_STATE();
+    case 175:
+      ACCEPT_TOKEN(aux_sym_select_expression_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'B') ||
+          ('D' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'b') ||
+          ('d' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(257);
+      END_STATE();
+    case 176:
+      ACCEPT_TOKEN(aux_sym_group_by_clause_token2);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 177:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(258);
+      END_STATE();
+    case 178:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(259);
+      END_STATE();
+    case 179:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(260);
+      END_STATE();
+    case 180:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(261);
+      END_STATE();
+    case 181:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'C') ||
+          ('E' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'c') ||
+          ('e' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(262);
+      END_STATE();
+    case 182:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'B') ||
+          ('D' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'b') ||
+          ('d' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(263);
+      END_STATE();
+    case 183:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'O') ||
+          ('Q' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'o') ||
+          ('q' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(264);
+      END_STATE();
+    case 184:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(265);
+      END_STATE();
+    case 185:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(266);
+      END_STATE();
+    case 186:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Q') ||
+          ('S' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'q') ||
+          ('s' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(267);
+      END_STATE();
+    case 187:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'N') ||
+          ('P' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'n') ||
+          ('p' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(268);
+      END_STATE();
+    case 188:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'N') ||
+          ('P' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'n') ||
+          ('p' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(269);
+      END_STATE();
+    case 189:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'U') ||
+          ('W' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'u') ||
+          ('w' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'V' ||
+          lookahead == 'v') ADVANCE(270);
+      END_STATE();
+    case 190:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'H') ||
+          ('J' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'h') ||
+          ('j' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(271);
+      END_STATE();
+    case 191:
+      ACCEPT_TOKEN(aux_sym_comparison_expression_token3);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 192:
+      ACCEPT_TOKEN(aux_sym_comparison_expression_token4);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 193:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'J') ||
+          lookahead == 'L' ||
+          ('N' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'j') ||
+          lookahead == 'l' ||
+          ('n' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'K' ||
+          lookahead == 'k') ADVANCE(272);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(273);
+      END_STATE();
+    case 194:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'W') ||
+          ('Y' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'w') ||
+          ('y' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(274);
+      END_STATE();
+    case 195:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(275);
+      END_STATE();
+    case 196:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(276);
+      END_STATE();
+    case 197:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'E') ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(277);
+      END_STATE();
+    case 198:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(278);
+      END_STATE();
+    case 199:
+      ACCEPT_TOKEN(aux_sym_or_expression_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'C') ||
+          ('E' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'c') ||
+          ('e' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(279);
+      END_STATE();
+    case 200:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'V') ||
+          ('X' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'v') ||
+          ('x' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'W' ||
+          lookahead == 'w') ADVANCE(280);
+      END_STATE();
+    case 201:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'L') ||
+          ('N' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'l') ||
+          ('n' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(281);
+      END_STATE();
+    case 202:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(282);
+      END_STATE();
+    case 203:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(283);
+      END_STATE();
+    case 204:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'L') ||
+          ('N' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'l') ||
+          ('n' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(284);
+      END_STATE();
+    case 205:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'T') ||
+          ('V' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 't') ||
+          ('v' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(285);
+      END_STATE();
+    case 206:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'H') ||
+          ('J' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'h') ||
+          ('j' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(286);
+      END_STATE();
+    case 207:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(287);
+      END_STATE();
+    case 208:
+      ACCEPT_TOKEN(anon_sym_PIPE_PIPE);
+      END_STATE();
+    case 209:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(288);
+      END_STATE();
+    case 210:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(289);
+      END_STATE();
+    case 211:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(290);
+      END_STATE();
+    case 212:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(291);
+      END_STATE();
+    case 213:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(292);
+      END_STATE();
+    case 214:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(293);
+      END_STATE();
+    case 215:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(294);
+      END_STATE();
+    case 216:
+      ACCEPT_TOKEN(aux_sym_select_expression_token1);
+      END_STATE();
+    case 217:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(295);
+      END_STATE();
+    case 218:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(296);
+      END_STATE();
+    case 219:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(297);
+      END_STATE();
+    case 220:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(298);
+      END_STATE();
+    case 221:
+      ACCEPT_TOKEN(aux_sym_comparison_expression_token3);
+      END_STATE();
+    case 222:
+      ACCEPT_TOKEN(aux_sym_comparison_expression_token4);
+      END_STATE();
+    case 223:
+      if (lookahead == 'K' ||
+          lookahead == 'k') ADVANCE(299);
+      END_STATE();
+    case 224:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(300);
+      END_STATE();
+    case 225:
+      ACCEPT_TOKEN(aux_sym_or_expression_token1);
+      END_STATE();
+    case 226:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(301);
+      END_STATE();
+    case 227:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(302);
+      END_STATE();
+    case 228:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(303);
+      END_STATE();
+    case 229:
+      if (lookahead == 'V' ||
+          lookahead == 'v') ADVANCE(304);
+      END_STATE();
+    case 230:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(305);
+      END_STATE();
+    case 231:
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(306);
+      END_STATE();
+    case 232:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(307);
+      END_STATE();
+    case 233:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(308);
+      END_STATE();
+    case 234:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(309);
+      END_STATE();
+    case 235:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(310);
+      END_STATE();
+    case 236:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(311);
+      END_STATE();
+    case 237:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(312);
+      END_STATE();
+    case 238:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(313);
+      END_STATE();
+    case 239:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(314);
+      END_STATE();
+    case 240:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(315);
+      END_STATE();
+    case 241:
+      ACCEPT_TOKEN(aux_sym_group_by_clause_token2);
+      END_STATE();
+    case 242:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(316);
+      END_STATE();
+    case 243:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(317);
+      END_STATE();
+    case 244:
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(318);
+      END_STATE();
+    case 245:
+      if (lookahead == 'K' ||
+          lookahead == 'k') ADVANCE(299);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(305);
+      END_STATE();
+    case 246:
+      ACCEPT_TOKEN(aux_sym_or_expression_token1);
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(307);
+      END_STATE();
+    case 247:
+      if (lookahead == 'W' ||
+          lookahead == 'w') ADVANCE(319);
+      END_STATE();
+    case 248:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(320);
+      END_STATE();
+    case 249:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(321);
+      END_STATE();
+    case 250:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(322);
+      END_STATE();
+    case 251:
+      ACCEPT_TOKEN(sym_number_literal);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(251);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(169);
+      END_STATE();
+    case 252:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(253);
+      END_STATE();
+    case 253:
+      ACCEPT_TOKEN(sym_number_literal);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(253);
+      END_STATE();
+    case 254:
+      ACCEPT_TOKEN(sym_union_all);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 255:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(323);
+      END_STATE();
+    case 256:
+      ACCEPT_TOKEN(aux_sym_and_expression_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 257:
+      ACCEPT_TOKEN(aux_sym_sort_direction_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 258:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(324);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(325);
+      END_STATE();
+    case 259:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(326);
+      END_STATE();
+    case 260:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'B') ||
+          ('D' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'b') ||
+          ('d' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(327);
+      END_STATE();
+    case 261:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(328);
+      END_STATE();
+    case 262:
+      ACCEPT_TOKEN(aux_sym_case_expression_token2);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 263:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('B' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(329);
+      END_STATE();
+    case 264:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(330);
+      END_STATE();
+    case 265:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(331);
+      END_STATE();
+    case 266:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'B') ||
+          ('D' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'b') ||
+          ('d' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(332);
+      END_STATE();
+    case 267:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(333);
+      END_STATE();
+    case 268:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'L') ||
+          ('N' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'l') ||
+          ('n' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(334);
+      END_STATE();
+    case 269:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'T') ||
+          ('V' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 't') ||
+          ('v' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(335);
+      END_STATE();
+    case 270:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'H') ||
+          ('J' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'h') ||
+          ('j' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(336);
+      END_STATE();
+    case 271:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'J') ||
+          ('L' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'j') ||
+          ('l' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'K' ||
+          lookahead == 'k') ADVANCE(337);
+      END_STATE();
+    case 272:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(338);
+      END_STATE();
+    case 273:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'H') ||
+          ('J' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'h') ||
+          ('j' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(339);
+      END_STATE();
+    case 274:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(340);
+      END_STATE();
+    case 275:
+      ACCEPT_TOKEN(aux_sym_not_expression_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 276:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(341);
+      END_STATE();
+    case 277:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(342);
+      END_STATE();
+    case 278:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'X') ||
+          lookahead == 'Z' ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'x') ||
+          lookahead == 'z') ADVANCE(101);
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(343);
+      END_STATE();
+    case 279:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(344);
+      END_STATE();
+    case 280:
+      ACCEPT_TOKEN(aux_sym_offset_clause_token3);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(345);
+      END_STATE();
+    case 281:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'O') ||
+          ('Q' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'o') ||
+          ('q' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(346);
+      END_STATE();
+    case 282:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(347);
+      END_STATE();
+    case 283:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(348);
+      END_STATE();
+    case 284:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(349);
+      END_STATE();
+    case 285:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(350);
+      END_STATE();
+    case 286:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'N') ||
+          ('P' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'n') ||
+          ('p' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(351);
+      END_STATE();
+    case 287:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'Q') ||
+          ('S' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 'q') ||
+          ('s' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(352);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(353);
+      END_STATE();
+    case 288:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(354);
+      END_STATE();
+    case 289:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(355);
+      END_STATE();
+    case 290:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(356);
+      END_STATE();
+    case 291:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(357);
+      END_STATE();
+    case 292:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(358);
+      END_STATE();
+    case 293:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(359);
+      END_STATE();
+    case 294:
+      ACCEPT_TOKEN(aux_sym_and_expression_token1);
+      END_STATE();
+    case 295:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(360);
+      END_STATE();
+    case 296:
+      ACCEPT_TOKEN(aux_sym_case_expression_token2);
+      END_STATE();
+    case 297:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(361);
+      END_STATE();
+    case 298:
+      if (lookahead == 'K' ||
+          lookahead == 'k') ADVANCE(362);
+      END_STATE();
+    case 299:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(363);
+      END_STATE();
+    case 300:
+      ACCEPT_TOKEN(aux_sym_not_expression_token1);
+      END_STATE();
+    case 301:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(364);
+      END_STATE();
+    case 302:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(365);
+      END_STATE();
+    case 303:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(366);
+      END_STATE();
+    case 304:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(367);
+      END_STATE();
+    case 305:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(368);
+      END_STATE();
+    case 306:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(369);
+      END_STATE();
+    case 307:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(370);
+      END_STATE();
+    case 308:
+      ACCEPT_TOKEN(sym_union_all);
+      END_STATE();
+    case 309:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(371);
+      END_STATE();
+    case 310:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(372);
+      END_STATE();
+    case 311:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(373);
+      END_STATE();
+    case 312:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(374);
+      END_STATE();
+    case 313:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(375);
+      END_STATE();
+    case 314:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(376);
+      END_STATE();
+    case 315:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(377);
+      END_STATE();
+    case 316:
+      ACCEPT_TOKEN(aux_sym_limit_clause_token2);
+      END_STATE();
+    case 317:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(378);
+      END_STATE();
+    case 318:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(379);
+      END_STATE();
+    case 319:
+      ACCEPT_TOKEN(aux_sym_offset_clause_token3);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(380);
+      END_STATE();
+    case 320:
+      ACCEPT_TOKEN(aux_sym_sort_direction_token1);
+      END_STATE();
+    case 321:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(381);
+      END_STATE();
+    case 322:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(382);
+      END_STATE();
+    case 323:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'X') ||
+          lookahead == 'Z' ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'x') ||
+          lookahead == 'z') ADVANCE(101);
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(383);
+      END_STATE();
+    case 324:
+      ACCEPT_TOKEN(aux_sym_case_expression_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 325:
+      ACCEPT_TOKEN(aux_sym_cast_expression_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 326:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(375);
+      if (lookahead == '\'') ADVANCE(384);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 327:
+      ACCEPT_TOKEN(aux_sym_sort_direction_token2);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 328:
+      ACCEPT_TOKEN(aux_sym_else_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 329:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'O') ||
+          ('Q' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'o') ||
+          ('q' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(385);
+      END_STATE();
+    case 330:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('B' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(386);
+      END_STATE();
+    case 331:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(387);
+      END_STATE();
+    case 332:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'G') ||
+          ('I' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'g') ||
+          ('i' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(388);
+      END_STATE();
+    case 333:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(389);
+      END_STATE();
+    case 334:
+      ACCEPT_TOKEN(aux_sym_select_statement_token2);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 335:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'O') ||
+          ('Q' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'o') ||
+          ('q' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(390);
+      END_STATE();
+    case 336:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(391);
+      END_STATE();
+    case 337:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(392);
+      END_STATE();
+    case 338:
+      ACCEPT_TOKEN(aux_sym_comparison_expression_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 339:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(393);
+      END_STATE();
+    case 340:
+      ACCEPT_TOKEN(aux_sym_fetch_clause_token3);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 341:
+      ACCEPT_TOKEN(aux_sym_literal_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 342:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(394);
+      END_STATE();
+    case 343:
+      ACCEPT_TOKEN(aux_sym_fetch_clause_token4);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 344:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Q') ||
+          ('S' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'q') ||
+          ('s' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(395);
+      END_STATE();
+    case 345:
+      ACCEPT_TOKEN(aux_sym_offset_clause_token2);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 346:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(396);
+      END_STATE();
+    case 347:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'B') ||
+          ('D' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'b') ||
+          ('d' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(397);
+      END_STATE();
+    case 348:
+      ACCEPT_TOKEN(aux_sym_when_clause_token2);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 349:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'R') ||
+          ('T' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'r') ||
+          ('t' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(398);
+      END_STATE();
+    case 350:
+      ACCEPT_TOKEN(aux_sym_boolean_literal_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 351:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(399);
+      END_STATE();
+    case 352:
+      ACCEPT_TOKEN(aux_sym_when_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 353:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(400);
+      END_STATE();
+    case 354:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(401);
+      END_STATE();
+    case 355:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(402);
+      END_STATE();
+    case 356:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(403);
+      END_STATE();
+    case 357:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(404);
+      END_STATE();
+    case 358:
+      ACCEPT_TOKEN(aux_sym_select_statement_token2);
+      END_STATE();
+    case 359:
+      ACCEPT_TOKEN(aux_sym_when_clause_token1);
+      END_STATE();
+    case 360:
+      ACCEPT_TOKEN(aux_sym_else_clause_token1);
+      END_STATE();
+    case 361:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(405);
+      END_STATE();
+    case 362:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(406);
+      END_STATE();
+    case 363:
+      ACCEPT_TOKEN(aux_sym_comparison_expression_token1);
+      END_STATE();
+    case 364:
+      ACCEPT_TOKEN(aux_sym_when_clause_token2);
+      END_STATE();
+    case 365:
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(407);
+      END_STATE();
+    case 366:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(408);
+      END_STATE();
+    case 367:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(409);
+      END_STATE();
+    case 368:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(410);
+      END_STATE();
+    case 369:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(411);
+      END_STATE();
+    case 370:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(412);
+      END_STATE();
+    case 371:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(413);
+      END_STATE();
+    case 372:
+      ACCEPT_TOKEN(aux_sym_boolean_literal_token1);
+      END_STATE();
+    case 373:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(414);
+      END_STATE();
+    case 374:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(415);
+      END_STATE();
+    case 375:
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(375);
+      if (lookahead == '\'') ADVANCE(384);
+      END_STATE();
+    case 376:
+      ACCEPT_TOKEN(aux_sym_literal_token1);
+      END_STATE();
+    case 377:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(416);
+      END_STATE();
+    case 378:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(417);
+      END_STATE();
+    case 379:
+      ACCEPT_TOKEN(aux_sym_fetch_clause_token3);
+      END_STATE();
+    case 380:
+      ACCEPT_TOKEN(aux_sym_offset_clause_token2);
+      END_STATE();
+    case 381:
+      ACCEPT_TOKEN(aux_sym_sort_direction_token2);
+      END_STATE();
+    case 382:
+      ACCEPT_TOKEN(aux_sym_fetch_clause_token4);
+      END_STATE();
+    case 383:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Y') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'y')) ADVANCE(101);
+      if (lookahead == 'Z' ||
+          lookahead == 'z') ADVANCE(418);
+      END_STATE();
+    case 384:
+      ACCEPT_TOKEN(aux_sym_date_literal_token1);
+      END_STATE();
+    case 385:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(419);
+      END_STATE();
+    case 386:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'H') ||
+          ('J' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'h') ||
+          ('j' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(420);
+      END_STATE();
+    case 387:
+      ACCEPT_TOKEN(aux_sym_boolean_literal_token2);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 388:
+      ACCEPT_TOKEN(aux_sym_fetch_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 389:
+      ACCEPT_TOKEN(aux_sym_fetch_clause_token2);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 390:
+      ACCEPT_TOKEN(aux_sym_group_by_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 391:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('H' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'f') ||
+          ('h' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(421);
+      END_STATE();
+    case 392:
+      ACCEPT_TOKEN(aux_sym_comparison_expression_token2);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 393:
+      ACCEPT_TOKEN(aux_sym_limit_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 394:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(422);
+      END_STATE();
+    case 395:
+      ACCEPT_TOKEN(aux_sym_order_by_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 396:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(423);
+      END_STATE();
+    case 397:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(424);
+      END_STATE();
+    case 398:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(425);
+      END_STATE();
+    case 399:
+      ACCEPT_TOKEN(aux_sym_union_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 400:
+      ACCEPT_TOKEN(aux_sym_where_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 401:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(426);
+      END_STATE();
+    case 402:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(427);
+      END_STATE();
+    case 403:
+      if (lookahead == 'Z' ||
+          lookahead == 'z') ADVANCE(428);
+      END_STATE();
+    case 404:
+      ACCEPT_TOKEN(aux_sym_union_clause_token1);
+      END_STATE();
+    case 405:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(429);
+      END_STATE();
+    case 406:
+      ACCEPT_TOKEN(aux_sym_comparison_expression_token2);
+      END_STATE();
+    case 407:
+      ACCEPT_TOKEN(aux_sym_fetch_clause_token1);
+      END_STATE();
+    case 408:
+      ACCEPT_TOKEN(aux_sym_group_by_clause_token1);
+      END_STATE();
+    case 409:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(430);
+      END_STATE();
+    case 410:
+      ACCEPT_TOKEN(aux_sym_limit_clause_token1);
+      END_STATE();
+    case 411:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(431);
+      END_STATE();
+    case 412:
+      ACCEPT_TOKEN(aux_sym_order_by_clause_token1);
+      END_STATE();
+    case 413:
+      ACCEPT_TOKEN(aux_sym_boolean_literal_token2);
+      END_STATE();
+    case 414:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(432);
+      END_STATE();
+    case 415:
+      ACCEPT_TOKEN(aux_sym_where_clause_token1);
+      END_STATE();
+    case 416:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(433);
+      END_STATE();
+    case 417:
+      ACCEPT_TOKEN(aux_sym_fetch_clause_token2);
+      END_STATE();
+    case 418:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(434);
+      END_STATE();
+    case 419:
+      ACCEPT_TOKEN(aux_sym_like_escape_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 420:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'M') ||
+          ('O' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'm') ||
+          ('o' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(435);
+      END_STATE();
+    case 421:
+      ACCEPT_TOKEN(aux_sym_having_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 422:
+      ACCEPT_TOKEN(aux_sym_offset_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 423:
+      ACCEPT_TOKEN(aux_sym_sample_clause_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 424:
+      ACCEPT_TOKEN(aux_sym_select_statement_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 425:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('B' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(436);
+      END_STATE();
+    case 426:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(437);
+      END_STATE();
+    case 427:
+      ACCEPT_TOKEN(aux_sym_select_statement_token1);
+      END_STATE();
+    case 428:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(438);
+      END_STATE();
+    case 429:
+      ACCEPT_TOKEN(aux_sym_like_escape_token1);
+      END_STATE();
+    case 430:
+      ACCEPT_TOKEN(aux_sym_having_clause_token1);
+      END_STATE();
+    case 431:
+      ACCEPT_TOKEN(aux_sym_offset_clause_token1);
+      END_STATE();
+    case 432:
+      ACCEPT_TOKEN(aux_sym_sample_clause_token1);
+      END_STATE();
+    case 433:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(439);
+      END_STATE();
+    case 434:
+      ACCEPT_TOKEN(sym_analyze);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 435:
+      ACCEPT_TOKEN(aux_sym_explain_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 436:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'L') ||
+          ('N' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'l') ||
+          ('n' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(440);
+      END_STATE();
+    case 437:
+      ACCEPT_TOKEN(aux_sym_explain_token1);
+      END_STATE();
+    case 438:
+      ACCEPT_TOKEN(sym_analyze);
+      END_STATE();
+    case 439:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(441);
+      END_STATE();
+    case 440:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'O') ||
+          ('Q' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'o') ||
+          ('q' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(442);
+      END_STATE();
+    case 441:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(443);
+      END_STATE();
+    case 442:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(443);
+      if (lookahead == '\'') ADVANCE(444);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 443:
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(443);
+      if (lookahead == '\'') ADVANCE(444);
+      END_STATE();
+    case 444:
+      ACCEPT_TOKEN(aux_sym_timestamp_literal_token1);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 1},
+  [2] = {.lex_state = 2},
+  [3] = {.lex_state = 3},
+  [4] = {.lex_state = 4},
+  [5] = {.lex_state = 5},
+  [6] = {.lex_state = 6},
+  [7] = {.lex_state = 6},
+  [8] = {.lex_state = 7},
+  [9] = {.lex_state = 5},
+  [10] = {.lex_state = 8},
+  [11] = {.lex_state = 9},
+  [12] = {.lex_state = 10},
+  [13] = {.lex_state = 11},
+  [14] = {.lex_state = 12},
+  [15] = {.lex_state = 9},
+  [16] = {.lex_state = 13},
+  [17] = {.lex_state = 14},
+  [18] = {.lex_state = 15},
+  [19] = {.lex_state = 16},
+  [20] = {.lex_state = 17},
+  [21] = {.lex_state = 17},
+  [22] = {.lex_state = 17},
+  [23] = {.lex_state = 16},
+  [24] = {.lex_state = 16},
+  [25] = {.lex_state = 16},
+  [26] = {.lex_state = 18},
+  [27] = {.lex_state = 19},
+  [28] = {.lex_state = 19},
+  [29] = {.lex_state = 8},
+  [30] = {.lex_state = 16},
+  [31] = {.lex_state = 14},
+  [32] = {.lex_state = 16},
+  [33] = {.lex_state = 20},
+  [34] = {.lex_state = 21},
+  [35] = {.lex_state = 22},
+  [36] = {.lex_state = 23},
+  [37] = {.lex_state = 24},
+  [38] = {.lex_state = 25},
+  [39] = {.lex_state = 16},
+  [40] = {.lex_state = 16},
+  [41] = {.lex_state = 16},
+  [42] = {.lex_state = 16},
+  [43] = {.lex_state = 16},
+  [44] = {.lex_state = 16},
+  [45] = {.lex_state = 16},
+  [46] = {.lex_state = 16},
+  [47] = {.lex_state = 16},
+  [48] = {.lex_state = 16},
+  [49] = {.lex_state = 6},
+  [50] = {.lex_state = 4},
+  [51] = {.lex_state = 26},
+  [52] = {.lex_state = 7},
+  [53] = {.lex_state = 7},
+  [54] = {.lex_state = 27},
+  [55] = {.lex_state = 28},
+  [56] = {.lex_state = 29},
+  [57] = {.lex_state = 30},
+  [58] = {.lex_state = 24},
+  [59] = {.lex_state = 16},
+  [60] = {.lex_state = 9},
+  [61] = {.lex_state = 9},
+  [62] = {.lex_state = 31},
+  [63] = {.lex_state = 31},
+  [64] = {.lex_state = 32},
+  [65] = {.lex_state = 32},
+  [66] = {.lex_state = 32},
+  [67] = {.lex_state = 33},
+  [68] = {.lex_state = 3},
+  [69] = {.lex_state = 8},
+  [70] = {.lex_state = 34},
+  [71] = {.lex_state = 35},
+  [72] = {.lex_state = 36},
+  [73] = {.lex_state = 9},
+  [74] = {.lex_state = 9},
+  [75] = {.lex_state = 13},
+  [76] = {.lex_state = 37},
+  [77] = {.lex_state = 13},
+  [78] = {.lex_state = 13},
+  [79] = {.lex_state = 13},
+  [80] = {.lex_state = 13},
+  [81] = {.lex_state = 13},
+  [82] = {.lex_state = 13},
+  [83] = {.lex_state = 13},
+  [84] = {.lex_state = 13},
+  [85] = {.lex_state = 13},
+  [86] = {.lex_state = 14},
+  [87] = {.lex_state = 38},
+  [88] = {.lex_state = 13},
+  [89] = {.lex_state = 13},
+  [90] = {.lex_state = 13},
+  [91] = {.lex_state = 13},
+  [92] = {.lex_state = 13},
+  [93] = {.lex_state = 4},
+  [94] = {.lex_state = 5},
+  [95] = {.lex_state = 7},
+  [96] = {.lex_state = 7},
+  [97] = {.lex_state = 16},
+  [98] = {.lex_state = 16},
+  [99] = {.lex_state = 16},
+  [100] = {.lex_state = 16},
+  [101] = {.lex_state = 39},
+  [102] = {.lex_state = 40},
+  [103] = {.lex_state = 16},
+  [104] = {.lex_state = 9},
+  [105] = {.lex_state = 41},
+  [106] = {.lex_state = 31},
+  [107] = {.lex_state = 16},
+  [108] = {.lex_state = 16},
+  [109] = {.lex_state = 16},
+  [110] = {.lex_state = 10},
+  [111] = {.lex_state = 17},
+  [112] = {.lex_state = 42},
+  [113] = {.lex_state = 42},
+  [114] = {.lex_state = 42},
+  [115] = {.lex_state = 8},
+  [116] = {.lex_state = 8},
+  [117] = {.lex_state = 27},
+  [118] = {.lex_state = 16},
+  [119] = {.lex_state = 43},
+  [120] = {.lex_state = 10},
+  [121] = {.lex_state = 11},
+  [122] = {.lex_state = 12},
+  [123] = {.lex_state = 16},
+  [124] = {.lex_state = 8},
+  [125] = {.lex_state = 8},
+  [126] = {.lex_state = 22},
+  [127] = {.lex_state = 23},
+  [128] = {.lex_state = 16},
+  [129] = {.lex_state = 13},
+  [130] = {.lex_state = 13},
+  [131] = {.lex_state = 14},
+  [132] = {.lex_state = 16},
+  [133] = {.lex_state = 16},
+  [134] = {.lex_state = 16},
+  [135] = {.lex_state = 16},
+  [136] = {.lex_state = 16},
+  [137] = {.lex_state = 16},
+  [138] = {.lex_state = 16},
+  [139] = {.lex_state = 16},
+  [140] = {.lex_state = 16},
+  [141] = {.lex_state = 44},
+  [142] = {.lex_state = 16},
+  [143] = {.lex_state = 45},
+  [144] = {.lex_state = 16},
+  [145] = {.lex_state = 16},
+  [146] = {.lex_state = 16},
+  [147] = {.lex_state = 16},
+  [148] = {.lex_state = 16},
+  [149] = {.lex_state = 16},
+  [150] = {.lex_state = 7},
+  [151] = {.lex_state = 36},
+  [152] = {.lex_state = 9},
+  [153] = {.lex_state = 41},
+  [154] = {.lex_state = 16},
+  [155] = {.lex_state = 28},
+  [156] = {.lex_state = 32},
+  [157] = {.lex_state = 33},
+  [158] = {.lex_state = 9},
+  [159] = {.lex_state = 46},
+  [160] = {.lex_state = 9},
+  [161] = {.lex_state = 46},
+  [162] = {.lex_state = 47},
+  [163] = {.lex_state = 48},
+  [164] = {.lex_state = 47},
+  [165] = {.lex_state = 49},
+  [166] = {.lex_state = 50},
+  [167] = {.lex_state = 51},
+  [168] = {.lex_state = 52},
+  [169] = {.lex_state = 53},
+  [170] = {.lex_state = 54},
+  [171] = {.lex_state = 55},
+  [172] = {.lex_state = 56},
+  [173] = {.lex_state = 7},
+  [174] = {.lex_state = 42},
+  [175] = {.lex_state = 16},
+  [176] = {.lex_state = 9},
+  [177] = {.lex_state = 16},
+  [178] = {.lex_state = 43},
+  [179] = {.lex_state = 28},
+  [180] = {.lex_state = 29},
+  [181] = {.lex_state = 30},
+  [182] = {.lex_state = 16},
+  [183] = {.lex_state = 16},
+  [184] = {.lex_state = 16},
+  [185] = {.lex_state = 32},
+  [186] = {.lex_state = 16},
+  [187] = {.lex_state = 16},
+  [188] = {.lex_state = 43},
+  [189] = {.lex_state = 16},
+  [190] = {.lex_state = 27},
+  [191] = {.lex_state = 27},
+  [192] = {.lex_state = 31},
+  [193] = {.lex_state = 42},
+  [194] = {.lex_state = 42},
+  [195] = {.lex_state = 42},
+  [196] = {.lex_state = 9},
+  [197] = {.lex_state = 10},
+  [198] = {.lex_state = 11},
+  [199] = {.lex_state = 12},
+  [200] = {.lex_state = 9},
+  [201] = {.lex_state = 13},
+  [202] = {.lex_state = 14},
+  [203] = {.lex_state = 15},
+  [204] = {.lex_state = 57},
+  [205] = {.lex_state = 17},
+  [206] = {.lex_state = 17},
+  [207] = {.lex_state = 17},
+  [208] = {.lex_state = 57},
+  [209] = {.lex_state = 57},
+  [210] = {.lex_state = 57},
+  [211] = {.lex_state = 58},
+  [212] = {.lex_state = 57},
+  [213] = {.lex_state = 14},
+  [214] = {.lex_state = 57},
+  [215] = {.lex_state = 20},
+  [216] = {.lex_state = 50},
+  [217] = {.lex_state = 59},
+  [218] = {.lex_state = 60},
+  [219] = {.lex_state = 61},
+  [220] = {.lex_state = 57},
+  [221] = {.lex_state = 57},
+  [222] = {.lex_state = 57},
+  [223] = {.lex_state = 57},
+  [224] = {.lex_state = 57},
+  [225] = {.lex_state = 57},
+  [226] = {.lex_state = 57},
+  [227] = {.lex_state = 57},
+  [228] = {.lex_state = 57},
+  [229] = {.lex_state = 57},
+  [230] = {.lex_state = 35},
+  [231] = {.lex_state = 52},
+  [232] = {.lex_state = 9},
+  [233] = {.lex_state = 54},
+  [234] = {.lex_state = 55},
+  [235] = {.lex_state = 55},
+  [236] = {.lex_state = 62},
+  [237] = {.lex_state = 47},
+  [238] = {.lex_state = 47},
+  [239] = {.lex_state = 51},
+  [240] = {.lex_state = 52},
+  [241] = {.lex_state = 53},
+  [242] = {.lex_state = 55},
+  [243] = {.lex_state = 56},
+  [244] = {.lex_state = 7},
+  [245] = {.lex_state = 52},
+  [246] = {.lex_state = 53},
+  [247] = {.lex_state = 55},
+  [248] = {.lex_state = 56},
+  [249] = {.lex_state = 7},
+  [250] = {.lex_state = 53},
+  [251] = {.lex_state = 55},
+  [252] = {.lex_state = 56},
+  [253] = {.lex_state = 7},
+  [254] = {.lex_state = 55},
+  [255] = {.lex_state = 56},
+  [256] = {.lex_state = 7},
+  [257] = {.lex_state = 50},
+  [258] = {.lex_state = 51},
+  [259] = {.lex_state = 52},
+  [260] = {.lex_state = 53},
+  [261] = {.lex_state = 55},
+  [262] = {.lex_state = 56},
+  [263] = {.lex_state = 7},
+  [264] = {.lex_state = 56},
+  [265] = {.lex_state = 7},
+  [266] = {.lex_state = 7},
+  [267] = {.lex_state = 50},
+  [268] = {.lex_state = 51},
+  [269] = {.lex_state = 52},
+  [270] = {.lex_state = 53},
+  [271] = {.lex_state = 54},
+  [272] = {.lex_state = 55},
+  [273] = {.lex_state = 56},
+  [274] = {.lex_state = 7},
+  [275] = {.lex_state = 42},
+  [276] = {.lex_state = 43},
+  [277] = {.lex_state = 16},
+  [278] = {.lex_state = 43},
+  [279] = {.lex_state = 16},
+  [280] = {.lex_state = 16},
+  [281] = {.lex_state = 16},
+  [282] = {.lex_state = 16},
+  [283] = {.lex_state = 16},
+  [284] = {.lex_state = 16},
+  [285] = {.lex_state = 44},
+  [286] = {.lex_state = 16},
+  [287] = {.lex_state = 43},
+  [288] = {.lex_state = 16},
+  [289] = {.lex_state = 27},
+  [290] = {.lex_state = 28},
+  [291] = {.lex_state = 29},
+  [292] = {.lex_state = 30},
+  [293] = {.lex_state = 60},
+  [294] = {.lex_state = 57},
+  [295] = {.lex_state = 9},
+  [296] = {.lex_state = 31},
+  [297] = {.lex_state = 32},
+  [298] = {.lex_state = 32},
+  [299] = {.lex_state = 32},
+  [300] = {.lex_state = 34},
+  [301] = {.lex_state = 35},
+  [302] = {.lex_state = 9},
+  [303] = {.lex_state = 9},
+  [304] = {.lex_state = 13},
+  [305] = {.lex_state = 37},
+  [306] = {.lex_state = 13},
+  [307] = {.lex_state = 13},
+  [308] = {.lex_state = 13},
+  [309] = {.lex_state = 13},
+  [310] = {.lex_state = 13},
+  [311] = {.lex_state = 13},
+  [312] = {.lex_state = 13},
+  [313] = {.lex_state = 13},
+  [314] = {.lex_state = 13},
+  [315] = {.lex_state = 14},
+  [316] = {.lex_state = 38},
+  [317] = {.lex_state = 13},
+  [318] = {.lex_state = 13},
+  [319] = {.lex_state = 13},
+  [320] = {.lex_state = 13},
+  [321] = {.lex_state = 13},
+  [322] = {.lex_state = 10},
+  [323] = {.lex_state = 11},
+  [324] = {.lex_state = 12},
+  [325] = {.lex_state = 63},
+  [326] = {.lex_state = 64},
+  [327] = {.lex_state = 20},
+  [328] = {.lex_state = 9},
+  [329] = {.lex_state = 10},
+  [330] = {.lex_state = 11},
+  [331] = {.lex_state = 12},
+  [332] = {.lex_state = 9},
+  [333] = {.lex_state = 13},
+  [334] = {.lex_state = 14},
+  [335] = {.lex_state = 15},
+  [336] = {.lex_state = 65},
+  [337] = {.lex_state = 17},
+  [338] = {.lex_state = 17},
+  [339] = {.lex_state = 17},
+  [340] = {.lex_state = 65},
+  [341] = {.lex_state = 65},
+  [342] = {.lex_state = 65},
+  [343] = {.lex_state = 66},
+  [344] = {.lex_state = 65},
+  [345] = {.lex_state = 14},
+  [346] = {.lex_state = 65},
+  [347] = {.lex_state = 20},
+  [348] = {.lex_state = 67},
+  [349] = {.lex_state = 68},
+  [350] = {.lex_state = 68},
+  [351] = {.lex_state = 69},
+  [352] = {.lex_state = 70},
+  [353] = {.lex_state = 71},
+  [354] = {.lex_state = 65},
+  [355] = {.lex_state = 65},
+  [356] = {.lex_state = 65},
+  [357] = {.lex_state = 65},
+  [358] = {.lex_state = 65},
+  [359] = {.lex_state = 65},
+  [360] = {.lex_state = 65},
+  [361] = {.lex_state = 65},
+  [362] = {.lex_state = 65},
+  [363] = {.lex_state = 65},
+  [364] = {.lex_state = 56},
+  [365] = {.lex_state = 56},
+  [366] = {.lex_state = 72},
+  [367] = {.lex_state = 72},
+  [368] = {.lex_state = 52},
+  [369] = {.lex_state = 53},
+  [370] = {.lex_state = 55},
+  [371] = {.lex_state = 56},
+  [372] = {.lex_state = 7},
+  [373] = {.lex_state = 53},
+  [374] = {.lex_state = 55},
+  [375] = {.lex_state = 56},
+  [376] = {.lex_state = 7},
+  [377] = {.lex_state = 55},
+  [378] = {.lex_state = 56},
+  [379] = {.lex_state = 7},
+  [380] = {.lex_state = 56},
+  [381] = {.lex_state = 7},
+  [382] = {.lex_state = 7},
+  [383] = {.lex_state = 53},
+  [384] = {.lex_state = 55},
+  [385] = {.lex_state = 56},
+  [386] = {.lex_state = 7},
+  [387] = {.lex_state = 55},
+  [388] = {.lex_state = 56},
+  [389] = {.lex_state = 7},
+  [390] = {.lex_state = 56},
+  [391] = {.lex_state = 7},
+  [392] = {.lex_state = 7},
+  [393] = {.lex_state = 55},
+  [394] = {.lex_state = 56},
+  [395] = {.lex_state = 7},
+  [396] = {.lex_state = 56},
+  [397] = {.lex_state = 7},
+  [398] = {.lex_state = 7},
+  [399] = {.lex_state = 56},
+  [400] = {.lex_state = 7},
+  [401] = {.lex_state = 7},
+  [402] = {.lex_state = 51},
+  [403] = {.lex_state = 52},
+  [404] = {.lex_state = 53},
+  [405] = {.lex_state = 55},
+  [406] = {.lex_state = 56},
+  [407] = {.lex_state = 7},
+  [408] = {.lex_state = 52},
+  [409] = {.lex_state = 53},
+  [410] = {.lex_state = 55},
+  [411] = {.lex_state = 56},
+  [412] = {.lex_state = 7},
+  [413] = {.lex_state = 53},
+  [414] = {.lex_state = 55},
+  [415] = {.lex_state = 56},
+  [416] = {.lex_state = 7},
+  [417] = {.lex_state = 55},
+  [418] = {.lex_state = 56},
+  [419] = {.lex_state = 7},
+  [420] = {.lex_state = 56},
+  [421] = {.lex_state = 7},
+  [422] = {.lex_state = 7},
+  [423] = {.lex_state = 7},
+  [424] = {.lex_state = 51},
+  [425] = {.lex_state = 52},
+  [426] = {.lex_state = 53},
+  [427] = {.lex_state = 55},
+  [428] = {.lex_state = 56},
+  [429] = {.lex_state = 7},
+  [430] = {.lex_state = 52},
+  [431] = {.lex_state = 53},
+  [432] = {.lex_state = 55},
+  [433] = {.lex_state = 56},
+  [434] = {.lex_state = 7},
+  [435] = {.lex_state = 53},
+  [436] = {.lex_state = 55},
+  [437] = {.lex_state = 56},
+  [438] = {.lex_state = 7},
+  [439] = {.lex_state = 55},
+  [440] = {.lex_state = 56},
+  [441] = {.lex_state = 7},
+  [442] = {.lex_state = 50},
+  [443] = {.lex_state = 51},
+  [444] = {.lex_state = 52},
+  [445] = {.lex_state = 53},
+  [446] = {.lex_state = 55},
+  [447] = {.lex_state = 56},
+  [448] = {.lex_state = 7},
+  [449] = {.lex_state = 56},
+  [450] = {.lex_state = 7},
+  [451] = {.lex_state = 7},
+  [452] = {.lex_state = 43},
+  [453] = {.lex_state = 16},
+  [454] = {.lex_state = 43},
+  [455] = {.lex_state = 57},
+  [456] = {.lex_state = 57},
+  [457] = {.lex_state = 57},
+  [458] = {.lex_state = 57},
+  [459] = {.lex_state = 39},
+  [460] = {.lex_state = 57},
+  [461] = {.lex_state = 41},
+  [462] = {.lex_state = 57},
+  [463] = {.lex_state = 57},
+  [464] = {.lex_state = 57},
+  [465] = {.lex_state = 27},
+  [466] = {.lex_state = 57},
+  [467] = {.lex_state = 43},
+  [468] = {.lex_state = 10},
+  [469] = {.lex_state = 11},
+  [470] = {.lex_state = 12},
+  [471] = {.lex_state = 57},
+  [472] = {.lex_state = 59},
+  [473] = {.lex_state = 57},
+  [474] = {.lex_state = 13},
+  [475] = {.lex_state = 13},
+  [476] = {.lex_state = 14},
+  [477] = {.lex_state = 57},
+  [478] = {.lex_state = 57},
+  [479] = {.lex_state = 57},
+  [480] = {.lex_state = 57},
+  [481] = {.lex_state = 57},
+  [482] = {.lex_state = 57},
+  [483] = {.lex_state = 57},
+  [484] = {.lex_state = 57},
+  [485] = {.lex_state = 57},
+  [486] = {.lex_state = 44},
+  [487] = {.lex_state = 57},
+  [488] = {.lex_state = 45},
+  [489] = {.lex_state = 57},
+  [490] = {.lex_state = 57},
+  [491] = {.lex_state = 57},
+  [492] = {.lex_state = 57},
+  [493] = {.lex_state = 57},
+  [494] = {.lex_state = 57},
+  [495] = {.lex_state = 28},
+  [496] = {.lex_state = 29},
+  [497] = {.lex_state = 30},
+  [498] = {.lex_state = 35},
+  [499] = {.lex_state = 64},
+  [500] = {.lex_state = 35},
+  [501] = {.lex_state = 27},
+  [502] = {.lex_state = 28},
+  [503] = {.lex_state = 29},
+  [504] = {.lex_state = 30},
+  [505] = {.lex_state = 70},
+  [506] = {.lex_state = 65},
+  [507] = {.lex_state = 9},
+  [508] = {.lex_state = 31},
+  [509] = {.lex_state = 32},
+  [510] = {.lex_state = 32},
+  [511] = {.lex_state = 32},
+  [512] = {.lex_state = 34},
+  [513] = {.lex_state = 35},
+  [514] = {.lex_state = 9},
+  [515] = {.lex_state = 67},
+  [516] = {.lex_state = 67},
+  [517] = {.lex_state = 67},
+  [518] = {.lex_state = 67},
+  [519] = {.lex_state = 9},
+  [520] = {.lex_state = 9},
+  [521] = {.lex_state = 13},
+  [522] = {.lex_state = 37},
+  [523] = {.lex_state = 13},
+  [524] = {.lex_state = 13},
+  [525] = {.lex_state = 13},
+  [526] = {.lex_state = 13},
+  [527] = {.lex_state = 13},
+  [528] = {.lex_state = 13},
+  [529] = {.lex_state = 13},
+  [530] = {.lex_state = 13},
+  [531] = {.lex_state = 13},
+  [532] = {.lex_state = 14},
+  [533] = {.lex_state = 38},
+  [534] = {.lex_state = 13},
+  [535] = {.lex_state = 13},
+  [536] = {.lex_state = 13},
+  [537] = {.lex_state = 13},
+  [538] = {.lex_state = 13},
+  [539] = {.lex_state = 73},
+  [540] = {.lex_state = 73},
+  [541] = {.lex_state = 73},
+  [542] = {.lex_state = 73},
+  [543] = {.lex_state = 53},
+  [544] = {.lex_state = 55},
+  [545] = {.lex_state = 56},
+  [546] = {.lex_state = 7},
+  [547] = {.lex_state = 55},
+  [548] = {.lex_state = 56},
+  [549] = {.lex_state = 7},
+  [550] = {.lex_state = 56},
+  [551] = {.lex_state = 7},
+  [552] = {.lex_state = 7},
+  [553] = {.lex_state = 55},
+  [554] = {.lex_state = 56},
+  [555] = {.lex_state = 7},
+  [556] = {.lex_state = 56},
+  [557] = {.lex_state = 7},
+  [558] = {.lex_state = 7},
+  [559] = {.lex_state = 56},
+  [560] = {.lex_state = 7},
+  [561] = {.lex_state = 7},
+  [562] = {.lex_state = 7},
+  [563] = {.lex_state = 55},
+  [564] = {.lex_state = 56},
+  [565] = {.lex_state = 7},
+  [566] = {.lex_state = 56},
+  [567] = {.lex_state = 7},
+  [568] = {.lex_state = 7},
+  [569] = {.lex_state = 56},
+  [570] = {.lex_state = 7},
+  [571] = {.lex_state = 7},
+  [572] = {.lex_state = 7},
+  [573] = {.lex_state = 56},
+  [574] = {.lex_state = 7},
+  [575] = {.lex_state = 7},
+  [576] = {.lex_state = 7},
+  [577] = {.lex_state = 7},
+  [578] = {.lex_state = 52},
+  [579] = {.lex_state = 53},
+  [580] = {.lex_state = 55},
+  [581] = {.lex_state = 56},
+  [582] = {.lex_state = 7},
+  [583] = {.lex_state = 53},
+  [584] = {.lex_state = 55},
+  [585] = {.lex_state = 56},
+  [586] = {.lex_state = 7},
+  [587] = {.lex_state = 55},
+  [588] = {.lex_state = 56},
+  [589] = {.lex_state = 7},
+  [590] = {.lex_state = 56},
+  [591] = {.lex_state = 7},
+  [592] = {.lex_state = 7},
+  [593] = {.lex_state = 53},
+  [594] = {.lex_state = 55},
+  [595] = {.lex_state = 56},
+  [596] = {.lex_state = 7},
+  [597] = {.lex_state = 55},
+  [598] = {.lex_state = 56},
+  [599] = {.lex_state = 7},
+  [600] = {.lex_state = 56},
+  [601] = {.lex_state = 7},
+  [602] = {.lex_state = 7},
+  [603] = {.lex_state = 55},
+  [604] = {.lex_state = 56},
+  [605] = {.lex_state = 7},
+  [606] = {.lex_state = 56},
+  [607] = {.lex_state = 7},
+  [608] = {.lex_state = 7},
+  [609] = {.lex_state = 56},
+  [610] = {.lex_state = 7},
+  [611] = {.lex_state = 7},
+  [612] = {.lex_state = 7},
+  [613] = {.lex_state = 52},
+  [614] = {.lex_state = 53},
+  [615] = {.lex_state = 55},
+  [616] = {.lex_state = 56},
+  [617] = {.lex_state = 7},
+  [618] = {.lex_state = 53},
+  [619] = {.lex_state = 55},
+  [620] = {.lex_state = 56},
+  [621] = {.lex_state = 7},
+  [622] = {.lex_state = 55},
+  [623] = {.lex_state = 56},
+  [624] = {.lex_state = 7},
+  [625] = {.lex_state = 56},
+  [626] = {.lex_state = 7},
+  [627] = {.lex_state = 7},
+  [628] = {.lex_state = 53},
+  [629] = {.lex_state = 55},
+  [630] = {.lex_state = 56},
+  [631] = {.lex_state = 7},
+  [632] = {.lex_state = 55},
+  [633] = {.lex_state = 56},
+  [634] = {.lex_state = 7},
+  [635] = {.lex_state = 56},
+  [636] = {.lex_state = 7},
+  [637] = {.lex_state = 7},
+  [638] = {.lex_state = 55},
+  [639] = {.lex_state = 56},
+  [640] = {.lex_state = 7},
+  [641] = {.lex_state = 56},
+  [642] = {.lex_state = 7},
+  [643] = {.lex_state = 7},
+  [644] = {.lex_state = 56},
+  [645] = {.lex_state = 7},
+  [646] = {.lex_state = 7},
+  [647] = {.lex_state = 51},
+  [648] = {.lex_state = 52},
+  [649] = {.lex_state = 53},
+  [650] = {.lex_state = 55},
+  [651] = {.lex_state = 56},
+  [652] = {.lex_state = 7},
+  [653] = {.lex_state = 52},
+  [654] = {.lex_state = 53},
+  [655] = {.lex_state = 55},
+  [656] = {.lex_state = 56},
+  [657] = {.lex_state = 7},
+  [658] = {.lex_state = 53},
+  [659] = {.lex_state = 55},
+  [660] = {.lex_state = 56},
+  [661] = {.lex_state = 7},
+  [662] = {.lex_state = 55},
+  [663] = {.lex_state = 56},
+  [664] = {.lex_state = 7},
+  [665] = {.lex_state = 56},
+  [666] = {.lex_state = 7},
+  [667] = {.lex_state = 7},
+  [668] = {.lex_state = 7},
+  [669] = {.lex_state = 36},
+  [670] = {.lex_state = 57},
+  [671] = {.lex_state = 57},
+  [672] = {.lex_state = 57},
+  [673] = {.lex_state = 43},
+  [674] = {.lex_state = 28},
+  [675] = {.lex_state = 29},
+  [676] = {.lex_state = 30},
+  [677] = {.lex_state = 57},
+  [678] = {.lex_state = 57},
+  [679] = {.lex_state = 57},
+  [680] = {.lex_state = 32},
+  [681] = {.lex_state = 57},
+  [682] = {.lex_state = 57},
+  [683] = {.lex_state = 43},
+  [684] = {.lex_state = 57},
+  [685] = {.lex_state = 64},
+  [686] = {.lex_state = 64},
+  [687] = {.lex_state = 64},
+  [688] = {.lex_state = 64},
+  [689] = {.lex_state = 64},
+  [690] = {.lex_state = 10},
+  [691] = {.lex_state = 11},
+  [692] = {.lex_state = 12},
+  [693] = {.lex_state = 64},
+  [694] = {.lex_state = 65},
+  [695] = {.lex_state = 65},
+  [696] = {.lex_state = 65},
+  [697] = {.lex_state = 65},
+  [698] = {.lex_state = 39},
+  [699] = {.lex_state = 65},
+  [700] = {.lex_state = 41},
+  [701] = {.lex_state = 65},
+  [702] = {.lex_state = 65},
+  [703] = {.lex_state = 65},
+  [704] = {.lex_state = 27},
+  [705] = {.lex_state = 65},
+  [706] = {.lex_state = 43},
+  [707] = {.lex_state = 10},
+  [708] = {.lex_state = 11},
+  [709] = {.lex_state = 12},
+  [710] = {.lex_state = 65},
+  [711] = {.lex_state = 67},
+  [712] = {.lex_state = 67},
+  [713] = {.lex_state = 68},
+  [714] = {.lex_state = 69},
+  [715] = {.lex_state = 65},
+  [716] = {.lex_state = 13},
+  [717] = {.lex_state = 13},
+  [718] = {.lex_state = 14},
+  [719] = {.lex_state = 65},
+  [720] = {.lex_state = 65},
+  [721] = {.lex_state = 65},
+  [722] = {.lex_state = 65},
+  [723] = {.lex_state = 65},
+  [724] = {.lex_state = 65},
+  [725] = {.lex_state = 65},
+  [726] = {.lex_state = 65},
+  [727] = {.lex_state = 65},
+  [728] = {.lex_state = 44},
+  [729] = {.lex_state = 65},
+  [730] = {.lex_state = 45},
+  [731] = {.lex_state = 65},
+  [732] = {.lex_state = 65},
+  [733] = {.lex_state = 65},
+  [734] = {.lex_state = 65},
+  [735] = {.lex_state = 65},
+  [736] = {.lex_state = 65},
+  [737] = {.lex_state = 7},
+  [738] = {.lex_state = 7},
+  [739] = {.lex_state = 7},
+  [740] = {.lex_state = 7},
+  [741] = {.lex_state = 55},
+  [742] = {.lex_state = 56},
+  [743] = {.lex_state = 7},
+  [744] = {.lex_state = 56},
+  [745] = {.lex_state = 7},
+  [746] = {.lex_state = 7},
+  [747] = {.lex_state = 56},
+  [748] = {.lex_state = 7},
+  [749] = {.lex_state = 7},
+  [750] = {.lex_state = 7},
+  [751] = {.lex_state = 56},
+  [752] = {.lex_state = 7},
+  [753] = {.lex_state = 7},
+  [754] = {.lex_state = 7},
+  [755] = {.lex_state = 7},
+  [756] = {.lex_state = 56},
+  [757] = {.lex_state = 7},
+  [758] = {.lex_state = 7},
+  [759] = {.lex_state = 7},
+  [760] = {.lex_state = 7},
+  [761] = {.lex_state = 7},
+  [762] = {.lex_state = 53},
+  [763] = {.lex_state = 55},
+  [764] = {.lex_state = 56},
+  [765] = {.lex_state = 7},
+  [766] = {.lex_state = 55},
+  [767] = {.lex_state = 56},
+  [768] = {.lex_state = 7},
+  [769] = {.lex_state = 56},
+  [770] = {.lex_state = 7},
+  [771] = {.lex_state = 7},
+  [772] = {.lex_state = 55},
+  [773] = {.lex_state = 56},
+  [774] = {.lex_state = 7},
+  [775] = {.lex_state = 56},
+  [776] = {.lex_state = 7},
+  [777] = {.lex_state = 7},
+  [778] = {.lex_state = 56},
+  [779] = {.lex_state = 7},
+  [780] = {.lex_state = 7},
+  [781] = {.lex_state = 7},
+  [782] = {.lex_state = 55},
+  [783] = {.lex_state = 56},
+  [784] = {.lex_state = 7},
+  [785] = {.lex_state = 56},
+  [786] = {.lex_state = 7},
+  [787] = {.lex_state = 7},
+  [788] = {.lex_state = 56},
+  [789] = {.lex_state = 7},
+  [790] = {.lex_state = 7},
+  [791] = {.lex_state = 7},
+  [792] = {.lex_state = 56},
+  [793] = {.lex_state = 7},
+  [794] = {.lex_state = 7},
+  [795] = {.lex_state = 7},
+  [796] = {.lex_state = 7},
+  [797] = {.lex_state = 53},
+  [798] = {.lex_state = 55},
+  [799] = {.lex_state = 56},
+  [800] = {.lex_state = 7},
+  [801] = {.lex_state = 55},
+  [802] = {.lex_state = 56},
+  [803] = {.lex_state = 7},
+  [804] = {.lex_state = 56},
+  [805] = {.lex_state = 7},
+  [806] = {.lex_state = 7},
+  [807] = {.lex_state = 55},
+  [808] = {.lex_state = 56},
+  [809] = {.lex_state = 7},
+  [810] = {.lex_state = 56},
+  [811] = {.lex_state = 7},
+  [812] = {.lex_state = 7},
+  [813] = {.lex_state = 56},
+  [814] = {.lex_state = 7},
+  [815] = {.lex_state = 7},
+  [816] = {.lex_state = 7},
+  [817] = {.lex_state = 55},
+  [818] = {.lex_state = 56},
+  [819] = {.lex_state = 7},
+  [820] = {.lex_state = 56},
+  [821] = {.lex_state = 7},
+  [822] = {.lex_state = 7},
+  [823] = {.lex_state = 56},
+  [824] = {.lex_state = 7},
+  [825] = {.lex_state = 7},
+  [826] = {.lex_state = 7},
+  [827] = {.lex_state = 56},
+  [828] = {.lex_state = 7},
+  [829] = {.lex_state = 7},
+  [830] = {.lex_state = 7},
+  [831] = {.lex_state = 7},
+  [832] = {.lex_state = 52},
+  [833] = {.lex_state = 53},
+  [834] = {.lex_state = 55},
+  [835] = {.lex_state = 56},
+  [836] = {.lex_state = 7},
+  [837] = {.lex_state = 53},
+  [838] = {.lex_state = 55},
+  [839] = {.lex_state = 56},
+  [840] = {.lex_state = 7},
+  [841] = {.lex_state = 55},
+  [842] = {.lex_state = 56},
+  [843] = {.lex_state = 7},
+  [844] = {.lex_state = 56},
+  [845] = {.lex_state = 7},
+  [846] = {.lex_state = 7},
+  [847] = {.lex_state = 53},
+  [848] = {.lex_state = 55},
+  [849] = {.lex_state = 56},
+  [850] = {.lex_state = 7},
+  [851] = {.lex_state = 55},
+  [852] = {.lex_state = 56},
+  [853] = {.lex_state = 7},
+  [854] = {.lex_state = 56},
+  [855] = {.lex_state = 7},
+  [856] = {.lex_state = 7},
+  [857] = {.lex_state = 55},
+  [858] = {.lex_state = 56},
+  [859] = {.lex_state = 7},
+  [860] = {.lex_state = 56},
+  [861] = {.lex_state = 7},
+  [862] = {.lex_state = 7},
+  [863] = {.lex_state = 56},
+  [864] = {.lex_state = 7},
+  [865] = {.lex_state = 7},
+  [866] = {.lex_state = 7},
+  [867] = {.lex_state = 27},
+  [868] = {.lex_state = 57},
+  [869] = {.lex_state = 57},
+  [870] = {.lex_state = 57},
+  [871] = {.lex_state = 57},
+  [872] = {.lex_state = 57},
+  [873] = {.lex_state = 57},
+  [874] = {.lex_state = 57},
+  [875] = {.lex_state = 57},
+  [876] = {.lex_state = 43},
+  [877] = {.lex_state = 28},
+  [878] = {.lex_state = 29},
+  [879] = {.lex_state = 30},
+  [880] = {.lex_state = 36},
+  [881] = {.lex_state = 65},
+  [882] = {.lex_state = 65},
+  [883] = {.lex_state = 65},
+  [884] = {.lex_state = 43},
+  [885] = {.lex_state = 28},
+  [886] = {.lex_state = 29},
+  [887] = {.lex_state = 30},
+  [888] = {.lex_state = 65},
+  [889] = {.lex_state = 65},
+  [890] = {.lex_state = 65},
+  [891] = {.lex_state = 32},
+  [892] = {.lex_state = 65},
+  [893] = {.lex_state = 65},
+  [894] = {.lex_state = 43},
+  [895] = {.lex_state = 65},
+  [896] = {.lex_state = 56},
+  [897] = {.lex_state = 7},
+  [898] = {.lex_state = 7},
+  [899] = {.lex_state = 7},
+  [900] = {.lex_state = 7},
+  [901] = {.lex_state = 7},
+  [902] = {.lex_state = 7},
+  [903] = {.lex_state = 55},
+  [904] = {.lex_state = 56},
+  [905] = {.lex_state = 7},
+  [906] = {.lex_state = 56},
+  [907] = {.lex_state = 7},
+  [908] = {.lex_state = 7},
+  [909] = {.lex_state = 56},
+  [910] = {.lex_state = 7},
+  [911] = {.lex_state = 7},
+  [912] = {.lex_state = 7},
+  [913] = {.lex_state = 56},
+  [914] = {.lex_state = 7},
+  [915] = {.lex_state = 7},
+  [916] = {.lex_state = 7},
+  [917] = {.lex_state = 7},
+  [918] = {.lex_state = 56},
+  [919] = {.lex_state = 7},
+  [920] = {.lex_state = 7},
+  [921] = {.lex_state = 7},
+  [922] = {.lex_state = 7},
+  [923] = {.lex_state = 7},
+  [924] = {.lex_state = 55},
+  [925] = {.lex_state = 56},
+  [926] = {.lex_state = 7},
+  [927] = {.lex_state = 56},
+  [928] = {.lex_state = 7},
+  [929] = {.lex_state = 7},
+  [930] = {.lex_state = 56},
+  [931] = {.lex_state = 7},
+  [932] = {.lex_state = 7},
+  [933] = {.lex_state = 7},
+  [934] = {.lex_state = 56},
+  [935] = {.lex_state = 7},
+  [936] = {.lex_state = 7},
+  [937] = {.lex_state = 7},
+  [938] = {.lex_state = 7},
+  [939] = {.lex_state = 56},
+  [940] = {.lex_state = 7},
+  [941] = {.lex_state = 7},
+  [942] = {.lex_state = 7},
+  [943] = {.lex_state = 7},
+  [944] = {.lex_state = 7},
+  [945] = {.lex_state = 53},
+  [946] = {.lex_state = 55},
+  [947] = {.lex_state = 56},
+  [948] = {.lex_state = 7},
+  [949] = {.lex_state = 55},
+  [950] = {.lex_state = 56},
+  [951] = {.lex_state = 7},
+  [952] = {.lex_state = 56},
+  [953] = {.lex_state = 7},
+  [954] = {.lex_state = 7},
+  [955] = {.lex_state = 55},
+  [956] = {.lex_state = 56},
+  [957] = {.lex_state = 7},
+  [958] = {.lex_state = 56},
+  [959] = {.lex_state = 7},
+  [960] = {.lex_state = 7},
+  [961] = {.lex_state = 56},
+  [962] = {.lex_state = 7},
+  [963] = {.lex_state = 7},
+  [964] = {.lex_state = 7},
+  [965] = {.lex_state = 55},
+  [966] = {.lex_state = 56},
+  [967] = {.lex_state = 7},
+  [968] = {.lex_state = 56},
+  [969] = {.lex_state = 7},
+  [970] = {.lex_state = 7},
+  [971] = {.lex_state = 56},
+  [972] = {.lex_state = 7},
+  [973] = {.lex_state = 7},
+  [974] = {.lex_state = 7},
+  [975] = {.lex_state = 56},
+  [976] = {.lex_state = 7},
+  [977] = {.lex_state = 7},
+  [978] = {.lex_state = 7},
+  [979] = {.lex_state = 7},
+  [980] = {.lex_state = 57},
+  [981] = {.lex_state = 57},
+  [982] = {.lex_state = 64},
+  [983] = {.lex_state = 64},
+  [984] = {.lex_state = 64},
+  [985] = {.lex_state = 27},
+  [986] = {.lex_state = 65},
+  [987] = {.lex_state = 65},
+  [988] = {.lex_state = 65},
+  [989] = {.lex_state = 65},
+  [990] = {.lex_state = 65},
+  [991] = {.lex_state = 65},
+  [992] = {.lex_state = 65},
+  [993] = {.lex_state = 65},
+  [994] = {.lex_state = 43},
+  [995] = {.lex_state = 7},
+  [996] = {.lex_state = 56},
+  [997] = {.lex_state = 7},
+  [998] = {.lex_state = 7},
+  [999] = {.lex_state = 7},
+  [1000] = {.lex_state = 7},
+  [1001] = {.lex_state = 7},
+  [1002] = {.lex_state = 7},
+  [1003] = {.lex_state = 56},
+  [1004] = {.lex_state = 7},
+  [1005] = {.lex_state = 7},
+  [1006] = {.lex_state = 7},
+  [1007] = {.lex_state = 7},
+  [1008] = {.lex_state = 7},
+  [1009] = {.lex_state = 7},
+  [1010] = {.lex_state = 55},
+  [1011] = {.lex_state = 56},
+  [1012] = {.lex_state = 7},
+  [1013] = {.lex_state = 56},
+  [1014] = {.lex_state = 7},
+  [1015] = {.lex_state = 7},
+  [1016] = {.lex_state = 56},
+  [1017] = {.lex_state = 7},
+  [1018] = {.lex_state = 7},
+  [1019] = {.lex_state = 7},
+  [1020] = {.lex_state = 56},
+  [1021] = {.lex_state = 7},
+  [1022] = {.lex_state = 7},
+  [1023] = {.lex_state = 7},
+  [1024] = {.lex_state = 7},
+  [1025] = {.lex_state = 56},
+  [1026] = {.lex_state = 7},
+  [1027] = {.lex_state = 7},
+  [1028] = {.lex_state = 7},
+  [1029] = {.lex_state = 7},
+  [1030] = {.lex_state = 7},
+  [1031] = {.lex_state = 65},
+  [1032] = {.lex_state = 65},
+  [1033] = {.lex_state = 7},
+  [1034] = {.lex_state = 7},
+  [1035] = {.lex_state = 56},
+  [1036] = {.lex_state = 7},
+  [1037] = {.lex_state = 7},
+  [1038] = {.lex_state = 7},
+  [1039] = {.lex_state = 7},
+  [1040] = {.lex_state = 7},
+  [1041] = {.lex_state = 7},
+  [1042] = {.lex_state = 7},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [STATE(0)] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
+    [aux_sym_explain_token1] = ACTIONS(1),
+    [sym_analyze] = ACTIONS(1),
+    [aux_sym_union_clause_token1] = ACTIONS(1),
+    [sym_union_all] = ACTIONS(1),
+    [aux_sym_select_statement_token1] = ACTIONS(1),
+    [aux_sym_select_statement_token2] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_STAR] = ACTIONS(1),
+    [aux_sym_select_expression_token1] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_DQUOTE] = ACTIONS(1),
+    [aux_sym_column_name_token1] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [aux_sym_column_name_token2] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_BQUOTE] = ACTIONS(1),
+    [aux_sym_column_name_token3] = ACTIONS(1),
+    [aux_sym_where_clause_token1] = ACTIONS(1),
+    [aux_sym_group_by_clause_token1] = ACTIONS(1),
+    [aux_sym_group_by_clause_token2] = ACTIONS(1),
+    [aux_sym_having_clause_token1] = ACTIONS(1),
+    [aux_sym_order_by_clause_token1] = ACTIONS(1),
+    [aux_sym_sort_direction_token1] = ACTIONS(1),
+    [aux_sym_sort_direction_token2] = ACTIONS(1),
+    [aux_sym_sample_clause_token1] = ACTIONS(1),
+    [aux_sym_limit_clause_token1] = ACTIONS(1),
+    [aux_sym_limit_clause_token2] = ACTIONS(1),
+    [aux_sym_offset_clause_token1] = ACTIONS(1),
+    [aux_sym_offset_clause_token2] = ACTIONS(1),
+    [aux_sym_offset_clause_token3] = ACTIONS(1),
+    [aux_sym_fetch_clause_token1] = ACTIONS(1),
+    [aux_sym_fetch_clause_token2] = ACTIONS(1),
+    [aux_sym_fetch_clause_token3] = ACTIONS(1),
+    [aux_sym_fetch_clause_token4] = ACTIONS(1),
+    [aux_sym_or_expression_token1] = ACTIONS(1),
+    [aux_sym_and_expression_token1] = ACTIONS(1),
+    [aux_sym_not_expression_token1] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_BANG_EQ] = ACTIONS(1),
+    [anon_sym_LT_GT] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [anon_sym_GT_EQ] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_LT_EQ] = ACTIONS(1),
+    [aux_sym_comparison_expression_token1] = ACTIONS(1),
+    [aux_sym_comparison_expression_token2] = ACTIONS(1),
+    [aux_sym_comparison_expression_token3] = ACTIONS(1),
+    [aux_sym_comparison_expression_token4] = ACTIONS(1),
+    [aux_sym_like_escape_token1] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [anon_sym_SLASH] = ACTIONS(1),
+    [anon_sym_PERCENT] = ACTIONS(1),
+    [aux_sym_cast_expression_token1] = ACTIONS(1),
+    [aux_sym_case_expression_token1] = ACTIONS(1),
+    [aux_sym_case_expression_token2] = ACTIONS(1),
+    [aux_sym_when_clause_token1] = ACTIONS(1),
+    [aux_sym_when_clause_token2] = ACTIONS(1),
+    [aux_sym_else_clause_token1] = ACTIONS(1),
+    [anon_sym_PIPE_PIPE] = ACTIONS(1),
+    [aux_sym_literal_token1] = ACTIONS(1),
+    [anon_sym_SQUOTE] = ACTIONS(1),
+    [aux_sym_string_literal_token1] = ACTIONS(1),
+    [aux_sym_date_literal_token1] = ACTIONS(1),
+    [aux_sym_timestamp_literal_token1] = ACTIONS(1),
+    [sym_number_literal] = ACTIONS(1),
+    [aux_sym_boolean_literal_token1] = ACTIONS(1),
+    [aux_sym_boolean_literal_token2] = ACTIONS(1),
+    [sym__identifier] = ACTIONS(1),
+  },
+  [STATE(1)] = {
+    [sym_source_file] = STATE(4),
+    [sym_explain] = STATE(5),
+    [sym__statement] = STATE(6),
+    [sym_union_statement] = STATE(7),
+    [sym_select_statement] = STATE(8),
+    [aux_sym_explain_token1] = ACTIONS(3),
+    [aux_sym_select_statement_token1] = ACTIONS(5),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 2,
+    ACTIONS(7), 1,
+      sym_analyze,
+    ACTIONS(9), 1,
+      aux_sym_select_statement_token1,
+  [7] = 39,
+    ACTIONS(11), 1,
+      anon_sym_STAR,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(27), 1,
+      sym_select_list,
+    STATE(28), 1,
+      sym_column_list,
+    STATE(29), 1,
+      sym_select_expression,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(34), 1,
+      sym_expression,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+  [125] = 1,
+    ACTIONS(45), 1,
+      ts_builtin_sym_end,
+  [129] = 4,
+    ACTIONS(5), 1,
+      aux_sym_select_statement_token1,
+    STATE(7), 1,
+      sym_union_statement,
+    STATE(8), 1,
+      sym_select_statement,
+    STATE(49), 1,
+      sym__statement,
+  [142] = 2,
+    ACTIONS(47), 1,
+      ts_builtin_sym_end,
+    ACTIONS(49), 1,
+      anon_sym_SEMI,
+  [149] = 1,
+    ACTIONS(51), 2,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+  [154] = 4,
+    ACTIONS(55), 1,
+      aux_sym_union_clause_token1,
+    STATE(52), 1,
+      sym_union_clause,
+    STATE(53), 1,
+      aux_sym_union_statement_repeat1,
+    ACTIONS(53), 2,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+  [168] = 1,
+    ACTIONS(57), 1,
+      aux_sym_select_statement_token1,
+  [172] = 1,
+    ACTIONS(59), 2,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+  [177] = 35,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(54), 1,
+      sym_expression,
+  [283] = 1,
+    ACTIONS(61), 1,
+      aux_sym_column_name_token1,
+  [287] = 1,
+    ACTIONS(63), 1,
+      aux_sym_column_name_token2,
+  [291] = 1,
+    ACTIONS(65), 1,
+      aux_sym_column_name_token3,
+  [295] = 32,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(58), 1,
+      sym_not_expression,
+  [392] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(59), 1,
+      sym_primary_expression,
+  [483] = 1,
+    ACTIONS(67), 1,
+      anon_sym_LPAREN,
+  [487] = 3,
+    ACTIONS(69), 1,
+      aux_sym_when_clause_token1,
+    STATE(62), 1,
+      sym_when_clause,
+    STATE(63), 1,
+      aux_sym_case_expression_repeat1,
+  [497] = 1,
+    ACTIONS(71), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [529] = 1,
+    ACTIONS(73), 1,
+      aux_sym_string_literal_token1,
+  [533] = 1,
+    ACTIONS(75), 1,
+      aux_sym_string_literal_token1,
+  [537] = 1,
+    ACTIONS(77), 1,
+      aux_sym_string_literal_token1,
+  [541] = 1,
+    ACTIONS(79), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [573] = 1,
+    ACTIONS(81), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [605] = 1,
+    ACTIONS(83), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [637] = 3,
+    ACTIONS(87), 1,
+      anon_sym_LPAREN,
+    ACTIONS(89), 1,
+      anon_sym_DOT,
+    ACTIONS(85), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [675] = 1,
+    ACTIONS(91), 1,
+      aux_sym_select_statement_token2,
+  [679] = 1,
+    ACTIONS(93), 1,
+      aux_sym_select_statement_token2,
+  [683] = 3,
+    ACTIONS(95), 1,
+      aux_sym_select_statement_token2,
+    ACTIONS(97), 1,
+      anon_sym_COMMA,
+    STATE(69), 1,
+      aux_sym_column_list_repeat1,
+  [693] = 1,
+    ACTIONS(99), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [725] = 1,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+  [729] = 1,
+    ACTIONS(103), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [761] = 1,
+    ACTIONS(105), 1,
+      anon_sym_DOT,
+  [765] = 2,
+    ACTIONS(109), 1,
+      aux_sym_select_expression_token1,
+    ACTIONS(107), 2,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+  [773] = 1,
+    ACTIONS(111), 17,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [793] = 2,
+    ACTIONS(115), 1,
+      aux_sym_or_expression_token1,
+    ACTIONS(113), 8,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [807] = 2,
+    ACTIONS(119), 1,
+      aux_sym_and_expression_token1,
+    ACTIONS(117), 9,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [822] = 19,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(125), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(127), 1,
+      anon_sym_EQ,
+    ACTIONS(129), 1,
+      anon_sym_BANG_EQ,
+    ACTIONS(131), 1,
+      anon_sym_LT_GT,
+    ACTIONS(133), 1,
+      anon_sym_GT,
+    ACTIONS(135), 1,
+      anon_sym_GT_EQ,
+    ACTIONS(137), 1,
+      anon_sym_LT,
+    ACTIONS(139), 1,
+      anon_sym_LT_EQ,
+    ACTIONS(141), 1,
+      aux_sym_comparison_expression_token1,
+    ACTIONS(143), 1,
+      aux_sym_comparison_expression_token2,
+    ACTIONS(145), 1,
+      aux_sym_comparison_expression_token3,
+    ACTIONS(147), 1,
+      aux_sym_comparison_expression_token4,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(121), 10,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [889] = 1,
+    ACTIONS(159), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [921] = 1,
+    ACTIONS(161), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [953] = 1,
+    ACTIONS(163), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [985] = 1,
+    ACTIONS(165), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [1017] = 1,
+    ACTIONS(167), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [1049] = 1,
+    ACTIONS(169), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [1081] = 1,
+    ACTIONS(171), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [1113] = 1,
+    ACTIONS(173), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [1145] = 1,
+    ACTIONS(175), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [1177] = 1,
+    ACTIONS(177), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [1209] = 2,
+    ACTIONS(179), 1,
+      ts_builtin_sym_end,
+    ACTIONS(181), 1,
+      anon_sym_SEMI,
+  [1216] = 1,
+    ACTIONS(183), 1,
+      ts_builtin_sym_end,
+  [1220] = 3,
+    ACTIONS(5), 1,
+      aux_sym_select_statement_token1,
+    ACTIONS(185), 1,
+      sym_union_all,
+    STATE(95), 1,
+      sym_select_statement,
+  [1230] = 1,
+    ACTIONS(187), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [1236] = 4,
+    ACTIONS(55), 1,
+      aux_sym_union_clause_token1,
+    STATE(52), 1,
+      sym_union_clause,
+    STATE(96), 1,
+      aux_sym_union_statement_repeat1,
+    ACTIONS(189), 2,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+  [1250] = 1,
+    ACTIONS(191), 1,
+      anon_sym_RPAREN,
+  [1254] = 1,
+    ACTIONS(193), 1,
+      anon_sym_DQUOTE,
+  [1258] = 1,
+    ACTIONS(195), 1,
+      anon_sym_RBRACK,
+  [1262] = 1,
+    ACTIONS(197), 1,
+      anon_sym_BQUOTE,
+  [1266] = 1,
+    ACTIONS(199), 10,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [1279] = 1,
+    ACTIONS(201), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [1311] = 35,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(101), 1,
+      sym_expression,
+  [1417] = 35,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(102), 1,
+      sym_expression,
+  [1523] = 1,
+    ACTIONS(203), 3,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_else_clause_token1,
+  [1529] = 6,
+    ACTIONS(69), 1,
+      aux_sym_when_clause_token1,
+    ACTIONS(205), 1,
+      aux_sym_case_expression_token2,
+    ACTIONS(207), 1,
+      aux_sym_else_clause_token1,
+    STATE(62), 1,
+      sym_when_clause,
+    STATE(105), 1,
+      sym_else_clause,
+    STATE(106), 1,
+      aux_sym_case_expression_repeat1,
+  [1548] = 1,
+    ACTIONS(209), 1,
+      anon_sym_SQUOTE,
+  [1552] = 1,
+    ACTIONS(211), 1,
+      anon_sym_SQUOTE,
+  [1556] = 1,
+    ACTIONS(213), 1,
+      anon_sym_SQUOTE,
+  [1560] = 5,
+    ACTIONS(215), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(217), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(219), 1,
+      sym__identifier,
+    STATE(113), 1,
+      sym_file_name,
+    STATE(114), 1,
+      sym_string_literal,
+  [1576] = 37,
+    ACTIONS(11), 1,
+      anon_sym_STAR,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(34), 1,
+      sym_expression,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(115), 1,
+      sym_select_expression,
+  [1688] = 3,
+    ACTIONS(97), 1,
+      anon_sym_COMMA,
+    ACTIONS(221), 1,
+      aux_sym_select_statement_token2,
+    STATE(116), 1,
+      aux_sym_column_list_repeat1,
+  [1698] = 37,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    ACTIONS(223), 1,
+      anon_sym_STAR,
+    ACTIONS(225), 1,
+      anon_sym_RPAREN,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(119), 1,
+      sym_expression,
+  [1810] = 4,
+    ACTIONS(227), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(229), 1,
+      anon_sym_LBRACK,
+    ACTIONS(231), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(233), 1,
+      sym__identifier,
+  [1823] = 2,
+    ACTIONS(235), 1,
+      sym__identifier,
+    STATE(125), 1,
+      sym_alias,
+  [1830] = 34,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(126), 1,
+      sym_or_expression,
+  [1933] = 33,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(127), 1,
+      sym_and_expression,
+  [2033] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(128), 1,
+      sym_primary_expression,
+  [2124] = 3,
+    ACTIONS(237), 1,
+      aux_sym_comparison_expression_token1,
+    ACTIONS(239), 1,
+      aux_sym_comparison_expression_token2,
+    ACTIONS(241), 1,
+      aux_sym_comparison_expression_token3,
+  [2134] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(132), 1,
+      sym_primary_expression,
+  [2225] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(133), 1,
+      sym_primary_expression,
+  [2316] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(134), 1,
+      sym_primary_expression,
+  [2407] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(135), 1,
+      sym_primary_expression,
+  [2498] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(136), 1,
+      sym_primary_expression,
+  [2589] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(137), 1,
+      sym_primary_expression,
+  [2680] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(138), 1,
+      sym_primary_expression,
+  [2771] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(139), 1,
+      sym_primary_expression,
+  [2862] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(140), 1,
+      sym_primary_expression,
+  [2953] = 2,
+    ACTIONS(243), 1,
+      anon_sym_LPAREN,
+    STATE(142), 1,
+      sym_in_list,
+  [2960] = 4,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(245), 1,
+      aux_sym_not_expression_token1,
+    STATE(144), 1,
+      sym_boolean_literal,
+  [2973] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(145), 1,
+      sym_primary_expression,
+  [3064] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(146), 1,
+      sym_primary_expression,
+  [3155] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(147), 1,
+      sym_primary_expression,
+  [3246] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(148), 1,
+      sym_primary_expression,
+  [3337] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(149), 1,
+      sym_primary_expression,
+  [3428] = 1,
+    ACTIONS(247), 1,
+      ts_builtin_sym_end,
+  [3432] = 2,
+    ACTIONS(5), 1,
+      aux_sym_select_statement_token1,
+    STATE(150), 1,
+      sym_select_statement,
+  [3439] = 1,
+    ACTIONS(249), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [3445] = 4,
+    ACTIONS(253), 1,
+      aux_sym_union_clause_token1,
+    STATE(52), 1,
+      sym_union_clause,
+    STATE(96), 1,
+      aux_sym_union_statement_repeat1,
+    ACTIONS(251), 2,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+  [3459] = 1,
+    ACTIONS(256), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [3491] = 1,
+    ACTIONS(258), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [3523] = 1,
+    ACTIONS(260), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [3555] = 1,
+    ACTIONS(262), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [3587] = 1,
+    ACTIONS(264), 1,
+      aux_sym_select_expression_token1,
+  [3591] = 1,
+    ACTIONS(266), 1,
+      aux_sym_when_clause_token2,
+  [3595] = 1,
+    ACTIONS(268), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [3627] = 35,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(153), 1,
+      sym_expression,
+  [3733] = 1,
+    ACTIONS(270), 1,
+      aux_sym_case_expression_token2,
+  [3737] = 4,
+    ACTIONS(274), 1,
+      aux_sym_when_clause_token1,
+    STATE(62), 1,
+      sym_when_clause,
+    STATE(106), 1,
+      aux_sym_case_expression_repeat1,
+    ACTIONS(272), 2,
+      aux_sym_case_expression_token2,
+      aux_sym_else_clause_token1,
+  [3751] = 1,
+    ACTIONS(277), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [3783] = 1,
+    ACTIONS(279), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [3815] = 1,
+    ACTIONS(281), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [3847] = 1,
+    ACTIONS(283), 1,
+      aux_sym_column_name_token1,
+  [3851] = 1,
+    ACTIONS(285), 1,
+      aux_sym_string_literal_token1,
+  [3855] = 1,
+    ACTIONS(287), 12,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_where_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_sample_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [3870] = 19,
+    ACTIONS(291), 1,
+      anon_sym_COMMA,
+    ACTIONS(293), 1,
+      aux_sym_where_clause_token1,
+    ACTIONS(295), 1,
+      aux_sym_group_by_clause_token1,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(301), 1,
+      aux_sym_sample_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(166), 1,
+      sym_where_clause,
+    STATE(167), 1,
+      sym_group_by_clause,
+    STATE(168), 1,
+      sym_having_clause,
+    STATE(169), 1,
+      sym_order_by_clause,
+    STATE(170), 1,
+      sym_sample_clause,
+    STATE(171), 1,
+      sym_limit_clause,
+    STATE(172), 1,
+      sym_offset_clause,
+    STATE(173), 1,
+      sym_fetch_clause,
+    STATE(174), 1,
+      aux_sym_select_statement_repeat1,
+    ACTIONS(289), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [3930] = 1,
+    ACTIONS(309), 12,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_where_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_sample_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [3945] = 1,
+    ACTIONS(311), 2,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+  [3950] = 3,
+    ACTIONS(313), 1,
+      aux_sym_select_statement_token2,
+    ACTIONS(315), 1,
+      anon_sym_COMMA,
+    STATE(116), 1,
+      aux_sym_column_list_repeat1,
+  [3960] = 1,
+    ACTIONS(318), 1,
+      anon_sym_RPAREN,
+  [3964] = 1,
+    ACTIONS(320), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [3996] = 3,
+    ACTIONS(322), 1,
+      anon_sym_COMMA,
+    ACTIONS(324), 1,
+      anon_sym_RPAREN,
+    STATE(178), 1,
+      aux_sym_function_call_repeat1,
+  [4006] = 1,
+    ACTIONS(326), 1,
+      aux_sym_column_name_token1,
+  [4010] = 1,
+    ACTIONS(328), 1,
+      aux_sym_column_name_token2,
+  [4014] = 1,
+    ACTIONS(330), 1,
+      aux_sym_column_name_token3,
+  [4018] = 1,
+    ACTIONS(332), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [4050] = 1,
+    ACTIONS(334), 2,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+  [4055] = 1,
+    ACTIONS(336), 2,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+  [4060] = 1,
+    ACTIONS(338), 17,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4080] = 1,
+    ACTIONS(340), 9,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4092] = 1,
+    ACTIONS(342), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [4124] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(182), 1,
+      sym_primary_expression,
+  [4215] = 30,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(183), 1,
+      sym_primary_expression,
+  [4306] = 2,
+    ACTIONS(243), 1,
+      anon_sym_LPAREN,
+    STATE(184), 1,
+      sym_in_list,
+  [4313] = 7,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(344), 23,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4357] = 7,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(346), 23,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4401] = 7,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(348), 23,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4445] = 7,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(350), 23,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4489] = 7,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(352), 23,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4533] = 7,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(354), 23,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4577] = 7,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(356), 23,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4621] = 9,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(360), 1,
+      aux_sym_like_escape_token1,
+    STATE(186), 1,
+      sym_like_escape,
+    ACTIONS(358), 22,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4670] = 9,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(360), 1,
+      aux_sym_like_escape_token1,
+    STATE(187), 1,
+      sym_like_escape,
+    ACTIONS(362), 22,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [4719] = 12,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(188), 1,
+      sym_literal,
+  [4756] = 1,
+    ACTIONS(364), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [4788] = 3,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    STATE(189), 1,
+      sym_boolean_literal,
+  [4798] = 1,
+    ACTIONS(366), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [4830] = 4,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(368), 26,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [4868] = 4,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(370), 26,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [4906] = 1,
+    ACTIONS(372), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [4938] = 1,
+    ACTIONS(374), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [4970] = 4,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(376), 26,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [5008] = 1,
+    ACTIONS(378), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [5014] = 2,
+    ACTIONS(380), 1,
+      sym__identifier,
+    STATE(191), 1,
+      sym_type_name,
+  [5021] = 35,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(192), 1,
+      sym_expression,
+  [5127] = 1,
+    ACTIONS(382), 1,
+      aux_sym_case_expression_token2,
+  [5131] = 1,
+    ACTIONS(384), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [5163] = 1,
+    ACTIONS(386), 1,
+      anon_sym_DQUOTE,
+  [5167] = 1,
+    ACTIONS(388), 1,
+      anon_sym_SQUOTE,
+  [5171] = 5,
+    ACTIONS(215), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(217), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(219), 1,
+      sym__identifier,
+    STATE(114), 1,
+      sym_string_literal,
+    STATE(195), 1,
+      sym_file_name,
+  [5187] = 35,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(398), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(216), 1,
+      sym_expression,
+    STATE(217), 1,
+      sym_and_expression,
+    STATE(218), 1,
+      sym_not_expression,
+    STATE(219), 1,
+      sym_primary_expression,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+  [5293] = 1,
+    ACTIONS(422), 1,
+      aux_sym_group_by_clause_token2,
+  [5297] = 35,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(398), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(217), 1,
+      sym_and_expression,
+    STATE(218), 1,
+      sym_not_expression,
+    STATE(219), 1,
+      sym_primary_expression,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(231), 1,
+      sym_expression,
+  [5403] = 1,
+    ACTIONS(424), 1,
+      aux_sym_group_by_clause_token2,
+  [5407] = 1,
+    ACTIONS(426), 1,
+      sym_number_literal,
+  [5411] = 2,
+    ACTIONS(428), 1,
+      aux_sym_limit_clause_token2,
+    ACTIONS(430), 1,
+      sym_number_literal,
+  [5418] = 1,
+    ACTIONS(432), 1,
+      sym_number_literal,
+  [5422] = 2,
+    ACTIONS(434), 1,
+      aux_sym_fetch_clause_token2,
+    ACTIONS(436), 1,
+      aux_sym_fetch_clause_token3,
+  [5429] = 13,
+    ACTIONS(295), 1,
+      aux_sym_group_by_clause_token1,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(239), 1,
+      sym_group_by_clause,
+    STATE(240), 1,
+      sym_having_clause,
+    STATE(241), 1,
+      sym_order_by_clause,
+    STATE(242), 1,
+      sym_limit_clause,
+    STATE(243), 1,
+      sym_offset_clause,
+    STATE(244), 1,
+      sym_fetch_clause,
+    ACTIONS(438), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [5471] = 11,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(245), 1,
+      sym_having_clause,
+    STATE(246), 1,
+      sym_order_by_clause,
+    STATE(247), 1,
+      sym_limit_clause,
+    STATE(248), 1,
+      sym_offset_clause,
+    STATE(249), 1,
+      sym_fetch_clause,
+    ACTIONS(440), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [5507] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(250), 1,
+      sym_order_by_clause,
+    STATE(251), 1,
+      sym_limit_clause,
+    STATE(252), 1,
+      sym_offset_clause,
+    STATE(253), 1,
+      sym_fetch_clause,
+    ACTIONS(442), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [5537] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(254), 1,
+      sym_limit_clause,
+    STATE(255), 1,
+      sym_offset_clause,
+    STATE(256), 1,
+      sym_fetch_clause,
+    ACTIONS(444), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [5561] = 15,
+    ACTIONS(293), 1,
+      aux_sym_where_clause_token1,
+    ACTIONS(295), 1,
+      aux_sym_group_by_clause_token1,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(257), 1,
+      sym_where_clause,
+    STATE(258), 1,
+      sym_group_by_clause,
+    STATE(259), 1,
+      sym_having_clause,
+    STATE(260), 1,
+      sym_order_by_clause,
+    STATE(261), 1,
+      sym_limit_clause,
+    STATE(262), 1,
+      sym_offset_clause,
+    STATE(263), 1,
+      sym_fetch_clause,
+    ACTIONS(446), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [5609] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(264), 1,
+      sym_offset_clause,
+    STATE(265), 1,
+      sym_fetch_clause,
+    ACTIONS(448), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [5627] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(266), 1,
+      sym_fetch_clause,
+    ACTIONS(450), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [5639] = 1,
+    ACTIONS(452), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [5645] = 19,
+    ACTIONS(291), 1,
+      anon_sym_COMMA,
+    ACTIONS(293), 1,
+      aux_sym_where_clause_token1,
+    ACTIONS(295), 1,
+      aux_sym_group_by_clause_token1,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(301), 1,
+      aux_sym_sample_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(267), 1,
+      sym_where_clause,
+    STATE(268), 1,
+      sym_group_by_clause,
+    STATE(269), 1,
+      sym_having_clause,
+    STATE(270), 1,
+      sym_order_by_clause,
+    STATE(271), 1,
+      sym_sample_clause,
+    STATE(272), 1,
+      sym_limit_clause,
+    STATE(273), 1,
+      sym_offset_clause,
+    STATE(274), 1,
+      sym_fetch_clause,
+    STATE(275), 1,
+      aux_sym_select_statement_repeat1,
+    ACTIONS(454), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [5705] = 1,
+    ACTIONS(456), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [5737] = 35,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(276), 1,
+      sym_expression,
+  [5843] = 1,
+    ACTIONS(458), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [5875] = 3,
+    ACTIONS(322), 1,
+      anon_sym_COMMA,
+    ACTIONS(460), 1,
+      anon_sym_RPAREN,
+    STATE(278), 1,
+      aux_sym_function_call_repeat1,
+  [5885] = 1,
+    ACTIONS(462), 1,
+      anon_sym_DQUOTE,
+  [5889] = 1,
+    ACTIONS(464), 1,
+      anon_sym_RBRACK,
+  [5893] = 1,
+    ACTIONS(466), 1,
+      anon_sym_BQUOTE,
+  [5897] = 9,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(360), 1,
+      aux_sym_like_escape_token1,
+    STATE(282), 1,
+      sym_like_escape,
+    ACTIONS(468), 22,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [5946] = 9,
+    ACTIONS(123), 1,
+      anon_sym_STAR,
+    ACTIONS(149), 1,
+      anon_sym_PLUS,
+    ACTIONS(151), 1,
+      anon_sym_DASH,
+    ACTIONS(153), 1,
+      anon_sym_SLASH,
+    ACTIONS(155), 1,
+      anon_sym_PERCENT,
+    ACTIONS(157), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(360), 1,
+      aux_sym_like_escape_token1,
+    STATE(283), 1,
+      sym_like_escape,
+    ACTIONS(470), 22,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+  [5995] = 1,
+    ACTIONS(472), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [6027] = 2,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    STATE(284), 1,
+      sym_string_literal,
+  [6034] = 1,
+    ACTIONS(474), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [6066] = 1,
+    ACTIONS(476), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [6098] = 3,
+    ACTIONS(478), 1,
+      anon_sym_COMMA,
+    ACTIONS(480), 1,
+      anon_sym_RPAREN,
+    STATE(287), 1,
+      aux_sym_in_list_repeat1,
+  [6108] = 1,
+    ACTIONS(482), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [6140] = 1,
+    ACTIONS(484), 1,
+      anon_sym_RPAREN,
+  [6144] = 1,
+    ACTIONS(486), 1,
+      anon_sym_RPAREN,
+  [6148] = 1,
+    ACTIONS(488), 3,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_else_clause_token1,
+  [6154] = 1,
+    ACTIONS(490), 12,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_where_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_sample_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [6169] = 1,
+    ACTIONS(277), 12,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_where_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_sample_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [6184] = 1,
+    ACTIONS(492), 12,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_where_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_sample_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [6199] = 35,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(289), 1,
+      sym_expression,
+  [6305] = 1,
+    ACTIONS(494), 1,
+      aux_sym_column_name_token1,
+  [6309] = 1,
+    ACTIONS(496), 1,
+      aux_sym_column_name_token2,
+  [6313] = 1,
+    ACTIONS(498), 1,
+      aux_sym_column_name_token3,
+  [6317] = 32,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(398), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(219), 1,
+      sym_primary_expression,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(293), 1,
+      sym_not_expression,
+  [6414] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(294), 1,
+      sym_primary_expression,
+  [6505] = 1,
+    ACTIONS(500), 1,
+      anon_sym_LPAREN,
+  [6509] = 3,
+    ACTIONS(69), 1,
+      aux_sym_when_clause_token1,
+    STATE(62), 1,
+      sym_when_clause,
+    STATE(296), 1,
+      aux_sym_case_expression_repeat1,
+  [6519] = 1,
+    ACTIONS(71), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [6552] = 1,
+    ACTIONS(502), 1,
+      aux_sym_string_literal_token1,
+  [6556] = 1,
+    ACTIONS(504), 1,
+      aux_sym_string_literal_token1,
+  [6560] = 1,
+    ACTIONS(506), 1,
+      aux_sym_string_literal_token1,
+  [6564] = 1,
+    ACTIONS(79), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [6597] = 1,
+    ACTIONS(81), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [6630] = 1,
+    ACTIONS(83), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [6663] = 3,
+    ACTIONS(87), 1,
+      anon_sym_LPAREN,
+    ACTIONS(89), 1,
+      anon_sym_DOT,
+    ACTIONS(85), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [6702] = 1,
+    ACTIONS(99), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [6735] = 1,
+    ACTIONS(508), 1,
+      anon_sym_LPAREN,
+  [6739] = 1,
+    ACTIONS(103), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [6772] = 1,
+    ACTIONS(510), 1,
+      anon_sym_DOT,
+  [6776] = 1,
+    ACTIONS(512), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [6788] = 2,
+    ACTIONS(514), 1,
+      aux_sym_or_expression_token1,
+    ACTIONS(113), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [6803] = 2,
+    ACTIONS(516), 1,
+      aux_sym_and_expression_token1,
+    ACTIONS(117), 10,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+  [6819] = 19,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(520), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(522), 1,
+      anon_sym_EQ,
+    ACTIONS(524), 1,
+      anon_sym_BANG_EQ,
+    ACTIONS(526), 1,
+      anon_sym_LT_GT,
+    ACTIONS(528), 1,
+      anon_sym_GT,
+    ACTIONS(530), 1,
+      anon_sym_GT_EQ,
+    ACTIONS(532), 1,
+      anon_sym_LT,
+    ACTIONS(534), 1,
+      anon_sym_LT_EQ,
+    ACTIONS(536), 1,
+      aux_sym_comparison_expression_token1,
+    ACTIONS(538), 1,
+      aux_sym_comparison_expression_token2,
+    ACTIONS(540), 1,
+      aux_sym_comparison_expression_token3,
+    ACTIONS(542), 1,
+      aux_sym_comparison_expression_token4,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(121), 11,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+  [6887] = 1,
+    ACTIONS(159), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [6920] = 1,
+    ACTIONS(161), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [6953] = 1,
+    ACTIONS(163), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [6986] = 1,
+    ACTIONS(165), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [7019] = 1,
+    ACTIONS(167), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [7052] = 1,
+    ACTIONS(169), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [7085] = 1,
+    ACTIONS(171), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [7118] = 1,
+    ACTIONS(173), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [7151] = 1,
+    ACTIONS(175), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [7184] = 1,
+    ACTIONS(177), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [7217] = 6,
+    ACTIONS(554), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(556), 1,
+      anon_sym_LBRACK,
+    ACTIONS(558), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(560), 1,
+      sym__identifier,
+    STATE(326), 1,
+      sym_column_name,
+    STATE(327), 1,
+      sym_table_qualifier,
+  [7236] = 1,
+    ACTIONS(562), 7,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [7246] = 36,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(572), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(348), 1,
+      sym_order_by_item,
+    STATE(349), 1,
+      sym_expression,
+    STATE(350), 1,
+      sym_or_expression,
+    STATE(351), 1,
+      sym_and_expression,
+    STATE(352), 1,
+      sym_not_expression,
+    STATE(353), 1,
+      sym_primary_expression,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+  [7355] = 1,
+    ACTIONS(596), 10,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_where_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [7368] = 1,
+    ACTIONS(598), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [7376] = 1,
+    ACTIONS(600), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [7384] = 3,
+    ACTIONS(604), 1,
+      aux_sym_offset_clause_token2,
+    ACTIONS(606), 1,
+      aux_sym_offset_clause_token3,
+    ACTIONS(602), 4,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [7397] = 1,
+    ACTIONS(608), 1,
+      sym_number_literal,
+  [7401] = 1,
+    ACTIONS(610), 1,
+      sym_number_literal,
+  [7405] = 11,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(368), 1,
+      sym_having_clause,
+    STATE(369), 1,
+      sym_order_by_clause,
+    STATE(370), 1,
+      sym_limit_clause,
+    STATE(371), 1,
+      sym_offset_clause,
+    STATE(372), 1,
+      sym_fetch_clause,
+    ACTIONS(612), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7441] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(373), 1,
+      sym_order_by_clause,
+    STATE(374), 1,
+      sym_limit_clause,
+    STATE(375), 1,
+      sym_offset_clause,
+    STATE(376), 1,
+      sym_fetch_clause,
+    ACTIONS(614), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7471] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(377), 1,
+      sym_limit_clause,
+    STATE(378), 1,
+      sym_offset_clause,
+    STATE(379), 1,
+      sym_fetch_clause,
+    ACTIONS(616), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7495] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(380), 1,
+      sym_offset_clause,
+    STATE(381), 1,
+      sym_fetch_clause,
+    ACTIONS(618), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7513] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(382), 1,
+      sym_fetch_clause,
+    ACTIONS(620), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7525] = 1,
+    ACTIONS(622), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7531] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(383), 1,
+      sym_order_by_clause,
+    STATE(384), 1,
+      sym_limit_clause,
+    STATE(385), 1,
+      sym_offset_clause,
+    STATE(386), 1,
+      sym_fetch_clause,
+    ACTIONS(624), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7561] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(387), 1,
+      sym_limit_clause,
+    STATE(388), 1,
+      sym_offset_clause,
+    STATE(389), 1,
+      sym_fetch_clause,
+    ACTIONS(626), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7585] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(390), 1,
+      sym_offset_clause,
+    STATE(391), 1,
+      sym_fetch_clause,
+    ACTIONS(628), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7603] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(392), 1,
+      sym_fetch_clause,
+    ACTIONS(630), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7615] = 1,
+    ACTIONS(632), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7621] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(393), 1,
+      sym_limit_clause,
+    STATE(394), 1,
+      sym_offset_clause,
+    STATE(395), 1,
+      sym_fetch_clause,
+    ACTIONS(634), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7645] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(396), 1,
+      sym_offset_clause,
+    STATE(397), 1,
+      sym_fetch_clause,
+    ACTIONS(636), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7663] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(398), 1,
+      sym_fetch_clause,
+    ACTIONS(638), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7675] = 1,
+    ACTIONS(640), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7681] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(399), 1,
+      sym_offset_clause,
+    STATE(400), 1,
+      sym_fetch_clause,
+    ACTIONS(642), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7699] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(401), 1,
+      sym_fetch_clause,
+    ACTIONS(644), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7711] = 1,
+    ACTIONS(646), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7717] = 13,
+    ACTIONS(295), 1,
+      aux_sym_group_by_clause_token1,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(402), 1,
+      sym_group_by_clause,
+    STATE(403), 1,
+      sym_having_clause,
+    STATE(404), 1,
+      sym_order_by_clause,
+    STATE(405), 1,
+      sym_limit_clause,
+    STATE(406), 1,
+      sym_offset_clause,
+    STATE(407), 1,
+      sym_fetch_clause,
+    ACTIONS(648), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7759] = 11,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(408), 1,
+      sym_having_clause,
+    STATE(409), 1,
+      sym_order_by_clause,
+    STATE(410), 1,
+      sym_limit_clause,
+    STATE(411), 1,
+      sym_offset_clause,
+    STATE(412), 1,
+      sym_fetch_clause,
+    ACTIONS(650), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7795] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(413), 1,
+      sym_order_by_clause,
+    STATE(414), 1,
+      sym_limit_clause,
+    STATE(415), 1,
+      sym_offset_clause,
+    STATE(416), 1,
+      sym_fetch_clause,
+    ACTIONS(652), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7825] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(417), 1,
+      sym_limit_clause,
+    STATE(418), 1,
+      sym_offset_clause,
+    STATE(419), 1,
+      sym_fetch_clause,
+    ACTIONS(654), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7849] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(420), 1,
+      sym_offset_clause,
+    STATE(421), 1,
+      sym_fetch_clause,
+    ACTIONS(656), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7867] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(422), 1,
+      sym_fetch_clause,
+    ACTIONS(658), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7879] = 1,
+    ACTIONS(660), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7885] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(423), 1,
+      sym_fetch_clause,
+    ACTIONS(662), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7897] = 1,
+    ACTIONS(664), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7903] = 1,
+    ACTIONS(666), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7909] = 13,
+    ACTIONS(295), 1,
+      aux_sym_group_by_clause_token1,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(424), 1,
+      sym_group_by_clause,
+    STATE(425), 1,
+      sym_having_clause,
+    STATE(426), 1,
+      sym_order_by_clause,
+    STATE(427), 1,
+      sym_limit_clause,
+    STATE(428), 1,
+      sym_offset_clause,
+    STATE(429), 1,
+      sym_fetch_clause,
+    ACTIONS(668), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7951] = 11,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(430), 1,
+      sym_having_clause,
+    STATE(431), 1,
+      sym_order_by_clause,
+    STATE(432), 1,
+      sym_limit_clause,
+    STATE(433), 1,
+      sym_offset_clause,
+    STATE(434), 1,
+      sym_fetch_clause,
+    ACTIONS(670), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [7987] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(435), 1,
+      sym_order_by_clause,
+    STATE(436), 1,
+      sym_limit_clause,
+    STATE(437), 1,
+      sym_offset_clause,
+    STATE(438), 1,
+      sym_fetch_clause,
+    ACTIONS(672), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [8017] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(439), 1,
+      sym_limit_clause,
+    STATE(440), 1,
+      sym_offset_clause,
+    STATE(441), 1,
+      sym_fetch_clause,
+    ACTIONS(674), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [8041] = 15,
+    ACTIONS(293), 1,
+      aux_sym_where_clause_token1,
+    ACTIONS(295), 1,
+      aux_sym_group_by_clause_token1,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(442), 1,
+      sym_where_clause,
+    STATE(443), 1,
+      sym_group_by_clause,
+    STATE(444), 1,
+      sym_having_clause,
+    STATE(445), 1,
+      sym_order_by_clause,
+    STATE(446), 1,
+      sym_limit_clause,
+    STATE(447), 1,
+      sym_offset_clause,
+    STATE(448), 1,
+      sym_fetch_clause,
+    ACTIONS(676), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [8089] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(449), 1,
+      sym_offset_clause,
+    STATE(450), 1,
+      sym_fetch_clause,
+    ACTIONS(678), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [8107] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(451), 1,
+      sym_fetch_clause,
+    ACTIONS(680), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [8119] = 1,
+    ACTIONS(682), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [8125] = 3,
+    ACTIONS(686), 1,
+      anon_sym_COMMA,
+    STATE(275), 1,
+      aux_sym_select_statement_repeat1,
+    ACTIONS(684), 11,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_where_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_sample_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [8145] = 1,
+    ACTIONS(689), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [8150] = 1,
+    ACTIONS(691), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [8182] = 3,
+    ACTIONS(693), 1,
+      anon_sym_COMMA,
+    ACTIONS(696), 1,
+      anon_sym_RPAREN,
+    STATE(278), 1,
+      aux_sym_function_call_repeat1,
+  [8192] = 1,
+    ACTIONS(698), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [8224] = 1,
+    ACTIONS(700), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [8256] = 1,
+    ACTIONS(702), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [8288] = 1,
+    ACTIONS(704), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [8320] = 1,
+    ACTIONS(706), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [8352] = 1,
+    ACTIONS(708), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [8384] = 12,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(452), 1,
+      sym_literal,
+  [8421] = 1,
+    ACTIONS(710), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [8453] = 3,
+    ACTIONS(478), 1,
+      anon_sym_COMMA,
+    ACTIONS(712), 1,
+      anon_sym_RPAREN,
+    STATE(454), 1,
+      aux_sym_in_list_repeat1,
+  [8463] = 1,
+    ACTIONS(714), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [8495] = 1,
+    ACTIONS(716), 1,
+      anon_sym_RPAREN,
+  [8499] = 1,
+    ACTIONS(718), 1,
+      anon_sym_DQUOTE,
+  [8503] = 1,
+    ACTIONS(720), 1,
+      anon_sym_RBRACK,
+  [8507] = 1,
+    ACTIONS(722), 1,
+      anon_sym_BQUOTE,
+  [8511] = 1,
+    ACTIONS(199), 11,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+  [8525] = 1,
+    ACTIONS(201), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [8558] = 35,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(459), 1,
+      sym_expression,
+  [8664] = 6,
+    ACTIONS(69), 1,
+      aux_sym_when_clause_token1,
+    ACTIONS(207), 1,
+      aux_sym_else_clause_token1,
+    ACTIONS(724), 1,
+      aux_sym_case_expression_token2,
+    STATE(62), 1,
+      sym_when_clause,
+    STATE(106), 1,
+      aux_sym_case_expression_repeat1,
+    STATE(461), 1,
+      sym_else_clause,
+  [8683] = 1,
+    ACTIONS(726), 1,
+      anon_sym_SQUOTE,
+  [8687] = 1,
+    ACTIONS(728), 1,
+      anon_sym_SQUOTE,
+  [8691] = 1,
+    ACTIONS(730), 1,
+      anon_sym_SQUOTE,
+  [8695] = 37,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    ACTIONS(732), 1,
+      anon_sym_STAR,
+    ACTIONS(734), 1,
+      anon_sym_RPAREN,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(467), 1,
+      sym_expression,
+  [8807] = 4,
+    ACTIONS(736), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(738), 1,
+      anon_sym_LBRACK,
+    ACTIONS(740), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(742), 1,
+      sym__identifier,
+  [8820] = 34,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(398), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(126), 1,
+      sym_or_expression,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(217), 1,
+      sym_and_expression,
+    STATE(218), 1,
+      sym_not_expression,
+    STATE(219), 1,
+      sym_primary_expression,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+  [8923] = 33,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(398), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(218), 1,
+      sym_not_expression,
+    STATE(219), 1,
+      sym_primary_expression,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(472), 1,
+      sym_and_expression,
+  [9023] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(473), 1,
+      sym_primary_expression,
+  [9114] = 3,
+    ACTIONS(744), 1,
+      aux_sym_comparison_expression_token1,
+    ACTIONS(746), 1,
+      aux_sym_comparison_expression_token2,
+    ACTIONS(748), 1,
+      aux_sym_comparison_expression_token3,
+  [9124] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(477), 1,
+      sym_primary_expression,
+  [9215] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(478), 1,
+      sym_primary_expression,
+  [9306] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(479), 1,
+      sym_primary_expression,
+  [9397] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(480), 1,
+      sym_primary_expression,
+  [9488] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(481), 1,
+      sym_primary_expression,
+  [9579] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(482), 1,
+      sym_primary_expression,
+  [9670] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(483), 1,
+      sym_primary_expression,
+  [9761] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(484), 1,
+      sym_primary_expression,
+  [9852] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(485), 1,
+      sym_primary_expression,
+  [9943] = 2,
+    ACTIONS(750), 1,
+      anon_sym_LPAREN,
+    STATE(487), 1,
+      sym_in_list,
+  [9950] = 4,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(752), 1,
+      aux_sym_not_expression_token1,
+    STATE(489), 1,
+      sym_boolean_literal,
+  [9963] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(490), 1,
+      sym_primary_expression,
+  [10054] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(491), 1,
+      sym_primary_expression,
+  [10145] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(492), 1,
+      sym_primary_expression,
+  [10236] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(493), 1,
+      sym_primary_expression,
+  [10327] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(494), 1,
+      sym_primary_expression,
+  [10418] = 1,
+    ACTIONS(754), 1,
+      aux_sym_column_name_token1,
+  [10422] = 1,
+    ACTIONS(756), 1,
+      aux_sym_column_name_token2,
+  [10426] = 1,
+    ACTIONS(758), 1,
+      aux_sym_column_name_token3,
+  [10430] = 2,
+    ACTIONS(89), 1,
+      anon_sym_DOT,
+    ACTIONS(85), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [10445] = 3,
+    ACTIONS(762), 1,
+      anon_sym_COMMA,
+    STATE(499), 1,
+      aux_sym_group_by_clause_repeat1,
+    ACTIONS(760), 8,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [10462] = 1,
+    ACTIONS(764), 1,
+      anon_sym_DOT,
+  [10466] = 35,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(501), 1,
+      sym_expression,
+  [10572] = 1,
+    ACTIONS(766), 1,
+      aux_sym_column_name_token1,
+  [10576] = 1,
+    ACTIONS(768), 1,
+      aux_sym_column_name_token2,
+  [10580] = 1,
+    ACTIONS(770), 1,
+      aux_sym_column_name_token3,
+  [10584] = 32,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(572), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(353), 1,
+      sym_primary_expression,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(505), 1,
+      sym_not_expression,
+  [10681] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(506), 1,
+      sym_primary_expression,
+  [10772] = 1,
+    ACTIONS(772), 1,
+      anon_sym_LPAREN,
+  [10776] = 3,
+    ACTIONS(69), 1,
+      aux_sym_when_clause_token1,
+    STATE(62), 1,
+      sym_when_clause,
+    STATE(508), 1,
+      aux_sym_case_expression_repeat1,
+  [10786] = 1,
+    ACTIONS(71), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [10819] = 1,
+    ACTIONS(774), 1,
+      aux_sym_string_literal_token1,
+  [10823] = 1,
+    ACTIONS(776), 1,
+      aux_sym_string_literal_token1,
+  [10827] = 1,
+    ACTIONS(778), 1,
+      aux_sym_string_literal_token1,
+  [10831] = 1,
+    ACTIONS(79), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [10864] = 1,
+    ACTIONS(81), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [10897] = 1,
+    ACTIONS(83), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [10930] = 3,
+    ACTIONS(87), 1,
+      anon_sym_LPAREN,
+    ACTIONS(89), 1,
+      anon_sym_DOT,
+    ACTIONS(85), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [10969] = 1,
+    ACTIONS(99), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11002] = 1,
+    ACTIONS(780), 1,
+      anon_sym_LPAREN,
+  [11006] = 1,
+    ACTIONS(103), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11039] = 1,
+    ACTIONS(782), 1,
+      anon_sym_DOT,
+  [11043] = 3,
+    ACTIONS(786), 1,
+      anon_sym_COMMA,
+    STATE(515), 1,
+      aux_sym_order_by_clause_repeat1,
+    ACTIONS(784), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [11058] = 4,
+    ACTIONS(790), 1,
+      aux_sym_sort_direction_token1,
+    ACTIONS(792), 1,
+      aux_sym_sort_direction_token2,
+    STATE(518), 1,
+      sym_sort_direction,
+    ACTIONS(788), 7,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [11077] = 1,
+    ACTIONS(111), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [11089] = 2,
+    ACTIONS(794), 1,
+      aux_sym_or_expression_token1,
+    ACTIONS(113), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [11104] = 2,
+    ACTIONS(796), 1,
+      aux_sym_and_expression_token1,
+    ACTIONS(117), 10,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+  [11120] = 19,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(800), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(802), 1,
+      anon_sym_EQ,
+    ACTIONS(804), 1,
+      anon_sym_BANG_EQ,
+    ACTIONS(806), 1,
+      anon_sym_LT_GT,
+    ACTIONS(808), 1,
+      anon_sym_GT,
+    ACTIONS(810), 1,
+      anon_sym_GT_EQ,
+    ACTIONS(812), 1,
+      anon_sym_LT,
+    ACTIONS(814), 1,
+      anon_sym_LT_EQ,
+    ACTIONS(816), 1,
+      aux_sym_comparison_expression_token1,
+    ACTIONS(818), 1,
+      aux_sym_comparison_expression_token2,
+    ACTIONS(820), 1,
+      aux_sym_comparison_expression_token3,
+    ACTIONS(822), 1,
+      aux_sym_comparison_expression_token4,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(121), 11,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+  [11188] = 1,
+    ACTIONS(159), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11221] = 1,
+    ACTIONS(161), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11254] = 1,
+    ACTIONS(163), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11287] = 1,
+    ACTIONS(165), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11320] = 1,
+    ACTIONS(167), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11353] = 1,
+    ACTIONS(169), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11386] = 1,
+    ACTIONS(171), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11419] = 1,
+    ACTIONS(173), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11452] = 1,
+    ACTIONS(175), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11485] = 1,
+    ACTIONS(177), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [11518] = 1,
+    ACTIONS(834), 4,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [11525] = 1,
+    ACTIONS(836), 4,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [11532] = 2,
+    ACTIONS(838), 1,
+      aux_sym_offset_clause_token2,
+    ACTIONS(840), 1,
+      aux_sym_offset_clause_token3,
+  [11539] = 2,
+    ACTIONS(842), 1,
+      aux_sym_offset_clause_token2,
+    ACTIONS(844), 1,
+      aux_sym_offset_clause_token3,
+  [11546] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(543), 1,
+      sym_order_by_clause,
+    STATE(544), 1,
+      sym_limit_clause,
+    STATE(545), 1,
+      sym_offset_clause,
+    STATE(546), 1,
+      sym_fetch_clause,
+    ACTIONS(846), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11576] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(547), 1,
+      sym_limit_clause,
+    STATE(548), 1,
+      sym_offset_clause,
+    STATE(549), 1,
+      sym_fetch_clause,
+    ACTIONS(848), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11600] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(550), 1,
+      sym_offset_clause,
+    STATE(551), 1,
+      sym_fetch_clause,
+    ACTIONS(850), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11618] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(552), 1,
+      sym_fetch_clause,
+    ACTIONS(852), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11630] = 1,
+    ACTIONS(854), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11636] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(553), 1,
+      sym_limit_clause,
+    STATE(554), 1,
+      sym_offset_clause,
+    STATE(555), 1,
+      sym_fetch_clause,
+    ACTIONS(856), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11660] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(556), 1,
+      sym_offset_clause,
+    STATE(557), 1,
+      sym_fetch_clause,
+    ACTIONS(858), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11678] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(558), 1,
+      sym_fetch_clause,
+    ACTIONS(860), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11690] = 1,
+    ACTIONS(862), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11696] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(559), 1,
+      sym_offset_clause,
+    STATE(560), 1,
+      sym_fetch_clause,
+    ACTIONS(864), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11714] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(561), 1,
+      sym_fetch_clause,
+    ACTIONS(866), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11726] = 1,
+    ACTIONS(868), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11732] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(562), 1,
+      sym_fetch_clause,
+    ACTIONS(870), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11744] = 1,
+    ACTIONS(872), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11750] = 1,
+    ACTIONS(874), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11756] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(563), 1,
+      sym_limit_clause,
+    STATE(564), 1,
+      sym_offset_clause,
+    STATE(565), 1,
+      sym_fetch_clause,
+    ACTIONS(876), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11780] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(566), 1,
+      sym_offset_clause,
+    STATE(567), 1,
+      sym_fetch_clause,
+    ACTIONS(878), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11798] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(568), 1,
+      sym_fetch_clause,
+    ACTIONS(880), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11810] = 1,
+    ACTIONS(882), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11816] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(569), 1,
+      sym_offset_clause,
+    STATE(570), 1,
+      sym_fetch_clause,
+    ACTIONS(884), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11834] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(571), 1,
+      sym_fetch_clause,
+    ACTIONS(886), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11846] = 1,
+    ACTIONS(888), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11852] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(572), 1,
+      sym_fetch_clause,
+    ACTIONS(890), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11864] = 1,
+    ACTIONS(892), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11870] = 1,
+    ACTIONS(894), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11876] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(573), 1,
+      sym_offset_clause,
+    STATE(574), 1,
+      sym_fetch_clause,
+    ACTIONS(896), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11894] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(575), 1,
+      sym_fetch_clause,
+    ACTIONS(898), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11906] = 1,
+    ACTIONS(900), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11912] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(576), 1,
+      sym_fetch_clause,
+    ACTIONS(902), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11924] = 1,
+    ACTIONS(904), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11930] = 1,
+    ACTIONS(906), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11936] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(577), 1,
+      sym_fetch_clause,
+    ACTIONS(908), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11948] = 1,
+    ACTIONS(910), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11954] = 1,
+    ACTIONS(912), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11960] = 11,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(578), 1,
+      sym_having_clause,
+    STATE(579), 1,
+      sym_order_by_clause,
+    STATE(580), 1,
+      sym_limit_clause,
+    STATE(581), 1,
+      sym_offset_clause,
+    STATE(582), 1,
+      sym_fetch_clause,
+    ACTIONS(914), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [11996] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(583), 1,
+      sym_order_by_clause,
+    STATE(584), 1,
+      sym_limit_clause,
+    STATE(585), 1,
+      sym_offset_clause,
+    STATE(586), 1,
+      sym_fetch_clause,
+    ACTIONS(916), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12026] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(587), 1,
+      sym_limit_clause,
+    STATE(588), 1,
+      sym_offset_clause,
+    STATE(589), 1,
+      sym_fetch_clause,
+    ACTIONS(918), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12050] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(590), 1,
+      sym_offset_clause,
+    STATE(591), 1,
+      sym_fetch_clause,
+    ACTIONS(920), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12068] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(592), 1,
+      sym_fetch_clause,
+    ACTIONS(922), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12080] = 1,
+    ACTIONS(924), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12086] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(593), 1,
+      sym_order_by_clause,
+    STATE(594), 1,
+      sym_limit_clause,
+    STATE(595), 1,
+      sym_offset_clause,
+    STATE(596), 1,
+      sym_fetch_clause,
+    ACTIONS(926), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12116] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(597), 1,
+      sym_limit_clause,
+    STATE(598), 1,
+      sym_offset_clause,
+    STATE(599), 1,
+      sym_fetch_clause,
+    ACTIONS(928), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12140] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(600), 1,
+      sym_offset_clause,
+    STATE(601), 1,
+      sym_fetch_clause,
+    ACTIONS(930), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12158] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(602), 1,
+      sym_fetch_clause,
+    ACTIONS(932), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12170] = 1,
+    ACTIONS(934), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12176] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(603), 1,
+      sym_limit_clause,
+    STATE(604), 1,
+      sym_offset_clause,
+    STATE(605), 1,
+      sym_fetch_clause,
+    ACTIONS(936), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12200] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(606), 1,
+      sym_offset_clause,
+    STATE(607), 1,
+      sym_fetch_clause,
+    ACTIONS(938), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12218] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(608), 1,
+      sym_fetch_clause,
+    ACTIONS(940), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12230] = 1,
+    ACTIONS(942), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12236] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(609), 1,
+      sym_offset_clause,
+    STATE(610), 1,
+      sym_fetch_clause,
+    ACTIONS(944), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12254] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(611), 1,
+      sym_fetch_clause,
+    ACTIONS(946), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12266] = 1,
+    ACTIONS(948), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12272] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(612), 1,
+      sym_fetch_clause,
+    ACTIONS(950), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12284] = 1,
+    ACTIONS(952), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12290] = 1,
+    ACTIONS(954), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12296] = 1,
+    ACTIONS(956), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12302] = 11,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(613), 1,
+      sym_having_clause,
+    STATE(614), 1,
+      sym_order_by_clause,
+    STATE(615), 1,
+      sym_limit_clause,
+    STATE(616), 1,
+      sym_offset_clause,
+    STATE(617), 1,
+      sym_fetch_clause,
+    ACTIONS(958), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12338] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(618), 1,
+      sym_order_by_clause,
+    STATE(619), 1,
+      sym_limit_clause,
+    STATE(620), 1,
+      sym_offset_clause,
+    STATE(621), 1,
+      sym_fetch_clause,
+    ACTIONS(960), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12368] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(622), 1,
+      sym_limit_clause,
+    STATE(623), 1,
+      sym_offset_clause,
+    STATE(624), 1,
+      sym_fetch_clause,
+    ACTIONS(962), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12392] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(625), 1,
+      sym_offset_clause,
+    STATE(626), 1,
+      sym_fetch_clause,
+    ACTIONS(964), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12410] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(627), 1,
+      sym_fetch_clause,
+    ACTIONS(966), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12422] = 1,
+    ACTIONS(968), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12428] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(628), 1,
+      sym_order_by_clause,
+    STATE(629), 1,
+      sym_limit_clause,
+    STATE(630), 1,
+      sym_offset_clause,
+    STATE(631), 1,
+      sym_fetch_clause,
+    ACTIONS(970), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12458] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(632), 1,
+      sym_limit_clause,
+    STATE(633), 1,
+      sym_offset_clause,
+    STATE(634), 1,
+      sym_fetch_clause,
+    ACTIONS(972), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12482] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(635), 1,
+      sym_offset_clause,
+    STATE(636), 1,
+      sym_fetch_clause,
+    ACTIONS(974), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12500] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(637), 1,
+      sym_fetch_clause,
+    ACTIONS(976), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12512] = 1,
+    ACTIONS(978), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12518] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(638), 1,
+      sym_limit_clause,
+    STATE(639), 1,
+      sym_offset_clause,
+    STATE(640), 1,
+      sym_fetch_clause,
+    ACTIONS(980), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12542] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(641), 1,
+      sym_offset_clause,
+    STATE(642), 1,
+      sym_fetch_clause,
+    ACTIONS(982), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12560] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(643), 1,
+      sym_fetch_clause,
+    ACTIONS(984), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12572] = 1,
+    ACTIONS(986), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12578] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(644), 1,
+      sym_offset_clause,
+    STATE(645), 1,
+      sym_fetch_clause,
+    ACTIONS(988), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12596] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(646), 1,
+      sym_fetch_clause,
+    ACTIONS(990), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12608] = 1,
+    ACTIONS(992), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12614] = 13,
+    ACTIONS(295), 1,
+      aux_sym_group_by_clause_token1,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(647), 1,
+      sym_group_by_clause,
+    STATE(648), 1,
+      sym_having_clause,
+    STATE(649), 1,
+      sym_order_by_clause,
+    STATE(650), 1,
+      sym_limit_clause,
+    STATE(651), 1,
+      sym_offset_clause,
+    STATE(652), 1,
+      sym_fetch_clause,
+    ACTIONS(994), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12656] = 11,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(653), 1,
+      sym_having_clause,
+    STATE(654), 1,
+      sym_order_by_clause,
+    STATE(655), 1,
+      sym_limit_clause,
+    STATE(656), 1,
+      sym_offset_clause,
+    STATE(657), 1,
+      sym_fetch_clause,
+    ACTIONS(996), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12692] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(658), 1,
+      sym_order_by_clause,
+    STATE(659), 1,
+      sym_limit_clause,
+    STATE(660), 1,
+      sym_offset_clause,
+    STATE(661), 1,
+      sym_fetch_clause,
+    ACTIONS(998), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12722] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(662), 1,
+      sym_limit_clause,
+    STATE(663), 1,
+      sym_offset_clause,
+    STATE(664), 1,
+      sym_fetch_clause,
+    ACTIONS(1000), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12746] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(665), 1,
+      sym_offset_clause,
+    STATE(666), 1,
+      sym_fetch_clause,
+    ACTIONS(1002), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12764] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(667), 1,
+      sym_fetch_clause,
+    ACTIONS(1004), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12776] = 1,
+    ACTIONS(1006), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12782] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(668), 1,
+      sym_fetch_clause,
+    ACTIONS(1008), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12794] = 1,
+    ACTIONS(1010), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12800] = 1,
+    ACTIONS(1012), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [12806] = 1,
+    ACTIONS(1014), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [12811] = 1,
+    ACTIONS(1016), 29,
+      aux_sym_select_statement_token2,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_select_expression_token1,
+      anon_sym_RPAREN,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      aux_sym_case_expression_token2,
+      aux_sym_when_clause_token1,
+      aux_sym_when_clause_token2,
+      aux_sym_else_clause_token1,
+      anon_sym_PIPE_PIPE,
+  [12843] = 3,
+    ACTIONS(1018), 1,
+      anon_sym_COMMA,
+    ACTIONS(1021), 1,
+      anon_sym_RPAREN,
+    STATE(454), 1,
+      aux_sym_in_list_repeat1,
+  [12853] = 1,
+    ACTIONS(256), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [12886] = 1,
+    ACTIONS(258), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [12919] = 1,
+    ACTIONS(260), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [12952] = 1,
+    ACTIONS(262), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [12985] = 1,
+    ACTIONS(1023), 1,
+      aux_sym_select_expression_token1,
+  [12989] = 1,
+    ACTIONS(268), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [13022] = 1,
+    ACTIONS(1025), 1,
+      aux_sym_case_expression_token2,
+  [13026] = 1,
+    ACTIONS(277), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [13059] = 1,
+    ACTIONS(279), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [13092] = 1,
+    ACTIONS(281), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [13125] = 1,
+    ACTIONS(1027), 1,
+      anon_sym_RPAREN,
+  [13129] = 1,
+    ACTIONS(320), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [13162] = 3,
+    ACTIONS(322), 1,
+      anon_sym_COMMA,
+    ACTIONS(1029), 1,
+      anon_sym_RPAREN,
+    STATE(673), 1,
+      aux_sym_function_call_repeat1,
+  [13172] = 1,
+    ACTIONS(1031), 1,
+      aux_sym_column_name_token1,
+  [13176] = 1,
+    ACTIONS(1033), 1,
+      aux_sym_column_name_token2,
+  [13180] = 1,
+    ACTIONS(1035), 1,
+      aux_sym_column_name_token3,
+  [13184] = 1,
+    ACTIONS(332), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [13217] = 1,
+    ACTIONS(340), 10,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+  [13230] = 1,
+    ACTIONS(342), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [13263] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(677), 1,
+      sym_primary_expression,
+  [13354] = 30,
+    ACTIONS(390), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(394), 1,
+      anon_sym_LBRACK,
+    ACTIONS(396), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(400), 1,
+      anon_sym_DASH,
+    ACTIONS(402), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(404), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(406), 1,
+      aux_sym_literal_token1,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(410), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(412), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(414), 1,
+      sym_number_literal,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(420), 1,
+      sym__identifier,
+    STATE(212), 1,
+      sym_function_call,
+    STATE(213), 1,
+      sym_function_name,
+    STATE(214), 1,
+      sym_column_name,
+    STATE(215), 1,
+      sym_table_qualifier,
+    STATE(220), 1,
+      sym_comparison_expression,
+    STATE(221), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_cast_expression,
+    STATE(223), 1,
+      sym_case_expression,
+    STATE(224), 1,
+      sym_concat_expression,
+    STATE(225), 1,
+      sym_literal,
+    STATE(226), 1,
+      sym_string_literal,
+    STATE(227), 1,
+      sym_date_literal,
+    STATE(228), 1,
+      sym_timestamp_literal,
+    STATE(229), 1,
+      sym_boolean_literal,
+    STATE(678), 1,
+      sym_primary_expression,
+  [13445] = 2,
+    ACTIONS(750), 1,
+      anon_sym_LPAREN,
+    STATE(679), 1,
+      sym_in_list,
+  [13452] = 7,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(344), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [13497] = 7,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(346), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [13542] = 7,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(348), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [13587] = 7,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(350), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [13632] = 7,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(352), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [13677] = 7,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(354), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [13722] = 7,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(356), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [13767] = 9,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(1037), 1,
+      aux_sym_like_escape_token1,
+    STATE(681), 1,
+      sym_like_escape,
+    ACTIONS(358), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+  [13817] = 9,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(1037), 1,
+      aux_sym_like_escape_token1,
+    STATE(682), 1,
+      sym_like_escape,
+    ACTIONS(362), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+  [13867] = 12,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(683), 1,
+      sym_literal,
+  [13904] = 1,
+    ACTIONS(364), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [13937] = 3,
+    ACTIONS(416), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(418), 1,
+      aux_sym_boolean_literal_token2,
+    STATE(684), 1,
+      sym_boolean_literal,
+  [13947] = 1,
+    ACTIONS(366), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [13980] = 4,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(368), 27,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_PIPE_PIPE,
+  [14019] = 4,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(370), 27,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_PIPE_PIPE,
+  [14058] = 1,
+    ACTIONS(372), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [14091] = 1,
+    ACTIONS(374), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [14124] = 4,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(376), 27,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_PIPE_PIPE,
+  [14163] = 1,
+    ACTIONS(1039), 1,
+      anon_sym_DQUOTE,
+  [14167] = 1,
+    ACTIONS(1041), 1,
+      anon_sym_RBRACK,
+  [14171] = 1,
+    ACTIONS(1043), 1,
+      anon_sym_BQUOTE,
+  [14175] = 6,
+    ACTIONS(554), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(556), 1,
+      anon_sym_LBRACK,
+    ACTIONS(558), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(560), 1,
+      sym__identifier,
+    STATE(327), 1,
+      sym_table_qualifier,
+    STATE(688), 1,
+      sym_column_name,
+  [14194] = 3,
+    ACTIONS(762), 1,
+      anon_sym_COMMA,
+    STATE(689), 1,
+      aux_sym_group_by_clause_repeat1,
+    ACTIONS(1045), 8,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [14211] = 4,
+    ACTIONS(1047), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(1049), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1051), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(1053), 1,
+      sym__identifier,
+  [14224] = 1,
+    ACTIONS(1055), 1,
+      anon_sym_RPAREN,
+  [14228] = 1,
+    ACTIONS(1057), 1,
+      anon_sym_DQUOTE,
+  [14232] = 1,
+    ACTIONS(1059), 1,
+      anon_sym_RBRACK,
+  [14236] = 1,
+    ACTIONS(1061), 1,
+      anon_sym_BQUOTE,
+  [14240] = 1,
+    ACTIONS(199), 11,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+  [14254] = 1,
+    ACTIONS(201), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [14287] = 35,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(698), 1,
+      sym_expression,
+  [14393] = 6,
+    ACTIONS(69), 1,
+      aux_sym_when_clause_token1,
+    ACTIONS(207), 1,
+      aux_sym_else_clause_token1,
+    ACTIONS(1063), 1,
+      aux_sym_case_expression_token2,
+    STATE(62), 1,
+      sym_when_clause,
+    STATE(106), 1,
+      aux_sym_case_expression_repeat1,
+    STATE(700), 1,
+      sym_else_clause,
+  [14412] = 1,
+    ACTIONS(1065), 1,
+      anon_sym_SQUOTE,
+  [14416] = 1,
+    ACTIONS(1067), 1,
+      anon_sym_SQUOTE,
+  [14420] = 1,
+    ACTIONS(1069), 1,
+      anon_sym_SQUOTE,
+  [14424] = 37,
+    ACTIONS(13), 1,
+      anon_sym_LPAREN,
+    ACTIONS(15), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(17), 1,
+      anon_sym_LBRACK,
+    ACTIONS(19), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(21), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(23), 1,
+      anon_sym_DASH,
+    ACTIONS(25), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(27), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(43), 1,
+      sym__identifier,
+    ACTIONS(1071), 1,
+      anon_sym_STAR,
+    ACTIONS(1073), 1,
+      anon_sym_RPAREN,
+    STATE(30), 1,
+      sym_function_call,
+    STATE(31), 1,
+      sym_function_name,
+    STATE(32), 1,
+      sym_column_name,
+    STATE(33), 1,
+      sym_table_qualifier,
+    STATE(35), 1,
+      sym_or_expression,
+    STATE(36), 1,
+      sym_and_expression,
+    STATE(37), 1,
+      sym_not_expression,
+    STATE(38), 1,
+      sym_primary_expression,
+    STATE(39), 1,
+      sym_comparison_expression,
+    STATE(40), 1,
+      sym_arithmetic_expression,
+    STATE(41), 1,
+      sym_cast_expression,
+    STATE(42), 1,
+      sym_case_expression,
+    STATE(43), 1,
+      sym_concat_expression,
+    STATE(44), 1,
+      sym_literal,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(706), 1,
+      sym_expression,
+  [14536] = 4,
+    ACTIONS(1075), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(1077), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1079), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(1081), 1,
+      sym__identifier,
+  [14549] = 36,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(572), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(349), 1,
+      sym_expression,
+    STATE(350), 1,
+      sym_or_expression,
+    STATE(351), 1,
+      sym_and_expression,
+    STATE(352), 1,
+      sym_not_expression,
+    STATE(353), 1,
+      sym_primary_expression,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(711), 1,
+      sym_order_by_item,
+  [14658] = 3,
+    ACTIONS(786), 1,
+      anon_sym_COMMA,
+    STATE(712), 1,
+      aux_sym_order_by_clause_repeat1,
+    ACTIONS(1083), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [14673] = 1,
+    ACTIONS(1085), 7,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [14683] = 1,
+    ACTIONS(1087), 7,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [14693] = 1,
+    ACTIONS(1089), 7,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [14703] = 34,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(572), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(351), 1,
+      sym_and_expression,
+    STATE(352), 1,
+      sym_not_expression,
+    STATE(353), 1,
+      sym_primary_expression,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(713), 1,
+      sym_or_expression,
+  [14806] = 33,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(572), 1,
+      aux_sym_not_expression_token1,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(352), 1,
+      sym_not_expression,
+    STATE(353), 1,
+      sym_primary_expression,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(714), 1,
+      sym_and_expression,
+  [14906] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(715), 1,
+      sym_primary_expression,
+  [14997] = 3,
+    ACTIONS(1091), 1,
+      aux_sym_comparison_expression_token1,
+    ACTIONS(1093), 1,
+      aux_sym_comparison_expression_token2,
+    ACTIONS(1095), 1,
+      aux_sym_comparison_expression_token3,
+  [15007] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(719), 1,
+      sym_primary_expression,
+  [15098] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(720), 1,
+      sym_primary_expression,
+  [15189] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(721), 1,
+      sym_primary_expression,
+  [15280] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(722), 1,
+      sym_primary_expression,
+  [15371] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(723), 1,
+      sym_primary_expression,
+  [15462] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(724), 1,
+      sym_primary_expression,
+  [15553] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(725), 1,
+      sym_primary_expression,
+  [15644] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(726), 1,
+      sym_primary_expression,
+  [15735] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(727), 1,
+      sym_primary_expression,
+  [15826] = 2,
+    ACTIONS(1097), 1,
+      anon_sym_LPAREN,
+    STATE(729), 1,
+      sym_in_list,
+  [15833] = 4,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(1099), 1,
+      aux_sym_not_expression_token1,
+    STATE(731), 1,
+      sym_boolean_literal,
+  [15846] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(732), 1,
+      sym_primary_expression,
+  [15937] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(733), 1,
+      sym_primary_expression,
+  [16028] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(734), 1,
+      sym_primary_expression,
+  [16119] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(735), 1,
+      sym_primary_expression,
+  [16210] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(736), 1,
+      sym_primary_expression,
+  [16301] = 1,
+    ACTIONS(1101), 1,
+      aux_sym_fetch_clause_token4,
+  [16305] = 1,
+    ACTIONS(1103), 1,
+      aux_sym_fetch_clause_token4,
+  [16309] = 1,
+    ACTIONS(1105), 1,
+      aux_sym_fetch_clause_token4,
+  [16313] = 1,
+    ACTIONS(1107), 1,
+      aux_sym_fetch_clause_token4,
+  [16317] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(741), 1,
+      sym_limit_clause,
+    STATE(742), 1,
+      sym_offset_clause,
+    STATE(743), 1,
+      sym_fetch_clause,
+    ACTIONS(1109), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16341] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(744), 1,
+      sym_offset_clause,
+    STATE(745), 1,
+      sym_fetch_clause,
+    ACTIONS(1111), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16359] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(746), 1,
+      sym_fetch_clause,
+    ACTIONS(1113), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16371] = 1,
+    ACTIONS(1115), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16377] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(747), 1,
+      sym_offset_clause,
+    STATE(748), 1,
+      sym_fetch_clause,
+    ACTIONS(1117), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16395] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(749), 1,
+      sym_fetch_clause,
+    ACTIONS(1119), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16407] = 1,
+    ACTIONS(1121), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16413] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(750), 1,
+      sym_fetch_clause,
+    ACTIONS(1123), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16425] = 1,
+    ACTIONS(1125), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16431] = 1,
+    ACTIONS(1127), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16437] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(751), 1,
+      sym_offset_clause,
+    STATE(752), 1,
+      sym_fetch_clause,
+    ACTIONS(1129), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16455] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(753), 1,
+      sym_fetch_clause,
+    ACTIONS(1131), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16467] = 1,
+    ACTIONS(1133), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16473] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(754), 1,
+      sym_fetch_clause,
+    ACTIONS(1135), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16485] = 1,
+    ACTIONS(1137), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16491] = 1,
+    ACTIONS(1139), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16497] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(755), 1,
+      sym_fetch_clause,
+    ACTIONS(1141), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16509] = 1,
+    ACTIONS(1143), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16515] = 1,
+    ACTIONS(1145), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16521] = 1,
+    ACTIONS(1147), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16527] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(756), 1,
+      sym_offset_clause,
+    STATE(757), 1,
+      sym_fetch_clause,
+    ACTIONS(1149), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16545] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(758), 1,
+      sym_fetch_clause,
+    ACTIONS(1151), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16557] = 1,
+    ACTIONS(1153), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16563] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(759), 1,
+      sym_fetch_clause,
+    ACTIONS(1155), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16575] = 1,
+    ACTIONS(1157), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16581] = 1,
+    ACTIONS(1159), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16587] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(760), 1,
+      sym_fetch_clause,
+    ACTIONS(1161), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16599] = 1,
+    ACTIONS(1163), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16605] = 1,
+    ACTIONS(1165), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16611] = 1,
+    ACTIONS(1167), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16617] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(761), 1,
+      sym_fetch_clause,
+    ACTIONS(1169), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16629] = 1,
+    ACTIONS(1171), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16635] = 1,
+    ACTIONS(1173), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16641] = 1,
+    ACTIONS(1175), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16647] = 1,
+    ACTIONS(1177), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16653] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(762), 1,
+      sym_order_by_clause,
+    STATE(763), 1,
+      sym_limit_clause,
+    STATE(764), 1,
+      sym_offset_clause,
+    STATE(765), 1,
+      sym_fetch_clause,
+    ACTIONS(1179), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16683] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(766), 1,
+      sym_limit_clause,
+    STATE(767), 1,
+      sym_offset_clause,
+    STATE(768), 1,
+      sym_fetch_clause,
+    ACTIONS(1181), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16707] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(769), 1,
+      sym_offset_clause,
+    STATE(770), 1,
+      sym_fetch_clause,
+    ACTIONS(1183), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16725] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(771), 1,
+      sym_fetch_clause,
+    ACTIONS(1185), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16737] = 1,
+    ACTIONS(1187), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16743] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(772), 1,
+      sym_limit_clause,
+    STATE(773), 1,
+      sym_offset_clause,
+    STATE(774), 1,
+      sym_fetch_clause,
+    ACTIONS(1189), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16767] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(775), 1,
+      sym_offset_clause,
+    STATE(776), 1,
+      sym_fetch_clause,
+    ACTIONS(1191), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16785] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(777), 1,
+      sym_fetch_clause,
+    ACTIONS(1193), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16797] = 1,
+    ACTIONS(1195), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16803] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(778), 1,
+      sym_offset_clause,
+    STATE(779), 1,
+      sym_fetch_clause,
+    ACTIONS(1197), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16821] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(780), 1,
+      sym_fetch_clause,
+    ACTIONS(1199), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16833] = 1,
+    ACTIONS(1201), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16839] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(781), 1,
+      sym_fetch_clause,
+    ACTIONS(1203), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16851] = 1,
+    ACTIONS(1205), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16857] = 1,
+    ACTIONS(1207), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16863] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(782), 1,
+      sym_limit_clause,
+    STATE(783), 1,
+      sym_offset_clause,
+    STATE(784), 1,
+      sym_fetch_clause,
+    ACTIONS(1209), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16887] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(785), 1,
+      sym_offset_clause,
+    STATE(786), 1,
+      sym_fetch_clause,
+    ACTIONS(1211), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16905] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(787), 1,
+      sym_fetch_clause,
+    ACTIONS(1213), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16917] = 1,
+    ACTIONS(1215), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16923] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(788), 1,
+      sym_offset_clause,
+    STATE(789), 1,
+      sym_fetch_clause,
+    ACTIONS(1217), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16941] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(790), 1,
+      sym_fetch_clause,
+    ACTIONS(1219), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16953] = 1,
+    ACTIONS(1221), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16959] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(791), 1,
+      sym_fetch_clause,
+    ACTIONS(1223), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16971] = 1,
+    ACTIONS(1225), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16977] = 1,
+    ACTIONS(1227), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [16983] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(792), 1,
+      sym_offset_clause,
+    STATE(793), 1,
+      sym_fetch_clause,
+    ACTIONS(1229), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17001] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(794), 1,
+      sym_fetch_clause,
+    ACTIONS(1231), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17013] = 1,
+    ACTIONS(1233), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17019] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(795), 1,
+      sym_fetch_clause,
+    ACTIONS(1235), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17031] = 1,
+    ACTIONS(1237), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17037] = 1,
+    ACTIONS(1239), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17043] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(796), 1,
+      sym_fetch_clause,
+    ACTIONS(1241), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17055] = 1,
+    ACTIONS(1243), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17061] = 1,
+    ACTIONS(1245), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17067] = 1,
+    ACTIONS(1247), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17073] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(797), 1,
+      sym_order_by_clause,
+    STATE(798), 1,
+      sym_limit_clause,
+    STATE(799), 1,
+      sym_offset_clause,
+    STATE(800), 1,
+      sym_fetch_clause,
+    ACTIONS(1249), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17103] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(801), 1,
+      sym_limit_clause,
+    STATE(802), 1,
+      sym_offset_clause,
+    STATE(803), 1,
+      sym_fetch_clause,
+    ACTIONS(1251), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17127] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(804), 1,
+      sym_offset_clause,
+    STATE(805), 1,
+      sym_fetch_clause,
+    ACTIONS(1253), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17145] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(806), 1,
+      sym_fetch_clause,
+    ACTIONS(1255), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17157] = 1,
+    ACTIONS(1257), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17163] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(807), 1,
+      sym_limit_clause,
+    STATE(808), 1,
+      sym_offset_clause,
+    STATE(809), 1,
+      sym_fetch_clause,
+    ACTIONS(1259), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17187] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(810), 1,
+      sym_offset_clause,
+    STATE(811), 1,
+      sym_fetch_clause,
+    ACTIONS(1261), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17205] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(812), 1,
+      sym_fetch_clause,
+    ACTIONS(1263), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17217] = 1,
+    ACTIONS(1265), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17223] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(813), 1,
+      sym_offset_clause,
+    STATE(814), 1,
+      sym_fetch_clause,
+    ACTIONS(1267), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17241] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(815), 1,
+      sym_fetch_clause,
+    ACTIONS(1269), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17253] = 1,
+    ACTIONS(1271), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17259] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(816), 1,
+      sym_fetch_clause,
+    ACTIONS(1273), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17271] = 1,
+    ACTIONS(1275), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17277] = 1,
+    ACTIONS(1277), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17283] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(817), 1,
+      sym_limit_clause,
+    STATE(818), 1,
+      sym_offset_clause,
+    STATE(819), 1,
+      sym_fetch_clause,
+    ACTIONS(1279), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17307] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(820), 1,
+      sym_offset_clause,
+    STATE(821), 1,
+      sym_fetch_clause,
+    ACTIONS(1281), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17325] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(822), 1,
+      sym_fetch_clause,
+    ACTIONS(1283), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17337] = 1,
+    ACTIONS(1285), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17343] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(823), 1,
+      sym_offset_clause,
+    STATE(824), 1,
+      sym_fetch_clause,
+    ACTIONS(1287), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17361] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(825), 1,
+      sym_fetch_clause,
+    ACTIONS(1289), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17373] = 1,
+    ACTIONS(1291), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17379] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(826), 1,
+      sym_fetch_clause,
+    ACTIONS(1293), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17391] = 1,
+    ACTIONS(1295), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17397] = 1,
+    ACTIONS(1297), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17403] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(827), 1,
+      sym_offset_clause,
+    STATE(828), 1,
+      sym_fetch_clause,
+    ACTIONS(1299), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17421] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(829), 1,
+      sym_fetch_clause,
+    ACTIONS(1301), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17433] = 1,
+    ACTIONS(1303), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17439] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(830), 1,
+      sym_fetch_clause,
+    ACTIONS(1305), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17451] = 1,
+    ACTIONS(1307), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17457] = 1,
+    ACTIONS(1309), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17463] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(831), 1,
+      sym_fetch_clause,
+    ACTIONS(1311), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17475] = 1,
+    ACTIONS(1313), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17481] = 1,
+    ACTIONS(1315), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17487] = 11,
+    ACTIONS(297), 1,
+      aux_sym_having_clause_token1,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(832), 1,
+      sym_having_clause,
+    STATE(833), 1,
+      sym_order_by_clause,
+    STATE(834), 1,
+      sym_limit_clause,
+    STATE(835), 1,
+      sym_offset_clause,
+    STATE(836), 1,
+      sym_fetch_clause,
+    ACTIONS(1317), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17523] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(837), 1,
+      sym_order_by_clause,
+    STATE(838), 1,
+      sym_limit_clause,
+    STATE(839), 1,
+      sym_offset_clause,
+    STATE(840), 1,
+      sym_fetch_clause,
+    ACTIONS(1319), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17553] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(841), 1,
+      sym_limit_clause,
+    STATE(842), 1,
+      sym_offset_clause,
+    STATE(843), 1,
+      sym_fetch_clause,
+    ACTIONS(1321), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17577] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(844), 1,
+      sym_offset_clause,
+    STATE(845), 1,
+      sym_fetch_clause,
+    ACTIONS(1323), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17595] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(846), 1,
+      sym_fetch_clause,
+    ACTIONS(1325), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17607] = 1,
+    ACTIONS(1327), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17613] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(847), 1,
+      sym_order_by_clause,
+    STATE(848), 1,
+      sym_limit_clause,
+    STATE(849), 1,
+      sym_offset_clause,
+    STATE(850), 1,
+      sym_fetch_clause,
+    ACTIONS(1329), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17643] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(851), 1,
+      sym_limit_clause,
+    STATE(852), 1,
+      sym_offset_clause,
+    STATE(853), 1,
+      sym_fetch_clause,
+    ACTIONS(1331), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17667] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(854), 1,
+      sym_offset_clause,
+    STATE(855), 1,
+      sym_fetch_clause,
+    ACTIONS(1333), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17685] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(856), 1,
+      sym_fetch_clause,
+    ACTIONS(1335), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17697] = 1,
+    ACTIONS(1337), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17703] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(857), 1,
+      sym_limit_clause,
+    STATE(858), 1,
+      sym_offset_clause,
+    STATE(859), 1,
+      sym_fetch_clause,
+    ACTIONS(1339), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17727] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(860), 1,
+      sym_offset_clause,
+    STATE(861), 1,
+      sym_fetch_clause,
+    ACTIONS(1341), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17745] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(862), 1,
+      sym_fetch_clause,
+    ACTIONS(1343), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17757] = 1,
+    ACTIONS(1345), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17763] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(863), 1,
+      sym_offset_clause,
+    STATE(864), 1,
+      sym_fetch_clause,
+    ACTIONS(1347), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17781] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(865), 1,
+      sym_fetch_clause,
+    ACTIONS(1349), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17793] = 1,
+    ACTIONS(1351), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17799] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(866), 1,
+      sym_fetch_clause,
+    ACTIONS(1353), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17811] = 1,
+    ACTIONS(1355), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17817] = 1,
+    ACTIONS(1357), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17823] = 1,
+    ACTIONS(1359), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [17829] = 2,
+    ACTIONS(380), 1,
+      sym__identifier,
+    STATE(867), 1,
+      sym_type_name,
+  [17836] = 1,
+    ACTIONS(384), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [17869] = 1,
+    ACTIONS(456), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [17902] = 1,
+    ACTIONS(458), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [17935] = 3,
+    ACTIONS(322), 1,
+      anon_sym_COMMA,
+    ACTIONS(1361), 1,
+      anon_sym_RPAREN,
+    STATE(278), 1,
+      aux_sym_function_call_repeat1,
+  [17945] = 1,
+    ACTIONS(1363), 1,
+      anon_sym_DQUOTE,
+  [17949] = 1,
+    ACTIONS(1365), 1,
+      anon_sym_RBRACK,
+  [17953] = 1,
+    ACTIONS(1367), 1,
+      anon_sym_BQUOTE,
+  [17957] = 9,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(1037), 1,
+      aux_sym_like_escape_token1,
+    STATE(872), 1,
+      sym_like_escape,
+    ACTIONS(468), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+  [18007] = 9,
+    ACTIONS(518), 1,
+      anon_sym_STAR,
+    ACTIONS(544), 1,
+      anon_sym_PLUS,
+    ACTIONS(546), 1,
+      anon_sym_DASH,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
+    ACTIONS(550), 1,
+      anon_sym_PERCENT,
+    ACTIONS(552), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(1037), 1,
+      aux_sym_like_escape_token1,
+    STATE(873), 1,
+      sym_like_escape,
+    ACTIONS(470), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+  [18057] = 1,
+    ACTIONS(472), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18090] = 2,
+    ACTIONS(408), 1,
+      anon_sym_SQUOTE,
+    STATE(874), 1,
+      sym_string_literal,
+  [18097] = 1,
+    ACTIONS(474), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18130] = 1,
+    ACTIONS(476), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18163] = 3,
+    ACTIONS(478), 1,
+      anon_sym_COMMA,
+    ACTIONS(1369), 1,
+      anon_sym_RPAREN,
+    STATE(876), 1,
+      aux_sym_in_list_repeat1,
+  [18173] = 1,
+    ACTIONS(482), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18206] = 1,
+    ACTIONS(258), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [18218] = 1,
+    ACTIONS(260), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [18230] = 1,
+    ACTIONS(262), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [18242] = 1,
+    ACTIONS(1371), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [18254] = 3,
+    ACTIONS(1375), 1,
+      anon_sym_COMMA,
+    STATE(689), 1,
+      aux_sym_group_by_clause_repeat1,
+    ACTIONS(1373), 8,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [18271] = 1,
+    ACTIONS(1378), 1,
+      aux_sym_column_name_token1,
+  [18275] = 1,
+    ACTIONS(1380), 1,
+      aux_sym_column_name_token2,
+  [18279] = 1,
+    ACTIONS(1382), 1,
+      aux_sym_column_name_token3,
+  [18283] = 1,
+    ACTIONS(332), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [18295] = 1,
+    ACTIONS(256), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18328] = 1,
+    ACTIONS(258), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18361] = 1,
+    ACTIONS(260), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18394] = 1,
+    ACTIONS(262), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18427] = 1,
+    ACTIONS(1384), 1,
+      aux_sym_select_expression_token1,
+  [18431] = 1,
+    ACTIONS(268), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18464] = 1,
+    ACTIONS(1386), 1,
+      aux_sym_case_expression_token2,
+  [18468] = 1,
+    ACTIONS(277), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18501] = 1,
+    ACTIONS(279), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18534] = 1,
+    ACTIONS(281), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18567] = 1,
+    ACTIONS(1388), 1,
+      anon_sym_RPAREN,
+  [18571] = 1,
+    ACTIONS(320), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18604] = 3,
+    ACTIONS(322), 1,
+      anon_sym_COMMA,
+    ACTIONS(1390), 1,
+      anon_sym_RPAREN,
+    STATE(884), 1,
+      aux_sym_function_call_repeat1,
+  [18614] = 1,
+    ACTIONS(1392), 1,
+      aux_sym_column_name_token1,
+  [18618] = 1,
+    ACTIONS(1394), 1,
+      aux_sym_column_name_token2,
+  [18622] = 1,
+    ACTIONS(1396), 1,
+      aux_sym_column_name_token3,
+  [18626] = 1,
+    ACTIONS(332), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18659] = 1,
+    ACTIONS(1398), 7,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [18669] = 3,
+    ACTIONS(1402), 1,
+      anon_sym_COMMA,
+    STATE(712), 1,
+      aux_sym_order_by_clause_repeat1,
+    ACTIONS(1400), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [18684] = 1,
+    ACTIONS(338), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [18696] = 1,
+    ACTIONS(340), 10,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+  [18709] = 1,
+    ACTIONS(342), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [18742] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
       aux_sym_literal_token1,
-    ACTIONS(11), 1,
+    ACTIONS(582), 1,
       anon_sym_SQUOTE,
-    ACTIONS(13), 1,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(888), 1,
+      sym_primary_expression,
+  [18833] = 30,
+    ACTIONS(564), 1,
+      anon_sym_LPAREN,
+    ACTIONS(566), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(568), 1,
+      anon_sym_LBRACK,
+    ACTIONS(570), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(574), 1,
+      anon_sym_DASH,
+    ACTIONS(576), 1,
+      aux_sym_cast_expression_token1,
+    ACTIONS(578), 1,
+      aux_sym_case_expression_token1,
+    ACTIONS(580), 1,
+      aux_sym_literal_token1,
+    ACTIONS(582), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(584), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(586), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(588), 1,
+      sym_number_literal,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    ACTIONS(594), 1,
+      sym__identifier,
+    STATE(344), 1,
+      sym_function_call,
+    STATE(345), 1,
+      sym_function_name,
+    STATE(346), 1,
+      sym_column_name,
+    STATE(347), 1,
+      sym_table_qualifier,
+    STATE(354), 1,
+      sym_comparison_expression,
+    STATE(355), 1,
+      sym_arithmetic_expression,
+    STATE(356), 1,
+      sym_cast_expression,
+    STATE(357), 1,
+      sym_case_expression,
+    STATE(358), 1,
+      sym_concat_expression,
+    STATE(359), 1,
+      sym_literal,
+    STATE(360), 1,
+      sym_string_literal,
+    STATE(361), 1,
+      sym_date_literal,
+    STATE(362), 1,
+      sym_timestamp_literal,
+    STATE(363), 1,
+      sym_boolean_literal,
+    STATE(889), 1,
+      sym_primary_expression,
+  [18924] = 2,
+    ACTIONS(1097), 1,
+      anon_sym_LPAREN,
+    STATE(890), 1,
+      sym_in_list,
+  [18931] = 7,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(344), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [18976] = 7,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(346), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [19021] = 7,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(348), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [19066] = 7,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(350), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [19111] = 7,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(352), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [19156] = 7,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(354), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [19201] = 7,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(356), 24,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+  [19246] = 9,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(1405), 1,
+      aux_sym_like_escape_token1,
+    STATE(892), 1,
+      sym_like_escape,
+    ACTIONS(358), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+  [19296] = 9,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(1405), 1,
+      aux_sym_like_escape_token1,
+    STATE(893), 1,
+      sym_like_escape,
+    ACTIONS(362), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+  [19346] = 12,
+    ACTIONS(29), 1,
+      aux_sym_literal_token1,
+    ACTIONS(31), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(33), 1,
+      aux_sym_date_literal_token1,
+    ACTIONS(35), 1,
+      aux_sym_timestamp_literal_token1,
+    ACTIONS(37), 1,
+      sym_number_literal,
+    ACTIONS(39), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(41), 1,
+      aux_sym_boolean_literal_token2,
+    STATE(45), 1,
+      sym_string_literal,
+    STATE(46), 1,
+      sym_date_literal,
+    STATE(47), 1,
+      sym_timestamp_literal,
+    STATE(48), 1,
+      sym_boolean_literal,
+    STATE(894), 1,
+      sym_literal,
+  [19383] = 1,
+    ACTIONS(364), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [19416] = 3,
+    ACTIONS(590), 1,
+      aux_sym_boolean_literal_token1,
+    ACTIONS(592), 1,
+      aux_sym_boolean_literal_token2,
+    STATE(895), 1,
+      sym_boolean_literal,
+  [19426] = 1,
+    ACTIONS(366), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [19459] = 4,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(368), 27,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_PIPE_PIPE,
+  [19498] = 4,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(370), 27,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_PIPE_PIPE,
+  [19537] = 1,
+    ACTIONS(372), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [19570] = 1,
+    ACTIONS(374), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [19603] = 4,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(376), 27,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_PIPE_PIPE,
+  [19642] = 1,
+    ACTIONS(1407), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19648] = 1,
+    ACTIONS(1409), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19654] = 1,
+    ACTIONS(1411), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19660] = 1,
+    ACTIONS(1413), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19666] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(896), 1,
+      sym_offset_clause,
+    STATE(897), 1,
+      sym_fetch_clause,
+    ACTIONS(1415), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19684] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(898), 1,
+      sym_fetch_clause,
+    ACTIONS(1417), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19696] = 1,
+    ACTIONS(1419), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19702] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(899), 1,
+      sym_fetch_clause,
+    ACTIONS(1421), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19714] = 1,
+    ACTIONS(1423), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19720] = 1,
+    ACTIONS(1425), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19726] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(900), 1,
+      sym_fetch_clause,
+    ACTIONS(1427), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19738] = 1,
+    ACTIONS(1429), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19744] = 1,
+    ACTIONS(1431), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19750] = 1,
+    ACTIONS(1433), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19756] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(901), 1,
+      sym_fetch_clause,
+    ACTIONS(1435), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19768] = 1,
+    ACTIONS(1437), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19774] = 1,
+    ACTIONS(1439), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19780] = 1,
+    ACTIONS(1441), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19786] = 1,
+    ACTIONS(1443), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19792] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(902), 1,
+      sym_fetch_clause,
+    ACTIONS(1445), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19804] = 1,
+    ACTIONS(1447), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19810] = 1,
+    ACTIONS(1449), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19816] = 1,
+    ACTIONS(1451), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19822] = 1,
+    ACTIONS(1453), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19828] = 1,
+    ACTIONS(1455), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19834] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(903), 1,
+      sym_limit_clause,
+    STATE(904), 1,
+      sym_offset_clause,
+    STATE(905), 1,
+      sym_fetch_clause,
+    ACTIONS(1457), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19858] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(906), 1,
+      sym_offset_clause,
+    STATE(907), 1,
+      sym_fetch_clause,
+    ACTIONS(1459), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19876] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(908), 1,
+      sym_fetch_clause,
+    ACTIONS(1461), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19888] = 1,
+    ACTIONS(1463), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19894] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(909), 1,
+      sym_offset_clause,
+    STATE(910), 1,
+      sym_fetch_clause,
+    ACTIONS(1465), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19912] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(911), 1,
+      sym_fetch_clause,
+    ACTIONS(1467), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19924] = 1,
+    ACTIONS(1469), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19930] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(912), 1,
+      sym_fetch_clause,
+    ACTIONS(1471), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19942] = 1,
+    ACTIONS(1473), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19948] = 1,
+    ACTIONS(1475), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19954] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(913), 1,
+      sym_offset_clause,
+    STATE(914), 1,
+      sym_fetch_clause,
+    ACTIONS(1477), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19972] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(915), 1,
+      sym_fetch_clause,
+    ACTIONS(1479), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19984] = 1,
+    ACTIONS(1481), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [19990] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(916), 1,
+      sym_fetch_clause,
+    ACTIONS(1483), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20002] = 1,
+    ACTIONS(1485), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20008] = 1,
+    ACTIONS(1487), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20014] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(917), 1,
+      sym_fetch_clause,
+    ACTIONS(1489), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20026] = 1,
+    ACTIONS(1491), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20032] = 1,
+    ACTIONS(1493), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20038] = 1,
+    ACTIONS(1495), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20044] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(918), 1,
+      sym_offset_clause,
+    STATE(919), 1,
+      sym_fetch_clause,
+    ACTIONS(1497), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20062] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(920), 1,
+      sym_fetch_clause,
+    ACTIONS(1499), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20074] = 1,
+    ACTIONS(1501), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20080] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(921), 1,
+      sym_fetch_clause,
+    ACTIONS(1503), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20092] = 1,
+    ACTIONS(1505), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20098] = 1,
+    ACTIONS(1507), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20104] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(922), 1,
+      sym_fetch_clause,
+    ACTIONS(1509), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20116] = 1,
+    ACTIONS(1511), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20122] = 1,
+    ACTIONS(1513), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20128] = 1,
+    ACTIONS(1515), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20134] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(923), 1,
+      sym_fetch_clause,
+    ACTIONS(1517), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20146] = 1,
+    ACTIONS(1519), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20152] = 1,
+    ACTIONS(1521), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20158] = 1,
+    ACTIONS(1523), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20164] = 1,
+    ACTIONS(1525), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20170] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(924), 1,
+      sym_limit_clause,
+    STATE(925), 1,
+      sym_offset_clause,
+    STATE(926), 1,
+      sym_fetch_clause,
+    ACTIONS(1527), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20194] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(927), 1,
+      sym_offset_clause,
+    STATE(928), 1,
+      sym_fetch_clause,
+    ACTIONS(1529), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20212] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(929), 1,
+      sym_fetch_clause,
+    ACTIONS(1531), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20224] = 1,
+    ACTIONS(1533), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20230] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(930), 1,
+      sym_offset_clause,
+    STATE(931), 1,
+      sym_fetch_clause,
+    ACTIONS(1535), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20248] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(932), 1,
+      sym_fetch_clause,
+    ACTIONS(1537), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20260] = 1,
+    ACTIONS(1539), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20266] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(933), 1,
+      sym_fetch_clause,
+    ACTIONS(1541), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20278] = 1,
+    ACTIONS(1543), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20284] = 1,
+    ACTIONS(1545), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20290] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(934), 1,
+      sym_offset_clause,
+    STATE(935), 1,
+      sym_fetch_clause,
+    ACTIONS(1547), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20308] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(936), 1,
+      sym_fetch_clause,
+    ACTIONS(1549), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20320] = 1,
+    ACTIONS(1551), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20326] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(937), 1,
+      sym_fetch_clause,
+    ACTIONS(1553), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20338] = 1,
+    ACTIONS(1555), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20344] = 1,
+    ACTIONS(1557), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20350] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(938), 1,
+      sym_fetch_clause,
+    ACTIONS(1559), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20362] = 1,
+    ACTIONS(1561), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20368] = 1,
+    ACTIONS(1563), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20374] = 1,
+    ACTIONS(1565), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20380] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(939), 1,
+      sym_offset_clause,
+    STATE(940), 1,
+      sym_fetch_clause,
+    ACTIONS(1567), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20398] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(941), 1,
+      sym_fetch_clause,
+    ACTIONS(1569), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20410] = 1,
+    ACTIONS(1571), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20416] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(942), 1,
+      sym_fetch_clause,
+    ACTIONS(1573), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20428] = 1,
+    ACTIONS(1575), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20434] = 1,
+    ACTIONS(1577), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20440] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(943), 1,
+      sym_fetch_clause,
+    ACTIONS(1579), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20452] = 1,
+    ACTIONS(1581), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20458] = 1,
+    ACTIONS(1583), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20464] = 1,
+    ACTIONS(1585), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20470] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(944), 1,
+      sym_fetch_clause,
+    ACTIONS(1587), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20482] = 1,
+    ACTIONS(1589), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20488] = 1,
+    ACTIONS(1591), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20494] = 1,
+    ACTIONS(1593), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20500] = 1,
+    ACTIONS(1595), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20506] = 9,
+    ACTIONS(299), 1,
+      aux_sym_order_by_clause_token1,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(945), 1,
+      sym_order_by_clause,
+    STATE(946), 1,
+      sym_limit_clause,
+    STATE(947), 1,
+      sym_offset_clause,
+    STATE(948), 1,
+      sym_fetch_clause,
+    ACTIONS(1597), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20536] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(949), 1,
+      sym_limit_clause,
+    STATE(950), 1,
+      sym_offset_clause,
+    STATE(951), 1,
+      sym_fetch_clause,
+    ACTIONS(1599), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20560] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(952), 1,
+      sym_offset_clause,
+    STATE(953), 1,
+      sym_fetch_clause,
+    ACTIONS(1601), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20578] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(954), 1,
+      sym_fetch_clause,
+    ACTIONS(1603), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20590] = 1,
+    ACTIONS(1605), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20596] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(955), 1,
+      sym_limit_clause,
+    STATE(956), 1,
+      sym_offset_clause,
+    STATE(957), 1,
+      sym_fetch_clause,
+    ACTIONS(1607), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20620] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(958), 1,
+      sym_offset_clause,
+    STATE(959), 1,
+      sym_fetch_clause,
+    ACTIONS(1609), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20638] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(960), 1,
+      sym_fetch_clause,
+    ACTIONS(1611), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20650] = 1,
+    ACTIONS(1613), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20656] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(961), 1,
+      sym_offset_clause,
+    STATE(962), 1,
+      sym_fetch_clause,
+    ACTIONS(1615), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20674] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(963), 1,
+      sym_fetch_clause,
+    ACTIONS(1617), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20686] = 1,
+    ACTIONS(1619), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20692] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(964), 1,
+      sym_fetch_clause,
+    ACTIONS(1621), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20704] = 1,
+    ACTIONS(1623), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20710] = 1,
+    ACTIONS(1625), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20716] = 7,
+    ACTIONS(303), 1,
+      aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(965), 1,
+      sym_limit_clause,
+    STATE(966), 1,
+      sym_offset_clause,
+    STATE(967), 1,
+      sym_fetch_clause,
+    ACTIONS(1627), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20740] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(968), 1,
+      sym_offset_clause,
+    STATE(969), 1,
+      sym_fetch_clause,
+    ACTIONS(1629), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20758] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(970), 1,
+      sym_fetch_clause,
+    ACTIONS(1631), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20770] = 1,
+    ACTIONS(1633), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20776] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(971), 1,
+      sym_offset_clause,
+    STATE(972), 1,
+      sym_fetch_clause,
+    ACTIONS(1635), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20794] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(973), 1,
+      sym_fetch_clause,
+    ACTIONS(1637), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20806] = 1,
+    ACTIONS(1639), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20812] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(974), 1,
+      sym_fetch_clause,
+    ACTIONS(1641), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20824] = 1,
+    ACTIONS(1643), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20830] = 1,
+    ACTIONS(1645), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20836] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(975), 1,
+      sym_offset_clause,
+    STATE(976), 1,
+      sym_fetch_clause,
+    ACTIONS(1647), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20854] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(977), 1,
+      sym_fetch_clause,
+    ACTIONS(1649), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20866] = 1,
+    ACTIONS(1651), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20872] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(978), 1,
+      sym_fetch_clause,
+    ACTIONS(1653), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20884] = 1,
+    ACTIONS(1655), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20890] = 1,
+    ACTIONS(1657), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20896] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(979), 1,
+      sym_fetch_clause,
+    ACTIONS(1659), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20908] = 1,
+    ACTIONS(1661), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20914] = 1,
+    ACTIONS(1663), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20920] = 1,
+    ACTIONS(1665), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [20926] = 1,
+    ACTIONS(1667), 1,
+      anon_sym_RPAREN,
+  [20930] = 1,
+    ACTIONS(691), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [20963] = 1,
+    ACTIONS(698), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [20996] = 1,
+    ACTIONS(700), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21029] = 1,
+    ACTIONS(702), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21062] = 1,
+    ACTIONS(704), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21095] = 1,
+    ACTIONS(706), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21128] = 1,
+    ACTIONS(708), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21161] = 1,
+    ACTIONS(710), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21194] = 3,
+    ACTIONS(478), 1,
+      anon_sym_COMMA,
+    ACTIONS(1669), 1,
+      anon_sym_RPAREN,
+    STATE(454), 1,
+      aux_sym_in_list_repeat1,
+  [21204] = 1,
+    ACTIONS(1671), 1,
       anon_sym_DQUOTE,
-    ACTIONS(15), 1,
-      sym_number_literal,
-    ACTIONS(19), 1,
+  [21208] = 1,
+    ACTIONS(1673), 1,
+      anon_sym_RBRACK,
+  [21212] = 1,
+    ACTIONS(1675), 1,
+      anon_sym_BQUOTE,
+  [21216] = 2,
+    ACTIONS(380), 1,
       sym__identifier,
-    STATE(14), 1,
-      sym_primary_expression,
-    STATE(18), 1,
-      sym_not_expression,
-    STATE(22), 1,
-      sym_and_expression,
-    STATE(26), 1,
-      sym_or_expression,
-    ACTIONS(17), 2,
-      aux_sym_boolean_literal_token1,
-      aux_sym_boolean_literal_token2,
-    STATE(9), 2,
-      sym_string_literal,
-      sym_boolean_literal,
-    STATE(10), 3,
-      sym_column_name,
-      sym_comparison_expression,
-      sym_literal,
-  [147] = 13,
-    ACTIONS(5), 1,
-      anon_sym_LPAREN,
-    ACTIONS(7), 1,
+    STATE(985), 1,
+      sym_type_name,
+  [21223] = 1,
+    ACTIONS(384), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
       aux_sym_not_expression_token1,
-    ACTIONS(9), 1,
-      aux_sym_literal_token1,
-    ACTIONS(11), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(13), 1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21256] = 1,
+    ACTIONS(456), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21289] = 1,
+    ACTIONS(458), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21322] = 3,
+    ACTIONS(322), 1,
+      anon_sym_COMMA,
+    ACTIONS(1677), 1,
+      anon_sym_RPAREN,
+    STATE(278), 1,
+      aux_sym_function_call_repeat1,
+  [21332] = 1,
+    ACTIONS(1679), 1,
       anon_sym_DQUOTE,
-    ACTIONS(15), 1,
-      sym_number_literal,
-    ACTIONS(19), 1,
-      sym__identifier,
-    STATE(14), 1,
-      sym_primary_expression,
-    STATE(18), 1,
-      sym_not_expression,
-    STATE(23), 1,
-      sym_and_expression,
-    ACTIONS(17), 2,
-      aux_sym_boolean_literal_token1,
-      aux_sym_boolean_literal_token2,
-    STATE(9), 2,
-      sym_string_literal,
-      sym_boolean_literal,
-    STATE(10), 3,
-      sym_column_name,
-      sym_comparison_expression,
-      sym_literal,
-  [191] = 12,
-    ACTIONS(5), 1,
-      anon_sym_LPAREN,
-    ACTIONS(7), 1,
+  [21336] = 1,
+    ACTIONS(1681), 1,
+      anon_sym_RBRACK,
+  [21340] = 1,
+    ACTIONS(1683), 1,
+      anon_sym_BQUOTE,
+  [21344] = 9,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(1405), 1,
+      aux_sym_like_escape_token1,
+    STATE(990), 1,
+      sym_like_escape,
+    ACTIONS(468), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
       aux_sym_not_expression_token1,
-    ACTIONS(9), 1,
-      aux_sym_literal_token1,
-    ACTIONS(11), 1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+  [21394] = 9,
+    ACTIONS(798), 1,
+      anon_sym_STAR,
+    ACTIONS(824), 1,
+      anon_sym_PLUS,
+    ACTIONS(826), 1,
+      anon_sym_DASH,
+    ACTIONS(828), 1,
+      anon_sym_SLASH,
+    ACTIONS(830), 1,
+      anon_sym_PERCENT,
+    ACTIONS(832), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(1405), 1,
+      aux_sym_like_escape_token1,
+    STATE(991), 1,
+      sym_like_escape,
+    ACTIONS(470), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+  [21444] = 1,
+    ACTIONS(472), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21477] = 2,
+    ACTIONS(582), 1,
       anon_sym_SQUOTE,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(15), 1,
-      sym_number_literal,
-    ACTIONS(19), 1,
-      sym__identifier,
-    STATE(14), 1,
-      sym_primary_expression,
-    STATE(19), 1,
-      sym_not_expression,
-    ACTIONS(17), 2,
-      aux_sym_boolean_literal_token1,
-      aux_sym_boolean_literal_token2,
-    STATE(9), 2,
+    STATE(992), 1,
       sym_string_literal,
-      sym_boolean_literal,
-    STATE(10), 3,
-      sym_column_name,
-      sym_comparison_expression,
-      sym_literal,
-  [232] = 2,
-    ACTIONS(23), 2,
+  [21484] = 1,
+    ACTIONS(474), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21517] = 1,
+    ACTIONS(476), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21550] = 3,
+    ACTIONS(478), 1,
+      anon_sym_COMMA,
+    ACTIONS(1685), 1,
+      anon_sym_RPAREN,
+    STATE(994), 1,
+      aux_sym_in_list_repeat1,
+  [21560] = 1,
+    ACTIONS(482), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
       anon_sym_GT,
+      anon_sym_GT_EQ,
       anon_sym_LT,
-    ACTIONS(21), 14,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [21593] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(995), 1,
+      sym_fetch_clause,
+    ACTIONS(1687), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21605] = 1,
+    ACTIONS(1689), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21611] = 1,
+    ACTIONS(1691), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21617] = 1,
+    ACTIONS(1693), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21623] = 1,
+    ACTIONS(1695), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21629] = 1,
+    ACTIONS(1697), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21635] = 1,
+    ACTIONS(1699), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      aux_sym_select_statement_token2,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
+      aux_sym_union_clause_token1,
+  [21641] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(996), 1,
+      sym_offset_clause,
+    STATE(997), 1,
+      sym_fetch_clause,
+    ACTIONS(1701), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21659] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(998), 1,
+      sym_fetch_clause,
+    ACTIONS(1703), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21671] = 1,
+    ACTIONS(1705), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21677] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(999), 1,
+      sym_fetch_clause,
+    ACTIONS(1707), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21689] = 1,
+    ACTIONS(1709), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21695] = 1,
+    ACTIONS(1711), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21701] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1000), 1,
+      sym_fetch_clause,
+    ACTIONS(1713), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21713] = 1,
+    ACTIONS(1715), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21719] = 1,
+    ACTIONS(1717), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21725] = 1,
+    ACTIONS(1719), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21731] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1001), 1,
+      sym_fetch_clause,
+    ACTIONS(1721), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21743] = 1,
+    ACTIONS(1723), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21749] = 1,
+    ACTIONS(1725), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21755] = 1,
+    ACTIONS(1727), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21761] = 1,
+    ACTIONS(1729), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21767] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1002), 1,
+      sym_fetch_clause,
+    ACTIONS(1731), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21779] = 1,
+    ACTIONS(1733), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21785] = 1,
+    ACTIONS(1735), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21791] = 1,
+    ACTIONS(1737), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21797] = 1,
+    ACTIONS(1739), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21803] = 1,
+    ACTIONS(1741), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21809] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1003), 1,
+      sym_offset_clause,
+    STATE(1004), 1,
+      sym_fetch_clause,
+    ACTIONS(1743), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21827] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1005), 1,
+      sym_fetch_clause,
+    ACTIONS(1745), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21839] = 1,
+    ACTIONS(1747), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21845] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1006), 1,
+      sym_fetch_clause,
+    ACTIONS(1749), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21857] = 1,
+    ACTIONS(1751), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21863] = 1,
+    ACTIONS(1753), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21869] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1007), 1,
+      sym_fetch_clause,
+    ACTIONS(1755), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21881] = 1,
+    ACTIONS(1757), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21887] = 1,
+    ACTIONS(1759), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21893] = 1,
+    ACTIONS(1761), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21899] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1008), 1,
+      sym_fetch_clause,
+    ACTIONS(1763), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21911] = 1,
+    ACTIONS(1765), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21917] = 1,
+    ACTIONS(1767), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21923] = 1,
+    ACTIONS(1769), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21929] = 1,
+    ACTIONS(1771), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21935] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1009), 1,
+      sym_fetch_clause,
+    ACTIONS(1773), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21947] = 1,
+    ACTIONS(1775), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21953] = 1,
+    ACTIONS(1777), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21959] = 1,
+    ACTIONS(1779), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21965] = 1,
+    ACTIONS(1781), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21971] = 1,
+    ACTIONS(1783), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [21977] = 7,
+    ACTIONS(303), 1,
       aux_sym_limit_clause_token1,
+    ACTIONS(305), 1,
       aux_sym_offset_clause_token1,
-      aux_sym_or_expression_token1,
-      aux_sym_and_expression_token1,
-      anon_sym_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_GT,
-      anon_sym_GT_EQ,
-      anon_sym_LT_EQ,
-  [253] = 2,
-    ACTIONS(27), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(25), 13,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1010), 1,
+      sym_limit_clause,
+    STATE(1011), 1,
+      sym_offset_clause,
+    STATE(1012), 1,
+      sym_fetch_clause,
+    ACTIONS(1785), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      aux_sym_where_clause_token1,
+      aux_sym_union_clause_token1,
+  [22001] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1013), 1,
+      sym_offset_clause,
+    STATE(1014), 1,
+      sym_fetch_clause,
+    ACTIONS(1787), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22019] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1015), 1,
+      sym_fetch_clause,
+    ACTIONS(1789), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22031] = 1,
+    ACTIONS(1791), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22037] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1016), 1,
+      sym_offset_clause,
+    STATE(1017), 1,
+      sym_fetch_clause,
+    ACTIONS(1793), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22055] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1018), 1,
+      sym_fetch_clause,
+    ACTIONS(1795), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22067] = 1,
+    ACTIONS(1797), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22073] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1019), 1,
+      sym_fetch_clause,
+    ACTIONS(1799), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22085] = 1,
+    ACTIONS(1801), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22091] = 1,
+    ACTIONS(1803), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22097] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1020), 1,
+      sym_offset_clause,
+    STATE(1021), 1,
+      sym_fetch_clause,
+    ACTIONS(1805), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22115] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1022), 1,
+      sym_fetch_clause,
+    ACTIONS(1807), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22127] = 1,
+    ACTIONS(1809), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22133] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1023), 1,
+      sym_fetch_clause,
+    ACTIONS(1811), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22145] = 1,
+    ACTIONS(1813), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22151] = 1,
+    ACTIONS(1815), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22157] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1024), 1,
+      sym_fetch_clause,
+    ACTIONS(1817), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22169] = 1,
+    ACTIONS(1819), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22175] = 1,
+    ACTIONS(1821), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22181] = 1,
+    ACTIONS(1823), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22187] = 5,
+    ACTIONS(305), 1,
+      aux_sym_offset_clause_token1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1025), 1,
+      sym_offset_clause,
+    STATE(1026), 1,
+      sym_fetch_clause,
+    ACTIONS(1825), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22205] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1027), 1,
+      sym_fetch_clause,
+    ACTIONS(1827), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22217] = 1,
+    ACTIONS(1829), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22223] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1028), 1,
+      sym_fetch_clause,
+    ACTIONS(1831), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22235] = 1,
+    ACTIONS(1833), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22241] = 1,
+    ACTIONS(1835), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22247] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1029), 1,
+      sym_fetch_clause,
+    ACTIONS(1837), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22259] = 1,
+    ACTIONS(1839), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22265] = 1,
+    ACTIONS(1841), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22271] = 1,
+    ACTIONS(1843), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22277] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1030), 1,
+      sym_fetch_clause,
+    ACTIONS(1845), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22289] = 1,
+    ACTIONS(1847), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22295] = 1,
+    ACTIONS(1849), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22301] = 1,
+    ACTIONS(1851), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22307] = 1,
+    ACTIONS(1853), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22313] = 1,
+    ACTIONS(714), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
       aux_sym_limit_clause_token1,
       aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
       aux_sym_or_expression_token1,
       aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
       anon_sym_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_GT,
-      anon_sym_GT_EQ,
-      anon_sym_LT_EQ,
-  [273] = 2,
-    ACTIONS(31), 2,
       anon_sym_GT,
+      anon_sym_GT_EQ,
       anon_sym_LT,
-    ACTIONS(29), 12,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22346] = 1,
+    ACTIONS(1016), 30,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
+      aux_sym_union_clause_token1,
+      anon_sym_STAR,
+      aux_sym_group_by_clause_token1,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
       aux_sym_limit_clause_token1,
       aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
       aux_sym_or_expression_token1,
       aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
       anon_sym_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_GT,
-      anon_sym_GT_EQ,
-      anon_sym_LT_EQ,
-  [292] = 2,
-    ACTIONS(35), 2,
       anon_sym_GT,
+      anon_sym_GT_EQ,
       anon_sym_LT,
-    ACTIONS(33), 12,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22379] = 1,
+    ACTIONS(698), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [22391] = 1,
+    ACTIONS(700), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [22403] = 1,
+    ACTIONS(702), 9,
       ts_builtin_sym_end,
       anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      aux_sym_having_clause_token1,
+      aux_sym_order_by_clause_token1,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+  [22415] = 1,
+    ACTIONS(1855), 1,
       anon_sym_RPAREN,
+  [22419] = 1,
+    ACTIONS(691), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
       aux_sym_limit_clause_token1,
       aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
       aux_sym_or_expression_token1,
       aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
       anon_sym_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_GT,
-      anon_sym_GT_EQ,
-      anon_sym_LT_EQ,
-  [311] = 2,
-    ACTIONS(39), 2,
       anon_sym_GT,
+      anon_sym_GT_EQ,
       anon_sym_LT,
-    ACTIONS(37), 12,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22452] = 1,
+    ACTIONS(698), 30,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
       aux_sym_limit_clause_token1,
       aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
       aux_sym_or_expression_token1,
       aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
       anon_sym_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_GT,
-      anon_sym_GT_EQ,
-      anon_sym_LT_EQ,
-  [330] = 2,
-    ACTIONS(43), 2,
       anon_sym_GT,
+      anon_sym_GT_EQ,
       anon_sym_LT,
-    ACTIONS(41), 12,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22485] = 1,
+    ACTIONS(700), 30,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
       aux_sym_limit_clause_token1,
       aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
       aux_sym_or_expression_token1,
       aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
       anon_sym_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_GT,
+      anon_sym_GT,
       anon_sym_GT_EQ,
+      anon_sym_LT,
       anon_sym_LT_EQ,
-  [349] = 10,
-    ACTIONS(5), 1,
-      anon_sym_LPAREN,
-    ACTIONS(9), 1,
-      aux_sym_literal_token1,
-    ACTIONS(11), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(15), 1,
-      sym_number_literal,
-    ACTIONS(19), 1,
-      sym__identifier,
-    STATE(15), 1,
-      sym_primary_expression,
-    ACTIONS(17), 2,
-      aux_sym_boolean_literal_token1,
-      aux_sym_boolean_literal_token2,
-    STATE(9), 2,
-      sym_string_literal,
-      sym_boolean_literal,
-    STATE(10), 3,
-      sym_column_name,
-      sym_comparison_expression,
-      sym_literal,
-  [384] = 3,
-    ACTIONS(49), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(47), 5,
-      anon_sym_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_GT,
-      anon_sym_GT_EQ,
-      anon_sym_LT_EQ,
-    ACTIONS(45), 7,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22518] = 1,
+    ACTIONS(702), 30,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
       aux_sym_limit_clause_token1,
       aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
       aux_sym_or_expression_token1,
       aux_sym_and_expression_token1,
-  [405] = 2,
-    ACTIONS(53), 2,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
       anon_sym_GT,
+      anon_sym_GT_EQ,
       anon_sym_LT,
-    ACTIONS(51), 12,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22551] = 1,
+    ACTIONS(704), 30,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
       aux_sym_limit_clause_token1,
       aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
       aux_sym_or_expression_token1,
       aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
       anon_sym_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_GT,
+      anon_sym_GT,
       anon_sym_GT_EQ,
+      anon_sym_LT,
       anon_sym_LT_EQ,
-  [424] = 8,
-    ACTIONS(19), 1,
-      sym__identifier,
-    ACTIONS(55), 1,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22584] = 1,
+    ACTIONS(706), 30,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
       anon_sym_STAR,
-    ACTIONS(57), 1,
-      anon_sym_LPAREN,
-    ACTIONS(59), 1,
-      aux_sym_aggregate_function_token1,
-    STATE(33), 1,
-      sym_select_expression,
-    STATE(48), 1,
-      sym_select_list,
-    STATE(58), 1,
-      sym_column_list,
-    STATE(39), 2,
-      sym_aggregate_function,
-      sym_column_name,
-  [450] = 7,
-    ACTIONS(63), 1,
-      aux_sym_where_clause_token1,
-    ACTIONS(65), 1,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
       aux_sym_limit_clause_token1,
-    ACTIONS(67), 1,
       aux_sym_offset_clause_token1,
-    STATE(20), 1,
-      sym_where_clause,
-    STATE(28), 1,
-      sym_limit_clause,
-    STATE(44), 1,
-      sym_offset_clause,
-    ACTIONS(61), 2,
-      ts_builtin_sym_end,
-      anon_sym_SEMI,
-  [473] = 2,
-    ACTIONS(71), 1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
       aux_sym_and_expression_token1,
-    ACTIONS(69), 6,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22617] = 1,
+    ACTIONS(708), 30,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
       aux_sym_limit_clause_token1,
       aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
       aux_sym_or_expression_token1,
-  [485] = 1,
-    ACTIONS(73), 7,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22650] = 1,
+    ACTIONS(710), 30,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
       aux_sym_limit_clause_token1,
       aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
       aux_sym_or_expression_token1,
       aux_sym_and_expression_token1,
-  [495] = 5,
-    ACTIONS(65), 1,
-      aux_sym_limit_clause_token1,
-    ACTIONS(67), 1,
-      aux_sym_offset_clause_token1,
-    STATE(30), 1,
-      sym_limit_clause,
-    STATE(36), 1,
-      sym_offset_clause,
-    ACTIONS(75), 2,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22683] = 3,
+    ACTIONS(478), 1,
+      anon_sym_COMMA,
+    ACTIONS(1857), 1,
+      anon_sym_RPAREN,
+    STATE(454), 1,
+      aux_sym_in_list_repeat1,
+  [22693] = 1,
+    ACTIONS(1859), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-  [512] = 5,
-    ACTIONS(19), 1,
-      sym__identifier,
-    ACTIONS(57), 1,
-      anon_sym_LPAREN,
-    ACTIONS(59), 1,
-      aux_sym_aggregate_function_token1,
-    STATE(37), 1,
-      sym_select_expression,
-    STATE(39), 2,
-      sym_aggregate_function,
-      sym_column_name,
-  [529] = 2,
-    ACTIONS(79), 1,
-      aux_sym_or_expression_token1,
-    ACTIONS(77), 5,
+      aux_sym_union_clause_token1,
+  [22699] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1033), 1,
+      sym_fetch_clause,
+    ACTIONS(1861), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      aux_sym_limit_clause_token1,
-      aux_sym_offset_clause_token1,
-  [540] = 1,
-    ACTIONS(81), 6,
+      aux_sym_union_clause_token1,
+  [22711] = 1,
+    ACTIONS(1863), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      aux_sym_limit_clause_token1,
-      aux_sym_offset_clause_token1,
-      aux_sym_or_expression_token1,
-  [549] = 1,
-    ACTIONS(83), 5,
+      aux_sym_union_clause_token1,
+  [22717] = 1,
+    ACTIONS(1865), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      aux_sym_where_clause_token1,
-      aux_sym_limit_clause_token1,
-      aux_sym_offset_clause_token1,
-  [557] = 1,
-    ACTIONS(85), 5,
+      aux_sym_union_clause_token1,
+  [22723] = 1,
+    ACTIONS(1867), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      aux_sym_limit_clause_token1,
-      aux_sym_offset_clause_token1,
-  [565] = 1,
-    ACTIONS(87), 5,
+      aux_sym_union_clause_token1,
+  [22729] = 1,
+    ACTIONS(1869), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      aux_sym_limit_clause_token1,
-      aux_sym_offset_clause_token1,
-  [573] = 5,
-    ACTIONS(11), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(13), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(89), 1,
-      sym__identifier,
-    STATE(17), 1,
-      sym_file_name,
-    STATE(24), 1,
-      sym_string_literal,
-  [589] = 3,
-    ACTIONS(67), 1,
+      aux_sym_union_clause_token1,
+  [22735] = 1,
+    ACTIONS(1871), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22741] = 1,
+    ACTIONS(1873), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22747] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1034), 1,
+      sym_fetch_clause,
+    ACTIONS(1875), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22759] = 1,
+    ACTIONS(1877), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22765] = 1,
+    ACTIONS(1879), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22771] = 1,
+    ACTIONS(1881), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22777] = 1,
+    ACTIONS(1883), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22783] = 1,
+    ACTIONS(1885), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22789] = 1,
+    ACTIONS(1887), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22795] = 5,
+    ACTIONS(305), 1,
       aux_sym_offset_clause_token1,
-    STATE(36), 1,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1035), 1,
       sym_offset_clause,
-    ACTIONS(75), 2,
+    STATE(1036), 1,
+      sym_fetch_clause,
+    ACTIONS(1889), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22813] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1037), 1,
+      sym_fetch_clause,
+    ACTIONS(1891), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22825] = 1,
+    ACTIONS(1893), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22831] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1038), 1,
+      sym_fetch_clause,
+    ACTIONS(1895), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22843] = 1,
+    ACTIONS(1897), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22849] = 1,
+    ACTIONS(1899), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22855] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1039), 1,
+      sym_fetch_clause,
+    ACTIONS(1901), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22867] = 1,
+    ACTIONS(1903), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22873] = 1,
+    ACTIONS(1905), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22879] = 1,
+    ACTIONS(1907), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22885] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1040), 1,
+      sym_fetch_clause,
+    ACTIONS(1909), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22897] = 1,
+    ACTIONS(1911), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22903] = 1,
+    ACTIONS(1913), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22909] = 1,
+    ACTIONS(1915), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22915] = 1,
+    ACTIONS(1917), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22921] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1041), 1,
+      sym_fetch_clause,
+    ACTIONS(1919), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22933] = 1,
+    ACTIONS(1921), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22939] = 1,
+    ACTIONS(1923), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22945] = 1,
+    ACTIONS(1925), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22951] = 1,
+    ACTIONS(1927), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [22957] = 1,
+    ACTIONS(1929), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-  [600] = 1,
-    ACTIONS(91), 4,
+      aux_sym_union_clause_token1,
+  [22963] = 1,
+    ACTIONS(714), 30,
       ts_builtin_sym_end,
       anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
       aux_sym_limit_clause_token1,
       aux_sym_offset_clause_token1,
-  [607] = 3,
-    ACTIONS(67), 1,
-      aux_sym_offset_clause_token1,
-    STATE(43), 1,
-      sym_offset_clause,
-    ACTIONS(93), 2,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [22996] = 1,
+    ACTIONS(1016), 30,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-  [618] = 3,
-    ACTIONS(95), 1,
-      anon_sym_STAR,
-    ACTIONS(97), 1,
-      sym__identifier,
-    STATE(47), 1,
-      sym_column_name,
-  [628] = 3,
-    ACTIONS(99), 1,
-      aux_sym_select_statement_token2,
-    ACTIONS(101), 1,
-      anon_sym_COMMA,
-    STATE(32), 1,
-      aux_sym_column_list_repeat1,
-  [638] = 3,
-    ACTIONS(104), 1,
-      aux_sym_select_statement_token2,
-    ACTIONS(106), 1,
-      anon_sym_COMMA,
-    STATE(34), 1,
-      aux_sym_column_list_repeat1,
-  [648] = 3,
-    ACTIONS(106), 1,
+      aux_sym_union_clause_token1,
       anon_sym_COMMA,
-    ACTIONS(108), 1,
-      aux_sym_select_statement_token2,
-    STATE(32), 1,
-      aux_sym_column_list_repeat1,
-  [658] = 1,
-    ACTIONS(110), 3,
+      anon_sym_STAR,
+      aux_sym_sort_direction_token1,
+      aux_sym_sort_direction_token2,
+      aux_sym_limit_clause_token1,
+      aux_sym_offset_clause_token1,
+      aux_sym_fetch_clause_token1,
+      aux_sym_or_expression_token1,
+      aux_sym_and_expression_token1,
+      aux_sym_not_expression_token1,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_GT,
+      anon_sym_GT,
+      anon_sym_GT_EQ,
+      anon_sym_LT,
+      anon_sym_LT_EQ,
+      aux_sym_comparison_expression_token1,
+      aux_sym_comparison_expression_token2,
+      aux_sym_comparison_expression_token3,
+      aux_sym_comparison_expression_token4,
+      aux_sym_like_escape_token1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_PIPE_PIPE,
+  [23029] = 1,
+    ACTIONS(1931), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-      aux_sym_offset_clause_token1,
-  [664] = 1,
-    ACTIONS(93), 2,
+      aux_sym_union_clause_token1,
+  [23035] = 1,
+    ACTIONS(1933), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-  [669] = 1,
-    ACTIONS(99), 2,
-      aux_sym_select_statement_token2,
-      anon_sym_COMMA,
-  [674] = 1,
-    ACTIONS(112), 2,
-      aux_sym_select_statement_token2,
-      anon_sym_COMMA,
-  [679] = 1,
-    ACTIONS(114), 2,
-      aux_sym_select_statement_token2,
-      anon_sym_COMMA,
-  [684] = 2,
-    ACTIONS(116), 1,
+      aux_sym_union_clause_token1,
+  [23041] = 3,
+    ACTIONS(307), 1,
+      aux_sym_fetch_clause_token1,
+    STATE(1042), 1,
+      sym_fetch_clause,
+    ACTIONS(1935), 3,
       ts_builtin_sym_end,
-    ACTIONS(118), 1,
       anon_sym_SEMI,
-  [691] = 1,
-    ACTIONS(120), 2,
-      aux_sym_select_statement_token2,
-      anon_sym_COMMA,
-  [696] = 1,
-    ACTIONS(122), 2,
+      aux_sym_union_clause_token1,
+  [23053] = 1,
+    ACTIONS(1937), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-  [701] = 1,
-    ACTIONS(124), 2,
+      aux_sym_union_clause_token1,
+  [23059] = 1,
+    ACTIONS(1939), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-  [706] = 1,
-    ACTIONS(75), 2,
+      aux_sym_union_clause_token1,
+  [23065] = 1,
+    ACTIONS(1941), 3,
       ts_builtin_sym_end,
       anon_sym_SEMI,
-  [711] = 2,
-    ACTIONS(97), 1,
-      sym__identifier,
-    STATE(56), 1,
-      sym_column_name,
-  [718] = 1,
-    ACTIONS(126), 1,
-      aux_sym_string_literal_token1,
-  [722] = 1,
-    ACTIONS(128), 1,
-      anon_sym_RPAREN,
-  [726] = 1,
-    ACTIONS(130), 1,
-      aux_sym_select_statement_token2,
-  [730] = 1,
-    ACTIONS(132), 1,
+      aux_sym_union_clause_token1,
+  [23071] = 1,
+    ACTIONS(1943), 3,
       ts_builtin_sym_end,
-  [734] = 1,
-    ACTIONS(134), 1,
-      aux_sym_string_literal_token2,
-  [738] = 1,
-    ACTIONS(136), 1,
-      anon_sym_SQUOTE,
-  [742] = 1,
-    ACTIONS(138), 1,
-      anon_sym_RPAREN,
-  [746] = 1,
-    ACTIONS(140), 1,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [23077] = 1,
+    ACTIONS(1945), 3,
       ts_builtin_sym_end,
-  [750] = 1,
-    ACTIONS(142), 1,
-      sym_number_literal,
-  [754] = 1,
-    ACTIONS(144), 1,
-      sym_number_literal,
-  [758] = 1,
-    ACTIONS(146), 1,
-      anon_sym_RPAREN,
-  [762] = 1,
-    ACTIONS(136), 1,
-      anon_sym_DQUOTE,
-  [766] = 1,
-    ACTIONS(148), 1,
-      aux_sym_select_statement_token2,
-  [770] = 1,
-    ACTIONS(150), 1,
-      anon_sym_LPAREN,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [23083] = 1,
+    ACTIONS(1947), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
+  [23089] = 1,
+    ACTIONS(1949), 3,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_union_clause_token1,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 50,
-  [SMALL_STATE(4)] = 100,
-  [SMALL_STATE(5)] = 147,
-  [SMALL_STATE(6)] = 191,
-  [SMALL_STATE(7)] = 232,
-  [SMALL_STATE(8)] = 253,
-  [SMALL_STATE(9)] = 273,
-  [SMALL_STATE(10)] = 292,
-  [SMALL_STATE(11)] = 311,
-  [SMALL_STATE(12)] = 330,
-  [SMALL_STATE(13)] = 349,
-  [SMALL_STATE(14)] = 384,
-  [SMALL_STATE(15)] = 405,
-  [SMALL_STATE(16)] = 424,
-  [SMALL_STATE(17)] = 450,
-  [SMALL_STATE(18)] = 473,
-  [SMALL_STATE(19)] = 485,
-  [SMALL_STATE(20)] = 495,
-  [SMALL_STATE(21)] = 512,
-  [SMALL_STATE(22)] = 529,
-  [SMALL_STATE(23)] = 540,
-  [SMALL_STATE(24)] = 549,
-  [SMALL_STATE(25)] = 557,
-  [SMALL_STATE(26)] = 565,
-  [SMALL_STATE(27)] = 573,
-  [SMALL_STATE(28)] = 589,
-  [SMALL_STATE(29)] = 600,
-  [SMALL_STATE(30)] = 607,
-  [SMALL_STATE(31)] = 618,
-  [SMALL_STATE(32)] = 628,
-  [SMALL_STATE(33)] = 638,
-  [SMALL_STATE(34)] = 648,
-  [SMALL_STATE(35)] = 658,
-  [SMALL_STATE(36)] = 664,
-  [SMALL_STATE(37)] = 669,
-  [SMALL_STATE(38)] = 674,
-  [SMALL_STATE(39)] = 679,
-  [SMALL_STATE(40)] = 684,
-  [SMALL_STATE(41)] = 691,
-  [SMALL_STATE(42)] = 696,
-  [SMALL_STATE(43)] = 701,
-  [SMALL_STATE(44)] = 706,
-  [SMALL_STATE(45)] = 711,
-  [SMALL_STATE(46)] = 718,
-  [SMALL_STATE(47)] = 722,
-  [SMALL_STATE(48)] = 726,
-  [SMALL_STATE(49)] = 730,
-  [SMALL_STATE(50)] = 734,
-  [SMALL_STATE(51)] = 738,
-  [SMALL_STATE(52)] = 742,
-  [SMALL_STATE(53)] = 746,
-  [SMALL_STATE(54)] = 750,
-  [SMALL_STATE(55)] = 754,
-  [SMALL_STATE(56)] = 758,
-  [SMALL_STATE(57)] = 762,
-  [SMALL_STATE(58)] = 766,
-  [SMALL_STATE(59)] = 770,
+  [SMALL_STATE(3)] = 7,
+  [SMALL_STATE(4)] = 125,
+  [SMALL_STATE(5)] = 129,
+  [SMALL_STATE(6)] = 142,
+  [SMALL_STATE(7)] = 149,
+  [SMALL_STATE(8)] = 154,
+  [SMALL_STATE(9)] = 168,
+  [SMALL_STATE(10)] = 172,
+  [SMALL_STATE(11)] = 177,
+  [SMALL_STATE(12)] = 283,
+  [SMALL_STATE(13)] = 287,
+  [SMALL_STATE(14)] = 291,
+  [SMALL_STATE(15)] = 295,
+  [SMALL_STATE(16)] = 392,
+  [SMALL_STATE(17)] = 483,
+  [SMALL_STATE(18)] = 487,
+  [SMALL_STATE(19)] = 497,
+  [SMALL_STATE(20)] = 529,
+  [SMALL_STATE(21)] = 533,
+  [SMALL_STATE(22)] = 537,
+  [SMALL_STATE(23)] = 541,
+  [SMALL_STATE(24)] = 573,
+  [SMALL_STATE(25)] = 605,
+  [SMALL_STATE(26)] = 637,
+  [SMALL_STATE(27)] = 675,
+  [SMALL_STATE(28)] = 679,
+  [SMALL_STATE(29)] = 683,
+  [SMALL_STATE(30)] = 693,
+  [SMALL_STATE(31)] = 725,
+  [SMALL_STATE(32)] = 729,
+  [SMALL_STATE(33)] = 761,
+  [SMALL_STATE(34)] = 765,
+  [SMALL_STATE(35)] = 773,
+  [SMALL_STATE(36)] = 793,
+  [SMALL_STATE(37)] = 807,
+  [SMALL_STATE(38)] = 822,
+  [SMALL_STATE(39)] = 889,
+  [SMALL_STATE(40)] = 921,
+  [SMALL_STATE(41)] = 953,
+  [SMALL_STATE(42)] = 985,
+  [SMALL_STATE(43)] = 1017,
+  [SMALL_STATE(44)] = 1049,
+  [SMALL_STATE(45)] = 1081,
+  [SMALL_STATE(46)] = 1113,
+  [SMALL_STATE(47)] = 1145,
+  [SMALL_STATE(48)] = 1177,
+  [SMALL_STATE(49)] = 1209,
+  [SMALL_STATE(50)] = 1216,
+  [SMALL_STATE(51)] = 1220,
+  [SMALL_STATE(52)] = 1230,
+  [SMALL_STATE(53)] = 1236,
+  [SMALL_STATE(54)] = 1250,
+  [SMALL_STATE(55)] = 1254,
+  [SMALL_STATE(56)] = 1258,
+  [SMALL_STATE(57)] = 1262,
+  [SMALL_STATE(58)] = 1266,
+  [SMALL_STATE(59)] = 1279,
+  [SMALL_STATE(60)] = 1311,
+  [SMALL_STATE(61)] = 1417,
+  [SMALL_STATE(62)] = 1523,
+  [SMALL_STATE(63)] = 1529,
+  [SMALL_STATE(64)] = 1548,
+  [SMALL_STATE(65)] = 1552,
+  [SMALL_STATE(66)] = 1556,
+  [SMALL_STATE(67)] = 1560,
+  [SMALL_STATE(68)] = 1576,
+  [SMALL_STATE(69)] = 1688,
+  [SMALL_STATE(70)] = 1698,
+  [SMALL_STATE(71)] = 1810,
+  [SMALL_STATE(72)] = 1823,
+  [SMALL_STATE(73)] = 1830,
+  [SMALL_STATE(74)] = 1933,
+  [SMALL_STATE(75)] = 2033,
+  [SMALL_STATE(76)] = 2124,
+  [SMALL_STATE(77)] = 2134,
+  [SMALL_STATE(78)] = 2225,
+  [SMALL_STATE(79)] = 2316,
+  [SMALL_STATE(80)] = 2407,
+  [SMALL_STATE(81)] = 2498,
+  [SMALL_STATE(82)] = 2589,
+  [SMALL_STATE(83)] = 2680,
+  [SMALL_STATE(84)] = 2771,
+  [SMALL_STATE(85)] = 2862,
+  [SMALL_STATE(86)] = 2953,
+  [SMALL_STATE(87)] = 2960,
+  [SMALL_STATE(88)] = 2973,
+  [SMALL_STATE(89)] = 3064,
+  [SMALL_STATE(90)] = 3155,
+  [SMALL_STATE(91)] = 3246,
+  [SMALL_STATE(92)] = 3337,
+  [SMALL_STATE(93)] = 3428,
+  [SMALL_STATE(94)] = 3432,
+  [SMALL_STATE(95)] = 3439,
+  [SMALL_STATE(96)] = 3445,
+  [SMALL_STATE(97)] = 3459,
+  [SMALL_STATE(98)] = 3491,
+  [SMALL_STATE(99)] = 3523,
+  [SMALL_STATE(100)] = 3555,
+  [SMALL_STATE(101)] = 3587,
+  [SMALL_STATE(102)] = 3591,
+  [SMALL_STATE(103)] = 3595,
+  [SMALL_STATE(104)] = 3627,
+  [SMALL_STATE(105)] = 3733,
+  [SMALL_STATE(106)] = 3737,
+  [SMALL_STATE(107)] = 3751,
+  [SMALL_STATE(108)] = 3783,
+  [SMALL_STATE(109)] = 3815,
+  [SMALL_STATE(110)] = 3847,
+  [SMALL_STATE(111)] = 3851,
+  [SMALL_STATE(112)] = 3855,
+  [SMALL_STATE(113)] = 3870,
+  [SMALL_STATE(114)] = 3930,
+  [SMALL_STATE(115)] = 3945,
+  [SMALL_STATE(116)] = 3950,
+  [SMALL_STATE(117)] = 3960,
+  [SMALL_STATE(118)] = 3964,
+  [SMALL_STATE(119)] = 3996,
+  [SMALL_STATE(120)] = 4006,
+  [SMALL_STATE(121)] = 4010,
+  [SMALL_STATE(122)] = 4014,
+  [SMALL_STATE(123)] = 4018,
+  [SMALL_STATE(124)] = 4050,
+  [SMALL_STATE(125)] = 4055,
+  [SMALL_STATE(126)] = 4060,
+  [SMALL_STATE(127)] = 4080,
+  [SMALL_STATE(128)] = 4092,
+  [SMALL_STATE(129)] = 4124,
+  [SMALL_STATE(130)] = 4215,
+  [SMALL_STATE(131)] = 4306,
+  [SMALL_STATE(132)] = 4313,
+  [SMALL_STATE(133)] = 4357,
+  [SMALL_STATE(134)] = 4401,
+  [SMALL_STATE(135)] = 4445,
+  [SMALL_STATE(136)] = 4489,
+  [SMALL_STATE(137)] = 4533,
+  [SMALL_STATE(138)] = 4577,
+  [SMALL_STATE(139)] = 4621,
+  [SMALL_STATE(140)] = 4670,
+  [SMALL_STATE(141)] = 4719,
+  [SMALL_STATE(142)] = 4756,
+  [SMALL_STATE(143)] = 4788,
+  [SMALL_STATE(144)] = 4798,
+  [SMALL_STATE(145)] = 4830,
+  [SMALL_STATE(146)] = 4868,
+  [SMALL_STATE(147)] = 4906,
+  [SMALL_STATE(148)] = 4938,
+  [SMALL_STATE(149)] = 4970,
+  [SMALL_STATE(150)] = 5008,
+  [SMALL_STATE(151)] = 5014,
+  [SMALL_STATE(152)] = 5021,
+  [SMALL_STATE(153)] = 5127,
+  [SMALL_STATE(154)] = 5131,
+  [SMALL_STATE(155)] = 5163,
+  [SMALL_STATE(156)] = 5167,
+  [SMALL_STATE(157)] = 5171,
+  [SMALL_STATE(158)] = 5187,
+  [SMALL_STATE(159)] = 5293,
+  [SMALL_STATE(160)] = 5297,
+  [SMALL_STATE(161)] = 5403,
+  [SMALL_STATE(162)] = 5407,
+  [SMALL_STATE(163)] = 5411,
+  [SMALL_STATE(164)] = 5418,
+  [SMALL_STATE(165)] = 5422,
+  [SMALL_STATE(166)] = 5429,
+  [SMALL_STATE(167)] = 5471,
+  [SMALL_STATE(168)] = 5507,
+  [SMALL_STATE(169)] = 5537,
+  [SMALL_STATE(170)] = 5561,
+  [SMALL_STATE(171)] = 5609,
+  [SMALL_STATE(172)] = 5627,
+  [SMALL_STATE(173)] = 5639,
+  [SMALL_STATE(174)] = 5645,
+  [SMALL_STATE(175)] = 5705,
+  [SMALL_STATE(176)] = 5737,
+  [SMALL_STATE(177)] = 5843,
+  [SMALL_STATE(178)] = 5875,
+  [SMALL_STATE(179)] = 5885,
+  [SMALL_STATE(180)] = 5889,
+  [SMALL_STATE(181)] = 5893,
+  [SMALL_STATE(182)] = 5897,
+  [SMALL_STATE(183)] = 5946,
+  [SMALL_STATE(184)] = 5995,
+  [SMALL_STATE(185)] = 6027,
+  [SMALL_STATE(186)] = 6034,
+  [SMALL_STATE(187)] = 6066,
+  [SMALL_STATE(188)] = 6098,
+  [SMALL_STATE(189)] = 6108,
+  [SMALL_STATE(190)] = 6140,
+  [SMALL_STATE(191)] = 6144,
+  [SMALL_STATE(192)] = 6148,
+  [SMALL_STATE(193)] = 6154,
+  [SMALL_STATE(194)] = 6169,
+  [SMALL_STATE(195)] = 6184,
+  [SMALL_STATE(196)] = 6199,
+  [SMALL_STATE(197)] = 6305,
+  [SMALL_STATE(198)] = 6309,
+  [SMALL_STATE(199)] = 6313,
+  [SMALL_STATE(200)] = 6317,
+  [SMALL_STATE(201)] = 6414,
+  [SMALL_STATE(202)] = 6505,
+  [SMALL_STATE(203)] = 6509,
+  [SMALL_STATE(204)] = 6519,
+  [SMALL_STATE(205)] = 6552,
+  [SMALL_STATE(206)] = 6556,
+  [SMALL_STATE(207)] = 6560,
+  [SMALL_STATE(208)] = 6564,
+  [SMALL_STATE(209)] = 6597,
+  [SMALL_STATE(210)] = 6630,
+  [SMALL_STATE(211)] = 6663,
+  [SMALL_STATE(212)] = 6702,
+  [SMALL_STATE(213)] = 6735,
+  [SMALL_STATE(214)] = 6739,
+  [SMALL_STATE(215)] = 6772,
+  [SMALL_STATE(216)] = 6776,
+  [SMALL_STATE(217)] = 6788,
+  [SMALL_STATE(218)] = 6803,
+  [SMALL_STATE(219)] = 6819,
+  [SMALL_STATE(220)] = 6887,
+  [SMALL_STATE(221)] = 6920,
+  [SMALL_STATE(222)] = 6953,
+  [SMALL_STATE(223)] = 6986,
+  [SMALL_STATE(224)] = 7019,
+  [SMALL_STATE(225)] = 7052,
+  [SMALL_STATE(226)] = 7085,
+  [SMALL_STATE(227)] = 7118,
+  [SMALL_STATE(228)] = 7151,
+  [SMALL_STATE(229)] = 7184,
+  [SMALL_STATE(230)] = 7217,
+  [SMALL_STATE(231)] = 7236,
+  [SMALL_STATE(232)] = 7246,
+  [SMALL_STATE(233)] = 7355,
+  [SMALL_STATE(234)] = 7368,
+  [SMALL_STATE(235)] = 7376,
+  [SMALL_STATE(236)] = 7384,
+  [SMALL_STATE(237)] = 7397,
+  [SMALL_STATE(238)] = 7401,
+  [SMALL_STATE(239)] = 7405,
+  [SMALL_STATE(240)] = 7441,
+  [SMALL_STATE(241)] = 7471,
+  [SMALL_STATE(242)] = 7495,
+  [SMALL_STATE(243)] = 7513,
+  [SMALL_STATE(244)] = 7525,
+  [SMALL_STATE(245)] = 7531,
+  [SMALL_STATE(246)] = 7561,
+  [SMALL_STATE(247)] = 7585,
+  [SMALL_STATE(248)] = 7603,
+  [SMALL_STATE(249)] = 7615,
+  [SMALL_STATE(250)] = 7621,
+  [SMALL_STATE(251)] = 7645,
+  [SMALL_STATE(252)] = 7663,
+  [SMALL_STATE(253)] = 7675,
+  [SMALL_STATE(254)] = 7681,
+  [SMALL_STATE(255)] = 7699,
+  [SMALL_STATE(256)] = 7711,
+  [SMALL_STATE(257)] = 7717,
+  [SMALL_STATE(258)] = 7759,
+  [SMALL_STATE(259)] = 7795,
+  [SMALL_STATE(260)] = 7825,
+  [SMALL_STATE(261)] = 7849,
+  [SMALL_STATE(262)] = 7867,
+  [SMALL_STATE(263)] = 7879,
+  [SMALL_STATE(264)] = 7885,
+  [SMALL_STATE(265)] = 7897,
+  [SMALL_STATE(266)] = 7903,
+  [SMALL_STATE(267)] = 7909,
+  [SMALL_STATE(268)] = 7951,
+  [SMALL_STATE(269)] = 7987,
+  [SMALL_STATE(270)] = 8017,
+  [SMALL_STATE(271)] = 8041,
+  [SMALL_STATE(272)] = 8089,
+  [SMALL_STATE(273)] = 8107,
+  [SMALL_STATE(274)] = 8119,
+  [SMALL_STATE(275)] = 8125,
+  [SMALL_STATE(276)] = 8145,
+  [SMALL_STATE(277)] = 8150,
+  [SMALL_STATE(278)] = 8182,
+  [SMALL_STATE(279)] = 8192,
+  [SMALL_STATE(280)] = 8224,
+  [SMALL_STATE(281)] = 8256,
+  [SMALL_STATE(282)] = 8288,
+  [SMALL_STATE(283)] = 8320,
+  [SMALL_STATE(284)] = 8352,
+  [SMALL_STATE(285)] = 8384,
+  [SMALL_STATE(286)] = 8421,
+  [SMALL_STATE(287)] = 8453,
+  [SMALL_STATE(288)] = 8463,
+  [SMALL_STATE(289)] = 8495,
+  [SMALL_STATE(290)] = 8499,
+  [SMALL_STATE(291)] = 8503,
+  [SMALL_STATE(292)] = 8507,
+  [SMALL_STATE(293)] = 8511,
+  [SMALL_STATE(294)] = 8525,
+  [SMALL_STATE(295)] = 8558,
+  [SMALL_STATE(296)] = 8664,
+  [SMALL_STATE(297)] = 8683,
+  [SMALL_STATE(298)] = 8687,
+  [SMALL_STATE(299)] = 8691,
+  [SMALL_STATE(300)] = 8695,
+  [SMALL_STATE(301)] = 8807,
+  [SMALL_STATE(302)] = 8820,
+  [SMALL_STATE(303)] = 8923,
+  [SMALL_STATE(304)] = 9023,
+  [SMALL_STATE(305)] = 9114,
+  [SMALL_STATE(306)] = 9124,
+  [SMALL_STATE(307)] = 9215,
+  [SMALL_STATE(308)] = 9306,
+  [SMALL_STATE(309)] = 9397,
+  [SMALL_STATE(310)] = 9488,
+  [SMALL_STATE(311)] = 9579,
+  [SMALL_STATE(312)] = 9670,
+  [SMALL_STATE(313)] = 9761,
+  [SMALL_STATE(314)] = 9852,
+  [SMALL_STATE(315)] = 9943,
+  [SMALL_STATE(316)] = 9950,
+  [SMALL_STATE(317)] = 9963,
+  [SMALL_STATE(318)] = 10054,
+  [SMALL_STATE(319)] = 10145,
+  [SMALL_STATE(320)] = 10236,
+  [SMALL_STATE(321)] = 10327,
+  [SMALL_STATE(322)] = 10418,
+  [SMALL_STATE(323)] = 10422,
+  [SMALL_STATE(324)] = 10426,
+  [SMALL_STATE(325)] = 10430,
+  [SMALL_STATE(326)] = 10445,
+  [SMALL_STATE(327)] = 10462,
+  [SMALL_STATE(328)] = 10466,
+  [SMALL_STATE(329)] = 10572,
+  [SMALL_STATE(330)] = 10576,
+  [SMALL_STATE(331)] = 10580,
+  [SMALL_STATE(332)] = 10584,
+  [SMALL_STATE(333)] = 10681,
+  [SMALL_STATE(334)] = 10772,
+  [SMALL_STATE(335)] = 10776,
+  [SMALL_STATE(336)] = 10786,
+  [SMALL_STATE(337)] = 10819,
+  [SMALL_STATE(338)] = 10823,
+  [SMALL_STATE(339)] = 10827,
+  [SMALL_STATE(340)] = 10831,
+  [SMALL_STATE(341)] = 10864,
+  [SMALL_STATE(342)] = 10897,
+  [SMALL_STATE(343)] = 10930,
+  [SMALL_STATE(344)] = 10969,
+  [SMALL_STATE(345)] = 11002,
+  [SMALL_STATE(346)] = 11006,
+  [SMALL_STATE(347)] = 11039,
+  [SMALL_STATE(348)] = 11043,
+  [SMALL_STATE(349)] = 11058,
+  [SMALL_STATE(350)] = 11077,
+  [SMALL_STATE(351)] = 11089,
+  [SMALL_STATE(352)] = 11104,
+  [SMALL_STATE(353)] = 11120,
+  [SMALL_STATE(354)] = 11188,
+  [SMALL_STATE(355)] = 11221,
+  [SMALL_STATE(356)] = 11254,
+  [SMALL_STATE(357)] = 11287,
+  [SMALL_STATE(358)] = 11320,
+  [SMALL_STATE(359)] = 11353,
+  [SMALL_STATE(360)] = 11386,
+  [SMALL_STATE(361)] = 11419,
+  [SMALL_STATE(362)] = 11452,
+  [SMALL_STATE(363)] = 11485,
+  [SMALL_STATE(364)] = 11518,
+  [SMALL_STATE(365)] = 11525,
+  [SMALL_STATE(366)] = 11532,
+  [SMALL_STATE(367)] = 11539,
+  [SMALL_STATE(368)] = 11546,
+  [SMALL_STATE(369)] = 11576,
+  [SMALL_STATE(370)] = 11600,
+  [SMALL_STATE(371)] = 11618,
+  [SMALL_STATE(372)] = 11630,
+  [SMALL_STATE(373)] = 11636,
+  [SMALL_STATE(374)] = 11660,
+  [SMALL_STATE(375)] = 11678,
+  [SMALL_STATE(376)] = 11690,
+  [SMALL_STATE(377)] = 11696,
+  [SMALL_STATE(378)] = 11714,
+  [SMALL_STATE(379)] = 11726,
+  [SMALL_STATE(380)] = 11732,
+  [SMALL_STATE(381)] = 11744,
+  [SMALL_STATE(382)] = 11750,
+  [SMALL_STATE(383)] = 11756,
+  [SMALL_STATE(384)] = 11780,
+  [SMALL_STATE(385)] = 11798,
+  [SMALL_STATE(386)] = 11810,
+  [SMALL_STATE(387)] = 11816,
+  [SMALL_STATE(388)] = 11834,
+  [SMALL_STATE(389)] = 11846,
+  [SMALL_STATE(390)] = 11852,
+  [SMALL_STATE(391)] = 11864,
+  [SMALL_STATE(392)] = 11870,
+  [SMALL_STATE(393)] = 11876,
+  [SMALL_STATE(394)] = 11894,
+  [SMALL_STATE(395)] = 11906,
+  [SMALL_STATE(396)] = 11912,
+  [SMALL_STATE(397)] = 11924,
+  [SMALL_STATE(398)] = 11930,
+  [SMALL_STATE(399)] = 11936,
+  [SMALL_STATE(400)] = 11948,
+  [SMALL_STATE(401)] = 11954,
+  [SMALL_STATE(402)] = 11960,
+  [SMALL_STATE(403)] = 11996,
+  [SMALL_STATE(404)] = 12026,
+  [SMALL_STATE(405)] = 12050,
+  [SMALL_STATE(406)] = 12068,
+  [SMALL_STATE(407)] = 12080,
+  [SMALL_STATE(408)] = 12086,
+  [SMALL_STATE(409)] = 12116,
+  [SMALL_STATE(410)] = 12140,
+  [SMALL_STATE(411)] = 12158,
+  [SMALL_STATE(412)] = 12170,
+  [SMALL_STATE(413)] = 12176,
+  [SMALL_STATE(414)] = 12200,
+  [SMALL_STATE(415)] = 12218,
+  [SMALL_STATE(416)] = 12230,
+  [SMALL_STATE(417)] = 12236,
+  [SMALL_STATE(418)] = 12254,
+  [SMALL_STATE(419)] = 12266,
+  [SMALL_STATE(420)] = 12272,
+  [SMALL_STATE(421)] = 12284,
+  [SMALL_STATE(422)] = 12290,
+  [SMALL_STATE(423)] = 12296,
+  [SMALL_STATE(424)] = 12302,
+  [SMALL_STATE(425)] = 12338,
+  [SMALL_STATE(426)] = 12368,
+  [SMALL_STATE(427)] = 12392,
+  [SMALL_STATE(428)] = 12410,
+  [SMALL_STATE(429)] = 12422,
+  [SMALL_STATE(430)] = 12428,
+  [SMALL_STATE(431)] = 12458,
+  [SMALL_STATE(432)] = 12482,
+  [SMALL_STATE(433)] = 12500,
+  [SMALL_STATE(434)] = 12512,
+  [SMALL_STATE(435)] = 12518,
+  [SMALL_STATE(436)] = 12542,
+  [SMALL_STATE(437)] = 12560,
+  [SMALL_STATE(438)] = 12572,
+  [SMALL_STATE(439)] = 12578,
+  [SMALL_STATE(440)] = 12596,
+  [SMALL_STATE(441)] = 12608,
+  [SMALL_STATE(442)] = 12614,
+  [SMALL_STATE(443)] = 12656,
+  [SMALL_STATE(444)] = 12692,
+  [SMALL_STATE(445)] = 12722,
+  [SMALL_STATE(446)] = 12746,
+  [SMALL_STATE(447)] = 12764,
+  [SMALL_STATE(448)] = 12776,
+  [SMALL_STATE(449)] = 12782,
+  [SMALL_STATE(450)] = 12794,
+  [SMALL_STATE(451)] = 12800,
+  [SMALL_STATE(452)] = 12806,
+  [SMALL_STATE(453)] = 12811,
+  [SMALL_STATE(454)] = 12843,
+  [SMALL_STATE(455)] = 12853,
+  [SMALL_STATE(456)] = 12886,
+  [SMALL_STATE(457)] = 12919,
+  [SMALL_STATE(458)] = 12952,
+  [SMALL_STATE(459)] = 12985,
+  [SMALL_STATE(460)] = 12989,
+  [SMALL_STATE(461)] = 13022,
+  [SMALL_STATE(462)] = 13026,
+  [SMALL_STATE(463)] = 13059,
+  [SMALL_STATE(464)] = 13092,
+  [SMALL_STATE(465)] = 13125,
+  [SMALL_STATE(466)] = 13129,
+  [SMALL_STATE(467)] = 13162,
+  [SMALL_STATE(468)] = 13172,
+  [SMALL_STATE(469)] = 13176,
+  [SMALL_STATE(470)] = 13180,
+  [SMALL_STATE(471)] = 13184,
+  [SMALL_STATE(472)] = 13217,
+  [SMALL_STATE(473)] = 13230,
+  [SMALL_STATE(474)] = 13263,
+  [SMALL_STATE(475)] = 13354,
+  [SMALL_STATE(476)] = 13445,
+  [SMALL_STATE(477)] = 13452,
+  [SMALL_STATE(478)] = 13497,
+  [SMALL_STATE(479)] = 13542,
+  [SMALL_STATE(480)] = 13587,
+  [SMALL_STATE(481)] = 13632,
+  [SMALL_STATE(482)] = 13677,
+  [SMALL_STATE(483)] = 13722,
+  [SMALL_STATE(484)] = 13767,
+  [SMALL_STATE(485)] = 13817,
+  [SMALL_STATE(486)] = 13867,
+  [SMALL_STATE(487)] = 13904,
+  [SMALL_STATE(488)] = 13937,
+  [SMALL_STATE(489)] = 13947,
+  [SMALL_STATE(490)] = 13980,
+  [SMALL_STATE(491)] = 14019,
+  [SMALL_STATE(492)] = 14058,
+  [SMALL_STATE(493)] = 14091,
+  [SMALL_STATE(494)] = 14124,
+  [SMALL_STATE(495)] = 14163,
+  [SMALL_STATE(496)] = 14167,
+  [SMALL_STATE(497)] = 14171,
+  [SMALL_STATE(498)] = 14175,
+  [SMALL_STATE(499)] = 14194,
+  [SMALL_STATE(500)] = 14211,
+  [SMALL_STATE(501)] = 14224,
+  [SMALL_STATE(502)] = 14228,
+  [SMALL_STATE(503)] = 14232,
+  [SMALL_STATE(504)] = 14236,
+  [SMALL_STATE(505)] = 14240,
+  [SMALL_STATE(506)] = 14254,
+  [SMALL_STATE(507)] = 14287,
+  [SMALL_STATE(508)] = 14393,
+  [SMALL_STATE(509)] = 14412,
+  [SMALL_STATE(510)] = 14416,
+  [SMALL_STATE(511)] = 14420,
+  [SMALL_STATE(512)] = 14424,
+  [SMALL_STATE(513)] = 14536,
+  [SMALL_STATE(514)] = 14549,
+  [SMALL_STATE(515)] = 14658,
+  [SMALL_STATE(516)] = 14673,
+  [SMALL_STATE(517)] = 14683,
+  [SMALL_STATE(518)] = 14693,
+  [SMALL_STATE(519)] = 14703,
+  [SMALL_STATE(520)] = 14806,
+  [SMALL_STATE(521)] = 14906,
+  [SMALL_STATE(522)] = 14997,
+  [SMALL_STATE(523)] = 15007,
+  [SMALL_STATE(524)] = 15098,
+  [SMALL_STATE(525)] = 15189,
+  [SMALL_STATE(526)] = 15280,
+  [SMALL_STATE(527)] = 15371,
+  [SMALL_STATE(528)] = 15462,
+  [SMALL_STATE(529)] = 15553,
+  [SMALL_STATE(530)] = 15644,
+  [SMALL_STATE(531)] = 15735,
+  [SMALL_STATE(532)] = 15826,
+  [SMALL_STATE(533)] = 15833,
+  [SMALL_STATE(534)] = 15846,
+  [SMALL_STATE(535)] = 15937,
+  [SMALL_STATE(536)] = 16028,
+  [SMALL_STATE(537)] = 16119,
+  [SMALL_STATE(538)] = 16210,
+  [SMALL_STATE(539)] = 16301,
+  [SMALL_STATE(540)] = 16305,
+  [SMALL_STATE(541)] = 16309,
+  [SMALL_STATE(542)] = 16313,
+  [SMALL_STATE(543)] = 16317,
+  [SMALL_STATE(544)] = 16341,
+  [SMALL_STATE(545)] = 16359,
+  [SMALL_STATE(546)] = 16371,
+  [SMALL_STATE(547)] = 16377,
+  [SMALL_STATE(548)] = 16395,
+  [SMALL_STATE(549)] = 16407,
+  [SMALL_STATE(550)] = 16413,
+  [SMALL_STATE(551)] = 16425,
+  [SMALL_STATE(552)] = 16431,
+  [SMALL_STATE(553)] = 16437,
+  [SMALL_STATE(554)] = 16455,
+  [SMALL_STATE(555)] = 16467,
+  [SMALL_STATE(556)] = 16473,
+  [SMALL_STATE(557)] = 16485,
+  [SMALL_STATE(558)] = 16491,
+  [SMALL_STATE(559)] = 16497,
+  [SMALL_STATE(560)] = 16509,
+  [SMALL_STATE(561)] = 16515,
+  [SMALL_STATE(562)] = 16521,
+  [SMALL_STATE(563)] = 16527,
+  [SMALL_STATE(564)] = 16545,
+  [SMALL_STATE(565)] = 16557,
+  [SMALL_STATE(566)] = 16563,
+  [SMALL_STATE(567)] = 16575,
+  [SMALL_STATE(568)] = 16581,
+  [SMALL_STATE(569)] = 16587,
+  [SMALL_STATE(570)] = 16599,
+  [SMALL_STATE(571)] = 16605,
+  [SMALL_STATE(572)] = 16611,
+  [SMALL_STATE(573)] = 16617,
+  [SMALL_STATE(574)] = 16629,
+  [SMALL_STATE(575)] = 16635,
+  [SMALL_STATE(576)] = 16641,
+  [SMALL_STATE(577)] = 16647,
+  [SMALL_STATE(578)] = 16653,
+  [SMALL_STATE(579)] = 16683,
+  [SMALL_STATE(580)] = 16707,
+  [SMALL_STATE(581)] = 16725,
+  [SMALL_STATE(582)] = 16737,
+  [SMALL_STATE(583)] = 16743,
+  [SMALL_STATE(584)] = 16767,
+  [SMALL_STATE(585)] = 16785,
+  [SMALL_STATE(586)] = 16797,
+  [SMALL_STATE(587)] = 16803,
+  [SMALL_STATE(588)] = 16821,
+  [SMALL_STATE(589)] = 16833,
+  [SMALL_STATE(590)] = 16839,
+  [SMALL_STATE(591)] = 16851,
+  [SMALL_STATE(592)] = 16857,
+  [SMALL_STATE(593)] = 16863,
+  [SMALL_STATE(594)] = 16887,
+  [SMALL_STATE(595)] = 16905,
+  [SMALL_STATE(596)] = 16917,
+  [SMALL_STATE(597)] = 16923,
+  [SMALL_STATE(598)] = 16941,
+  [SMALL_STATE(599)] = 16953,
+  [SMALL_STATE(600)] = 16959,
+  [SMALL_STATE(601)] = 16971,
+  [SMALL_STATE(602)] = 16977,
+  [SMALL_STATE(603)] = 16983,
+  [SMALL_STATE(604)] = 17001,
+  [SMALL_STATE(605)] = 17013,
+  [SMALL_STATE(606)] = 17019,
+  [SMALL_STATE(607)] = 17031,
+  [SMALL_STATE(608)] = 17037,
+  [SMALL_STATE(609)] = 17043,
+  [SMALL_STATE(610)] = 17055,
+  [SMALL_STATE(611)] = 17061,
+  [SMALL_STATE(612)] = 17067,
+  [SMALL_STATE(613)] = 17073,
+  [SMALL_STATE(614)] = 17103,
+  [SMALL_STATE(615)] = 17127,
+  [SMALL_STATE(616)] = 17145,
+  [SMALL_STATE(617)] = 17157,
+  [SMALL_STATE(618)] = 17163,
+  [SMALL_STATE(619)] = 17187,
+  [SMALL_STATE(620)] = 17205,
+  [SMALL_STATE(621)] = 17217,
+  [SMALL_STATE(622)] = 17223,
+  [SMALL_STATE(623)] = 17241,
+  [SMALL_STATE(624)] = 17253,
+  [SMALL_STATE(625)] = 17259,
+  [SMALL_STATE(626)] = 17271,
+  [SMALL_STATE(627)] = 17277,
+  [SMALL_STATE(628)] = 17283,
+  [SMALL_STATE(629)] = 17307,
+  [SMALL_STATE(630)] = 17325,
+  [SMALL_STATE(631)] = 17337,
+  [SMALL_STATE(632)] = 17343,
+  [SMALL_STATE(633)] = 17361,
+  [SMALL_STATE(634)] = 17373,
+  [SMALL_STATE(635)] = 17379,
+  [SMALL_STATE(636)] = 17391,
+  [SMALL_STATE(637)] = 17397,
+  [SMALL_STATE(638)] = 17403,
+  [SMALL_STATE(639)] = 17421,
+  [SMALL_STATE(640)] = 17433,
+  [SMALL_STATE(641)] = 17439,
+  [SMALL_STATE(642)] = 17451,
+  [SMALL_STATE(643)] = 17457,
+  [SMALL_STATE(644)] = 17463,
+  [SMALL_STATE(645)] = 17475,
+  [SMALL_STATE(646)] = 17481,
+  [SMALL_STATE(647)] = 17487,
+  [SMALL_STATE(648)] = 17523,
+  [SMALL_STATE(649)] = 17553,
+  [SMALL_STATE(650)] = 17577,
+  [SMALL_STATE(651)] = 17595,
+  [SMALL_STATE(652)] = 17607,
+  [SMALL_STATE(653)] = 17613,
+  [SMALL_STATE(654)] = 17643,
+  [SMALL_STATE(655)] = 17667,
+  [SMALL_STATE(656)] = 17685,
+  [SMALL_STATE(657)] = 17697,
+  [SMALL_STATE(658)] = 17703,
+  [SMALL_STATE(659)] = 17727,
+  [SMALL_STATE(660)] = 17745,
+  [SMALL_STATE(661)] = 17757,
+  [SMALL_STATE(662)] = 17763,
+  [SMALL_STATE(663)] = 17781,
+  [SMALL_STATE(664)] = 17793,
+  [SMALL_STATE(665)] = 17799,
+  [SMALL_STATE(666)] = 17811,
+  [SMALL_STATE(667)] = 17817,
+  [SMALL_STATE(668)] = 17823,
+  [SMALL_STATE(669)] = 17829,
+  [SMALL_STATE(670)] = 17836,
+  [SMALL_STATE(671)] = 17869,
+  [SMALL_STATE(672)] = 17902,
+  [SMALL_STATE(673)] = 17935,
+  [SMALL_STATE(674)] = 17945,
+  [SMALL_STATE(675)] = 17949,
+  [SMALL_STATE(676)] = 17953,
+  [SMALL_STATE(677)] = 17957,
+  [SMALL_STATE(678)] = 18007,
+  [SMALL_STATE(679)] = 18057,
+  [SMALL_STATE(680)] = 18090,
+  [SMALL_STATE(681)] = 18097,
+  [SMALL_STATE(682)] = 18130,
+  [SMALL_STATE(683)] = 18163,
+  [SMALL_STATE(684)] = 18173,
+  [SMALL_STATE(685)] = 18206,
+  [SMALL_STATE(686)] = 18218,
+  [SMALL_STATE(687)] = 18230,
+  [SMALL_STATE(688)] = 18242,
+  [SMALL_STATE(689)] = 18254,
+  [SMALL_STATE(690)] = 18271,
+  [SMALL_STATE(691)] = 18275,
+  [SMALL_STATE(692)] = 18279,
+  [SMALL_STATE(693)] = 18283,
+  [SMALL_STATE(694)] = 18295,
+  [SMALL_STATE(695)] = 18328,
+  [SMALL_STATE(696)] = 18361,
+  [SMALL_STATE(697)] = 18394,
+  [SMALL_STATE(698)] = 18427,
+  [SMALL_STATE(699)] = 18431,
+  [SMALL_STATE(700)] = 18464,
+  [SMALL_STATE(701)] = 18468,
+  [SMALL_STATE(702)] = 18501,
+  [SMALL_STATE(703)] = 18534,
+  [SMALL_STATE(704)] = 18567,
+  [SMALL_STATE(705)] = 18571,
+  [SMALL_STATE(706)] = 18604,
+  [SMALL_STATE(707)] = 18614,
+  [SMALL_STATE(708)] = 18618,
+  [SMALL_STATE(709)] = 18622,
+  [SMALL_STATE(710)] = 18626,
+  [SMALL_STATE(711)] = 18659,
+  [SMALL_STATE(712)] = 18669,
+  [SMALL_STATE(713)] = 18684,
+  [SMALL_STATE(714)] = 18696,
+  [SMALL_STATE(715)] = 18709,
+  [SMALL_STATE(716)] = 18742,
+  [SMALL_STATE(717)] = 18833,
+  [SMALL_STATE(718)] = 18924,
+  [SMALL_STATE(719)] = 18931,
+  [SMALL_STATE(720)] = 18976,
+  [SMALL_STATE(721)] = 19021,
+  [SMALL_STATE(722)] = 19066,
+  [SMALL_STATE(723)] = 19111,
+  [SMALL_STATE(724)] = 19156,
+  [SMALL_STATE(725)] = 19201,
+  [SMALL_STATE(726)] = 19246,
+  [SMALL_STATE(727)] = 19296,
+  [SMALL_STATE(728)] = 19346,
+  [SMALL_STATE(729)] = 19383,
+  [SMALL_STATE(730)] = 19416,
+  [SMALL_STATE(731)] = 19426,
+  [SMALL_STATE(732)] = 19459,
+  [SMALL_STATE(733)] = 19498,
+  [SMALL_STATE(734)] = 19537,
+  [SMALL_STATE(735)] = 19570,
+  [SMALL_STATE(736)] = 19603,
+  [SMALL_STATE(737)] = 19642,
+  [SMALL_STATE(738)] = 19648,
+  [SMALL_STATE(739)] = 19654,
+  [SMALL_STATE(740)] = 19660,
+  [SMALL_STATE(741)] = 19666,
+  [SMALL_STATE(742)] = 19684,
+  [SMALL_STATE(743)] = 19696,
+  [SMALL_STATE(744)] = 19702,
+  [SMALL_STATE(745)] = 19714,
+  [SMALL_STATE(746)] = 19720,
+  [SMALL_STATE(747)] = 19726,
+  [SMALL_STATE(748)] = 19738,
+  [SMALL_STATE(749)] = 19744,
+  [SMALL_STATE(750)] = 19750,
+  [SMALL_STATE(751)] = 19756,
+  [SMALL_STATE(752)] = 19768,
+  [SMALL_STATE(753)] = 19774,
+  [SMALL_STATE(754)] = 19780,
+  [SMALL_STATE(755)] = 19786,
+  [SMALL_STATE(756)] = 19792,
+  [SMALL_STATE(757)] = 19804,
+  [SMALL_STATE(758)] = 19810,
+  [SMALL_STATE(759)] = 19816,
+  [SMALL_STATE(760)] = 19822,
+  [SMALL_STATE(761)] = 19828,
+  [SMALL_STATE(762)] = 19834,
+  [SMALL_STATE(763)] = 19858,
+  [SMALL_STATE(764)] = 19876,
+  [SMALL_STATE(765)] = 19888,
+  [SMALL_STATE(766)] = 19894,
+  [SMALL_STATE(767)] = 19912,
+  [SMALL_STATE(768)] = 19924,
+  [SMALL_STATE(769)] = 19930,
+  [SMALL_STATE(770)] = 19942,
+  [SMALL_STATE(771)] = 19948,
+  [SMALL_STATE(772)] = 19954,
+  [SMALL_STATE(773)] = 19972,
+  [SMALL_STATE(774)] = 19984,
+  [SMALL_STATE(775)] = 19990,
+  [SMALL_STATE(776)] = 20002,
+  [SMALL_STATE(777)] = 20008,
+  [SMALL_STATE(778)] = 20014,
+  [SMALL_STATE(779)] = 20026,
+  [SMALL_STATE(780)] = 20032,
+  [SMALL_STATE(781)] = 20038,
+  [SMALL_STATE(782)] = 20044,
+  [SMALL_STATE(783)] = 20062,
+  [SMALL_STATE(784)] = 20074,
+  [SMALL_STATE(785)] = 20080,
+  [SMALL_STATE(786)] = 20092,
+  [SMALL_STATE(787)] = 20098,
+  [SMALL_STATE(788)] = 20104,
+  [SMALL_STATE(789)] = 20116,
+  [SMALL_STATE(790)] = 20122,
+  [SMALL_STATE(791)] = 20128,
+  [SMALL_STATE(792)] = 20134,
+  [SMALL_STATE(793)] = 20146,
+  [SMALL_STATE(794)] = 20152,
+  [SMALL_STATE(795)] = 20158,
+  [SMALL_STATE(796)] = 20164,
+  [SMALL_STATE(797)] = 20170,
+  [SMALL_STATE(798)] = 20194,
+  [SMALL_STATE(799)] = 20212,
+  [SMALL_STATE(800)] = 20224,
+  [SMALL_STATE(801)] = 20230,
+  [SMALL_STATE(802)] = 20248,
+  [SMALL_STATE(803)] = 20260,
+  [SMALL_STATE(804)] = 20266,
+  [SMALL_STATE(805)] = 20278,
+  [SMALL_STATE(806)] = 20284,
+  [SMALL_STATE(807)] = 20290,
+  [SMALL_STATE(808)] = 20308,
+  [SMALL_STATE(809)] = 20320,
+  [SMALL_STATE(810)] = 20326,
+  [SMALL_STATE(811)] = 20338,
+  [SMALL_STATE(812)] = 20344,
+  [SMALL_STATE(813)] = 20350,
+  [SMALL_STATE(814)] = 20362,
+  [SMALL_STATE(815)] = 20368,
+  [SMALL_STATE(816)] = 20374,
+  [SMALL_STATE(817)] = 20380,
+  [SMALL_STATE(818)] = 20398,
+  [SMALL_STATE(819)] = 20410,
+  [SMALL_STATE(820)] = 20416,
+  [SMALL_STATE(821)] = 20428,
+  [SMALL_STATE(822)] = 20434,
+  [SMALL_STATE(823)] = 20440,
+  [SMALL_STATE(824)] = 20452,
+  [SMALL_STATE(825)] = 20458,
+  [SMALL_STATE(826)] = 20464,
+  [SMALL_STATE(827)] = 20470,
+  [SMALL_STATE(828)] = 20482,
+  [SMALL_STATE(829)] = 20488,
+  [SMALL_STATE(830)] = 20494,
+  [SMALL_STATE(831)] = 20500,
+  [SMALL_STATE(832)] = 20506,
+  [SMALL_STATE(833)] = 20536,
+  [SMALL_STATE(834)] = 20560,
+  [SMALL_STATE(835)] = 20578,
+  [SMALL_STATE(836)] = 20590,
+  [SMALL_STATE(837)] = 20596,
+  [SMALL_STATE(838)] = 20620,
+  [SMALL_STATE(839)] = 20638,
+  [SMALL_STATE(840)] = 20650,
+  [SMALL_STATE(841)] = 20656,
+  [SMALL_STATE(842)] = 20674,
+  [SMALL_STATE(843)] = 20686,
+  [SMALL_STATE(844)] = 20692,
+  [SMALL_STATE(845)] = 20704,
+  [SMALL_STATE(846)] = 20710,
+  [SMALL_STATE(847)] = 20716,
+  [SMALL_STATE(848)] = 20740,
+  [SMALL_STATE(849)] = 20758,
+  [SMALL_STATE(850)] = 20770,
+  [SMALL_STATE(851)] = 20776,
+  [SMALL_STATE(852)] = 20794,
+  [SMALL_STATE(853)] = 20806,
+  [SMALL_STATE(854)] = 20812,
+  [SMALL_STATE(855)] = 20824,
+  [SMALL_STATE(856)] = 20830,
+  [SMALL_STATE(857)] = 20836,
+  [SMALL_STATE(858)] = 20854,
+  [SMALL_STATE(859)] = 20866,
+  [SMALL_STATE(860)] = 20872,
+  [SMALL_STATE(861)] = 20884,
+  [SMALL_STATE(862)] = 20890,
+  [SMALL_STATE(863)] = 20896,
+  [SMALL_STATE(864)] = 20908,
+  [SMALL_STATE(865)] = 20914,
+  [SMALL_STATE(866)] = 20920,
+  [SMALL_STATE(867)] = 20926,
+  [SMALL_STATE(868)] = 20930,
+  [SMALL_STATE(869)] = 20963,
+  [SMALL_STATE(870)] = 20996,
+  [SMALL_STATE(871)] = 21029,
+  [SMALL_STATE(872)] = 21062,
+  [SMALL_STATE(873)] = 21095,
+  [SMALL_STATE(874)] = 21128,
+  [SMALL_STATE(875)] = 21161,
+  [SMALL_STATE(876)] = 21194,
+  [SMALL_STATE(877)] = 21204,
+  [SMALL_STATE(878)] = 21208,
+  [SMALL_STATE(879)] = 21212,
+  [SMALL_STATE(880)] = 21216,
+  [SMALL_STATE(881)] = 21223,
+  [SMALL_STATE(882)] = 21256,
+  [SMALL_STATE(883)] = 21289,
+  [SMALL_STATE(884)] = 21322,
+  [SMALL_STATE(885)] = 21332,
+  [SMALL_STATE(886)] = 21336,
+  [SMALL_STATE(887)] = 21340,
+  [SMALL_STATE(888)] = 21344,
+  [SMALL_STATE(889)] = 21394,
+  [SMALL_STATE(890)] = 21444,
+  [SMALL_STATE(891)] = 21477,
+  [SMALL_STATE(892)] = 21484,
+  [SMALL_STATE(893)] = 21517,
+  [SMALL_STATE(894)] = 21550,
+  [SMALL_STATE(895)] = 21560,
+  [SMALL_STATE(896)] = 21593,
+  [SMALL_STATE(897)] = 21605,
+  [SMALL_STATE(898)] = 21611,
+  [SMALL_STATE(899)] = 21617,
+  [SMALL_STATE(900)] = 21623,
+  [SMALL_STATE(901)] = 21629,
+  [SMALL_STATE(902)] = 21635,
+  [SMALL_STATE(903)] = 21641,
+  [SMALL_STATE(904)] = 21659,
+  [SMALL_STATE(905)] = 21671,
+  [SMALL_STATE(906)] = 21677,
+  [SMALL_STATE(907)] = 21689,
+  [SMALL_STATE(908)] = 21695,
+  [SMALL_STATE(909)] = 21701,
+  [SMALL_STATE(910)] = 21713,
+  [SMALL_STATE(911)] = 21719,
+  [SMALL_STATE(912)] = 21725,
+  [SMALL_STATE(913)] = 21731,
+  [SMALL_STATE(914)] = 21743,
+  [SMALL_STATE(915)] = 21749,
+  [SMALL_STATE(916)] = 21755,
+  [SMALL_STATE(917)] = 21761,
+  [SMALL_STATE(918)] = 21767,
+  [SMALL_STATE(919)] = 21779,
+  [SMALL_STATE(920)] = 21785,
+  [SMALL_STATE(921)] = 21791,
+  [SMALL_STATE(922)] = 21797,
+  [SMALL_STATE(923)] = 21803,
+  [SMALL_STATE(924)] = 21809,
+  [SMALL_STATE(925)] = 21827,
+  [SMALL_STATE(926)] = 21839,
+  [SMALL_STATE(927)] = 21845,
+  [SMALL_STATE(928)] = 21857,
+  [SMALL_STATE(929)] = 21863,
+  [SMALL_STATE(930)] = 21869,
+  [SMALL_STATE(931)] = 21881,
+  [SMALL_STATE(932)] = 21887,
+  [SMALL_STATE(933)] = 21893,
+  [SMALL_STATE(934)] = 21899,
+  [SMALL_STATE(935)] = 21911,
+  [SMALL_STATE(936)] = 21917,
+  [SMALL_STATE(937)] = 21923,
+  [SMALL_STATE(938)] = 21929,
+  [SMALL_STATE(939)] = 21935,
+  [SMALL_STATE(940)] = 21947,
+  [SMALL_STATE(941)] = 21953,
+  [SMALL_STATE(942)] = 21959,
+  [SMALL_STATE(943)] = 21965,
+  [SMALL_STATE(944)] = 21971,
+  [SMALL_STATE(945)] = 21977,
+  [SMALL_STATE(946)] = 22001,
+  [SMALL_STATE(947)] = 22019,
+  [SMALL_STATE(948)] = 22031,
+  [SMALL_STATE(949)] = 22037,
+  [SMALL_STATE(950)] = 22055,
+  [SMALL_STATE(951)] = 22067,
+  [SMALL_STATE(952)] = 22073,
+  [SMALL_STATE(953)] = 22085,
+  [SMALL_STATE(954)] = 22091,
+  [SMALL_STATE(955)] = 22097,
+  [SMALL_STATE(956)] = 22115,
+  [SMALL_STATE(957)] = 22127,
+  [SMALL_STATE(958)] = 22133,
+  [SMALL_STATE(959)] = 22145,
+  [SMALL_STATE(960)] = 22151,
+  [SMALL_STATE(961)] = 22157,
+  [SMALL_STATE(962)] = 22169,
+  [SMALL_STATE(963)] = 22175,
+  [SMALL_STATE(964)] = 22181,
+  [SMALL_STATE(965)] = 22187,
+  [SMALL_STATE(966)] = 22205,
+  [SMALL_STATE(967)] = 22217,
+  [SMALL_STATE(968)] = 22223,
+  [SMALL_STATE(969)] = 22235,
+  [SMALL_STATE(970)] = 22241,
+  [SMALL_STATE(971)] = 22247,
+  [SMALL_STATE(972)] = 22259,
+  [SMALL_STATE(973)] = 22265,
+  [SMALL_STATE(974)] = 22271,
+  [SMALL_STATE(975)] = 22277,
+  [SMALL_STATE(976)] = 22289,
+  [SMALL_STATE(977)] = 22295,
+  [SMALL_STATE(978)] = 22301,
+  [SMALL_STATE(979)] = 22307,
+  [SMALL_STATE(980)] = 22313,
+  [SMALL_STATE(981)] = 22346,
+  [SMALL_STATE(982)] = 22379,
+  [SMALL_STATE(983)] = 22391,
+  [SMALL_STATE(984)] = 22403,
+  [SMALL_STATE(985)] = 22415,
+  [SMALL_STATE(986)] = 22419,
+  [SMALL_STATE(987)] = 22452,
+  [SMALL_STATE(988)] = 22485,
+  [SMALL_STATE(989)] = 22518,
+  [SMALL_STATE(990)] = 22551,
+  [SMALL_STATE(991)] = 22584,
+  [SMALL_STATE(992)] = 22617,
+  [SMALL_STATE(993)] = 22650,
+  [SMALL_STATE(994)] = 22683,
+  [SMALL_STATE(995)] = 22693,
+  [SMALL_STATE(996)] = 22699,
+  [SMALL_STATE(997)] = 22711,
+  [SMALL_STATE(998)] = 22717,
+  [SMALL_STATE(999)] = 22723,
+  [SMALL_STATE(1000)] = 22729,
+  [SMALL_STATE(1001)] = 22735,
+  [SMALL_STATE(1002)] = 22741,
+  [SMALL_STATE(1003)] = 22747,
+  [SMALL_STATE(1004)] = 22759,
+  [SMALL_STATE(1005)] = 22765,
+  [SMALL_STATE(1006)] = 22771,
+  [SMALL_STATE(1007)] = 22777,
+  [SMALL_STATE(1008)] = 22783,
+  [SMALL_STATE(1009)] = 22789,
+  [SMALL_STATE(1010)] = 22795,
+  [SMALL_STATE(1011)] = 22813,
+  [SMALL_STATE(1012)] = 22825,
+  [SMALL_STATE(1013)] = 22831,
+  [SMALL_STATE(1014)] = 22843,
+  [SMALL_STATE(1015)] = 22849,
+  [SMALL_STATE(1016)] = 22855,
+  [SMALL_STATE(1017)] = 22867,
+  [SMALL_STATE(1018)] = 22873,
+  [SMALL_STATE(1019)] = 22879,
+  [SMALL_STATE(1020)] = 22885,
+  [SMALL_STATE(1021)] = 22897,
+  [SMALL_STATE(1022)] = 22903,
+  [SMALL_STATE(1023)] = 22909,
+  [SMALL_STATE(1024)] = 22915,
+  [SMALL_STATE(1025)] = 22921,
+  [SMALL_STATE(1026)] = 22933,
+  [SMALL_STATE(1027)] = 22939,
+  [SMALL_STATE(1028)] = 22945,
+  [SMALL_STATE(1029)] = 22951,
+  [SMALL_STATE(1030)] = 22957,
+  [SMALL_STATE(1031)] = 22963,
+  [SMALL_STATE(1032)] = 22996,
+  [SMALL_STATE(1033)] = 23029,
+  [SMALL_STATE(1034)] = 23035,
+  [SMALL_STATE(1035)] = 23041,
+  [SMALL_STATE(1036)] = 23053,
+  [SMALL_STATE(1037)] = 23059,
+  [SMALL_STATE(1038)] = 23065,
+  [SMALL_STATE(1039)] = 23071,
+  [SMALL_STATE(1040)] = 23077,
+  [SMALL_STATE(1041)] = 23083,
+  [SMALL_STATE(1042)] = 23089,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
-  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
   [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(6),
-  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(9),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(11),
-  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(7),
-  [21] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_name, 1, 0, 0),
-  [23] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_column_name, 1, 0, 0),
-  [25] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_literal, 3, 0, 0),
-  [27] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_literal, 3, 0, 0),
-  [29] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
-  [31] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 1, 0, 0),
-  [33] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 1, 0, 0),
-  [35] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_primary_expression, 1, 0, 0),
-  [37] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean_literal, 1, 0, 0),
-  [39] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean_literal, 1, 0, 0),
-  [41] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 3, 0, 0),
-  [43] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_primary_expression, 3, 0, 0),
-  [45] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_not_expression, 1, 0, 0),
-  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(13),
-  [51] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
-  [53] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_comparison_expression, 3, 0, 0),
-  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
-  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
-  [59] = {.entry = {.count = 1, .reusable = false}}, SHIFT(59),
-  [61] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 4, 0, 0),
-  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
-  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
-  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
-  [69] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_and_expression, 1, 0, 0),
-  [71] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
-  [73] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_not_expression, 2, 0, 0),
-  [75] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 0),
-  [77] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_or_expression, 1, 0, 0),
-  [79] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [81] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_and_expression, 3, 0, 0),
-  [83] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_file_name, 1, 0, 0),
-  [85] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1, 0, 0),
-  [87] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_or_expression, 3, 0, 0),
-  [89] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
-  [91] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_where_clause, 2, 0, 0),
-  [93] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
-  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
-  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
-  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_column_list_repeat1, 2, 0, 0),
-  [101] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_column_list_repeat1, 2, 0, 0), SHIFT_REPEAT(21),
-  [104] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_list, 1, 0, 0),
-  [106] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
-  [108] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_list, 2, 0, 0),
-  [110] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_limit_clause, 2, 0, 0),
-  [112] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_expression, 3, 0, 0),
-  [114] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_expression, 1, 0, 0),
-  [116] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
-  [118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
-  [120] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_aggregate_function, 4, 0, 0),
-  [122] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_offset_clause, 2, 0, 0),
-  [124] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
-  [126] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
-  [128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
-  [130] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
-  [132] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [134] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [136] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
-  [138] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [140] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
-  [142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
-  [144] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
-  [148] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_list, 1, 0, 0),
-  [150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [9] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_explain, 1, 0, 0),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [45] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [47] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [51] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__statement, 1, 0, 0),
+  [53] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__statement, 1, 0, 0),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [57] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_explain, 2, 0, 0),
+  [59] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_expression, 1, 0, 0),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [69] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [71] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [75] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [79] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
+  [81] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean_literal, 1, 0, 0),
+  [83] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean_literal, 1, 0, 0),
+  [85] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_name, 1, 0, 0),
+  [87] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_name, 1, 0, 0),
+  [89] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_table_qualifier, 1, 0, 0),
+  [91] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [93] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_list, 1, 0, 0),
+  [95] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_list, 1, 0, 0),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 1, 0, 0),
+  [101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 1, 0, 0),
+  [105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_expression, 1, 0, 0),
+  [109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [111] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1, 0, 0),
+  [113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_or_expression, 1, 0, 0),
+  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [117] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_and_expression, 1, 0, 0),
+  [119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_not_expression, 1, 0, 0),
+  [123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [127] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [151] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [155] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [159] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 1, 0, 0),
+  [161] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 1, 0, 0),
+  [163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 1, 0, 0),
+  [165] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 1, 0, 0),
+  [167] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 1, 0, 0),
+  [169] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 1, 0, 0),
+  [171] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
+  [173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
+  [175] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
+  [177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
+  [179] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
+  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
+  [185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [187] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_union_statement_repeat1, 1, 0, 0),
+  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_statement, 2, 0, 0),
+  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [195] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [199] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_not_expression, 2, 0, 0),
+  [201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_expression, 2, 0, 0),
+  [203] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_case_expression_repeat1, 1, 0, 0),
+  [205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [209] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [211] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [221] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_list, 2, 0, 0),
+  [223] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [239] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [241] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [243] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [245] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [247] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 0),
+  [249] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_clause, 2, 0, 0),
+  [251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_union_statement_repeat1, 2, 0, 0),
+  [253] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_union_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(51),
+  [256] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_expression, 3, 0, 0),
+  [258] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_name, 3, 0, 0),
+  [260] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_name, 3, 0, 0),
+  [262] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_name, 3, 0, 0),
+  [264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [268] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case_expression, 3, 0, 0),
+  [270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [272] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_case_expression_repeat1, 2, 0, 0),
+  [274] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_case_expression_repeat1, 2, 0, 0), SHIFT_REPEAT(61),
+  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_literal, 3, 0, 0),
+  [279] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_date_literal, 3, 0, 0),
+  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_timestamp_literal, 3, 0, 0),
+  [283] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [285] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [287] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_file_name, 1, 0, 0),
+  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 4, 0, 0),
+  [291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [297] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [307] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_file_name, 1, 0, 0),
+  [311] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_column_list_repeat1, 2, 0, 0),
+  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_column_list_repeat1, 2, 0, 0),
+  [315] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_column_list_repeat1, 2, 0, 0), SHIFT_REPEAT(68),
+  [318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [320] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 3, 0, 0),
+  [322] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [324] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [328] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [330] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [332] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_name, 3, 0, 0),
+  [334] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alias, 1, 0, 0),
+  [336] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_expression, 3, 0, 0),
+  [338] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_or_expression, 3, 0, 0),
+  [340] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_and_expression, 3, 0, 0),
+  [342] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_expression, 3, 0, 0),
+  [344] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [346] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [348] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [352] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [354] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [356] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [358] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [362] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [364] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [366] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 3, 0, 0),
+  [368] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_expression, 3, 0, 0),
+  [370] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_expression, 3, 0, 0),
+  [372] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_expression, 3, 0, 0),
+  [374] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_expression, 3, 0, 0),
+  [376] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_concat_expression, 3, 0, 0),
+  [378] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_clause, 3, 0, 0),
+  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [382] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_clause, 2, 0, 0),
+  [384] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case_expression, 4, 0, 0),
+  [386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
+  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
+  [398] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [422] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [426] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
+  [430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [432] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [434] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [438] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 0),
+  [440] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 0),
+  [442] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 0),
+  [444] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 0),
+  [446] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 0),
+  [448] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 0),
+  [450] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 0),
+  [452] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 0),
+  [454] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 0),
+  [456] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 4, 0, 0),
+  [458] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 4, 0, 0),
+  [460] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [462] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [464] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
+  [466] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [468] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 4, 0, 0),
+  [470] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 4, 0, 0),
+  [472] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 4, 0, 0),
+  [474] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 4, 0, 0),
+  [476] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 4, 0, 0),
+  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
+  [482] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 4, 0, 0),
+  [484] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_name, 1, 0, 0),
+  [486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [488] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_when_clause, 4, 0, 0),
+  [490] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_file_name, 3, 0, 0),
+  [492] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_select_statement_repeat1, 2, 0, 0),
+  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
+  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
+  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(292),
+  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
+  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
+  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
+  [508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [512] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_where_clause, 2, 0, 0),
+  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
+  [518] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [520] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [522] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
+  [528] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [530] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [532] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [534] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
+  [536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [550] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [554] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [556] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [558] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [560] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [562] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_having_clause, 2, 0, 0),
+  [564] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [568] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [572] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [574] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
+  [576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
+  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
+  [580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
+  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
+  [586] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
+  [592] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [594] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [596] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sample_clause, 2, 0, 0),
+  [598] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_limit_clause, 2, 0, 0),
+  [600] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_limit_clause, 2, 0, 0),
+  [602] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_offset_clause, 2, 0, 0),
+  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [610] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [612] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [614] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [616] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [618] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [620] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [622] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [624] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [626] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [628] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [630] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [632] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [634] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [636] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [638] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [640] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [642] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [644] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [646] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [648] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [650] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [652] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [654] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [656] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [658] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [660] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [662] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [664] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [666] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [668] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [670] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [672] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [674] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [676] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [678] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [680] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [682] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 0),
+  [684] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_select_statement_repeat1, 2, 0, 0),
+  [686] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_select_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(157),
+  [689] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_call_repeat1, 2, 0, 0),
+  [691] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 5, 0, 0),
+  [693] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_call_repeat1, 2, 0, 0), SHIFT_REPEAT(176),
+  [696] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_call_repeat1, 2, 0, 0),
+  [698] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_name, 5, 0, 0),
+  [700] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_name, 5, 0, 0),
+  [702] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_name, 5, 0, 0),
+  [704] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 5, 0, 0),
+  [706] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_expression, 5, 0, 0),
+  [708] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_like_escape, 2, 0, 0),
+  [710] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_in_list, 3, 0, 0),
+  [712] = {.entry = {.count = 1, .reusable = true}}, SHIFT(453),
+  [714] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cast_expression, 6, 0, 0),
+  [716] = {.entry = {.count = 1, .reusable = true}}, SHIFT(455),
+  [718] = {.entry = {.count = 1, .reusable = true}}, SHIFT(456),
+  [720] = {.entry = {.count = 1, .reusable = true}}, SHIFT(457),
+  [722] = {.entry = {.count = 1, .reusable = true}}, SHIFT(458),
+  [724] = {.entry = {.count = 1, .reusable = true}}, SHIFT(460),
+  [726] = {.entry = {.count = 1, .reusable = true}}, SHIFT(462),
+  [728] = {.entry = {.count = 1, .reusable = true}}, SHIFT(463),
+  [730] = {.entry = {.count = 1, .reusable = true}}, SHIFT(464),
+  [732] = {.entry = {.count = 1, .reusable = true}}, SHIFT(465),
+  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(466),
+  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(468),
+  [738] = {.entry = {.count = 1, .reusable = true}}, SHIFT(469),
+  [740] = {.entry = {.count = 1, .reusable = true}}, SHIFT(470),
+  [742] = {.entry = {.count = 1, .reusable = true}}, SHIFT(471),
+  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(474),
+  [746] = {.entry = {.count = 1, .reusable = true}}, SHIFT(475),
+  [748] = {.entry = {.count = 1, .reusable = true}}, SHIFT(476),
+  [750] = {.entry = {.count = 1, .reusable = true}}, SHIFT(486),
+  [752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(488),
+  [754] = {.entry = {.count = 1, .reusable = true}}, SHIFT(495),
+  [756] = {.entry = {.count = 1, .reusable = true}}, SHIFT(496),
+  [758] = {.entry = {.count = 1, .reusable = true}}, SHIFT(497),
+  [760] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group_by_clause, 3, 0, 0),
+  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(498),
+  [764] = {.entry = {.count = 1, .reusable = true}}, SHIFT(500),
+  [766] = {.entry = {.count = 1, .reusable = true}}, SHIFT(502),
+  [768] = {.entry = {.count = 1, .reusable = true}}, SHIFT(503),
+  [770] = {.entry = {.count = 1, .reusable = true}}, SHIFT(504),
+  [772] = {.entry = {.count = 1, .reusable = true}}, SHIFT(507),
+  [774] = {.entry = {.count = 1, .reusable = true}}, SHIFT(509),
+  [776] = {.entry = {.count = 1, .reusable = true}}, SHIFT(510),
+  [778] = {.entry = {.count = 1, .reusable = true}}, SHIFT(511),
+  [780] = {.entry = {.count = 1, .reusable = true}}, SHIFT(512),
+  [782] = {.entry = {.count = 1, .reusable = true}}, SHIFT(513),
+  [784] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_order_by_clause, 3, 0, 0),
+  [786] = {.entry = {.count = 1, .reusable = true}}, SHIFT(514),
+  [788] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_order_by_item, 1, 0, 0),
+  [790] = {.entry = {.count = 1, .reusable = true}}, SHIFT(516),
+  [792] = {.entry = {.count = 1, .reusable = true}}, SHIFT(517),
+  [794] = {.entry = {.count = 1, .reusable = true}}, SHIFT(519),
+  [796] = {.entry = {.count = 1, .reusable = true}}, SHIFT(520),
+  [798] = {.entry = {.count = 1, .reusable = true}}, SHIFT(521),
+  [800] = {.entry = {.count = 1, .reusable = true}}, SHIFT(522),
+  [802] = {.entry = {.count = 1, .reusable = true}}, SHIFT(523),
+  [804] = {.entry = {.count = 1, .reusable = true}}, SHIFT(524),
+  [806] = {.entry = {.count = 1, .reusable = true}}, SHIFT(525),
+  [808] = {.entry = {.count = 1, .reusable = true}}, SHIFT(526),
+  [810] = {.entry = {.count = 1, .reusable = true}}, SHIFT(527),
+  [812] = {.entry = {.count = 1, .reusable = true}}, SHIFT(528),
+  [814] = {.entry = {.count = 1, .reusable = true}}, SHIFT(529),
+  [816] = {.entry = {.count = 1, .reusable = true}}, SHIFT(530),
+  [818] = {.entry = {.count = 1, .reusable = true}}, SHIFT(531),
+  [820] = {.entry = {.count = 1, .reusable = true}}, SHIFT(532),
+  [822] = {.entry = {.count = 1, .reusable = true}}, SHIFT(533),
+  [824] = {.entry = {.count = 1, .reusable = true}}, SHIFT(534),
+  [826] = {.entry = {.count = 1, .reusable = true}}, SHIFT(535),
+  [828] = {.entry = {.count = 1, .reusable = true}}, SHIFT(536),
+  [830] = {.entry = {.count = 1, .reusable = true}}, SHIFT(537),
+  [832] = {.entry = {.count = 1, .reusable = true}}, SHIFT(538),
+  [834] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_offset_clause, 3, 0, 0),
+  [836] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_offset_clause, 3, 0, 0),
+  [838] = {.entry = {.count = 1, .reusable = true}}, SHIFT(539),
+  [840] = {.entry = {.count = 1, .reusable = true}}, SHIFT(540),
+  [842] = {.entry = {.count = 1, .reusable = true}}, SHIFT(541),
+  [844] = {.entry = {.count = 1, .reusable = true}}, SHIFT(542),
+  [846] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [848] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [850] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [852] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [854] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [856] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [858] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [860] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [862] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [864] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [866] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [868] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [870] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [872] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [874] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [876] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [878] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [880] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [882] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [884] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [886] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [888] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [890] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [892] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [894] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [896] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [898] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [900] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [902] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [904] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [906] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [908] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [910] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [912] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [914] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [916] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [918] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [920] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [922] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [924] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [926] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [928] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [930] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [932] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [934] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [936] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [938] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [940] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [942] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [944] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [946] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [948] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [950] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [952] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [954] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [956] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [958] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [960] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [962] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [964] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [966] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [968] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [970] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [972] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [974] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [976] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [978] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [980] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [982] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [984] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [986] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [988] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [990] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [992] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [994] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [996] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [998] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [1000] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [1002] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [1004] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [1006] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [1008] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [1010] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [1012] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 0),
+  [1014] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_in_list_repeat1, 2, 0, 0),
+  [1016] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_in_list, 4, 0, 0),
+  [1018] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_in_list_repeat1, 2, 0, 0), SHIFT_REPEAT(285),
+  [1021] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_in_list_repeat1, 2, 0, 0),
+  [1023] = {.entry = {.count = 1, .reusable = true}}, SHIFT(669),
+  [1025] = {.entry = {.count = 1, .reusable = true}}, SHIFT(670),
+  [1027] = {.entry = {.count = 1, .reusable = true}}, SHIFT(671),
+  [1029] = {.entry = {.count = 1, .reusable = true}}, SHIFT(672),
+  [1031] = {.entry = {.count = 1, .reusable = true}}, SHIFT(674),
+  [1033] = {.entry = {.count = 1, .reusable = true}}, SHIFT(675),
+  [1035] = {.entry = {.count = 1, .reusable = true}}, SHIFT(676),
+  [1037] = {.entry = {.count = 1, .reusable = true}}, SHIFT(680),
+  [1039] = {.entry = {.count = 1, .reusable = true}}, SHIFT(685),
+  [1041] = {.entry = {.count = 1, .reusable = true}}, SHIFT(686),
+  [1043] = {.entry = {.count = 1, .reusable = true}}, SHIFT(687),
+  [1045] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group_by_clause, 4, 0, 0),
+  [1047] = {.entry = {.count = 1, .reusable = true}}, SHIFT(690),
+  [1049] = {.entry = {.count = 1, .reusable = true}}, SHIFT(691),
+  [1051] = {.entry = {.count = 1, .reusable = true}}, SHIFT(692),
+  [1053] = {.entry = {.count = 1, .reusable = true}}, SHIFT(693),
+  [1055] = {.entry = {.count = 1, .reusable = true}}, SHIFT(694),
+  [1057] = {.entry = {.count = 1, .reusable = true}}, SHIFT(695),
+  [1059] = {.entry = {.count = 1, .reusable = true}}, SHIFT(696),
+  [1061] = {.entry = {.count = 1, .reusable = true}}, SHIFT(697),
+  [1063] = {.entry = {.count = 1, .reusable = true}}, SHIFT(699),
+  [1065] = {.entry = {.count = 1, .reusable = true}}, SHIFT(701),
+  [1067] = {.entry = {.count = 1, .reusable = true}}, SHIFT(702),
+  [1069] = {.entry = {.count = 1, .reusable = true}}, SHIFT(703),
+  [1071] = {.entry = {.count = 1, .reusable = true}}, SHIFT(704),
+  [1073] = {.entry = {.count = 1, .reusable = true}}, SHIFT(705),
+  [1075] = {.entry = {.count = 1, .reusable = true}}, SHIFT(707),
+  [1077] = {.entry = {.count = 1, .reusable = true}}, SHIFT(708),
+  [1079] = {.entry = {.count = 1, .reusable = true}}, SHIFT(709),
+  [1081] = {.entry = {.count = 1, .reusable = true}}, SHIFT(710),
+  [1083] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_order_by_clause, 4, 0, 0),
+  [1085] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sort_direction, 1, 0, 0),
+  [1087] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sort_direction, 1, 0, 0),
+  [1089] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_order_by_item, 2, 0, 0),
+  [1091] = {.entry = {.count = 1, .reusable = true}}, SHIFT(716),
+  [1093] = {.entry = {.count = 1, .reusable = true}}, SHIFT(717),
+  [1095] = {.entry = {.count = 1, .reusable = true}}, SHIFT(718),
+  [1097] = {.entry = {.count = 1, .reusable = true}}, SHIFT(728),
+  [1099] = {.entry = {.count = 1, .reusable = true}}, SHIFT(730),
+  [1101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(737),
+  [1103] = {.entry = {.count = 1, .reusable = true}}, SHIFT(738),
+  [1105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(739),
+  [1107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(740),
+  [1109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1111] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1115] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1117] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1119] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1123] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1127] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1129] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1131] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1135] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1137] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1139] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1141] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1143] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1145] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1147] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1149] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1153] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1155] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1157] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1159] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1161] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1165] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1167] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1169] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1171] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1175] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1179] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1187] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1191] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1195] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1197] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1199] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1203] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1205] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1207] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1211] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1215] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1217] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1219] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1221] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1223] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1225] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1227] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1229] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1231] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1237] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1241] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1247] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1249] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1253] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1257] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1261] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1263] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1265] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1269] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1271] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1273] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1275] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1279] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1283] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1287] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1291] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1295] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1299] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1301] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1303] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1307] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1311] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1315] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1319] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1321] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1323] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1325] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1329] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1331] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1335] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1339] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1341] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1343] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1345] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1347] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1349] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1351] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1353] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1355] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1357] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1359] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 0),
+  [1361] = {.entry = {.count = 1, .reusable = true}}, SHIFT(868),
+  [1363] = {.entry = {.count = 1, .reusable = true}}, SHIFT(869),
+  [1365] = {.entry = {.count = 1, .reusable = true}}, SHIFT(870),
+  [1367] = {.entry = {.count = 1, .reusable = true}}, SHIFT(871),
+  [1369] = {.entry = {.count = 1, .reusable = true}}, SHIFT(875),
+  [1371] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_group_by_clause_repeat1, 2, 0, 0),
+  [1373] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_group_by_clause_repeat1, 2, 0, 0),
+  [1375] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_group_by_clause_repeat1, 2, 0, 0), SHIFT_REPEAT(498),
+  [1378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(877),
+  [1380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(878),
+  [1382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(879),
+  [1384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(880),
+  [1386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(881),
+  [1388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(882),
+  [1390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(883),
+  [1392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(885),
+  [1394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(886),
+  [1396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(887),
+  [1398] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_order_by_clause_repeat1, 2, 0, 0),
+  [1400] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_order_by_clause_repeat1, 2, 0, 0),
+  [1402] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_order_by_clause_repeat1, 2, 0, 0), SHIFT_REPEAT(514),
+  [1405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(891),
+  [1407] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fetch_clause, 5, 0, 0),
+  [1409] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fetch_clause, 5, 0, 0),
+  [1411] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fetch_clause, 5, 0, 0),
+  [1413] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fetch_clause, 5, 0, 0),
+  [1415] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1417] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1419] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1421] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1423] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1425] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1427] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1429] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1431] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1433] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1435] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1437] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1439] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1441] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1443] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1445] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1449] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1453] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1455] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1457] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1459] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1461] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1463] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1465] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1467] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1469] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1471] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1473] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1475] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1477] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1481] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1483] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1485] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1487] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1489] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1491] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1493] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1495] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1497] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1499] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1501] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1503] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1505] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1509] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1511] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1513] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1515] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1517] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1519] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1521] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1523] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1525] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1527] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1529] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1531] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1533] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1535] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1537] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1539] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1541] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1543] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1545] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1547] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1549] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1551] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1553] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1555] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1557] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1559] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1561] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1563] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1565] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1567] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1569] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1571] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1573] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1575] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1577] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1579] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1581] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1583] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1585] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1587] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1589] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1591] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1593] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1595] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1597] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1599] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1601] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1603] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1607] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1609] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1611] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1613] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1615] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1617] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1621] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1625] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1627] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1629] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1631] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1633] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1635] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1637] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1639] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1641] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1643] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1645] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1647] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1649] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1651] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1653] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1655] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1657] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1659] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1661] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1663] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1665] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 0),
+  [1667] = {.entry = {.count = 1, .reusable = true}}, SHIFT(980),
+  [1669] = {.entry = {.count = 1, .reusable = true}}, SHIFT(981),
+  [1671] = {.entry = {.count = 1, .reusable = true}}, SHIFT(982),
+  [1673] = {.entry = {.count = 1, .reusable = true}}, SHIFT(983),
+  [1675] = {.entry = {.count = 1, .reusable = true}}, SHIFT(984),
+  [1677] = {.entry = {.count = 1, .reusable = true}}, SHIFT(986),
+  [1679] = {.entry = {.count = 1, .reusable = true}}, SHIFT(987),
+  [1681] = {.entry = {.count = 1, .reusable = true}}, SHIFT(988),
+  [1683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(989),
+  [1685] = {.entry = {.count = 1, .reusable = true}}, SHIFT(993),
+  [1687] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1689] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1691] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1693] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1695] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1697] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1699] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1701] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1703] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1705] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1707] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1709] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1711] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1713] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1715] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1717] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1719] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1721] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1723] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1725] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1727] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1729] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1731] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1733] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1735] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1737] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1739] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1741] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1743] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1745] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1747] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1749] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1751] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1753] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1755] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1757] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1759] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1761] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1763] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1765] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1767] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1769] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1771] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1773] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1775] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1777] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1779] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1781] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1783] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1785] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1787] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1789] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1791] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1793] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1795] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1797] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1799] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1801] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1803] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1805] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1807] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1809] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1811] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1813] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1815] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1817] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1819] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1821] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1823] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1825] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1827] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1829] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1831] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1833] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1835] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1837] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1839] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1841] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1843] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1845] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1847] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1849] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1851] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1853] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 0),
+  [1855] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1031),
+  [1857] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1032),
+  [1859] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1861] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1863] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1865] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1867] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1869] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1871] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1873] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1875] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1877] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1879] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1881] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1883] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1885] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1887] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1889] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1891] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1893] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1895] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1897] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1899] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1901] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1903] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1905] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1907] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1909] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1911] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1913] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1915] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1917] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1919] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1921] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1923] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1925] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1927] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1929] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 0),
+  [1931] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 0),
+  [1933] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 0),
+  [1935] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 0),
+  [1937] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 0),
+  [1939] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 0),
+  [1941] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 0),
+  [1943] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 0),
+  [1945] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 0),
+  [1947] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 0),
+  [1949] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 13, 0, 0),
 };
 
 #ifdef __cplusplus
@@ -1,3 +1,5 @@
+use crate::binder::ColumnType;
+use std::fmt;
 use tree_sitter::{Language, Node, Parser as TreeSitterParser, Tree};
 
 unsafe extern "C" {
@@ -10,6 +12,14 @@ pub struct ParseError {
     pub offset: usize,
 }
 
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 pub type ParseResult<T> = Result<T, ParseError>;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -17,8 +27,25 @@ pub struct Query {
     pub select: SelectClause,
     pub from: FromClause,
     pub where_clause: Option<WhereClause>,
+    pub group_by: Vec<String>,      // GROUP BY column names (empty if none)
+    pub having: Option<Expression>, // HAVING condition, may reference aggregates
+    pub order_by: Vec<OrderBy>,     // ORDER BY keys, most significant first
+    pub sample: Option<usize>,      // SAMPLE n: rows taken from the file before WHERE
     pub limit: Option<usize>,
     pub offset: Option<usize>,
+    pub explain: bool, // EXPLAIN prefix: describe the plan instead of running it
+    pub analyze: bool, // EXPLAIN ANALYZE: run the plan and report per-operator metrics
+    pub qualifiers: Vec<String>, // table qualifiers on column references (data.name)
+    // SELECTs combined with this one, in order; ORDER BY, LIMIT and OFFSET
+    // above then apply to the combined rows
+    pub unions: Vec<UnionClause>,
+}
+
+/// `UNION [ALL] SELECT ...` after the first SELECT of a query
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnionClause {
+    pub all: bool, // UNION ALL keeps duplicate rows
+    pub query: Query,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -28,20 +55,44 @@ pub struct SelectClause {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SelectColumn {
-    All,                          // select *
-    Column(String),               // select column_name
-    Aggregate(AggregateFunction), // select COUNT(*)
+    All,                                          // select *
+    Aggregate(AggregateFunction, Option<String>), // select COUNT(*) [AS n]
+    Expression(Expression, Option<String>),       // select name, price * 2 AS total
+}
+
+impl SelectColumn {
+    /// the AS name of this entry, if it was renamed
+    pub fn alias(&self) -> Option<&str> {
+        match self {
+            SelectColumn::All => None,
+            SelectColumn::Aggregate(_, alias) | SelectColumn::Expression(_, alias) => {
+                alias.as_deref()
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AggregateFunction {
     CountStar,
-    Count(String), // column name
+    Count(Expression), // counts non-NULL values of the expression
+    Sum(Expression),
+    Min(Expression),
+    Max(Expression),
+    Avg(Expression),
+}
+
+/// one ORDER BY key. a bare integer is a 1-based SELECT list position and a
+/// bare name may be an output alias; the binder resolves both
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderBy {
+    pub expression: Expression,
+    pub descending: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FromClause {
-    pub file: String,
+    pub files: Vec<String>, // one or more files with the same columns, read in order
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -64,6 +115,52 @@ pub enum Expression {
     LessThan(Box<Expression>, Box<Expression>),
     LessThanOrEqual(Box<Expression>, Box<Expression>),
 
+    // pattern matching (% matches any sequence, _ matches one character);
+    // the optional ESCAPE character makes the % or _ after it literal
+    Like(Box<Expression>, Box<Expression>, Option<char>),
+    // LIKE ignoring case (NOT ILIKE is parsed as Not(ILike(..)))
+    ILike(Box<Expression>, Box<Expression>, Option<char>),
+
+    // membership in a literal list (NOT IN is parsed as Not(In(..)))
+    In(Box<Expression>, Vec<Expression>),
+
+    // truth tests, never NULL: a NULL operand is neither true nor false
+    // (IS NOT TRUE is parsed as Not(IsTrue(..)))
+    IsTrue(Box<Expression>),
+    IsFalse(Box<Expression>),
+
+    // arithmetic operators (unary - binds tightest, then * / %, then + and -)
+    Add(Box<Expression>, Box<Expression>),
+    Subtract(Box<Expression>, Box<Expression>),
+    Multiply(Box<Expression>, Box<Expression>),
+    Divide(Box<Expression>, Box<Expression>),
+    Modulo(Box<Expression>, Box<Expression>),
+    Negate(Box<Expression>),
+
+    // string concatenation (a || b)
+    Concat(Box<Expression>, Box<Expression>),
+
+    // explicit type conversion: CAST(expr AS INTEGER)
+    Cast {
+        expr: Box<Expression>,
+        target: ColumnType,
+    },
+
+    // CASE WHEN cond THEN result ... [ELSE result] END (no ELSE yields NULL)
+    Case {
+        branches: Vec<(Expression, Expression)>,
+        else_: Option<Box<Expression>>,
+    },
+
+    // scalar function call, name upper-cased (checked by the binder)
+    FunctionCall {
+        name: String,
+        args: Vec<Expression>,
+    },
+
+    // aggregate call inside an expression (only valid in HAVING)
+    Aggregate(Box<AggregateFunction>),
+
     // leaf nodes
     Column(String),
     Literal(LiteralValue),
@@ -75,11 +172,140 @@ pub enum LiteralValue {
     Float(f64),
     String(String),
     Boolean(bool),
+    Date(String),      // DATE 'YYYY-MM-DD', checked by the binder
+    Timestamp(String), // TIMESTAMP 'YYYY-MM-DD HH:MM:SS', checked by the binder
     Null,
 }
 
+/// SQL-like text form, used to label aggregate results (e.g. "SUM(price * qty)")
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // wrap compound operands so the text keeps the tree's grouping
+        let operand = |e: &Expression| match e {
+            Expression::Column(_)
+            | Expression::Literal(_)
+            | Expression::Aggregate(_)
+            | Expression::FunctionCall { .. }
+            | Expression::Cast { .. }
+            | Expression::Case { .. } => e.to_string(),
+            _ => format!("({})", e),
+        };
+        let binary = |f: &mut fmt::Formatter<'_>, l: &Expression, op: &str, r: &Expression| {
+            write!(f, "{} {} {}", operand(l), op, operand(r))
+        };
+
+        match self {
+            Expression::Or(l, r) => binary(f, l, "OR", r),
+            Expression::And(l, r) => binary(f, l, "AND", r),
+            Expression::Not(inner) => write!(f, "NOT {}", operand(inner)),
+            Expression::Equal(l, r) => binary(f, l, "=", r),
+            Expression::NotEqual(l, r) => binary(f, l, "!=", r),
+            Expression::GreaterThan(l, r) => binary(f, l, ">", r),
+            Expression::GreaterThanOrEqual(l, r) => binary(f, l, ">=", r),
+            Expression::LessThan(l, r) => binary(f, l, "<", r),
+            Expression::LessThanOrEqual(l, r) => binary(f, l, "<=", r),
+            Expression::Like(l, r, escape) | Expression::ILike(l, r, escape) => {
+                let op = match self {
+                    Expression::ILike(..) => "ILIKE",
+                    _ => "LIKE",
+                };
+                binary(f, l, op, r)?;
+                match escape {
+                    Some(escape) => write!(f, " ESCAPE '{}'", escape),
+                    None => Ok(()),
+                }
+            }
+            Expression::In(l, list) => {
+                let items: Vec<String> = list.iter().map(|item| item.to_string()).collect();
+                write!(f, "{} IN ({})", operand(l), items.join(", "))
+            }
+            Expression::IsTrue(inner) => write!(f, "{} IS TRUE", operand(inner)),
+            Expression::IsFalse(inner) => write!(f, "{} IS FALSE", operand(inner)),
+            Expression::Add(l, r) => binary(f, l, "+", r),
+            Expression::Subtract(l, r) => binary(f, l, "-", r),
+            Expression::Multiply(l, r) => binary(f, l, "*", r),
+            Expression::Divide(l, r) => binary(f, l, "/", r),
+            Expression::Modulo(l, r) => binary(f, l, "%", r),
+            Expression::Negate(inner) => write!(f, "-{}", operand(inner)),
+            Expression::Concat(l, r) => match l.as_ref() {
+                // chains read left to right without nested parentheses: a || b || c
+                Expression::Concat(..) => write!(f, "{} || {}", l, operand(r)),
+                _ => binary(f, l, "||", r),
+            },
+            Expression::FunctionCall { name, args } => {
+                let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+                write!(f, "{}({})", name, args.join(", "))
+            }
+            Expression::Cast { expr, target } => {
+                write!(f, "CAST({} AS {})", expr, sql_type_name(target))
+            }
+            Expression::Case { branches, else_ } => {
+                write!(f, "CASE")?;
+                for (condition, result) in branches {
+                    write!(f, " WHEN {} THEN {}", condition, result)?;
+                }
+                if let Some(else_) = else_ {
+                    write!(f, " ELSE {}", else_)?;
+                }
+                write!(f, " END")
+            }
+            Expression::Aggregate(function) => write!(f, "{}", function),
+            Expression::Column(name) => {
+                // quote names that wouldn't parse as a bare identifier
+                let bare = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                    && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if bare {
+                    write!(f, "{}", name)
+                } else {
+                    write!(f, "\"{}\"", name)
+                }
+            }
+            Expression::Literal(value) => match value {
+                LiteralValue::Integer(i) => write!(f, "{}", i),
+                LiteralValue::Float(v) => write!(f, "{}", v),
+                LiteralValue::String(s) => write!(f, "'{}'", s),
+                LiteralValue::Boolean(b) => write!(f, "{}", b),
+                LiteralValue::Date(text) => write!(f, "DATE '{}'", text),
+                LiteralValue::Timestamp(text) => write!(f, "TIMESTAMP '{}'", text),
+                LiteralValue::Null => write!(f, "NULL"),
+            },
+        }
+    }
+}
+
+/// SQL spelling of a column type, as written in CAST
+pub fn sql_type_name(type_: &ColumnType) -> &'static str {
+    match type_ {
+        ColumnType::Integer => "INTEGER",
+        ColumnType::Float => "FLOAT",
+        ColumnType::Boolean => "BOOLEAN",
+        ColumnType::Varchar => "VARCHAR",
+        ColumnType::Date => "DATE",
+        ColumnType::Timestamp => "TIMESTAMP",
+        ColumnType::Null => "NULL",
+    }
+}
+
+impl fmt::Display for AggregateFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AggregateFunction::CountStar => write!(f, "COUNT(*)"),
+            AggregateFunction::Count(arg) => write!(f, "COUNT({})", arg),
+            AggregateFunction::Sum(arg) => write!(f, "SUM({})", arg),
+            AggregateFunction::Min(arg) => write!(f, "MIN({})", arg),
+            AggregateFunction::Max(arg) => write!(f, "MAX({})", arg),
+            AggregateFunction::Avg(arg) => write!(f, "AVG({})", arg),
+        }
+    }
+}
+
+/// nesting depth allowed unless Parser::with_max_depth says otherwise
+pub const DEFAULT_MAX_DEPTH: usize = 100;
+
 pub struct Parser {
     parser: TreeSitterParser,
+    lenient_commas: bool, // accept `SELECT a, b, FROM ...`
+    max_depth: usize,     // operators and parentheses an operand may be nested in
 }
 
 impl Parser {
@@ -90,14 +316,42 @@ impl Parser {
             .set_language(&language)
             .expect("Failed to load SQL grammar");
 
-        Self { parser }
+        Self {
+            parser,
+            lenient_commas: false,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// reject expressions nested more than `depth` levels deep (each operator,
+    /// function call and pair of parentheses around an operand is a level; a
+    /// whole chain of ORs or ANDs is one). transforming, binding and optimizing
+    /// recurse once per level, so a pathological query fails with an error
+    /// instead of overflowing the stack
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// tolerate a single trailing comma at the end of the SELECT list
+    /// (`SELECT id, name, FROM ...`), as generated SQL sometimes has.
+    /// off by default: strict parsing rejects it
+    pub fn with_lenient_commas(mut self, enabled: bool) -> Self {
+        self.lenient_commas = enabled;
+        self
     }
 
     pub fn parse(&mut self, sql: &str) -> ParseResult<Query> {
+        let mut sql = blank_comments(sql)?;
+        if self.lenient_commas {
+            sql = blank_trailing_commas(sql);
+        }
+        let sql = &sql;
         let tree = self.parser.parse(sql, None).ok_or_else(|| ParseError {
             message: "Failed to parse query".to_string(),
             offset: 0,
         })?;
+        self.check_depth(&tree)?;
 
         // check for parse errors
         if self.has_parse_errors(&tree, sql) {
@@ -111,6 +365,60 @@ impl Parser {
         self.transform_tree(&root_node, sql)
     }
 
+    /// fails when an expression nests deeper than max_depth. walks the tree
+    /// with a cursor, so the check itself doesn't recurse
+    fn check_depth(&self, tree: &Tree) -> ParseResult<()> {
+        // an operator, function call or parenthesized expression: more than a wrapper
+        let is_level = |node: Node, parent: Option<&str>| match node.kind() {
+            // NOT is a hidden token: its only child is the operand
+            "not_expression" => wrapped_operand(node).is_none(),
+            "select_expression" => false,
+            // the rest of an OR or AND chain: the chain becomes a balanced tree,
+            // so it is one level however many terms it has
+            kind @ ("or_expression" | "and_expression") if parent == Some(kind) => false,
+            kind => {
+                (kind.ends_with("_expression") || kind == "function_call") && node.child_count() > 1
+            }
+        };
+
+        let mut cursor = tree.walk();
+        let mut depth = 0;
+        // kind of each node from the root to the cursor, and whether it is a level
+        let mut path: Vec<(&str, bool)> = Vec::new();
+        loop {
+            let node = cursor.node();
+            let level = is_level(node, path.last().map(|&(kind, _)| kind));
+            path.push((node.kind(), level));
+            if level {
+                depth += 1;
+                if depth > self.max_depth {
+                    return Err(ParseError {
+                        message: format!(
+                            "Expression nesting exceeds the maximum depth of {}",
+                            self.max_depth
+                        ),
+                        offset: node.start_byte(),
+                    });
+                }
+            }
+            if cursor.goto_first_child() {
+                continue;
+            }
+            // leave finished nodes until one has a next sibling
+            loop {
+                if let Some((_, true)) = path.pop() {
+                    depth -= 1;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+                if !cursor.goto_parent() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
     fn has_parse_errors(&self, tree: &Tree, source: &str) -> bool {
         let root = tree.root_node();
 
@@ -124,52 +432,68 @@ impl Parser {
             return true;
         }
 
-        // recursively check for error nodes
-        self.check_node_for_errors(&root)
-    }
-
-    fn check_node_for_errors(&self, node: &Node) -> bool {
-        // check if this node is an error node
-        if node.is_error() || node.kind() == "ERROR" {
-            return true;
-        }
-
-        // recursively check children
-        for i in 0..node.child_count() {
-            if let Some(child) = node.child(i) {
-                if self.check_node_for_errors(&child) {
-                    return true;
+        // check for error nodes, with a cursor rather than a call per level
+        let mut cursor = tree.walk();
+        loop {
+            let node = cursor.node();
+            if node.is_error() || node.kind() == "ERROR" {
+                return true;
+            }
+            if cursor.goto_first_child() {
+                continue;
+            }
+            while !cursor.goto_next_sibling() {
+                if !cursor.goto_parent() {
+                    return false;
                 }
             }
         }
-
-        false
     }
 
     fn transform_tree(&self, node: &Node, source: &str) -> ParseResult<Query> {
         match node.kind() {
             "source_file" => {
-                let child = node.child(0).ok_or_else(|| ParseError {
+                let explain_node = node.child(0).filter(|child| child.kind() == "explain");
+                let explain = explain_node.is_some();
+                let analyze = explain_node
+                    .and_then(|explain| explain.named_child(0))
+                    .is_some_and(|child| child.kind() == "analyze");
+                let child = node.child(usize::from(explain)).ok_or_else(|| ParseError {
                     message: "Expected select_statement".to_string(),
                     offset: node.start_byte(),
                 })?;
-                self.transform_tree(&child, source)
+                let query = self.transform_tree(&child, source)?;
+                Ok(Query {
+                    explain,
+                    analyze,
+                    ..query
+                })
             }
             "select_statement" => {
                 let mut select_list_node = None;
-                let mut file_name_node = None;
+                let mut file_name_nodes = Vec::new();
+                let mut sample_clause_node = None;
                 let mut where_clause_node = None;
+                let mut group_by_clause_node = None;
+                let mut having_clause_node = None;
+                let mut order_by_clause_node = None;
                 let mut limit_clause_node = None;
                 let mut offset_clause_node = None;
+                let mut fetch_clause_node = None;
 
                 for i in 0..node.child_count() {
                     if let Some(child) = node.child(i) {
                         match child.kind() {
                             "select_list" => select_list_node = Some(child),
-                            "file_name" => file_name_node = Some(child),
+                            "file_name" => file_name_nodes.push(child),
+                            "sample_clause" => sample_clause_node = Some(child),
                             "where_clause" => where_clause_node = Some(child),
+                            "group_by_clause" => group_by_clause_node = Some(child),
+                            "having_clause" => having_clause_node = Some(child),
+                            "order_by_clause" => order_by_clause_node = Some(child),
                             "limit_clause" => limit_clause_node = Some(child),
                             "offset_clause" => offset_clause_node = Some(child),
+                            "fetch_clause" => fetch_clause_node = Some(child),
                             _ => {} // skip keywords like SELECT, FROM, WHERE, LIMIT, OFFSET
                         }
                     }
@@ -182,12 +506,24 @@ impl Parser {
                     })
                     .and_then(|n| self.transform_select_list(&n, source))?;
 
-                let from = file_name_node
-                    .ok_or_else(|| ParseError {
+                if file_name_nodes.is_empty() {
+                    return Err(ParseError {
                         message: "Missing file_name".to_string(),
                         offset: node.start_byte(),
-                    })
-                    .and_then(|n| self.transform_file_name(&n, source))?;
+                    });
+                }
+                let from = FromClause {
+                    files: file_name_nodes
+                        .iter()
+                        .map(|n| self.transform_file_name(n, source))
+                        .collect::<ParseResult<Vec<_>>>()?,
+                };
+
+                let sample = if let Some(n) = sample_clause_node {
+                    Some(self.extract_number_from_clause(&n, source)?)
+                } else {
+                    None
+                };
 
                 let where_clause = if let Some(n) = where_clause_node {
                     Some(self.transform_where_clause(&n, source)?)
@@ -195,12 +531,37 @@ impl Parser {
                     None
                 };
 
-                let limit = if let Some(n) = limit_clause_node {
-                    Some(self.extract_number_from_clause(&n, source)?)
+                let group_by = match group_by_clause_node {
+                    Some(n) => self.transform_group_by_clause(&n, source)?,
+                    None => Vec::new(),
+                };
+
+                let having = if let Some(n) = having_clause_node {
+                    Some(self.transform_where_clause(&n, source)?.condition)
                 } else {
                     None
                 };
 
+                let order_by = match order_by_clause_node {
+                    Some(n) => self.transform_order_by_clause(&n, source)?,
+                    None => Vec::new(),
+                };
+
+                let limit = match (limit_clause_node, fetch_clause_node) {
+                    (Some(_), Some(fetch)) => {
+                        return Err(ParseError {
+                            message: "LIMIT and FETCH FIRST cannot be combined".to_string(),
+                            offset: fetch.start_byte(),
+                        });
+                    }
+                    // LIMIT ALL has no number: no limit
+                    (Some(n), None) if n.named_child_count() == 0 => None,
+                    (Some(n), None) | (None, Some(n)) => {
+                        Some(self.extract_number_from_clause(&n, source)?)
+                    }
+                    (None, None) => None,
+                };
+
                 let offset = if let Some(n) = offset_clause_node {
                     Some(self.extract_number_from_clause(&n, source)?)
                 } else {
@@ -211,8 +572,66 @@ impl Parser {
                     select,
                     from,
                     where_clause,
+                    group_by,
+                    having,
+                    order_by,
+                    sample,
+                    limit,
+                    offset,
+                    explain: false,
+                    analyze: false,
+                    qualifiers: self.collect_qualifiers(node, source)?,
+                    unions: Vec::new(),
+                })
+            }
+            "union_statement" => {
+                // the first SELECT, then one union_clause per UNION
+                let mut branches = Vec::new();
+                for i in 0..node.named_child_count() {
+                    let Some(child) = node.named_child(i) else {
+                        continue;
+                    };
+                    let (all, select) = match child.kind() {
+                        "union_clause" => {
+                            let all = child
+                                .named_child(0)
+                                .is_some_and(|all| all.kind() == "union_all");
+                            (all, child.named_child(usize::from(all)))
+                        }
+                        _ => (false, Some(child)),
+                    };
+                    let select = select.ok_or_else(|| ParseError {
+                        message: "Expected select_statement after UNION".to_string(),
+                        offset: child.start_byte(),
+                    })?;
+                    branches.push((all, select, self.transform_tree(&select, source)?));
+                }
+
+                // ORDER BY, LIMIT and OFFSET after the last SELECT belong to the whole union
+                let (_, _, last) = branches.last_mut().unwrap();
+                let order_by = std::mem::take(&mut last.order_by);
+                let (limit, offset) = (last.limit.take(), last.offset.take());
+                if let Some((_, select, _)) = branches.iter().find(|(_, _, query)| {
+                    !query.order_by.is_empty() || query.limit.is_some() || query.offset.is_some()
+                }) {
+                    return Err(ParseError {
+                        message:
+                            "ORDER BY, LIMIT and OFFSET must follow the last SELECT of a UNION"
+                                .to_string(),
+                        offset: select.start_byte(),
+                    });
+                }
+
+                let mut branches = branches.into_iter();
+                let (_, _, first) = branches.next().unwrap();
+                Ok(Query {
+                    order_by,
                     limit,
                     offset,
+                    unions: branches
+                        .map(|(all, _, query)| UnionClause { all, query })
+                        .collect(),
+                    ..first
                 })
             }
             _ => Err(ParseError {
@@ -264,14 +683,14 @@ impl Parser {
             if let Some(child) = node.child(i) {
                 match child.kind() {
                     "select_expression" => {
-                        // handle select_expression which can be column_name, (column_name), or aggregate_function
+                        // handle select_expression which can be *, a column, an aggregate or an expression
                         let select_col = self.transform_select_expression(&child, source)?;
                         columns.push(select_col);
                     }
                     "column_name" => {
                         // fallback for direct column_name (if still present)
-                        let name = self.get_node_text(&child, source)?;
-                        columns.push(SelectColumn::Column(name));
+                        let name = self.get_column_name(&child, source)?;
+                        columns.push(SelectColumn::Expression(Expression::Column(name), None));
                     }
                     _ => {
                         // skip commas and other tokens
@@ -283,21 +702,32 @@ impl Parser {
         Ok(SelectClause { columns })
     }
 
+    /// the name after AS in a select_expression, if any
+    fn get_alias(&self, node: &Node, source: &str) -> ParseResult<Option<String>> {
+        (0..node.child_count())
+            .filter_map(|i| node.child(i))
+            .find(|child| child.kind() == "alias")
+            .map(|child| self.get_node_text(&child, source))
+            .transpose()
+    }
+
     fn transform_select_expression(&self, node: &Node, source: &str) -> ParseResult<SelectColumn> {
         // select_expression can be:
-        // 1. column_name
-        // 2. ( column_name )
-        // 3. aggregate_function
-
+        // 1. *
+        // 2. an expression, optionally renamed with AS: a lone aggregate call,
+        //    or any other expression (a plain column included)
         for i in 0..node.child_count() {
             if let Some(child) = node.child(i) {
                 match child.kind() {
-                    "column_name" => {
-                        let name = self.get_node_text(&child, source)?;
-                        return Ok(SelectColumn::Column(name));
-                    }
-                    "aggregate_function" => {
-                        return self.transform_aggregate_function(&child, source);
+                    "*" => return Ok(SelectColumn::All),
+                    "expression" => {
+                        let alias = self.get_alias(node, source)?;
+                        return Ok(match self.transform_expression(&child, source)? {
+                            Expression::Aggregate(function) => {
+                                SelectColumn::Aggregate(*function, alias)
+                            }
+                            expression => SelectColumn::Expression(expression, alias),
+                        });
                     }
                     _ => {}
                 }
@@ -306,53 +736,114 @@ impl Parser {
 
         // if no child found, try to get text directly as column name
         let name = self.get_node_text(node, source)?;
-        Ok(SelectColumn::Column(name))
+        Ok(SelectColumn::Expression(Expression::Column(name), None))
     }
 
-    fn transform_aggregate_function(&self, node: &Node, source: &str) -> ParseResult<SelectColumn> {
-        // aggregate_function can be:
-        // 1. COUNT ( * )
-        // 2. COUNT ( column_name )
-
-        let mut is_count_star = false;
-        let mut column_name: Option<String> = None;
+    fn transform_function_call(&self, node: &Node, source: &str) -> ParseResult<Expression> {
+        // function_call: function_name ( [* | expression (, expression)*] )
+        let mut name = String::new();
+        let mut is_star = false;
+        let mut args = Vec::new();
 
         for i in 0..node.child_count() {
             if let Some(child) = node.child(i) {
                 match child.kind() {
+                    "function_name" => {
+                        name = self.get_node_text(&child, source)?.to_uppercase();
+                    }
                     "*" => {
-                        is_count_star = true;
+                        is_star = true;
                     }
-                    "column_name" => {
-                        column_name = Some(self.get_node_text(&child, source)?);
+                    "expression" => {
+                        args.push(self.transform_expression(&child, source)?);
                     }
                     _ => {}
                 }
             }
         }
 
-        if is_count_star {
-            Ok(SelectColumn::Aggregate(AggregateFunction::CountStar))
-        } else if let Some(col) = column_name {
-            Ok(SelectColumn::Aggregate(AggregateFunction::Count(col)))
-        } else {
-            Err(ParseError {
-                message: "Invalid aggregate function".to_string(),
-                offset: node.start_byte(),
-            })
+        // anything that isn't an aggregate is a scalar function, checked by the binder
+        if !matches!(name.as_str(), "COUNT" | "SUM" | "MIN" | "MAX" | "AVG") {
+            if is_star {
+                return Err(ParseError {
+                    message: format!("{}(*) is not valid - only COUNT accepts *", name),
+                    offset: node.start_byte(),
+                });
+            }
+            return Ok(Expression::FunctionCall { name, args });
         }
+
+        let mut args = args.into_iter();
+        let function = match (name.as_str(), is_star, args.next(), args.next()) {
+            ("COUNT", true, None, None) => AggregateFunction::CountStar,
+            ("COUNT", false, Some(arg), None) => AggregateFunction::Count(arg),
+            ("SUM", false, Some(arg), None) => AggregateFunction::Sum(arg),
+            ("MIN", false, Some(arg), None) => AggregateFunction::Min(arg),
+            ("MAX", false, Some(arg), None) => AggregateFunction::Max(arg),
+            ("AVG", false, Some(arg), None) => AggregateFunction::Avg(arg),
+            _ => {
+                return Err(ParseError {
+                    message: "Invalid aggregate function".to_string(),
+                    offset: node.start_byte(),
+                });
+            }
+        };
+
+        Ok(Expression::Aggregate(Box::new(function)))
     }
 
-    fn transform_file_name(&self, node: &Node, source: &str) -> ParseResult<FromClause> {
+    fn transform_order_by_clause(&self, node: &Node, source: &str) -> ParseResult<Vec<OrderBy>> {
+        // order_by_clause: ORDER BY order_by_item (',' order_by_item)*
+        // order_by_item: expression [ASC | DESC]
+        let mut order_by = Vec::new();
+        for item in (0..node.child_count()).filter_map(|i| node.child(i)) {
+            if item.kind() != "order_by_item" {
+                continue;
+            }
+
+            let mut expression = None;
+            let mut descending = false;
+            for child in (0..item.child_count()).filter_map(|i| item.child(i)) {
+                match child.kind() {
+                    "expression" => expression = Some(self.transform_expression(&child, source)?),
+                    "sort_direction" => {
+                        descending = self
+                            .get_node_text(&child, source)?
+                            .eq_ignore_ascii_case("DESC")
+                    }
+                    _ => {}
+                }
+            }
+
+            let expression = expression.ok_or_else(|| ParseError {
+                message: "Invalid order_by_item".to_string(),
+                offset: item.start_byte(),
+            })?;
+            order_by.push(OrderBy {
+                expression,
+                descending,
+            });
+        }
+        Ok(order_by)
+    }
+
+    fn transform_group_by_clause(&self, node: &Node, source: &str) -> ParseResult<Vec<String>> {
+        // group_by_clause: GROUP BY column_name (',' column_name)*
+        (0..node.child_count())
+            .filter_map(|i| node.child(i))
+            .filter(|child| child.kind() == "column_name")
+            .map(|child| self.get_column_name(&child, source))
+            .collect()
+    }
+
+    fn transform_file_name(&self, node: &Node, source: &str) -> ParseResult<String> {
         let name = self.get_node_text(node, source)?;
         // if it's a string literal, strip the quotes (both ' and ")
-        let file_name = if (name.starts_with("'") && name.ends_with("'")) || 
-                           (name.starts_with('"') && name.ends_with('"')) {
-            name[1..name.len() - 1].to_string()
-        } else {
-            name
-        };
-        Ok(FromClause { file: file_name })
+        let unquoted = ['\'', '"']
+            .iter()
+            .find_map(|&quote| name.strip_prefix(quote)?.strip_suffix(quote))
+            .map(str::to_string);
+        Ok(unquoted.unwrap_or(name))
     }
 
     fn transform_where_clause(&self, node: &Node, source: &str) -> ParseResult<WhereClause> {
@@ -372,14 +863,28 @@ impl Parser {
     }
 
     fn transform_expression(&self, node: &Node, source: &str) -> ParseResult<Expression> {
+        // step through wrappers around a single operand (an or_expression without
+        // OR, ...) in a loop rather than a call each, so that every level of
+        // parentheses costs only a few stack frames
+        let mut node = *node;
+        while let Some(child) = wrapped_operand(node) {
+            node = child;
+        }
+        let node = &node;
+
         match node.kind() {
             "or_expression" => self.transform_or(&node, source),
             "and_expression" => self.transform_and(&node, source),
             "not_expression" => self.transform_not(&node, source),
             "primary_expression" => self.transform_primary(&node, source),
             "comparison_expression" => self.transform_comparison(&node, source),
+            "arithmetic_expression" => self.transform_arithmetic(node, source),
+            "concat_expression" => self.transform_concat(node, source),
+            "cast_expression" => self.transform_cast(node, source),
+            "case_expression" => self.transform_case(node, source),
+            "function_call" => self.transform_function_call(node, source),
             "column_name" => {
-                let name = self.get_node_text(node, source)?;
+                let name = self.get_column_name(node, source)?;
                 Ok(Expression::Column(name))
             }
             "literal" => {
@@ -389,9 +894,8 @@ impl Parser {
                         match child.kind() {
                             "string_literal" => {
                                 let text = self.get_node_text(&child, source)?;
-                                // remove quotes (both ' and ")
-                                let text = text.trim_start_matches(&['\'', '"'][..])
-                                              .trim_end_matches(&['\'', '"'][..]);
+                                // remove quotes
+                                let text = text.trim_start_matches('\'').trim_end_matches('\'');
                                 return Ok(Expression::Literal(LiteralValue::String(
                                     text.to_string(),
                                 )));
@@ -409,6 +913,16 @@ impl Parser {
                                 let b = text.trim().to_lowercase() == "true";
                                 return Ok(Expression::Literal(LiteralValue::Boolean(b)));
                             }
+                            "date_literal" => {
+                                return Ok(Expression::Literal(LiteralValue::Date(
+                                    self.typed_literal_text(&child, source)?,
+                                )));
+                            }
+                            "timestamp_literal" => {
+                                return Ok(Expression::Literal(LiteralValue::Timestamp(
+                                    self.typed_literal_text(&child, source)?,
+                                )));
+                            }
                             _ => {}
                         }
                     }
@@ -503,64 +1017,49 @@ impl Parser {
 
     fn transform_or(&self, node: &Node, source: &str) -> ParseResult<Expression> {
         // or_expression: and_expression | and_expression OR or_expression
-        let mut and_expr = None;
-        let mut or_expr = None;
-
-        for i in 0..node.child_count() {
-            if let Some(child) = node.child(i) {
-                match child.kind() {
-                    "and_expression" => {
-                        if and_expr.is_none() {
-                            and_expr = Some(self.transform_expression(&child, source)?);
-                        }
-                    }
-                    "or_expression" => {
-                        or_expr = Some(self.transform_expression(&child, source)?);
-                    }
-                    _ => {} // skip operators
-                }
-            }
-        }
-
-        match (and_expr, or_expr) {
-            (Some(left), Some(right)) => Ok(Expression::Or(Box::new(left), Box::new(right))),
-            (Some(expr), None) => Ok(expr), // just and_expression
-            _ => Err(ParseError {
-                message: "Invalid OR expression".to_string(),
-                offset: node.start_byte(),
-            }),
-        }
+        let operands = self.transform_chain(node, "and_expression", source)?;
+        balanced(operands, Expression::Or).ok_or_else(|| ParseError {
+            message: "Invalid OR expression".to_string(),
+            offset: node.start_byte(),
+        })
     }
 
     fn transform_and(&self, node: &Node, source: &str) -> ParseResult<Expression> {
         // and_expression: not_expression | not_expression AND and_expression
-        let mut not_expr = None;
-        let mut and_expr = None;
+        let operands = self.transform_chain(node, "not_expression", source)?;
+        balanced(operands, Expression::And).ok_or_else(|| ParseError {
+            message: "Invalid AND expression".to_string(),
+            offset: node.start_byte(),
+        })
+    }
 
-        for i in 0..node.child_count() {
-            if let Some(child) = node.child(i) {
-                match child.kind() {
-                    "not_expression" => {
-                        if not_expr.is_none() {
-                            not_expr = Some(self.transform_expression(&child, source)?);
-                        }
-                    }
-                    "and_expression" => {
-                        and_expr = Some(self.transform_expression(&child, source)?);
+    /// the operands of an OR or AND chain, in order. the grammar nests the rest
+    /// of the chain in the last child, which a loop follows instead of a call
+    /// per operator
+    fn transform_chain(
+        &self,
+        node: &Node,
+        operand_kind: &str,
+        source: &str,
+    ) -> ParseResult<Vec<Expression>> {
+        let mut operands = Vec::new();
+        let mut next = Some(*node);
+        while let Some(current) = next.take() {
+            let mut operand = None;
+            for i in 0..current.child_count() {
+                if let Some(child) = current.child(i) {
+                    if child.kind() == operand_kind && operand.is_none() {
+                        operand = Some(child);
+                    } else if child.kind() == current.kind() {
+                        next = Some(child);
                     }
-                    _ => {} // skip operators
                 }
             }
+            if let Some(operand) = operand {
+                operands.push(self.transform_expression(&operand, source)?);
+            }
         }
-
-        match (not_expr, and_expr) {
-            (Some(left), Some(right)) => Ok(Expression::And(Box::new(left), Box::new(right))),
-            (Some(expr), None) => Ok(expr), // just not_expression
-            _ => Err(ParseError {
-                message: "Invalid AND expression".to_string(),
-                offset: node.start_byte(),
-            }),
-        }
+        Ok(operands)
     }
 
     fn transform_not(&self, node: &Node, source: &str) -> ParseResult<Expression> {
@@ -610,8 +1109,13 @@ impl Parser {
             if let Some(child) = node.child(i) {
                 match child.kind() {
                     "comparison_expression" => return self.transform_comparison(&child, source),
+                    "arithmetic_expression" => return self.transform_arithmetic(&child, source),
+                    "concat_expression" => return self.transform_concat(&child, source),
+                    "cast_expression" => return self.transform_cast(&child, source),
+                    "case_expression" => return self.transform_case(&child, source),
+                    "function_call" => return self.transform_function_call(&child, source),
                     "column_name" => {
-                        let name = self.get_node_text(&child, source)?;
+                        let name = self.get_column_name(&child, source)?;
                         return Ok(Expression::Column(name));
                     }
                     "literal" => return self.transform_literal(&child, source),
@@ -633,9 +1137,11 @@ impl Parser {
                 match child.kind() {
                     "string_literal" => {
                         let text = self.get_node_text(&child, source)?;
-                        // remove quotes (both ' and ")
-                        let text = text.trim_start_matches(&['\'', '"'][..])
-                                      .trim_end_matches(&['\'', '"'][..]);
+                        // remove the enclosing quotes (only those: the contents may be anything)
+                        let text = text
+                            .strip_prefix('\'')
+                            .and_then(|t| t.strip_suffix('\''))
+                            .unwrap_or(&text);
                         return Ok(Expression::Literal(LiteralValue::String(text.to_string())));
                     }
                     "number_literal" => {
@@ -651,6 +1157,14 @@ impl Parser {
                         let b = text.trim().to_lowercase() == "true";
                         return Ok(Expression::Literal(LiteralValue::Boolean(b)));
                     }
+                    "date_literal" => {
+                        let text = self.typed_literal_text(&child, source)?;
+                        return Ok(Expression::Literal(LiteralValue::Date(text)));
+                    }
+                    "timestamp_literal" => {
+                        let text = self.typed_literal_text(&child, source)?;
+                        return Ok(Expression::Literal(LiteralValue::Timestamp(text)));
+                    }
                     _ => {}
                 }
             }
@@ -666,11 +1180,34 @@ impl Parser {
         })
     }
 
+    /// the quoted part of `DATE '...'` or `TIMESTAMP '...'`, unchecked (the
+    /// binder validates it)
+    fn typed_literal_text(&self, node: &Node, source: &str) -> ParseResult<String> {
+        let text = self.get_node_text(node, source)?;
+        let quoted = &text[text.find('\'').map_or(0, |quote| quote + 1)..];
+        Ok(quoted.strip_suffix('\'').unwrap_or(quoted).to_string())
+    }
+
     fn transform_comparison(&self, node: &Node, source: &str) -> ParseResult<Expression> {
         let children: Vec<Node> = (0..node.child_count())
             .filter_map(|i| node.child(i))
             .collect();
 
+        // `a = 1 = 2` would compare a boolean result with 2; as in SQL,
+        // comparisons only nest inside parentheses
+        let chained = children.iter().find(|child| {
+            child.kind() == "primary_expression"
+                && child
+                    .named_child(0)
+                    .is_some_and(|operand| operand.kind() == "comparison_expression")
+        });
+        if let Some(operand) = chained {
+            return Err(ParseError {
+                message: "comparisons cannot be chained, add parentheses".to_string(),
+                offset: operand.start_byte(),
+            });
+        }
+
         // find the operator
         for (i, child) in children.iter().enumerate() {
             let text = self.get_node_text(child, source)?;
@@ -695,18 +1232,277 @@ impl Parser {
             }
         }
 
+        // keyword operators are hidden tokens, read them from the gap between operands
+        // (LIKE may be followed by an ESCAPE clause)
+        if children.len() == 2 || (children.len() == 3 && children[2].kind() == "like_escape") {
+            let op = source[children[0].end_byte()..children[1].start_byte()]
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_uppercase();
+            let left = self.transform_expression(&children[0], source)?;
+            match op.as_str() {
+                "LIKE" | "NOT LIKE" | "ILIKE" | "NOT ILIKE" => {
+                    let right = self.transform_expression(&children[1], source)?;
+                    let escape = match children.get(2) {
+                        Some(clause) => Some(self.transform_like_escape(clause, source)?),
+                        None => None,
+                    };
+                    let like = if op.ends_with("ILIKE") {
+                        Expression::ILike(Box::new(left), Box::new(right), escape)
+                    } else {
+                        Expression::Like(Box::new(left), Box::new(right), escape)
+                    };
+                    if op.starts_with("NOT") {
+                        return Ok(Expression::Not(Box::new(like)));
+                    }
+                    return Ok(like);
+                }
+                "IN" | "NOT IN" => {
+                    let list = self.transform_in_list(&children[1], source)?;
+                    let in_expr = Expression::In(Box::new(left), list);
+                    if op == "NOT IN" {
+                        // reuse NOT handling instead of a separate variant
+                        return Ok(Expression::Not(Box::new(in_expr)));
+                    }
+                    return Ok(in_expr);
+                }
+                "IS" | "IS NOT" => {
+                    let truth = self.get_node_text(&children[1], source)?;
+                    let test = if truth.eq_ignore_ascii_case("true") {
+                        Expression::IsTrue(Box::new(left))
+                    } else {
+                        Expression::IsFalse(Box::new(left))
+                    };
+                    if op == "IS NOT" {
+                        return Ok(Expression::Not(Box::new(test)));
+                    }
+                    return Ok(test);
+                }
+                _ => {}
+            }
+        }
+
         Err(ParseError {
             message: "Invalid comparison_expression".to_string(),
             offset: node.start_byte(),
         })
     }
 
+    /// the character of an ESCAPE clause, which must be exactly one
+    fn transform_like_escape(&self, node: &Node, source: &str) -> ParseResult<char> {
+        // like_escape: ESCAPE string_literal
+        let literal = node.named_child(0).ok_or_else(|| ParseError {
+            message: "ESCAPE requires a string".to_string(),
+            offset: node.start_byte(),
+        })?;
+        let text = self.get_node_text(&literal, source)?;
+        let text = text
+            .strip_prefix('\'')
+            .and_then(|t| t.strip_suffix('\''))
+            .unwrap_or(&text);
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(escape), None) => Ok(escape),
+            _ => Err(ParseError {
+                message: format!("ESCAPE must be a single character, got '{}'", text),
+                offset: literal.start_byte(),
+            }),
+        }
+    }
+
+    fn transform_arithmetic(&self, node: &Node, source: &str) -> ParseResult<Expression> {
+        // arithmetic_expression: primary_expression (+ | - | * | / | %) primary_expression
+        //                       | '-' primary_expression
+        let children: Vec<Node> = (0..node.child_count())
+            .filter_map(|i| node.child(i))
+            .collect();
+
+        // '-' is the only prefix operator
+        if let [_, operand] = children.as_slice() {
+            let operand = Box::new(self.transform_expression(operand, source)?);
+            return Ok(Expression::Negate(operand));
+        }
+
+        if let [left, op, right] = children.as_slice() {
+            let left = Box::new(self.transform_expression(left, source)?);
+            let right = Box::new(self.transform_expression(right, source)?);
+            match op.kind() {
+                "+" => return Ok(Expression::Add(left, right)),
+                "-" => return Ok(Expression::Subtract(left, right)),
+                "*" => return Ok(Expression::Multiply(left, right)),
+                "/" => return Ok(Expression::Divide(left, right)),
+                "%" => return Ok(Expression::Modulo(left, right)),
+                _ => {}
+            }
+        }
+
+        Err(ParseError {
+            message: "Invalid arithmetic_expression".to_string(),
+            offset: node.start_byte(),
+        })
+    }
+
+    fn transform_concat(&self, node: &Node, source: &str) -> ParseResult<Expression> {
+        // concat_expression: primary_expression || primary_expression
+        if let (Some(left), Some(right)) = (node.child(0), node.child(2)) {
+            let left = Box::new(self.transform_expression(&left, source)?);
+            let right = Box::new(self.transform_expression(&right, source)?);
+            return Ok(Expression::Concat(left, right));
+        }
+
+        Err(ParseError {
+            message: "Invalid concat_expression".to_string(),
+            offset: node.start_byte(),
+        })
+    }
+
+    fn transform_cast(&self, node: &Node, source: &str) -> ParseResult<Expression> {
+        // cast_expression: CAST ( expression AS type_name )
+        let mut expr = None;
+        let mut target = None;
+
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                match child.kind() {
+                    "expression" => expr = Some(self.transform_expression(&child, source)?),
+                    "type_name" => {
+                        let name = self.get_node_text(&child, source)?;
+                        target = Some(match name.to_uppercase().as_str() {
+                            "INTEGER" | "INT" | "BIGINT" => ColumnType::Integer,
+                            "FLOAT" | "DOUBLE" | "REAL" => ColumnType::Float,
+                            "BOOLEAN" | "BOOL" => ColumnType::Boolean,
+                            "VARCHAR" | "TEXT" | "STRING" => ColumnType::Varchar,
+                            "DATE" => ColumnType::Date,
+                            "TIMESTAMP" | "DATETIME" => ColumnType::Timestamp,
+                            _ => {
+                                return Err(ParseError {
+                                    message: format!(
+                                        "Unknown type '{}' in CAST - expected INTEGER, FLOAT, BOOLEAN, VARCHAR, DATE or TIMESTAMP",
+                                        name
+                                    ),
+                                    offset: child.start_byte(),
+                                });
+                            }
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        match (expr, target) {
+            (Some(expr), Some(target)) => Ok(Expression::Cast {
+                expr: Box::new(expr),
+                target,
+            }),
+            _ => Err(ParseError {
+                message: "Invalid cast_expression".to_string(),
+                offset: node.start_byte(),
+            }),
+        }
+    }
+
+    fn transform_case(&self, node: &Node, source: &str) -> ParseResult<Expression> {
+        // case_expression: CASE when_clause+ [else_clause] END
+        let mut branches = Vec::new();
+        let mut else_ = None;
+
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                // both clauses hold their expressions in order: WHEN cond THEN result / ELSE result
+                let mut expressions = (0..child.child_count())
+                    .filter_map(|j| child.child(j))
+                    .filter(|grandchild| grandchild.kind() == "expression")
+                    .map(|grandchild| self.transform_expression(&grandchild, source))
+                    .collect::<ParseResult<Vec<_>>>()?;
+
+                match (child.kind(), expressions.len()) {
+                    ("when_clause", 2) => {
+                        let result = expressions.pop().unwrap();
+                        let condition = expressions.pop().unwrap();
+                        branches.push((condition, result));
+                    }
+                    ("else_clause", 1) => else_ = expressions.pop().map(Box::new),
+                    ("when_clause" | "else_clause", _) => {
+                        return Err(ParseError {
+                            message: format!("Invalid {}", child.kind()),
+                            offset: child.start_byte(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if branches.is_empty() {
+            return Err(ParseError {
+                message: "Invalid case_expression".to_string(),
+                offset: node.start_byte(),
+            });
+        }
+        Ok(Expression::Case { branches, else_ })
+    }
+
+    fn transform_in_list(&self, node: &Node, source: &str) -> ParseResult<Vec<Expression>> {
+        // in_list: '(' literal (',' literal)* ')'
+        let items = (0..node.child_count())
+            .filter_map(|i| node.child(i))
+            .filter(|child| child.kind() == "literal")
+            .map(|child| self.transform_literal(&child, source))
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        if items.is_empty() {
+            return Err(ParseError {
+                message: "IN list must not be empty".to_string(),
+                offset: node.start_byte(),
+            });
+        }
+        Ok(items)
+    }
+
     fn get_node_text(&self, node: &Node, source: &str) -> ParseResult<String> {
         Ok(source[node.start_byte()..node.end_byte()].to_string())
     }
 
+    /// column name without its qualifier or quotes ("first name", [first name] or `first name`)
+    fn get_column_name(&self, node: &Node, source: &str) -> ParseResult<String> {
+        // data.name: the name starts after the '.'
+        let start = match node.child(0) {
+            Some(child) if child.kind() == "table_qualifier" => {
+                node.child(1).map_or(node.end_byte(), |dot| dot.end_byte())
+            }
+            _ => node.start_byte(),
+        };
+        let text = source[start..node.end_byte()].trim();
+        let unquoted = [('"', '"'), ('[', ']'), ('`', '`')]
+            .iter()
+            .find_map(|&(open, close)| text.strip_prefix(open)?.strip_suffix(close));
+        Ok(unquoted.unwrap_or(text).to_string())
+    }
+
+    /// every table qualifier used on a column reference, in query order
+    fn collect_qualifiers(&self, node: &Node, source: &str) -> ParseResult<Vec<String>> {
+        // a cursor walk: long AND/OR chains nest the tree one node per operator
+        let mut qualifiers = Vec::new();
+        let mut cursor = node.walk();
+        loop {
+            let current = cursor.node();
+            if current.kind() == "table_qualifier" {
+                qualifiers.push(self.get_node_text(&current, source)?);
+            } else if cursor.goto_first_child() {
+                continue;
+            }
+            while !cursor.goto_next_sibling() {
+                if !cursor.goto_parent() {
+                    return Ok(qualifiers);
+                }
+            }
+        }
+    }
+
     fn extract_number_from_clause(&self, node: &Node, source: &str) -> ParseResult<usize> {
-        // extract number from limit_clause or offset_clause
+        // extract number from sample_clause, limit_clause, offset_clause or fetch_clause
         for i in 0..node.child_count() {
             if let Some(child) = node.child(i) {
                 if child.kind() == "number_literal" {
@@ -731,6 +1527,129 @@ impl Default for Parser {
     }
 }
 
+/// replaces `-- line` and `/* block */` comments with spaces (keeping their
+/// newlines), so error offsets still point into the original text. quoted
+/// strings and identifiers are left as they are
+fn blank_comments(sql: &str) -> ParseResult<String> {
+    let mut bytes = sql.as_bytes().to_vec();
+    let mut i = 0;
+    while i < bytes.len() {
+        let end = match bytes[i] {
+            b'\'' | b'"' | b'`' | b'[' => {
+                i = quoted_end(&bytes, i);
+                continue;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => bytes[i..]
+                .iter()
+                .position(|&byte| byte == b'\n')
+                .map_or(bytes.len(), |position| i + position),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => match sql[i + 2..].find("*/") {
+                Some(position) => i + position + 4,
+                None => {
+                    return Err(ParseError {
+                        message: "Unterminated /* comment".to_string(),
+                        offset: i,
+                    });
+                }
+            },
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        for byte in &mut bytes[i..end] {
+            if *byte != b'\n' {
+                *byte = b' ';
+            }
+        }
+        i = end;
+    }
+    // whole characters were replaced by ASCII spaces, so this is still UTF-8
+    Ok(String::from_utf8(bytes).expect("comments are blanked a character at a time"))
+}
+
+/// `operands` joined by `join` (AND or OR) into a balanced tree, so a chain of
+/// thousands of terms nests only a few levels deep. both are associative, and
+/// up to three operands keep the grammar's right-nested shape
+fn balanced(
+    mut operands: Vec<Expression>,
+    join: fn(Box<Expression>, Box<Expression>) -> Expression,
+) -> Option<Expression> {
+    if operands.len() <= 1 {
+        return operands.pop();
+    }
+    let right = operands.split_off(operands.len() / 2);
+    Some(join(
+        Box::new(balanced(operands, join)?),
+        Box::new(balanced(right, join)?),
+    ))
+}
+
+/// the single operand of a node that only wraps it (an or_expression without
+/// OR, a primary_expression holding a comparison, ...). keywords are hidden
+/// tokens, so `NOT x` is a not_expression whose only child is the operand
+fn wrapped_operand(node: Node) -> Option<Node> {
+    let wrapper = matches!(
+        node.kind(),
+        "expression" | "or_expression" | "and_expression" | "not_expression" | "primary_expression"
+    );
+    if !wrapper || node.child_count() != 1 {
+        return None;
+    }
+    let child = node.child(0)?;
+    if node.kind() == "not_expression" && child.kind() == "not_expression" {
+        return None;
+    }
+    Some(child)
+}
+
+/// the offset just past the quoted string or identifier opening at `start`.
+/// an unclosed quote runs to the end, left for the grammar to reject
+fn quoted_end(bytes: &[u8], start: usize) -> usize {
+    let close = if bytes[start] == b'[' {
+        b']'
+    } else {
+        bytes[start]
+    };
+    bytes[start + 1..]
+        .iter()
+        .position(|&byte| byte == close)
+        .map_or(bytes.len(), |position| start + position + 2)
+}
+
+/// replaces with a space each comma that only whitespace separates from a
+/// following FROM keyword, outside quotes
+fn blank_trailing_commas(sql: String) -> String {
+    let mut bytes = sql.into_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' | b'"' | b'`' | b'[' => {
+                i = quoted_end(&bytes, i);
+                continue;
+            }
+            b',' => {
+                let next = bytes[i + 1..]
+                    .iter()
+                    .position(|byte| !byte.is_ascii_whitespace())
+                    .map_or(bytes.len(), |position| i + 1 + position);
+                let rest = &bytes[next..];
+                let is_from = rest.len() >= 4
+                    && rest[..4].eq_ignore_ascii_case(b"from")
+                    && rest
+                        .get(4)
+                        .is_none_or(|&byte| !(byte.is_ascii_alphanumeric() || byte == b'_'));
+                if is_from {
+                    bytes[i] = b' ';
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    String::from_utf8(bytes).expect("only an ASCII comma is replaced")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,10 @@
-use crate::binder::{BoundAggregateExpression, BoundExpression, BoundQuery, Column};
+use crate::binder::{
+    BoundAggregateExpression, BoundExpression, BoundOrderBy, BoundQuery, Column, SourceFormat,
+};
+use crate::estimator;
+use crate::source::DataSource;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum LogicalOperator {
@@ -8,25 +13,41 @@ pub enum LogicalOperator {
     Projection(LogicalProjection),
     Limit(LogicalLimit),
     Aggregate(LogicalAggregate),
+    Sort(LogicalSort),
+    TopN(LogicalTopN),
+    Sample(LogicalSample),
+    Union(LogicalUnion),
+    Distinct(LogicalDistinct),
+    Explain(LogicalExplain),
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct LogicalGet {
     pub file_path: PathBuf,
-    pub columns: Vec<Column>,    // schema of the file
-    pub max_rows: Option<usize>, // pushed down from LIMIT for early termination
+    pub union_files: Vec<PathBuf>, // read after file_path, same columns
+    pub columns: Vec<Column>,      // schema of the file
+    pub max_rows: Option<usize>,   // pushed down from LIMIT for early termination
+    pub has_header: bool,          // skip the file's first line
+    pub format: SourceFormat,      // CSV or JSON lines
+    pub empty_as_null: bool,       // empty Varchar cells read as NULL
+    pub null_tokens: Vec<String>,  // cell texts read as NULL
+    pub source: Option<Arc<dyn DataSource>>, // read instead of file_path (bind_source)
+    pub estimated_rows: Option<usize>, // filled in by the estimator (EXPLAIN only)
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct LogicalFilter {
     pub expression: BoundExpression,
     pub child: Box<LogicalOperator>,
+    pub estimated_rows: Option<usize>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct LogicalProjection {
     pub expressions: Vec<BoundExpression>,
+    pub aliases: Vec<Option<String>>, // output names from AS, one per expression (None: default)
     pub child: Box<LogicalOperator>,
+    pub estimated_rows: Option<usize>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -34,50 +55,332 @@ pub struct LogicalLimit {
     pub limit: Option<usize>,
     pub offset: Option<usize>,
     pub child: Box<LogicalOperator>,
+    pub estimated_rows: Option<usize>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct LogicalAggregate {
+    pub groups: Vec<BoundExpression>, // GROUP BY keys (empty for a single result row)
     pub aggregates: Vec<BoundAggregateExpression>,
     pub child: Box<LogicalOperator>,
+    pub estimated_rows: Option<usize>,
 }
 
-pub struct Planner;
+/// ORDER BY: emits the child's rows ordered by the keys (most significant first)
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalSort {
+    pub keys: Vec<BoundOrderBy>,
+    pub child: Box<LogicalOperator>,
+    pub estimated_rows: Option<usize>,
+}
+
+/// ORDER BY with LIMIT: the first limit rows in sort order after skipping offset.
+/// never planned directly; the optimizer fuses it from a Sort under a Limit
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalTopN {
+    pub keys: Vec<BoundOrderBy>,
+    pub limit: usize,
+    pub offset: usize,
+    pub child: Box<LogicalOperator>,
+    pub estimated_rows: Option<usize>,
+}
+
+/// SAMPLE with a reservoir: a uniform random choice of size rows of the child,
+/// in input order. the same seed picks the same rows
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalSample {
+    pub size: usize,
+    pub seed: u64,
+    pub child: Box<LogicalOperator>,
+    pub estimated_rows: Option<usize>,
+}
+
+/// UNION ALL: every row of each child, one child after another. the children
+/// output the same number of columns, named like the first child's
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalUnion {
+    pub children: Vec<LogicalOperator>,
+    pub estimated_rows: Option<usize>,
+}
+
+/// the child's rows without duplicates, first occurrences in input order
+/// (UNION without ALL)
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalDistinct {
+    pub child: Box<LogicalOperator>,
+    pub estimated_rows: Option<usize>,
+}
+
+/// EXPLAIN: produces the child plan's description instead of running it
+/// (or, with ANALYZE, after running it)
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalExplain {
+    pub child: Box<LogicalOperator>,
+    pub analyze: bool, // run the child and report what each operator did
+}
+
+impl LogicalOperator {
+    /// indented text form of the plan, one operator per line (root first)
+    pub fn explain(&self) -> String {
+        let mut lines = Vec::new();
+        self.explain_into(0, &mut lines);
+        lines.join("\n")
+    }
+
+    fn explain_into(&self, depth: usize, lines: &mut Vec<String>) {
+        let join = |items: Vec<String>| items.join(", ");
+        let (line, children) = match self {
+            LogicalOperator::Get(get) => {
+                let columns = join(get.columns.iter().map(|c| c.name.clone()).collect());
+                let files = join(
+                    std::iter::once(&get.file_path)
+                        .chain(&get.union_files)
+                        .map(|path| path.display().to_string())
+                        .collect(),
+                );
+                let mut line = format!("Get {} [{}]", files, columns);
+                if let Some(max_rows) = get.max_rows {
+                    line.push_str(&format!(" max_rows={}", max_rows));
+                }
+                (with_estimate(line, get.estimated_rows), Vec::new())
+            }
+            LogicalOperator::Filter(filter) => {
+                let mut line = format!("Filter {}", filter.expression);
+                if filter.estimated_rows.is_some() {
+                    let fraction = estimator::selectivity(&filter.expression);
+                    line.push_str(&format!(" selectivity={:.2}", fraction));
+                }
+                (
+                    with_estimate(line, filter.estimated_rows),
+                    vec![&*filter.child],
+                )
+            }
+            LogicalOperator::Projection(proj) => {
+                let expressions = join(proj.expressions.iter().map(|e| e.to_string()).collect());
+                let line = format!("Projection [{}]", expressions);
+                (with_estimate(line, proj.estimated_rows), vec![&*proj.child])
+            }
+            LogicalOperator::Limit(limit) => {
+                let mut line = "Limit".to_string();
+                if let Some(n) = limit.limit {
+                    line.push_str(&format!(" {}", n));
+                }
+                if let Some(n) = limit.offset {
+                    line.push_str(&format!(" offset {}", n));
+                }
+                (
+                    with_estimate(line, limit.estimated_rows),
+                    vec![&*limit.child],
+                )
+            }
+            LogicalOperator::Aggregate(agg) => {
+                let aggregates = join(agg.aggregates.iter().map(|a| a.to_string()).collect());
+                let mut line = format!("Aggregate [{}]", aggregates);
+                if !agg.groups.is_empty() {
+                    let groups = join(agg.groups.iter().map(|g| g.to_string()).collect());
+                    line.push_str(&format!(" group by [{}]", groups));
+                }
+                (with_estimate(line, agg.estimated_rows), vec![&*agg.child])
+            }
+            LogicalOperator::Sort(sort) => {
+                let keys = join(sort.keys.iter().map(|k| k.to_string()).collect());
+                let line = format!("Sort [{}]", keys);
+                (with_estimate(line, sort.estimated_rows), vec![&*sort.child])
+            }
+            LogicalOperator::TopN(top_n) => {
+                let keys = join(top_n.keys.iter().map(|k| k.to_string()).collect());
+                let mut line = format!("TopN {}", top_n.limit);
+                if top_n.offset > 0 {
+                    line.push_str(&format!(" offset {}", top_n.offset));
+                }
+                line.push_str(&format!(" [{}]", keys));
+                (
+                    with_estimate(line, top_n.estimated_rows),
+                    vec![&*top_n.child],
+                )
+            }
+            LogicalOperator::Sample(sample) => {
+                let line = format!("Sample {} seed={}", sample.size, sample.seed);
+                (
+                    with_estimate(line, sample.estimated_rows),
+                    vec![&*sample.child],
+                )
+            }
+            LogicalOperator::Union(union) => (
+                with_estimate("Union".to_string(), union.estimated_rows),
+                union.children.iter().collect(),
+            ),
+            LogicalOperator::Distinct(distinct) => (
+                with_estimate("Distinct".to_string(), distinct.estimated_rows),
+                vec![&*distinct.child],
+            ),
+            LogicalOperator::Explain(explain) => {
+                let line = if explain.analyze {
+                    "Explain Analyze"
+                } else {
+                    "Explain"
+                };
+                (line.to_string(), vec![&*explain.child])
+            }
+        };
+
+        lines.push(format!("{}{}", "  ".repeat(depth), line));
+        for child in children {
+            child.explain_into(depth + 1, lines);
+        }
+    }
+}
+
+/// sort the rows by the keys, bound against them: the input of the output
+/// projection, or the combined rows of a UNION
+fn sorted(child: LogicalOperator, keys: Vec<BoundOrderBy>) -> LogicalOperator {
+    if keys.is_empty() {
+        return child;
+    }
+    LogicalOperator::Sort(LogicalSort {
+        keys,
+        child: Box::new(child),
+        estimated_rows: None,
+    })
+}
+
+/// append the estimator's row count to an EXPLAIN line, if it ran
+fn with_estimate(mut line: String, estimated_rows: Option<usize>) -> String {
+    if let Some(rows) = estimated_rows {
+        line.push_str(&format!(" est_rows={}", rows));
+    }
+    line
+}
+
+/// how SAMPLE n picks its rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleMethod {
+    /// the first n rows; the scan stops early, so this is cheap on big files
+    Head,
+    /// n rows chosen uniformly from the whole file with a seeded generator
+    Reservoir { seed: u64 },
+}
+
+pub struct Planner {
+    sample_method: SampleMethod,
+}
 
 impl Planner {
     pub fn new() -> Self {
-        Self
+        Self {
+            sample_method: SampleMethod::Head,
+        }
     }
 
-    pub fn plan(&self, query: BoundQuery) -> LogicalOperator {
+    /// pick SAMPLE rows with the given method instead of taking the first ones
+    pub fn with_sample_method(mut self, method: SampleMethod) -> Self {
+        self.sample_method = method;
+        self
+    }
+
+    pub fn plan(&self, mut query: BoundQuery) -> LogicalOperator {
+        let (limit, offset) = (query.limit, query.offset);
+        let (explain, analyze) = (query.explain, query.analyze);
+        let unions = std::mem::take(&mut query.unions);
+        // after a UNION the keys sort the combined rows, not the first SELECT's
+        let union_order_by = if unions.is_empty() {
+            Vec::new()
+        } else {
+            std::mem::take(&mut query.order_by)
+        };
+
+        let mut root = self.plan_select(query);
+
+        // 4. Combine the UNIONed SELECTs, left to right: UNION ALL appends
+        // the next SELECT's rows, UNION then drops the duplicates
+        for union in unions {
+            let next = self.plan_select(union.query);
+            root = match root {
+                LogicalOperator::Union(mut combined) => {
+                    combined.children.push(next);
+                    LogicalOperator::Union(combined)
+                }
+                first => LogicalOperator::Union(LogicalUnion {
+                    children: vec![first, next],
+                    estimated_rows: None,
+                }),
+            };
+            if !union.all {
+                root = LogicalOperator::Distinct(LogicalDistinct {
+                    child: Box::new(root),
+                    estimated_rows: None,
+                });
+            }
+        }
+        root = sorted(root, union_order_by);
+
+        // 5. Apply Limit/Offset (if present)
+        if limit.is_some() || offset.is_some() {
+            root = LogicalOperator::Limit(LogicalLimit {
+                limit,
+                offset,
+                child: Box::new(root),
+                estimated_rows: None,
+            });
+        }
+
+        // 6. EXPLAIN describes the plan instead of running it
+        if explain {
+            root = LogicalOperator::Explain(LogicalExplain {
+                child: Box::new(root),
+                analyze,
+            });
+        }
+
+        root
+    }
+
+    /// the rows of one SELECT: scan, filter, aggregate, ORDER BY and the output
+    /// projection (LIMIT and EXPLAIN are added over the whole query)
+    fn plan_select(&self, query: BoundQuery) -> LogicalOperator {
         // 1. Create Source (LogicalGet)
         let mut root = LogicalOperator::Get(LogicalGet {
             file_path: query.file_path,
+            union_files: query.union_files,
             columns: query.schema.columns,
-            max_rows: None, // will be set by optimizer if LIMIT can be pushed down
+            // a head SAMPLE stops the scan; the optimizer may lower this for LIMIT
+            max_rows: match self.sample_method {
+                SampleMethod::Head => query.sample,
+                SampleMethod::Reservoir { .. } => None,
+            },
+            has_header: query.has_header,
+            format: query.format,
+            empty_as_null: query.empty_as_null,
+            null_tokens: query.null_tokens,
+            source: query.source,
+            estimated_rows: None,
         });
 
+        // 1b. Reservoir SAMPLE (if present), before anything looks at the rows
+        if let (Some(size), SampleMethod::Reservoir { seed }) = (query.sample, self.sample_method) {
+            root = LogicalOperator::Sample(LogicalSample {
+                size,
+                seed,
+                child: Box::new(root),
+                estimated_rows: None,
+            });
+        }
+
         // 2. Apply Filter (if present)
         if let Some(where_clause) = query.where_clause {
             root = LogicalOperator::Filter(LogicalFilter {
                 expression: where_clause,
                 child: Box::new(root),
+                estimated_rows: None,
             });
         }
 
         // 3. Apply Aggregate (if present)
-        // aggregates consume all rows and produce single result row
-        if !query.aggregates.is_empty() {
-            root = LogicalOperator::Aggregate(LogicalAggregate {
-                aggregates: query.aggregates,
-                child: Box::new(root),
-            });
-            // note: For aggregates, we don't add projection - aggregate itself returns the result
-        } else {
-            // 3b. Apply Projection (only if no aggregates)
-            // convert selected columns into BoundExpression::ColumnRef
-            let projection_expressions = query
-                .select_columns
+        if !query.group_by.is_empty() {
+            // grouped: one row per group, then HAVING and the SELECT list
+            // are evaluated over the aggregate output
+            let groups = query
+                .group_by
                 .into_iter()
                 .map(|col| BoundExpression::ColumnRef {
                     name: col.name,
@@ -86,18 +389,55 @@ impl Planner {
                 })
                 .collect();
 
-            root = LogicalOperator::Projection(LogicalProjection {
-                expressions: projection_expressions,
+            root = LogicalOperator::Aggregate(LogicalAggregate {
+                groups,
+                aggregates: query.aggregates,
                 child: Box::new(root),
+                estimated_rows: None,
             });
-        }
 
-        // 4. Apply Limit/Offset (if present)
-        if query.limit.is_some() || query.offset.is_some() {
-            root = LogicalOperator::Limit(LogicalLimit {
-                limit: query.limit,
-                offset: query.offset,
+            if let Some(having) = query.having {
+                root = LogicalOperator::Filter(LogicalFilter {
+                    expression: having,
+                    child: Box::new(root),
+                    estimated_rows: None,
+                });
+            }
+
+            root = sorted(root, query.order_by);
+            root = LogicalOperator::Projection(LogicalProjection {
+                expressions: query.output,
+                aliases: query.aliases,
+                child: Box::new(root),
+                estimated_rows: None,
+            });
+        } else if !query.aggregates.is_empty() {
+            // aggregates consume all rows and produce single result row
+            root = LogicalOperator::Aggregate(LogicalAggregate {
+                groups: Vec::new(),
+                aggregates: query.aggregates,
+                child: Box::new(root),
+                estimated_rows: None,
+            });
+            // the aggregate itself returns the result; a projection is only
+            // needed to rename its columns (SELECT COUNT(*) AS n)
+            if !query.output.is_empty() {
+                root = LogicalOperator::Projection(LogicalProjection {
+                    expressions: query.output,
+                    aliases: query.aliases,
+                    child: Box::new(root),
+                    estimated_rows: None,
+                });
+            }
+        } else {
+            // 3b. Apply Projection (only if no aggregates)
+            // the binder already turned the SELECT list into expressions
+            root = sorted(root, query.order_by);
+            root = LogicalOperator::Projection(LogicalProjection {
+                expressions: query.output,
+                aliases: query.aliases,
                 child: Box::new(root),
+                estimated_rows: None,
             });
         }
 
@@ -0,0 +1,109 @@
+use crate::compression;
+use std::fmt;
+use std::io::{self, Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// where a query's rows come from. the binder reads the first lines to find
+/// the columns and their types; each scan opens a fresh reader over the rows
+pub trait DataSource: fmt::Debug + Send + Sync {
+    /// name shown in EXPLAIN and in error messages
+    fn name(&self) -> String;
+
+    /// the whole source as text
+    fn read_to_string(&self) -> io::Result<String>;
+
+    /// a reader over the source from its first line
+    fn open(&self) -> io::Result<Box<dyn Read + Send>>;
+
+    /// an uncompressed file the parallel scan can split into byte ranges.
+    /// sources without one are scanned sequentially
+    fn seekable_path(&self) -> Option<&Path> {
+        None
+    }
+}
+
+/// sources are equal when they are the same object
+impl PartialEq for dyn DataSource {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::addr_eq(self, other)
+    }
+}
+
+/// a file on disk, the default source (gzip files are decompressed while
+/// read; JSON-lines files are read through it too)
+#[derive(Debug, Clone)]
+pub struct CsvFileSource {
+    path: PathBuf,
+    compressed: bool,
+}
+
+impl CsvFileSource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let compressed = compression::is_gzip(&path);
+        Self { path, compressed }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl DataSource for CsvFileSource {
+    fn name(&self) -> String {
+        self.path.display().to_string()
+    }
+
+    fn read_to_string(&self) -> io::Result<String> {
+        compression::read_to_string(&self.path)
+    }
+
+    fn open(&self) -> io::Result<Box<dyn Read + Send>> {
+        compression::open_file(&self.path)
+    }
+
+    fn seekable_path(&self) -> Option<&Path> {
+        // a gzip stream can't be split
+        (!self.compressed).then_some(self.path.as_path())
+    }
+}
+
+/// text held in memory, queried by name (see Binder::bind_from_str)
+#[derive(Debug, Clone)]
+pub struct StringSource {
+    name: String,
+    contents: Arc<str>,
+}
+
+impl StringSource {
+    pub fn new(name: impl Into<String>, contents: impl Into<Arc<str>>) -> Self {
+        Self {
+            name: name.into(),
+            contents: contents.into(),
+        }
+    }
+}
+
+impl DataSource for StringSource {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn read_to_string(&self) -> io::Result<String> {
+        Ok(self.contents.to_string())
+    }
+
+    fn open(&self) -> io::Result<Box<dyn Read + Send>> {
+        Ok(Box::new(Cursor::new(SharedText(self.contents.clone()))))
+    }
+}
+
+/// in-memory text as bytes, so a Cursor can read it without a copy
+struct SharedText(Arc<str>);
+
+impl AsRef<[u8]> for SharedText {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
@@ -0,0 +1,359 @@
+use celect::{Binder, Optimizer, Parser, Planner};
+use celect::{DataChunk, PhysicalPlanner, PipelineExecutor, Value};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+
+fn create_test_csv(name: &str, content: &str) -> PathBuf {
+    let file_path = std::env::temp_dir().join(format!("celect_agg_test_{}.csv", name));
+    let mut file = File::create(&file_path).unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file_path
+}
+
+fn cleanup_test_csv(path: &PathBuf) {
+    let _ = fs::remove_file(path);
+}
+
+fn execute_aggregate_query(sql: &str) -> DataChunk {
+    let mut parser = Parser::new();
+    let query = parser.parse(sql).unwrap();
+
+    let binder = Binder::new();
+    let bound_query = binder.bind(query).unwrap();
+
+    let planner = Planner::new();
+    let logical_plan = planner.plan(bound_query);
+
+    let optimizer = Optimizer::new();
+    let optimized_plan = optimizer.optimize(logical_plan);
+
+    let physical_planner = PhysicalPlanner::new();
+    let (operators, schemas) = physical_planner.plan(optimized_plan);
+
+    let mut executor = PipelineExecutor::new(operators, schemas);
+    let results = executor.execute();
+
+    assert_eq!(
+        results.len(),
+        1,
+        "Expected single result chunk for aggregate"
+    );
+    results.into_iter().next().unwrap()
+}
+
+fn execute_grouped_query(sql: &str) -> Vec<Vec<Value>> {
+    let mut parser = Parser::new();
+    let query = parser.parse(sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let logical_plan = Planner::new().plan(bound_query);
+    let optimized_plan = Optimizer::new().optimize(logical_plan);
+    let (operators, schemas) = PhysicalPlanner::new().plan(optimized_plan);
+
+    let mut executor = PipelineExecutor::new(operators, schemas);
+    executor
+        .execute()
+        .iter()
+        .flat_map(|chunk| chunk.selected_rows_as_values())
+        .collect()
+}
+
+#[test]
+fn test_sum_of_product() {
+    // the unused note column checks that pushdown keeps both price and qty
+    let csv_content = "id,price,note,qty\n1,10,a,3\n2,25,b,2\n3,7,c,10\n4,100,d,\n";
+    let file_path = create_test_csv("sum_product", csv_content);
+
+    let sql = format!("SELECT SUM(price * qty) FROM '{}'", file_path.display());
+    let result = execute_aggregate_query(&sql);
+
+    // 10*3 + 25*2 + 7*10, the row with a NULL qty is skipped
+    assert_eq!(result.selected_count(), 1);
+    assert_eq!(result.get_value(0, 0), Some(Value::Integer(150)));
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_aggregates_over_expressions_with_filter() {
+    let csv_content = "item,price,qty\napple,1.5,4\npear,2.0,3\nplum,0.5,10\nfig,4.0,1\n";
+    let file_path = create_test_csv("agg_expr_filter", csv_content);
+
+    let sql = format!(
+        "SELECT SUM(price * qty), MIN(qty - 1), MAX(price), AVG(qty), COUNT(price + 1) FROM '{}' WHERE qty > 1",
+        file_path.display()
+    );
+    let result = execute_aggregate_query(&sql);
+
+    assert_eq!(result.get_value(0, 0), Some(Value::Float(17.0)));
+    assert_eq!(result.get_value(1, 0), Some(Value::Integer(2)));
+    assert_eq!(result.get_value(2, 0), Some(Value::Float(2.0)));
+    assert_eq!(result.get_value(3, 0), Some(Value::Float(17.0 / 3.0)));
+    assert_eq!(result.get_value(4, 0), Some(Value::Integer(3)));
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_sum_rejects_varchar_argument() {
+    let csv_content = "id,name\n1,Alice\n";
+    let file_path = create_test_csv("sum_varchar", csv_content);
+
+    let sql = format!("SELECT SUM(name) FROM '{}'", file_path.display());
+    let mut parser = Parser::new();
+    let query = parser.parse(&sql).unwrap();
+    let err = Binder::new().bind(query).unwrap_err();
+    assert!(err.message.contains("SUM"), "{}", err.message);
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_having_filters_groups_by_unselected_aggregate() {
+    let csv_content =
+        "city,sales,rep\nParis,600,a\nBerlin,300,b\nParis,500,c\nRome,1200,d\nBerlin,200,e\n";
+    let file_path = create_test_csv("having_unselected", csv_content);
+
+    let sql = format!(
+        "SELECT city FROM '{}' GROUP BY city HAVING SUM(sales) > 1000",
+        file_path.display()
+    );
+    let rows = execute_grouped_query(&sql);
+
+    // Paris = 1100, Rome = 1200 pass; Berlin = 500 is filtered out.
+    // only the city column is output, in first-seen group order
+    assert_eq!(
+        rows,
+        vec![
+            vec![Value::Varchar("Paris".to_string())],
+            vec![Value::Varchar("Rome".to_string())],
+        ]
+    );
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_having_filters_groups_by_count() {
+    let csv_content =
+        "city,sales\nParis,600\nBerlin,300\nParis,500\nRome,1200\nParis,100\nBerlin,200\n";
+    let file_path = create_test_csv("having_count", csv_content);
+
+    let sql = format!(
+        "SELECT city, COUNT(*) FROM '{}' GROUP BY city HAVING COUNT(*) >= 2",
+        file_path.display()
+    );
+    let rows = execute_grouped_query(&sql);
+
+    // Rome has a single row and is dropped
+    assert_eq!(
+        rows,
+        vec![
+            vec![Value::Varchar("Paris".to_string()), Value::Integer(3)],
+            vec![Value::Varchar("Berlin".to_string()), Value::Integer(2)],
+        ]
+    );
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_having_rejects_ungrouped_column() {
+    let csv_content = "city,sales\nParis,600\n";
+    let file_path = create_test_csv("having_ungrouped", csv_content);
+
+    let sql = format!(
+        "SELECT city, COUNT(*) FROM '{}' GROUP BY city HAVING sales > 100",
+        file_path.display()
+    );
+    let mut parser = Parser::new();
+    let query = parser.parse(&sql).unwrap();
+    let err = Binder::new().bind(query).unwrap_err();
+    assert_eq!(
+        err.message,
+        "Column 'sales' in HAVING must appear in the GROUP BY clause or be used in an aggregate function"
+    );
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_order_by_aggregate_alias() {
+    let csv_content =
+        "city,sales\nRome,600\nBerlin,300\nParis,500\nParis,1200\nParis,100\nBerlin,200\n";
+    let file_path = create_test_csv("order_by_alias", csv_content);
+
+    let sql = format!(
+        "SELECT city, COUNT(*) AS n FROM '{}' GROUP BY city ORDER BY n DESC",
+        file_path.display()
+    );
+    let rows = execute_grouped_query(&sql);
+
+    assert_eq!(
+        rows,
+        vec![
+            vec![Value::Varchar("Paris".to_string()), Value::Integer(3)],
+            vec![Value::Varchar("Berlin".to_string()), Value::Integer(2)],
+            vec![Value::Varchar("Rome".to_string()), Value::Integer(1)],
+        ]
+    );
+
+    // an aggregate that is not selected can still be a sort key
+    let sql = format!(
+        "SELECT city FROM '{}' GROUP BY city ORDER BY SUM(sales)",
+        file_path.display()
+    );
+    let rows = execute_grouped_query(&sql);
+    assert_eq!(
+        rows,
+        vec![
+            vec![Value::Varchar("Berlin".to_string())],
+            vec![Value::Varchar("Rome".to_string())],
+            vec![Value::Varchar("Paris".to_string())],
+        ]
+    );
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_group_by_emits_groups_across_chunks() {
+    // more groups than fit in a single vector
+    let mut csv_content = String::from("id,bucket\n");
+    for i in 0..5000 {
+        csv_content.push_str(&format!("{},{}\n", i, i % 3000));
+    }
+    let file_path = create_test_csv("many_groups", &csv_content);
+
+    let sql = format!(
+        "SELECT bucket, COUNT(*) FROM '{}' GROUP BY bucket HAVING COUNT(*) > 1",
+        file_path.display()
+    );
+    let rows = execute_grouped_query(&sql);
+
+    // buckets 0..2000 are hit twice, the rest once
+    assert_eq!(rows.len(), 2000);
+    assert_eq!(rows[0], vec![Value::Integer(0), Value::Integer(2)]);
+    assert_eq!(rows[1999], vec![Value::Integer(1999), Value::Integer(2)]);
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_group_by_rejects_ungrouped_column() {
+    let csv_content = "city,sales\nParis,600\n";
+    let file_path = create_test_csv("ungrouped_column", csv_content);
+
+    let sql = format!(
+        "SELECT city, sales FROM '{}' GROUP BY city",
+        file_path.display()
+    );
+    let mut parser = Parser::new();
+    let query = parser.parse(&sql).unwrap();
+    let err = Binder::new().bind(query).unwrap_err();
+    assert!(err.message.contains("GROUP BY"), "{}", err.message);
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_parallel_float_sum_is_reproducible() {
+    // large enough (> 1MB) for the scan to split the file across workers
+    let mut csv_content = String::from("id,amount,note\n");
+    let mut serial_sum = 0.0f64;
+    for i in 0..60_000u64 {
+        let amount = format!("{}.{:03}", (i * 7919) % 100_000, (i * 37) % 1000);
+        serial_sum += amount.parse::<f64>().unwrap();
+        csv_content.push_str(&format!("{},{},padding-padding\n", i, amount));
+    }
+    assert!(csv_content.len() > 1_000_000);
+    let file_path = create_test_csv("parallel_float_sum", &csv_content);
+
+    let sql = format!("SELECT SUM(amount) FROM '{}'", file_path.display());
+    let sums: Vec<f64> = (0..5)
+        .map(|_| match execute_aggregate_query(&sql).get_value(0, 0) {
+            Some(Value::Float(sum)) => sum,
+            other => panic!("expected a float sum, got {:?}", other),
+        })
+        .collect();
+
+    // same bits on every run: each worker's partial sum is merged in partition
+    // order, whatever order the workers delivered their chunks in
+    assert!(
+        sums.iter().all(|sum| sum.to_bits() == sums[0].to_bits()),
+        "{:?}",
+        sums
+    );
+    assert!(((sums[0] - serial_sum) / serial_sum).abs() < 1e-12);
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_where_rejects_aggregates() {
+    let csv_content = "city,sales\nParis,600\nRome,300\n";
+    let file_path = create_test_csv("where_aggregate", csv_content);
+
+    for condition in ["COUNT(*) > 1", "sales > AVG(sales)", "NOT (SUM(sales) = 0)"] {
+        let sql = format!(
+            "SELECT city FROM '{}' WHERE {}",
+            file_path.display(),
+            condition
+        );
+        let mut parser = Parser::new();
+        let query = parser.parse(&sql).unwrap();
+        let err = Binder::new().bind(query).unwrap_err();
+        assert_eq!(
+            err.message, "aggregate functions are not allowed in WHERE",
+            "{}",
+            condition
+        );
+    }
+
+    cleanup_test_csv(&file_path);
+}
+
+#[test]
+fn test_aggregates_over_all_null_column() {
+    // note is empty on every line, so it's a Null column; score's NULL is
+    // stored as a placeholder 0 that MIN must not see
+    let csv_content = "id,note,score,city\n1,,5,Paris\n2,,,Rome\n3,,7,Paris\n";
+    let file_path = create_test_csv("all_null_column", csv_content);
+
+    let sql = format!(
+        "SELECT COUNT(note), SUM(note), AVG(note), MIN(note), MAX(note), COUNT(*), MIN(score), COUNT(score) FROM '{}'",
+        file_path.display()
+    );
+    let result = execute_aggregate_query(&sql);
+    assert_eq!(
+        result.selected_rows_as_values(),
+        vec![vec![
+            Value::Integer(0),
+            Value::Null,
+            Value::Null,
+            Value::Null,
+            Value::Null,
+            Value::Integer(3),
+            Value::Integer(5),
+            Value::Integer(2),
+        ]]
+    );
+
+    // the same per group
+    let sql = format!(
+        "SELECT city, COUNT(note), SUM(note), MAX(note) FROM '{}' GROUP BY city",
+        file_path.display()
+    );
+    let rows = execute_grouped_query(&sql);
+    let group = |city: &str| {
+        vec![
+            Value::Varchar(city.to_string()),
+            Value::Integer(0),
+            Value::Null,
+            Value::Null,
+        ]
+    };
+    assert_eq!(rows, vec![group("Paris"), group("Rome")]);
+
+    cleanup_test_csv(&file_path);
+}
@@ -1,6 +1,8 @@
-use celect::binder::{BoundAggregateExpression, BoundExpression, Column, ColumnType, Schema};
+use celect::binder::{
+    BinderErrorKind, BoundAggregateExpression, BoundExpression, Column, ColumnType, Schema,
+};
 use celect::parser::{Expression, LiteralValue, SelectColumn};
-use celect::{Binder, Parser};
+use celect::{Binder, CelectError, HeaderMode, Parser, validate_query};
 
 #[cfg(test)]
 mod tests {
@@ -70,6 +72,51 @@ mod tests {
         assert!(error.message.contains("File not found"));
     }
 
+    #[test]
+    fn test_error_kinds_for_file_problems() {
+        let binder = Binder::new();
+        let missing = |sql: &str| binder.bind(Parser::new().parse(sql).unwrap()).unwrap_err();
+        assert_eq!(
+            missing("SELECT * FROM 'nonexistent.csv'").kind,
+            BinderErrorKind::FileNotFound
+        );
+        assert_eq!(
+            missing("SELECT * FROM 'nonexistent_*.csv'").kind,
+            BinderErrorKind::FileNotFound
+        );
+
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let empty = TestFileGuard::new(format!("empty_kind_{}", counter));
+        fs::write(empty.path(), "").unwrap();
+        let path = binder.resolve_file_name(empty.path()).unwrap();
+        let error = binder.read_csv_headers(&path).unwrap_err();
+        assert_eq!(error.kind, BinderErrorKind::EmptyFile);
+        assert_eq!(error.message, "CSV file is empty");
+
+        let test_file = setup_test_file();
+        let sql = format!("SELECT salary FROM '{}'", test_file.path());
+        assert_eq!(missing(&sql).kind, BinderErrorKind::Query);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_error_kind_for_unreadable_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let test_file = setup_test_file();
+        fs::set_permissions(test_file.path(), fs::Permissions::from_mode(0o000)).unwrap();
+        if fs::read(test_file.path()).is_ok() {
+            // running as root: permissions don't stop the read
+            return;
+        }
+
+        let binder = Binder::new();
+        let path = binder.resolve_file_name(test_file.path()).unwrap();
+        let error = binder.read_csv_headers(&path).unwrap_err();
+        assert_eq!(error.kind, BinderErrorKind::PermissionDenied);
+        assert!(error.message.starts_with("Failed to read file"));
+    }
+
     #[test]
     fn test_resolve_relative_path() {
         let test_file = setup_test_file();
@@ -113,7 +160,7 @@ mod tests {
         let query = parse_result.unwrap();
 
         // resolve file name from AST
-        let resolve_result = binder.resolve_file_name(&query.from.file);
+        let resolve_result = binder.resolve_file_name(&query.from.files[0]);
         assert!(
             resolve_result.is_ok(),
             "Resolve should succeed, got: {:?}",
@@ -124,6 +171,72 @@ mod tests {
         // file will be automatically cleaned up when test_file goes out of scope
     }
 
+    #[test]
+    fn test_double_quoted_path_with_space() {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let test_file = TestFileGuard::new(format!("test data {}.csv", counter));
+        fs::write(test_file.path(), "id,name\n1,Alice\n").unwrap();
+
+        let sql = format!("SELECT name FROM \"{}\"", test_file.path());
+        let query = Parser::new().parse(&sql).unwrap();
+        assert_eq!(query.from.files, vec![test_file.path().to_string()]);
+
+        let bound = Binder::new().bind(query).unwrap();
+        assert!(bound.file_path.is_absolute());
+        assert!(bound.file_path.ends_with(test_file.path()));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_windows_absolute_path() {
+        let test_file = setup_test_file();
+        let absolute = std::env::current_dir().unwrap().join(test_file.path());
+        let absolute = absolute.to_str().unwrap();
+        // a drive letter and backslashes, e.g. C:\Users\me\testdata_3
+        assert!(absolute.contains(":\\"));
+
+        let query = Parser::new()
+            .parse(&format!("SELECT * FROM '{}'", absolute))
+            .unwrap();
+        assert_eq!(query.from.files, vec![absolute.to_string()]);
+
+        let binder = Binder::new();
+        let path = binder.resolve_file_name(absolute).unwrap();
+        assert_eq!(path, Path::new(absolute));
+        assert!(binder.bind(query).is_ok());
+    }
+
+    #[test]
+    fn test_describe_mixed_types() {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let test_file = TestFileGuard::new(format!("describe_{}.csv", counter));
+        fs::write(
+            test_file.path(),
+            "id,name,price,active,note\n1,Pen,2.5,true,\n2,Ink,3,false,\n",
+        )
+        .unwrap();
+
+        let schema = Binder::new().describe(test_file.path()).unwrap();
+        let described: Vec<(&str, ColumnType)> = schema
+            .columns
+            .iter()
+            .map(|col| (col.name.as_str(), col.type_.clone()))
+            .collect();
+        assert_eq!(
+            described,
+            vec![
+                ("id", ColumnType::Integer),
+                ("name", ColumnType::Varchar),
+                ("price", ColumnType::Float),
+                ("active", ColumnType::Boolean),
+                ("note", ColumnType::Null),
+            ]
+        );
+
+        let error = Binder::new().describe("nonexistent.csv").unwrap_err();
+        assert_eq!(error.kind, BinderErrorKind::FileNotFound);
+    }
+
     #[test]
     fn test_validate() {
         let test_file = setup_test_file();
@@ -208,6 +321,62 @@ mod tests {
         fs::remove_file(&test_file).unwrap();
     }
 
+    #[test]
+    fn test_infer_types_integer_overflow_is_float() {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let test_file = format!("overflow_test_{}", counter);
+        fs::write(&test_file, "id,big\n1,5\n2,99999999999999999999\n3,-7\n").unwrap();
+
+        let binder = Binder::new();
+        let file_path = binder.resolve_file_name(&test_file).unwrap();
+
+        let mut schema = binder.read_csv_headers(&file_path).unwrap();
+        binder
+            .infer_column_types(&file_path, &mut schema, true)
+            .unwrap();
+
+        assert_eq!(schema.columns[0].type_, ColumnType::Integer);
+        assert_eq!(schema.columns[1].type_, ColumnType::Float);
+
+        fs::remove_file(&test_file).unwrap();
+    }
+
+    #[test]
+    fn test_infer_types_sample_size() {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let test_file = format!("sample_size_test_{}", counter);
+        // the only Float is on row 25, past the default 20-row sample
+        let mut contents = String::from("score\n");
+        for i in 1..=30 {
+            if i == 25 {
+                contents.push_str("2.5\n");
+            } else {
+                contents.push_str(&format!("{}\n", i));
+            }
+        }
+        fs::write(&test_file, contents).unwrap();
+
+        let infer = |binder: Binder| {
+            let file_path = binder.resolve_file_name(&test_file).unwrap();
+            let mut schema = binder.read_csv_headers(&file_path).unwrap();
+            binder
+                .infer_column_types(&file_path, &mut schema, true)
+                .unwrap();
+            schema.columns[0].type_.clone()
+        };
+
+        assert_eq!(infer(Binder::new()), ColumnType::Integer);
+        assert_eq!(
+            infer(Binder::new().with_sample_size(24)),
+            ColumnType::Integer
+        );
+        assert_eq!(infer(Binder::new().with_sample_size(25)), ColumnType::Float);
+        // 0 samples every row
+        assert_eq!(infer(Binder::new().with_sample_size(0)), ColumnType::Float);
+
+        fs::remove_file(&test_file).unwrap();
+    }
+
     #[test]
     fn test_infer_types_boolean() {
         let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
@@ -406,8 +575,8 @@ mod tests {
         };
 
         let select_columns = vec![
-            SelectColumn::Column("id".to_string()),
-            SelectColumn::Column("name".to_string()),
+            SelectColumn::Expression(Expression::Column("id".to_string()), None),
+            SelectColumn::Expression(Expression::Column("name".to_string()), None),
         ];
         let result = binder.validate_select_columns(&select_columns, &schema);
 
@@ -437,7 +606,10 @@ mod tests {
             ],
         };
 
-        let select_columns = vec![SelectColumn::Column("nonexistent".to_string())];
+        let select_columns = vec![SelectColumn::Expression(
+            Expression::Column("nonexistent".to_string()),
+            None,
+        )];
         let result = binder.validate_select_columns(&select_columns, &schema);
 
         assert!(result.is_err());
@@ -465,7 +637,10 @@ mod tests {
         };
 
         // select *, name - should expand * to all columns, then add name again
-        let select_columns = vec![SelectColumn::All, SelectColumn::Column("name".to_string())];
+        let select_columns = vec![
+            SelectColumn::All,
+            SelectColumn::Expression(Expression::Column("name".to_string()), None),
+        ];
         let result = binder.validate_select_columns(&select_columns, &schema);
 
         assert!(result.is_ok());
@@ -583,6 +758,38 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validate_where_boolean_equality_only() {
+        let binder = Binder::new();
+        let schema = Schema {
+            columns: vec![Column {
+                name: "active".to_string(),
+                type_: ColumnType::Boolean,
+                index: 0,
+            }],
+        };
+        let active = || Box::new(Expression::Column("active".to_string()));
+        let literal = |value| Box::new(Expression::Literal(LiteralValue::Boolean(value)));
+
+        // active = true and active != false - should work
+        let expr = Expression::Equal(active(), literal(true));
+        assert!(binder.validate_where_expression(&expr, &schema).is_ok());
+        let expr = Expression::NotEqual(active(), literal(false));
+        assert!(binder.validate_where_expression(&expr, &schema).is_ok());
+
+        // active > false - booleans have no order
+        let expr = Expression::GreaterThan(active(), literal(false));
+        let error = binder
+            .validate_where_expression(&expr, &schema)
+            .unwrap_err();
+        assert_eq!(
+            error.message,
+            "Cannot compare Boolean values with > - only = and != are supported"
+        );
+        let expr = Expression::LessThanOrEqual(literal(true), active());
+        assert!(binder.validate_where_expression(&expr, &schema).is_err());
+    }
+
     #[test]
     fn test_bind_expression_column_ref() {
         let binder = Binder::new();
@@ -889,8 +1096,10 @@ mod tests {
         assert_eq!(bound.aggregates.len(), 1);
 
         match &bound.aggregates[0] {
-            BoundAggregateExpression::Count { column } => {
-                assert_eq!(column.name, "name");
+            BoundAggregateExpression::Count {
+                expression: BoundExpression::ColumnRef { name, .. },
+            } => {
+                assert_eq!(name, "name");
             }
             _ => panic!("Expected Count"),
         }
@@ -997,4 +1206,620 @@ mod tests {
         assert_eq!(bound.aggregates.len(), 1);
         assert_eq!(bound.limit, Some(1));
     }
+
+    #[test]
+    fn test_infer_types_boolean_header_hint() {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let test_file = format!("bool_hint_test_{}", counter);
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "note,is_admin\ntrue,false\nfalse,true\n").unwrap();
+
+        // without the heuristic, any true/false column is Boolean
+        let binder = Binder::new();
+        let file_path = binder.resolve_file_name(&test_file).unwrap();
+        let mut schema = binder.read_csv_headers(&file_path).unwrap();
+        binder
+            .infer_column_types(&file_path, &mut schema, true)
+            .unwrap();
+        assert_eq!(schema.columns[0].type_, ColumnType::Boolean);
+        assert_eq!(schema.columns[1].type_, ColumnType::Boolean);
+
+        // with the heuristic, only hinted headers are Boolean
+        let binder = Binder::new().with_boolean_header_hint(true);
+        let mut schema = binder.read_csv_headers(&file_path).unwrap();
+        binder
+            .infer_column_types(&file_path, &mut schema, true)
+            .unwrap();
+        assert_eq!(schema.columns[0].type_, ColumnType::Varchar);
+        assert_eq!(schema.columns[1].type_, ColumnType::Boolean);
+    }
+
+    #[test]
+    fn test_bom_header_and_case_insensitive_columns() {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let test_file = format!("bom_header_test_{}", counter);
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "\u{feff}id, Name \n1,Alice\n2,Bob\n").unwrap();
+
+        // the BOM and surrounding spaces are stripped from header names
+        let binder = Binder::new();
+        let file_path = binder.resolve_file_name(&test_file).unwrap();
+        let schema = binder.read_csv_headers(&file_path).unwrap();
+        assert_eq!(schema.columns[0].name, "id");
+        assert_eq!(schema.columns[1].name, "Name");
+
+        let sql = format!("SELECT id, name FROM '{}' WHERE ID > 1", test_file);
+        let mut parser = Parser::new();
+
+        // exact matching when case-insensitive resolution is turned off
+        let query = parser.parse(&sql).unwrap();
+        let err = Binder::new()
+            .with_case_insensitive_columns(false)
+            .bind(query)
+            .unwrap_err();
+        assert!(err.message.contains("not found"), "{}", err.message);
+
+        // mixed-case references resolve by default
+        let query = parser.parse(&sql).unwrap();
+        let bound = Binder::new().bind(query).unwrap();
+        assert_eq!(bound.select_columns[0].name, "id");
+        assert_eq!(bound.select_columns[1].name, "Name");
+        assert_eq!(bound.select_columns[1].index, 1);
+        assert!(matches!(
+            bound.where_clause,
+            Some(BoundExpression::GreaterThan(ref left, _))
+                if matches!(**left, BoundExpression::ColumnRef { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_case_insensitive_column_resolution() {
+        let test_file = setup_test_file();
+        let mut parser = Parser::new();
+
+        let sql = format!("SELECT NAME FROM '{}' WHERE Age > 1", test_file.path());
+        let query = parser.parse(&sql).unwrap();
+        let bound = Binder::new().bind(query).unwrap();
+
+        // the output keeps the header's spelling
+        assert_eq!(bound.select_columns[0].name, "name");
+        assert_eq!(bound.select_columns[0].index, 1);
+        match bound.where_clause {
+            Some(BoundExpression::GreaterThan(left, _)) => match *left {
+                BoundExpression::ColumnRef { name, index, .. } => {
+                    assert_eq!(name, "age");
+                    assert_eq!(index, 2);
+                }
+                other => panic!("Expected ColumnRef, got {:?}", other),
+            },
+            other => panic!("Expected GreaterThan, got {:?}", other),
+        }
+
+        // opting out makes the lookup case-sensitive again
+        let query = parser.parse(&sql).unwrap();
+        let result = Binder::new()
+            .with_case_insensitive_columns(false)
+            .bind(query);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_case_insensitive_ambiguous_column() {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let test_file = format!("ambiguous_test_{}", counter);
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "name,NAME\nAlice,ALICE\n").unwrap();
+        let mut parser = Parser::new();
+
+        // exact spellings still resolve
+        let sql = format!("SELECT NAME, name FROM '{}'", test_file);
+        let bound = Binder::new().bind(parser.parse(&sql).unwrap()).unwrap();
+        assert_eq!(bound.select_columns[0].index, 1);
+        assert_eq!(bound.select_columns[1].index, 0);
+
+        // a third spelling matches both columns
+        let sql = format!("SELECT Name FROM '{}'", test_file);
+        let err = Binder::new().bind(parser.parse(&sql).unwrap()).unwrap_err();
+        assert!(err.message.contains("ambiguous"), "{}", err.message);
+    }
+
+    #[test]
+    fn test_qualified_column_names() {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let test_file = format!("qualified_test_{}.csv", counter);
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "name,age\nAlice,30\n").unwrap();
+        let mut parser = Parser::new();
+        let stem = test_file.trim_end_matches(".csv");
+
+        // the qualifier matches the file stem
+        let sql = format!(
+            "SELECT {}.name FROM '{}' WHERE {}.age > 25",
+            stem, test_file, stem
+        );
+        let bound = Binder::new().bind(parser.parse(&sql).unwrap()).unwrap();
+        assert_eq!(bound.select_columns[0].name, "name");
+        assert!(bound.where_clause.is_some());
+
+        // any other qualifier is rejected
+        let sql = format!("SELECT other.name FROM '{}'", test_file);
+        let err = Binder::new().bind(parser.parse(&sql).unwrap()).unwrap_err();
+        assert_eq!(
+            err.message,
+            format!(
+                "Table qualifier 'other' does not match the source '{}'",
+                stem
+            )
+        );
+    }
+
+    #[test]
+    fn test_auto_header_detection() {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let headered = format!("auto_header_test_{}", counter);
+        let headerless = format!("auto_no_header_test_{}", counter);
+        let _guard = TestFileGuard::new(headered.clone());
+        let _guard2 = TestFileGuard::new(headerless.clone());
+        fs::write(&headered, "id,score\n1,2.5\n2,3.0\n").unwrap();
+        fs::write(&headerless, "1,2.5\n2,3.0\n3,4.5\n").unwrap();
+
+        let binder = Binder::new().with_header(HeaderMode::Auto);
+        let mut parser = Parser::new();
+
+        // 'id' doesn't fit the Integer column below it, so it's a header
+        let sql = format!("SELECT * FROM '{}'", headered);
+        let bound = binder.bind(parser.parse(&sql).unwrap()).unwrap();
+        assert!(bound.has_header);
+        assert_eq!(bound.schema.columns[0].name, "id");
+        assert_eq!(bound.schema.columns[0].type_, ColumnType::Integer);
+        assert_eq!(bound.schema.columns[1].type_, ColumnType::Float);
+
+        // the first line fits every column's type, so it's data
+        let sql = format!("SELECT column1, column2 FROM '{}'", headerless);
+        let bound = binder.bind(parser.parse(&sql).unwrap()).unwrap();
+        assert!(!bound.has_header);
+        assert_eq!(bound.schema.columns[0].type_, ColumnType::Integer);
+        assert_eq!(bound.schema.columns[1].type_, ColumnType::Float);
+
+        // explicit modes don't look at the data
+        let sql = format!("SELECT * FROM '{}'", headerless);
+        let bound = Binder::new().bind(parser.parse(&sql).unwrap()).unwrap();
+        assert!(bound.has_header);
+        assert_eq!(bound.schema.columns[0].name, "1");
+        let binder = Binder::new().with_header(HeaderMode::Never);
+        let bound = binder.bind(parser.parse(&sql).unwrap()).unwrap();
+        assert!(!bound.has_header);
+        assert_eq!(bound.schema.columns[1].name, "column2");
+    }
+
+    #[test]
+    fn test_duplicate_header_names() {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let test_file = format!("duplicate_header_test_{}", counter);
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "id,name,id,id_2,id\n1,Ada,2,3,4\n").unwrap();
+
+        let binder = Binder::new();
+        let file_path = binder.resolve_file_name(&test_file).unwrap();
+        let err = binder.read_csv_headers(&file_path).unwrap_err();
+        assert_eq!(err.message, "duplicate column 'id' in CSV header");
+
+        // renamed repeats skip names the header already has
+        let binder = Binder::new().with_rename_duplicate_headers(true);
+        let schema = binder.read_csv_headers(&file_path).unwrap();
+        let names: Vec<&str> = schema.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "name", "id_3", "id_2", "id_4"]);
+
+        let sql = format!("SELECT id_3 FROM '{}'", test_file);
+        let query = Parser::new().parse(&sql).unwrap();
+        let bound = binder.bind(query).unwrap();
+        assert_eq!(bound.select_columns[0].index, 2);
+    }
+
+    #[test]
+    fn test_bind_with_schema() {
+        // no file on disk - bind against a hand-built schema
+        let schema = Schema {
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    type_: ColumnType::Integer,
+                    index: 0,
+                },
+                Column {
+                    name: "name".to_string(),
+                    type_: ColumnType::Varchar,
+                    index: 1,
+                },
+                Column {
+                    name: "score".to_string(),
+                    type_: ColumnType::Float,
+                    index: 2,
+                },
+            ],
+        };
+
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT name FROM 'not_there.csv' WHERE score >= 1.5 AND id = 3")
+            .unwrap();
+
+        let binder = Binder::new();
+        let bound = binder.bind_with_schema(query, schema).unwrap();
+
+        assert_eq!(bound.select_columns.len(), 1);
+        assert_eq!(bound.select_columns[0].name, "name");
+        assert_eq!(bound.select_columns[0].index, 1);
+
+        match bound.where_clause.unwrap() {
+            BoundExpression::And(left, right) => {
+                match *left {
+                    BoundExpression::GreaterThanOrEqual(col, _) => match *col {
+                        BoundExpression::ColumnRef { index, type_, .. } => {
+                            assert_eq!(index, 2);
+                            assert_eq!(type_, ColumnType::Float);
+                        }
+                        other => panic!("expected ColumnRef, got {:?}", other),
+                    },
+                    other => panic!("expected >=, got {:?}", other),
+                }
+                match *right {
+                    BoundExpression::Equal(col, _) => match *col {
+                        BoundExpression::ColumnRef { index, type_, .. } => {
+                            assert_eq!(index, 0);
+                            assert_eq!(type_, ColumnType::Integer);
+                        }
+                        other => panic!("expected ColumnRef, got {:?}", other),
+                    },
+                    other => panic!("expected =, got {:?}", other),
+                }
+            }
+            other => panic!("expected AND, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_bind_with_schema_unknown_column() {
+        let schema = Schema {
+            columns: vec![Column {
+                name: "id".to_string(),
+                type_: ColumnType::Integer,
+                index: 0,
+            }],
+        };
+
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT id FROM 'not_there.csv' WHERE age > 1")
+            .unwrap();
+
+        let result = Binder::new().bind_with_schema(query, schema);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bind_in_list_type_mismatch() {
+        let test_file = format!(
+            "test_bind_in_mismatch_{}.csv",
+            TEST_COUNTER.fetch_add(1, Ordering::SeqCst)
+        );
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "id,name\n1,Alice\n2,Bob").unwrap();
+
+        let mut parser = Parser::new();
+        let query = parser
+            .parse(&format!(
+                "SELECT name FROM '{}' WHERE id IN (1, 'two')",
+                test_file
+            ))
+            .unwrap();
+
+        let result = Binder::new().bind(query);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().message.contains("IN list"));
+    }
+
+    #[test]
+    fn test_like_and_in_validate_their_operands() {
+        let test_file = format!(
+            "test_bind_like_in_operands_{}.csv",
+            TEST_COUNTER.fetch_add(1, Ordering::SeqCst)
+        );
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "id,name\n1,Alice\n2,Bob").unwrap();
+
+        let bind = |condition: &str| {
+            let sql = format!("SELECT name FROM '{}' WHERE {}", test_file, condition);
+            Binder::new().bind(Parser::new().parse(&sql).unwrap())
+        };
+
+        // the operands are checked like they are under =, not just their result type
+        let err = bind("UPPER(id) LIKE 'A%'").unwrap_err();
+        assert!(
+            err.message
+                .contains("UPPER requires a Varchar argument, got Integer"),
+            "{}",
+            err.message
+        );
+        let err = bind("(id + name) IN (1)").unwrap_err();
+        assert!(
+            err.message
+                .contains("Arithmetic requires numeric operands, got Varchar"),
+            "{}",
+            err.message
+        );
+        assert!(bind("UPPER(name) LIKE 'A%' AND (id + 1) IN (2, 3)").is_ok());
+    }
+
+    #[test]
+    fn test_bare_column_predicate() {
+        let test_file = format!(
+            "test_bind_bare_predicate_{}.csv",
+            TEST_COUNTER.fetch_add(1, Ordering::SeqCst)
+        );
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "name,age,active\nAlice,30,true\nBob,25,false").unwrap();
+
+        let mut parser = Parser::new();
+        let query = parser
+            .parse(&format!(
+                "SELECT name FROM '{}' WHERE NOT active",
+                test_file
+            ))
+            .unwrap();
+        let bound = Binder::new().bind(query).unwrap();
+        assert_eq!(
+            bound.where_clause.unwrap().to_string(),
+            "NOT (active = true)"
+        );
+
+        // only Boolean columns can stand alone as a predicate
+        for sql in [
+            format!("SELECT name FROM '{}' WHERE age", test_file),
+            format!("SELECT name FROM '{}' WHERE active AND name", test_file),
+        ] {
+            let query = parser.parse(&sql).unwrap();
+            let err = Binder::new().bind(query).unwrap_err();
+            assert!(err.message.contains("as a predicate"), "{}", err.message);
+        }
+    }
+
+    #[test]
+    fn test_strict_type_comparisons() {
+        let test_file = format!(
+            "test_bind_strict_types_{}.csv",
+            TEST_COUNTER.fetch_add(1, Ordering::SeqCst)
+        );
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "name,age,score\nAlice,30,1.5\nBob,25,2.5").unwrap();
+
+        let bind = |binder: Binder, condition: &str| {
+            let sql = format!("SELECT name FROM '{}' WHERE {}", test_file, condition);
+            binder.bind(Parser::new().parse(&sql).unwrap())
+        };
+
+        // lenient (default): Integer and Float compare numerically
+        assert!(bind(Binder::new(), "age > 18.5").is_ok());
+        assert!(bind(Binder::new(), "age < score").is_ok());
+
+        // strict: only identical types compare
+        let strict = || Binder::new().with_strict_types(true);
+        let err = bind(strict(), "age > 18.5").unwrap_err();
+        assert!(err.message.contains("Cannot compare Integer and Float"));
+        assert!(bind(strict(), "age < score").is_err());
+        assert!(bind(strict(), "age IN (1, 2.0)").is_err());
+        assert!(bind(strict(), "age > 18").is_ok());
+        assert!(bind(strict(), "score > 1.0").is_ok());
+    }
+
+    #[test]
+    fn test_modulo_requires_integers() {
+        let test_file = format!(
+            "test_bind_modulo_{}.csv",
+            TEST_COUNTER.fetch_add(1, Ordering::SeqCst)
+        );
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "name,age,score\nAlice,30,1.5\nBob,25,2.5").unwrap();
+
+        let bind = |condition: &str| {
+            let sql = format!("SELECT name FROM '{}' WHERE {}", test_file, condition);
+            Binder::new().bind(Parser::new().parse(&sql).unwrap())
+        };
+
+        assert!(bind("age % 2 = 0").is_ok());
+        let err = bind("score % 2 = 0").unwrap_err();
+        assert!(
+            err.message
+                .contains("Modulo requires Integer operands, got Float")
+        );
+        assert!(bind("age % 1.5 = 0").is_err());
+
+        // negation keeps the operand's type and rejects non-numbers
+        assert!(bind("-score < 0").is_ok());
+        assert!(bind("-age < 0").is_ok());
+        assert!(bind("-name = 'x'").is_err());
+    }
+
+    #[test]
+    fn test_scalar_function_binding() {
+        let test_file = format!(
+            "test_bind_functions_{}.csv",
+            TEST_COUNTER.fetch_add(1, Ordering::SeqCst)
+        );
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "name,age\nAlice,30\nBob,25").unwrap();
+
+        let bind = |select: &str| {
+            let sql = format!("SELECT {} FROM '{}'", select, test_file);
+            Binder::new().bind(Parser::new().parse(&sql).unwrap())
+        };
+
+        let bound = bind("UPPER(name), LENGTH(name), name || age").unwrap();
+        let types: Vec<ColumnType> = bound.output.iter().map(|e| e.result_type()).collect();
+        assert_eq!(
+            types,
+            vec![
+                ColumnType::Varchar,
+                ColumnType::Integer,
+                ColumnType::Varchar
+            ]
+        );
+
+        let err = bind("REVERSE(name)").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Unknown function 'REVERSE' - supported functions are UPPER, LOWER, LENGTH, SUBSTR, TRIM, COALESCE"
+        );
+        let err = bind("UPPER(age)").unwrap_err();
+        assert!(
+            err.message
+                .contains("UPPER requires a Varchar argument, got Integer")
+        );
+        assert!(bind("LOWER(name, name)").is_err());
+        assert!(bind("SUBSTR(name, 1)").is_ok());
+        assert!(bind("SUBSTR(name)").is_err());
+        assert!(bind("SUBSTR(name, '1')").is_err());
+
+        let bound = bind("COALESCE(NULL, age, 1.5)").unwrap();
+        assert_eq!(bound.output[0].result_type(), ColumnType::Float);
+        let err = bind("COALESCE(name, age)").unwrap_err();
+        assert_eq!(
+            err.message,
+            "COALESCE arguments must have compatible types, got Varchar and Integer"
+        );
+
+        let bound = bind("CASE WHEN age > 1 THEN age ELSE NULL END").unwrap();
+        assert_eq!(bound.output[0].result_type(), ColumnType::Integer);
+        let err = bind("CASE WHEN age > 1 THEN name ELSE age END").unwrap_err();
+        assert_eq!(
+            err.message,
+            "CASE results must have compatible types, got Varchar and Integer"
+        );
+        assert!(bind("CASE WHEN age THEN 1 END").is_err());
+        assert!(bind("UPPER(missing)").is_err());
+    }
+
+    #[test]
+    fn test_validate_query() {
+        let test_file = format!(
+            "test_validate_query_{}.csv",
+            TEST_COUNTER.fetch_add(1, Ordering::SeqCst)
+        );
+        let _guard = TestFileGuard::new(test_file.clone());
+        fs::write(&test_file, "id,name,age\n1,Alice,30\n2,Bob,25").unwrap();
+
+        let bound = validate_query(&format!(
+            "SELECT name, age FROM '{}' WHERE id > 1",
+            test_file
+        ))
+        .unwrap();
+        assert_eq!(
+            bound.select_columns,
+            vec![
+                Column {
+                    name: "name".to_string(),
+                    type_: ColumnType::Varchar,
+                    index: 1,
+                },
+                Column {
+                    name: "age".to_string(),
+                    type_: ColumnType::Integer,
+                    index: 2,
+                },
+            ]
+        );
+        assert_eq!(bound.schema.names(), vec!["id", "name", "age"]);
+
+        let err = validate_query(&format!("SELECT salary FROM '{}'", test_file)).unwrap_err();
+        assert!(matches!(err, CelectError::Bind(_)));
+        assert!(err.message().contains("salary"));
+
+        let err = validate_query("SELEC name FROM people").unwrap_err();
+        assert!(matches!(err, CelectError::Parse(_)));
+    }
+
+    #[test]
+    fn test_validate_query_reads_only_the_type_sample() {
+        let test_file = format!(
+            "test_validate_sample_{}.csv",
+            TEST_COUNTER.fetch_add(1, Ordering::SeqCst)
+        );
+        let _guard = TestFileGuard::new(test_file.clone());
+        // bytes that aren't UTF-8 after the sampled rows: reading them fails
+        let mut contents = String::from("id,name\n");
+        for i in 0..1000 {
+            contents.push_str(&format!("{},row{}\n", i, i));
+        }
+        let mut bytes = contents.into_bytes();
+        bytes.extend_from_slice(b"1000,\xff\xfe\n");
+        fs::write(&test_file, bytes).unwrap();
+
+        let bound =
+            validate_query(&format!("SELECT name FROM '{}' WHERE id > 5", test_file)).unwrap();
+        assert_eq!(bound.schema.names(), vec!["id", "name"]);
+
+        // sampling every row reaches them
+        let query = Parser::new()
+            .parse(&format!("SELECT name FROM '{}'", test_file))
+            .unwrap();
+        let err = Binder::new().with_sample_size(0).bind(query).unwrap_err();
+        assert!(
+            err.message.contains("Failed to read file"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn test_bind_union() {
+        let test_file = setup_test_file();
+        let binder = Binder::new();
+        let bind = |sql: String| binder.bind(Parser::new().parse(&sql).unwrap());
+        let path = test_file.path();
+
+        let bound = bind(format!(
+            "SELECT name, age FROM '{0}' UNION SELECT name, id FROM '{0}' ORDER BY age DESC, 1",
+            path
+        ))
+        .unwrap();
+        assert_eq!(bound.unions.len(), 1);
+        assert!(!bound.unions[0].all);
+        // the sort keys index the combined columns, named like the first SELECT's
+        let keys: Vec<String> = bound.order_by.iter().map(|key| key.to_string()).collect();
+        assert_eq!(keys, vec!["age DESC", "name"]);
+        assert!(matches!(
+            bound.order_by[0].expression,
+            BoundExpression::ColumnRef { index: 1, .. }
+        ));
+
+        let err = bind(format!(
+            "SELECT name FROM '{0}' UNION ALL SELECT name, age FROM '{0}'",
+            path
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err.message,
+            "SELECT 2 of the UNION has 2 columns, expected 1"
+        );
+
+        let err = bind(format!(
+            "SELECT id FROM '{0}' UNION SELECT id FROM '{0}' UNION SELECT name FROM '{0}'",
+            path
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err.message,
+            "Column 1 of the UNION is Varchar in SELECT 3 but Integer before it"
+        );
+
+        let err = bind(format!(
+            "SELECT id FROM '{0}' UNION SELECT id FROM '{0}' ORDER BY age",
+            path
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err.message,
+            "ORDER BY after a UNION must name or number an output column, found age"
+        );
+    }
 }
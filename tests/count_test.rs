@@ -223,3 +223,28 @@ fn test_count_column_with_where_clause() {
 
     cleanup_test_csv(&file_path);
 }
+
+#[test]
+fn test_count_star_and_count_column_in_one_query() {
+    let csv_content = "id,name,age\n1,Alice,30\n2,,\n3,Charlie,35\n4,,\n5,Eve,\n";
+    let file_path = create_test_csv("count_star_and_col", csv_content);
+
+    // both kinds share one scan; only COUNT(column) skips the empty (NULL) fields
+    let sql = format!(
+        "SELECT COUNT(*), COUNT(name), COUNT(age) FROM '{}' WHERE id > 1",
+        file_path.display()
+    );
+    let result = execute_count_query(&sql);
+
+    assert_eq!(result.selected_count(), 1);
+    let counts: Vec<i64> = (0..3)
+        .map(|col| match result.get_value(col, 0) {
+            Some(celect::Value::Integer(count)) => count,
+            other => panic!("Expected integer count, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(counts, vec![4, 2, 1]);
+    assert!(counts[0] > counts[1] && counts[1] > counts[2]);
+
+    cleanup_test_csv(&file_path);
+}
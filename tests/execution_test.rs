@@ -1,7 +1,16 @@
-use celect::Value;
-use celect::{Binder, Optimizer, Parser, PhysicalPlanner, PipelineExecutor, Planner};
+use celect::PhysicalOperator;
+use celect::execution::{PhysicalFilter, PhysicalProjection, PhysicalScan, evaluate_predicate};
+use celect::planner::LogicalOperator;
+use celect::{Binder, Optimizer, Parser, PhysicalPlanner, PipelineExecutor, Planner, QueryEngine};
+use celect::{Collation, ColumnType, HeaderMode, SampleMethod, SourceFormat, Value};
+use celect::{CsvFileSource, DataChunk, DataSource, ExecuteResult, StringSource, Vector};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
 use std::fs;
 use std::io::Write;
+use std::sync::Arc;
 
 struct TestFile {
     path: String,
@@ -14,6 +23,17 @@ impl TestFile {
         file.write_all(contents.as_bytes()).unwrap();
         TestFile { path }
     }
+
+    /// gzip-compressed file at `path`
+    fn gzipped(path: &str, contents: &str) -> Self {
+        let file = fs::File::create(path).unwrap();
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        encoder.write_all(contents.as_bytes()).unwrap();
+        encoder.finish().unwrap();
+        TestFile {
+            path: path.to_string(),
+        }
+    }
 }
 
 impl Drop for TestFile {
@@ -296,6 +316,43 @@ fn test_filter_with_not() {
     );
 }
 
+#[test]
+fn test_filter_counts_rejected_rows() {
+    let mut contents = String::from("id,value\n");
+    for i in 0..20_000 {
+        contents.push_str(&format!("{},{}\n", i, i % 7));
+    }
+    let test_file = TestFile::new("filter_rejected", &contents);
+
+    let sql = format!("SELECT id FROM '{}' WHERE value = 3", test_file.path);
+    let query = Parser::new().parse(&sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let mut scan = PhysicalScan::new(bound_query.file_path, bound_query.schema, None, None);
+    let mut filter = PhysicalFilter::new(bound_query.where_clause.unwrap());
+
+    let mut scanned = DataChunk::empty();
+    let mut filtered = DataChunk::empty();
+    let mut selected = 0;
+    loop {
+        let result = scan.execute(&DataChunk::empty(), &mut scanned);
+        if scanned.count > 0 {
+            filter.execute(&scanned, &mut filtered);
+            selected += filtered.selected_count();
+        }
+        if result == ExecuteResult::Finished {
+            break;
+        }
+    }
+
+    // every scanned row was either kept or rejected
+    assert_eq!(selected, 2857);
+    assert_eq!(filter.rows_filtered() + selected, scan.rows_read());
+    assert_eq!(scan.rows_read(), 20_000);
+
+    filter.reset();
+    assert_eq!(filter.rows_filtered(), 0);
+}
+
 #[test]
 fn test_empty_result() {
     let test_file = TestFile::new("empty", "id,name,age\n1,Alice,30\n2,Bob,25\n");
@@ -322,6 +379,76 @@ fn test_empty_result() {
     assert_eq!(results.len(), 0); // no results
 }
 
+#[test]
+fn test_count_star_with_where_counts_matching_rows() {
+    // several chunks' worth of rows, ids 1..=5000
+    let contents: String = std::iter::once("id,even\n".to_string())
+        .chain((1..=5000).map(|id| format!("{},{}\n", id, id % 2 == 0)))
+        .collect();
+    let test_file = TestFile::new("count_where", &contents);
+
+    let sql = format!("SELECT COUNT(*) FROM '{}' WHERE id > 5", test_file.path);
+    let query = Parser::new().parse(&sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+    // the filter runs below the aggregate, so only matching rows are counted
+    match &plan {
+        LogicalOperator::Aggregate(aggregate) => {
+            assert!(matches!(
+                aggregate.child.as_ref(),
+                LogicalOperator::Filter(filter) if matches!(filter.child.as_ref(), LogicalOperator::Get(_))
+            ));
+        }
+        other => panic!("Expected Aggregate as root, got {:?}", other),
+    }
+    assert_eq!(query_first_column(&sql), vec![Value::Integer(4995)]);
+
+    for (filter, expected) in [
+        ("id <= 5", 5),
+        ("even = true AND id > 4000", 500),
+        ("id > 5000", 0),
+    ] {
+        let sql = format!("SELECT COUNT(*) FROM '{}' WHERE {}", test_file.path, filter);
+        assert_eq!(
+            query_first_column(&sql),
+            vec![Value::Integer(expected)],
+            "{}",
+            filter
+        );
+    }
+}
+
+#[test]
+fn test_header_only_file() {
+    // with and without a newline after the header
+    for contents in ["id,name,age\n", "id,name,age"] {
+        let test_file = TestFile::new("header_only", contents);
+        let mut engine = QueryEngine::new();
+
+        let sql = format!("SELECT * FROM '{}'", test_file.path);
+        let (results, schema) = engine.execute(&sql).unwrap();
+        assert!(results.iter().all(|chunk| chunk.selected_count() == 0));
+        assert_eq!(schema.columns.len(), 3);
+        // no data to infer from: every column stays Varchar
+        assert!(
+            schema
+                .columns
+                .iter()
+                .all(|column| column.type_ == ColumnType::Varchar)
+        );
+
+        let sql = format!(
+            "SELECT name FROM '{}' WHERE age = '30' ORDER BY id LIMIT 5",
+            test_file.path
+        );
+        let (results, _) = engine.execute(&sql).unwrap();
+        assert!(results.iter().all(|chunk| chunk.selected_count() == 0));
+
+        let sql = format!("SELECT COUNT(*) FROM '{}'", test_file.path);
+        assert_eq!(query_first_column(&sql), vec![Value::Integer(0)]);
+    }
+}
+
 #[test]
 fn test_multiple_types() {
     let test_file = TestFile::new(
@@ -780,3 +907,2196 @@ fn test_numeric_coercion_complex_expression() {
         Some(Value::Varchar("Bob".to_string()))
     );
 }
+
+#[test]
+fn test_prefix_like_matches_unoptimized_like() {
+    let test_file = TestFile::new(
+        "prefix_like",
+        "id,name\n1,abc\n2,abcdef\n3,abd\n4,ab\n5,xabc\n6,ABC\n7,abc_z\n8,\n",
+    );
+
+    let sql = format!("SELECT id FROM '{}' WHERE name LIKE 'abc%'", test_file.path);
+
+    let run = |optimize: bool| -> Vec<Value> {
+        let mut parser = Parser::new();
+        let query = parser.parse(&sql).unwrap();
+
+        let binder = Binder::new();
+        let bound_query = binder.bind(query).unwrap();
+
+        let planner = Planner::new();
+        let mut plan = planner.plan(bound_query);
+        if optimize {
+            plan = Optimizer::new().optimize(plan);
+        }
+
+        let physical_planner = PhysicalPlanner::new();
+        let (operators, schemas) = physical_planner.plan(plan);
+
+        let mut executor = PipelineExecutor::new(operators, schemas);
+        executor
+            .execute()
+            .iter()
+            .flat_map(|c| c.iter_column(0))
+            .collect()
+    };
+
+    let expected = vec![Value::Integer(1), Value::Integer(2), Value::Integer(7)];
+    assert_eq!(run(false), expected, "LIKE should match the prefix");
+    assert_eq!(run(true), expected, "range rewrite should match the LIKE");
+}
+
+#[test]
+fn test_like_escape_matches_literal_wildcards() {
+    let test_file = TestFile::new(
+        "like_escape",
+        "id,label\n1,50% off\n2,500 off\n3,50_50\n4,50-50\n5,a!b\n6,50%\n",
+    );
+
+    let run = |filter: &str, optimize: bool| -> Vec<Value> {
+        let sql = format!("SELECT id FROM '{}' WHERE {}", test_file.path, filter);
+        let query = Parser::new().parse(&sql).unwrap();
+        let mut plan = Planner::new().plan(Binder::new().bind(query).unwrap());
+        if optimize {
+            plan = Optimizer::new().optimize(plan);
+        }
+        let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+        PipelineExecutor::new(operators, schemas)
+            .execute()
+            .iter()
+            .flat_map(|c| c.iter_column(0))
+            .collect()
+    };
+
+    for (filter, expected) in [
+        // without ESCAPE, % and _ are wildcards
+        ("label LIKE '50%'", vec![1, 2, 3, 4, 6]),
+        ("label LIKE '50_50'", vec![3, 4]),
+        // the escaped metacharacter is literal (the prefix form is rewritten to a range)
+        ("label LIKE '50!%%' ESCAPE '!'", vec![1, 6]),
+        ("label LIKE '%!%' ESCAPE '!'", vec![6]),
+        ("label LIKE '50\\_50' ESCAPE '\\'", vec![3]),
+        ("label NOT LIKE '50#%%' ESCAPE '#'", vec![2, 3, 4, 5]),
+        // a doubled escape is the escape character itself
+        ("label LIKE 'a!!b' ESCAPE '!'", vec![5]),
+    ] {
+        let expected: Vec<Value> = expected.into_iter().map(Value::Integer).collect();
+        assert_eq!(run(filter, false), expected, "{}", filter);
+        assert_eq!(run(filter, true), expected, "{} (optimized)", filter);
+    }
+}
+
+#[test]
+fn test_ilike_ignores_case() {
+    let test_file = TestFile::new("ilike", "id,name\n1,alice\n2,ALBERT\n3,Bob\n4,\n5,Al_x\n");
+
+    for (filter, expected) in [
+        ("name ILIKE 'ALICE'", vec![1]),
+        ("name LIKE 'ALICE'", vec![]),
+        ("name ILIKE 'al%'", vec![1, 2, 5]),
+        ("name NOT ILIKE 'AL%'", vec![3]),
+        ("name ILIKE 'al!_%' ESCAPE '!'", vec![5]),
+    ] {
+        let sql = format!("SELECT id FROM '{}' WHERE {}", test_file.path, filter);
+        assert_eq!(
+            query_first_column(&sql),
+            expected.into_iter().map(Value::Integer).collect::<Vec<_>>(),
+            "{}",
+            filter
+        );
+    }
+
+    let sql = format!("SELECT id FROM '{}' WHERE id ILIKE '1'", test_file.path);
+    let err = Binder::new()
+        .bind(Parser::new().parse(&sql).unwrap())
+        .unwrap_err();
+    assert_eq!(err.message, "ILIKE requires Varchar operands, got Integer");
+}
+
+#[test]
+fn test_reset_and_replay() {
+    let mut contents = String::from("id,name,age\n");
+    for i in 0..6000 {
+        contents.push_str(&format!("{},user{},{}\n", i, i, i % 90));
+    }
+    let test_file = TestFile::new("reset_replay", &contents);
+
+    let queries = [
+        format!("SELECT id, name FROM '{}' WHERE age > 50", test_file.path),
+        format!("SELECT COUNT(*) FROM '{}' WHERE age > 50", test_file.path),
+        format!(
+            "SELECT id FROM '{}' WHERE age > 50 LIMIT 10 OFFSET 5",
+            test_file.path
+        ),
+        format!("SELECT id FROM '{}' LIMIT 3", test_file.path),
+    ];
+
+    for sql in &queries {
+        let mut parser = Parser::new();
+        let query = parser.parse(sql).unwrap();
+
+        let binder = Binder::new();
+        let bound_query = binder.bind(query).unwrap();
+
+        let planner = Planner::new();
+        let logical_plan = planner.plan(bound_query);
+
+        let optimizer = Optimizer::new();
+        let optimized_plan = optimizer.optimize(logical_plan);
+
+        let physical_planner = PhysicalPlanner::new();
+        let (operators, schemas) = physical_planner.plan(optimized_plan);
+
+        let mut executor = PipelineExecutor::new(operators, schemas);
+        let rows = |chunks: Vec<celect::DataChunk>| -> Vec<Vec<Value>> {
+            chunks
+                .iter()
+                .flat_map(|c| c.selected_rows_as_values())
+                .collect()
+        };
+
+        let first = rows(executor.execute());
+        assert!(!first.is_empty(), "first run should produce rows: {}", sql);
+
+        executor.reset();
+        let second = rows(executor.execute());
+        assert_eq!(first, second, "replay should match the first run: {}", sql);
+    }
+}
+
+/// counts the heap allocations each thread makes, so a test can measure its own
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // the counter is gone while the thread exits
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// address of the first column's data, to tell whether a chunk was reallocated
+fn integer_data(chunk: &DataChunk) -> *const i64 {
+    match &chunk.columns[0] {
+        Vector::Integer { data, .. } => data.as_ptr(),
+        other => panic!("expected an Integer column, got {:?}", other),
+    }
+}
+
+/// scan → filter → projection a batch at a time, into output chunks that are
+/// kept across batches, or (`reuse` false) new for every batch. returns the
+/// ids, the allocations filter and projection made after the first batch, and
+/// the vectors each batch went into
+fn scan_filter_project(sql: &str, reuse: bool) -> (Vec<Value>, usize, Vec<[*const i64; 3]>) {
+    let query = Parser::new().parse(sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let id = bound_query.output[0].clone();
+    // the sequential scan fills the chunk it is given
+    let mut scan =
+        PhysicalScan::new(bound_query.file_path, bound_query.schema, None, None).with_strict(true);
+    let mut filter = PhysicalFilter::new(bound_query.where_clause.unwrap());
+    let mut projection = PhysicalProjection::new(vec![id]);
+
+    let (mut scanned, mut filtered, mut projected) =
+        (DataChunk::empty(), DataChunk::empty(), DataChunk::empty());
+    let mut ids = Vec::new();
+    let mut allocations = Vec::new();
+    let mut addresses = Vec::new();
+    loop {
+        if !reuse {
+            (scanned, filtered, projected) =
+                (DataChunk::empty(), DataChunk::empty(), DataChunk::empty());
+        }
+        let result = scan.execute(&DataChunk::empty(), &mut scanned);
+        if scanned.count > 0 {
+            let before = ALLOCATIONS.with(Cell::get);
+            filter.execute(&scanned, &mut filtered);
+            projection.execute(&filtered, &mut projected);
+            allocations.push(ALLOCATIONS.with(Cell::get) - before);
+            addresses.push([
+                integer_data(&scanned),
+                integer_data(&filtered),
+                integer_data(&projected),
+            ]);
+            ids.extend(projected.iter_column(0));
+        }
+        if result == ExecuteResult::Finished {
+            break;
+        }
+    }
+    (ids, allocations[1..].iter().sum(), addresses)
+}
+
+#[test]
+fn test_operators_reuse_their_output_chunks() {
+    let mut contents = String::from("id,value\n");
+    for i in 0..20_000 {
+        contents.push_str(&format!("{},{}\n", i, i % 7));
+    }
+    let test_file = TestFile::new("chunk_reuse", &contents);
+    let sql = format!("SELECT id FROM '{}' WHERE value = 3", test_file.path);
+
+    let (ids, reused, addresses) = scan_filter_project(&sql, true);
+    let (fresh_ids, fresh, _) = scan_filter_project(&sql, false);
+
+    // every batch went into the same vectors, and the rows are all there
+    assert_eq!(addresses.len(), 10);
+    assert!(addresses.iter().all(|a| *a == addresses[0]));
+    let expected: Vec<Value> = (0..20_000)
+        .filter(|i| i % 7 == 3)
+        .map(Value::Integer)
+        .collect();
+    assert_eq!(ids, expected);
+    assert_eq!(fresh_ids, expected);
+
+    // once the vectors have grown, a batch costs at most one allocation;
+    // new chunks for every batch cost several more
+    assert!(reused < addresses.len(), "{} allocations", reused);
+    assert!(
+        fresh > 5 * reused,
+        "{} allocations, {} reused",
+        fresh,
+        reused
+    );
+}
+
+#[test]
+fn test_union_removes_duplicates_across_files_and_chunks() {
+    // rows 0..3000, then 0..100 again a chunk later; the second file overlaps
+    // the first on 1000..3000
+    let rows = |ids: &mut dyn Iterator<Item = usize>| {
+        let mut contents = String::from("id,name\n");
+        for i in ids {
+            contents.push_str(&format!("{},name{}\n", i, i % 10));
+        }
+        contents
+    };
+    let first = TestFile::new("union_first", &rows(&mut (0..3_000).chain(0..100)));
+    let second = TestFile::new("union_second", &rows(&mut (1_000..4_000)));
+    let union = |operator: &str, rest: &str| {
+        let sql = format!(
+            "SELECT id, name FROM '{}' {} SELECT id, name FROM '{}'{}",
+            first.path, operator, second.path, rest
+        );
+        query_first_column(&sql)
+    };
+
+    // first occurrences, in the order they were read
+    assert_eq!(
+        union("UNION", ""),
+        (0..4_000).map(Value::Integer).collect::<Vec<_>>()
+    );
+    assert_eq!(union("UNION ALL", "").len(), 6_100);
+    assert_eq!(
+        union("UNION", " ORDER BY id DESC LIMIT 3"),
+        vec![
+            Value::Integer(3_999),
+            Value::Integer(3_998),
+            Value::Integer(3_997)
+        ]
+    );
+    assert_eq!(
+        union("UNION ALL", " ORDER BY 1 LIMIT 3 OFFSET 1"),
+        vec![Value::Integer(0), Value::Integer(1), Value::Integer(1)]
+    );
+}
+
+#[test]
+fn test_blocking_operators_under_limit_offset_reuse_buffers() {
+    let mut contents = String::from("id,value\n");
+    for i in 0..5_000 {
+        contents.push_str(&format!("{},{}\n", i, i % 7));
+    }
+    let test_file = TestFile::new("blocking_limit_offset", &contents);
+    let reservoir = || Planner::new().with_sample_method(SampleMethod::Reservoir { seed: 7 });
+
+    // each operator emits several vectors; LIMIT/OFFSET above it must see
+    // every row once, on the first run and after a reset
+    let cases: [(&str, fn() -> Planner); 4] = [
+        ("SELECT id FROM '{}' ORDER BY value, id", Planner::new),
+        ("SELECT id, COUNT(*) FROM '{}' GROUP BY id", Planner::new),
+        ("SELECT SUM(value) FROM '{}'", Planner::new),
+        ("SELECT id FROM '{}' SAMPLE 3000", reservoir),
+    ];
+    for (base, planner) in cases {
+        let base = base.replace("{}", &test_file.path);
+        let all = run_twice(&base, planner());
+        for (limit, offset) in [
+            (None, 0),
+            (None, 1),
+            (None, 2_048),
+            (None, 4_999),
+            (Some(1), 0),
+            (Some(2_500), 1_000),
+            (Some(100), 2_040),
+        ] {
+            let sql = match limit {
+                Some(limit) => format!("{} LIMIT {} OFFSET {}", base, limit, offset),
+                None => format!("{} OFFSET {}", base, offset),
+            };
+            let start = offset.min(all.len());
+            let end = limit.map_or(all.len(), |limit| (start + limit).min(all.len()));
+            assert_eq!(run_twice(&sql, planner()), all[start..end], "{}", sql);
+        }
+    }
+}
+
+// runs a query, resets the executor and runs it again on the same buffers;
+// both runs must return the same first column
+fn run_twice(sql: &str, planner: Planner) -> Vec<Value> {
+    let query = Parser::new().parse(sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let plan = Optimizer::new().optimize(planner.plan(bound_query));
+    let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+
+    let mut executor = PipelineExecutor::new(operators, schemas);
+    let first: Vec<Value> = executor
+        .execute()
+        .iter()
+        .flat_map(|c| c.iter_column(0))
+        .collect();
+    executor.reset();
+    let second: Vec<Value> = executor
+        .execute()
+        .iter()
+        .flat_map(|c| c.iter_column(0))
+        .collect();
+    assert_eq!(first, second, "{}", sql);
+    first
+}
+
+// helper to run a query and collect the first column of every selected row
+fn query_first_column(sql: &str) -> Vec<Value> {
+    query_first_column_collated(sql, Collation::Binary)
+}
+
+fn query_first_column_collated(sql: &str, collation: Collation) -> Vec<Value> {
+    let mut parser = Parser::new();
+    let query = parser.parse(sql).unwrap();
+
+    let binder = Binder::new();
+    let bound_query = binder.bind(query).unwrap();
+
+    let planner = Planner::new();
+    let logical_plan = planner.plan(bound_query);
+
+    let optimizer = Optimizer::new().with_collation(collation);
+    let optimized_plan = optimizer.optimize(logical_plan);
+
+    let physical_planner = PhysicalPlanner::new().with_collation(collation);
+    let (operators, schemas) = physical_planner.plan(optimized_plan);
+
+    let mut executor = PipelineExecutor::new(operators, schemas);
+    executor
+        .execute()
+        .iter()
+        .flat_map(|c| c.iter_column(0))
+        .collect()
+}
+
+#[test]
+fn test_offset_above_blocking_operators_counts_rows() {
+    let test_file = TestFile::new("offset_blocking", "id,age\n1,30\n2,20\n3,50\n4,40\n5,10\n");
+
+    // the operator below the OFFSET finishes after emitting, and the rows
+    // it emitted last must not be collected a second time
+    for (clause, rows) in [
+        ("SELECT id FROM '{}' ORDER BY age OFFSET 1", 4),
+        ("SELECT id FROM '{}' ORDER BY age LIMIT 2 OFFSET 1", 2),
+        ("SELECT age, COUNT(*) FROM '{}' GROUP BY age OFFSET 2", 3),
+        ("SELECT COUNT(*) FROM '{}' OFFSET 0", 1),
+        ("SELECT COUNT(*) FROM '{}' OFFSET 1", 0),
+    ] {
+        let sql = clause.replace("{}", &test_file.path);
+        assert_eq!(query_first_column(&sql).len(), rows, "{}", sql);
+    }
+}
+
+#[test]
+fn test_in_and_not_in() {
+    let test_file = TestFile::new(
+        "in_not_in",
+        "id,city\n1,NYC\n2,LA\n3,SF\n4,\n5,NYC\n6,Boston\n",
+    );
+
+    let in_rows = query_first_column(&format!(
+        "SELECT id FROM '{}' WHERE city IN ('NYC', 'LA')",
+        test_file.path
+    ));
+    assert_eq!(
+        in_rows,
+        vec![Value::Integer(1), Value::Integer(2), Value::Integer(5)]
+    );
+
+    // complement of IN, except the NULL city which is UNKNOWN either way
+    let not_in_rows = query_first_column(&format!(
+        "SELECT id FROM '{}' WHERE city NOT IN ('NYC', 'LA')",
+        test_file.path
+    ));
+    assert_eq!(not_in_rows, vec![Value::Integer(3), Value::Integer(6)]);
+}
+
+#[test]
+fn test_not_in_with_null_in_list() {
+    let test_file = TestFile::new("not_in_null_list", "id,age\n1,10\n2,20\n3,30\n");
+
+    // x NOT IN (10, NULL) is never true: either false or unknown
+    let rows = query_first_column(&format!(
+        "SELECT id FROM '{}' WHERE age NOT IN (10, NULL)",
+        test_file.path
+    ));
+    assert!(rows.is_empty());
+
+    // a match still wins over the NULL for IN
+    let rows = query_first_column(&format!(
+        "SELECT id FROM '{}' WHERE age IN (10, NULL)",
+        test_file.path
+    ));
+    assert_eq!(rows, vec![Value::Integer(1)]);
+}
+
+#[test]
+fn test_collation_in_filter_comparisons() {
+    let test_file = TestFile::new(
+        "collation",
+        "id,name\n1,cafe\n2,Café\n3,CAFE\n4,café\n5,tea\n6,Cafeteria\n",
+    );
+
+    let sql = format!("SELECT id FROM '{}' WHERE name = 'cafe'", test_file.path);
+    assert_eq!(
+        query_first_column_collated(&sql, Collation::Binary),
+        vec![Value::Integer(1)]
+    );
+    assert_eq!(
+        query_first_column_collated(&sql, Collation::CaseInsensitive),
+        vec![Value::Integer(1), Value::Integer(3)]
+    );
+    assert_eq!(
+        query_first_column_collated(&sql, Collation::AccentInsensitive),
+        vec![
+            Value::Integer(1),
+            Value::Integer(2),
+            Value::Integer(3),
+            Value::Integer(4)
+        ]
+    );
+
+    // prefix LIKE must not be turned into a byte-order range under a collation
+    let sql = format!("SELECT id FROM '{}' WHERE name LIKE 'CAF%'", test_file.path);
+    assert_eq!(
+        query_first_column_collated(&sql, Collation::AccentInsensitive),
+        vec![
+            Value::Integer(1),
+            Value::Integer(2),
+            Value::Integer(3),
+            Value::Integer(4),
+            Value::Integer(6)
+        ]
+    );
+
+    // ordering comparisons use the collated keys too
+    let sql = format!("SELECT id FROM '{}' WHERE name < 'D'", test_file.path);
+    assert_eq!(
+        query_first_column_collated(&sql, Collation::CaseInsensitive),
+        vec![
+            Value::Integer(1),
+            Value::Integer(2),
+            Value::Integer(3),
+            Value::Integer(4),
+            Value::Integer(6)
+        ]
+    );
+}
+
+/// runs a query with the given strictness and returns the executor's result
+fn try_query_first_column(sql: &str, strict: bool) -> Result<Vec<Value>, String> {
+    let mut parser = Parser::new();
+    let query = parser.parse(sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let logical_plan = Planner::new().plan(bound_query);
+    let optimized_plan = Optimizer::new().optimize(logical_plan);
+    let (operators, schemas) = PhysicalPlanner::new()
+        .with_strict(strict)
+        .plan(optimized_plan);
+
+    let mut executor = PipelineExecutor::new(operators, schemas);
+    let results = executor.try_execute().map_err(|e| e.message)?;
+    Ok(results.iter().flat_map(|c| c.iter_column(0)).collect())
+}
+
+#[test]
+fn test_strict_mode_reports_bad_cell() {
+    // the bad cell is past the type-inference sample, so qty stays Integer
+    let mut contents = String::from("id,qty\n");
+    for i in 1..=25 {
+        contents.push_str(&format!("{},{}\n", i, i * 10));
+    }
+    contents.push_str("26,twelve\n27,270\n");
+    let test_file = TestFile::new("strict_bad_cell", &contents);
+
+    let sql = format!("SELECT qty FROM '{}'", test_file.path);
+
+    // default mode reads the bad cell as NULL and keeps going
+    let values = try_query_first_column(&sql, false).unwrap();
+    assert_eq!(values.len(), 27);
+    assert_eq!(values[25], Value::Null);
+    assert_eq!(values[26], Value::Integer(270));
+
+    // strict mode stops at the cell: file, line, column, value, expected type
+    let err = try_query_first_column(&sql, true).unwrap_err();
+    assert!(err.contains(&test_file.path), "{}", err);
+    assert!(err.contains(":27:"), "{}", err);
+    assert!(err.contains("'qty'"), "{}", err);
+    assert!(err.contains("'twelve'"), "{}", err);
+    assert!(err.contains("Integer"), "{}", err);
+}
+
+/// a plan over a file that is deleted once the query is bound
+fn executor_for_deleted_file(name: &str, strict: bool) -> PipelineExecutor {
+    let test_file = TestFile::new(name, "id,name\n1,Alice\n");
+    let sql = format!("SELECT id FROM '{}'", test_file.path);
+    let bound_query = Binder::new()
+        .bind(Parser::new().parse(&sql).unwrap())
+        .unwrap();
+    let optimized_plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+    let (operators, schemas) = PhysicalPlanner::new()
+        .with_strict(strict)
+        .plan(optimized_plan);
+    PipelineExecutor::new(operators, schemas)
+}
+
+#[test]
+fn test_scan_reports_a_file_it_cannot_open() {
+    // the parallel scan (default) and the sequential one (strict)
+    for strict in [false, true] {
+        let name = format!("deleted_{}", strict);
+        let err = executor_for_deleted_file(&name, strict)
+            .try_execute()
+            .unwrap_err();
+        assert!(err.message.starts_with("cannot open "), "{}", err);
+        let file_name = format!("test_{}.csv", name);
+        assert!(err.message.contains(&file_name), "{}", err);
+    }
+}
+
+#[test]
+#[should_panic(expected = "query failed: cannot open")]
+fn test_execute_panics_on_a_failed_query() {
+    executor_for_deleted_file("deleted_panics", false).execute();
+}
+
+#[test]
+fn test_quoted_column_with_space() {
+    let test_file = TestFile::new(
+        "quoted_column",
+        "id,first name,age\n1,Ada,36\n2,Alan,41\n3,Grace,85\n",
+    );
+
+    // quoted name in the projection and in the predicate
+    let sql = format!(
+        "SELECT \"first name\" FROM '{}' WHERE [first name] LIKE 'A%' AND age > 40",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Varchar("Alan".to_string())]
+    );
+
+    let sql = format!(
+        "SELECT `first name`, age FROM '{}' WHERE \"first name\" = 'Grace'",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Varchar("Grace".to_string())]
+    );
+}
+
+#[test]
+fn test_ragged_rows() {
+    // row 2 is short (no age), row 3 has an extra field
+    let test_file = TestFile::new(
+        "ragged_rows",
+        "id,name,age\n1,Ada,36\n2,Alan\n3,Grace,85,extra\n4,Linus,54\n",
+    );
+
+    // lenient: missing trailing columns are NULL, extra fields are dropped
+    let sql = format!("SELECT age FROM '{}'", test_file.path);
+    assert_eq!(
+        try_query_first_column(&sql, false).unwrap(),
+        vec![
+            Value::Integer(36),
+            Value::Null,
+            Value::Integer(85),
+            Value::Integer(54)
+        ]
+    );
+    let sql = format!("SELECT name FROM '{}' WHERE id > 2", test_file.path);
+    assert_eq!(
+        try_query_first_column(&sql, false).unwrap(),
+        vec![
+            Value::Varchar("Grace".to_string()),
+            Value::Varchar("Linus".to_string())
+        ]
+    );
+
+    // strict: the first ragged row is an error
+    let sql = format!("SELECT age FROM '{}'", test_file.path);
+    let err = try_query_first_column(&sql, true).unwrap_err();
+    assert!(err.contains(":3:"), "{}", err);
+    assert!(err.contains("expected 3 fields, found 2"), "{}", err);
+
+    // the strict binder already rejects ragged rows in the type-inference sample
+    let mut parser = Parser::new();
+    let query = parser.parse(&sql).unwrap();
+    let err = Binder::new().with_strict(true).bind(query).unwrap_err();
+    assert!(err.message.contains("line 3"), "{}", err.message);
+}
+
+#[test]
+fn test_headerless_file_keeps_first_row() {
+    let test_file = TestFile::new("headerless_auto", "10,1.5\n20,2.5\n30,3.5\n");
+
+    let sql = format!("SELECT column1 FROM '{}' WHERE column2 > 2", test_file.path);
+    let query = Parser::new().parse(&sql).unwrap();
+    let bound_query = Binder::new()
+        .with_header(HeaderMode::Auto)
+        .bind(query)
+        .unwrap();
+    let optimized_plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+    let (operators, schemas) = PhysicalPlanner::new().plan(optimized_plan);
+    let results = PipelineExecutor::new(operators, schemas).execute();
+
+    let values: Vec<Value> = results.iter().flat_map(|c| c.iter_column(0)).collect();
+    assert_eq!(values, vec![Value::Integer(20), Value::Integer(30)]);
+}
+
+#[test]
+fn test_gzip_compressed_csv() {
+    let mut contents = String::from("id,name,score\n");
+    for i in 0..5000 {
+        contents.push_str(&format!("{},user{},{}.5\n", i, i, i % 10));
+    }
+
+    // detected by the .gz extension
+    let test_file = TestFile::gzipped("test_gzip_scan.csv.gz", &contents);
+    let sql = format!(
+        "SELECT name FROM '{}' WHERE id >= 4998 AND score > 6",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("user4998".to_string()),
+            Value::Varchar("user4999".to_string())
+        ]
+    );
+
+    // and by the gzip magic bytes without one; types are inferred from decompressed rows
+    let test_file = TestFile::gzipped("test_gzip_magic.csv", &contents);
+    let sql = format!("SELECT score FROM '{}' LIMIT 2", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Float(0.5), Value::Float(1.5)]
+    );
+}
+
+#[test]
+fn test_json_lines_source() {
+    let test_file = TestFile::gzipped(
+        "test_json_lines.jsonl.gz",
+        concat!(
+            "{\"id\": 1, \"name\": \"Ada\", \"score\": 1.5, \"active\": true}\n",
+            "{\"id\": 2, \"score\": 2, \"tags\": [\"x\", \"y\"]}\n",
+            "\n",
+            "{\"id\": 3, \"name\": null, \"active\": false, \"tags\": \"z\"}\n",
+        ),
+    );
+    let mut parser = Parser::new();
+
+    // schema: union of keys in first-seen order, typed from the JSON values
+    let sql = format!("SELECT * FROM '{}'", test_file.path);
+    let bound = Binder::new().bind(parser.parse(&sql).unwrap()).unwrap();
+    assert_eq!(bound.format, SourceFormat::JsonLines);
+    let columns: Vec<(&str, ColumnType)> = bound
+        .schema
+        .columns
+        .iter()
+        .map(|c| (c.name.as_str(), c.type_.clone()))
+        .collect();
+    assert_eq!(
+        columns,
+        vec![
+            ("id", ColumnType::Integer),
+            ("name", ColumnType::Varchar),
+            ("score", ColumnType::Float),
+            ("active", ColumnType::Boolean),
+            ("tags", ColumnType::Varchar),
+        ]
+    );
+
+    // missing keys are NULL; a whole-number score is still a Float
+    let sql = format!("SELECT score FROM '{}'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Float(1.5), Value::Float(2.0), Value::Null]
+    );
+    let sql = format!("SELECT tags FROM '{}' WHERE id >= 2", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("[\"x\",\"y\"]".to_string()),
+            Value::Varchar("z".to_string())
+        ]
+    );
+    let sql = format!("SELECT name FROM '{}' WHERE active = true", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Varchar("Ada".to_string())]
+    );
+}
+
+#[test]
+fn test_scan_coerces_numbers_to_column_type() {
+    let test_file = TestFile::new(
+        "numeric_coercion_scan",
+        "price,qty\n42,+5\n42.0,007\n\"19.5\",\"12\"\n",
+    );
+
+    // price mixes integer- and float-looking values, so it's a Float column
+    let sql = format!("SELECT price FROM '{}'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Float(42.0), Value::Float(42.0), Value::Float(19.5)]
+    );
+    let sql = format!("SELECT qty FROM '{}' WHERE qty > 6", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Integer(7), Value::Integer(12)]
+    );
+}
+
+#[test]
+fn test_scientific_notation_floats() {
+    let test_file = TestFile::new(
+        "scientific_notation",
+        "sensor,measurement\na,1.5e10\nb,3E-4\nc,2e6\nd,12\ne,-7.25E+2\n",
+    );
+
+    let sql = format!("SELECT measurement FROM '{}'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Float(1.5e10),
+            Value::Float(3e-4),
+            Value::Float(2e6),
+            Value::Float(12.0),
+            Value::Float(-725.0),
+        ]
+    );
+
+    let sql = format!(
+        "SELECT sensor FROM '{}' WHERE measurement > 1e6",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("a".to_string()),
+            Value::Varchar("c".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_output_schema_matches_select_list() {
+    let test_file = TestFile::new(
+        "output_schema",
+        "id,name,age,city\n1,Alice,30,NYC\n2,Bob,25,LA\n",
+    );
+
+    // age is only read by the filter, so projection pushdown keeps it out of the output
+    let sql = format!(
+        "SELECT city, name, id FROM '{}' WHERE age > 26",
+        test_file.path
+    );
+    let query = Parser::new().parse(&sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+    let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+    let mut executor = PipelineExecutor::new(operators, schemas);
+
+    let schema = executor.output_schema();
+    assert_eq!(schema.names(), vec!["city", "name", "id"]);
+    assert_eq!(
+        schema.types(),
+        vec![
+            ColumnType::Varchar,
+            ColumnType::Varchar,
+            ColumnType::Integer
+        ]
+    );
+
+    let results = executor.execute();
+    assert_eq!(results[0].column_count(), 3);
+    assert_eq!(
+        results[0].get_value(0, 0),
+        Some(Value::Varchar("NYC".to_string()))
+    );
+}
+
+#[test]
+fn test_output_schema_names_aggregates() {
+    let test_file = TestFile::new(
+        "aggregate_names",
+        "id,name,age\n1,Alice,30\n2,,25\n3,Carol,30\n",
+    );
+    let names = |select: &str, rest: &str| {
+        let sql = format!("SELECT {} FROM '{}' {}", select, test_file.path, rest);
+        let bound_query = Binder::new()
+            .bind(Parser::new().parse(&sql).unwrap())
+            .unwrap();
+        let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+        let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+        PipelineExecutor::new(operators, schemas)
+            .output_schema()
+            .names()
+    };
+
+    // aggregates are named by their text, unless aliased
+    assert_eq!(names("COUNT(name)", ""), vec!["COUNT(name)"]);
+    assert_eq!(
+        names("COUNT(*), AVG(age + 1), MAX(id) AS newest", ""),
+        vec!["COUNT(*)", "AVG(age + 1)", "newest"]
+    );
+    assert_eq!(
+        names("age, COUNT(name)", "GROUP BY age"),
+        vec!["age", "COUNT(name)"]
+    );
+}
+
+#[test]
+fn test_select_constant_columns() {
+    let test_file = TestFile::new("constant_columns", "id,name\n1,Alice\n2,Bob\n3,Carol\n");
+    let mut engine = QueryEngine::new();
+    let mut run = |sql: String| {
+        let (results, schema) = engine.execute(&sql).unwrap();
+        let rows: Vec<Vec<Value>> = results.iter().flat_map(|chunk| chunk.rows()).collect();
+        (schema.names(), rows)
+    };
+
+    // the constant is repeated on every row
+    let (names, rows) = run(format!(
+        "SELECT 1 AS one, name, 'csv' AS source FROM '{}' WHERE id > 1",
+        test_file.path
+    ));
+    assert_eq!(names, vec!["one", "name", "source"]);
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                Value::Integer(1),
+                Value::Varchar("Bob".to_string()),
+                Value::Varchar("csv".to_string())
+            ],
+            vec![
+                Value::Integer(1),
+                Value::Varchar("Carol".to_string()),
+                Value::Varchar("csv".to_string())
+            ],
+        ]
+    );
+
+    // beside ungrouped aggregates too
+    let (names, rows) = run(format!(
+        "SELECT 'total' AS label, COUNT(*), 2.5 FROM '{}'",
+        test_file.path
+    ));
+    assert_eq!(names, vec!["label", "COUNT(*)", "2.5"]);
+    assert_eq!(
+        rows,
+        vec![vec![
+            Value::Varchar("total".to_string()),
+            Value::Integer(3),
+            Value::Float(2.5)
+        ]]
+    );
+
+    // a column beside an aggregate still needs a GROUP BY
+    let sql = format!("SELECT name, COUNT(*) FROM '{}'", test_file.path);
+    let err = Binder::new()
+        .bind(Parser::new().parse(&sql).unwrap())
+        .unwrap_err();
+    assert_eq!(
+        err.message,
+        "Column 'name' must appear in the GROUP BY clause or be used in an aggregate function"
+    );
+}
+
+#[test]
+fn test_bare_boolean_column_predicate() {
+    let test_file = TestFile::new(
+        "bare_boolean_predicate",
+        "name,active\nAlice,true\nBob,false\nCarol,true\n",
+    );
+
+    let sql = format!("SELECT name FROM '{}' WHERE active", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("Alice".to_string()),
+            Value::Varchar("Carol".to_string())
+        ]
+    );
+
+    let sql = format!("SELECT name FROM '{}' WHERE NOT active", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Varchar("Bob".to_string())]
+    );
+}
+
+#[test]
+fn test_column_to_column_comparison() {
+    let test_file = TestFile::new(
+        "column_vs_column",
+        "name,age,score,first,last\n\
+         r1,1,0.5,apple,banana\n\
+         r2,2,2.0,pear,pear\n\
+         r3,3,3.5,zoo,abc\n\
+         r4,,1.0,,b\n",
+    );
+    let names = |condition: &str| {
+        let sql = format!("SELECT name FROM '{}' WHERE {}", test_file.path, condition);
+        query_first_column(&sql)
+            .into_iter()
+            .map(|v| match v {
+                Value::Varchar(s) => s,
+                other => panic!("expected a name, got {:?}", other),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // age is Integer and score is Float: compared numerically
+    assert_eq!(names("age > score"), vec!["r1"]);
+    assert_eq!(names("age >= score"), vec!["r1", "r2"]);
+    assert_eq!(names("age = score"), vec!["r2"]);
+    assert_eq!(names("score > age"), vec!["r3"]);
+    // r4's NULL age makes the comparison unknown, not true
+    assert_eq!(names("age != score"), vec!["r1", "r3"]);
+
+    assert_eq!(names("first < last"), vec!["r1"]);
+    assert_eq!(names("first = last"), vec!["r2"]);
+    assert_eq!(names("first > last"), vec!["r3"]);
+}
+
+#[test]
+fn test_modulo_and_negation() {
+    let test_file = TestFile::new(
+        "modulo_negation",
+        "id,balance\n1,10.5\n2,-2.0\n3,7.25\n4,0.0\n5,\n6,-1.5\n",
+    );
+
+    let sql = format!("SELECT id FROM '{}' WHERE id % 2 = 0", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Integer(2), Value::Integer(4), Value::Integer(6)]
+    );
+
+    // modulo by zero is NULL, so no row matches
+    let sql = format!("SELECT id FROM '{}' WHERE id % 0 = 0", test_file.path);
+    assert!(query_first_column(&sql).is_empty());
+
+    let sql = format!("SELECT id FROM '{}' WHERE -balance > 0", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Integer(2), Value::Integer(6)]
+    );
+
+    let sql = format!("SELECT SUM(-balance) FROM '{}'", test_file.path);
+    assert_eq!(query_first_column(&sql), vec![Value::Float(-14.25)]);
+
+    let sql = format!("SELECT MIN(-id) FROM '{}'", test_file.path);
+    assert_eq!(query_first_column(&sql), vec![Value::Integer(-6)]);
+}
+
+#[test]
+fn test_concat_and_string_functions() {
+    let test_file = TestFile::new(
+        "concat_string_functions",
+        "id,name,city\n1,alice,Paris\n2,Bob,\n3,Émile,Rome\n",
+    );
+
+    let sql = format!(
+        "SELECT name || ' (' || city || ')' FROM '{}'",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("alice (Paris)".to_string()),
+            Value::Null, // NULL city makes the whole concatenation NULL
+            Value::Varchar("Émile (Rome)".to_string()),
+        ]
+    );
+
+    // non-string operands use their text form
+    let sql = format!("SELECT id || ':' || name FROM '{}'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql)[0],
+        Value::Varchar("1:alice".to_string())
+    );
+
+    let sql = format!("SELECT UPPER(name), id FROM '{}'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("ALICE".to_string()),
+            Value::Varchar("BOB".to_string()),
+            Value::Varchar("ÉMILE".to_string()),
+        ]
+    );
+
+    let sql = format!(
+        "SELECT LOWER(name) FROM '{}' WHERE LENGTH(name) = 5",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("alice".to_string()),
+            Value::Varchar("émile".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_substr_and_trim() {
+    let test_file = TestFile::new("substr_trim", "name\nalice\nBob\nÉmile\n");
+    let first = |select: &str| {
+        let sql = format!("SELECT {} FROM '{}'", select, test_file.path);
+        match query_first_column(&sql).into_iter().next() {
+            Some(Value::Varchar(s)) => s,
+            other => panic!("expected a string, got {:?}", other),
+        }
+    };
+
+    assert_eq!(first("SUBSTR(name, 1, 3)"), "ali");
+    assert_eq!(first("SUBSTR(name, 2)"), "lice");
+    // positions before 1 use up the length without producing characters
+    assert_eq!(first("SUBSTR(name, 0, 3)"), "al");
+    assert_eq!(first("SUBSTR(name, -2, 4)"), "a");
+    assert_eq!(first("SUBSTR(name, -10, 3)"), "");
+    // bounds past the end clamp to the string
+    assert_eq!(first("SUBSTR(name, 4, 100)"), "ce");
+    assert_eq!(first("SUBSTR(name, 9)"), "");
+    assert_eq!(first("SUBSTR(name, 2, -1)"), "");
+
+    assert_eq!(first("TRIM('  ' || name || ' ')"), "alice");
+
+    // positions count characters, not bytes
+    let sql = format!(
+        "SELECT SUBSTR(name, 1, 2) FROM '{}' WHERE TRIM(name) = 'Émile'",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Varchar("Ém".to_string())]
+    );
+}
+
+#[test]
+fn test_cast_varchar_to_integer() {
+    let test_file = TestFile::new(
+        "cast_varchar",
+        "name,zip,score\na,90210,2.5\nb,n/a,-1.5\nc, 10001 ,0.4\nd,9x,1.0\n",
+    );
+
+    // zip is inferred as Varchar because of the unparseable rows, which become NULL
+    let sql = format!("SELECT CAST(zip AS INTEGER) FROM '{}'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Integer(90210),
+            Value::Null,
+            Value::Integer(10001),
+            Value::Null
+        ]
+    );
+
+    let sql = format!(
+        "SELECT name FROM '{}' WHERE CAST(zip AS INTEGER) > 90000",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Varchar("a".to_string())]
+    );
+
+    // floats round to the nearest integer; numbers widen and print as text
+    let sql = format!("SELECT CAST(score AS INT) FROM '{}'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Integer(3),
+            Value::Integer(-2),
+            Value::Integer(0),
+            Value::Integer(1)
+        ]
+    );
+    let sql = format!(
+        "SELECT CAST(CAST(score AS INTEGER) AS FLOAT) FROM '{}' LIMIT 1",
+        test_file.path
+    );
+    assert_eq!(query_first_column(&sql), vec![Value::Float(3.0)]);
+    let sql = format!(
+        "SELECT CAST(CAST(score > 1 AS INTEGER) AS VARCHAR) FROM '{}'",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("1".to_string()),
+            Value::Varchar("0".to_string()),
+            Value::Varchar("0".to_string()),
+            Value::Varchar("0".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_case_when() {
+    let test_file = TestFile::new("case_when", "name,age\nann,30\nbob,12\ncat,\n");
+
+    let sql = format!(
+        "SELECT CASE WHEN age >= 18 THEN 'adult' ELSE 'minor' END FROM '{}'",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("adult".to_string()),
+            Value::Varchar("minor".to_string()),
+            // a NULL condition is not true, so the ELSE branch is taken
+            Value::Varchar("minor".to_string())
+        ]
+    );
+
+    // without ELSE, rows matching no branch are NULL
+    let sql = format!(
+        "SELECT CASE WHEN age >= 18 THEN 'adult' WHEN age < 18 THEN 'minor' END FROM '{}'",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("adult".to_string()),
+            Value::Varchar("minor".to_string()),
+            Value::Null
+        ]
+    );
+
+    // Integer and Float results share a Float column
+    let sql = format!(
+        "SELECT CASE WHEN age > 20 THEN age ELSE 0.5 END FROM '{}'",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Float(30.0), Value::Float(0.5), Value::Float(0.5)]
+    );
+}
+
+#[test]
+fn test_coalesce_falls_back_on_null() {
+    let test_file = TestFile::new(
+        "coalesce_fallback",
+        "name,nickname,age,score\nalice,ali,30,\nbob,,,2.5\ncarl,,,\n",
+    );
+
+    let sql = format!("SELECT COALESCE(nickname, name) FROM '{}'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("ali".to_string()),
+            Value::Varchar("bob".to_string()),
+            Value::Varchar("carl".to_string())
+        ]
+    );
+
+    // Integer and Float arguments give a Float; all-NULL rows stay NULL
+    let sql = format!("SELECT COALESCE(age, score) FROM '{}'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Float(30.0), Value::Float(2.5), Value::Null]
+    );
+
+    let sql = format!(
+        "SELECT name FROM '{}' WHERE COALESCE(age, 0) = 0",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("bob".to_string()),
+            Value::Varchar("carl".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_select_from_multiple_files() {
+    let jan = TestFile::new("union_jan", "id,name,amount\n1,Alice,10\n2,Bob,25\n");
+    let feb = TestFile::new("union_feb", "id,name,amount\n3,Carol,7.5\n4,Dave,40\n");
+
+    // rows come from each file in turn; amount is Float because feb has a fraction
+    let sql = format!(
+        "SELECT name FROM '{}', '{}' WHERE amount > 9",
+        jan.path, feb.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Varchar("Alice".to_string()),
+            Value::Varchar("Bob".to_string()),
+            Value::Varchar("Dave".to_string()),
+        ]
+    );
+
+    let sql = format!("SELECT amount FROM '{}', '{}'", jan.path, feb.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Float(10.0),
+            Value::Float(25.0),
+            Value::Float(7.5),
+            Value::Float(40.0),
+        ]
+    );
+
+    let sql = format!("SELECT id FROM '{}', '{}' LIMIT 3", jan.path, feb.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)]
+    );
+
+    // files with different columns can't be combined
+    let other = TestFile::new("union_other", "id,title\n5,Boss\n");
+    let sql = format!("SELECT id FROM '{}', '{}'", jan.path, other.path);
+    let err = Binder::new()
+        .bind(Parser::new().parse(&sql).unwrap())
+        .unwrap_err();
+    assert!(err.message.contains("does not have the same columns"));
+}
+
+#[test]
+fn test_glob_pattern_in_from() {
+    let dir = std::env::temp_dir().join("celect_glob_test");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("part-2.csv"), "id,city\n3,Oslo\n").unwrap();
+    fs::write(dir.join("part-1.csv"), "id,city\n1,Paris\n2,Rome\n").unwrap();
+    fs::write(dir.join("part-3.txt"), "id,city\n9,Nowhere\n").unwrap();
+
+    // matches are read in sorted order; the .txt file doesn't match
+    let sql = format!("SELECT id FROM '{}'", dir.join("*.csv").display());
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)]
+    );
+
+    let sql = format!("SELECT id FROM '{}'", dir.join("*.parquet").display());
+    let err = Binder::new()
+        .bind(Parser::new().parse(&sql).unwrap())
+        .unwrap_err();
+    assert!(err.message.contains("No files match"));
+
+    // matched files must share their columns
+    fs::write(dir.join("part-4.csv"), "id,country\n4,Norway\n").unwrap();
+    let sql = format!("SELECT id FROM '{}'", dir.join("*.csv").display());
+    let err = Binder::new()
+        .bind(Parser::new().parse(&sql).unwrap())
+        .unwrap_err();
+    assert!(err.message.contains("does not have the same columns"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_select_star_keeps_header_order() {
+    let test_file = TestFile::new(
+        "select_star_order",
+        "id,name,age,city\n1,Alice,30,NYC\n2,Bob,25,LA\n3,Carol,40,SF\n",
+    );
+
+    let run = |binder: Binder, sql: &str| {
+        let bound_query = binder.bind(Parser::new().parse(sql).unwrap()).unwrap();
+        let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+        let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+        let mut executor = PipelineExecutor::new(operators, schemas);
+        let names = executor.output_schema().names();
+        let rows: Vec<Vec<Value>> = executor
+            .execute()
+            .iter()
+            .flat_map(|chunk| chunk.rows().collect::<Vec<_>>())
+            .collect();
+        (names, rows)
+    };
+
+    // the filter reads the last column; * still lists columns in header order
+    let sql = format!("SELECT * FROM '{}' WHERE city != 'LA'", test_file.path);
+    let (names, rows) = run(Binder::new(), &sql);
+    assert_eq!(names, vec!["id", "name", "age", "city"]);
+    assert_eq!(
+        rows[1],
+        vec![
+            Value::Integer(3),
+            Value::Varchar("Carol".to_string()),
+            Value::Integer(40),
+            Value::Varchar("SF".to_string()),
+        ]
+    );
+
+    // a column listed next to * is output again, unless deduplicated
+    let sql = format!(
+        "SELECT city, *, name FROM '{}' WHERE age > 35",
+        test_file.path
+    );
+    let (names, rows) = run(Binder::new(), &sql);
+    assert_eq!(names, vec!["city", "id", "name", "age", "city", "name"]);
+    assert_eq!(rows[0][0], Value::Varchar("SF".to_string()));
+    assert_eq!(rows[0][5], Value::Varchar("Carol".to_string()));
+
+    let (names, _) = run(Binder::new().with_dedupe_columns(true), &sql);
+    assert_eq!(names, vec!["city", "id", "name", "age"]);
+}
+
+#[test]
+fn test_order_by_ordinal() {
+    let test_file = TestFile::new(
+        "order_by_ordinal",
+        "name,age\nann,30\nbob,12\ncat,\ndan,45\neve,30\n",
+    );
+
+    // equal keys keep their input order and NULL sorts last
+    let sql = format!("SELECT name, age FROM '{}' ORDER BY 2", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        ["bob", "ann", "eve", "dan", "cat"]
+            .map(|name| Value::Varchar(name.to_string()))
+            .to_vec()
+    );
+
+    let sql = format!(
+        "SELECT name, age FROM '{}' ORDER BY 2 DESC, 1 DESC",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        ["cat", "dan", "eve", "ann", "bob"]
+            .map(|name| Value::Varchar(name.to_string()))
+            .to_vec()
+    );
+
+    let sql = format!("SELECT name FROM '{}' ORDER BY 2", test_file.path);
+    let query = Parser::new().parse(&sql).unwrap();
+    let err = Binder::new().bind(query).unwrap_err();
+    assert_eq!(
+        err.message,
+        "ORDER BY position 2 is not in the SELECT list (expected 1 to 1)"
+    );
+}
+
+fn query_sampled(sql: &str, method: SampleMethod) -> Vec<Value> {
+    let query = Parser::new().parse(sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let logical_plan = Planner::new().with_sample_method(method).plan(bound_query);
+    let optimized_plan = Optimizer::new().optimize(logical_plan);
+    let (operators, schemas) = PhysicalPlanner::new().plan(optimized_plan);
+    PipelineExecutor::new(operators, schemas)
+        .execute()
+        .iter()
+        .flat_map(|c| c.iter_column(0))
+        .collect()
+}
+
+#[test]
+fn test_sample() {
+    let mut contents = String::from("id,even\n");
+    for i in 0..5000 {
+        contents.push_str(&format!("{},{}\n", i, i % 2 == 0));
+    }
+    let test_file = TestFile::new("sample", &contents);
+    let ids = |values: Vec<Value>| -> Vec<i64> {
+        values
+            .into_iter()
+            .map(|v| match v {
+                Value::Integer(id) => id,
+                other => panic!("expected an id, got {:?}", other),
+            })
+            .collect()
+    };
+
+    // head sampling takes the first rows, before WHERE
+    let sql = format!("SELECT id FROM '{}' SAMPLE 4 WHERE even", test_file.path);
+    assert_eq!(ids(query_sampled(&sql, SampleMethod::Head)), vec![0, 2]);
+
+    // a reservoir picks rows from the whole file, in file order
+    let sql = format!("SELECT id FROM '{}' SAMPLE 100", test_file.path);
+    let first = ids(query_sampled(&sql, SampleMethod::Reservoir { seed: 42 }));
+    assert_eq!(first.len(), 100);
+    assert!(first.windows(2).all(|w| w[0] < w[1]));
+    assert!(*first.last().unwrap() >= 100);
+
+    // the same seed picks the same rows; another seed picks different ones
+    let again = ids(query_sampled(&sql, SampleMethod::Reservoir { seed: 42 }));
+    assert_eq!(first, again);
+    let other = ids(query_sampled(&sql, SampleMethod::Reservoir { seed: 7 }));
+    assert_ne!(first, other);
+
+    // asking for more rows than the file has returns every row
+    let sql = format!("SELECT id FROM '{}' SAMPLE 6000", test_file.path);
+    let all = ids(query_sampled(&sql, SampleMethod::Reservoir { seed: 42 }));
+    assert_eq!(all, (0..5000).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_utf8_file_name_and_values() {
+    let test_file = TestFile::new(
+        "données_東京",
+        "name,city\nAnaïs,Zürich\n太郎,東京\nЖеня,Москва\nKenji,東京都\n",
+    );
+
+    let sql = format!("SELECT name FROM '{}' WHERE city = '東京'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Varchar("太郎".to_string())]
+    );
+
+    let sql = format!(
+        "SELECT name FROM '{}' WHERE city LIKE '東%' OR city = 'Zürich'",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        ["Anaïs", "太郎", "Kenji"]
+            .map(|name| Value::Varchar(name.to_string()))
+            .to_vec()
+    );
+
+    // string functions count characters, not bytes
+    let sql = format!(
+        "SELECT SUBSTR(city, 2, 2) FROM '{}' WHERE LENGTH(city) = 3",
+        test_file.path
+    );
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Varchar("京都".to_string())]
+    );
+}
+
+fn query_chunks(sql: &str) -> Vec<celect::DataChunk> {
+    let query = Parser::new().parse(sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let optimized_plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+    let (operators, schemas) = PhysicalPlanner::new().plan(optimized_plan);
+    PipelineExecutor::new(operators, schemas).execute()
+}
+
+#[test]
+fn test_projection_keeps_selected_column_types() {
+    let test_file = TestFile::new(
+        "projection_types",
+        "id,name,age,score,active\n1,ann,30,1.5,true\n2,bob,25,2.5,false\n",
+    );
+
+    // the selected columns are read out of file order and pushed down to the scan
+    let sql = format!(
+        "SELECT age, name, active, score FROM '{}' WHERE id > 0",
+        test_file.path
+    );
+    let results = query_chunks(&sql);
+    let expected = [
+        ColumnType::Integer,
+        ColumnType::Varchar,
+        ColumnType::Boolean,
+        ColumnType::Float,
+    ];
+    assert!(!results.is_empty());
+    for chunk in &results {
+        assert_eq!(chunk.columns.len(), expected.len());
+        for (i, type_) in expected.iter().enumerate() {
+            assert_eq!(&chunk.columns[i].column_type(), type_, "column {}", i);
+        }
+    }
+}
+
+#[test]
+fn test_projection_follows_select_order() {
+    let test_file = TestFile::new(
+        "projection_order",
+        "id,name,age\n1,ann,30\n2,bob,25\n3,cat,41\n",
+    );
+
+    // age comes after id in the file but first in the SELECT list
+    for sql in [
+        format!("SELECT age, id FROM '{}'", test_file.path),
+        format!(
+            "SELECT age, id FROM '{}' WHERE name != 'bob'",
+            test_file.path
+        ),
+        format!("SELECT age, id FROM '{}' LIMIT 2", test_file.path),
+    ] {
+        let results = query_chunks(&sql);
+        assert_eq!(
+            results[0].get_value(0, 0),
+            Some(Value::Integer(30)),
+            "{}",
+            sql
+        );
+        assert_eq!(
+            results[0].get_value(1, 0),
+            Some(Value::Integer(1)),
+            "{}",
+            sql
+        );
+    }
+}
+
+#[test]
+fn test_repeated_select_columns() {
+    let test_file = TestFile::new(
+        "repeated_columns",
+        "id,name,age\n1,ann,30\n2,bob,25\n3,cat,41\n",
+    );
+
+    // pushdown reads name once; every SELECT slot still gets its own copy
+    let sql = format!(
+        "SELECT name, id, name FROM '{}' WHERE age > 26",
+        test_file.path
+    );
+    let results = query_chunks(&sql);
+    let rows: Vec<Vec<Value>> = results
+        .iter()
+        .flat_map(|chunk| {
+            (0..chunk.selected_count()).map(|row| {
+                (0..3)
+                    .map(|col| chunk.get_value(col, row).unwrap())
+                    .collect()
+            })
+        })
+        .collect();
+    let text = |s: &str| Value::Varchar(s.to_string());
+    assert_eq!(
+        rows,
+        vec![
+            vec![text("ann"), Value::Integer(1), text("ann")],
+            vec![text("cat"), Value::Integer(3), text("cat")],
+        ]
+    );
+    assert_eq!(results[0].columns.len(), 3);
+}
+
+#[test]
+fn test_empty_column_is_null_typed() {
+    let test_file = TestFile::new("empty_column", "id,note\n1,\n2,\n3,\n");
+
+    let sql = format!("SELECT id, note FROM '{}'", test_file.path);
+    let results = query_chunks(&sql);
+    let chunk = &results[0];
+    assert_eq!(chunk.columns[1].column_type(), ColumnType::Null);
+    assert_eq!(chunk.columns[1].len(), 3);
+    assert_eq!(
+        chunk.iter_column(1).collect::<Vec<_>>(),
+        vec![Value::Null, Value::Null, Value::Null]
+    );
+    assert_eq!(chunk.get_value(1, 3), None);
+}
+
+#[test]
+fn test_last_row_without_trailing_newline() {
+    // the final row has no '\n' and is the only one that makes score a float
+    let test_file = TestFile::new("no_trailing_newline", "id,score\n1,10\n2,20\n3,2.5");
+
+    // the full scan runs in parallel, a small LIMIT reads single-threaded
+    for sql in [
+        format!("SELECT score FROM '{}'", test_file.path),
+        format!("SELECT score FROM '{}' LIMIT 10", test_file.path),
+    ] {
+        assert_eq!(
+            query_first_column(&sql),
+            vec![Value::Float(10.0), Value::Float(20.0), Value::Float(2.5)],
+            "{}",
+            sql
+        );
+    }
+}
+
+#[test]
+fn test_empty_varchar_cells_as_null_or_empty_string() {
+    let test_file = TestFile::new("empty_as_null", "id,name,note\n1,ann,\n2,,\n3,cat,\n");
+    let run = |binder: Binder, sql: &str| -> Vec<Vec<Value>> {
+        let query = Parser::new().parse(sql).unwrap();
+        let bound_query = binder.bind(query).unwrap();
+        let logical_plan = Planner::new().plan(bound_query);
+        let optimized_plan = Optimizer::new().optimize(logical_plan);
+        let (operators, schemas) = PhysicalPlanner::new().plan(optimized_plan);
+        PipelineExecutor::new(operators, schemas)
+            .execute()
+            .iter()
+            .flat_map(|chunk| chunk.rows())
+            .collect()
+    };
+    let text = |s: &str| Value::Varchar(s.to_string());
+
+    // the full scan runs in parallel, a small LIMIT reads single-threaded
+    for sql in [
+        format!("SELECT name, note FROM '{}'", test_file.path),
+        format!("SELECT name, note FROM '{}' LIMIT 10", test_file.path),
+    ] {
+        let rows = run(Binder::new(), &sql);
+        assert_eq!(rows[1], vec![Value::Null, Value::Null], "{}", sql);
+        assert_eq!(rows[0][0], text("ann"), "{}", sql);
+
+        // kept as "", an all-empty column is text rather than Null
+        let rows = run(Binder::new().with_empty_as_null(false), &sql);
+        assert_eq!(
+            rows,
+            vec![
+                vec![text("ann"), text("")],
+                vec![text(""), text("")],
+                vec![text("cat"), text("")],
+            ],
+            "{}",
+            sql
+        );
+    }
+
+    let sql = format!("SELECT name FROM '{}' WHERE name = ''", test_file.path);
+    assert_eq!(run(Binder::new(), &sql).len(), 0);
+    assert_eq!(
+        run(Binder::new().with_empty_as_null(false), &sql),
+        vec![vec![text("")]]
+    );
+}
+
+#[test]
+fn test_null_tokens_read_as_null() {
+    let test_file = TestFile::new(
+        "null_tokens",
+        "id,score,tag\n1,10,NA\n2,na,x\n3,N/A,\\N\n4,7,y\n",
+    );
+    let run = |binder: Binder, sql: &str| -> (Vec<ColumnType>, Vec<Vec<Value>>) {
+        let query = Parser::new().parse(sql).unwrap();
+        let bound_query = binder.bind(query).unwrap();
+        let types = bound_query
+            .schema
+            .columns
+            .iter()
+            .map(|c| c.type_.clone())
+            .collect();
+        let logical_plan = Planner::new().plan(bound_query);
+        let optimized_plan = Optimizer::new().optimize(logical_plan);
+        let (operators, schemas) = PhysicalPlanner::new().plan(optimized_plan);
+        let rows = PipelineExecutor::new(operators, schemas)
+            .execute()
+            .iter()
+            .flat_map(|chunk| chunk.rows())
+            .collect();
+        (types, rows)
+    };
+    let tokens = || vec!["NA".to_string(), "N/A".to_string(), "\\N".to_string()];
+    let text = |s: &str| Value::Varchar(s.to_string());
+
+    // the full scan runs in parallel, a small LIMIT reads single-threaded
+    for sql in [
+        format!("SELECT score, tag FROM '{}'", test_file.path),
+        format!("SELECT score, tag FROM '{}' LIMIT 10", test_file.path),
+    ] {
+        let (types, rows) = run(Binder::new().with_null_tokens(tokens()), &sql);
+        assert_eq!(types[1], ColumnType::Integer, "{}", sql);
+        assert_eq!(
+            rows,
+            vec![
+                vec![Value::Integer(10), Value::Null],
+                vec![Value::Null, text("x")],
+                vec![Value::Null, Value::Null],
+                vec![Value::Integer(7), text("y")],
+            ],
+            "{}",
+            sql
+        );
+
+        // by default NA is text, so the column is too
+        let (types, rows) = run(Binder::new(), &sql);
+        assert_eq!(types[1], ColumnType::Varchar, "{}", sql);
+        assert_eq!(rows[1][0], text("na"), "{}", sql);
+    }
+}
+
+#[test]
+fn test_and_or_with_null_operands() {
+    let test_file = TestFile::new("and_or_null", "id,age\n1,\n2,50\n3,10\n");
+
+    // NULL OR true is true and NULL AND false is false, in either order
+    for (sql, expected) in [
+        ("age > 30 OR id = 1", vec![1, 2]),
+        ("id = 1 OR age > 30", vec![1, 2]),
+        ("NOT (age > 30 AND id = 2)", vec![1, 3]),
+        ("NOT (id = 2 AND age > 30)", vec![1, 3]),
+        ("age > 30 OR id = 3", vec![2, 3]),
+    ] {
+        let sql = format!("SELECT id FROM '{}' WHERE {}", test_file.path, sql);
+        assert_eq!(
+            query_first_column(&sql),
+            expected.into_iter().map(Value::Integer).collect::<Vec<_>>(),
+            "{}",
+            sql
+        );
+    }
+}
+
+#[test]
+fn test_is_true_and_is_false_never_unknown() {
+    let test_file = TestFile::new("is_true", "id,active\n1,true\n2,\n3,false\n");
+
+    // = true drops the NULL row on both sides of NOT; IS TRUE keeps it on one
+    for (sql, expected) in [
+        ("active = true", vec![1]),
+        ("NOT (active = true)", vec![3]),
+        ("active IS TRUE", vec![1]),
+        ("active IS NOT TRUE", vec![2, 3]),
+        ("active IS FALSE", vec![3]),
+        ("active is not false", vec![1, 2]),
+        ("(id > 1) IS TRUE AND active IS NOT FALSE", vec![2]),
+    ] {
+        let sql = format!("SELECT id FROM '{}' WHERE {}", test_file.path, sql);
+        assert_eq!(
+            query_first_column(&sql),
+            expected.into_iter().map(Value::Integer).collect::<Vec<_>>(),
+            "{}",
+            sql
+        );
+    }
+
+    // as a value the test is never NULL
+    let sql = format!("SELECT active IS TRUE FROM '{}'", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![
+            Value::Boolean(true),
+            Value::Boolean(false),
+            Value::Boolean(false)
+        ]
+    );
+
+    let sql = format!("SELECT id FROM '{}' WHERE id IS TRUE", test_file.path);
+    let err = Binder::new()
+        .bind(Parser::new().parse(&sql).unwrap())
+        .unwrap_err();
+    assert_eq!(
+        err.message,
+        "IS TRUE requires a Boolean operand, got Integer"
+    );
+}
+
+#[test]
+fn test_query_inline_csv() {
+    let csv = "id,name,age\n1,ann,30\n2,bob,17\n3,cat,45\n";
+    let run = |sql: &str| -> Vec<Vec<Value>> {
+        let query = Parser::new().parse(sql).unwrap();
+        let bound_query = Binder::new().bind_from_str(query, csv, "people").unwrap();
+        let logical_plan = Planner::new().plan(bound_query);
+        let optimized_plan = Optimizer::new().optimize(logical_plan);
+        let (operators, schemas) = PhysicalPlanner::new().plan(optimized_plan);
+        PipelineExecutor::new(operators, schemas)
+            .execute()
+            .iter()
+            .flat_map(|chunk| chunk.rows())
+            .collect()
+    };
+
+    // no file is touched; types are inferred from the text
+    assert_eq!(
+        run("SELECT name, age + 1 FROM people WHERE age >= 18 ORDER BY age DESC"),
+        vec![
+            vec![Value::Varchar("cat".to_string()), Value::Integer(46)],
+            vec![Value::Varchar("ann".to_string()), Value::Integer(31)],
+        ]
+    );
+    assert_eq!(
+        run("SELECT COUNT(*) FROM people"),
+        vec![vec![Value::Integer(3)]]
+    );
+    assert_eq!(
+        run("EXPLAIN SELECT id FROM people")[0][0],
+        Value::Varchar("Get people [id] est_rows=3".to_string())
+    );
+
+    let query = Parser::new().parse("SELECT * FROM other").unwrap();
+    let err = Binder::new()
+        .bind_from_str(query, csv, "people")
+        .unwrap_err();
+    assert_eq!(
+        err.message,
+        "FROM must name the source 'people', found 'other'"
+    );
+}
+
+#[test]
+fn test_evaluate_predicate_on_in_memory_rows() {
+    // a sample row types the columns; the rows filtered never go through a scan
+    let predicate = |filter: &str| {
+        let sql = format!("SELECT * FROM people WHERE {}", filter);
+        let query = Parser::new().parse(&sql).unwrap();
+        Binder::new()
+            .bind_from_str(query, "id,name,age\n1,ann,30\n", "people")
+            .unwrap()
+            .where_clause
+            .unwrap()
+    };
+    let row = |id: i64, name: &str, age: Option<i64>| {
+        vec![
+            Value::Integer(id),
+            Value::Varchar(name.to_string()),
+            age.map_or(Value::Null, Value::Integer),
+        ]
+    };
+    let rows = [
+        row(1, "ann", Some(30)),
+        row(2, "bob", Some(17)),
+        row(3, "cat", None),
+    ];
+
+    for (filter, expected) in [
+        ("age >= 18", [Some(true), Some(false), None]),
+        (
+            "name = 'bob' OR id > 2",
+            [Some(false), Some(true), Some(true)],
+        ),
+        ("age > 18 OR id = 3", [Some(true), Some(false), Some(true)]),
+        ("age > 18 AND id = 3", [Some(false), Some(false), None]),
+        ("NOT (age < 18)", [Some(true), Some(false), None]),
+        ("age + 1 = 31", [Some(true), Some(false), None]),
+        ("name LIKE '%a%'", [Some(true), Some(false), Some(true)]),
+    ] {
+        let predicate = predicate(filter);
+        let results: Vec<Option<bool>> = rows
+            .iter()
+            .map(|row| evaluate_predicate(&predicate, row))
+            .collect();
+        assert_eq!(results, expected, "{}", filter);
+    }
+
+    // a value rather than a predicate is never true
+    let sql = "SELECT age + 1 FROM people";
+    let query = Parser::new().parse(sql).unwrap();
+    let bound_query = Binder::new()
+        .bind_from_str(query, "id,name,age\n1,ann,30\n", "people")
+        .unwrap();
+    assert_eq!(evaluate_predicate(&bound_query.output[0], &rows[0]), None);
+}
+
+/// rows generated on the fly: `n,square` for n in 1..=rows
+#[derive(Debug)]
+struct SquaresSource {
+    rows: i64,
+}
+
+impl DataSource for SquaresSource {
+    fn name(&self) -> String {
+        "squares".to_string()
+    }
+
+    fn read_to_string(&self) -> std::io::Result<String> {
+        let rows: String = (1..=self.rows)
+            .map(|n| format!("{},{}\n", n, n * n))
+            .collect();
+        Ok(format!("n,square\n{}", rows))
+    }
+
+    fn open(&self) -> std::io::Result<Box<dyn std::io::Read + Send>> {
+        Ok(Box::new(std::io::Cursor::new(self.read_to_string()?)))
+    }
+}
+
+#[test]
+fn test_data_sources_share_the_pipeline() {
+    let run = |source: Arc<dyn DataSource>, sql: &str| -> Vec<Vec<Value>> {
+        let query = Parser::new().parse(sql).unwrap();
+        let bound_query = Binder::new().bind_source(query, source).unwrap();
+        let logical_plan = Planner::new().plan(bound_query);
+        let optimized_plan = Optimizer::new().optimize(logical_plan);
+        let (operators, schemas) = PhysicalPlanner::new().plan(optimized_plan);
+        PipelineExecutor::new(operators, schemas)
+            .execute()
+            .iter()
+            .flat_map(|chunk| chunk.rows())
+            .collect()
+    };
+
+    let csv = SquaresSource { rows: 50 }.read_to_string().unwrap();
+    let test_file = TestFile::new("data_sources", &csv);
+    let file_sql = format!(
+        "SELECT n, square FROM '{}' WHERE square % 7 = 0 ORDER BY n DESC",
+        test_file.path
+    );
+    let from_file = run(Arc::new(CsvFileSource::new(&test_file.path)), &file_sql);
+    let from_string = run(
+        Arc::new(StringSource::new("squares", csv.as_str())),
+        "SELECT n, square FROM squares WHERE square % 7 = 0 ORDER BY n DESC",
+    );
+    let generated = run(
+        Arc::new(SquaresSource { rows: 50 }),
+        "SELECT n, square FROM squares WHERE square % 7 = 0 ORDER BY n DESC",
+    );
+
+    let expected: Vec<Vec<Value>> = [49, 42, 35, 28, 21, 14, 7]
+        .into_iter()
+        .map(|n| vec![Value::Integer(n), Value::Integer(n * n)])
+        .collect();
+    assert_eq!(from_file, expected);
+    assert_eq!(from_string, expected);
+    assert_eq!(generated, expected);
+}
+
+#[test]
+fn test_integer_overflow_does_not_fail_the_scan() {
+    // inside the type sample the column becomes Float
+    let sampled = TestFile::new("overflow_sampled", "id,big\n1,5\n2,99999999999999999999\n");
+    let sql = format!("SELECT big FROM '{}'", sampled.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Float(5.0), Value::Float(1e20)]
+    );
+
+    // past the sample the column stays Integer and the cell reads as NULL,
+    // or fails a strict scan with its line
+    let mut contents = String::from("id,big\n");
+    for i in 1..=30 {
+        contents.push_str(&format!("{},{}\n", i, i));
+    }
+    contents.push_str("31,99999999999999999999\n");
+    let late = TestFile::new("overflow_late", &contents);
+    let sql = format!("SELECT big FROM '{}' WHERE id > 29", late.path);
+    assert_eq!(
+        query_first_column(&sql),
+        vec![Value::Integer(30), Value::Null]
+    );
+
+    let query = Parser::new().parse(&sql).unwrap();
+    let plan = Optimizer::new().optimize(Planner::new().plan(Binder::new().bind(query).unwrap()));
+    let (operators, schemas) = PhysicalPlanner::new().with_strict(true).plan(plan);
+    let err = PipelineExecutor::new(operators, schemas)
+        .try_execute()
+        .unwrap_err();
+    assert!(
+        err.message.ends_with(
+            "test_overflow_late.csv:32: column 'big' has value '99999999999999999999', expected Integer"
+        ),
+        "{}",
+        err.message
+    );
+}
+
+#[test]
+fn test_query_engine_reuses_cached_plans() {
+    let file = TestFile::new("plan_cache", "name,age\nAlice,30\nBob,25\n");
+    let sql = format!("SELECT name FROM '{}' WHERE age > 26", file.path);
+    let mut engine = QueryEngine::new();
+
+    let (first, schema) = engine.execute(&sql).unwrap();
+    assert_eq!((engine.cache_hits(), engine.cache_misses()), (0, 1));
+    assert_eq!(schema.names(), vec!["name"]);
+
+    // the same text skips parsing, binding and optimization
+    let (second, _) = engine.execute(&sql).unwrap();
+    assert_eq!((engine.cache_hits(), engine.cache_misses()), (1, 1));
+    assert_eq!(engine.cached_plans(), 1);
+    assert_eq!(first[0].get_value(0, 0), second[0].get_value(0, 0));
+
+    // a rewritten file is planned again, picking up its new type
+    fs::write(&file.path, "name,age\nAlice,30.5\nBob,25\n").unwrap();
+    let later = std::time::SystemTime::now() + std::time::Duration::from_secs(60);
+    fs::File::options()
+        .write(true)
+        .open(&file.path)
+        .unwrap()
+        .set_modified(later)
+        .unwrap();
+    let (third, _) = engine.execute(&sql).unwrap();
+    assert_eq!((engine.cache_hits(), engine.cache_misses()), (1, 2));
+    assert_eq!(engine.cached_plans(), 1);
+    assert_eq!(
+        third[0].get_value(0, 0),
+        Some(Value::Varchar("Alice".to_string()))
+    );
+
+    // no capacity, no caching
+    let mut uncached = QueryEngine::new().with_capacity(0);
+    uncached.execute(&sql).unwrap();
+    uncached.execute(&sql).unwrap();
+    assert_eq!((uncached.cache_hits(), uncached.cache_misses()), (0, 2));
+}
+
+#[test]
+fn test_arithmetic_projection_beside_plain_columns() {
+    let test_file = TestFile::new(
+        "arithmetic_projection",
+        "name,price,qty\nPen,2,10\nInk,5,3\n",
+    );
+    let mut engine = QueryEngine::new();
+
+    let (results, schema) = engine
+        .execute(&format!(
+            "SELECT name, price * qty AS total, qty + 1 FROM '{}'",
+            test_file.path
+        ))
+        .unwrap();
+    assert_eq!(schema.names(), vec!["name", "total", "qty + 1"]);
+    let rows: Vec<Vec<Value>> = results.iter().flat_map(|chunk| chunk.rows()).collect();
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                Value::Varchar("Pen".to_string()),
+                Value::Integer(20),
+                Value::Integer(11)
+            ],
+            vec![
+                Value::Varchar("Ink".to_string()),
+                Value::Integer(15),
+                Value::Integer(4)
+            ],
+        ]
+    );
+
+    // plain column selection is unchanged
+    let (results, schema) = engine
+        .execute(&format!("SELECT qty, name FROM '{}'", test_file.path))
+        .unwrap();
+    assert_eq!(schema.names(), vec!["qty", "name"]);
+    let rows: Vec<Vec<Value>> = results.iter().flat_map(|chunk| chunk.rows()).collect();
+    assert_eq!(
+        rows[1],
+        vec![Value::Integer(3), Value::Varchar("Ink".to_string())]
+    );
+}
+
+#[test]
+fn test_date_column_compares_to_date_literal() {
+    let test_file = TestFile::new(
+        "date_literal",
+        "id,created\n1,2024-03-05\n2,2023-12-31\n3,\n4,2024-01-01\n5,2024-02-29\n",
+    );
+
+    let mut parser = Parser::new();
+    let query = parser
+        .parse(&format!("SELECT * FROM '{}'", test_file.path))
+        .unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    assert_eq!(bound_query.schema.columns[1].type_, ColumnType::Date);
+
+    for (condition, expected) in [
+        ("created > DATE '2024-01-01'", vec![1, 5]),
+        ("created >= date '2024-01-01'", vec![1, 4, 5]),
+        ("created < DATE '2024-01-01'", vec![2]),
+        ("created = DATE '2024-02-29'", vec![5]),
+        ("created != DATE '2024-02-29'", vec![1, 2, 4]),
+    ] {
+        let sql = format!("SELECT id FROM '{}' WHERE {}", test_file.path, condition);
+        assert_eq!(
+            query_first_column(&sql),
+            expected.into_iter().map(Value::Integer).collect::<Vec<_>>(),
+            "{}",
+            sql
+        );
+    }
+
+    // dates sort chronologically, NULL last
+    let sql = format!("SELECT id FROM '{}' ORDER BY created", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        [2, 4, 5, 1, 3].map(Value::Integer).to_vec()
+    );
+}
+
+#[test]
+fn test_malformed_date_literal_fails_to_bind() {
+    let test_file = TestFile::new("date_malformed", "id,created\n1,2024-03-05\n");
+
+    for literal in ["2024-13-45", "2023-02-29", "March 5th"] {
+        let sql = format!(
+            "SELECT id FROM '{}' WHERE created > DATE '{}'",
+            test_file.path, literal
+        );
+        let mut parser = Parser::new();
+        let query = parser.parse(&sql).unwrap();
+        let err = Binder::new().bind(query).unwrap_err();
+        assert_eq!(
+            err.message,
+            format!("Invalid DATE literal '{}' - expected YYYY-MM-DD", literal)
+        );
+    }
+}
+
+#[test]
+fn test_timestamp_column_orders_and_compares_to_literal() {
+    let test_file = TestFile::new(
+        "timestamp_literal",
+        "id,at\n1,2024-01-15 13:45:00\n2,2024-01-15 09:00:00.5\n3,\n4,2024-01-14T23:59:59\n5,2024-01-16\n",
+    );
+
+    // a bare date among timestamps is midnight, not a reason to fall back to text
+    let mut parser = Parser::new();
+    let query = parser
+        .parse(&format!("SELECT * FROM '{}'", test_file.path))
+        .unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    assert_eq!(bound_query.schema.columns[1].type_, ColumnType::Timestamp);
+
+    for (condition, expected) in [
+        ("at >= TIMESTAMP '2024-01-15 09:00:00'", vec![1, 2, 5]),
+        ("at > timestamp '2024-01-15 09:00:00.5'", vec![1, 5]),
+        ("at < TIMESTAMP '2024-01-15'", vec![4]),
+        ("at = TIMESTAMP '2024-01-16 00:00:00'", vec![5]),
+    ] {
+        let sql = format!("SELECT id FROM '{}' WHERE {}", test_file.path, condition);
+        assert_eq!(
+            query_first_column(&sql),
+            expected.into_iter().map(Value::Integer).collect::<Vec<_>>(),
+            "{}",
+            sql
+        );
+    }
+
+    // time of day breaks ties between equal dates, NULL last
+    let sql = format!("SELECT id FROM '{}' ORDER BY at", test_file.path);
+    assert_eq!(
+        query_first_column(&sql),
+        [4, 2, 1, 5, 3].map(Value::Integer).to_vec()
+    );
+
+    let sql = format!(
+        "SELECT id FROM '{}' WHERE at < TIMESTAMP '2024-01-15 24:00:00'",
+        test_file.path
+    );
+    let err = Binder::new()
+        .bind(Parser::new().parse(&sql).unwrap())
+        .unwrap_err();
+    assert_eq!(
+        err.message,
+        "Invalid TIMESTAMP literal '2024-01-15 24:00:00' - expected YYYY-MM-DD HH:MM:SS"
+    );
+}
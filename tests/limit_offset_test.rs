@@ -1,7 +1,12 @@
+use celect::execution::PhysicalScan;
 use celect::{Binder, Optimizer, Parser, Planner};
-use celect::{PhysicalPlanner, PipelineExecutor};
+use celect::{
+    DataChunk, ExecuteResult, PhysicalOperator, PhysicalPlanner, PipelineExecutor, Value,
+};
+use std::cell::RefCell;
 use std::fs;
 use std::io::Write;
+use std::rc::Rc;
 
 struct TestFile {
     path: String,
@@ -83,6 +88,81 @@ fn test_offset_only() {
     assert_eq!(total_rows, 5, "Should return 5 rows after skipping first 5");
 }
 
+#[test]
+fn test_offset_without_limit_returns_remaining_rows() {
+    let contents: String = (1..=10).fold("id\n".to_string(), |mut acc, i| {
+        acc.push_str(&format!("{}\n", i));
+        acc
+    });
+    let test_file = TestFile::new("offset_without_limit", &contents);
+
+    let sql = format!("SELECT id FROM '{}' OFFSET 3", test_file.path);
+    let chunks = execute_query(&sql).unwrap();
+    let ids: Vec<Value> = chunks.iter().flat_map(|c| c.iter_column(0)).collect();
+    assert_eq!(ids, (4..=10).map(Value::Integer).collect::<Vec<_>>());
+
+    // the skipped rows span several chunks
+    let contents: String = (1..=5000).fold("id\n".to_string(), |mut acc, i| {
+        acc.push_str(&format!("{}\n", i));
+        acc
+    });
+    let test_file = TestFile::new("offset_without_limit_chunks", &contents);
+
+    let sql = format!("SELECT id FROM '{}' OFFSET 2500", test_file.path);
+    let chunks = execute_query(&sql).unwrap();
+    let ids: Vec<Value> = chunks.iter().flat_map(|c| c.iter_column(0)).collect();
+    assert_eq!(ids, (2501..=5000).map(Value::Integer).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_offset_above_blocking_operators() {
+    let test_file = TestFile::new(
+        "offset_blocking",
+        "id,city\n1,Oslo\n2,Lima\n3,Oslo\n4,Rome\n5,Lima\n",
+    );
+    let rows = |sql: &str| -> Vec<Vec<Value>> {
+        let sql = sql.replace("{}", &test_file.path);
+        let chunks = execute_query(&sql).unwrap();
+        chunks
+            .iter()
+            .flat_map(|c| c.selected_rows_as_values())
+            .collect()
+    };
+    let int = |values: &[i64]| -> Vec<Vec<Value>> {
+        values.iter().map(|&v| vec![Value::Integer(v)]).collect()
+    };
+
+    // sort
+    assert_eq!(
+        rows("SELECT id FROM '{}' ORDER BY id DESC OFFSET 2"),
+        int(&[3, 2, 1])
+    );
+    assert_eq!(
+        rows("SELECT id FROM '{}' ORDER BY id LIMIT ALL OFFSET 3"),
+        int(&[4, 5])
+    );
+    assert_eq!(rows("SELECT id FROM '{}' ORDER BY id OFFSET 5"), int(&[]));
+
+    // hash aggregate, groups in first-seen order
+    let city = |name: &str, n: i64| vec![Value::Varchar(name.to_string()), Value::Integer(n)];
+    assert_eq!(
+        rows("SELECT city, COUNT(*) FROM '{}' GROUP BY city OFFSET 1"),
+        vec![city("Lima", 2), city("Rome", 1)]
+    );
+    assert_eq!(
+        rows("SELECT city, COUNT(*) FROM '{}' GROUP BY city LIMIT ALL OFFSET 2"),
+        vec![city("Rome", 1)]
+    );
+
+    // ungrouped aggregate: a single row
+    assert_eq!(rows("SELECT SUM(id) FROM '{}' OFFSET 0"), int(&[15]));
+    assert_eq!(
+        rows("SELECT SUM(id) FROM '{}' LIMIT ALL OFFSET 0"),
+        int(&[15])
+    );
+    assert_eq!(rows("SELECT SUM(id) FROM '{}' OFFSET 1"), int(&[]));
+}
+
 #[test]
 fn test_offset_zero() {
     let test_file = TestFile::new("offset_zero", "name,age\nAlice,25\nBob,30\nCharlie,35\n");
@@ -131,6 +211,36 @@ fn test_limit_and_offset() {
     assert_eq!(total_rows, 3, "Should return exactly 3 rows (LIMIT 3)");
 }
 
+#[test]
+fn test_limit_offset_window_values() {
+    let contents: String = (0..10).fold("id\n".to_string(), |mut acc, i| {
+        acc.push_str(&format!("{}\n", i));
+        acc
+    });
+    let test_file = TestFile::new("limit_offset_window", &contents);
+
+    // offset is applied before the limit starts counting
+    let sql = format!("SELECT id FROM '{}' LIMIT 3 OFFSET 2", test_file.path);
+    let chunks = execute_query(&sql).unwrap();
+    let ids: Vec<Value> = chunks.iter().flat_map(|c| c.iter_column(0)).collect();
+    assert_eq!(
+        ids,
+        vec![Value::Integer(2), Value::Integer(3), Value::Integer(4)]
+    );
+
+    // the window straddles the boundary between the first two chunks
+    let contents: String = (0..5000).fold("id\n".to_string(), |mut acc, i| {
+        acc.push_str(&format!("{}\n", i));
+        acc
+    });
+    let test_file = TestFile::new("limit_offset_window_chunks", &contents);
+
+    let sql = format!("SELECT id FROM '{}' LIMIT 4 OFFSET 2046", test_file.path);
+    let chunks = execute_query(&sql).unwrap();
+    let ids: Vec<Value> = chunks.iter().flat_map(|c| c.iter_column(0)).collect();
+    assert_eq!(ids, (2046..2050).map(Value::Integer).collect::<Vec<_>>());
+}
+
 #[test]
 fn test_limit_and_offset_pagination() {
     let test_file = TestFile::new(
@@ -319,6 +429,80 @@ fn test_limit_offset_complex_filter() {
     assert_eq!(total_rows, 2, "Should return 2 rows from filtered results");
 }
 
+#[test]
+fn test_limit_with_filter_stops_scan_early() {
+    let mut contents = String::from("id,value\n");
+    for i in 0..10000 {
+        contents.push_str(&format!("{},{}\n", i, i * 2));
+    }
+    let test_file = TestFile::new("limit_filter_early", &contents);
+
+    let sql = format!(
+        "SELECT id, value FROM '{}' WHERE id > 100 LIMIT 5",
+        test_file.path
+    );
+    let (chunks, rows_scanned) = execute_query_with_scan_count(&sql).unwrap();
+
+    let ids: Vec<celect::Value> = chunks.iter().flat_map(|c| c.iter_column(0)).collect();
+    let expected: Vec<celect::Value> = (101..106).map(celect::Value::Integer).collect();
+    assert_eq!(
+        ids, expected,
+        "Should return exactly the first 5 matching rows"
+    );
+    assert!(
+        rows_scanned < 10000,
+        "Scan should stop before reading the whole file, read {} rows",
+        rows_scanned
+    );
+}
+
+#[test]
+fn test_limit_with_filter_spanning_chunks() {
+    let mut contents = String::from("id,value\n");
+    for i in 0..10000 {
+        contents.push_str(&format!("{},{}\n", i, i * 2));
+    }
+    let test_file = TestFile::new("limit_filter_chunks", &contents);
+
+    // needs more than one 2048-row chunk to satisfy the limit
+    let sql = format!(
+        "SELECT id FROM '{}' WHERE id > 100 LIMIT 3000",
+        test_file.path
+    );
+    let (chunks, rows_scanned) = execute_query_with_scan_count(&sql).unwrap();
+
+    let total_rows: usize = chunks.iter().map(|c| c.selected_count()).sum();
+    assert_eq!(total_rows, 3000, "Should return exactly 3000 rows");
+    assert!(
+        rows_scanned < 10000,
+        "Scan should stop before reading the whole file, read {} rows",
+        rows_scanned
+    );
+}
+
+#[test]
+fn test_limit_with_selective_filter() {
+    let mut contents = String::from("id,value\n");
+    for i in 0..10000 {
+        contents.push_str(&format!("{},{}\n", i, i * 2));
+    }
+    let test_file = TestFile::new("limit_selective_filter", &contents);
+
+    // matching rows sit at the very end of the file
+    let sql = format!(
+        "SELECT id FROM '{}' WHERE id >= 9990 LIMIT 5",
+        test_file.path
+    );
+    let (chunks, _) = execute_query_with_scan_count(&sql).unwrap();
+
+    let ids: Vec<celect::Value> = chunks.iter().flat_map(|c| c.iter_column(0)).collect();
+    let expected: Vec<celect::Value> = (9990..9995).map(celect::Value::Integer).collect();
+    assert_eq!(
+        ids, expected,
+        "Selective filter should still fill the limit"
+    );
+}
+
 // helper function to execute a query and return chunks
 fn execute_query(sql: &str) -> Result<Vec<celect::DataChunk>, String> {
     // parse
@@ -345,3 +529,87 @@ fn execute_query(sql: &str) -> Result<Vec<celect::DataChunk>, String> {
     let mut executor = PipelineExecutor::new(operators, schemas);
     Ok(executor.execute())
 }
+
+// helper function to execute a query and also report how many rows the scan produced
+fn execute_query_with_scan_count(sql: &str) -> Result<(Vec<celect::DataChunk>, usize), String> {
+    let mut parser = Parser::new();
+    let query = parser.parse(sql).map_err(|e| e.message)?;
+
+    let binder = Binder::new();
+    let bound_query = binder.bind(query).map_err(|e| e.message)?;
+
+    let planner = Planner::new();
+    let logical_plan = planner.plan(bound_query);
+
+    let optimizer = Optimizer::new();
+    let optimized_plan = optimizer.optimize(logical_plan);
+
+    let physical_planner = PhysicalPlanner::new();
+    let (operators, schemas) = physical_planner.plan(optimized_plan);
+
+    let mut executor = PipelineExecutor::new(operators, schemas);
+    let chunks = executor.execute();
+    Ok((chunks, executor.rows_scanned()))
+}
+
+#[test]
+fn test_scan_reads_at_most_max_rows() {
+    let mut contents = String::from("id,value\n");
+    for i in 0..1_000_000 {
+        contents.push_str(&format!("{},{}\n", i, i % 7));
+    }
+    let test_file = TestFile::new("scan_max_rows", &contents);
+
+    // LIMIT 5 is pushed down to the scan as max_rows
+    let sql = format!("SELECT id FROM '{}' LIMIT 5", test_file.path);
+    let (chunks, rows_scanned) = execute_query_with_scan_count(&sql).unwrap();
+    let total_rows: usize = chunks.iter().map(|c| c.selected_count()).sum();
+    assert_eq!(total_rows, 5);
+    assert_eq!(rows_scanned, 5);
+
+    // driven directly, the scan finishes on its first call without reading past max_rows
+    let query = Parser::new().parse(&sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let mut scan = PhysicalScan::new(bound_query.file_path, bound_query.schema, None, Some(5));
+    let mut output = DataChunk::empty();
+    let result = scan.execute(&DataChunk::empty(), &mut output);
+    assert!(matches!(result, ExecuteResult::Finished));
+    assert_eq!(output.count, 5);
+    assert_eq!(scan.rows_read(), 5);
+}
+
+#[test]
+fn test_progress_reports_scanned_rows() {
+    let mut contents = String::from("id,value\n");
+    for i in 0..20_000 {
+        contents.push_str(&format!("{},{}\n", i, i % 7));
+    }
+    let test_file = TestFile::new("scan_progress", &contents);
+
+    let sql = format!("SELECT id FROM '{}' WHERE value = 3", test_file.path);
+    let query = Parser::new().parse(&sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+    let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+
+    let reports = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&reports);
+    let mut executor = PipelineExecutor::new(operators, schemas)
+        .with_progress(2, move |rows| sink.borrow_mut().push(rows));
+    executor.execute();
+
+    // counts only grow, and the last one covers every data row of the file
+    let reports = reports.borrow();
+    assert!(reports.len() > 1, "{:?}", reports);
+    assert!(reports.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(reports.last(), Some(&20_000));
+    assert_eq!(executor.rows_scanned(), 20_000);
+
+    // the scan counts the rows it hands over as well
+    let query = Parser::new().parse(&sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let mut scan = PhysicalScan::new(bound_query.file_path, bound_query.schema, None, None);
+    let mut output = DataChunk::empty();
+    while scan.execute(&DataChunk::empty(), &mut output) != ExecuteResult::Finished {}
+    assert_eq!(scan.rows_read(), 20_000);
+}
@@ -1,6 +1,9 @@
 use celect::parser::LiteralValue;
 use celect::planner::LogicalOperator;
-use celect::{Binder, BoundExpression, Optimizer, Parser, Planner};
+use celect::{
+    Binder, BoundExpression, ColumnType, Optimizer, Parser, PhysicalPlanner, PipelineExecutor,
+    Planner, Value,
+};
 use std::fs;
 use std::io::Write;
 use std::path::PathBuf;
@@ -197,19 +200,15 @@ mod tests {
         let optimizer = Optimizer::new();
         let optimized_plan = optimizer.optimize(plan);
 
-        // should have 2 columns
-        if let LogicalOperator::Projection(proj) = optimized_plan {
-            if let LogicalOperator::Get(get) = *proj.child {
-                assert_eq!(get.columns.len(), 2, "Should only have id and name");
+        // should have 2 columns, output as read (the projection is dropped)
+        if let LogicalOperator::Get(get) = optimized_plan {
+            assert_eq!(get.columns.len(), 2, "Should only have id and name");
 
-                let names: Vec<&str> = get.columns.iter().map(|c| c.name.as_str()).collect();
-                assert!(names.contains(&"id"));
-                assert!(names.contains(&"name"));
-            } else {
-                panic!("Expected Get operator");
-            }
+            let names: Vec<&str> = get.columns.iter().map(|c| c.name.as_str()).collect();
+            assert!(names.contains(&"id"));
+            assert!(names.contains(&"name"));
         } else {
-            panic!("Expected Projection operator");
+            panic!("Expected Get operator");
         }
     }
 
@@ -395,19 +394,16 @@ mod tests {
         let optimizer = Optimizer::new();
         let optimized_plan = optimizer.optimize(plan);
 
-        // filter should be completely removed!
-        if let LogicalOperator::Projection(proj) = optimized_plan {
-            match *proj.child {
-                LogicalOperator::Get(_) => {
-                    // correct - Filter was removed, goes straight to Get
-                }
-                LogicalOperator::Filter(_) => {
-                    panic!("Filter with constant true should have been removed");
-                }
-                _ => panic!("Unexpected operator"),
+        // filter should be completely removed! (and with it the projection of
+        // name, now the only column read)
+        match optimized_plan {
+            LogicalOperator::Get(_) => {
+                // correct - Filter was removed, goes straight to Get
             }
-        } else {
-            panic!("Expected Projection operator");
+            LogicalOperator::Filter(_) => {
+                panic!("Filter with constant true should have been removed");
+            }
+            _ => panic!("Unexpected operator"),
         }
     }
 
@@ -964,6 +960,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_constant_folding_modulo_and_negation() {
+        // where id % 4 = 7 % 4 AND id > -(2) → WHERE (id % 4) = 3 AND id > -2
+        let _guard = TestFileGuard::new("test_const_modulo.csv", "id,name\n1,Alice\n");
+
+        let sql = "SELECT name FROM 'test_const_modulo.csv' WHERE id % 4 = 7 % 4 AND id > -(2)";
+
+        let mut parser = Parser::new();
+        let query = parser.parse(sql).unwrap();
+
+        let binder = Binder::new();
+        let bound_query = binder.bind(query).unwrap();
+
+        let planner = Planner::new();
+        let plan = planner.plan(bound_query);
+
+        let optimizer = Optimizer::new();
+        let optimized_plan = optimizer.optimize(plan);
+
+        let LogicalOperator::Projection(proj) = optimized_plan else {
+            panic!("expected a projection");
+        };
+        let LogicalOperator::Filter(filter) = *proj.child else {
+            panic!("expected a filter");
+        };
+        let BoundExpression::And(left, right) = filter.expression else {
+            panic!("expected AND, got {}", filter.expression);
+        };
+        // the cheaper comparison is moved to the left of the AND
+        match (*left, *right) {
+            (BoundExpression::GreaterThan(_, negated), BoundExpression::Equal(modulo, folded)) => {
+                assert!(matches!(*modulo, BoundExpression::Modulo(..)));
+                assert_eq!(
+                    *folded,
+                    BoundExpression::Literal {
+                        value: LiteralValue::Integer(3),
+                        type_: ColumnType::Integer,
+                    }
+                );
+                assert_eq!(
+                    *negated,
+                    BoundExpression::Literal {
+                        value: LiteralValue::Integer(-2),
+                        type_: ColumnType::Integer,
+                    }
+                );
+            }
+            other => panic!("unexpected filter shape: {:?}", other),
+        }
+    }
+
     #[test]
     fn test_double_not_elimination() {
         // where NOT NOT (age > 25) → WHERE age > 25
@@ -1095,4 +1142,392 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_prefix_like_becomes_range() {
+        // where name LIKE 'abc%' → WHERE name >= 'abc' AND name < 'abd'
+        let _guard = TestFileGuard::new("test_prefix_like.csv", "id,name\n1,abcd\n2,abd\n");
+
+        let sql = "SELECT id FROM 'test_prefix_like.csv' WHERE name LIKE 'abc%'";
+
+        let mut parser = Parser::new();
+        let query = parser.parse(sql).unwrap();
+
+        let binder = Binder::new();
+        let bound_query = binder.bind(query).unwrap();
+
+        let planner = Planner::new();
+        let plan = planner.plan(bound_query);
+
+        let optimizer = Optimizer::new();
+        let optimized_plan = optimizer.optimize(plan);
+
+        let string_literal = |expr: &BoundExpression| match expr {
+            BoundExpression::Literal {
+                value: LiteralValue::String(s),
+                ..
+            } => s.clone(),
+            other => panic!("expected string literal, got {:?}", other),
+        };
+
+        if let LogicalOperator::Projection(proj) = optimized_plan {
+            if let LogicalOperator::Filter(filter) = *proj.child {
+                match filter.expression {
+                    BoundExpression::And(left, right) => match (*left, *right) {
+                        (
+                            BoundExpression::GreaterThanOrEqual(_, lower),
+                            BoundExpression::LessThan(_, upper),
+                        ) => {
+                            assert_eq!(string_literal(&lower), "abc");
+                            assert_eq!(string_literal(&upper), "abd");
+                        }
+                        other => panic!("expected range predicate, got {:?}", other),
+                    },
+                    other => panic!("prefix LIKE should become a range, got {:?}", other),
+                }
+            } else {
+                panic!("expected Filter under Projection");
+            }
+        } else {
+            panic!("expected Projection at the root");
+        }
+    }
+
+    #[test]
+    fn test_non_prefix_like_is_kept() {
+        // wildcards before the end can't be expressed as a range
+        let _guard = TestFileGuard::new("test_non_prefix_like.csv", "id,name\n1,abcd\n");
+
+        let sql = "SELECT id FROM 'test_non_prefix_like.csv' WHERE name LIKE 'a_c%'";
+
+        let mut parser = Parser::new();
+        let query = parser.parse(sql).unwrap();
+
+        let binder = Binder::new();
+        let bound_query = binder.bind(query).unwrap();
+
+        let planner = Planner::new();
+        let plan = planner.plan(bound_query);
+
+        let optimizer = Optimizer::new();
+        let optimized_plan = optimizer.optimize(plan);
+
+        if let LogicalOperator::Projection(proj) = optimized_plan {
+            if let LogicalOperator::Filter(filter) = *proj.child {
+                assert!(
+                    matches!(filter.expression, BoundExpression::Like(_, _, None)),
+                    "LIKE with inner wildcards should not be rewritten"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_explain_filtered_projection() {
+        let _guard = TestFileGuard::new(
+            "test_optimizer_explain.csv",
+            "id,name,age,alive\n1,Alice,30,true\n2,Bob,25,false\n",
+        );
+
+        let sql = "SELECT name FROM 'test_optimizer_explain.csv' WHERE age > 25 AND name != 'Bob' LIMIT 5";
+        let mut parser = Parser::new();
+        let query = parser.parse(sql).unwrap();
+        let bound_query = Binder::new().bind(query).unwrap();
+        let file_path = bound_query.file_path.clone();
+
+        let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+
+        // the optimized plan reads only the referenced columns
+        let expected = format!(
+            "Limit 5\n  Projection [name]\n    Filter (age > 25) AND (name != 'Bob')\n      Get {} [name, age]",
+            file_path.display()
+        );
+        assert_eq!(plan.explain(), expected);
+    }
+
+    #[test]
+    fn test_explain_query_prefix() {
+        let _guard = TestFileGuard::new(
+            "test_optimizer_explain_prefix.csv",
+            "id,name,age\n1,Alice,30\n",
+        );
+
+        let sql = "EXPLAIN SELECT COUNT(*) FROM 'test_optimizer_explain_prefix.csv' WHERE age > 25";
+        let mut parser = Parser::new();
+        let query = parser.parse(sql).unwrap();
+        assert!(query.explain);
+        let bound_query = Binder::new().bind(query).unwrap();
+        let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+
+        // the plan is returned as one Varchar row; the file is never scanned
+        let (operators, schemas) = PhysicalPlanner::new().plan(plan.clone());
+        assert_eq!(schemas.len(), 1);
+        assert_eq!(schemas[0].names(), vec!["plan".to_string()]);
+        assert_eq!(schemas[0].types(), vec![ColumnType::Varchar]);
+        let results = PipelineExecutor::new(operators, schemas).execute();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].selected_count(), 1);
+
+        let LogicalOperator::Explain(explain) = plan else {
+            panic!("expected an Explain root, got {:?}", plan);
+        };
+        assert_eq!(
+            results[0].get_value(0, 0),
+            Some(Value::Varchar(explain.child.explain()))
+        );
+        assert!(
+            explain
+                .child
+                .explain()
+                .starts_with("Aggregate [COUNT(*)] est_rows=1\n  Filter age > 25")
+        );
+    }
+
+    #[test]
+    fn test_explain_row_estimates() {
+        // 30 data rows, the last one without a trailing newline
+        let mut contents = "id,name,age\n".to_string();
+        let rows: Vec<String> = (0..30).map(|i| format!("{},p{},{}", i, i, i)).collect();
+        contents.push_str(&rows.join("\n"));
+        let _guard = TestFileGuard::new("test_optimizer_explain_estimates.csv", &contents);
+
+        let sql = "EXPLAIN SELECT name FROM 'test_optimizer_explain_estimates.csv' WHERE age > 25 LIMIT 5";
+        let mut parser = Parser::new();
+        let query = parser.parse(sql).unwrap();
+        let bound_query = Binder::new().bind(query).unwrap();
+        let file_path = bound_query.file_path.clone();
+        let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+
+        let LogicalOperator::Explain(explain) = plan else {
+            panic!("expected an Explain root, got {:?}", plan);
+        };
+        let expected = format!(
+            "Limit 5 est_rows=5\n  Projection [name] est_rows=10\n    Filter age > 25 selectivity=0.33 est_rows=10\n      Get {} [name, age] est_rows=30",
+            file_path.display()
+        );
+        assert_eq!(explain.child.explain(), expected);
+
+        // plain optimization leaves the estimates out
+        let query = parser
+            .parse("SELECT name FROM 'test_optimizer_explain_estimates.csv' WHERE age > 25")
+            .unwrap();
+        let plan =
+            Optimizer::new().optimize(Planner::new().plan(Binder::new().bind(query).unwrap()));
+        assert!(!plan.explain().contains("est_rows"));
+    }
+
+    #[test]
+    fn test_explain_analyze_reports_operator_metrics() {
+        let mut contents = "id,name,age\n".to_string();
+        for i in 0..30 {
+            contents.push_str(&format!("{},p{},{}\n", i, i, i));
+        }
+        let _guard = TestFileGuard::new("test_optimizer_explain_analyze.csv", &contents);
+
+        let sql =
+            "EXPLAIN ANALYZE SELECT name FROM 'test_optimizer_explain_analyze.csv' WHERE age > 25";
+        let query = Parser::new().parse(sql).unwrap();
+        assert!(query.explain && query.analyze);
+        let bound_query = Binder::new().bind(query).unwrap();
+        let plan = Optimizer::new().optimize(Planner::new().plan(bound_query));
+        assert!(plan.explain().starts_with("Explain Analyze\n"));
+
+        // the query runs, and each operator's line gets what it output
+        let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+        let results = PipelineExecutor::new(operators, schemas).execute();
+        let Some(Value::Varchar(analyzed)) = results[0].get_value(0, 0) else {
+            panic!("expected the plan text, got {:?}", results);
+        };
+        let lines: Vec<&str> = analyzed.lines().collect();
+        assert_eq!(lines.len(), 3, "{}", analyzed);
+        for (line, (operator, rows)) in
+            lines
+                .iter()
+                .zip([("Projection", 4), ("Filter", 4), ("Get", 30)])
+        {
+            assert!(line.trim_start().starts_with(operator), "{}", line);
+            assert!(line.contains(&format!(" rows={} time=", rows)), "{}", line);
+            assert!(line.ends_with("ms"), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_top_n_fuses_sort_and_limit() {
+        let mut contents = "id,score\n".to_string();
+        for i in 0..500 {
+            contents.push_str(&format!("{},{}\n", i, (i * 37) % 50));
+        }
+        let _guard = TestFileGuard::new("test_optimizer_top_n.csv", &contents);
+
+        let run = |sql: &str| -> (String, Vec<Value>) {
+            let query = Parser::new().parse(sql).unwrap();
+            let plan =
+                Optimizer::new().optimize(Planner::new().plan(Binder::new().bind(query).unwrap()));
+            let explain = plan.explain();
+            let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+            let ids = PipelineExecutor::new(operators, schemas)
+                .execute()
+                .iter()
+                .flat_map(|chunk| chunk.iter_column(0))
+                .collect();
+            (explain, ids)
+        };
+
+        let (plan, top_n) =
+            run("SELECT id FROM 'test_optimizer_top_n.csv' ORDER BY score DESC LIMIT 10 OFFSET 5");
+        let (_, sorted) = run("SELECT id FROM 'test_optimizer_top_n.csv' ORDER BY score DESC");
+
+        // the limit and offset move into the top-n below the output projection
+        assert!(
+            plan.starts_with("Projection [id]\n  TopN 10 offset 5 [score DESC]\n    Get"),
+            "{}",
+            plan
+        );
+        assert_eq!(top_n, sorted[5..15].to_vec());
+    }
+
+    #[test]
+    fn test_cheap_or_operand_is_evaluated_first() {
+        let mut contents = "id,name,age\n".to_string();
+        for i in 0..300 {
+            // every seventh name and every fifth age is missing
+            let name = if i % 7 == 0 {
+                String::new()
+            } else {
+                format!("n{}x", i % 11)
+            };
+            let age = if i % 5 == 0 {
+                String::new()
+            } else {
+                (i % 40).to_string()
+            };
+            contents.push_str(&format!("{},{},{}\n", i, name, age));
+        }
+        let _guard = TestFileGuard::new("test_optimizer_or_order.csv", &contents);
+
+        let run = |sql: &str, optimize: bool| -> (String, Vec<Value>) {
+            let query = Parser::new().parse(sql).unwrap();
+            let mut plan = Planner::new().plan(Binder::new().bind(query).unwrap());
+            if optimize {
+                plan = Optimizer::new().optimize(plan);
+            }
+            let explain = plan.explain();
+            let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+            let ids = PipelineExecutor::new(operators, schemas)
+                .execute()
+                .iter()
+                .flat_map(|chunk| chunk.iter_column(0))
+                .collect();
+            (explain, ids)
+        };
+
+        for sql in [
+            "SELECT id FROM 'test_optimizer_or_order.csv' WHERE UPPER(name) LIKE '%3X' OR age = 12",
+            "SELECT id FROM 'test_optimizer_or_order.csv' WHERE (age > 5 AND name = 'n1x') OR id < 20",
+            "SELECT id FROM 'test_optimizer_or_order.csv' WHERE NOT (LOWER(name) = 'n2x' OR age = 3)",
+        ] {
+            let (plan, optimized) = run(sql, true);
+            let (_, unoptimized) = run(sql, false);
+            assert_eq!(optimized, unoptimized, "{}", plan);
+            assert!(!optimized.is_empty(), "{}", sql);
+        }
+
+        // the column-vs-literal comparison moves in front of the costlier side
+        let (plan, _) = run(
+            "SELECT id FROM 'test_optimizer_or_order.csv' WHERE UPPER(name) LIKE '%3X' OR age = 12",
+            true,
+        );
+        assert!(
+            plan.contains("Filter (age = 12) OR (UPPER(name) LIKE '%3X')"),
+            "{}",
+            plan
+        );
+        let (plan, _) = run(
+            "SELECT id FROM 'test_optimizer_or_order.csv' WHERE (age > 5 AND name = 'n1x') OR id < 20",
+            true,
+        );
+        assert!(
+            plan.contains("Filter (id < 20) OR ((age > 5) AND (name = 'n1x'))"),
+            "{}",
+            plan
+        );
+    }
+
+    #[test]
+    fn test_identity_projection_is_removed() {
+        let _guard = TestFileGuard::new(
+            "test_optimizer_identity.csv",
+            "id,name,age\n1,Alice,30\n2,Bob,25\n",
+        );
+        let optimize = |sql: &str| {
+            let query = Parser::new().parse(sql).unwrap();
+            let bound_query = Binder::new().bind(query).unwrap();
+            Optimizer::new().optimize(Planner::new().plan(bound_query))
+        };
+
+        // SELECT * outputs the scanned columns unchanged
+        let plan = optimize("SELECT * FROM 'test_optimizer_identity.csv'");
+        let LogicalOperator::Get(get) = &plan else {
+            panic!("Expected Get operator, got {:?}", plan);
+        };
+        assert_eq!(get.columns.len(), 3);
+        let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+        let mut executor = PipelineExecutor::new(operators, schemas);
+        let results = executor.execute();
+        assert_eq!(executor.output_schema().names(), vec!["id", "name", "age"]);
+        assert_eq!(
+            results[0].get_value(1, 1),
+            Some(Value::Varchar("Bob".to_string()))
+        );
+
+        // reordered, renamed, computed or filtered output keeps its projection
+        for sql in [
+            "SELECT name, id FROM 'test_optimizer_identity.csv'",
+            "SELECT id AS key, name, age FROM 'test_optimizer_identity.csv'",
+            "SELECT id, name, age + 1 FROM 'test_optimizer_identity.csv'",
+            "SELECT * FROM 'test_optimizer_identity.csv' WHERE age > 26",
+        ] {
+            assert!(
+                matches!(optimize(sql), LogicalOperator::Projection(_)),
+                "{}",
+                sql
+            );
+        }
+    }
+
+    #[test]
+    fn test_constant_false_filter_skips_the_scan() {
+        let mut contents = "id,name\n".to_string();
+        for i in 0..50 {
+            contents.push_str(&format!("{},p{}\n", i, i));
+        }
+        let _guard = TestFileGuard::new("test_optimizer_false_filter.csv", &contents);
+        let run = |sql: &str| {
+            let query = Parser::new().parse(sql).unwrap();
+            let plan =
+                Optimizer::new().optimize(Planner::new().plan(Binder::new().bind(query).unwrap()));
+            let explain = plan.explain();
+            let (operators, schemas) = PhysicalPlanner::new().plan(plan);
+            (explain, PipelineExecutor::new(operators, schemas).execute())
+        };
+
+        let (plan, results) = run("SELECT name FROM 'test_optimizer_false_filter.csv' WHERE 1 = 2");
+        assert!(plan.contains("Filter false"), "{}", plan);
+        assert!(plan.contains("max_rows=0"), "{}", plan);
+        assert_eq!(results.iter().map(|c| c.selected_count()).sum::<usize>(), 0);
+
+        // the scan outputs nothing: no row of the file is read
+        let (_, results) =
+            run("EXPLAIN ANALYZE SELECT name FROM 'test_optimizer_false_filter.csv' WHERE 1 = 2");
+        let Some(Value::Varchar(analyzed)) = results[0].get_value(0, 0) else {
+            panic!("expected the plan text, got {:?}", results);
+        };
+        let get = analyzed.lines().last().unwrap();
+        assert!(get.trim_start().starts_with("Get"), "{}", analyzed);
+        assert!(get.contains(" rows=0 time="), "{}", analyzed);
+
+        let (_, results) =
+            run("SELECT COUNT(*) FROM 'test_optimizer_false_filter.csv' WHERE 1 = 2");
+        assert_eq!(results[0].get_value(0, 0), Some(Value::Integer(0)));
+    }
 }
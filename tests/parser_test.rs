@@ -1,5 +1,5 @@
-use celect::Parser;
-use celect::parser::{Expression, LiteralValue, SelectColumn};
+use celect::{ColumnType, Parser};
+use celect::parser::{AggregateFunction, Expression, LiteralValue, OrderBy, SelectColumn};
 
 #[cfg(test)]
 mod tests {
@@ -13,7 +13,7 @@ mod tests {
         assert!(result.is_ok());
         let query = result.unwrap();
         assert_eq!(query.select.columns, vec![SelectColumn::All]);
-        assert_eq!(query.from.file, "users");
+        assert_eq!(query.from.files, vec!["users"]);
         assert_eq!(query.where_clause, None);
     }
 
@@ -27,12 +27,12 @@ mod tests {
         assert_eq!(
             query.select.columns,
             vec![
-                SelectColumn::Column("id".to_string()),
-                SelectColumn::Column("name".to_string()),
-                SelectColumn::Column("email".to_string()),
+                SelectColumn::Expression(Expression::Column("id".to_string()), None),
+                SelectColumn::Expression(Expression::Column("name".to_string()), None),
+                SelectColumn::Expression(Expression::Column("email".to_string()), None),
             ]
         );
-        assert_eq!(query.from.file, "users");
+        assert_eq!(query.from.files, vec!["users"]);
         assert_eq!(query.where_clause, None);
     }
 
@@ -44,7 +44,7 @@ mod tests {
         assert!(result.is_ok());
         let query = result.unwrap();
         assert_eq!(query.select.columns, vec![SelectColumn::All]);
-        assert_eq!(query.from.file, "users");
+        assert_eq!(query.from.files, vec!["users"]);
         assert!(query.where_clause.is_some());
         let where_clause = query.where_clause.unwrap();
         assert!(matches!(where_clause.condition, Expression::Equal(_, _)));
@@ -60,11 +60,11 @@ mod tests {
         assert_eq!(
             query.select.columns,
             vec![
-                SelectColumn::Column("name".to_string()),
-                SelectColumn::Column("email".to_string()),
+                SelectColumn::Expression(Expression::Column("name".to_string()), None),
+                SelectColumn::Expression(Expression::Column("email".to_string()), None),
             ]
         );
-        assert_eq!(query.from.file, "users");
+        assert_eq!(query.from.files, vec!["users"]);
         assert!(query.where_clause.is_some());
         let where_clause = query.where_clause.unwrap();
         assert!(matches!(
@@ -384,8 +384,8 @@ mod tests {
         assert_eq!(
             query.select.columns,
             vec![
-                SelectColumn::Column("name".to_string()),
-                SelectColumn::Column("age".to_string()),
+                SelectColumn::Expression(Expression::Column("name".to_string()), None),
+                SelectColumn::Expression(Expression::Column("age".to_string()), None),
             ]
         );
     }
@@ -400,7 +400,10 @@ mod tests {
 
         assert_eq!(
             query.select.columns,
-            vec![SelectColumn::Column("id".to_string()),]
+            vec![SelectColumn::Expression(
+                Expression::Column("id".to_string()),
+                None
+            )]
         );
     }
 
@@ -437,9 +440,9 @@ mod tests {
         assert_eq!(
             query.select.columns,
             vec![
-                SelectColumn::Column("id".to_string()),
-                SelectColumn::Column("name".to_string()),
-                SelectColumn::Column("age".to_string()),
+                SelectColumn::Expression(Expression::Column("id".to_string()), None),
+                SelectColumn::Expression(Expression::Column("name".to_string()), None),
+                SelectColumn::Expression(Expression::Column("age".to_string()), None),
             ]
         );
     }
@@ -947,7 +950,7 @@ mod tests {
         assert!(result.is_ok());
         let query = result.unwrap();
         assert_eq!(query.select.columns, vec![SelectColumn::All]);
-        assert_eq!(query.from.file, "users");
+        assert_eq!(query.from.files, vec!["users"]);
         assert!(query.where_clause.is_some());
     }
 
@@ -961,11 +964,11 @@ mod tests {
         assert_eq!(
             query.select.columns,
             vec![
-                SelectColumn::Column("name".to_string()),
-                SelectColumn::Column("age".to_string()),
+                SelectColumn::Expression(Expression::Column("name".to_string()), None),
+                SelectColumn::Expression(Expression::Column("age".to_string()), None),
             ]
         );
-        assert_eq!(query.from.file, "data.csv");
+        assert_eq!(query.from.files, vec!["data.csv"]);
         assert!(query.where_clause.is_some());
     }
 
@@ -1001,7 +1004,7 @@ mod tests {
         // both should produce same query structure
         let query_without = result_without.unwrap();
         let query_with = result_with.unwrap();
-        assert_eq!(query_without.from.file, query_with.from.file);
+        assert_eq!(query_without.from.files, query_with.from.files);
     }
 
     #[test]
@@ -1013,9 +1016,12 @@ mod tests {
         let query = result.unwrap();
         assert_eq!(
             query.select.columns,
-            vec![SelectColumn::Column("name".to_string())]
+            vec![SelectColumn::Expression(
+                Expression::Column("name".to_string()),
+                None
+            )]
         );
-        assert_eq!(query.from.file, "data.csv");
+        assert_eq!(query.from.files, vec!["data.csv"]);
         assert!(query.where_clause.is_some());
     }
 
@@ -1036,7 +1042,7 @@ mod tests {
         let result = parser.parse(sql);
         assert!(result.is_ok());
         let query = result.unwrap();
-        assert_eq!(query.from.file, "data.csv");
+        assert_eq!(query.from.files, vec!["data.csv"]);
     }
 
     #[test]
@@ -1047,6 +1053,50 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_quoted_identifiers() {
+        let mut parser = Parser::new();
+        let sql = "SELECT \"first name\", [last name], `home city` FROM 'data.csv' WHERE \"first name\" = 'Ada'";
+        let query = parser.parse(sql).unwrap();
+        assert_eq!(
+            query.select.columns,
+            vec![
+                SelectColumn::Expression(Expression::Column("first name".to_string()), None),
+                SelectColumn::Expression(Expression::Column("last name".to_string()), None),
+                SelectColumn::Expression(Expression::Column("home city".to_string()), None),
+            ]
+        );
+        assert_eq!(
+            query.where_clause.unwrap().condition,
+            Expression::Equal(
+                Box::new(Expression::Column("first name".to_string())),
+                Box::new(Expression::Literal(LiteralValue::String("Ada".to_string()))),
+            )
+        );
+    }
+
+    #[test]
+    fn test_qualified_column_names() {
+        let mut parser = Parser::new();
+        let sql = "SELECT data.name, data.\"first name\" FROM 'data.csv' WHERE data.age > 30";
+        let query = parser.parse(sql).unwrap();
+        assert_eq!(
+            query.select.columns,
+            vec![
+                SelectColumn::Expression(Expression::Column("name".to_string()), None),
+                SelectColumn::Expression(Expression::Column("first name".to_string()), None),
+            ]
+        );
+        assert_eq!(
+            query.where_clause.unwrap().condition,
+            Expression::GreaterThan(
+                Box::new(Expression::Column("age".to_string())),
+                Box::new(Expression::Literal(LiteralValue::Integer(30))),
+            )
+        );
+        assert_eq!(query.qualifiers, vec!["data", "data", "data"]);
+    }
+
     #[test]
     fn test_lowercase_count() {
         let mut parser = Parser::new();
@@ -1073,4 +1123,802 @@ mod tests {
         let result = parser.parse(sql);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_like() {
+        let mut parser = Parser::new();
+        let sql = "SELECT * FROM users WHERE name like 'ab%'";
+        let query = parser.parse(sql).unwrap();
+        assert_eq!(
+            query.where_clause.unwrap().condition,
+            Expression::Like(
+                Box::new(Expression::Column("name".to_string())),
+                Box::new(Expression::Literal(LiteralValue::String("ab%".to_string()))),
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_in_and_not_in() {
+        let mut parser = Parser::new();
+        let in_list = vec![
+            Expression::Literal(LiteralValue::String("NYC".to_string())),
+            Expression::Literal(LiteralValue::String("LA".to_string())),
+        ];
+
+        let query = parser
+            .parse("SELECT * FROM users WHERE city IN ('NYC', 'LA')")
+            .unwrap();
+        assert_eq!(
+            query.where_clause.unwrap().condition,
+            Expression::In(
+                Box::new(Expression::Column("city".to_string())),
+                in_list.clone()
+            )
+        );
+
+        // not IN wraps the In expression in Not
+        let query = parser
+            .parse("SELECT * FROM users WHERE city not in ('NYC', 'LA')")
+            .unwrap();
+        assert_eq!(
+            query.where_clause.unwrap().condition,
+            Expression::Not(Box::new(Expression::In(
+                Box::new(Expression::Column("city".to_string())),
+                in_list
+            )))
+        );
+    }
+
+    #[test]
+    fn test_in_empty_list_fails() {
+        let mut parser = Parser::new();
+        let result = parser.parse("SELECT * FROM users WHERE city IN ()");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_arithmetic_precedence() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT * FROM sales WHERE price + tax * 2 > 10")
+            .unwrap();
+
+        let col = |name: &str| Box::new(Expression::Column(name.to_string()));
+        assert_eq!(
+            query.where_clause.unwrap().condition,
+            Expression::GreaterThan(
+                Box::new(Expression::Add(
+                    col("price"),
+                    Box::new(Expression::Multiply(
+                        col("tax"),
+                        Box::new(Expression::Literal(LiteralValue::Integer(2)))
+                    ))
+                )),
+                Box::new(Expression::Literal(LiteralValue::Integer(10)))
+            )
+        );
+    }
+
+    #[test]
+    fn test_modulo_and_negation_precedence() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT * FROM users WHERE id % 2 = 0 AND -balance + 1 > 0")
+            .unwrap();
+
+        let col = |name: &str| Box::new(Expression::Column(name.to_string()));
+        let int = |i: i64| Box::new(Expression::Literal(LiteralValue::Integer(i)));
+        assert_eq!(
+            query.where_clause.unwrap().condition,
+            Expression::And(
+                Box::new(Expression::Equal(
+                    Box::new(Expression::Modulo(col("id"), int(2))),
+                    int(0)
+                )),
+                Box::new(Expression::GreaterThan(
+                    // unary minus applies before the addition
+                    Box::new(Expression::Add(
+                        Box::new(Expression::Negate(col("balance"))),
+                        int(1)
+                    )),
+                    int(0)
+                ))
+            )
+        );
+    }
+
+    #[test]
+    fn test_aggregate_over_expression() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT COUNT(*), sum(price * qty) FROM sales")
+            .unwrap();
+
+        assert_eq!(
+            query.select.columns,
+            vec![
+                SelectColumn::Aggregate(AggregateFunction::CountStar, None),
+                SelectColumn::Aggregate(
+                    AggregateFunction::Sum(Expression::Multiply(
+                        Box::new(Expression::Column("price".to_string())),
+                        Box::new(Expression::Column("qty".to_string()))
+                    )),
+                    None
+                ),
+            ]
+        );
+
+        // only COUNT accepts *
+        assert!(parser.parse("SELECT SUM(*) FROM sales").is_err());
+    }
+
+    #[test]
+    fn test_concat_and_function_calls() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT name || ' (' || city || ')', upper(name), COUNT(*) FROM users")
+            .unwrap();
+
+        let col = |name: &str| Box::new(Expression::Column(name.to_string()));
+        let text = |s: &str| Box::new(Expression::Literal(LiteralValue::String(s.to_string())));
+        assert_eq!(
+            query.select.columns,
+            vec![
+                // || is left-associative
+                SelectColumn::Expression(
+                    Expression::Concat(
+                        Box::new(Expression::Concat(
+                            Box::new(Expression::Concat(col("name"), text(" ("))),
+                            col("city")
+                        )),
+                        text(")")
+                    ),
+                    None
+                ),
+                SelectColumn::Expression(
+                    Expression::FunctionCall {
+                        name: "UPPER".to_string(),
+                        args: vec![Expression::Column("name".to_string())],
+                    },
+                    None
+                ),
+                SelectColumn::Aggregate(AggregateFunction::CountStar, None),
+            ]
+        );
+
+        // unknown names parse; the binder rejects them
+        assert!(parser.parse("SELECT reverse(name) FROM users").is_ok());
+        assert!(parser.parse("SELECT UPPER(*) FROM users").is_err());
+    }
+
+    #[test]
+    fn test_cast_expression() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT * FROM places WHERE CAST(zip AS int) > 90000")
+            .unwrap();
+
+        assert_eq!(
+            query.where_clause.unwrap().condition,
+            Expression::GreaterThan(
+                Box::new(Expression::Cast {
+                    expr: Box::new(Expression::Column("zip".to_string())),
+                    target: ColumnType::Integer,
+                }),
+                Box::new(Expression::Literal(LiteralValue::Integer(90000)))
+            )
+        );
+
+        let err = parser
+            .parse("SELECT CAST(zip AS BLOB) FROM places")
+            .unwrap_err();
+        assert!(err.message.contains("Unknown type 'BLOB'"));
+    }
+
+    #[test]
+    fn test_case_expression() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse(
+                "SELECT CASE WHEN age >= 18 THEN 'adult' WHEN age > 12 THEN 'teen' END FROM people",
+            )
+            .unwrap();
+
+        let expected = Expression::Case {
+            branches: vec![
+                (
+                    Expression::GreaterThanOrEqual(
+                        Box::new(Expression::Column("age".to_string())),
+                        Box::new(Expression::Literal(LiteralValue::Integer(18))),
+                    ),
+                    Expression::Literal(LiteralValue::String("adult".to_string())),
+                ),
+                (
+                    Expression::GreaterThan(
+                        Box::new(Expression::Column("age".to_string())),
+                        Box::new(Expression::Literal(LiteralValue::Integer(12))),
+                    ),
+                    Expression::Literal(LiteralValue::String("teen".to_string())),
+                ),
+            ],
+            else_: None,
+        };
+        assert_eq!(
+            query.select.columns,
+            vec![SelectColumn::Expression(expected.clone(), None)]
+        );
+        assert_eq!(
+            expected.to_string(),
+            "CASE WHEN age >= 18 THEN 'adult' WHEN age > 12 THEN 'teen' END"
+        );
+
+        let query = parser
+            .parse("SELECT * FROM people WHERE case when active then 1 else 0 end = 1")
+            .unwrap();
+        assert!(matches!(
+            query.where_clause.unwrap().condition,
+            Expression::Equal(left, _) if matches!(*left, Expression::Case { else_: Some(_), .. })
+        ));
+
+        assert!(parser.parse("SELECT CASE ELSE 1 END FROM people").is_err());
+    }
+
+    #[test]
+    fn test_scientific_notation_literals() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT * FROM sensors WHERE measurement > 1e6 OR measurement < -3.5E-4")
+            .unwrap();
+
+        let col = || Box::new(Expression::Column("measurement".to_string()));
+        assert_eq!(
+            query.where_clause.unwrap().condition,
+            Expression::Or(
+                Box::new(Expression::GreaterThan(
+                    col(),
+                    Box::new(Expression::Literal(LiteralValue::Float(1e6)))
+                )),
+                Box::new(Expression::LessThan(
+                    col(),
+                    Box::new(Expression::Literal(LiteralValue::Float(-3.5e-4)))
+                ))
+            )
+        );
+    }
+
+    #[test]
+    fn test_multiple_from_files() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT * FROM 'jan.csv', \"feb.csv\", mar WHERE amount > 10")
+            .unwrap();
+        assert_eq!(query.from.files, vec!["jan.csv", "feb.csv", "mar"]);
+        assert!(query.where_clause.is_some());
+
+        assert!(parser.parse("SELECT * FROM 'jan.csv',").is_err());
+    }
+
+    #[test]
+    fn test_star_combined_with_columns() {
+        let mut parser = Parser::new();
+        let query = parser.parse("SELECT *, name FROM users").unwrap();
+        assert_eq!(
+            query.select.columns,
+            vec![
+                SelectColumn::All,
+                SelectColumn::Expression(Expression::Column("name".to_string()), None)
+            ]
+        );
+
+        let query = parser.parse("SELECT COUNT(*), * FROM users").unwrap();
+        assert_eq!(
+            query.select.columns,
+            vec![
+                SelectColumn::Aggregate(AggregateFunction::CountStar, None),
+                SelectColumn::All
+            ]
+        );
+    }
+
+    #[test]
+    fn test_order_by_with_aliases() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT city, COUNT(*) AS n FROM sales GROUP BY city ORDER BY n DESC, city")
+            .unwrap();
+
+        let aliases: Vec<_> = query
+            .select
+            .columns
+            .iter()
+            .map(SelectColumn::alias)
+            .collect();
+        assert_eq!(aliases, vec![None, Some("n")]);
+        assert_eq!(
+            query.order_by,
+            vec![
+                OrderBy {
+                    expression: Expression::Column("n".to_string()),
+                    descending: true,
+                },
+                OrderBy {
+                    expression: Expression::Column("city".to_string()),
+                    descending: false,
+                },
+            ]
+        );
+
+        let query = parser
+            .parse("SELECT name FROM users ORDER BY 1 ASC")
+            .unwrap();
+        assert_eq!(
+            query.order_by,
+            vec![OrderBy {
+                expression: Expression::Literal(LiteralValue::Integer(1)),
+                descending: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_sample_clause() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT * FROM 'big.csv' SAMPLE 100 WHERE age > 30 LIMIT 5")
+            .unwrap();
+        assert_eq!(query.sample, Some(100));
+        assert_eq!(query.limit, Some(5));
+        assert!(query.where_clause.is_some());
+
+        let query = parser.parse("SELECT * FROM 'big.csv'").unwrap();
+        assert_eq!(query.sample, None);
+    }
+
+    #[test]
+    fn test_limit_all_and_fetch_first() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT * FROM users LIMIT ALL OFFSET 5")
+            .unwrap();
+        assert_eq!(query.limit, None);
+        assert_eq!(query.offset, Some(5));
+
+        let query = parser
+            .parse("SELECT * FROM users OFFSET 2 ROWS FETCH FIRST 10 ROWS ONLY")
+            .unwrap();
+        assert_eq!(query.limit, Some(10));
+        assert_eq!(query.offset, Some(2));
+
+        let query = parser
+            .parse("select * from users fetch next 1 row only")
+            .unwrap();
+        assert_eq!(query.limit, Some(1));
+        assert_eq!(query.offset, None);
+
+        let err = parser
+            .parse("SELECT * FROM users LIMIT 3 FETCH FIRST 10 ROWS ONLY")
+            .unwrap_err();
+        assert_eq!(err.message, "LIMIT and FETCH FIRST cannot be combined");
+    }
+
+    #[test]
+    fn test_union() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse(
+                "SELECT id FROM a WHERE id > 1 UNION ALL SELECT id FROM b \
+                 UNION SELECT id FROM c ORDER BY 1 DESC LIMIT 3 OFFSET 1",
+            )
+            .unwrap();
+        assert_eq!(query.from.files, vec!["a"]);
+        assert!(query.where_clause.is_some());
+        assert_eq!(query.unions.len(), 2);
+        assert!(query.unions[0].all);
+        assert_eq!(query.unions[0].query.from.files, vec!["b"]);
+        assert!(!query.unions[1].all);
+        assert_eq!(query.unions[1].query.from.files, vec!["c"]);
+
+        // the clauses after the last SELECT belong to the whole union
+        assert_eq!(
+            query.order_by,
+            vec![OrderBy {
+                expression: Expression::Literal(LiteralValue::Integer(1)),
+                descending: true,
+            }]
+        );
+        assert_eq!((query.limit, query.offset), (Some(3), Some(1)));
+        let last = &query.unions[1].query;
+        assert!(last.order_by.is_empty());
+        assert_eq!((last.limit, last.offset), (None, None));
+
+        let err = parser
+            .parse("SELECT id FROM a LIMIT 1 UNION SELECT id FROM b")
+            .unwrap_err();
+        assert_eq!(
+            err.message,
+            "ORDER BY, LIMIT and OFFSET must follow the last SELECT of a UNION"
+        );
+    }
+
+    #[test]
+    fn test_not_binds_to_a_single_predicate() {
+        let mut parser = Parser::new();
+        let column = |name: &str| Box::new(Expression::Column(name.to_string()));
+        let int = |n: i64| Expression::Literal(LiteralValue::Integer(n));
+        let text = |s: &str| Box::new(Expression::Literal(LiteralValue::String(s.to_string())));
+        let b_is_2 = Box::new(Expression::Equal(column("b"), Box::new(int(2))));
+        let condition = |parser: &mut Parser, filter: &str| {
+            let sql = format!("SELECT * FROM users WHERE {}", filter);
+            parser.parse(&sql).unwrap().where_clause.unwrap().condition
+        };
+
+        // NOT a IN (1, 2) AND b = 2 → (NOT (a IN (1, 2))) AND (b = 2)
+        assert_eq!(
+            condition(&mut parser, "NOT a IN (1, 2) AND b = 2"),
+            Expression::And(
+                Box::new(Expression::Not(Box::new(Expression::In(
+                    column("a"),
+                    vec![int(1), int(2)]
+                )))),
+                b_is_2.clone()
+            )
+        );
+
+        // NOT a LIKE 'x%' OR b = 2 → (NOT (a LIKE 'x%')) OR (b = 2)
+        assert_eq!(
+            condition(&mut parser, "NOT a LIKE 'x%' OR b = 2"),
+            Expression::Or(
+                Box::new(Expression::Not(Box::new(Expression::Like(
+                    column("a"),
+                    text("x%"),
+                    None
+                )))),
+                b_is_2.clone()
+            )
+        );
+
+        // a NOT LIKE 'x%' AND b = 2 → (NOT (a LIKE 'x%')) AND (b = 2)
+        assert_eq!(
+            condition(&mut parser, "a NOT LIKE 'x%' AND b = 2"),
+            Expression::And(
+                Box::new(Expression::Not(Box::new(Expression::Like(
+                    column("a"),
+                    text("x%"),
+                    None
+                )))),
+                b_is_2.clone()
+            )
+        );
+
+        // NOT a NOT IN (1) → NOT (NOT (a IN (1)))
+        assert_eq!(
+            condition(&mut parser, "NOT a NOT IN (1)"),
+            Expression::Not(Box::new(Expression::Not(Box::new(Expression::In(
+                column("a"),
+                vec![int(1)]
+            )))))
+        );
+
+        // comparisons don't chain without parentheses
+        let err = parser
+            .parse("SELECT * FROM users WHERE NOT a = 1 = 2")
+            .unwrap_err();
+        assert_eq!(
+            err.message,
+            "comparisons cannot be chained, add parentheses"
+        );
+        assert_eq!(
+            condition(&mut parser, "NOT (a = 1) = true"),
+            Expression::Not(Box::new(Expression::Equal(
+                Box::new(Expression::Equal(column("a"), Box::new(int(1)))),
+                Box::new(Expression::Literal(LiteralValue::Boolean(true)))
+            )))
+        );
+    }
+
+    #[test]
+    fn test_comments_are_ignored() {
+        let mut parser = Parser::new();
+        let plain = parser
+            .parse("SELECT name FROM 'x.csv' WHERE age > 30 LIMIT 2")
+            .unwrap();
+
+        // trailing line comment, with and without a newline after it
+        let query = parser.parse("SELECT * FROM 'x.csv' -- get all").unwrap();
+        assert_eq!(query.select.columns, vec![SelectColumn::All]);
+        assert!(parser.parse("SELECT * FROM 'x.csv' -- get all\n").is_ok());
+
+        // block comments between clauses and line comments across lines
+        let query = parser
+            .parse("SELECT name /* who */ FROM 'x.csv'\n-- adults only\nWHERE age > 30 /* and\nonly two */ LIMIT 2;")
+            .unwrap();
+        assert_eq!(query, plain);
+
+        // comment markers inside quotes are text
+        let query = parser
+            .parse("SELECT \"a--b\" FROM 'x--y.csv' WHERE name = '/* not a comment */'")
+            .unwrap();
+        assert_eq!(
+            query.select.columns,
+            vec![SelectColumn::Expression(
+                Expression::Column("a--b".to_string()),
+                None
+            )]
+        );
+        assert_eq!(query.from.files, vec!["x--y.csv".to_string()]);
+
+        let err = parser
+            .parse("SELECT * FROM 'x.csv' /* never closed")
+            .unwrap_err();
+        assert_eq!(err.message, "Unterminated /* comment");
+        assert_eq!(err.offset, 22);
+    }
+
+    #[test]
+    fn test_is_true_and_is_false() {
+        let mut parser = Parser::new();
+        let active = || Box::new(Expression::Column("active".to_string()));
+        let condition = |parser: &mut Parser, filter: &str| {
+            let sql = format!("SELECT * FROM users WHERE {}", filter);
+            parser.parse(&sql).unwrap().where_clause.unwrap().condition
+        };
+
+        assert_eq!(
+            condition(&mut parser, "active IS TRUE"),
+            Expression::IsTrue(active())
+        );
+        assert_eq!(
+            condition(&mut parser, "active is false"),
+            Expression::IsFalse(active())
+        );
+        assert_eq!(
+            condition(&mut parser, "active IS NOT TRUE"),
+            Expression::Not(Box::new(Expression::IsTrue(active())))
+        );
+        assert_eq!(Expression::IsFalse(active()).to_string(), "active IS FALSE");
+    }
+
+    #[test]
+    fn test_lenient_trailing_comma() {
+        let sql = "SELECT id, name, FROM users";
+        assert!(Parser::new().parse(sql).is_err());
+
+        let mut parser = Parser::new().with_lenient_commas(true);
+        let expected = parser.parse("SELECT id, name FROM users").unwrap();
+        assert_eq!(parser.parse(sql).unwrap(), expected);
+        assert_eq!(
+            parser.parse("SELECT id, name,\n  from users").unwrap(),
+            expected
+        );
+        assert_eq!(
+            parser
+                .parse("SELECT id, name, /* last */ FROM users")
+                .unwrap(),
+            expected
+        );
+
+        // only a single comma right before FROM is forgiven
+        assert!(parser.parse("SELECT , FROM users").is_err());
+        assert!(parser.parse("SELECT id, name,, FROM users").is_err());
+        assert!(parser.parse("SELECT id, FROM_date FROM users").is_ok());
+        assert!(parser.parse("SELECT id, name, FROMX users").is_err());
+
+        // a comma inside a string is text
+        let query = parser
+            .parse("SELECT id FROM users WHERE name = 'a, FROM b'")
+            .unwrap();
+        assert_eq!(query.from.files, vec!["users".to_string()]);
+    }
+
+    #[test]
+    fn test_like_escape() {
+        let mut parser = Parser::new();
+        let like = |pattern: &str, escape| {
+            Expression::Like(
+                Box::new(Expression::Column("name".to_string())),
+                Box::new(Expression::Literal(LiteralValue::String(
+                    pattern.to_string(),
+                ))),
+                escape,
+            )
+        };
+        let condition = |parser: &mut Parser, filter: &str| {
+            let sql = format!("SELECT * FROM users WHERE {}", filter);
+            parser.parse(&sql).unwrap().where_clause.unwrap().condition
+        };
+
+        assert_eq!(
+            condition(&mut parser, "name LIKE '50!%' ESCAPE '!'"),
+            like("50!%", Some('!'))
+        );
+        assert_eq!(
+            condition(&mut parser, "name not like 'a\\_%' escape '\\'"),
+            Expression::Not(Box::new(like("a\\_%", Some('\\'))))
+        );
+        assert_eq!(
+            like("50!%", Some('!')).to_string(),
+            "name LIKE '50!%' ESCAPE '!'"
+        );
+
+        let err = parser
+            .parse("SELECT * FROM users WHERE name LIKE 'a' ESCAPE '!!'")
+            .unwrap_err();
+        assert_eq!(err.message, "ESCAPE must be a single character, got '!!'");
+        assert_eq!(err.offset, 47);
+        assert!(
+            parser
+                .parse("SELECT * FROM users WHERE name LIKE 'a' ESCAPE ''")
+                .is_err()
+        );
+        assert!(
+            parser
+                .parse("SELECT * FROM users WHERE name = 'a' ESCAPE '!'")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_ilike() {
+        let mut parser = Parser::new();
+        let ilike = Expression::ILike(
+            Box::new(Expression::Column("name".to_string())),
+            Box::new(Expression::Literal(LiteralValue::String("al%".to_string()))),
+            None,
+        );
+
+        let query = parser
+            .parse("SELECT * FROM users WHERE name ILIKE 'al%'")
+            .unwrap();
+        assert_eq!(query.where_clause.unwrap().condition, ilike);
+        let query = parser
+            .parse("SELECT * FROM users WHERE name not ilike 'al%'")
+            .unwrap();
+        assert_eq!(
+            query.where_clause.unwrap().condition,
+            Expression::Not(Box::new(ilike.clone()))
+        );
+        assert_eq!(ilike.to_string(), "name ILIKE 'al%'");
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let nested = |depth: usize| {
+            format!(
+                "SELECT * FROM users WHERE {}a = 1{}",
+                "(".repeat(depth),
+                ")".repeat(depth)
+            )
+        };
+
+        // far past the limit: a clean error, not a stack overflow
+        let err = Parser::new().parse(&nested(10_000)).unwrap_err();
+        let message = "Expression nesting exceeds the maximum depth of";
+        assert_eq!(err.message, format!("{} 100", message));
+        // at the 101st parenthesis
+        assert_eq!(err.offset, 26 + 100);
+        assert!(Parser::new().parse(&nested(50)).is_ok());
+
+        // a flat chain is one level however long: it becomes a balanced tree
+        for operator in [" OR ", " AND "] {
+            let chain = vec!["a = 1"; 5_000].join(operator);
+            let sql = format!("SELECT * FROM users WHERE {}", chain);
+            let query = Parser::new().parse(&sql).unwrap();
+            let mut depth = 0;
+            let mut operand = &query.where_clause.unwrap().condition;
+            while let Expression::Or(left, _) | Expression::And(left, _) = operand {
+                depth += 1;
+                operand = left;
+            }
+            assert_eq!(depth, 12);
+        }
+
+        // each pair of parentheses and the comparison are a level
+        let mut parser = Parser::new().with_max_depth(3);
+        assert!(parser.parse(&nested(2)).is_ok());
+        let err = parser.parse(&nested(3)).unwrap_err();
+        assert_eq!(err.message, format!("{} 3", message));
+        for (expression, ok) in [
+            ("a + b + c + d = 1", false),
+            ("a = 1 OR b = 1 AND c = 1 OR d = 1", true),
+            ("(a = 1 OR b = 1) AND c = 1", false),
+            ("NOT NOT a = 1", true),
+            ("NOT NOT NOT a = 1", false),
+        ] {
+            let sql = format!("SELECT * FROM users WHERE {}", expression);
+            assert_eq!(parser.parse(&sql).is_ok(), ok, "{}", expression);
+        }
+        for (call, ok) in [
+            ("UPPER(LOWER(TRIM(name)))", true),
+            ("UPPER(LOWER(TRIM(name || 'x')))", false),
+        ] {
+            let sql = format!("SELECT {} FROM users", call);
+            assert_eq!(parser.parse(&sql).is_ok(), ok, "{}", call);
+        }
+    }
+
+    #[test]
+    fn test_select_expressions_with_aliases() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT id, price * 2 AS double, name AS who, COUNT(*) AS n FROM items")
+            .unwrap();
+
+        let col = |name: &str| Expression::Column(name.to_string());
+        assert_eq!(
+            query.select.columns,
+            vec![
+                // a plain column is a column expression
+                SelectColumn::Expression(col("id"), None),
+                SelectColumn::Expression(
+                    Expression::Multiply(
+                        Box::new(col("price")),
+                        Box::new(Expression::Literal(LiteralValue::Integer(2)))
+                    ),
+                    Some("double".to_string())
+                ),
+                SelectColumn::Expression(col("name"), Some("who".to_string())),
+                SelectColumn::Aggregate(AggregateFunction::CountStar, Some("n".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quoted_file_paths() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT * FROM \"my data/sales 2024.csv\", 'C:\\data\\file.csv'")
+            .unwrap();
+        // quotes are stripped and backslashes are kept as written
+        assert_eq!(
+            query.from.files,
+            vec!["my data/sales 2024.csv", "C:\\data\\file.csv"]
+        );
+    }
+
+    #[test]
+    fn test_date_literal() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT date FROM users WHERE date >= DATE '2024-01-15'")
+            .unwrap();
+        let where_clause = query.where_clause.unwrap();
+        // the text is kept as written; the binder checks it
+        assert_eq!(
+            where_clause.condition,
+            Expression::GreaterThanOrEqual(
+                Box::new(Expression::Column("date".to_string())),
+                Box::new(Expression::Literal(LiteralValue::Date(
+                    "2024-01-15".to_string()
+                )))
+            )
+        );
+        assert_eq!(
+            where_clause.condition.to_string(),
+            "date >= DATE '2024-01-15'"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_literal() {
+        let mut parser = Parser::new();
+        let query = parser
+            .parse("SELECT id FROM users WHERE created < TIMESTAMP '2024-01-15 13:45:00'")
+            .unwrap();
+        let where_clause = query.where_clause.unwrap();
+        assert_eq!(
+            where_clause.condition,
+            Expression::LessThan(
+                Box::new(Expression::Column("created".to_string())),
+                Box::new(Expression::Literal(LiteralValue::Timestamp(
+                    "2024-01-15 13:45:00".to_string()
+                )))
+            )
+        );
+        assert_eq!(
+            where_clause.condition.to_string(),
+            "created < TIMESTAMP '2024-01-15 13:45:00'"
+        );
+    }
 }
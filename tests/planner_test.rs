@@ -273,8 +273,10 @@ fn test_plan_count_column() {
         LogicalOperator::Aggregate(agg) => {
             assert_eq!(agg.aggregates.len(), 1);
             match &agg.aggregates[0] {
-                BoundAggregateExpression::Count { column } => {
-                    assert_eq!(column.name, "name");
+                BoundAggregateExpression::Count {
+                    expression: BoundExpression::ColumnRef { name, .. },
+                } => {
+                    assert_eq!(name, "name");
                 }
                 _ => panic!("Expected Count aggregate"),
             }
@@ -376,7 +378,7 @@ fn test_plan_multiple_aggregates() {
 
     // create a bound query manually with multiple aggregates
     use celect::binder::BoundAggregateExpression;
-    use celect::{BoundQuery, Column, ColumnType, Schema};
+    use celect::{BoundQuery, Column, ColumnType, Schema, SourceFormat};
     use std::path::PathBuf;
 
     let id_column = Column {
@@ -388,6 +390,7 @@ fn test_plan_multiple_aggregates() {
     let bound_query = BoundQuery {
         select_columns: vec![],
         file_path: PathBuf::from(&test_file),
+        union_files: vec![],
         schema: Schema {
             columns: vec![
                 id_column.clone(),
@@ -404,12 +407,32 @@ fn test_plan_multiple_aggregates() {
             ],
         },
         where_clause: None,
+        group_by: vec![],
+        having: None,
+        output: vec![],
+        aliases: vec![],
+        order_by: vec![],
+        sample: None,
         limit: None,
         offset: None,
         aggregates: vec![
             BoundAggregateExpression::CountStar,
-            BoundAggregateExpression::Count { column: id_column },
+            BoundAggregateExpression::Count {
+                expression: BoundExpression::ColumnRef {
+                    name: id_column.name,
+                    index: id_column.index,
+                    type_: id_column.type_,
+                },
+            },
         ],
+        explain: false,
+        analyze: false,
+        has_header: true,
+        format: SourceFormat::Csv,
+        empty_as_null: true,
+        null_tokens: vec!["null".to_string()],
+        source: None,
+        unions: vec![],
     };
 
     let plan = planner.plan(bound_query);
@@ -430,3 +453,37 @@ fn test_plan_multiple_aggregates() {
         _ => panic!("Expected Aggregate as root"),
     }
 }
+
+#[test]
+fn test_plan_union() {
+    let test_file = format!(
+        "test_plan_union_{}.csv",
+        TEST_COUNTER.fetch_add(1, Ordering::SeqCst)
+    );
+    let _guard = TestFileGuard::new(test_file.clone());
+    fs::write(&test_file, "id,name\n1,Alice").unwrap();
+
+    let sql = format!(
+        "SELECT id FROM '{0}' UNION ALL SELECT id FROM '{0}' UNION SELECT id FROM '{0}' LIMIT 5",
+        test_file
+    );
+    let query = Parser::new().parse(&sql).unwrap();
+    let bound_query = Binder::new().bind(query).unwrap();
+    let plan = Planner::new().plan(bound_query);
+
+    // expected plan: Limit -> Distinct -> Union of the three SELECTs
+    let LogicalOperator::Limit(limit) = plan else {
+        panic!("Expected Limit as root");
+    };
+    assert_eq!(limit.limit, Some(5));
+    let LogicalOperator::Distinct(distinct) = *limit.child else {
+        panic!("Expected Distinct under Limit");
+    };
+    let LogicalOperator::Union(union) = *distinct.child else {
+        panic!("Expected Union under Distinct");
+    };
+    assert_eq!(union.children.len(), 3);
+    for child in &union.children {
+        assert!(matches!(child, LogicalOperator::Projection(_)));
+    }
+}